@@ -0,0 +1,179 @@
+use barter_data::subscription::book::{InnerOrderBook, Level, OrderBook, OrderBookSide};
+use barter_integration::model::Side;
+use chrono::Utc;
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use parking_lot::Mutex;
+use rust_decimal::Decimal;
+use std::sync::Arc;
+
+/// Number of price levels on each side of the benchmarked books.
+const DEPTHS: [usize; 3] = [20, 100, 1000];
+
+/// Previous `Vec` based [`OrderBookSide`] implementation (best to worst, linear scan), kept
+/// here as the baseline to compare against.
+mod legacy {
+    use super::*;
+
+    #[derive(Clone)]
+    pub struct LegacyOrderBookSide {
+        pub side: Side,
+        pub levels: Vec<Level>,
+    }
+
+    impl LegacyOrderBookSide {
+        pub fn upsert_single(&mut self, new_level: Level) {
+            let len = self.levels.len();
+
+            let mut placeholder = Level::new(0, 0);
+            let (index, level) = match self.side {
+                Side::Buy => self
+                    .levels
+                    .iter_mut()
+                    .enumerate()
+                    .find(|(_, level)| {
+                        level.price < new_level.price || level.eq_price(new_level.price)
+                    })
+                    .unwrap_or((len, &mut placeholder)),
+                Side::Sell => self
+                    .levels
+                    .iter_mut()
+                    .enumerate()
+                    .find(|(_, level)| {
+                        level.price > new_level.price || level.eq_price(new_level.price)
+                    })
+                    .unwrap_or((len, &mut placeholder)),
+            };
+
+            match (level.eq_price(new_level.price), new_level.amount.is_zero()) {
+                (true, true) => {
+                    self.levels.remove(index);
+                }
+                (true, false) => *level = new_level,
+                (false, false) => self.levels.insert(index, new_level),
+                (false, true) => {}
+            }
+        }
+    }
+
+    #[derive(Clone)]
+    pub struct LegacyOrderBook {
+        pub book: Arc<Mutex<(LegacyOrderBookSide, LegacyOrderBookSide)>>,
+    }
+}
+
+/// Generate `depth` bid [`Level`]s below 1000.0 with a tick size of 0.01.
+fn bid_levels(depth: usize) -> Vec<Level> {
+    (0..depth)
+        .map(|tick| Level::new(Decimal::new(100_000 - tick as i64, 2), Decimal::ONE))
+        .collect()
+}
+
+/// Deterministic stream of updates concentrated near the top of the book, mimicking 100ms
+/// depth deltas: replace, remove & re-insert levels within the top 10 ticks.
+fn updates(count: usize) -> Vec<Level> {
+    (0..count)
+        .map(|i| {
+            let price = Decimal::new(100_000 - (i % 10) as i64, 2);
+            let amount = if i % 3 == 0 {
+                Decimal::ZERO
+            } else {
+                Decimal::from(i % 7 + 1)
+            };
+            Level::new(price, amount)
+        })
+        .collect()
+}
+
+fn bench_upsert(c: &mut Criterion) {
+    let mut group = c.benchmark_group("order_book_side_upsert");
+    let updates = updates(1_000);
+
+    for depth in DEPTHS {
+        let levels = bid_levels(depth);
+
+        group.bench_with_input(BenchmarkId::new("ladder", depth), &levels, |b, levels| {
+            let side = OrderBookSide::new(Side::Buy, levels.clone());
+            b.iter(|| {
+                let mut side = side.clone();
+                for level in &updates {
+                    side.upsert_single(*level);
+                }
+                black_box(side)
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("legacy", depth), &levels, |b, levels| {
+            let side = legacy::LegacyOrderBookSide {
+                side: Side::Buy,
+                levels: levels.clone(),
+            };
+            b.iter(|| {
+                let mut side = side.clone();
+                for level in &updates {
+                    side.upsert_single(*level);
+                }
+                black_box(side)
+            })
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_update_and_publish(c: &mut Criterion) {
+    let mut group = c.benchmark_group("order_book_update_and_publish");
+    let updates = updates(1_000);
+
+    for depth in DEPTHS {
+        let levels = bid_levels(depth);
+
+        group.bench_with_input(
+            BenchmarkId::new("copy_on_publish", depth),
+            &levels,
+            |b, levels| {
+                let mut book = OrderBook::from(InnerOrderBook {
+                    last_update_time: Utc::now(),
+                    bids: OrderBookSide::new(Side::Buy, levels.clone()),
+                    asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+                });
+                b.iter(|| {
+                    for level in &updates {
+                        book.make_mut().bids.upsert_single(*level);
+                        black_box(book.snapshot());
+                    }
+                })
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("legacy_mutex", depth),
+            &levels,
+            |b, levels| {
+                let book = legacy::LegacyOrderBook {
+                    book: Arc::new(Mutex::new((
+                        legacy::LegacyOrderBookSide {
+                            side: Side::Buy,
+                            levels: levels.clone(),
+                        },
+                        legacy::LegacyOrderBookSide {
+                            side: Side::Sell,
+                            levels: vec![],
+                        },
+                    ))),
+                };
+                b.iter(|| {
+                    for level in &updates {
+                        let published = book.clone();
+                        published.book.lock().0.upsert_single(*level);
+                        black_box(published);
+                    }
+                })
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_upsert, bench_update_and_publish);
+criterion_main!(benches);
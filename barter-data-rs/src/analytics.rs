@@ -0,0 +1,429 @@
+use crate::subscription::book::{
+    mid_price, volume_weighted_mid_price, Level, OrderBook, OrderBookL1,
+};
+use barter_integration::model::Side;
+use rust_decimal::{prelude::ToPrimitive, Decimal};
+use serde::{Deserialize, Serialize};
+
+/// Number of basis points in 1.0.
+const BPS: Decimal = Decimal::from_parts(10_000, 0, 0, false, 0);
+
+/// Read-only access to the [`Level`]s of an order book, from best to worst price.
+///
+/// Implementors gain every [`BookAnalytics`] calculation for free.
+pub trait BookLevels {
+    /// Bid [`Level`]s ordered from best (highest) to worst price.
+    fn bids(&self) -> impl Iterator<Item = &Level>;
+
+    /// Ask [`Level`]s ordered from best (lowest) to worst price.
+    fn asks(&self) -> impl Iterator<Item = &Level>;
+}
+
+impl BookLevels for OrderBook {
+    fn bids(&self) -> impl Iterator<Item = &Level> {
+        self.book.bids.levels()
+    }
+
+    fn asks(&self) -> impl Iterator<Item = &Level> {
+        self.book.asks.levels()
+    }
+}
+
+impl BookLevels for OrderBookL1 {
+    fn bids(&self) -> impl Iterator<Item = &Level> {
+        std::iter::once(&self.best_bid)
+    }
+
+    fn asks(&self) -> impl Iterator<Item = &Level> {
+        std::iter::once(&self.best_ask)
+    }
+}
+
+/// Order book analytics calculated from the [`BookLevels`] of an [`OrderBook`] or
+/// [`OrderBookL1`].
+///
+/// Calculations that require both sides of the book return `None` if either side is empty.
+pub trait BookAnalytics: BookLevels {
+    /// Best bid [`Level`], if any.
+    fn best_bid_level(&self) -> Option<Level> {
+        self.bids().next().copied()
+    }
+
+    /// Best ask [`Level`], if any.
+    fn best_ask_level(&self) -> Option<Level> {
+        self.asks().next().copied()
+    }
+
+    /// Average of the best bid and ask prices.
+    fn mid(&self) -> Option<Decimal> {
+        let (bid, ask) = (self.best_bid_level()?, self.best_ask_level()?);
+        Some(mid_price(bid.price, ask.price))
+    }
+
+    /// Best ask price minus the best bid price.
+    fn spread(&self) -> Option<Decimal> {
+        let (bid, ask) = (self.best_bid_level()?, self.best_ask_level()?);
+        Some(ask.price - bid.price)
+    }
+
+    /// [`Self::spread`] relative to the [`Self::mid`] price, in basis points.
+    fn spread_bps(&self) -> Option<Decimal> {
+        self.spread()?
+            .checked_div(self.mid()?)
+            .map(|ratio| ratio * BPS)
+    }
+
+    /// Micro-price: the best bid and ask prices weighted by the amount on the opposite side.
+    ///
+    /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
+    fn microprice(&self) -> Option<Decimal> {
+        let (bid, ask) = (self.best_bid_level()?, self.best_ask_level()?);
+        Some(volume_weighted_mid_price(bid, ask))
+    }
+
+    /// Imbalance of the amount resting in the top `n` [`Level`]s of each side, in the
+    /// range [-1.0, 1.0].
+    ///
+    /// Positive values indicate more bid than ask amount.
+    fn imbalance(&self, n: usize) -> Option<Decimal> {
+        let bid_amount = self
+            .bids()
+            .take(n)
+            .map(|level| level.amount)
+            .sum::<Decimal>();
+        let ask_amount = self
+            .asks()
+            .take(n)
+            .map(|level| level.amount)
+            .sum::<Decimal>();
+
+        let total = bid_amount + ask_amount;
+        (total > Decimal::ZERO).then(|| (bid_amount - ask_amount) / total)
+    }
+
+    /// Cumulative amount resting on the provided [`Side`] within `bps` basis points of the
+    /// [`Self::mid`] price.
+    fn depth_within_bps(&self, side: Side, bps: Decimal) -> Option<Decimal> {
+        let mid = self.mid()?;
+        let distance = mid * bps / BPS;
+
+        let depth = match side {
+            Side::Buy => self
+                .bids()
+                .take_while(|level| level.price >= mid - distance)
+                .map(|level| level.amount)
+                .sum(),
+            Side::Sell => self
+                .asks()
+                .take_while(|level| level.price <= mid + distance)
+                .map(|level| level.amount)
+                .sum(),
+        };
+
+        Some(depth)
+    }
+
+    /// Volume weighted average price of immediately executing `quantity` against the book.
+    ///
+    /// A [`Side::Buy`] consumes the asks and a [`Side::Sell`] consumes the bids. Returns `None`
+    /// if the book does not contain enough liquidity to fill the `quantity`.
+    fn vwap(&self, side: Side, quantity: Decimal) -> Option<Decimal> {
+        if quantity <= Decimal::ZERO {
+            return None;
+        }
+
+        let levels: Box<dyn Iterator<Item = &Level> + '_> = match side {
+            Side::Buy => Box::new(self.asks()),
+            Side::Sell => Box::new(self.bids()),
+        };
+
+        let mut remaining = quantity;
+        let mut notional = Decimal::ZERO;
+        for level in levels {
+            let fill = remaining.min(level.amount);
+            notional += fill * level.price;
+            remaining -= fill;
+
+            if remaining <= Decimal::ZERO {
+                return Some(notional / quantity);
+            }
+        }
+
+        None
+    }
+
+    /// Cost of immediately executing `quantity` against the book relative to the
+    /// [`Self::mid`] price, in basis points.
+    ///
+    /// Always positive for a non-empty book, regardless of [`Side`].
+    fn impact_cost_bps(&self, side: Side, quantity: Decimal) -> Option<Decimal> {
+        let mid = self.mid()?;
+        let vwap = self.vwap(side, quantity)?;
+
+        let impact = match side {
+            Side::Buy => vwap - mid,
+            Side::Sell => mid - vwap,
+        };
+
+        impact.checked_div(mid).map(|ratio| ratio * BPS)
+    }
+}
+
+impl<Book> BookAnalytics for Book where Book: BookLevels {}
+
+/// Running statistics of the spread observed across a series of order book updates.
+///
+/// Uses Welford's online algorithm so the variance can be updated without storing the series.
+/// Statistics are approximate, so are tracked as `f64` rather than [`Decimal`].
+#[derive(Copy, Clone, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct SpreadStatistics {
+    pub count: u64,
+    pub last: f64,
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    m2: f64,
+}
+
+impl SpreadStatistics {
+    /// Update the [`SpreadStatistics`] with the spread of the provided book, if both sides of
+    /// the book are populated.
+    pub fn update_from_book<Book>(&mut self, book: &Book)
+    where
+        Book: BookAnalytics,
+    {
+        if let Some(spread) = book.spread().and_then(|spread| spread.to_f64()) {
+            self.update(spread);
+        }
+    }
+
+    /// Update the [`SpreadStatistics`] with the next observed spread.
+    pub fn update(&mut self, spread: f64) {
+        self.count += 1;
+        self.last = spread;
+
+        if self.count == 1 {
+            self.min = spread;
+            self.max = spread;
+        } else {
+            self.min = self.min.min(spread);
+            self.max = self.max.max(spread);
+        }
+
+        let delta = spread - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (spread - self.mean);
+    }
+
+    /// Population variance of the observed spreads.
+    pub fn variance(&self) -> f64 {
+        match self.count {
+            0 => 0.0,
+            count => self.m2 / count as f64,
+        }
+    }
+
+    /// Population standard deviation of the observed spreads.
+    pub fn std_dev(&self) -> f64 {
+        self.variance().sqrt()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscription::book::{InnerOrderBook, OrderBookSide};
+    use rust_decimal_macros::dec;
+
+    fn book() -> OrderBook {
+        OrderBook::from(InnerOrderBook {
+            last_update_time: Default::default(),
+            bids: OrderBookSide::new(
+                Side::Buy,
+                vec![
+                    Level::new(dec!(99.0), dec!(1.0)),
+                    Level::new(dec!(98.0), dec!(2.0)),
+                    Level::new(dec!(97.0), dec!(4.0)),
+                ],
+            ),
+            asks: OrderBookSide::new(
+                Side::Sell,
+                vec![
+                    Level::new(dec!(101.0), dec!(3.0)),
+                    Level::new(dec!(102.0), dec!(1.0)),
+                    Level::new(dec!(103.0), dec!(5.0)),
+                ],
+            ),
+        })
+    }
+
+    #[test]
+    fn test_spread() {
+        let book = book();
+        assert_eq!(book.mid(), Some(dec!(100.0)));
+        assert_eq!(book.spread(), Some(dec!(2.0)));
+        assert_eq!(book.spread_bps(), Some(dec!(200.0)));
+
+        let empty = OrderBook::from(InnerOrderBook {
+            last_update_time: Default::default(),
+            bids: OrderBookSide::new(Side::Buy, vec![Level::new(dec!(99.0), dec!(1.0))]),
+            asks: OrderBookSide::new(Side::Sell, Vec::<Level>::new()),
+        });
+        assert_eq!(empty.spread(), None);
+        assert_eq!(empty.microprice(), None);
+    }
+
+    #[test]
+    fn test_microprice() {
+        // (99 * 3 + 101 * 1) / 4
+        assert_eq!(book().microprice(), Some(dec!(99.5)));
+    }
+
+    #[test]
+    fn test_imbalance() {
+        struct TestCase {
+            n: usize,
+            expected: Option<Decimal>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: top of book only
+                n: 1,
+                expected: Some(dec!(-2) / dec!(4)),
+            },
+            TestCase {
+                // TC1: top 2 levels
+                n: 2,
+                expected: Some(dec!(-1) / dec!(7)),
+            },
+            TestCase {
+                // TC2: n larger than the book uses every level
+                n: 10,
+                expected: Some(dec!(-2) / dec!(16)),
+            },
+            TestCase {
+                // TC3: no levels
+                n: 0,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(book().imbalance(test.n), test.expected, "TC{index} failed");
+        }
+    }
+
+    #[test]
+    fn test_depth_within_bps() {
+        let book = book();
+        // 100 bps of mid dec!(100.0) => [dec!(99.0), dec!(101.0)]
+        assert_eq!(
+            book.depth_within_bps(Side::Buy, dec!(100.0)),
+            Some(dec!(1.0))
+        );
+        assert_eq!(
+            book.depth_within_bps(Side::Sell, dec!(100.0)),
+            Some(dec!(3.0))
+        );
+        // 250 bps of mid dec!(100.0) => [dec!(97.5), dec!(102.5)]
+        assert_eq!(
+            book.depth_within_bps(Side::Buy, dec!(250.0)),
+            Some(dec!(3.0))
+        );
+        assert_eq!(
+            book.depth_within_bps(Side::Sell, dec!(250.0)),
+            Some(dec!(4.0))
+        );
+    }
+
+    #[test]
+    fn test_vwap_and_impact_cost() {
+        struct TestCase {
+            side: Side,
+            quantity: Decimal,
+            expected_vwap: Option<Decimal>,
+            expected_impact_bps: Option<Decimal>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: buy filled entirely by the best ask
+                side: Side::Buy,
+                quantity: dec!(2.0),
+                expected_vwap: Some(dec!(101.0)),
+                expected_impact_bps: Some(dec!(100.0)),
+            },
+            TestCase {
+                // TC1: buy walks the asks: (3 * 101 + 1 * 102) / 4
+                side: Side::Buy,
+                quantity: dec!(4.0),
+                expected_vwap: Some(dec!(101.25)),
+                expected_impact_bps: Some(dec!(125.0)),
+            },
+            TestCase {
+                // TC2: sell walks the bids: (1 * 99 + 2 * 98 + 1 * 97) / 4
+                side: Side::Sell,
+                quantity: dec!(4.0),
+                expected_vwap: Some(dec!(98.0)),
+                expected_impact_bps: Some(dec!(200.0)),
+            },
+            TestCase {
+                // TC3: not enough liquidity
+                side: Side::Sell,
+                quantity: dec!(8.0),
+                expected_vwap: None,
+                expected_impact_bps: None,
+            },
+        ];
+
+        let book = book();
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(
+                book.vwap(test.side, test.quantity),
+                test.expected_vwap,
+                "TC{index} failed"
+            );
+            assert_eq!(
+                book.impact_cost_bps(test.side, test.quantity),
+                test.expected_impact_bps,
+                "TC{index} failed"
+            );
+        }
+    }
+
+    #[test]
+    fn test_order_book_l1_analytics() {
+        let book = OrderBookL1 {
+            last_update_time: Default::default(),
+            best_bid: Level::new(dec!(99.0), dec!(1.0)),
+            best_ask: Level::new(dec!(101.0), dec!(3.0)),
+        };
+
+        assert_eq!(book.spread(), Some(dec!(2.0)));
+        assert_eq!(book.microprice(), Some(dec!(99.5)));
+        assert_eq!(book.imbalance(5), Some(-dec!(0.5)));
+        assert_eq!(book.vwap(Side::Buy, dec!(3.0)), Some(dec!(101.0)));
+        assert_eq!(book.vwap(Side::Buy, dec!(4.0)), None);
+    }
+
+    #[test]
+    fn test_spread_statistics() {
+        let mut stats = SpreadStatistics::default();
+        for spread in [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0] {
+            stats.update(spread);
+        }
+
+        assert_eq!(stats.count, 8);
+        assert_eq!(stats.last, 9.0);
+        assert_eq!(stats.min, 2.0);
+        assert_eq!(stats.max, 9.0);
+        assert_eq!(stats.mean, 5.0);
+        assert_eq!(stats.variance(), 4.0);
+        assert_eq!(stats.std_dev(), 2.0);
+
+        stats.update_from_book(&book());
+        assert_eq!(stats.count, 9);
+        assert_eq!(stats.last, 2.0);
+    }
+}
@@ -1,4 +1,4 @@
-use barter_integration::error::SocketError;
+use barter_integration::{error::SocketError, model::SubscriptionId};
 use thiserror::Error;
 
 /// All errors generated in `barter-data`.
@@ -17,6 +17,12 @@ pub enum DataError {
         prev_last_update_id: u64,
         first_update_id: u64,
     },
+
+    #[error("SubscriptionId {subscription_id}: {error}")]
+    Subscription {
+        subscription_id: SubscriptionId,
+        error: Box<DataError>,
+    },
 }
 
 impl DataError {
@@ -25,9 +31,33 @@ impl DataError {
     pub fn is_terminal(&self) -> bool {
         match self {
             DataError::InvalidSequence { .. } => true,
+            DataError::Subscription { error, .. } => error.is_terminal(),
             _ => false,
         }
     }
+
+    /// Return the [`SubscriptionId`] this error is attributed to, if known.
+    pub fn subscription_id(&self) -> Option<&SubscriptionId> {
+        match self {
+            DataError::Subscription {
+                subscription_id, ..
+            } => Some(subscription_id),
+            _ => None,
+        }
+    }
+
+    /// Return the `(prev_last_update_id, first_update_id)` of a sequence gap, if this error
+    /// communicates one.
+    pub fn sequence_gap(&self) -> Option<(u64, u64)> {
+        match self {
+            DataError::InvalidSequence {
+                prev_last_update_id,
+                first_update_id,
+            } => Some((*prev_last_update_id, *first_update_id)),
+            DataError::Subscription { error, .. } => error.sequence_gap(),
+            _ => None,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -55,6 +85,17 @@ mod tests {
                 input: DataError::Socket(SocketError::Sink),
                 expected: false,
             },
+            TestCase {
+                // TC2: is terminal w/ DataError::Subscription wrapping DataError::InvalidSequence
+                input: DataError::Subscription {
+                    subscription_id: SubscriptionId::from("@depth@100ms|BTCUSDT"),
+                    error: Box::new(DataError::InvalidSequence {
+                        prev_last_update_id: 0,
+                        first_update_id: 0,
+                    }),
+                },
+                expected: true,
+            },
         ];
 
         for (index, test) in tests.into_iter().enumerate() {
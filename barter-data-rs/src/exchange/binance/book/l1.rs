@@ -6,6 +6,7 @@ use crate::{
 };
 use barter_integration::model::{instrument::Instrument, Exchange, SubscriptionId};
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// [`Binance`](super::super::Binance) real-time OrderBook Level1 (top of book) message.
@@ -47,13 +48,13 @@ pub struct BinanceOrderBookL1 {
     )]
     pub time: DateTime<Utc>,
     #[serde(alias = "b", deserialize_with = "barter_integration::de::de_str")]
-    pub best_bid_price: f64,
+    pub best_bid_price: Decimal,
     #[serde(alias = "B", deserialize_with = "barter_integration::de::de_str")]
-    pub best_bid_amount: f64,
+    pub best_bid_amount: Decimal,
     #[serde(alias = "a", deserialize_with = "barter_integration::de::de_str")]
-    pub best_ask_price: f64,
+    pub best_ask_price: Decimal,
     #[serde(alias = "A", deserialize_with = "barter_integration::de::de_str")]
-    pub best_ask_amount: f64,
+    pub best_ask_amount: Decimal,
 }
 
 impl Identifier<Option<SubscriptionId>> for BinanceOrderBookL1 {
@@ -92,6 +93,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal_macros::dec;
 
     mod de {
         use super::*;
@@ -121,10 +123,10 @@ mod tests {
                     expected: BinanceOrderBookL1 {
                         subscription_id: SubscriptionId::from("@bookTicker|ETHUSDT"),
                         time,
-                        best_bid_price: 1215.27000000,
-                        best_bid_amount: 32.49110000,
-                        best_ask_price: 1215.28000000,
-                        best_ask_amount: 13.93900000,
+                        best_bid_price: dec!(1215.27000000),
+                        best_bid_amount: dec!(32.49110000),
+                        best_ask_price: dec!(1215.28000000),
+                        best_ask_amount: dec!(13.93900000),
                     },
                 },
                 TestCase {
@@ -144,10 +146,10 @@ mod tests {
                     expected: BinanceOrderBookL1 {
                         subscription_id: SubscriptionId::from("@bookTicker|BTCUSDT"),
                         time,
-                        best_bid_price: 16858.90,
-                        best_bid_amount: 13.692,
-                        best_ask_price: 16859.00,
-                        best_ask_amount: 30.219,
+                        best_bid_price: dec!(16858.90),
+                        best_bid_amount: dec!(13.692),
+                        best_ask_price: dec!(16859.00),
+                        best_ask_amount: dec!(30.219),
                     },
                 },
             ];
@@ -1,4 +1,5 @@
 use crate::subscription::book::Level;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Level 1 OrderBook types (top of book).
@@ -17,9 +18,9 @@ pub mod l2;
 #[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct BinanceLevel {
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub amount: f64,
+    pub amount: Decimal,
 }
 
 impl From<BinanceLevel> for Level {
@@ -34,6 +35,7 @@ impl From<BinanceLevel> for Level {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal_macros::dec;
 
     mod de {
         use super::*;
@@ -44,8 +46,8 @@ mod tests {
             assert_eq!(
                 serde_json::from_str::<BinanceLevel>(input).unwrap(),
                 BinanceLevel {
-                    price: 4.00000200,
-                    amount: 12.0
+                    price: dec!(4.00000200),
+                    amount: dec!(12.0)
                 },
             )
         }
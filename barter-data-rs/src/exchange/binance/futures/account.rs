@@ -10,6 +10,7 @@ use barter_integration::model::{
     Exchange, PerpSide, SubscriptionId,
 };
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) AccountUpdate messages.
@@ -129,11 +130,11 @@ pub struct BinanceBalanceUpdate {
     #[serde(alias = "a")]
     pub asset: Symbol,
     #[serde(alias = "wb", deserialize_with = "barter_integration::de::de_str")]
-    pub wallet_balance: f64,
+    pub wallet_balance: Decimal,
     #[serde(alias = "cw", deserialize_with = "barter_integration::de::de_str")]
-    pub cross_wallet_balance: f64,
+    pub cross_wallet_balance: Decimal,
     #[serde(alias = "bc", deserialize_with = "barter_integration::de::de_str")]
-    pub balance_change: f64,
+    pub balance_change: Decimal,
 }
 
 /// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) BinancePositionUpdate.
@@ -156,19 +157,19 @@ pub struct BinancePositionUpdate {
     #[serde(alias = "s")]
     pub symbol: Symbol,
     #[serde(alias = "pa", deserialize_with = "barter_integration::de::de_str")]
-    pub position_amount: f64,
+    pub position_amount: Decimal,
     #[serde(alias = "ep", deserialize_with = "barter_integration::de::de_str")]
-    pub entry_price: f64,
+    pub entry_price: Decimal,
     #[serde(alias = "bep", deserialize_with = "barter_integration::de::de_str")]
-    pub breakeven_price: f64,
+    pub breakeven_price: Decimal,
     #[serde(alias = "cr", deserialize_with = "barter_integration::de::de_str")]
-    pub accumulated_realized: f64,
+    pub accumulated_realized: Decimal,
     #[serde(alias = "up", deserialize_with = "barter_integration::de::de_str")]
-    pub unrealized_pnl: f64,
+    pub unrealized_pnl: Decimal,
     #[serde(alias = "mt")]
     pub margin_type: String,
     #[serde(alias = "iw", deserialize_with = "barter_integration::de::de_str")]
-    pub isolated_wallet: f64,
+    pub isolated_wallet: Decimal,
     #[serde(alias = "ps")]
     pub position_side: PerpSide,
 }
@@ -243,6 +244,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal_macros::dec;
 
     mod de {
         use super::*;
@@ -320,49 +322,49 @@ mod tests {
                     balance_updates: vec![
                         BinanceBalanceUpdate {
                             asset: Symbol::from("USDT"),
-                            wallet_balance: 122624.12345678,
-                            cross_wallet_balance: 100.12345678,
-                            balance_change: 50.12345678,
+                            wallet_balance: dec!(122624.12345678),
+                            cross_wallet_balance: dec!(100.12345678),
+                            balance_change: dec!(50.12345678),
                         },
                         BinanceBalanceUpdate {
                             asset: Symbol::from("BUSD"),
-                            wallet_balance: 1.0,
-                            cross_wallet_balance: 0.0,
-                            balance_change: -49.12345678,
+                            wallet_balance: dec!(1.0),
+                            cross_wallet_balance: dec!(0.0),
+                            balance_change: -dec!(49.12345678),
                         },
                     ],
                     position_updates: vec![
                         BinancePositionUpdate {
                             symbol: Symbol::from("BTCUSDT"),
-                            position_amount: 0.0,
-                            entry_price: 0.0,
-                            breakeven_price: 0.0,
-                            accumulated_realized: 200.0,
-                            unrealized_pnl: 0.0,
+                            position_amount: dec!(0.0),
+                            entry_price: dec!(0.0),
+                            breakeven_price: dec!(0.0),
+                            accumulated_realized: dec!(200.0),
+                            unrealized_pnl: dec!(0.0),
                             margin_type: "isolated".to_string(),
-                            isolated_wallet: 0.0,
+                            isolated_wallet: dec!(0.0),
                             position_side: PerpSide::Both,
                         },
                         BinancePositionUpdate {
                             symbol: Symbol::from("BTCUSDT"),
-                            position_amount: 20.0,
-                            entry_price: 6563.665,
-                            breakeven_price: 6563.6,
-                            accumulated_realized: 0.0,
-                            unrealized_pnl: 2850.212,
+                            position_amount: dec!(20.0),
+                            entry_price: dec!(6563.665),
+                            breakeven_price: dec!(6563.6),
+                            accumulated_realized: dec!(0.0),
+                            unrealized_pnl: dec!(2850.212),
                             margin_type: "isolated".to_string(),
-                            isolated_wallet: 13200.70726908,
+                            isolated_wallet: dec!(13200.70726908),
                             position_side: PerpSide::Long,
                         },
                         BinancePositionUpdate {
                             symbol: Symbol::from("BTCUSDT"),
-                            position_amount: -10.0,
-                            entry_price: 6563.86,
-                            breakeven_price: 6563.6,
-                            accumulated_realized: -45.04,
-                            unrealized_pnl: -1423.156,
+                            position_amount: -dec!(10.0),
+                            entry_price: dec!(6563.86),
+                            breakeven_price: dec!(6563.6),
+                            accumulated_realized: -dec!(45.04),
+                            unrealized_pnl: -dec!(1423.156),
                             margin_type: "isolated".to_string(),
-                            isolated_wallet: 6570.42511771,
+                            isolated_wallet: dec!(6570.42511771),
                             position_side: PerpSide::Short,
                         },
                     ],
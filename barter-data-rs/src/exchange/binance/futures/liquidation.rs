@@ -7,6 +7,7 @@ use crate::{
 };
 use barter_integration::model::{instrument::Instrument, Exchange, Side, SubscriptionId};
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// [`BinanceFuturesUsd`](super::BinanceFuturesUsd) Liquidation order message.
@@ -65,9 +66,9 @@ pub struct BinanceLiquidationOrder {
     #[serde(alias = "S")]
     pub side: Side,
     #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(alias = "q", deserialize_with = "barter_integration::de::de_str")]
-    pub quantity: f64,
+    pub quantity: Decimal,
     #[serde(
         alias = "T",
         deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
@@ -116,6 +117,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal_macros::dec;
 
     mod de {
         use super::*;
@@ -150,8 +152,8 @@ mod tests {
                     order: BinanceLiquidationOrder {
                         subscription_id: SubscriptionId::from("@forceOrder|BTCUSDT"),
                         side: Side::Sell,
-                        price: 18917.15,
-                        quantity: 0.009,
+                        price: dec!(18917.15),
+                        quantity: dec!(0.009),
                         time: datetime_utc_from_epoch_duration(Duration::from_millis(
                             1665523974217,
                         )),
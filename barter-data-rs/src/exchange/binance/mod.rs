@@ -31,6 +31,11 @@ pub mod futures;
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
 
+/// [`InstrumentSpec`](barter_integration::model::instrument::spec::InstrumentSpec) exchange
+/// information types and HTTP fetcher common to both [`BinanceSpot`](spot::BinanceSpot) and
+/// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
+pub mod spec;
+
 /// [`ExchangeServer`] and [`StreamSelector`] implementations for
 /// [`BinanceSpot`](spot::BinanceSpot).
 pub mod spot;
@@ -0,0 +1,319 @@
+use crate::error::DataError;
+use barter_integration::{
+    de::de_u64_epoch_ms_as_datetime_utc,
+    error::SocketError,
+    model::instrument::{
+        kind::{FutureContract, InstrumentKind},
+        spec::{InstrumentSpec, InstrumentSpecs},
+        Instrument,
+    },
+};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// [`BinanceSpot`](super::spot::BinanceSpot) HTTP exchange information url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#exchange-information>
+pub const HTTP_EXCHANGE_INFO_URL_BINANCE_SPOT: &str = "https://api.binance.com/api/v3/exchangeInfo";
+
+/// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) HTTP exchange information url.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#exchange-information>
+pub const HTTP_EXCHANGE_INFO_URL_BINANCE_FUTURES_USD: &str =
+    "https://fapi.binance.com/fapi/v1/exchangeInfo";
+
+/// Fetch the [`InstrumentSpecs`] of every symbol listed by the provided Binance exchange
+/// information url.
+///
+/// eg/ [`HTTP_EXCHANGE_INFO_URL_BINANCE_SPOT`], [`HTTP_EXCHANGE_INFO_URL_BINANCE_FUTURES_USD`]
+pub async fn fetch_instrument_specs(url: &str) -> Result<InstrumentSpecs, DataError> {
+    let exchange_info = reqwest::get(url)
+        .await
+        .map_err(SocketError::Http)?
+        .json::<BinanceExchangeInfo>()
+        .await
+        .map_err(SocketError::Http)?;
+
+    Ok(InstrumentSpecs::from(exchange_info))
+}
+
+/// [`Binance`](super::Binance) exchange information, containing the trading rules of every
+/// listed symbol.
+///
+/// ### Raw Payload Examples
+/// #### BinanceSpot
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#exchange-information>
+/// ```json
+/// {
+///     "timezone": "UTC",
+///     "serverTime": 1565246363776,
+///     "symbols": [
+///         {
+///             "symbol": "ETHBTC",
+///             "status": "TRADING",
+///             "baseAsset": "ETH",
+///             "quoteAsset": "BTC",
+///             "filters": [
+///                 {"filterType": "PRICE_FILTER", "minPrice": "0.00000100", "maxPrice": "922327.00000000", "tickSize": "0.00000100"},
+///                 {"filterType": "LOT_SIZE", "minQty": "0.00010000", "maxQty": "100000.00000000", "stepSize": "0.00010000"},
+///                 {"filterType": "NOTIONAL", "minNotional": "0.00010000", "maxNotional": "9000000.00000000"}
+///             ]
+///         }
+///     ]
+/// }
+/// ```
+///
+/// #### BinanceFuturesUsd
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#exchange-information>
+/// ```json
+/// {
+///     "timezone": "UTC",
+///     "serverTime": 1565246363776,
+///     "symbols": [
+///         {
+///             "symbol": "BTCUSDT",
+///             "status": "TRADING",
+///             "contractType": "PERPETUAL",
+///             "deliveryDate": 4133404800000,
+///             "baseAsset": "BTC",
+///             "quoteAsset": "USDT",
+///             "filters": [
+///                 {"filterType": "PRICE_FILTER", "minPrice": "556.80", "maxPrice": "4529764", "tickSize": "0.10"},
+///                 {"filterType": "LOT_SIZE", "minQty": "0.001", "maxQty": "1000", "stepSize": "0.001"},
+///                 {"filterType": "MIN_NOTIONAL", "notional": "5.0"}
+///             ]
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceExchangeInfo {
+    pub symbols: Vec<BinanceSymbolInfo>,
+}
+
+/// [`Binance`](super::Binance) trading rules for a single symbol.
+///
+/// See [`BinanceExchangeInfo`] for full raw payload examples.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceSymbolInfo {
+    pub symbol: String,
+    pub base_asset: String,
+    pub quote_asset: String,
+    /// Only present for [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) symbols.
+    #[serde(default)]
+    pub contract_type: Option<String>,
+    /// Only present for [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) symbols.
+    #[serde(default, deserialize_with = "de_option_epoch_ms_as_datetime_utc")]
+    pub delivery_date: Option<DateTime<Utc>>,
+    pub filters: Vec<BinanceSymbolFilter>,
+}
+
+/// [`Binance`](super::Binance) symbol filter. Filters not required to construct an
+/// [`InstrumentSpec`] are deserialised as [`BinanceSymbolFilter::Other`].
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#filters>
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(tag = "filterType", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BinanceSymbolFilter {
+    #[serde(rename_all = "camelCase")]
+    PriceFilter { tick_size: Decimal },
+    #[serde(rename_all = "camelCase")]
+    LotSize {
+        step_size: Decimal,
+        min_qty: Decimal,
+    },
+    /// [`BinanceSpot`](super::spot::BinanceSpot) uses `minNotional`, whereas
+    /// [`BinanceFuturesUsd`](super::futures::BinanceFuturesUsd) uses `notional`.
+    MinNotional {
+        #[serde(alias = "minNotional")]
+        notional: Decimal,
+    },
+    #[serde(rename_all = "camelCase")]
+    Notional { min_notional: Decimal },
+    #[serde(other)]
+    Other,
+}
+
+impl BinanceSymbolInfo {
+    /// Determine the [`InstrumentKind`] of this symbol. Returns `None` for contract types
+    /// without a delivery date that are not perpetuals.
+    pub fn kind(&self) -> Option<InstrumentKind> {
+        match (self.contract_type.as_deref(), self.delivery_date) {
+            (None, _) => Some(InstrumentKind::Spot),
+            (Some("PERPETUAL"), _) => Some(InstrumentKind::Perpetual),
+            (Some(_), Some(expiry)) => Some(InstrumentKind::Future(FutureContract { expiry })),
+            (Some(_), None) => None,
+        }
+    }
+
+    /// Construct an [`InstrumentSpec`] from this symbol's filters.
+    pub fn spec(&self) -> Option<InstrumentSpec> {
+        let instrument = Instrument::from((&self.base_asset, &self.quote_asset, self.kind()?));
+
+        let mut spec = InstrumentSpec::new(instrument, Decimal::ZERO, Decimal::ZERO);
+        for filter in &self.filters {
+            match filter {
+                BinanceSymbolFilter::PriceFilter { tick_size } => spec.tick_size = *tick_size,
+                BinanceSymbolFilter::LotSize { step_size, min_qty } => {
+                    spec.lot_size = *step_size;
+                    spec.min_quantity = *min_qty;
+                }
+                BinanceSymbolFilter::MinNotional { notional }
+                | BinanceSymbolFilter::Notional {
+                    min_notional: notional,
+                } => spec.min_notional = *notional,
+                BinanceSymbolFilter::Other => {}
+            }
+        }
+
+        Some(spec)
+    }
+}
+
+impl From<BinanceExchangeInfo> for InstrumentSpecs {
+    fn from(exchange_info: BinanceExchangeInfo) -> Self {
+        exchange_info
+            .symbols
+            .iter()
+            .filter_map(BinanceSymbolInfo::spec)
+            .collect()
+    }
+}
+
+/// Deserialize an optional u64 milliseconds value as `DateTime<Utc>`.
+fn de_option_epoch_ms_as_datetime_utc<'de, D>(
+    deserializer: D,
+) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct Wrapper(#[serde(deserialize_with = "de_u64_epoch_ms_as_datetime_utc")] DateTime<Utc>);
+
+    Option::<Wrapper>::deserialize(deserializer).map(|wrapper| wrapper.map(|Wrapper(time)| time))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_de_binance_exchange_info() {
+        struct TestCase {
+            input: &'static str,
+            expected: Vec<InstrumentSpec>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: BinanceSpot exchange info with NOTIONAL filter
+                input: r#"
+                {
+                    "timezone": "UTC",
+                    "serverTime": 1565246363776,
+                    "symbols": [
+                        {
+                            "symbol": "ETHBTC",
+                            "status": "TRADING",
+                            "baseAsset": "ETH",
+                            "quoteAsset": "BTC",
+                            "filters": [
+                                {"filterType": "PRICE_FILTER", "minPrice": "0.00000100", "maxPrice": "922327.00000000", "tickSize": "0.00000100"},
+                                {"filterType": "LOT_SIZE", "minQty": "0.00010000", "maxQty": "100000.00000000", "stepSize": "0.00010000"},
+                                {"filterType": "ICEBERG_PARTS", "limit": 10},
+                                {"filterType": "NOTIONAL", "minNotional": "0.00010000", "maxNotional": "9000000.00000000"}
+                            ]
+                        }
+                    ]
+                }
+                "#,
+                expected: vec![InstrumentSpec {
+                    instrument: Instrument::from(("eth", "btc", InstrumentKind::Spot)),
+                    tick_size: dec!(0.000001),
+                    lot_size: dec!(0.0001),
+                    min_quantity: dec!(0.0001),
+                    min_notional: dec!(0.0001),
+                    contract_multiplier: dec!(1),
+                }],
+            },
+            TestCase {
+                // TC1: BinanceFuturesUsd exchange info with perpetual & quarterly contracts
+                input: r#"
+                {
+                    "timezone": "UTC",
+                    "serverTime": 1565246363776,
+                    "symbols": [
+                        {
+                            "symbol": "BTCUSDT",
+                            "status": "TRADING",
+                            "contractType": "PERPETUAL",
+                            "deliveryDate": 4133404800000,
+                            "baseAsset": "BTC",
+                            "quoteAsset": "USDT",
+                            "filters": [
+                                {"filterType": "PRICE_FILTER", "minPrice": "556.80", "maxPrice": "4529764", "tickSize": "0.10"},
+                                {"filterType": "LOT_SIZE", "minQty": "0.001", "maxQty": "1000", "stepSize": "0.001"},
+                                {"filterType": "MIN_NOTIONAL", "notional": "5.0"}
+                            ]
+                        },
+                        {
+                            "symbol": "BTCUSDT_231229",
+                            "status": "TRADING",
+                            "contractType": "CURRENT_QUARTER",
+                            "deliveryDate": 1703836800000,
+                            "baseAsset": "BTC",
+                            "quoteAsset": "USDT",
+                            "filters": [
+                                {"filterType": "PRICE_FILTER", "minPrice": "576.30", "maxPrice": "1000000", "tickSize": "0.10"},
+                                {"filterType": "LOT_SIZE", "minQty": "0.001", "maxQty": "500", "stepSize": "0.001"}
+                            ]
+                        }
+                    ]
+                }
+                "#,
+                expected: vec![
+                    InstrumentSpec {
+                        instrument: Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+                        tick_size: dec!(0.1),
+                        lot_size: dec!(0.001),
+                        min_quantity: dec!(0.001),
+                        min_notional: dec!(5),
+                        contract_multiplier: dec!(1),
+                    },
+                    InstrumentSpec {
+                        instrument: Instrument::from((
+                            "btc",
+                            "usdt",
+                            InstrumentKind::Future(FutureContract {
+                                expiry: DateTime::from_timestamp_millis(1703836800000).unwrap(),
+                            }),
+                        )),
+                        tick_size: dec!(0.1),
+                        lot_size: dec!(0.001),
+                        min_quantity: dec!(0.001),
+                        min_notional: dec!(0),
+                        contract_multiplier: dec!(1),
+                    },
+                ],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = InstrumentSpecs::from(
+                serde_json::from_str::<BinanceExchangeInfo>(test.input).unwrap(),
+            );
+            assert_eq!(actual.len(), test.expected.len(), "TC{} failed", index);
+            for expected in test.expected {
+                assert_eq!(
+                    actual.get(&expected.instrument),
+                    Some(&expected),
+                    "TC{} failed",
+                    index
+                );
+            }
+        }
+    }
+}
@@ -222,11 +222,10 @@ impl OrderBookUpdater for BinanceSpotBookUpdater {
         // 7. The data in each event is the absolute quantity for a price level.
         // 8. If the quantity is 0, remove the price level.
 
-        let mut lock = book.book.lock();
-        lock.last_update_time = Utc::now();
-        lock.bids.upsert(update.bids);
-        lock.asks.upsert(update.asks);
-        drop(lock);
+        let inner = book.make_mut();
+        inner.last_update_time = Utc::now();
+        inner.bids.upsert(update.bids);
+        inner.asks.upsert(update.asks);
 
         // Update OrderBookUpdater metadata
         self.updates_processed += 1;
@@ -240,6 +239,7 @@ impl OrderBookUpdater for BinanceSpotBookUpdater {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal_macros::dec;
 
     mod de {
         use super::*;
@@ -269,12 +269,12 @@ mod tests {
                     last_update_id: 22611425151,
                     bids: vec![
                         BinanceLevel {
-                            price: 1209.67000000,
-                            amount: 85.48210000
+                            price: dec!(1209.67000000),
+                            amount: dec!(85.48210000)
                         },
                         BinanceLevel {
-                            price: 1209.66000000,
-                            amount: 20.68790000
+                            price: dec!(1209.66000000),
+                            amount: dec!(20.68790000)
                         },
                     ],
                     asks: vec![]
@@ -544,25 +544,25 @@ mod tests {
                         bids: vec![
                             // Level exists & new value is 0 => remove Level
                             BinanceLevel {
-                                price: 80.0,
-                                amount: 0.0,
+                                price: dec!(80.0),
+                                amount: dec!(0.0),
                             },
                             // Level exists & new value is > 0 => replace Level
                             BinanceLevel {
-                                price: 90.0,
-                                amount: 10.0,
+                                price: dec!(90.0),
+                                amount: dec!(10.0),
                             },
                         ],
                         asks: vec![
                             // Level does not exist & new value > 0 => insert new Level
                             BinanceLevel {
-                                price: 200.0,
-                                amount: 1.0,
+                                price: dec!(200.0),
+                                amount: dec!(1.0),
                             },
                             // Level does not exist & new value is 0 => no change
                             BinanceLevel {
-                                price: 500.0,
-                                amount: 0.0,
+                                price: dec!(500.0),
+                                amount: dec!(0.0),
                             },
                         ],
                     },
@@ -590,12 +590,9 @@ mod tests {
                 let actual = test.updater.update(&mut book, test.input_update);
 
                 match (actual, test.expected) {
-                    (Ok(Some(actual)), Ok(Some(expected))) => {
+                    (Ok(Some(mut actual)), Ok(Some(expected))) => {
                         // Replace time with deterministic timestamp
-                        let mut lock = actual.book.lock();
-
-                        lock.last_update_time = time;
-                        drop(lock);
+                        actual.make_mut().last_update_time = time;
 
                         assert_eq!(actual, expected, "TC{} failed", index)
                     }
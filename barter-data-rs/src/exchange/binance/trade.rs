@@ -7,6 +7,7 @@ use crate::{
 };
 use barter_integration::model::{instrument::Instrument, Exchange, Side, SubscriptionId};
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Binance real-time trade message.
@@ -62,9 +63,9 @@ pub struct BinanceTrade {
     #[serde(alias = "t")]
     pub id: u64,
     #[serde(alias = "p", deserialize_with = "barter_integration::de::de_str")]
-    pub price: f64,
+    pub price: Decimal,
     #[serde(alias = "q", deserialize_with = "barter_integration::de::de_str")]
-    pub amount: f64,
+    pub amount: Decimal,
     #[serde(alias = "m", deserialize_with = "de_side_from_buyer_is_maker")]
     pub side: Side,
 }
@@ -123,6 +124,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal_macros::dec;
 
     mod de {
         use super::*;
@@ -153,8 +155,8 @@ mod tests {
                             1749354825200,
                         )),
                         id: 1000000000,
-                        price: 10000.19,
-                        amount: 0.239000,
+                        price: dec!(10000.19),
+                        amount: dec!(0.239000),
                         side: Side::Buy,
                     }),
                 },
@@ -184,8 +186,8 @@ mod tests {
                             1749354825200,
                         )),
                         id: 1000000000,
-                        price: 10000.19,
-                        amount: 0.239000,
+                        price: dec!(10000.19),
+                        amount: dec!(0.239000),
                         side: Side::Sell,
                     }),
                 },
@@ -203,8 +205,8 @@ mod tests {
                             1749354825200,
                         )),
                         id: 1000000000,
-                        price: 10000.19,
-                        amount: 0.239000,
+                        price: dec!(10000.19),
+                        amount: dec!(0.239000),
                         side: Side::Buy,
                     }),
                 },
@@ -220,8 +222,8 @@ mod tests {
                             1749354825200,
                         )),
                         id: 1000000000,
-                        price: 10000.19,
-                        amount: 0.239000,
+                        price: dec!(10000.19),
+                        amount: dec!(0.239000),
                         side: Side::Buy,
                     }),
                 },
@@ -40,6 +40,10 @@ pub mod message;
 /// [`BybitSpot`](spot::BybitSpot).
 pub mod spot;
 
+/// [`InstrumentSpec`](barter_integration::model::instrument::spec::InstrumentSpec) instrument
+/// types and HTTP fetcher for [`Bybit`].
+pub mod spec;
+
 /// [`Subscription`](crate::subscription::Subscription) response type and response
 /// [`Validator`](barter_integration::Validator) common to both [`BybitSpot`](spot::BybitSpot)
 /// and [`BybitFuturesUsd`](futures::BybitPerpetualsUsd).
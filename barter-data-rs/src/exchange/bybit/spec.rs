@@ -0,0 +1,306 @@
+use crate::error::DataError;
+use barter_integration::{
+    error::SocketError,
+    model::instrument::{
+        kind::InstrumentKind,
+        spec::{InstrumentSpec, InstrumentSpecs},
+        Instrument,
+    },
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// [`Bybit`](super::Bybit) HTTP public instruments info url.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/market/instrument>
+pub const HTTP_INSTRUMENTS_URL_BYBIT: &str = "https://api.bybit.com/v5/market/instruments-info";
+
+/// Fetch the [`InstrumentSpecs`] of every [`Bybit`](super::Bybit) instrument of the provided
+/// `category` (eg/ "spot", "linear"), following the `nextPageCursor` until every page is fetched.
+pub async fn fetch_instrument_specs(category: &str) -> Result<InstrumentSpecs, DataError> {
+    let mut specs = InstrumentSpecs::new();
+    let mut cursor = String::new();
+
+    loop {
+        let response = reqwest::get(format!(
+            "{HTTP_INSTRUMENTS_URL_BYBIT}?category={category}&limit=1000&cursor={cursor}"
+        ))
+        .await
+        .map_err(SocketError::Http)?
+        .json::<BybitInstruments>()
+        .await
+        .map_err(SocketError::Http)?;
+
+        if response.ret_code != 0 {
+            return Err(DataError::Socket(SocketError::Exchange(format!(
+                "Bybit instruments response code {}: {}",
+                response.ret_code, response.ret_msg
+            ))));
+        }
+
+        cursor = response.result.next_page_cursor.clone();
+        specs.extend(InstrumentSpecs::from(response).iter().cloned());
+
+        if cursor.is_empty() {
+            break Ok(specs);
+        }
+    }
+}
+
+/// [`Bybit`](super::Bybit) public instruments info HTTP response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/market/instrument>
+/// ```json
+/// {
+///     "retCode": 0,
+///     "retMsg": "OK",
+///     "result": {
+///         "category": "linear",
+///         "list": [
+///             {
+///                 "symbol": "BTCUSDT",
+///                 "contractType": "LinearPerpetual",
+///                 "status": "Trading",
+///                 "baseCoin": "BTC",
+///                 "quoteCoin": "USDT",
+///                 "priceFilter": {"minPrice": "0.10", "maxPrice": "199999.80", "tickSize": "0.10"},
+///                 "lotSizeFilter": {"minOrderQty": "0.001", "qtyStep": "0.001", "minNotionalValue": "5"}
+///             }
+///         ],
+///         "nextPageCursor": ""
+///     }
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitInstruments {
+    pub ret_code: i64,
+    pub ret_msg: String,
+    pub result: BybitInstrumentsResult,
+}
+
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitInstrumentsResult {
+    pub category: String,
+    pub list: Vec<BybitInstrument>,
+    #[serde(default)]
+    pub next_page_cursor: String,
+}
+
+/// [`Bybit`](super::Bybit) trading rules for a single instrument.
+///
+/// Spot instruments have no `contractType`, and send their quantity step as `basePrecision`.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitInstrument {
+    pub symbol: String,
+    #[serde(default)]
+    pub contract_type: String,
+    pub status: String,
+    pub base_coin: String,
+    pub quote_coin: String,
+    pub price_filter: BybitPriceFilter,
+    pub lot_size_filter: BybitLotSizeFilter,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitPriceFilter {
+    pub tick_size: Decimal,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitLotSizeFilter {
+    /// Quantity step of derivatives.
+    pub qty_step: Option<Decimal>,
+    /// Quantity step of spot instruments.
+    pub base_precision: Option<Decimal>,
+    pub min_order_qty: Decimal,
+    /// Minimum notional of derivatives.
+    pub min_notional_value: Option<Decimal>,
+    /// Minimum notional of spot instruments.
+    pub min_order_amt: Option<Decimal>,
+}
+
+impl BybitInstrument {
+    /// Construct an [`InstrumentSpec`] for this instrument, using the provided `category`.
+    /// Returns `None` for instruments that are not trading, and for unsupported contract types
+    /// (eg/ "LinearFutures").
+    pub fn spec(&self, category: &str) -> Option<InstrumentSpec> {
+        let kind = match (category, self.contract_type.as_str()) {
+            ("spot", _) => InstrumentKind::Spot,
+            ("linear", "LinearPerpetual") => InstrumentKind::Perpetual,
+            _ => return None,
+        };
+
+        if self.status != "Trading" {
+            return None;
+        }
+
+        let lot_size = self
+            .lot_size_filter
+            .qty_step
+            .or(self.lot_size_filter.base_precision)?;
+        let min_notional = self
+            .lot_size_filter
+            .min_notional_value
+            .or(self.lot_size_filter.min_order_amt)
+            .unwrap_or(Decimal::ZERO);
+
+        // Bybit spot & linear quantities are always denominated in the base coin
+        Some(InstrumentSpec {
+            instrument: Instrument::from((&self.base_coin, &self.quote_coin, kind)),
+            tick_size: self.price_filter.tick_size,
+            lot_size,
+            min_quantity: self.lot_size_filter.min_order_qty,
+            min_notional,
+            contract_multiplier: Decimal::ONE,
+        })
+    }
+}
+
+impl From<BybitInstruments> for InstrumentSpecs {
+    fn from(instruments: BybitInstruments) -> Self {
+        let category = instruments.result.category.as_str();
+        instruments
+            .result
+            .list
+            .iter()
+            .filter_map(|instrument| instrument.spec(category))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_de_bybit_instruments() {
+        struct TestCase {
+            input: &'static str,
+            expected: Vec<InstrumentSpec>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: spot instruments, ignoring instruments that are not trading
+                input: r#"
+                {
+                    "retCode": 0,
+                    "retMsg": "OK",
+                    "result": {
+                        "category": "spot",
+                        "list": [
+                            {
+                                "symbol": "BTCUSDT",
+                                "baseCoin": "BTC",
+                                "quoteCoin": "USDT",
+                                "innovation": "0",
+                                "status": "Trading",
+                                "marginTrading": "both",
+                                "lotSizeFilter": {
+                                    "basePrecision": "0.000001",
+                                    "quotePrecision": "0.00000001",
+                                    "minOrderQty": "0.000048",
+                                    "maxOrderQty": "71.73956243",
+                                    "minOrderAmt": "1",
+                                    "maxOrderAmt": "2000000"
+                                },
+                                "priceFilter": {"tickSize": "0.01"}
+                            },
+                            {
+                                "symbol": "ETHUSDT",
+                                "baseCoin": "ETH",
+                                "quoteCoin": "USDT",
+                                "status": "PreLaunch",
+                                "lotSizeFilter": {
+                                    "basePrecision": "0.00001",
+                                    "minOrderQty": "0.001",
+                                    "minOrderAmt": "1"
+                                },
+                                "priceFilter": {"tickSize": "0.01"}
+                            }
+                        ]
+                    }
+                }
+                "#,
+                expected: vec![InstrumentSpec {
+                    instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                    tick_size: dec!(0.01),
+                    lot_size: dec!(0.000001),
+                    min_quantity: dec!(0.000048),
+                    min_notional: dec!(1),
+                    contract_multiplier: dec!(1),
+                }],
+            },
+            TestCase {
+                // TC1: linear perpetuals, ignoring dated futures
+                input: r#"
+                {
+                    "retCode": 0,
+                    "retMsg": "OK",
+                    "result": {
+                        "category": "linear",
+                        "list": [
+                            {
+                                "symbol": "BTCUSDT",
+                                "contractType": "LinearPerpetual",
+                                "status": "Trading",
+                                "baseCoin": "BTC",
+                                "quoteCoin": "USDT",
+                                "settleCoin": "USDT",
+                                "priceFilter": {"minPrice": "0.10", "maxPrice": "199999.80", "tickSize": "0.10"},
+                                "lotSizeFilter": {
+                                    "maxOrderQty": "100.000",
+                                    "minOrderQty": "0.001",
+                                    "qtyStep": "0.001",
+                                    "minNotionalValue": "5"
+                                }
+                            },
+                            {
+                                "symbol": "BTC-26JUN26",
+                                "contractType": "LinearFutures",
+                                "status": "Trading",
+                                "baseCoin": "BTC",
+                                "quoteCoin": "USDT",
+                                "priceFilter": {"tickSize": "0.50"},
+                                "lotSizeFilter": {"minOrderQty": "0.001", "qtyStep": "0.001"}
+                            }
+                        ],
+                        "nextPageCursor": ""
+                    }
+                }
+                "#,
+                expected: vec![InstrumentSpec {
+                    instrument: Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+                    tick_size: dec!(0.1),
+                    lot_size: dec!(0.001),
+                    min_quantity: dec!(0.001),
+                    min_notional: dec!(5),
+                    contract_multiplier: dec!(1),
+                }],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = InstrumentSpecs::from(
+                serde_json::from_str::<BybitInstruments>(test.input).unwrap(),
+            );
+
+            assert_eq!(actual.len(), test.expected.len(), "TC{} failed", index);
+            for expected in test.expected {
+                assert_eq!(
+                    actual.get(&expected.instrument),
+                    Some(&expected),
+                    "TC{} failed",
+                    index
+                );
+            }
+        }
+    }
+}
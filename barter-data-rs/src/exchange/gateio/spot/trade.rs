@@ -7,6 +7,7 @@ use crate::{
 };
 use barter_integration::model::{instrument::Instrument, Exchange, Side, SubscriptionId};
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Terse type alias for an [`GateioSpot`](super::GateioSpot) real-time trades WebSocket message.
@@ -38,10 +39,10 @@ pub struct GateioSpotTradeInner {
     pub time: DateTime<Utc>,
     pub id: u64,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub price: f64,
+    pub price: Decimal,
 
     #[serde(alias = "size", deserialize_with = "barter_integration::de::de_str")]
-    pub amount: f64,
+    pub amount: Decimal,
 
     /// Taker [`Side`] of the trade.
     pub side: Side,
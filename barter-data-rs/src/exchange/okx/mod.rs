@@ -22,6 +22,10 @@ pub mod channel;
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
 
+/// [`InstrumentSpec`](barter_integration::model::instrument::spec::InstrumentSpec) instrument
+/// types and HTTP fetcher for [`Okx`].
+pub mod spec;
+
 /// [`Subscription`](crate::subscription::Subscription) response type and response
 /// [`Validator`](barter_integration::Validator) for [`Okx`].
 pub mod subscription;
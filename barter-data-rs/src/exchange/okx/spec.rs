@@ -0,0 +1,262 @@
+use crate::error::DataError;
+use barter_integration::{
+    error::SocketError,
+    model::instrument::{
+        kind::{FutureContract, InstrumentKind},
+        spec::{InstrumentSpec, InstrumentSpecs},
+        Instrument,
+    },
+};
+use chrono::DateTime;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// [`Okx`](super::Okx) HTTP public instruments url.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-rest-api-get-instruments>
+pub const HTTP_INSTRUMENTS_URL_OKX: &str = "https://www.okx.com/api/v5/public/instruments";
+
+/// Fetch the [`InstrumentSpecs`] of every [`Okx`](super::Okx) instrument of the provided
+/// `instType` (eg/ "SPOT", "SWAP", "FUTURES").
+pub async fn fetch_instrument_specs(instrument_type: &str) -> Result<InstrumentSpecs, DataError> {
+    let response = reqwest::get(format!(
+        "{HTTP_INSTRUMENTS_URL_OKX}?instType={instrument_type}"
+    ))
+    .await
+    .map_err(SocketError::Http)?
+    .json::<OkxInstruments>()
+    .await
+    .map_err(SocketError::Http)?;
+
+    match response.code.as_str() {
+        "0" => Ok(InstrumentSpecs::from(response)),
+        _ => Err(DataError::Socket(SocketError::Exchange(format!(
+            "OKX instruments response code {}: {}",
+            response.code, response.msg
+        )))),
+    }
+}
+
+/// [`Okx`](super::Okx) public instruments HTTP response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-rest-api-get-instruments>
+/// ```json
+/// {
+///     "code": "0",
+///     "msg": "",
+///     "data": [
+///         {
+///             "instType": "SWAP",
+///             "instId": "BTC-USDT-SWAP",
+///             "uly": "BTC-USDT",
+///             "baseCcy": "",
+///             "quoteCcy": "",
+///             "ctVal": "0.01",
+///             "ctMult": "1",
+///             "expTime": "",
+///             "tickSz": "0.1",
+///             "lotSz": "1",
+///             "minSz": "1"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OkxInstruments {
+    pub code: String,
+    pub msg: String,
+    pub data: Vec<OkxInstrument>,
+}
+
+/// [`Okx`](super::Okx) trading rules for a single instrument.
+///
+/// Fields that do not apply to an `instType` are sent as empty strings (eg/ `ctVal` for "SPOT").
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxInstrument {
+    pub inst_type: String,
+    pub inst_id: String,
+    #[serde(default)]
+    pub uly: String,
+    #[serde(default)]
+    pub base_ccy: String,
+    #[serde(default)]
+    pub quote_ccy: String,
+    #[serde(default)]
+    pub ct_val: String,
+    #[serde(default)]
+    pub ct_mult: String,
+    #[serde(default)]
+    pub exp_time: String,
+    pub tick_sz: Decimal,
+    pub lot_sz: Decimal,
+    pub min_sz: Decimal,
+}
+
+impl OkxInstrument {
+    /// Construct an [`InstrumentSpec`] for this instrument. Returns `None` for unsupported
+    /// `instType`s (eg/ "OPTION", "MARGIN").
+    pub fn spec(&self) -> Option<InstrumentSpec> {
+        let instrument = match self.inst_type.as_str() {
+            "SPOT" => Instrument::from((&self.base_ccy, &self.quote_ccy, InstrumentKind::Spot)),
+            "SWAP" => {
+                let (base, quote) = self.uly.split_once('-')?;
+                Instrument::from((base, quote, InstrumentKind::Perpetual))
+            }
+            "FUTURES" => {
+                let (base, quote) = self.uly.split_once('-')?;
+                let expiry = DateTime::from_timestamp_millis(self.exp_time.parse().ok()?)?;
+                Instrument::from((
+                    base,
+                    quote,
+                    InstrumentKind::Future(FutureContract { expiry }),
+                ))
+            }
+            _ => return None,
+        };
+
+        // Contract value is only sent for derivatives, spot instruments have a multiplier of one
+        let contract_multiplier = match (
+            self.ct_val.parse::<Decimal>(),
+            self.ct_mult.parse::<Decimal>(),
+        ) {
+            (Ok(value), Ok(multiplier)) => value * multiplier,
+            _ => Decimal::ONE,
+        };
+
+        Some(InstrumentSpec {
+            instrument,
+            tick_size: self.tick_sz,
+            lot_size: self.lot_sz,
+            min_quantity: self.min_sz,
+            min_notional: Decimal::ZERO,
+            contract_multiplier,
+        })
+    }
+}
+
+impl From<OkxInstruments> for InstrumentSpecs {
+    fn from(instruments: OkxInstruments) -> Self {
+        instruments
+            .data
+            .iter()
+            .filter_map(OkxInstrument::spec)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_de_okx_instruments() {
+        let input = r#"
+        {
+            "code": "0",
+            "msg": "",
+            "data": [
+                {
+                    "instType": "SPOT",
+                    "instId": "BTC-USDT",
+                    "uly": "",
+                    "baseCcy": "BTC",
+                    "quoteCcy": "USDT",
+                    "ctVal": "",
+                    "ctMult": "",
+                    "expTime": "",
+                    "tickSz": "0.1",
+                    "lotSz": "0.00000001",
+                    "minSz": "0.00001"
+                },
+                {
+                    "instType": "SWAP",
+                    "instId": "BTC-USDT-SWAP",
+                    "uly": "BTC-USDT",
+                    "baseCcy": "",
+                    "quoteCcy": "",
+                    "ctVal": "0.01",
+                    "ctMult": "1",
+                    "expTime": "",
+                    "tickSz": "0.1",
+                    "lotSz": "1",
+                    "minSz": "1"
+                },
+                {
+                    "instType": "FUTURES",
+                    "instId": "BTC-USD-231229",
+                    "uly": "BTC-USD",
+                    "baseCcy": "",
+                    "quoteCcy": "",
+                    "ctVal": "100",
+                    "ctMult": "1",
+                    "expTime": "1703836800000",
+                    "tickSz": "0.1",
+                    "lotSz": "1",
+                    "minSz": "1"
+                },
+                {
+                    "instType": "OPTION",
+                    "instId": "BTC-USD-231229-35000-C",
+                    "uly": "BTC-USD",
+                    "baseCcy": "",
+                    "quoteCcy": "",
+                    "ctVal": "0.01",
+                    "ctMult": "1",
+                    "expTime": "1703836800000",
+                    "tickSz": "0.0005",
+                    "lotSz": "1",
+                    "minSz": "1"
+                }
+            ]
+        }
+        "#;
+
+        let actual = InstrumentSpecs::from(serde_json::from_str::<OkxInstruments>(input).unwrap());
+
+        let expected = vec![
+            InstrumentSpec {
+                instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                tick_size: dec!(0.1),
+                lot_size: dec!(0.00000001),
+                min_quantity: dec!(0.00001),
+                min_notional: dec!(0),
+                contract_multiplier: dec!(1),
+            },
+            InstrumentSpec {
+                instrument: Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+                tick_size: dec!(0.1),
+                lot_size: dec!(1),
+                min_quantity: dec!(1),
+                min_notional: dec!(0),
+                contract_multiplier: dec!(0.01),
+            },
+            InstrumentSpec {
+                instrument: Instrument::from((
+                    "btc",
+                    "usd",
+                    InstrumentKind::Future(FutureContract {
+                        expiry: DateTime::from_timestamp_millis(1703836800000).unwrap(),
+                    }),
+                )),
+                tick_size: dec!(0.1),
+                lot_size: dec!(1),
+                min_quantity: dec!(1),
+                min_notional: dec!(0),
+                contract_multiplier: dec!(100),
+            },
+        ];
+
+        assert_eq!(actual.len(), expected.len());
+        for (index, expected) in expected.into_iter().enumerate() {
+            assert_eq!(
+                actual.get(&expected.instrument),
+                Some(&expected),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
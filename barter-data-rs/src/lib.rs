@@ -101,6 +101,11 @@ use futures::{SinkExt, Stream, StreamExt};
 use tokio::sync::mpsc::{self};
 use tracing::{debug, error};
 
+/// Order book analytics such as depth, imbalance, microprice and impact cost, calculated from
+/// an [`OrderBook`](subscription::book::OrderBook) or
+/// [`OrderBookL1`](subscription::book::OrderBookL1).
+pub mod analytics;
+
 /// All [`Error`](std::error::Error)s generated in Barter-Data.
 pub mod error;
 
@@ -1,4 +1,8 @@
-use super::{consumer::consume, Streams};
+use super::{
+    consumer::consume,
+    status::{StreamHealth, StreamMonitor, StreamStatus},
+    Streams,
+};
 use crate::{
     error::DataError,
     event::MarketEvent,
@@ -8,7 +12,10 @@ use crate::{
     transformer::ExchangeTransformer,
     Identifier, MarketStream,
 };
-use barter_integration::{error::SocketError, protocol::flat_files::BacktestMode, Validator};
+use barter_integration::{
+    error::SocketError, metric::registry::MetricRegistry, protocol::flat_files::BacktestMode,
+    Validator,
+};
 use std::{collections::HashMap, fmt::Debug, future::Future, pin::Pin};
 use tokio::sync::mpsc;
 
@@ -23,13 +30,25 @@ pub type SubscribeFuture = Pin<Box<dyn Future<Output = Result<(), DataError>>>>;
 
 /// Builder to configure and initialise a [`Streams<MarketEvent<SubKind::Event>`](Streams) instance
 /// for a specific [`SubKind`].
-#[derive(Default)]
 pub struct StreamBuilder<Kind>
 where
     Kind: SubKind,
 {
     pub channels: HashMap<ExchangeId, ExchangeChannel<MarketEvent<Kind::Event>>>,
     pub futures: Vec<SubscribeFuture>,
+    pub status: ExchangeChannel<StreamStatus>,
+    pub health: StreamHealth,
+    /// [`MetricRegistry`] that aggregates the message latency of every subscription.
+    pub metrics: MetricRegistry,
+}
+
+impl<Kind> Default for StreamBuilder<Kind>
+where
+    Kind: SubKind,
+{
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<Kind> Debug for StreamBuilder<Kind>
@@ -40,6 +59,8 @@ where
         f.debug_struct("StreamBuilder<SubKind>")
             .field("channels", &self.channels)
             .field("num_futures", &self.futures.len())
+            .field("health", &self.health)
+            .field("metrics", &self.metrics)
             .finish()
     }
 }
@@ -53,9 +74,18 @@ where
         Self {
             channels: HashMap::new(),
             futures: Vec::new(),
+            status: ExchangeChannel::new(),
+            health: StreamHealth::new(),
+            metrics: MetricRegistry::global(),
         }
     }
 
+    /// Aggregate subscription message latency metrics in the provided [`MetricRegistry`], rather
+    /// than the [`MetricRegistry::global`] registry.
+    pub fn with_metrics(self, metrics: MetricRegistry) -> Self {
+        Self { metrics, ..self }
+    }
+
     pub fn subscribe<SubIter, Sub, Exchange>(self, subscriptions: SubIter) -> Self
     where
         SubIter: IntoIterator<Item = Sub>,
@@ -98,6 +128,11 @@ where
         // '--> Add ExchangeChannel Entry if this Exchange <--> SubKind combination is new
         let exchange_tx = self.channels.entry(Exchange::ID).or_default().tx.clone();
 
+        // Acquire channel Sender & StreamHealth used to monitor the consumer loop
+        let status_tx = self.status.tx.clone();
+        let health = self.health.clone();
+        let metrics = self.metrics.clone();
+
         // Add Future that once awaited will yield the Result<(), SocketError> of subscribing
         self.futures.push(Box::pin(async move {
             // Validate Subscriptions
@@ -114,6 +149,9 @@ where
                 &subscriptions,
             );
 
+            let monitor = StreamMonitor::new(Exchange::ID, &instrument_map, status_tx, &health)
+                .with_metrics(metrics);
+
             let transformer = <Exchange::Stream as MarketStream<Exchange, Kind>>::Transformer::new(
                 instrument_map,
                 backtest_mode,
@@ -127,6 +165,7 @@ where
                 exchange_tx,
                 transformer,
                 backtest_mode,
+                monitor,
             ));
 
             Ok(())
@@ -164,6 +203,8 @@ where
                 .into_iter()
                 .map(|(exchange, channel)| (exchange, channel.rx))
                 .collect(),
+            status: Some(self.status.rx),
+            health: self.health,
         })
     }
 }
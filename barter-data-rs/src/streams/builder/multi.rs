@@ -1,4 +1,5 @@
 use super::{ExchangeChannel, StreamBuilder, Streams};
+use crate::streams::status::{StreamHealth, StreamStatus};
 use crate::{error::DataError, event::MarketEvent, exchange::ExchangeId, subscription::SubKind};
 use std::{collections::HashMap, fmt::Debug, future::Future, pin::Pin};
 
@@ -12,6 +13,8 @@ pub type BuilderInitFuture = Pin<Box<dyn Future<Output = Result<(), DataError>>>
 pub struct MultiStreamBuilder<Output> {
     pub channels: HashMap<ExchangeId, ExchangeChannel<Output>>,
     pub futures: Vec<BuilderInitFuture>,
+    pub status: ExchangeChannel<StreamStatus>,
+    pub health: StreamHealth,
 }
 
 impl<Output> Debug for MultiStreamBuilder<Output>
@@ -22,6 +25,7 @@ where
         f.debug_struct("MultiStreamBuilder<Output>")
             .field("channels", &self.channels)
             .field("num_futures", &self.futures.len())
+            .field("health", &self.health)
             .finish()
     }
 }
@@ -32,6 +36,8 @@ impl<Output> MultiStreamBuilder<Output> {
         Self {
             channels: HashMap::new(),
             futures: Vec::new(),
+            status: ExchangeChannel::new(),
+            health: StreamHealth::default(),
         }
     }
 
@@ -60,27 +66,41 @@ impl<Output> MultiStreamBuilder<Output> {
             exchange_txs.insert(exchange, exchange_tx);
         }
 
+        // Share the StreamHealth registry of the StreamBuilder
+        self.health.merge(builder.health.clone());
+        let status_tx = self.status.tx.clone();
+
         // Init Streams<Kind::Event> & send mapped Outputs to the associated exchange_tx
         self.futures.push(Box::pin(async move {
-            builder
-                .init()
-                .await?
-                .streams
-                .into_iter()
-                .for_each(|(exchange, mut exchange_rx)| {
-                    // Remove exchange_tx<Output> from HashMap that's associated with this tuple:
-                    // (ExchangeId, exchange_rx<MarketEvent<SubKind::Event>>)
-                    let exchange_tx = exchange_txs
-                        .remove(&exchange)
-                        .expect("all exchange_txs should be present here");
-
-                    // Task to receive MarketEvent<SubKind::Event> and send Outputs via exchange_tx
-                    tokio::spawn(async move {
-                        while let Some(event) = exchange_rx.recv().await {
-                            let _ = exchange_tx.send(Output::from(event));
-                        }
-                    });
+            let Streams {
+                streams,
+                status,
+                health: _,
+            } = builder.init().await?;
+
+            // Task to forward StreamStatus events to the common status_tx
+            if let Some(mut status_rx) = status {
+                tokio::spawn(async move {
+                    while let Some(status) = status_rx.recv().await {
+                        let _ = status_tx.send(status);
+                    }
+                });
+            }
+
+            streams.into_iter().for_each(|(exchange, mut exchange_rx)| {
+                // Remove exchange_tx<Output> from HashMap that's associated with this tuple:
+                // (ExchangeId, exchange_rx<MarketEvent<SubKind::Event>>)
+                let exchange_tx = exchange_txs
+                    .remove(&exchange)
+                    .expect("all exchange_txs should be present here");
+
+                // Task to receive MarketEvent<SubKind::Event> and send Outputs via exchange_tx
+                tokio::spawn(async move {
+                    while let Some(event) = exchange_rx.recv().await {
+                        let _ = exchange_tx.send(Output::from(event));
+                    }
                 });
+            });
 
             Ok(())
         }));
@@ -102,6 +122,8 @@ impl<Output> MultiStreamBuilder<Output> {
                 .into_iter()
                 .map(|(exchange, channel)| (exchange, channel.rx))
                 .collect(),
+            status: Some(self.status.rx),
+            health: self.health,
         })
     }
 }
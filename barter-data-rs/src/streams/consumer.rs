@@ -1,3 +1,4 @@
+use super::status::{StreamMonitor, StreamStatusKind};
 use crate::{
     error::DataError,
     event::MarketEvent,
@@ -24,11 +25,17 @@ pub type StreamTransformer<Exchange, Kind> =
 /// Initialises an exchange [`MarketStream`] using a collection of [`Subscription`]s. Consumed
 /// events are distributed downstream via the `exchange_tx mpsc::UnboundedSender`. A re-connection
 /// mechanism with an exponential backoff policy is utilised to ensure maximum up-time.
+///
+/// Every change in connection health is communicated downstream as a
+/// [`StreamStatus`](super::status::StreamStatus) via the [`StreamMonitor`], which also records
+/// the latency of the last consumed event for each
+/// [`SubscriptionId`](barter_integration::model::SubscriptionId).
 pub async fn consume<Exchange, Kind>(
     subscriptions: Vec<Subscription<Exchange, Kind>>,
     exchange_tx: mpsc::UnboundedSender<MarketEvent<Kind::Event>>,
     transformer: StreamTransformer<Exchange, Kind>,
     backtest_mode: BacktestMode,
+    monitor: StreamMonitor,
 ) -> DataError
 where
     Exchange: StreamSelector<Kind>,
@@ -49,6 +56,9 @@ where
     let mut attempt: u32 = 0;
     let mut backoff_ms: u64 = STARTING_RECONNECT_BACKOFF_MS;
 
+    // Set when the previous MarketStream was re-initialised due to a sequence gap
+    let mut resync = false;
+
     loop {
         // Increment retry parameters at start of every iteration
         attempt += 1;
@@ -66,12 +76,20 @@ where
         {
             Ok(stream) => {
                 info!(%exchange, attempt, "successfully initialised MarketStream");
+                monitor.notify_all(match resync {
+                    true => StreamStatusKind::Resynced,
+                    false => StreamStatusKind::Connected,
+                });
+                resync = false;
                 attempt = 0;
                 backoff_ms = STARTING_RECONNECT_BACKOFF_MS;
                 stream
             }
             Err(error) => {
                 error!(%exchange, attempt, ?error, "failed to initialise MarketStream");
+                monitor.notify_all(StreamStatusKind::SubscriptionFailed {
+                    reason: error.to_string(),
+                });
 
                 // Exit function function if Stream::init failed the first attempt, else retry
                 if attempt == 1 {
@@ -87,6 +105,7 @@ where
             match event_result {
                 // If Ok: send MarketEvent<T> to exchange receiver
                 Ok(market_event) => {
+                    monitor.record(&market_event);
                     let _ = exchange_tx.send(market_event).map_err(|err| {
                         error!(
                             payload = ?err.0,
@@ -97,6 +116,18 @@ where
                 }
                 // If terminal DataError: break
                 Err(error) if error.is_terminal() => {
+                    if let Some((prev_last_update_id, first_update_id)) = error.sequence_gap() {
+                        let kind = StreamStatusKind::GapDetected {
+                            prev_last_update_id,
+                            first_update_id,
+                        };
+                        match error.subscription_id() {
+                            Some(subscription_id) => monitor.notify(subscription_id.clone(), kind),
+                            None => monitor.notify_all(kind),
+                        }
+                        resync = true;
+                    }
+
                     error!(
                         %exchange,
                         %error,
@@ -126,6 +157,10 @@ where
             action = "attempt re-connection after backoff",
             "exchange MarketStream unexpectedly ended"
         );
+        monitor.notify_all(StreamStatusKind::Reconnecting {
+            attempt: attempt + 1,
+            backoff_ms,
+        });
         tokio::time::sleep(Duration::from_millis(backoff_ms)).await;
     }
 }
@@ -1,4 +1,7 @@
-use self::builder::{multi::MultiStreamBuilder, StreamBuilder};
+use self::{
+    builder::{multi::MultiStreamBuilder, StreamBuilder},
+    status::{StreamHealth, StreamStatus},
+};
 use crate::{exchange::ExchangeId, subscription::SubKind};
 use std::collections::HashMap;
 use tokio::sync::mpsc;
@@ -13,10 +16,16 @@ pub mod builder;
 /// to drive a re-connecting [`MarketStream`](super::MarketStream).
 pub mod consumer;
 
+/// [`StreamStatus`](status::StreamStatus) events and the [`StreamHealth`](status::StreamHealth)
+/// registry used to communicate the health of each exchange subscription downstream.
+pub mod status;
+
 /// Ergonomic collection of exchange [`MarketEvent<T>`](crate::event::MarketEvent) receivers.
 #[derive(Debug)]
 pub struct Streams<T> {
     pub streams: HashMap<ExchangeId, mpsc::UnboundedReceiver<T>>,
+    pub status: Option<mpsc::UnboundedReceiver<StreamStatus>>,
+    pub health: StreamHealth,
 }
 
 impl<T> Streams<T> {
@@ -40,6 +49,17 @@ impl<T> Streams<T> {
         self.streams.remove(&exchange)
     }
 
+    /// Remove the [`StreamStatus`] [`mpsc::UnboundedReceiver`] from the [`Streams`].
+    pub fn status(&mut self) -> Option<mpsc::UnboundedReceiver<StreamStatus>> {
+        self.status.take()
+    }
+
+    /// Shared [`StreamHealth`] registry containing the last-message latency and status of every
+    /// exchange subscription.
+    pub fn health(&self) -> StreamHealth {
+        self.health.clone()
+    }
+
     /// Join all exchange [`mpsc::UnboundedReceiver`] streams into a unified
     /// [`mpsc::UnboundedReceiver`].
     pub async fn join(self) -> mpsc::UnboundedReceiver<T>
@@ -0,0 +1,380 @@
+use crate::{event::MarketEvent, exchange::ExchangeId, subscription::Map};
+use barter_integration::{
+    metric::{
+        registry::{CounterHandle, HistogramHandle, MetricRegistry},
+        Tag,
+    },
+    model::{instrument::Instrument, SubscriptionId},
+};
+use chrono::{DateTime, Duration, Utc};
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, sync::Arc};
+use tokio::sync::mpsc;
+
+/// Normalised Barter [`StreamStatus`] event communicating a change in health of the
+/// [`MarketStream`](crate::MarketStream) serving a [`SubscriptionId`].
+///
+/// Consumers (eg/ a strategy quoting off an [`OrderBook`](crate::subscription::book::OrderBook))
+/// can use these to determine if the data they are acting on is stale, or has been rebuilt.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct StreamStatus {
+    pub exchange: ExchangeId,
+    pub subscription_id: SubscriptionId,
+    pub time: DateTime<Utc>,
+    pub kind: StreamStatusKind,
+}
+
+/// Kinds of [`StreamStatus`] events generated by the [`consume`](super::consumer::consume) loop.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub enum StreamStatusKind {
+    /// [`MarketStream`](crate::MarketStream) successfully initialised and subscribed.
+    Connected,
+    /// [`MarketStream`](crate::MarketStream) ended and will attempt to re-initialise after
+    /// `backoff_ms`.
+    Reconnecting { attempt: u32, backoff_ms: u64 },
+    /// [`MarketStream`](crate::MarketStream) re-initialised after a sequence gap, meaning any
+    /// local state (eg/ an `OrderBook`) has been rebuilt from a fresh snapshot.
+    Resynced,
+    /// Sequence gap detected in the exchange update stream, local state is now stale until a
+    /// [`StreamStatusKind::Resynced`] event is received.
+    GapDetected {
+        prev_last_update_id: u64,
+        first_update_id: u64,
+    },
+    /// [`MarketStream`](crate::MarketStream) failed to initialise the exchange subscription.
+    SubscriptionFailed { reason: String },
+}
+
+/// Health of the [`MarketStream`](crate::MarketStream) serving a [`SubscriptionId`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct SubscriptionHealth {
+    /// Most recent [`StreamStatusKind`] generated for the [`SubscriptionId`].
+    pub status: StreamStatusKind,
+    pub last_exchange_time: Option<DateTime<Utc>>,
+    pub last_received_time: Option<DateTime<Utc>>,
+    /// Latency of the last message received, ie/ `received_time - exchange_time`.
+    pub latency: Option<Duration>,
+}
+
+impl SubscriptionHealth {
+    /// Construct a new [`Self`] with the provided [`StreamStatusKind`] and no messages received.
+    pub fn new(status: StreamStatusKind) -> Self {
+        Self {
+            status,
+            last_exchange_time: None,
+            last_received_time: None,
+            latency: None,
+        }
+    }
+
+    /// Determine if the [`SubscriptionId`] has not received a message within the provided
+    /// `max_age` [`Duration`] of `now`.
+    pub fn is_stale(&self, now: DateTime<Utc>, max_age: Duration) -> bool {
+        match self.last_received_time {
+            Some(last_received_time) => now - last_received_time > max_age,
+            None => true,
+        }
+    }
+}
+
+/// Communicative type alias for the shared `HashMap` of each [`SubscriptionHealth`].
+pub type HealthMap = Arc<RwLock<HashMap<(ExchangeId, SubscriptionId), SubscriptionHealth>>>;
+
+/// Shared, cheaply cloneable registry of the [`SubscriptionHealth`] of every [`SubscriptionId`]
+/// served by a [`Streams`](super::Streams) instance.
+#[derive(Clone, Debug, Default)]
+pub struct StreamHealth {
+    maps: Vec<HealthMap>,
+}
+
+impl StreamHealth {
+    /// Construct a new [`Self`] containing a single empty [`HealthMap`].
+    pub fn new() -> Self {
+        Self {
+            maps: vec![HealthMap::default()],
+        }
+    }
+
+    /// Merge the [`HealthMap`]s of another [`StreamHealth`] into [`Self`].
+    pub fn merge(&mut self, other: StreamHealth) {
+        self.maps.extend(other.maps);
+    }
+
+    /// Return the [`SubscriptionHealth`] associated with the provided [`ExchangeId`] and
+    /// [`SubscriptionId`], if any.
+    pub fn get(
+        &self,
+        exchange: ExchangeId,
+        subscription_id: &SubscriptionId,
+    ) -> Option<SubscriptionHealth> {
+        let key = (exchange, subscription_id.clone());
+        self.maps
+            .iter()
+            .find_map(|map| map.read().get(&key).cloned())
+    }
+
+    /// Return a snapshot of every [`SubscriptionHealth`] in the registry.
+    pub fn snapshot(&self) -> HashMap<(ExchangeId, SubscriptionId), SubscriptionHealth> {
+        self.maps
+            .iter()
+            .flat_map(|map| {
+                map.read()
+                    .iter()
+                    .map(|(key, health)| (key.clone(), health.clone()))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Shared [`HashMap`] that new [`StreamMonitor`]s write to.
+    fn primary(&self) -> HealthMap {
+        self.maps.first().cloned().unwrap_or_default()
+    }
+}
+
+/// Used by the [`consume`](super::consumer::consume) loop to distribute [`StreamStatus`] events
+/// and record the [`SubscriptionHealth`] of every [`SubscriptionId`] on a connection.
+///
+/// The latency & count of consumed messages are also aggregated per [`SubscriptionId`] in a
+/// [`MetricRegistry`] as the "ws_message_latency" histogram (milliseconds) and "ws_messages"
+/// counter. Series handles are created once per [`SubscriptionId`], so recording a message
+/// never allocates or locks the [`MetricRegistry`].
+#[derive(Clone, Debug)]
+pub struct StreamMonitor {
+    pub exchange: ExchangeId,
+    instruments: HashMap<Instrument, SubscriptionMetrics>,
+    status_tx: mpsc::UnboundedSender<StreamStatus>,
+    health: HealthMap,
+}
+
+/// [`MetricRegistry`] series handles of a single [`SubscriptionId`].
+#[derive(Clone, Debug)]
+struct SubscriptionMetrics {
+    subscription_id: SubscriptionId,
+    latency: HistogramHandle,
+    messages: CounterHandle,
+}
+
+impl SubscriptionMetrics {
+    fn new(
+        exchange: ExchangeId,
+        subscription_id: SubscriptionId,
+        metrics: &MetricRegistry,
+    ) -> Self {
+        let tags = vec![
+            Tag::new("exchange", exchange.as_str()),
+            Tag::new("subscription_id", subscription_id.0.as_str()),
+        ];
+
+        Self {
+            latency: metrics.histogram("ws_message_latency", tags.clone()),
+            messages: metrics.counter("ws_messages", tags),
+            subscription_id,
+        }
+    }
+}
+
+impl StreamMonitor {
+    /// Construct a new [`Self`] for the provided [`SubscriptionId`] to [`Instrument`] [`Map`].
+    pub fn new(
+        exchange: ExchangeId,
+        instrument_map: &Map<Instrument>,
+        status_tx: mpsc::UnboundedSender<StreamStatus>,
+        health: &StreamHealth,
+    ) -> Self {
+        let metrics = MetricRegistry::global();
+
+        Self {
+            exchange,
+            instruments: instrument_map
+                .0
+                .iter()
+                .map(|(subscription_id, instrument)| {
+                    (
+                        instrument.clone(),
+                        SubscriptionMetrics::new(exchange, subscription_id.clone(), &metrics),
+                    )
+                })
+                .collect(),
+            status_tx,
+            health: health.primary(),
+        }
+    }
+
+    /// Aggregate message latency metrics in the provided [`MetricRegistry`], rather than the
+    /// [`MetricRegistry::global`] registry.
+    pub fn with_metrics(self, metrics: MetricRegistry) -> Self {
+        let exchange = self.exchange;
+        Self {
+            instruments: self
+                .instruments
+                .into_iter()
+                .map(|(instrument, subscription)| {
+                    (
+                        instrument,
+                        SubscriptionMetrics::new(exchange, subscription.subscription_id, &metrics),
+                    )
+                })
+                .collect(),
+            ..self
+        }
+    }
+
+    /// Send a [`StreamStatus`] for every [`SubscriptionId`] served by this connection.
+    pub fn notify_all(&self, kind: StreamStatusKind) {
+        for subscription in self.instruments.values() {
+            self.notify(subscription.subscription_id.clone(), kind.clone());
+        }
+    }
+
+    /// Send a [`StreamStatus`] for the provided [`SubscriptionId`] and update its
+    /// [`SubscriptionHealth`].
+    pub fn notify(&self, subscription_id: SubscriptionId, kind: StreamStatusKind) {
+        self.health
+            .write()
+            .entry((self.exchange, subscription_id.clone()))
+            .and_modify(|health| health.status = kind.clone())
+            .or_insert_with(|| SubscriptionHealth::new(kind.clone()));
+
+        let status = StreamStatus {
+            exchange: self.exchange,
+            subscription_id,
+            time: Utc::now(),
+            kind,
+        };
+
+        // Consuming StreamStatus events is optional, so a dropped receiver is not an error
+        let _ = self.status_tx.send(status);
+    }
+
+    /// Record the latency of a consumed [`MarketEvent<T>`](MarketEvent) against the
+    /// [`SubscriptionId`] of its [`Instrument`].
+    pub fn record<T>(&self, event: &MarketEvent<T>) {
+        let Some(subscription) = self.instruments.get(&event.instrument) else {
+            return;
+        };
+
+        let mut map = self.health.write();
+        let health = map
+            .entry((self.exchange, subscription.subscription_id.clone()))
+            .or_insert_with(|| SubscriptionHealth::new(StreamStatusKind::Connected));
+
+        let latency = event.received_time - event.exchange_time;
+        health.last_exchange_time = Some(event.exchange_time);
+        health.last_received_time = Some(event.received_time);
+        health.latency = Some(latency);
+        drop(map);
+
+        subscription
+            .latency
+            .observe(latency.num_microseconds().unwrap_or(i64::MAX) as f64 / 1_000.0);
+        subscription.messages.increment(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::{
+        metric::registry::SeriesKey,
+        model::{instrument::kind::InstrumentKind, Exchange},
+    };
+    use chrono::TimeZone;
+
+    fn monitor(
+        health: &StreamHealth,
+    ) -> (
+        StreamMonitor,
+        mpsc::UnboundedReceiver<StreamStatus>,
+        Instrument,
+    ) {
+        let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Spot));
+        let map = Map(HashMap::from([(
+            SubscriptionId::from("@depth@100ms|BTCUSDT"),
+            instrument.clone(),
+        )]));
+        let (status_tx, status_rx) = mpsc::unbounded_channel();
+        let monitor = StreamMonitor::new(ExchangeId::BinanceSpot, &map, status_tx, health);
+        (monitor, status_rx, instrument)
+    }
+
+    #[test]
+    fn test_stream_monitor_notify_all() {
+        let health = StreamHealth::new();
+        let (monitor, mut status_rx, _) = monitor(&health);
+
+        monitor.notify_all(StreamStatusKind::Reconnecting {
+            attempt: 1,
+            backoff_ms: 250,
+        });
+
+        let status = status_rx.try_recv().unwrap();
+        assert_eq!(status.exchange, ExchangeId::BinanceSpot);
+        assert_eq!(
+            status.subscription_id,
+            SubscriptionId::from("@depth@100ms|BTCUSDT")
+        );
+        assert_eq!(
+            status.kind,
+            StreamStatusKind::Reconnecting {
+                attempt: 1,
+                backoff_ms: 250
+            }
+        );
+        assert!(status_rx.try_recv().is_err());
+
+        let actual = health
+            .get(
+                ExchangeId::BinanceSpot,
+                &SubscriptionId::from("@depth@100ms|BTCUSDT"),
+            )
+            .unwrap();
+        assert_eq!(actual.status, status.kind);
+    }
+
+    #[test]
+    fn test_stream_monitor_record_latency() {
+        let health = StreamHealth::new();
+        let metrics = MetricRegistry::new();
+        let (monitor, _status_rx, instrument) = monitor(&health);
+        let monitor = monitor.with_metrics(metrics.clone());
+
+        let exchange_time = Utc.timestamp_opt(1_000, 0).unwrap();
+        let received_time = exchange_time + Duration::milliseconds(15);
+
+        monitor.record(&MarketEvent {
+            exchange_time,
+            received_time,
+            exchange: Exchange::from(ExchangeId::BinanceSpot),
+            instrument,
+            kind: (),
+        });
+
+        let actual = health
+            .get(
+                ExchangeId::BinanceSpot,
+                &SubscriptionId::from("@depth@100ms|BTCUSDT"),
+            )
+            .unwrap();
+
+        assert_eq!(actual.latency, Some(Duration::milliseconds(15)));
+        assert_eq!(actual.last_received_time, Some(received_time));
+        assert!(!actual.is_stale(received_time, Duration::seconds(1)));
+        assert!(actual.is_stale(received_time + Duration::seconds(2), Duration::seconds(1)));
+
+        let snapshot = metrics.snapshot();
+        let key = SeriesKey::new(
+            "ws_message_latency",
+            vec![
+                Tag::new("exchange", "binance_spot"),
+                Tag::new("subscription_id", "@depth@100ms|BTCUSDT"),
+            ],
+        );
+        assert_eq!(snapshot.histograms[&key].sum(), 15.0);
+        assert_eq!(
+            snapshot.counters[&SeriesKey::new("ws_messages", key.tags.clone())],
+            1
+        );
+    }
+}
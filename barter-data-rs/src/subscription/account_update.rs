@@ -1,5 +1,6 @@
 use barter_integration::model::{instrument::symbol::Symbol, PerpSide};
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 
 // Account update type
 #[derive(Debug)]
@@ -13,18 +14,18 @@ pub struct AccountUpdate {
 #[derive(Debug)]
 pub struct BalanceUpdate {
     pub asset: Symbol,
-    pub wallet_balance: f64,
-    pub cross_wallet_balance: f64,
-    pub balance_change: f64,
+    pub wallet_balance: Decimal,
+    pub cross_wallet_balance: Decimal,
+    pub balance_change: Decimal,
 }
 
 // Position update type
 #[derive(Debug)]
 pub struct PositionUpdate {
     pub symbol: Symbol,
-    pub position_amount: f64,
+    pub position_amount: Decimal,
     pub position_side: PerpSide,
-    pub unrealized_pnl: f64,
-    pub entry_price: f64,
-    pub breakeven_price: f64,
+    pub unrealized_pnl: Decimal,
+    pub entry_price: Decimal,
+    pub breakeven_price: Decimal,
 }
@@ -6,9 +6,9 @@ use crate::{
 use barter_integration::model::{instrument::Instrument, Exchange, Side};
 use barter_macro::{DeSubKind, SerSubKind};
 use chrono::{DateTime, Utc};
-use parking_lot::Mutex;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use std::{cmp::Ordering, sync::Arc};
+use std::{cmp::Ordering, ops::Deref, sync::Arc};
 use tracing::debug;
 
 /// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields level 1 [`OrderBook`]
@@ -35,7 +35,7 @@ impl OrderBookL1 {
     /// Calculate the mid price by taking the average of the best bid and ask prices.
     ///
     /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
-    pub fn mid_price(&self) -> f64 {
+    pub fn mid_price(&self) -> Decimal {
         mid_price(self.best_bid.price, self.best_ask.price)
     }
 
@@ -43,7 +43,7 @@ impl OrderBookL1 {
     /// with their associated amount.
     ///
     /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
-    pub fn volume_weighed_mid_price(&self) -> f64 {
+    pub fn volume_weighed_mid_price(&self) -> Decimal {
         volume_weighted_mid_price(self.best_bid, self.best_ask)
     }
 }
@@ -79,9 +79,15 @@ pub struct InnerOrderBook {
     pub asks: OrderBookSide,
 }
 
-#[derive(Clone, Debug)]
+/// Normalised Barter [`OrderBook`] published to consumers.
+///
+/// The [`InnerOrderBook`] is shared via an [`Arc`] without a lock. Publishing a snapshot is a
+/// cheap [`Arc`] clone, and an [`OrderBookUpdater`](crate::transformer::book::OrderBookUpdater)
+/// applies updates via [`OrderBook::make_mut`], which only copies the [`InnerOrderBook`] if a
+/// previously published snapshot is still held by a consumer (copy-on-publish).
+#[derive(Clone, PartialEq, Debug)]
 pub struct OrderBook {
-    pub book: Arc<Mutex<InnerOrderBook>>,
+    pub book: Arc<InnerOrderBook>,
 }
 
 impl Serialize for OrderBook {
@@ -89,8 +95,7 @@ impl Serialize for OrderBook {
     where
         S: Serializer,
     {
-        // Lock the mutex and serialize the inner data
-        self.book.lock().serialize(serializer)
+        self.book.serialize(serializer)
     }
 }
 
@@ -99,41 +104,39 @@ impl<'de> Deserialize<'de> for OrderBook {
     where
         D: Deserializer<'de>,
     {
-        // Deserialize the data and put it in a Mutex
         let book = InnerOrderBook::deserialize(deserializer)?;
         Ok(OrderBook::from(book))
     }
 }
 
-impl PartialEq for OrderBook {
-    fn eq(&self, other: &Self) -> bool {
-        let book = self.book.lock();
-        let other_book = other.book.lock();
-        book.eq(&*other_book)
+impl Deref for OrderBook {
+    type Target = InnerOrderBook;
+
+    fn deref(&self) -> &Self::Target {
+        &self.book
     }
 }
 
 impl OrderBook {
-    /// Generate an [`OrderBook`] snapshot by cloning [`Self`] after sorting each [`OrderBookSide`].
-    /// Note: Sorting is not required here because of the way insertion into the orderbook works
-    /// however, this only works if we sart off with a sorted orderbook to begin with
-    /// we can optionally sort here as well if speed is not an issue.
-    pub fn snapshot(&mut self) -> Self {
-        // Sort OrderBook & Clone
-        // let mut book = self.book.lock();
-        // let prev_book = book.clone();
-        // book.bids.sort();
-        // book.asks.sort();
-        // assert!(book.eq(&prev_book), "OrderBook sort failed");
+    /// Generate an immutable [`OrderBook`] snapshot to publish downstream.
+    ///
+    /// This is an [`Arc`] clone, so no [`Level`]s are copied until the next update is applied
+    /// whilst the snapshot is still held.
+    pub fn snapshot(&self) -> Self {
         self.clone()
     }
 
+    /// Mutable access to the [`InnerOrderBook`], copying it first only if a published snapshot
+    /// is still held elsewhere.
+    pub fn make_mut(&mut self) -> &mut InnerOrderBook {
+        Arc::make_mut(&mut self.book)
+    }
+
     /// Calculate the mid price by taking the average of the best bid and ask prices.
     ///
     /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
-    pub fn mid_price(&self) -> Option<f64> {
-        let book = self.book.lock();
-        match (book.bids.levels.first(), book.asks.levels.first()) {
+    pub fn mid_price(&self) -> Option<Decimal> {
+        match (self.bids.best(), self.asks.best()) {
             (Some(best_bid), Some(best_ask)) => Some(mid_price(best_bid.price, best_ask.price)),
             (Some(best_bid), None) => Some(best_bid.price),
             (None, Some(best_ask)) => Some(best_ask.price),
@@ -141,29 +144,20 @@ impl OrderBook {
         }
     }
 
-    pub fn best_bid(&self) -> Option<f64> {
-        let book = self.book.lock();
-        match book.bids.levels.first() {
-            Some(best_bid) => Some(best_bid.price),
-            None => None,
-        }
+    pub fn best_bid(&self) -> Option<Decimal> {
+        self.bids.best().map(|best_bid| best_bid.price)
     }
 
-    pub fn best_ask(&self) -> Option<f64> {
-        let book = self.book.lock();
-        match book.asks.levels.first() {
-            Some(best_ask) => Some(best_ask.price),
-            None => None,
-        }
+    pub fn best_ask(&self) -> Option<Decimal> {
+        self.asks.best().map(|best_ask| best_ask.price)
     }
 
     /// Calculate the volume weighted mid price (micro-price), weighing the best bid and ask prices
     /// with their associated amount.
     ///
     /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
-    pub fn volume_weighed_mid_price(&self) -> Option<f64> {
-        let book = self.book.lock();
-        match (book.bids.levels.first(), book.asks.levels.first()) {
+    pub fn volume_weighed_mid_price(&self) -> Option<Decimal> {
+        match (self.bids.best(), self.asks.best()) {
             (Some(best_bid), Some(best_ask)) => {
                 Some(volume_weighted_mid_price(*best_bid, *best_ask))
             }
@@ -175,23 +169,82 @@ impl OrderBook {
 }
 
 /// Normalised Barter [`Level`]s for one [`Side`] of the [`OrderBook`].
+///
+/// [`Level`]s are stored as a sorted price ladder from the worst price to the best price, so
+/// the best [`Level`] is at the end of the `Vec`. This means:
+/// - Locating a price [`Level`] is an O(log n) binary search.
+/// - Inserting or removing [`Level`]s near the top of the book (the vast majority of updates)
+///   only shifts the few [`Level`]s above it.
+///
+/// Serialised representations list the [`Level`]s from best to worst.
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize)]
+#[serde(from = "OrderBookSideRepr", into = "OrderBookSideRepr")]
 pub struct OrderBookSide {
     side: Side,
     levels: Vec<Level>,
 }
 
+/// Serialisable representation of an [`OrderBookSide`] with [`Level`]s ordered best to worst.
+#[derive(Deserialize, Serialize)]
+struct OrderBookSideRepr {
+    side: Side,
+    levels: Vec<Level>,
+}
+
+impl From<OrderBookSideRepr> for OrderBookSide {
+    fn from(repr: OrderBookSideRepr) -> Self {
+        Self::new(repr.side, repr.levels)
+    }
+}
+
+impl From<OrderBookSide> for OrderBookSideRepr {
+    fn from(mut side: OrderBookSide) -> Self {
+        side.levels.reverse();
+        Self {
+            side: side.side,
+            levels: side.levels,
+        }
+    }
+}
+
 impl OrderBookSide {
-    /// Construct a new [`Self`] with the [`Level`]s provided.
+    /// Construct a new sorted [`Self`] with the [`Level`]s provided.
     pub fn new<Iter, L>(side: Side, levels: Iter) -> Self
     where
         Iter: IntoIterator<Item = L>,
         L: Into<Level>,
     {
-        Self {
+        let mut book_side = Self {
             side,
             levels: levels.into_iter().map(L::into).collect(),
-        }
+        };
+        book_side.sort();
+        book_side
+    }
+
+    /// [`Side`] of the [`OrderBook`] these [`Level`]s belong to.
+    pub fn side(&self) -> Side {
+        self.side
+    }
+
+    /// Best [`Level`] of this [`OrderBookSide`], if any.
+    pub fn best(&self) -> Option<&Level> {
+        self.levels.last()
+    }
+
+    /// Iterate over the [`Level`]s of this [`OrderBookSide`], from best to worst price.
+    pub fn levels(&self) -> impl DoubleEndedIterator<Item = &Level> + ExactSizeIterator {
+        self.levels.iter().rev()
+    }
+
+    /// Number of [`Level`]s in this [`OrderBookSide`].
+    pub fn len(&self) -> usize {
+        self.levels.len()
+    }
+
+    /// Determine if this [`OrderBookSide`] contains no [`Level`]s.
+    pub fn is_empty(&self) -> bool {
+        self.levels.is_empty()
     }
 
     /// Upsert a collection of [`Level`]s into this [`OrderBookSide`].
@@ -220,44 +273,22 @@ impl OrderBookSide {
         L: Into<Level>,
     {
         let new_level = new_level.into();
-        let len = self.levels.len();
-
-        let mut placehodler = Level::new(0, 0);
-        let index: usize;
-        let level: &mut Level;
-        if self.side == Side::Buy {
-            (index, level) = self
-                .levels
-                .iter_mut()
-                .enumerate()
-                .find(|(_index, level)| {
-                    level.price < new_level.price || level.eq_price(new_level.price)
-                })
-                .unwrap_or((len, &mut placehodler));
-        } else {
-            (index, level) = self
-                .levels
-                .iter_mut()
-                .enumerate()
-                .find(|(_index, level)| {
-                    level.price > new_level.price || level.eq_price(new_level.price)
-                })
-                .unwrap_or((len, &mut placehodler));
-        }
 
-        match (level.eq_price(new_level.price), new_level.amount == 0.0) {
+        match (self.find(new_level.price), new_level.amount.is_zero()) {
             // Scenario 1a: Level exists & new value is 0 => remove Level
-            (true, true) => {
+            (Ok(index), true) => {
                 self.levels.remove(index);
             }
             // Scenario 1b: Level exists & new value is > 0 => replace Level
-            (true, false) => {
-                *level = new_level;
+            (Ok(index), false) => {
+                self.levels[index] = new_level;
             }
-            (false, false) => {
+            // Scenario 2a: Level does not exist & new value > 0 => insert new Level
+            (Err(index), false) => {
                 self.levels.insert(index, new_level);
             }
-            (false, true) => {
+            // Scenario 2b: Level does not exist & new value is 0 => log error & continue
+            (Err(_), true) => {
                 debug!(
                     ?new_level,
                     side = %self.side,
@@ -265,96 +296,72 @@ impl OrderBookSide {
                 );
             }
         }
+    }
 
-        // if level.eq_price(new_level.price) && new_level.amount == 0.0 {
-        //     self.levels.remove(index);
-        // }
-        // // Scenario 1b: Level exists & new value is > 0 => replace Level
-        // else if level.eq_price(new_level.price) {
-        //     *level = new_level;
-        // }
-        // // Scenario 2a: Level does not exist & new value > 0 => insert new Level
-        // else if new_level.amount != 0.0 {
-        //     self.levels.insert(index, new_level);
-        // } else {
-        //     debug!(
-        //         ?new_level,
-        //         side = %self.side,
-        //         "Level to remove not found",
-        //     );
-        // }
-
-        // None => {
-        //     // println!("sdie: {:?}, res: {:#?}", self.side, res);
-
-        //     println!("level: {:?} levels: {:?}", new_level, self.levels,);
-        //     debug!(
-        //         ?new_level,
-        //         side = %self.side,
-        //         "Level not found",
-        //     );
-        // }
-
-        // // Scenario 2b: Level does not exist & new value is 0 => log error & continue
-        // _ => {
-        //     debug!(
-        //         ?new_level,
-        //         side = %self.side,
-        //         "Level to remove not found",
-        //     );
-        // }
+    /// Search the price ladder for the provided price. Returns the index of the matching
+    /// [`Level`], or the index a [`Level`] with this price should be inserted at.
+    ///
+    /// Gallops down from the best price before binary searching, since the vast majority of
+    /// updates are near the top of the book. This is O(log k), where k is the distance from the
+    /// best price.
+    fn find(&self, price: Decimal) -> Result<usize, usize> {
+        // Compare a Level's position in the ladder (worst to best) to the provided price
+        let compare = |level: &Level| match self.side {
+            Side::Buy => level.price.cmp(&price),
+            Side::Sell => price.cmp(&level.price),
+        };
+
+        // Find the range of the ladder containing the price, doubling the window each step
+        let len = self.levels.len();
+        let mut bound = 1;
+        while bound <= len && compare(&self.levels[len - bound]) == Ordering::Greater {
+            bound *= 2;
+        }
+        let start = len.saturating_sub(bound);
+        let end = len - bound / 2;
+
+        self.levels[start..end]
+            .binary_search_by(compare)
+            .map(|index| start + index)
+            .map_err(|index| start + index)
     }
 
-    /// Sort this [`OrderBookSide`] (bids are reversed).
+    /// Sort this [`OrderBookSide`] into a price ladder from worst to best price.
     pub fn sort(&mut self) {
         // Sort Levels
         self.levels.sort_unstable();
 
-        // Reverse Bids
-        if let Side::Buy = self.side {
+        // Reverse Asks
+        if let Side::Sell = self.side {
             self.levels.reverse();
         }
     }
 }
 
 /// Normalised Barter OrderBook [`Level`].
-#[derive(Clone, Copy, PartialEq, Debug, Default, Deserialize, Serialize)]
+///
+/// Levels are ordered by price, then amount.
+#[derive(
+    Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
 pub struct Level {
-    pub price: f64,
-    pub amount: f64,
+    pub price: Decimal,
+    pub amount: Decimal,
 }
 
 impl<T> From<(T, T)> for Level
 where
-    T: Into<f64>,
+    T: Into<Decimal>,
 {
     fn from((price, amount): (T, T)) -> Self {
         Self::new(price, amount)
     }
 }
 
-impl Ord for Level {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other)
-            .unwrap_or_else(|| panic!("{:?}.partial_cmp({:?}) impossible", self, other))
-    }
-}
-
-impl PartialOrd for Level {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        match self.price.partial_cmp(&other.price)? {
-            Ordering::Equal => self.amount.partial_cmp(&other.amount),
-            non_equal => Some(non_equal),
-        }
-    }
-}
-
-impl Eq for Level {}
-
 impl Level {
     pub fn new<T>(price: T, amount: T) -> Self
     where
-        T: Into<f64>,
+        T: Into<Decimal>,
     {
         Self {
             price: price.into(),
@@ -362,9 +369,8 @@ impl Level {
         }
     }
 
-    pub fn eq_price(&self, price: f64) -> bool {
-        let diff = (price - self.price).abs();
-        f64::EPSILON > diff
+    pub fn eq_price(&self, price: Decimal) -> bool {
+        self.price == price
     }
 }
 
@@ -373,26 +379,29 @@ impl Level {
 /// Calculate the mid price by taking the average of the best bid and ask prices.
 ///
 /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
-pub fn mid_price(best_bid_price: f64, best_ask_price: f64) -> f64 {
-    (best_bid_price + best_ask_price) / 2.0
+pub fn mid_price(best_bid_price: Decimal, best_ask_price: Decimal) -> Decimal {
+    (best_bid_price + best_ask_price) / Decimal::TWO
 }
 
 /// Calculate the volume weighted mid price (micro-price), weighing the best bid and ask prices
 /// with their associated amount.
 ///
 /// See Docs: <https://www.quantstart.com/articles/high-frequency-trading-ii-limit-order-book>
-pub fn volume_weighted_mid_price(best_bid: Level, best_ask: Level) -> f64 {
-    ((best_bid.price * best_ask.amount) + (best_ask.price * best_bid.amount))
-        / (best_bid.amount + best_ask.amount)
+///
+/// Falls back to the [`mid_price`] if both [`Level`]s have zero amount.
+pub fn volume_weighted_mid_price(best_bid: Level, best_ask: Level) -> Decimal {
+    let total_amount = best_bid.amount + best_ask.amount;
+    if total_amount.is_zero() {
+        return mid_price(best_bid.price, best_ask.price);
+    }
+
+    ((best_bid.price * best_ask.amount) + (best_ask.price * best_bid.amount)) / total_amount
 }
 
 impl From<(ExchangeId, Instrument, OrderBook)> for MarketIter<OrderBook> {
     fn from((exchange_id, instrument, book): (ExchangeId, Instrument, OrderBook)) -> Self {
-        let lock = book.book.lock();
-        let exchange_time = lock.last_update_time;
-        drop(lock);
         Self(vec![Ok(MarketEvent {
-            exchange_time,
+            exchange_time: book.last_update_time,
             received_time: Utc::now(),
             exchange: Exchange::from(exchange_id),
             instrument,
@@ -406,7 +415,7 @@ impl From<InnerOrderBook> for OrderBook {
         book.asks.sort();
         book.bids.sort();
         Self {
-            book: Arc::new(Mutex::new(book)),
+            book: Arc::new(book),
         }
     }
 }
@@ -414,6 +423,7 @@ impl From<InnerOrderBook> for OrderBook {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal_macros::dec;
 
     mod order_book_l1 {
         use super::*;
@@ -422,7 +432,7 @@ mod tests {
         fn test_mid_price() {
             struct TestCase {
                 input: OrderBookL1,
-                expected: f64,
+                expected: Decimal,
             }
 
             let tests = vec![
@@ -433,7 +443,7 @@ mod tests {
                         best_bid: Level::new(100, 999999),
                         best_ask: Level::new(200, 1),
                     },
-                    expected: 150.0,
+                    expected: dec!(150.0),
                 },
                 TestCase {
                     // TC1
@@ -442,7 +452,7 @@ mod tests {
                         best_bid: Level::new(50, 1),
                         best_ask: Level::new(250, 999999),
                     },
-                    expected: 150.0,
+                    expected: dec!(150.0),
                 },
                 TestCase {
                     // TC2
@@ -451,7 +461,7 @@ mod tests {
                         best_bid: Level::new(10, 999999),
                         best_ask: Level::new(250, 999999),
                     },
-                    expected: 130.0,
+                    expected: dec!(130.0),
                 },
             ];
 
@@ -464,7 +474,7 @@ mod tests {
         fn test_volume_weighted_mid_price() {
             struct TestCase {
                 input: OrderBookL1,
-                expected: f64,
+                expected: Decimal,
             }
 
             let tests = vec![
@@ -475,7 +485,7 @@ mod tests {
                         best_bid: Level::new(100, 100),
                         best_ask: Level::new(200, 100),
                     },
-                    expected: 150.0,
+                    expected: dec!(150.0),
                 },
                 TestCase {
                     // TC1: volume affects mid-price
@@ -484,7 +494,7 @@ mod tests {
                         best_bid: Level::new(100, 600),
                         best_ask: Level::new(200, 1000),
                     },
-                    expected: 137.5,
+                    expected: dec!(137.5),
                 },
                 TestCase {
                     // TC2: volume the same and price the same
@@ -493,7 +503,7 @@ mod tests {
                         best_bid: Level::new(1000, 999999),
                         best_ask: Level::new(1000, 999999),
                     },
-                    expected: 1000.0,
+                    expected: dec!(1000.0),
                 },
             ];
 
@@ -510,11 +520,34 @@ mod tests {
     mod order_book {
         use super::*;
 
+        #[test]
+        fn test_snapshot_copy_on_publish() {
+            let mut book = OrderBook::from(InnerOrderBook {
+                last_update_time: Default::default(),
+                bids: OrderBookSide::new(Side::Buy, vec![Level::new(100, 1)]),
+                asks: OrderBookSide::new(Side::Sell, vec![Level::new(110, 1)]),
+            });
+
+            // Published snapshot is unaffected by subsequent updates
+            let snapshot = book.snapshot();
+            book.make_mut().bids.upsert_single(Level::new(105, 1));
+
+            assert_eq!(snapshot.best_bid(), Some(dec!(100.0)));
+            assert_eq!(book.best_bid(), Some(dec!(105.0)));
+
+            // No outstanding snapshots means updates are applied in place
+            drop(snapshot);
+            let before = Arc::as_ptr(&book.book);
+            book.make_mut().asks.upsert_single(Level::new(110, 0));
+            assert_eq!(Arc::as_ptr(&book.book), before);
+            assert_eq!(book.best_ask(), None);
+        }
+
         #[test]
         fn test_mid_price() {
             struct TestCase {
                 input: OrderBook,
-                expected: Option<f64>,
+                expected: Option<Decimal>,
             }
 
             let tests = vec![
@@ -539,14 +572,17 @@ mod tests {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
                             side: Side::Buy,
-                            levels: vec![Level::new(100.0, 100.0), Level::new(50.0, 100.0)],
+                            levels: vec![
+                                Level::new(dec!(100.0), dec!(100.0)),
+                                Level::new(dec!(50.0), dec!(100.0)),
+                            ],
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
                             levels: vec![],
                         },
                     }),
-                    expected: Some(100.0),
+                    expected: Some(dec!(100.0)),
                 },
                 TestCase {
                     // TC2: no bids in the book so take ask price
@@ -558,10 +594,13 @@ mod tests {
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
-                            levels: vec![Level::new(50.0, 100.0), Level::new(100.0, 100.0)],
+                            levels: vec![
+                                Level::new(dec!(50.0), dec!(100.0)),
+                                Level::new(dec!(100.0), dec!(100.0)),
+                            ],
                         },
                     }),
-                    expected: Some(50.0),
+                    expected: Some(dec!(50.0)),
                 },
                 TestCase {
                     // TC3: best bid and ask amount is the same, so regular mid-price
@@ -569,14 +608,20 @@ mod tests {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
                             side: Side::Buy,
-                            levels: vec![Level::new(100.0, 100.0), Level::new(50.0, 100.0)],
+                            levels: vec![
+                                Level::new(dec!(100.0), dec!(100.0)),
+                                Level::new(dec!(50.0), dec!(100.0)),
+                            ],
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
-                            levels: vec![Level::new(200.0, 100.0), Level::new(300.0, 100.0)],
+                            levels: vec![
+                                Level::new(dec!(200.0), dec!(100.0)),
+                                Level::new(dec!(300.0), dec!(100.0)),
+                            ],
                         },
                     }),
-                    expected: Some(150.0),
+                    expected: Some(dec!(150.0)),
                 },
             ];
 
@@ -589,7 +634,7 @@ mod tests {
         fn test_volume_weighted_mid_price() {
             struct TestCase {
                 input: OrderBook,
-                expected: Option<f64>,
+                expected: Option<Decimal>,
             }
 
             let tests = vec![
@@ -614,14 +659,17 @@ mod tests {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
                             side: Side::Buy,
-                            levels: vec![Level::new(100.0, 100.0), Level::new(50.0, 100.0)],
+                            levels: vec![
+                                Level::new(dec!(100.0), dec!(100.0)),
+                                Level::new(dec!(50.0), dec!(100.0)),
+                            ],
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
                             levels: vec![],
                         },
                     }),
-                    expected: Some(100.0),
+                    expected: Some(dec!(100.0)),
                 },
                 TestCase {
                     // TC2: no bids in the book so take ask price
@@ -633,10 +681,13 @@ mod tests {
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
-                            levels: vec![Level::new(50.0, 100.0), Level::new(100.0, 100.0)],
+                            levels: vec![
+                                Level::new(dec!(50.0), dec!(100.0)),
+                                Level::new(dec!(100.0), dec!(100.0)),
+                            ],
                         },
                     }),
-                    expected: Some(50.0),
+                    expected: Some(dec!(50.0)),
                 },
                 TestCase {
                     // TC3: best bid and ask amount is the same, so regular mid-price
@@ -644,14 +695,20 @@ mod tests {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
                             side: Side::Buy,
-                            levels: vec![Level::new(100.0, 100.0), Level::new(50.0, 100.0)],
+                            levels: vec![
+                                Level::new(dec!(100.0), dec!(100.0)),
+                                Level::new(dec!(50.0), dec!(100.0)),
+                            ],
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
-                            levels: vec![Level::new(200.0, 100.0), Level::new(300.0, 100.0)],
+                            levels: vec![
+                                Level::new(dec!(200.0), dec!(100.0)),
+                                Level::new(dec!(300.0), dec!(100.0)),
+                            ],
                         },
                     }),
-                    expected: Some(150.0),
+                    expected: Some(dec!(150.0)),
                 },
                 TestCase {
                     // TC4: valid volume weighted mid-price
@@ -659,14 +716,20 @@ mod tests {
                         last_update_time: Default::default(),
                         bids: OrderBookSide {
                             side: Side::Buy,
-                            levels: vec![Level::new(100.0, 3000.0), Level::new(50.0, 100.0)],
+                            levels: vec![
+                                Level::new(dec!(100.0), dec!(3000.0)),
+                                Level::new(dec!(50.0), dec!(100.0)),
+                            ],
                         },
                         asks: OrderBookSide {
                             side: Side::Sell,
-                            levels: vec![Level::new(200.0, 1000.0), Level::new(300.0, 100.0)],
+                            levels: vec![
+                                Level::new(dec!(200.0), dec!(1000.0)),
+                                Level::new(dec!(300.0), dec!(100.0)),
+                            ],
                         },
                     }),
-                    expected: Some(175.0),
+                    expected: Some(dec!(175.0)),
                 },
             ];
 
@@ -683,6 +746,73 @@ mod tests {
     mod order_book_side {
         use super::*;
 
+        #[test]
+        fn test_levels_best_to_worst() {
+            let bids = OrderBookSide::new(
+                Side::Buy,
+                vec![Level::new(90, 1), Level::new(100, 1), Level::new(80, 1)],
+            );
+            let asks = OrderBookSide::new(
+                Side::Sell,
+                vec![Level::new(120, 1), Level::new(110, 1), Level::new(130, 1)],
+            );
+
+            assert_eq!(bids.best(), Some(&Level::new(100, 1)));
+            assert_eq!(asks.best(), Some(&Level::new(110, 1)));
+            assert_eq!(
+                bids.levels().copied().collect::<Vec<_>>(),
+                vec![Level::new(100, 1), Level::new(90, 1), Level::new(80, 1)]
+            );
+            assert_eq!(
+                asks.levels().copied().collect::<Vec<_>>(),
+                vec![Level::new(110, 1), Level::new(120, 1), Level::new(130, 1)]
+            );
+        }
+
+        #[test]
+        fn test_upsert_deep_levels() {
+            // Upserts far from the best price exercise the full ladder search
+            let mut bids = OrderBookSide::new(Side::Buy, Vec::<Level>::new());
+            let mut asks = OrderBookSide::new(Side::Sell, Vec::<Level>::new());
+            for price in [50, 10, 90, 30, 70, 20, 80, 40, 60, 100] {
+                bids.upsert_single(Level::new(price, 1));
+                asks.upsert_single(Level::new(price, 1));
+            }
+            bids.upsert_single(Level::new(10, 0));
+            asks.upsert_single(Level::new(100, 0));
+            bids.upsert_single(Level::new(30, 5));
+            asks.upsert_single(Level::new(30, 5));
+
+            let expected_bids = [100, 90, 80, 70, 60, 50, 40, 30, 20];
+            let expected_asks = [10, 20, 30, 40, 50, 60, 70, 80, 90];
+
+            assert!(bids
+                .levels()
+                .map(|level| level.price)
+                .eq(expected_bids.map(Decimal::from)));
+            assert!(asks
+                .levels()
+                .map(|level| level.price)
+                .eq(expected_asks.map(Decimal::from)));
+            assert!(bids.levels().any(|level| *level == Level::new(30, 5)));
+            assert!(asks.levels().any(|level| *level == Level::new(30, 5)));
+        }
+
+        #[test]
+        fn test_serde_best_to_worst() {
+            let bids = OrderBookSide::new(Side::Buy, vec![Level::new(90, 1), Level::new(100, 2)]);
+
+            let serialised = serde_json::to_string(&bids).unwrap();
+            assert_eq!(
+                serialised,
+                r#"{"side":"Buy","levels":[{"price":"100","amount":"2"},{"price":"90","amount":"1"}]}"#
+            );
+            assert_eq!(
+                serde_json::from_str::<OrderBookSide>(&serialised).unwrap(),
+                bids
+            );
+        }
+
         #[test]
         fn test_upsert_single() {
             struct TestCase {
@@ -1,6 +1,7 @@
 use super::SubKind;
 use barter_integration::model::Side;
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Barter [`Subscription`](super::Subscription) [`SubKind`] that yields [`Liquidation`]
@@ -16,7 +17,7 @@ impl SubKind for Liquidations {
 #[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct Liquidation {
     pub side: Side,
-    pub price: f64,
-    pub quantity: f64,
+    pub price: Decimal,
+    pub quantity: Decimal,
     pub time: DateTime<Utc>,
 }
@@ -67,12 +67,14 @@ pub struct MultiBookTransformer<Exchange, Kind, Updater> {
 }
 
 impl<Exchange, Kind, Updater> MultiBookTransformer<Exchange, Kind, Updater> {
-    pub fn init_book_map(book_map: Map<InstrumentOrderBook<Updater>>) -> Result<Self, DataError> {
+    pub fn init_book_map(
+        mut book_map: Map<InstrumentOrderBook<Updater>>,
+    ) -> Result<Self, DataError> {
         // make sure to sort the orderbooks
-        for (_, inst_book) in book_map.0.iter() {
-            let mut lock = inst_book.book.book.lock();
-            lock.asks.sort();
-            lock.bids.sort();
+        for inst_book in book_map.0.values_mut() {
+            let book = inst_book.book.make_mut();
+            book.asks.sort();
+            book.bids.sort();
         }
 
         Ok(Self {
@@ -144,17 +146,17 @@ where
             .collect::<Result<Vec<InstrumentOrderBook<Updater>>, DataError>>()?;
 
         // Construct OrderBookMap if all requests successful
-        let book_map = sub_ids
+        let mut book_map = sub_ids
             .into_iter()
             .map(|sub_id| Arc::<SubscriptionId>::try_unwrap(sub_id).unwrap())
             .zip(init_order_books.into_iter())
             .collect::<Map<InstrumentOrderBook<Updater>>>();
 
         // make sure to sort the orderbooks
-        for (_, inst_book) in book_map.0.iter() {
-            let mut lock = inst_book.book.book.lock();
-            lock.asks.sort();
-            lock.bids.sort();
+        for inst_book in book_map.0.values_mut() {
+            let book = inst_book.book.make_mut();
+            book.asks.sort();
+            book.bids.sort();
         }
 
         self.book_map = book_map;
@@ -195,15 +197,17 @@ where
             updater,
         } = book;
 
-        let mut book = book.clone();
-
         // Apply update (snapshot or delta) to OrderBook & generate Market<OrderBook> snapshot
-        match updater.update(&mut book, update) {
+        // '--> published snapshots share the OrderBook until the next update is applied
+        match updater.update(book, update) {
             Ok(Some(book)) => {
                 MarketIter::<OrderBook>::from((Exchange::ID, instrument.clone(), book)).0
             }
             Ok(None) => vec![],
-            Err(error) => vec![Err(error)],
+            Err(error) => vec![Err(DataError::Subscription {
+                subscription_id,
+                error: Box::new(error),
+            })],
         }
     }
 }
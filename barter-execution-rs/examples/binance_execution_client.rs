@@ -7,6 +7,8 @@ use barter_execution::{
 use barter_integration::init_logging;
 // use barter_integration::init_logging;
 use dotenv::dotenv;
+use tokio::sync::mpsc;
+use tracing::info;
 
 /// See Barter-Execution for a comprehensive real-life example, as well as code you can use out of the
 /// box to execute trades on many exchanges.
@@ -14,12 +16,16 @@ use dotenv::dotenv;
 async fn main() {
     dotenv().ok();
     init_logging();
+    let (event_account_tx, mut event_account_rx) = mpsc::unbounded_channel();
     let _binance_execution_client: BinanceExecution = ExecutionClient::init(BinanceConfig {
         client_type: BinanceApi::Futures(LiveOrTest::Test),
+        instrument_specs: None,
+        event_account_tx,
+        recv_window: None,
     })
     .await;
 
-    loop {
-        tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+    while let Some(account_event) = event_account_rx.recv().await {
+        info!(?account_event, "received AccountEvent");
     }
 }
@@ -14,6 +14,7 @@ use barter_integration::model::{
     instrument::{kind::InstrumentKind, Instrument},
     Exchange, Side,
 };
+use rust_decimal_macros::dec;
 use uuid::Uuid;
 
 /// See Barter-Execution for a comprehensive real-life example, as well as code you can use out of the
@@ -21,12 +22,13 @@ use uuid::Uuid;
 #[tokio::main]
 async fn main() {
     let order = Order {
-        exchange: Exchange::from(ExecutionId::Binance),
+        exchange: Exchange::from(ExecutionId::BinanceFuturesUsd),
         instrument: Instrument::from(("eth", "usdt", InstrumentKind::Perpetual)),
         state: RequestOpen {
             kind: OrderKind::Limit,
-            price: 10000.0,
-            quantity: 0.001,
+            price: dec!(10000),
+            quantity: dec!(0.001),
+            reduce_only: false,
         },
         side: Side::Buy,
         cid: ClientOrderId(Uuid::new_v4()),
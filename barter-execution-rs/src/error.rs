@@ -1,5 +1,8 @@
 use crate::model::{order::OrderKind, ClientOrderId};
-use barter_integration::{error::SocketError, model::instrument::symbol::Symbol};
+use barter_integration::{
+    error::{SocketError, SpecError},
+    model::instrument::symbol::Symbol,
+};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -19,6 +22,9 @@ pub enum ExecutionError {
     #[error("failed to open Order due to unsupported OrderKind: {0}")]
     UnsupportedOrderKind(OrderKind),
 
+    #[error("Order does not conform to InstrumentSpec: {0}")]
+    InvalidOrder(#[from] SpecError),
+
     #[error("request authorisation invalid: {0}")]
     Unauthorised(String),
 
@@ -1,26 +1,38 @@
-use std::{fmt::Debug, marker::PhantomData};
+use std::{fmt::Debug, marker::PhantomData, time::Duration};
 
 use bytes::Bytes;
 
 use barter_integration::{
     error::SocketError,
+    metric::registry::MetricRegistry,
     protocol::http::{
-        private::{encoder::HexEncoder, get_default_signer, RequestSigner, Signer},
-        rest::{client::RestClient, ApiRequest, QueryParams, RestRequest},
+        private::{
+            encoder::HexEncoder,
+            get_default_signer,
+            time_sync::{TimeOffset, TimeSync},
+            RequestSigner, Signer,
+        },
+        public::PublicNoHeaders,
+        rest::{
+            client::RestClient,
+            rate_limit::{RateLimit, RateLimitKind, RateLimiter},
+            ApiRequest, QueryParams, RestRequest,
+        },
         HttpParser,
     },
 };
-use chrono::Utc;
 use dotenv::dotenv;
 use hmac::Hmac;
 use reqwest::{RequestBuilder, StatusCode};
+use rust_decimal::Decimal;
 use serde::Deserialize;
-use tokio::sync::mpsc;
 
 use crate::{
     error::ExecutionError,
+    execution::binance::requests::{ServerTimeResponse, FUT_TIME_REQUEST, SPOT_TIME_REQUEST},
     fill::Decision,
-    model::order::{Order, OrderKind, RequestOpen},
+    model::order::{Order, OrderKind, RequestAmend, RequestCancel, RequestOpen},
+    ExecutionId,
 };
 
 #[derive(Debug, Copy, Clone)]
@@ -35,44 +47,120 @@ pub enum BinanceApi {
     Futures(LiveOrTest),
 }
 
+impl From<BinanceApi> for ExecutionId {
+    fn from(api_type: BinanceApi) -> Self {
+        match api_type {
+            BinanceApi::Spot(_) => ExecutionId::BinanceSpot,
+            BinanceApi::Futures(_) => ExecutionId::BinanceFuturesUsd,
+        }
+    }
+}
+
 pub type BinanceInternalClient =
     RestClient<RequestSigner<BinanceSigner, Hmac<sha2::Sha256>, HexEncoder>, BinanceParser>;
 
+/// [`TimeSync`] service that keeps a [`BinanceClient`] [`TimeOffset`] synchronised with the
+/// Binance server time.
+pub type BinanceTimeSync =
+    TimeSync<PublicNoHeaders, BinanceParser, ApiRequest<ServerTimeResponse, ()>>;
+
+/// Binance REST client. Http request duration [`Metric`](barter_integration::metric::Metric)s
+/// are aggregated by the [`MetricRegistry::global`] registry, so a [`BinanceClient`] must be
+/// constructed from within a tokio runtime.
 #[derive(Debug, Clone)]
 pub struct BinanceClient {
     pub client: BinanceInternalClient,
     pub kind: BinanceApi,
+    /// Server time offset shared with the [`BinanceSigner`], updated by a [`BinanceTimeSync`].
+    pub time_offset: TimeOffset,
 }
 
 impl BinanceClient {
     pub fn new_with_url(api_type: BinanceApi, url: String) -> BinanceClient {
-        Self::build_client(api_type, url)
+        Self::build_client(api_type, url, None)
     }
 
     pub fn new(api_type: BinanceApi) -> BinanceClient {
         let client_url = Self::get_url(api_type);
-        Self::build_client(api_type, client_url.to_string())
+        Self::build_client(api_type, client_url.to_string(), None)
     }
 
-    fn build_client(api_type: BinanceApi, client_url: String) -> BinanceClient {
+    /// Construct a [`BinanceClient`] that signs requests with the provided `recvWindow`, rather
+    /// than the Binance default of 5000ms.
+    pub fn new_with_recv_window(api_type: BinanceApi, recv_window: Duration) -> BinanceClient {
+        let client_url = Self::get_url(api_type);
+        Self::build_client(api_type, client_url.to_string(), Some(recv_window))
+    }
+
+    fn build_client(
+        api_type: BinanceApi,
+        client_url: String,
+        recv_window: Option<Duration>,
+    ) -> BinanceClient {
         let (api_key, api_secret) = Self::get_key_secret(api_type);
 
-        // // Construct Metric channel to send Http execution metrics over
-        let (http_metric_tx, _http_metric_rx) = mpsc::unbounded_channel();
+        // Send Http execution metrics over the Metric channel shared by every client, aggregated
+        // by the global MetricRegistry
+        let http_metric_tx = MetricRegistry::global_sender();
 
+        let time_offset = TimeOffset::new();
         let request_signer = get_default_signer(
             &api_secret,
-            BinanceSigner {
-                api_key: api_key.to_string(),
-                timestamp_delta: 0,
-            },
+            BinanceSigner::init(api_key.to_string(), time_offset.clone(), recv_window),
         );
 
         // Build RestClient with Binance configuration
-        let client = RestClient::new(client_url, http_metric_tx, request_signer, BinanceParser);
+        let client = RestClient::new(client_url, http_metric_tx, request_signer, BinanceParser)
+            .with_rate_limiter(Self::rate_limiter(api_type));
         BinanceClient {
             client,
             kind: api_type,
+            time_offset,
+        }
+    }
+
+    /// Construct a [`BinanceTimeSync`] service that updates the [`TimeOffset`] used to sign
+    /// requests. Server time requests share the [`RateLimiter`] of this client.
+    pub fn time_sync(&self) -> BinanceTimeSync {
+        let client = RestClient::new(
+            self.client.base_url.clone(),
+            self.client.metric_tx.clone(),
+            PublicNoHeaders,
+            BinanceParser,
+        )
+        .with_rate_limiter(self.client.rate_limiter.clone());
+
+        let request = match self.kind {
+            BinanceApi::Spot(_) => SPOT_TIME_REQUEST,
+            BinanceApi::Futures(_) => FUT_TIME_REQUEST,
+        };
+
+        TimeSync::new(client, request, self.time_offset.clone())
+    }
+
+    /// Construct a [`RateLimiter`] enforcing the default Binance request weight & order count
+    /// limits, synchronised with the usage reported in the "X-MBX-*" response headers.
+    pub fn rate_limiter(api_type: BinanceApi) -> RateLimiter {
+        const MINUTE: Duration = Duration::from_secs(60);
+        const DAY: Duration = Duration::from_secs(60 * 60 * 24);
+
+        match api_type {
+            BinanceApi::Spot(_) => RateLimiter::new([
+                RateLimit::new(RateLimitKind::Weight, 6000, MINUTE)
+                    .with_header("X-MBX-USED-WEIGHT-1M"),
+                RateLimit::new(RateLimitKind::Orders, 100, Duration::from_secs(10))
+                    .with_header("X-MBX-ORDER-COUNT-10S"),
+                RateLimit::new(RateLimitKind::Orders, 200_000, DAY)
+                    .with_header("X-MBX-ORDER-COUNT-1D"),
+            ]),
+            BinanceApi::Futures(_) => RateLimiter::new([
+                RateLimit::new(RateLimitKind::Weight, 2400, MINUTE)
+                    .with_header("X-MBX-USED-WEIGHT-1M"),
+                RateLimit::new(RateLimitKind::Orders, 300, Duration::from_secs(10))
+                    .with_header("X-MBX-ORDER-COUNT-10S"),
+                RateLimit::new(RateLimitKind::Orders, 1200, MINUTE)
+                    .with_header("X-MBX-ORDER-COUNT-1M"),
+            ]),
         }
     }
 
@@ -97,7 +185,10 @@ impl BinanceClient {
 
     pub fn get_url(api_type: BinanceApi) -> &'static str {
         match api_type {
-            BinanceApi::Spot(_) => "https://api.binance.com",
+            BinanceApi::Spot(kind) => match kind {
+                LiveOrTest::Live => "https://api.binance.com",
+                LiveOrTest::Test => "https://testnet.binance.vision",
+            },
             BinanceApi::Futures(kind) => match kind {
                 LiveOrTest::Live => "https://fapi.binance.com",
                 LiveOrTest::Test => "https://testnet.binancefuture.com",
@@ -132,41 +223,11 @@ impl BinanceClient {
         query_params.add_kv("side", order.side.to_string().to_uppercase());
         query_params.add_kv("quantity", order.state.quantity);
         query_params.add_kv("newClientOrderId", order.cid);
-
-        match order.state.kind {
-            OrderKind::Limit => {
-                query_params.add_kv("type", "LIMIT");
-                query_params.add_kv("timeInForce", "GTC");
-                query_params.add_kv("price", order.state.price);
-            }
-            OrderKind::Market => {
-                query_params.add_kv("type", "MARKET");
-                query_params.add_kv("newOrderRespType", "RESULT");
-            }
-            // OrderType::StopLoss { stop_price } => {
-            //     query_params.add_kv("type", "STOP_LOSS");
-            //     query_params.add_kv("stopPrice", stop_price);
-            //     query_params.add_kv("timeInForce", "GTC");
-            // }
-            // OrderType::TrailingStop {
-            //     trailing_delta,
-            //     stop_price,
-            // } => {
-            //     query_params.add_kv("type", "STOP_LOSS");
-            //     query_params.add_kv("trailingDelta", trailing_delta);
-            //     query_params.add_kv("timeInForce", "GTC");
-
-            //     if let Some(stop_price) = stop_price {
-            //         query_params.add_kv("stopPrice", stop_price)
-            //     }
-            // }
-            _ => todo!("Order type not supported"),
-        }
+        Self::add_order_kind(self.kind, &mut query_params, &order.state);
 
         let path = match self.kind {
-            BinanceApi::Futures(LiveOrTest::Live) => "/fapi/v1/order",
-            BinanceApi::Futures(LiveOrTest::Test) => "/fapi/v1/order",
-            _ => todo!("Api type not supported"),
+            BinanceApi::Spot(_) => "/api/v3/order",
+            BinanceApi::Futures(_) => "/fapi/v1/order",
         };
         let request: ApiRequest<Response, ()> = ApiRequest {
             path,
@@ -174,12 +235,126 @@ impl BinanceClient {
             tag_method: "open_order",
             body: None,
             query_params: Some(query_params),
+            weight: 1,
+            order_count: 1,
             response: PhantomData,
         };
 
         self.client.execute(request).await
     }
 
+    /// Add the Binance `type` & `timeInForce` parameters associated with the [`OrderKind`], as
+    /// well as any trigger, trailing & reduce-only parameters.
+    ///
+    /// [`OrderKind::PostOnly`] maps to `LIMIT_MAKER` on [`BinanceApi::Spot`], and to `LIMIT` with
+    /// `timeInForce=GTX` on [`BinanceApi::Futures`].
+    ///
+    /// [`OrderKind::TrailingStop`] deltas map to `trailingDelta` BIPS on [`BinanceApi::Spot`], and
+    /// to a `callbackRate` percentage on [`BinanceApi::Futures`]. `reduceOnly` is only sent to
+    /// [`BinanceApi::Futures`] since spot has no positions.
+    fn add_order_kind(kind: BinanceApi, query_params: &mut QueryParams, state: &RequestOpen) {
+        match state.kind {
+            OrderKind::Market => {
+                query_params.add_kv("type", "MARKET");
+                query_params.add_kv("newOrderRespType", "RESULT");
+            }
+            OrderKind::Limit => {
+                query_params.add_kv("type", "LIMIT");
+                query_params.add_kv("timeInForce", "GTC");
+                query_params.add_kv("price", state.price);
+            }
+            OrderKind::PostOnly => match kind {
+                BinanceApi::Spot(_) => {
+                    query_params.add_kv("type", "LIMIT_MAKER");
+                    query_params.add_kv("price", state.price);
+                }
+                BinanceApi::Futures(_) => {
+                    query_params.add_kv("type", "LIMIT");
+                    query_params.add_kv("timeInForce", "GTX");
+                    query_params.add_kv("price", state.price);
+                }
+            },
+            OrderKind::ImmediateOrCancel => {
+                query_params.add_kv("type", "LIMIT");
+                query_params.add_kv("timeInForce", "IOC");
+                query_params.add_kv("price", state.price);
+            }
+            OrderKind::StopMarket { trigger_price } => {
+                query_params.add_kv(
+                    "type",
+                    match kind {
+                        BinanceApi::Spot(_) => "STOP_LOSS",
+                        BinanceApi::Futures(_) => "STOP_MARKET",
+                    },
+                );
+                query_params.add_kv("stopPrice", trigger_price);
+            }
+            OrderKind::StopLimit { trigger_price } => {
+                query_params.add_kv(
+                    "type",
+                    match kind {
+                        BinanceApi::Spot(_) => "STOP_LOSS_LIMIT",
+                        BinanceApi::Futures(_) => "STOP",
+                    },
+                );
+                query_params.add_kv("timeInForce", "GTC");
+                query_params.add_kv("price", state.price);
+                query_params.add_kv("stopPrice", trigger_price);
+            }
+            OrderKind::TakeProfitMarket { trigger_price } => {
+                query_params.add_kv(
+                    "type",
+                    match kind {
+                        BinanceApi::Spot(_) => "TAKE_PROFIT",
+                        BinanceApi::Futures(_) => "TAKE_PROFIT_MARKET",
+                    },
+                );
+                query_params.add_kv("stopPrice", trigger_price);
+            }
+            OrderKind::TakeProfitLimit { trigger_price } => {
+                query_params.add_kv(
+                    "type",
+                    match kind {
+                        BinanceApi::Spot(_) => "TAKE_PROFIT_LIMIT",
+                        BinanceApi::Futures(_) => "TAKE_PROFIT",
+                    },
+                );
+                query_params.add_kv("timeInForce", "GTC");
+                query_params.add_kv("price", state.price);
+                query_params.add_kv("stopPrice", trigger_price);
+            }
+            OrderKind::TrailingStop {
+                trailing_delta,
+                activation_price,
+            } => match kind {
+                BinanceApi::Spot(_) => {
+                    query_params.add_kv("type", "STOP_LOSS");
+                    query_params.add_kv(
+                        "trailingDelta",
+                        (trailing_delta * Decimal::from(10_000)).round(),
+                    );
+                    if let Some(activation_price) = activation_price {
+                        query_params.add_kv("stopPrice", activation_price);
+                    }
+                }
+                BinanceApi::Futures(_) => {
+                    query_params.add_kv("type", "TRAILING_STOP_MARKET");
+                    query_params.add_kv(
+                        "callbackRate",
+                        (trailing_delta * Decimal::ONE_HUNDRED).normalize(),
+                    );
+                    if let Some(activation_price) = activation_price {
+                        query_params.add_kv("activationPrice", activation_price);
+                    }
+                }
+            },
+        }
+
+        if state.reduce_only && matches!(kind, BinanceApi::Futures(_)) {
+            query_params.add_kv("reduceOnly", "true");
+        }
+    }
+
     pub async fn open_orders<Response>(
         &self,
         orders: Vec<Order<RequestOpen>>,
@@ -187,6 +362,7 @@ impl BinanceClient {
     where
         Response: for<'de> Deserialize<'de> + Debug,
     {
+        let order_count = orders.len() as u32;
         let mut query_params = QueryParams::new();
         for order in orders {
             let instrument = &order.instrument;
@@ -199,51 +375,20 @@ impl BinanceClient {
             query_params.add_kv("quantity", order.state.quantity);
             query_params.add_kv("newClientOrderId", order.cid);
 
-            match order.state.kind {
-                OrderKind::Limit => {
-                    // match execution_type {
-                    // OrderExecutionType::None => {
-                    //     query_params.add_kv("type", "LIMIT");
-                    //     query_params.add_kv("timeInForce", "GTC");
-                    // }
-                    // OrderExecutionType::MakerOnly => query_params.add_kv("type", "LIMIT_MAKER"),
-                    // }
-                    query_params.add_kv("type", "LIMIT");
-                    query_params.add_kv("timeInForce", "GTC");
-                    query_params.add_kv("price", order.state.price);
-                }
-                OrderKind::Market => {
-                    query_params.add_kv("type", "MARKET");
-                    query_params.add_kv("newOrderRespType", "RESULT");
-                }
-                // OrderType::StopLoss { stop_price } => {
-                //     query_params.add_kv("type", "STOP_LOSS");
-                //     query_params.add_kv("stopPrice", stop_price);
-                //     query_params.add_kv("timeInForce", "GTC");
-                // }
-                // OrderType::TrailingStop {
-                //     trailing_delta,
-                //     stop_price,
-                // } => {
-                //     query_params.add_kv("type", "STOP_LOSS");
-                //     query_params.add_kv("trailingDelta", trailing_delta);
-                //     query_params.add_kv("timeInForce", "GTC");
-
-                //     if let Some(stop_price) = stop_price {
-                //         query_params.add_kv("stopPrice", stop_price)
-                //     }
-                // }
-                _ => todo!("Order type not supported"),
-            }
+            Self::add_order_kind(self.kind, &mut query_params, &order.state);
         }
         let query = query_params.to_string();
         let mut query_params = QueryParams::new();
         query_params.add_kv("batchOrders", query);
 
         let path = match self.kind {
-            BinanceApi::Futures(LiveOrTest::Live) => "/fapi/v1/batchOrders",
-            BinanceApi::Futures(LiveOrTest::Test) => "/fapi/v1/batchOrders",
-            _ => todo!("Api type not supported"),
+            BinanceApi::Futures(_) => "/fapi/v1/batchOrders",
+            BinanceApi::Spot(_) => {
+                return Err(ExecutionError::Socket(SocketError::Unsupported {
+                    entity: "BinanceSpot",
+                    item: "batch orders".to_string(),
+                }))
+            }
         };
         let request: ApiRequest<Response, ()> = ApiRequest {
             path,
@@ -251,6 +396,128 @@ impl BinanceClient {
             tag_method: "batch_orders",
             body: None,
             query_params: Some(query_params),
+            weight: 5,
+            order_count,
+            response: PhantomData,
+        };
+
+        self.client.execute(request).await
+    }
+
+    pub async fn cancel_order<Response>(
+        &self,
+        order: &Order<RequestCancel>,
+    ) -> Result<Response, ExecutionError>
+    where
+        Response: for<'de> Deserialize<'de> + Debug,
+    {
+        let mut query_params = QueryParams::new();
+        let instrument = &order.instrument;
+        let symbol = format!("{}{}", instrument.base, instrument.quote).to_uppercase();
+
+        query_params.add_kv("symbol", symbol);
+        query_params.add_kv("orderId", &order.state.id.0);
+
+        let path = match self.kind {
+            BinanceApi::Spot(_) => "/api/v3/order",
+            BinanceApi::Futures(_) => "/fapi/v1/order",
+        };
+        let request: ApiRequest<Response, ()> = ApiRequest {
+            path,
+            method: reqwest::Method::DELETE,
+            tag_method: "cancel_order",
+            body: None,
+            query_params: Some(query_params),
+            weight: 1,
+            order_count: 0,
+            response: PhantomData,
+        };
+
+        self.client.execute(request).await
+    }
+
+    /// Modify the price & quantity of an open [`BinanceApi::Futures`] limit order, keeping it's
+    /// queue priority if only the quantity is reduced. Binance spot has no modify endpoint.
+    pub async fn amend_order<Response>(
+        &self,
+        order: &Order<RequestAmend>,
+    ) -> Result<Response, ExecutionError>
+    where
+        Response: for<'de> Deserialize<'de> + Debug,
+    {
+        let path = match self.kind {
+            BinanceApi::Futures(_) => "/fapi/v1/order",
+            BinanceApi::Spot(_) => {
+                return Err(ExecutionError::Socket(SocketError::Unsupported {
+                    entity: "BinanceSpot",
+                    item: "amend order".to_string(),
+                }))
+            }
+        };
+
+        let mut query_params = QueryParams::new();
+        let instrument = &order.instrument;
+        let symbol = format!("{}{}", instrument.base, instrument.quote).to_uppercase();
+
+        query_params.add_kv("symbol", symbol);
+        query_params.add_kv("orderId", &order.state.id.0);
+        query_params.add_kv("side", order.side.to_string().to_uppercase());
+        query_params.add_kv("quantity", order.state.quantity);
+        query_params.add_kv("price", order.state.price);
+
+        let request: ApiRequest<Response, ()> = ApiRequest {
+            path,
+            method: reqwest::Method::PUT,
+            tag_method: "amend_order",
+            body: None,
+            query_params: Some(query_params),
+            weight: 1,
+            order_count: 1,
+            response: PhantomData,
+        };
+
+        self.client.execute(request).await
+    }
+
+    /// Fetch every open order of the account, across all symbols.
+    pub async fn fetch_open_orders<Response>(&self) -> Result<Response, ExecutionError>
+    where
+        Response: for<'de> Deserialize<'de> + Debug,
+    {
+        // Fetching open orders without a symbol is weighted heavily by Binance
+        let (path, weight) = match self.kind {
+            BinanceApi::Spot(_) => ("/api/v3/openOrders", 80),
+            BinanceApi::Futures(_) => ("/fapi/v1/openOrders", 40),
+        };
+        let request: ApiRequest<Response, ()> =
+            ApiRequest::new(path, reqwest::Method::GET, "fetch_open_orders").with_weight(weight);
+
+        self.client.execute(request).await
+    }
+
+    /// Cancel every open order of the provided Binance symbol (eg/ "BTCUSDT").
+    pub async fn cancel_open_orders<Response>(
+        &self,
+        symbol: &str,
+    ) -> Result<Response, ExecutionError>
+    where
+        Response: for<'de> Deserialize<'de> + Debug,
+    {
+        let mut query_params = QueryParams::new();
+        query_params.add_kv("symbol", symbol);
+
+        let path = match self.kind {
+            BinanceApi::Spot(_) => "/api/v3/openOrders",
+            BinanceApi::Futures(_) => "/fapi/v1/allOpenOrders",
+        };
+        let request: ApiRequest<Response, ()> = ApiRequest {
+            path,
+            method: reqwest::Method::DELETE,
+            tag_method: "cancel_open_orders",
+            body: None,
+            query_params: Some(query_params),
+            weight: 1,
+            order_count: 0,
             response: PhantomData,
         };
 
@@ -270,14 +537,23 @@ pub(super) fn get_order_side(side: Decision) -> &'static str {
 #[derive(Debug, Clone)]
 pub struct BinanceSigner {
     pub api_key: String,
-    pub timestamp_delta: i64,
+    /// Offset between the Binance server clock and the local clock, used to generate the
+    /// request `timestamp`.
+    pub timestamp_delta: TimeOffset,
+    /// Optional `recvWindow` the request must be received within. Binance defaults to 5000ms.
+    pub recv_window: Option<Duration>,
 }
 
 impl BinanceSigner {
-    pub fn init(api_key: String, timestamp_delta: i64) -> Self {
+    pub fn init(
+        api_key: String,
+        timestamp_delta: TimeOffset,
+        recv_window: Option<Duration>,
+    ) -> Self {
         Self {
             api_key,
             timestamp_delta,
+            recv_window,
         }
     }
 }
@@ -299,11 +575,14 @@ impl Signer for BinanceSigner {
     where
         Request: RestRequest,
     {
-        let timestamp = (Utc::now().timestamp_millis() - self.timestamp_delta) as u128;
+        let timestamp = self.timestamp_delta.server_time_millis();
 
         // this is a little ugly, but the only way I could find to add
         // and grab query parameters to a request
         builder = builder.query(&[("timestamp", timestamp)]);
+        if let Some(recv_window) = self.recv_window {
+            builder = builder.query(&[("recvWindow", recv_window.as_millis())]);
+        }
         let (client, request) = builder.build_split();
         if let Err(e) = request {
             return Err(SocketError::from(e));
@@ -376,12 +655,17 @@ mod tests {
         model::{order_event::OrderEventBuilder, ClientOrderId},
         ExecutionId,
     };
-    use barter_integration::model::{
-        instrument::{kind::InstrumentKind, symbol::Symbol, Instrument},
-        Exchange, Side,
+    use barter_integration::{
+        model::{
+            instrument::{kind::InstrumentKind, symbol::Symbol, Instrument},
+            Exchange, Side,
+        },
+        protocol::http::private::time_sync::TimeSample,
     };
+    use chrono::Utc;
     use dotenv::dotenv;
     use mockito::Matcher;
+    use rust_decimal_macros::dec;
     use serde_json::json;
     use uuid::Uuid;
 
@@ -438,12 +722,13 @@ mod tests {
             .create();
         // create new order with test data
         let order = Order {
-            exchange: Exchange::from(ExecutionId::Binance),
+            exchange: Exchange::from(ExecutionId::BinanceFuturesUsd),
             instrument: Instrument::from(("eth", "usdt", InstrumentKind::Perpetual)),
             state: RequestOpen {
                 kind: OrderKind::Limit,
-                price: 10000.0,
-                quantity: 0.001,
+                price: dec!(10000),
+                quantity: dec!(0.001),
+                reduce_only: false,
             },
             side: Side::Buy,
             cid: ClientOrderId(Uuid::new_v4()),
@@ -454,4 +739,193 @@ mod tests {
         println!("resopnse {:#?}", response);
         assert_eq!(response.symbol, Symbol::from("ETHUSDT"));
     }
+
+    #[test]
+    fn test_add_order_kind() {
+        struct TestCase {
+            kind: BinanceApi,
+            order_kind: OrderKind,
+            reduce_only: bool,
+            expected: &'static str,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Spot PostOnly -> LIMIT_MAKER
+                kind: BinanceApi::Spot(LiveOrTest::Live),
+                order_kind: OrderKind::PostOnly,
+                reduce_only: false,
+                expected: "type:LIMIT_MAKER,price:100",
+            },
+            TestCase {
+                // TC1: Futures PostOnly -> LIMIT GTX
+                kind: BinanceApi::Futures(LiveOrTest::Live),
+                order_kind: OrderKind::PostOnly,
+                reduce_only: false,
+                expected: "type:LIMIT,timeInForce:GTX,price:100",
+            },
+            TestCase {
+                // TC2: Spot ImmediateOrCancel -> LIMIT IOC
+                kind: BinanceApi::Spot(LiveOrTest::Live),
+                order_kind: OrderKind::ImmediateOrCancel,
+                reduce_only: false,
+                expected: "type:LIMIT,timeInForce:IOC,price:100",
+            },
+            TestCase {
+                // TC3: Spot Limit -> LIMIT GTC
+                kind: BinanceApi::Spot(LiveOrTest::Test),
+                order_kind: OrderKind::Limit,
+                reduce_only: false,
+                expected: "type:LIMIT,timeInForce:GTC,price:100",
+            },
+            TestCase {
+                // TC4: Spot Market -> MARKET
+                kind: BinanceApi::Spot(LiveOrTest::Test),
+                order_kind: OrderKind::Market,
+                reduce_only: false,
+                expected: "type:MARKET,newOrderRespType:RESULT",
+            },
+            TestCase {
+                // TC5: Futures reduce-only StopMarket -> STOP_MARKET
+                kind: BinanceApi::Futures(LiveOrTest::Live),
+                order_kind: OrderKind::StopMarket {
+                    trigger_price: dec!(90),
+                },
+                reduce_only: true,
+                expected: "type:STOP_MARKET,stopPrice:90,reduceOnly:true",
+            },
+            TestCase {
+                // TC6: Spot reduce-only StopLimit -> STOP_LOSS_LIMIT without reduceOnly
+                kind: BinanceApi::Spot(LiveOrTest::Live),
+                order_kind: OrderKind::StopLimit {
+                    trigger_price: dec!(90),
+                },
+                reduce_only: true,
+                expected: "type:STOP_LOSS_LIMIT,timeInForce:GTC,price:100,stopPrice:90",
+            },
+            TestCase {
+                // TC7: Futures TakeProfitLimit -> TAKE_PROFIT
+                kind: BinanceApi::Futures(LiveOrTest::Test),
+                order_kind: OrderKind::TakeProfitLimit {
+                    trigger_price: dec!(110),
+                },
+                reduce_only: false,
+                expected: "type:TAKE_PROFIT,timeInForce:GTC,price:100,stopPrice:110",
+            },
+            TestCase {
+                // TC8: Spot TakeProfitMarket -> TAKE_PROFIT
+                kind: BinanceApi::Spot(LiveOrTest::Test),
+                order_kind: OrderKind::TakeProfitMarket {
+                    trigger_price: dec!(110),
+                },
+                reduce_only: false,
+                expected: "type:TAKE_PROFIT,stopPrice:110",
+            },
+            TestCase {
+                // TC9: Futures TrailingStop -> TRAILING_STOP_MARKET with callbackRate percent
+                kind: BinanceApi::Futures(LiveOrTest::Live),
+                order_kind: OrderKind::TrailingStop {
+                    trailing_delta: dec!(0.015),
+                    activation_price: Some(dec!(105)),
+                },
+                reduce_only: true,
+                expected:
+                    "type:TRAILING_STOP_MARKET,callbackRate:1.5,activationPrice:105,reduceOnly:true",
+            },
+            TestCase {
+                // TC10: Spot TrailingStop -> STOP_LOSS with trailingDelta BIPS
+                kind: BinanceApi::Spot(LiveOrTest::Live),
+                order_kind: OrderKind::TrailingStop {
+                    trailing_delta: dec!(0.015),
+                    activation_price: None,
+                },
+                reduce_only: false,
+                expected: "type:STOP_LOSS,trailingDelta:150",
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut query_params = QueryParams::new();
+            let state = RequestOpen {
+                kind: test.order_kind,
+                price: dec!(100),
+                quantity: dec!(1),
+                reduce_only: test.reduce_only,
+            };
+            BinanceClient::add_order_kind(test.kind, &mut query_params, &state);
+            assert_eq!(
+                query_params.to_string(),
+                test.expected,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_binance_signer_config() {
+        struct TestCase {
+            offset_ms: i64,
+            recv_window: Option<Duration>,
+            expected_recv_window: Option<&'static str>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: synchronised clocks & default recvWindow
+                offset_ms: 0,
+                recv_window: None,
+                expected_recv_window: None,
+            },
+            TestCase {
+                // TC1: local clock ahead of server & custom recvWindow
+                offset_ms: -60_000,
+                recv_window: Some(Duration::from_secs(10)),
+                expected_recv_window: Some("10000"),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let time_offset = TimeOffset::new();
+            time_offset.update(TimeSample {
+                offset_ms: test.offset_ms,
+                round_trip_ms: 0,
+            });
+            let signer = BinanceSigner::init("key".to_string(), time_offset, test.recv_window);
+
+            let builder = reqwest::Client::new().get("https://api.binance.com/api/v3/account");
+            let (config, _) = signer.config(&SPOT_TIME_REQUEST, builder).unwrap();
+            let params = config
+                .query_string
+                .split('&')
+                .filter_map(|kv| kv.split_once('='))
+                .collect::<std::collections::HashMap<_, _>>();
+
+            let timestamp = params["timestamp"].parse::<i64>().unwrap();
+            let expected_timestamp = Utc::now().timestamp_millis() + test.offset_ms;
+            assert!(
+                (expected_timestamp - timestamp).abs() < 1_000,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                params.get("recvWindow").copied(),
+                test.expected_recv_window,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_execution_id_from_binance_api() {
+        assert_eq!(
+            ExecutionId::from(BinanceApi::Spot(LiveOrTest::Live)),
+            ExecutionId::BinanceSpot
+        );
+        assert_eq!(
+            ExecutionId::from(BinanceApi::Futures(LiveOrTest::Test)),
+            ExecutionId::BinanceFuturesUsd
+        );
+    }
 }
@@ -1,21 +1,35 @@
 use async_trait::async_trait;
-use barter_integration::model::{instrument::symbol::Symbol, Exchange};
+use barter_data::exchange::binance::spec::fetch_instrument_specs;
+use barter_integration::model::{
+    instrument::{kind::InstrumentKind, spec::InstrumentSpecs, symbol::Symbol, Instrument},
+    Exchange,
+};
 use futures::future::join_all;
-use tracing::{error, info};
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
+use tokio::sync::mpsc;
+use tracing::{error, warn};
+use uuid::Uuid;
 
 use crate::{
     error::ExecutionError,
     model::{
         balance::SymbolBalance,
-        order::{self, Cancelled, Open, Order, OrderId, RequestCancel, RequestOpen},
+        order::{Cancelled, Open, Order, OrderId, RequestAmend, RequestCancel, RequestOpen},
+        AccountEvent, ClientOrderId,
     },
     ExecutionClient, ExecutionId,
 };
 
 use self::{
     connection::{BinanceApi, BinanceClient},
-    requests::{FutOrderResponse, FUT_BALANCES_REQUEST},
-    websocket::init_listener,
+    requests::{
+        CancelOrderResponse, FutOrderResponse, OpenOrderResponse, SpotOrderResponse,
+        FUT_BALANCES_REQUEST, SPOT_ACCOUNT_REQUEST,
+    },
+    websocket::UserDataStream,
 };
 
 pub mod connection;
@@ -26,13 +40,27 @@ pub mod websocket;
 #[derive(Debug)]
 pub struct BinanceExecution {
     client: BinanceClient,
-    // client_type: BinanceApi,
+    client_type: BinanceApi,
+    specs: InstrumentSpecs,
+    instruments: HashMap<BinancePair, Instrument>,
 }
 
 /// Config for initializing a [`BinanceExecution`] instance.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct BinanceConfig {
     pub client_type: BinanceApi,
+    /// [`InstrumentSpecs`] used to round & validate [`Order<RequestOpen>`]s before submission,
+    /// and to identify the [`Instrument`] of user data stream order & trade updates. If `None`,
+    /// they are fetched from the Binance exchange information endpoint on init.
+    ///
+    /// **Note:**
+    /// Init panics if the specs cannot be fetched or contain no spot or perpetual [`Instrument`].
+    pub instrument_specs: Option<InstrumentSpecs>,
+    /// [`AccountEvent`] transmitter used by the supervised user data stream.
+    pub event_account_tx: mpsc::UnboundedSender<AccountEvent>,
+    /// Optional `recvWindow` signed requests must be received within. If `None`, the Binance
+    /// default of 5000ms is used.
+    pub recv_window: Option<Duration>,
 }
 
 #[async_trait]
@@ -40,34 +68,85 @@ impl ExecutionClient for BinanceExecution {
     type Config = BinanceConfig;
 
     fn exchange(&self) -> Exchange {
-        Exchange::from(ExecutionId::Simulated)
+        Exchange::from(ExecutionId::from(self.client_type))
     }
 
     async fn init(config: Self::Config) -> Self {
-        let client = BinanceClient::new(config.client_type);
+        let client = match config.recv_window {
+            Some(recv_window) => {
+                BinanceClient::new_with_recv_window(config.client_type, recv_window)
+            }
+            None => BinanceClient::new(config.client_type),
+        };
+
+        // Synchronise with the Binance server time before any signed requests are sent
+        let time_sync = client.time_sync();
+        if let Err(error) = time_sync.sync().await {
+            warn!(
+                ?error,
+                "failed to synchronise Binance server time, assuming local time"
+            );
+        }
+        tokio::spawn(time_sync.run());
+
         let url = BinanceClient::get_url(config.client_type);
         let (api_key, _) = BinanceClient::get_key_secret(config.client_type);
-        init_listener(&api_key, url).await;
+
+        let specs = match config.instrument_specs {
+            Some(specs) => specs,
+            None => {
+                let path = match config.client_type {
+                    BinanceApi::Spot(_) => "/api/v3/exchangeInfo",
+                    BinanceApi::Futures(_) => "/fapi/v1/exchangeInfo",
+                };
+                fetch_instrument_specs(&format!("{url}{path}"))
+                    .await
+                    .expect("failed to fetch Binance InstrumentSpecs")
+            }
+        };
+
+        // User data stream order & trade updates can only be mapped to known Instruments
+        let instruments = instruments_by_pair(&specs);
+        assert!(
+            !instruments.is_empty(),
+            "Binance InstrumentSpecs must contain at least one spot or perpetual Instrument"
+        );
+
+        // Translate user data stream messages into AccountEvents
+        UserDataStream::new(config.client_type, api_key, &specs, config.event_account_tx).spawn();
+
         Self {
             client,
-            // client_type: config.client_type,
+            client_type: config.client_type,
+            instruments,
+            specs,
         }
     }
 
     async fn fetch_orders_open(&self) -> Result<Vec<Order<Open>>, ExecutionError> {
-        todo!()
+        let orders = self
+            .client
+            .fetch_open_orders::<Vec<OpenOrderResponse>>()
+            .await?;
+
+        Ok(orders
+            .into_iter()
+            .filter_map(|order| self.order_open(order))
+            .collect())
     }
 
     async fn fetch_balances(&self) -> Result<Vec<SymbolBalance>, ExecutionError> {
-        match self.client.send(FUT_BALANCES_REQUEST).await {
-            Ok(response) => {
-                println!("{:#?}", response);
-                return Ok(<Vec<SymbolBalance>>::from(response));
-            }
-            Err(e) => {
-                println!("{:?}", e);
-                return Err(e);
-            }
+        match self.client_type {
+            BinanceApi::Spot(_) => self
+                .client
+                .send(SPOT_ACCOUNT_REQUEST)
+                .await
+                .map(<Vec<SymbolBalance>>::from),
+            BinanceApi::Futures(_) => self
+                .client
+                .send(FUT_BALANCES_REQUEST)
+                .await
+                .map(<Vec<SymbolBalance>>::from),
         }
     }
 
@@ -78,14 +157,36 @@ impl ExecutionClient for BinanceExecution {
         // TODO: this is a ugly, should we be using batch orders?
         let mut tasks = Vec::new();
         for open_request in open_requests {
+            // Round & validate Order<RequestOpen> to the exchange trading rules, if known
+            let open_request = match self.specs.get(&open_request.instrument) {
+                Some(spec) => match open_request.conform(spec) {
+                    Ok(open_request) => open_request,
+                    Err(error) => {
+                        error!(?error, "Order<RequestOpen> rejected before submission");
+                        tasks.push(tokio::spawn(async move { Err(error) }));
+                        continue;
+                    }
+                },
+                None => open_request,
+            };
+
             let client = self.client.clone();
+            let client_type = self.client_type;
             let task = tokio::spawn(async move {
-                let res = client.open_order::<FutOrderResponse>(&open_request).await;
+                let res = match client_type {
+                    BinanceApi::Spot(_) => client
+                        .open_order::<SpotOrderResponse>(&open_request)
+                        .await
+                        .map(|res| res.orderId),
+                    BinanceApi::Futures(_) => client
+                        .open_order::<FutOrderResponse>(&open_request)
+                        .await
+                        .map(|res| res.orderId),
+                };
                 match res {
-                    Ok(res) => Ok(Order::<Open>::from((
-                        OrderId::from(res.orderId),
-                        open_request,
-                    ))),
+                    Ok(order_id) => {
+                        Ok(Order::<Open>::from((OrderId::from(order_id), open_request)))
+                    }
                     Err(e) => {
                         error!("{:?}", e);
                         Err(e)
@@ -106,13 +207,143 @@ impl ExecutionClient for BinanceExecution {
 
     async fn cancel_orders(
         &self,
-        _cancel_requests: Vec<Order<RequestCancel>>,
+        cancel_requests: Vec<Order<RequestCancel>>,
     ) -> Vec<Result<Order<Cancelled>, ExecutionError>> {
-        todo!()
+        let tasks = cancel_requests.into_iter().map(|cancel_request| {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                match client
+                    .cancel_order::<CancelOrderResponse>(&cancel_request)
+                    .await
+                {
+                    Ok(res) => Ok(Order {
+                        exchange: cancel_request.exchange,
+                        instrument: cancel_request.instrument,
+                        cid: cancel_request.cid,
+                        side: cancel_request.side,
+                        state: Cancelled::from(res.orderId),
+                    }),
+                    Err(e) => {
+                        error!("{:?}", e);
+                        Err(e)
+                    }
+                }
+            })
+        });
+
+        join_all(tasks)
+            .await
+            .into_iter()
+            .map(|res| res.unwrap())
+            .collect()
+    }
+
+    async fn amend_orders(
+        &self,
+        amend_requests: Vec<Order<RequestAmend>>,
+    ) -> Vec<Result<Order<Open>, ExecutionError>> {
+        // Binance spot has no modify endpoint, so orders are cancelled & replaced
+        if let BinanceApi::Spot(_) = self.client_type {
+            return self.cancel_replace_orders(amend_requests).await;
+        }
+
+        let tasks = amend_requests.into_iter().map(|amend_request| {
+            // Round & validate Order<RequestAmend> to the exchange trading rules, if known
+            let amend_request = match self.specs.get(&amend_request.instrument) {
+                Some(spec) => amend_request.conform(spec),
+                None => Ok(amend_request),
+            };
+
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                let amend_request = amend_request?;
+                match client
+                    .amend_order::<OpenOrderResponse>(&amend_request)
+                    .await
+                {
+                    Ok(res) => Ok(Order {
+                        exchange: amend_request.exchange,
+                        instrument: amend_request.instrument,
+                        cid: amend_request.cid,
+                        side: amend_request.side,
+                        state: Open {
+                            id: OrderId::from(res.orderId),
+                            price: res.price,
+                            quantity: res.origQty,
+                            filled_quantity: res.executedQty,
+                        },
+                    }),
+                    Err(e) => {
+                        error!("{:?}", e);
+                        Err(e)
+                    }
+                }
+            })
+        });
+
+        join_all(tasks)
+            .await
+            .into_iter()
+            .map(|res| res.unwrap())
+            .collect()
     }
 
     async fn cancel_orders_all(&self) -> Result<Vec<Order<Cancelled>>, ExecutionError> {
-        todo!()
+        // Binance requires a symbol to cancel all open orders, so cancel per symbol
+        let orders = self
+            .client
+            .fetch_open_orders::<Vec<OpenOrderResponse>>()
+            .await?;
+
+        let symbols = orders
+            .iter()
+            .map(|order| order.symbol.as_str())
+            .collect::<HashSet<_>>();
+        for symbol in symbols {
+            self.client
+                .cancel_open_orders::<serde_json::Value>(symbol)
+                .await?;
+        }
+
+        Ok(orders
+            .into_iter()
+            .filter_map(|order| self.order_open(order))
+            .map(|order| Order {
+                exchange: order.exchange,
+                instrument: order.instrument,
+                cid: order.cid,
+                side: order.side,
+                state: Cancelled::from(order.state.id),
+            })
+            .collect())
+    }
+}
+
+impl BinanceExecution {
+    /// Map a Binance [`OpenOrderResponse`] to an [`Order<Open>`]. Returns `None` for orders with
+    /// an unknown symbol, or without a Barter [`ClientOrderId`].
+    fn order_open(&self, order: OpenOrderResponse) -> Option<Order<Open>> {
+        let Some(instrument) = self.instruments.get(&BinancePair(order.symbol.clone())) else {
+            warn!(symbol = %order.symbol, "ignoring open order with unknown Binance symbol");
+            return None;
+        };
+
+        let cid = Uuid::parse_str(&order.clientOrderId)
+            .map(ClientOrderId)
+            .ok()?;
+
+        Some(Order {
+            exchange: self.exchange(),
+            instrument: instrument.clone(),
+            cid,
+            side: order.side,
+            state: Open {
+                id: OrderId::from(order.orderId),
+                price: order.price,
+                quantity: order.origQty,
+                filled_quantity: order.executedQty,
+            },
+        })
     }
 }
 
@@ -124,3 +355,24 @@ impl BinancePair {
         Self(format!("{base}{quote}").to_uppercase())
     }
 }
+
+/// Map every spot & perpetual [`Instrument`] to the [`BinancePair`] symbol used by the Binance
+/// REST & user data stream APIs.
+pub(crate) fn instruments_by_pair(specs: &InstrumentSpecs) -> HashMap<BinancePair, Instrument> {
+    specs
+        .iter()
+        .filter(|spec| {
+            matches!(
+                spec.instrument.kind,
+                InstrumentKind::Spot | InstrumentKind::Perpetual
+            )
+        })
+        .map(|spec| {
+            let instrument = spec.instrument.clone();
+            (
+                BinancePair::new(&instrument.base, &instrument.quote),
+                instrument,
+            )
+        })
+        .collect()
+}
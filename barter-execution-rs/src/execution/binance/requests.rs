@@ -1,4 +1,8 @@
-use barter_integration::{model::instrument::symbol::Symbol, protocol::http::rest::ApiRequest};
+use barter_integration::{
+    model::{instrument::symbol::Symbol, Side},
+    protocol::http::{private::time_sync::ServerTime, rest::ApiRequest},
+};
+use rust_decimal::Decimal;
 use serde::Deserialize;
 
 use crate::model::balance::{Balance, SymbolBalance};
@@ -17,13 +21,13 @@ pub struct BalancesResponse(Vec<BinanceBalance>);
 struct BinanceBalance {
     asset: Symbol,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    free: f64,
+    free: Decimal,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    freeze: f64,
+    freeze: Decimal,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    withdrawing: f64,
+    withdrawing: Decimal,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    ipoable: f64,
+    ipoable: Decimal,
 }
 impl From<BinanceBalance> for SymbolBalance {
     fn from(balance: BinanceBalance) -> Self {
@@ -43,13 +47,73 @@ impl From<BalancesResponse> for Vec<SymbolBalance> {
     }
 }
 
+// SPOT ACCOUNT
+
+pub const SPOT_ACCOUNT_REQUEST: ApiRequest<SpotAccountResponse, ()> = ApiRequest::new(
+    "/api/v3/account",
+    reqwest::Method::GET,
+    "fetch_spot_account",
+)
+.with_weight(20);
+
+// {
+//     "makerCommission": 15,
+//     "canTrade": true,
+//     "accountType": "SPOT",
+//     "balances": [
+//         {
+//             "asset": "BTC",
+//             "free": "4723846.89208129",
+//             "locked": "0.00000000"
+//         }
+//     ],
+//     "permissions": ["SPOT"]
+// }
+#[derive(Debug, Deserialize)]
+pub struct SpotAccountResponse {
+    balances: Vec<SpotBalance>,
+}
+
+#[derive(Debug, Deserialize)]
+struct SpotBalance {
+    asset: Symbol,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    free: Decimal,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    locked: Decimal,
+}
+
+impl From<SpotBalance> for SymbolBalance {
+    fn from(balance: SpotBalance) -> Self {
+        Self {
+            symbol: balance.asset,
+            balance: Balance {
+                total: balance.free + balance.locked,
+                available: balance.free,
+            },
+        }
+    }
+}
+
+impl From<SpotAccountResponse> for Vec<SymbolBalance> {
+    fn from(account: SpotAccountResponse) -> Vec<SymbolBalance> {
+        account
+            .balances
+            .into_iter()
+            .filter(|balance| !(balance.free + balance.locked).is_zero())
+            .map(SymbolBalance::from)
+            .collect()
+    }
+}
+
 // FUTURES BALANCES ***NOTE*** api endpoint is different
 
 pub const FUT_BALANCES_REQUEST: ApiRequest<FutBalancesResponse, ()> = ApiRequest::new(
     "/fapi/v2/balance",
     reqwest::Method::GET,
     "fetch_fut_balances",
-);
+)
+.with_weight(5);
 
 #[derive(Debug, Deserialize)]
 pub struct FutBalancesResponse(Vec<FutBalance>);
@@ -60,15 +124,15 @@ pub struct FutBalance {
     accountAlias: String, // account alias
     asset: Symbol,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    balance: f64, // wallet balance
+    balance: Decimal, // wallet balance
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    crossWalletBalance: f64, // crossed wallet balance
+    crossWalletBalance: Decimal, // crossed wallet balance
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    crossUnPnl: f64, // unrealized profit of crossed positions
+    crossUnPnl: Decimal, // unrealized profit of crossed positions
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    availableBalance: f64, // available balance
+    availableBalance: Decimal, // available balance
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    maxWithdrawAmount: f64, // maximum amount for transfer out
+    maxWithdrawAmount: Decimal, // maximum amount for transfer out
     marginAvailable: bool, // whether the asset can be used as margin in Multi-Assets mode
     #[serde(deserialize_with = "barter_integration::de::de_str")]
     updateTime: u64,
@@ -97,7 +161,7 @@ impl From<FutBalancesResponse> for Vec<SymbolBalance> {
 pub const FUT_ORDER_REQUEST: ApiRequest<FutOrderResponse, FutOrderResponse> =
     ApiRequest::new("/fapi/v1/order", reqwest::Method::POST, "fut_order");
 
-// define order filled type for futures binance order with public fields and conversion to f64
+// define order filled type for futures binance order with public fields and conversion to Decimal
 // {
 //     "clientOrderId": "testOrder",
 //     "cumQty": "0",
@@ -131,18 +195,18 @@ pub const FUT_ORDER_REQUEST: ApiRequest<FutOrderResponse, FutOrderResponse> =
 pub struct FutOrderResponse {
     pub clientOrderId: String,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub cumQty: f64,
+    pub cumQty: Decimal,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub cumQuote: f64,
+    pub cumQuote: Decimal,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub executedQty: f64,
+    pub executedQty: Decimal,
     pub orderId: u64,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub avgPrice: f64,
+    pub avgPrice: Decimal,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub origQty: f64,
+    pub origQty: Decimal,
     #[serde(deserialize_with = "barter_integration::de::de_str")]
-    pub price: f64,
+    pub price: Decimal,
     pub reduceOnly: bool,
     pub side: String,
     pub positionSide: String,
@@ -162,3 +226,112 @@ pub struct FutOrderResponse {
     pub selfTradePreventionMode: String,
     pub goodTillDate: u64,
 }
+
+// SPOT ORDER
+
+// {
+//     "symbol": "BTCUSDT",
+//     "orderId": 28,
+//     "orderListId": -1,
+//     "clientOrderId": "6gCrw2kRUAF9CvJDGP16IP",
+//     "transactTime": 1507725176595,
+//     "price": "0.00000000",
+//     "origQty": "10.00000000",
+//     "executedQty": "10.00000000",
+//     "cummulativeQuoteQty": "10.00000000",
+//     "status": "FILLED",
+//     "timeInForce": "GTC",
+//     "type": "MARKET",
+//     "side": "SELL",
+//     "workingTime": 1507725176595,
+//     "selfTradePreventionMode": "NONE"
+// }
+#[derive(Debug, Deserialize)]
+#[allow(dead_code, non_snake_case)]
+pub struct SpotOrderResponse {
+    pub symbol: Symbol,
+    pub orderId: u64,
+    pub clientOrderId: String,
+    pub transactTime: u64,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: Decimal,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub origQty: Decimal,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub executedQty: Decimal,
+    pub status: String,
+    pub r#type: String,
+    pub side: String,
+}
+
+// CANCEL ORDER (SPOT & FUTURES)
+
+// {
+//     "symbol": "BTCUSDT",
+//     "origClientOrderId": "myOrder1",
+//     "orderId": 4,
+//     "clientOrderId": "cancelMyOrder1",
+//     "status": "CANCELED",
+//     "side": "BUY"
+// }
+#[derive(Debug, Deserialize)]
+#[allow(dead_code, non_snake_case)]
+pub struct CancelOrderResponse {
+    pub symbol: Symbol,
+    pub orderId: u64,
+    pub status: String,
+}
+
+// OPEN ORDERS (SPOT & FUTURES)
+
+// [
+//     {
+//         "symbol": "LTCBTC",
+//         "orderId": 1,
+//         "clientOrderId": "myOrder1",
+//         "price": "0.1",
+//         "origQty": "1.0",
+//         "executedQty": "0.0",
+//         "status": "NEW",
+//         "timeInForce": "GTC",
+//         "type": "LIMIT",
+//         "side": "BUY"
+//     }
+// ]
+#[derive(Debug, Deserialize)]
+#[allow(non_snake_case)]
+pub struct OpenOrderResponse {
+    pub symbol: String,
+    pub orderId: u64,
+    pub clientOrderId: String,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: Decimal,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub origQty: Decimal,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub executedQty: Decimal,
+    pub side: Side,
+}
+
+// SERVER TIME (SPOT & FUTURES)
+
+pub const SPOT_TIME_REQUEST: ApiRequest<ServerTimeResponse, ()> =
+    ApiRequest::new("/api/v3/time", reqwest::Method::GET, "fetch_spot_time");
+
+pub const FUT_TIME_REQUEST: ApiRequest<ServerTimeResponse, ()> =
+    ApiRequest::new("/fapi/v1/time", reqwest::Method::GET, "fetch_fut_time");
+
+// {
+//     "serverTime": 1499827319559
+// }
+#[derive(Debug, Copy, Clone, Deserialize)]
+#[allow(non_snake_case)]
+pub struct ServerTimeResponse {
+    pub serverTime: i64,
+}
+
+impl ServerTime for ServerTimeResponse {
+    fn server_time_millis(&self) -> i64 {
+        self.serverTime
+    }
+}
@@ -1,79 +1,703 @@
+use super::{
+    connection::{BinanceApi, BinanceClient, LiveOrTest},
+    instruments_by_pair, BinancePair,
+};
+use crate::{
+    error::ExecutionError,
+    model::{
+        balance::{Balance, SymbolBalance, SymbolBalanceTotal},
+        order::{Cancelled, Open, Order, OrderId},
+        trade::{SymbolFees, Trade, TradeId},
+        AccountEvent, AccountEventKind, ClientOrderId,
+    },
+    ExecutionId,
+};
+use barter_data::exchange::binance::futures::account::BinanceAccountUpdate;
+use barter_integration::{
+    error::SocketError,
+    model::{
+        instrument::{spec::InstrumentSpecs, symbol::Symbol, Instrument},
+        Exchange, Side,
+    },
+};
+use chrono::Utc;
 use futures::StreamExt;
 use reqwest::Client;
-use tokio::{net::TcpStream, spawn};
-use tokio_tungstenite::{connect_async, MaybeTlsStream, WebSocketStream};
-use tracing::{error, info};
-use url::Url;
-
-pub async fn init_listener(api_key: &str, api_url: &str) {
-    let listen_key = get_listen_key(api_key, api_url)
-        .await
-        .expect("Failed to get listen key");
-    let stream_url = format!("wss://fstream.binancefuture.com/ws/{}", listen_key);
-
-    // TODO:
-    // PUT /fapi/v1/listenKey
-    // Keepalive a user data stream to prevent a time out.
-    // User data streams will close after 60 minutes.
-    // It's recommended to send a ping about every 60 minutes.
-    // If response -1125 error "This listenKey does not exist."
-    // Please use POST /fapi/v1/listenKey to recreate listenKey and use new listenKey to build connection.
-
-    // let mut interval = interval(Duration::from_secs(60 * 30)); // Renew every 30 minutes
-    // loop {
-    //     interval.tick().await;
-
-    spawn(async move {
-        if let Err(e) = listen_to_user_data_stream(&stream_url).await {
-            error!("Error listening to user data stream: {}", e);
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, time::Duration};
+use tokio::{sync::mpsc, task::JoinHandle};
+use tokio_tungstenite::{connect_async, tungstenite::Message as WsMessage};
+use tracing::{debug, error, info, warn};
+use uuid::Uuid;
+
+/// Interval between listenKey keepalive requests. Binance closes user data streams after 60
+/// minutes without a keepalive, and recommends sending one every 30 minutes.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#keepalive-user-data-stream-user_stream>
+pub const LISTEN_KEY_KEEPALIVE_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+/// Initial delay before re-connecting a failed [`UserDataStream`] session. Doubles after each
+/// consecutive failure up to [`RECONNECT_BACKOFF_MAX`].
+const RECONNECT_BACKOFF_INITIAL: Duration = Duration::from_secs(1);
+
+/// Maximum delay before re-connecting a failed [`UserDataStream`] session.
+const RECONNECT_BACKOFF_MAX: Duration = Duration::from_secs(60);
+
+/// Supervised Binance user data stream that translates exchange account messages into
+/// [`AccountEvent`]s.
+///
+/// Each session creates a listenKey, connects to the user data WebSocket, and keeps the listenKey
+/// alive every [`LISTEN_KEY_KEEPALIVE_INTERVAL`]. When a session ends (disconnect, expired
+/// listenKey, failed keepalive), a new session is started with an exponential backoff. The
+/// stream stops once the [`AccountEvent`] receiver is dropped.
+#[derive(Debug, Clone)]
+pub struct UserDataStream {
+    api_type: BinanceApi,
+    api_key: String,
+    exchange: Exchange,
+    http: Client,
+    instruments: HashMap<BinancePair, Instrument>,
+    event_tx: mpsc::UnboundedSender<AccountEvent>,
+}
+
+/// Outcome of a single [`UserDataStream`] session.
+enum Session {
+    /// Session ended and a new one should be started (eg/ disconnect, listenKey expired).
+    Reconnect,
+    /// [`AccountEvent`] receiver dropped, so the [`UserDataStream`] should stop.
+    Terminate,
+}
+
+impl UserDataStream {
+    /// Construct a new [`UserDataStream`]. The provided [`InstrumentSpecs`] are used to map
+    /// Binance symbols (eg/ "BTCUSDT") back to the associated [`Instrument`].
+    pub fn new(
+        api_type: BinanceApi,
+        api_key: String,
+        specs: &InstrumentSpecs,
+        event_tx: mpsc::UnboundedSender<AccountEvent>,
+    ) -> Self {
+        Self {
+            api_type,
+            api_key,
+            exchange: Exchange::from(ExecutionId::from(api_type)),
+            http: Client::new(),
+            instruments: instruments_by_pair(specs),
+            event_tx,
+        }
+    }
+
+    /// Spawn the supervised user data stream task.
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move { self.run().await })
+    }
+
+    /// Run user data stream sessions until the [`AccountEvent`] receiver is dropped.
+    pub async fn run(self) {
+        let mut backoff = RECONNECT_BACKOFF_INITIAL;
+        loop {
+            match self.session().await {
+                Ok(Session::Terminate) => {
+                    info!("AccountEvent receiver dropped, stopping Binance user data stream");
+                    break;
+                }
+                Ok(Session::Reconnect) => {
+                    info!("Binance user data stream session ended, reconnecting");
+                    backoff = RECONNECT_BACKOFF_INITIAL;
+                    tokio::time::sleep(backoff).await;
+                }
+                Err(error) => {
+                    error!(
+                        ?error,
+                        ?backoff,
+                        "Binance user data stream failed, reconnecting"
+                    );
+                    tokio::time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(RECONNECT_BACKOFF_MAX);
+                }
+            }
+        }
+    }
+
+    /// Run a single user data stream session: create a listenKey, connect, and consume messages
+    /// until the connection ends or the listenKey can no longer be kept alive.
+    async fn session(&self) -> Result<Session, ExecutionError> {
+        let listen_key = self.create_listen_key().await?;
+        let stream_url = format!("{}/{}", Self::websocket_url(self.api_type), listen_key);
+
+        let (mut websocket, _) = connect_async(stream_url)
+            .await
+            .map_err(SocketError::WebSocket)?;
+        info!(api = ?self.api_type, "connected to Binance user data stream");
+
+        let mut keepalive = tokio::time::interval(LISTEN_KEY_KEEPALIVE_INTERVAL);
+        keepalive.tick().await;
+
+        loop {
+            tokio::select! {
+                _ = keepalive.tick() => {
+                    self.keepalive_listen_key(&listen_key).await?;
+                    debug!("kept alive Binance user data stream listenKey");
+                }
+                message = websocket.next() => match message {
+                    Some(Ok(WsMessage::Text(payload))) => {
+                        match BinanceUserData::parse(&payload) {
+                            Ok(BinanceUserData::ListenKeyExpired) => {
+                                warn!("Binance user data stream listenKey expired");
+                                return Ok(Session::Reconnect);
+                            }
+                            Ok(user_data) => {
+                                for kind in user_data.account_events(&self.exchange, &self.instruments) {
+                                    let event = AccountEvent {
+                                        received_time: Utc::now(),
+                                        exchange: self.exchange.clone(),
+                                        kind,
+                                    };
+                                    if self.event_tx.send(event).is_err() {
+                                        return Ok(Session::Terminate);
+                                    }
+                                }
+                            }
+                            Err(error) => {
+                                error!(?error, "failed to parse Binance user data message");
+                            }
+                        }
+                    }
+                    Some(Ok(WsMessage::Close(frame))) => {
+                        warn!(?frame, "Binance user data stream closed");
+                        return Ok(Session::Reconnect);
+                    }
+                    Some(Ok(_)) => {}
+                    Some(Err(error)) => return Err(SocketError::WebSocket(error).into()),
+                    None => return Ok(Session::Reconnect),
+                },
+            }
+        }
+    }
+
+    /// Create a new listenKey for the user data stream.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#start-user-data-stream-user_stream>
+    async fn create_listen_key(&self) -> Result<String, ExecutionError> {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct ListenKey {
+            listen_key: String,
+        }
+
+        let response = self
+            .http
+            .post(Self::listen_key_url(self.api_type))
+            .header("X-MBX-APIKEY", &self.api_key)
+            .send()
+            .await
+            .map_err(SocketError::from)?;
+
+        Self::check_status(response)
+            .await?
+            .json::<ListenKey>()
+            .await
+            .map(|ListenKey { listen_key }| listen_key)
+            .map_err(|error| SocketError::from(error).into())
+    }
+
+    /// Extend the validity of the listenKey by 60 minutes. Fails if the listenKey no longer exists
+    /// (error -1125), in which case a new session must be started.
+    ///
+    /// See docs: <https://binance-docs.github.io/apidocs/futures/en/#keepalive-user-data-stream-user_stream>
+    async fn keepalive_listen_key(&self, listen_key: &str) -> Result<(), ExecutionError> {
+        let response = self
+            .http
+            .put(Self::listen_key_url(self.api_type))
+            .header("X-MBX-APIKEY", &self.api_key)
+            .query(&[("listenKey", listen_key)])
+            .send()
+            .await
+            .map_err(SocketError::from)?;
+
+        Self::check_status(response).await.map(|_| ())
+    }
+
+    async fn check_status(
+        response: reqwest::Response,
+    ) -> Result<reqwest::Response, ExecutionError> {
+        match response.status() {
+            status if status.is_success() => Ok(response),
+            status => {
+                let body = response.text().await.unwrap_or_default();
+                Err(SocketError::HttpResponse(status, body).into())
+            }
+        }
+    }
+
+    /// Return the REST url used to create & keepalive listenKeys.
+    pub fn listen_key_url(api_type: BinanceApi) -> String {
+        let path = match api_type {
+            BinanceApi::Spot(_) => "/api/v3/userDataStream",
+            BinanceApi::Futures(_) => "/fapi/v1/listenKey",
+        };
+        format!("{}{}", BinanceClient::get_url(api_type), path)
+    }
+
+    /// Return the base WebSocket url of the user data stream.
+    pub fn websocket_url(api_type: BinanceApi) -> &'static str {
+        match api_type {
+            BinanceApi::Spot(LiveOrTest::Live) => "wss://stream.binance.com:9443/ws",
+            BinanceApi::Spot(LiveOrTest::Test) => "wss://testnet.binance.vision/ws",
+            BinanceApi::Futures(LiveOrTest::Live) => "wss://fstream.binance.com/ws",
+            BinanceApi::Futures(LiveOrTest::Test) => "wss://fstream.binancefuture.com/ws",
+        }
+    }
+}
+
+/// Binance user data stream messages that are translated into [`AccountEvent`]s.
+#[derive(Clone, PartialEq, Debug)]
+pub enum BinanceUserData {
+    /// [`BinanceApi::Futures`] order update.
+    OrderTradeUpdate(BinanceOrderUpdate),
+    /// [`BinanceApi::Futures`] balance & position update.
+    AccountUpdate(BinanceAccountUpdate),
+    /// [`BinanceApi::Spot`] order update.
+    ExecutionReport(BinanceOrderUpdate),
+    /// [`BinanceApi::Spot`] balance update.
+    AccountPosition(BinanceAccountPosition),
+    /// listenKey has expired and the stream must be re-created.
+    ListenKeyExpired,
+    /// Message not translated into [`AccountEvent`]s (eg/ MARGIN_CALL, balanceUpdate).
+    Other(String),
+}
+
+impl BinanceUserData {
+    /// Parse a raw user data stream message using it's "e" event type.
+    pub fn parse(payload: &str) -> Result<Self, SocketError> {
+        #[derive(Deserialize)]
+        struct EventType<'a> {
+            #[serde(rename = "e", borrow)]
+            kind: &'a str,
         }
-    });
+
+        #[derive(Deserialize)]
+        struct FuturesOrderUpdate {
+            #[serde(rename = "o")]
+            order: BinanceOrderUpdate,
+        }
+
+        let deserialise = |error| SocketError::Deserialise {
+            error,
+            payload: payload.to_owned(),
+        };
+
+        match serde_json::from_str::<EventType<'_>>(payload)
+            .map_err(deserialise)?
+            .kind
+        {
+            "ORDER_TRADE_UPDATE" => serde_json::from_str::<FuturesOrderUpdate>(payload)
+                .map(|update| Self::OrderTradeUpdate(update.order)),
+            "ACCOUNT_UPDATE" => serde_json::from_str(payload).map(Self::AccountUpdate),
+            "executionReport" => serde_json::from_str(payload).map(Self::ExecutionReport),
+            "outboundAccountPosition" => serde_json::from_str(payload).map(Self::AccountPosition),
+            "listenKeyExpired" => Ok(Self::ListenKeyExpired),
+            other => Ok(Self::Other(other.to_owned())),
+        }
+        .map_err(deserialise)
+    }
+
+    /// Translate this message into the associated [`AccountEventKind`]s, using the provided
+    /// [`BinancePair`] to [`Instrument`] map to identify order & trade instruments.
+    pub fn account_events(
+        self,
+        exchange: &Exchange,
+        instruments: &HashMap<BinancePair, Instrument>,
+    ) -> Vec<AccountEventKind> {
+        match self {
+            Self::OrderTradeUpdate(update) | Self::ExecutionReport(update) => {
+                match instruments.get(&BinancePair(update.symbol.to_uppercase())) {
+                    Some(instrument) => update.account_events(exchange, instrument),
+                    None => {
+                        warn!(symbol = %update.symbol, "received order update for unknown Binance symbol");
+                        vec![]
+                    }
+                }
+            }
+            Self::AccountUpdate(update) => {
+                // Wallet balance is the total balance - the cross wallet balance excludes
+                // isolated margin & is not the available balance, so that is left unchanged
+                let totals = update
+                    .update_data
+                    .balance_updates
+                    .into_iter()
+                    .map(|balance| SymbolBalanceTotal::new(balance.asset, balance.wallet_balance))
+                    .collect();
+                vec![AccountEventKind::BalanceTotals(totals)]
+            }
+            Self::AccountPosition(position) => {
+                let balances = position
+                    .balances
+                    .into_iter()
+                    .map(|balance| {
+                        SymbolBalance::new(
+                            balance.asset,
+                            Balance::new(balance.free + balance.locked, balance.free),
+                        )
+                    })
+                    .collect();
+                vec![AccountEventKind::Balances(balances)]
+            }
+            Self::ListenKeyExpired | Self::Other(_) => vec![],
+        }
+    }
+}
+
+/// Binance order update, common to both the [`BinanceApi::Futures`] "ORDER_TRADE_UPDATE" (nested
+/// in "o") and the [`BinanceApi::Spot`] "executionReport" messages.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/futures/en/#event-order-update>
+/// ```json
+/// {
+///     "e": "ORDER_TRADE_UPDATE",
+///     "E": 1568879465651,
+///     "T": 1568879465650,
+///     "o": {
+///         "s": "BTCUSDT",
+///         "c": "b7c8ca81-08a7-4f8f-a3a2-4cf8a8c6e8f0",
+///         "S": "SELL",
+///         "o": "LIMIT",
+///         "f": "GTC",
+///         "q": "0.001",
+///         "p": "9910",
+///         "x": "TRADE",
+///         "X": "FILLED",
+///         "i": 8886774,
+///         "l": "0.001",
+///         "z": "0.001",
+///         "L": "9910",
+///         "N": "USDT",
+///         "n": "0.00198200",
+///         "T": 1568879465650,
+///         "t": 12345
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceOrderUpdate {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+    /// Original client order id of a cancelled [`BinanceApi::Spot`] order.
+    #[serde(rename = "C", default)]
+    pub original_client_order_id: Option<String>,
+    #[serde(rename = "S")]
+    pub side: Side,
+    #[serde(rename = "q")]
+    pub quantity: Decimal,
+    #[serde(rename = "p")]
+    pub price: Decimal,
+    #[serde(rename = "x")]
+    pub execution_type: BinanceExecutionType,
+    #[serde(rename = "i")]
+    pub order_id: u64,
+    #[serde(rename = "l")]
+    pub last_filled_quantity: Decimal,
+    #[serde(rename = "z")]
+    pub filled_quantity: Decimal,
+    #[serde(rename = "L")]
+    pub last_filled_price: Decimal,
+    #[serde(rename = "N", default)]
+    pub commission_asset: Option<Symbol>,
+    #[serde(rename = "n", default)]
+    pub commission: Option<Decimal>,
+    #[serde(rename = "t")]
+    pub trade_id: i64,
+}
+
+/// Binance order update execution type.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#public-api-definitions>
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BinanceExecutionType {
+    New,
+    Trade,
+    Canceled,
+    Expired,
+    #[serde(other)]
+    Other,
+}
+
+impl BinanceOrderUpdate {
+    /// Translate this order update into the associated [`AccountEventKind`]s.
+    ///
+    /// Order state events are only generated for orders with a Barter [`ClientOrderId`], whereas
+    /// [`Trade`]s are generated for every fill.
+    pub fn account_events(
+        self,
+        exchange: &Exchange,
+        instrument: &Instrument,
+    ) -> Vec<AccountEventKind> {
+        let client_order_id = match self.execution_type {
+            BinanceExecutionType::Canceled => self
+                .original_client_order_id
+                .as_deref()
+                .filter(|original| !original.is_empty())
+                .unwrap_or(&self.client_order_id),
+            _ => &self.client_order_id,
+        };
+        let cid = Uuid::parse_str(client_order_id).ok().map(ClientOrderId);
+
+        match (self.execution_type, cid) {
+            (BinanceExecutionType::New, Some(cid)) => {
+                vec![AccountEventKind::OrdersNew(vec![Order {
+                    exchange: exchange.clone(),
+                    instrument: instrument.clone(),
+                    cid,
+                    side: self.side,
+                    state: Open {
+                        id: OrderId::from(self.order_id),
+                        price: self.price,
+                        quantity: self.quantity,
+                        filled_quantity: self.filled_quantity,
+                    },
+                }])]
+            }
+            (BinanceExecutionType::Canceled | BinanceExecutionType::Expired, Some(cid)) => {
+                vec![AccountEventKind::OrdersCancelled(vec![Order {
+                    exchange: exchange.clone(),
+                    instrument: instrument.clone(),
+                    cid,
+                    side: self.side,
+                    state: Cancelled::from(self.order_id),
+                }])]
+            }
+            (BinanceExecutionType::Trade, _) => {
+                let fees = match self.commission_asset {
+                    Some(asset) => SymbolFees::new(asset, self.commission.unwrap_or_default()),
+                    None => SymbolFees::new(instrument.quote.clone(), Decimal::ZERO),
+                };
+
+                vec![AccountEventKind::Trade(Trade {
+                    id: TradeId::from(self.trade_id.to_string()),
+                    order_id: OrderId::from(self.order_id),
+                    instrument: instrument.clone(),
+                    side: self.side,
+                    price: self.last_filled_price,
+                    quantity: self.last_filled_quantity,
+                    fees,
+                })]
+            }
+            _ => vec![],
+        }
+    }
 }
 
-async fn get_listen_key(
-    api_key: &str,
-    api_url: &str,
-) -> Result<String, Box<dyn std::error::Error>> {
-    let client = Client::new();
-    let res = client
-        .post(format!("{api_url}/fapi/v1/listenKey"))
-        .header("X-MBX-APIKEY", api_key)
-        .send()
-        .await?
-        .json::<serde_json::Value>()
-        .await?;
-    info!("Got Listener Key from Binance: {:?}", res);
-    Ok(res["listenKey"].as_str().unwrap().to_string())
+/// [`BinanceApi::Spot`] "outboundAccountPosition" balance update message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#account-update>
+/// ```json
+/// {
+///     "e": "outboundAccountPosition",
+///     "E": 1564034571105,
+///     "u": 1564034571073,
+///     "B": [
+///         {"a": "ETH", "f": "10000.000000", "l": "0.000000"}
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceAccountPosition {
+    #[serde(rename = "B")]
+    pub balances: Vec<BinanceAssetBalance>,
 }
 
-// async fn return_ws_stream(stream_url: &str) -> WebSocketStream<MaybeTlsStream<TcpStream>> {
-//     let ws_stream = connect_async(Url::parse(stream_url).expect("Failed to parse url"))
-//         .await
-//         .map(|(websocket, _)| websocket)
-//         .expect("Failed to conenct");
-
-//     info!("Connected to Binance user data stream");
-//     println!("stream_url: {}", stream_url);
-//     ws_stream
-// }
-
-async fn listen_to_user_data_stream(stream_url: &str) -> Result<(), Box<dyn std::error::Error>> {
-    let ws_stream = connect_async(Url::parse(stream_url)?)
-        .await
-        .map(|(websocket, _)| websocket)
-        .expect("Failed to conenct");
-
-    info!("Connected to Binance user data stream");
-    println!("stream_url: {}", stream_url);
-    let (write, read) = ws_stream.split();
-
-    // TODO track account state here
-    // update account state on diff and send to account state manager
-    read.for_each(|message| async {
-        if let Ok(msg) = message {
-            info!("Received message from Binance: {}", msg.to_text().unwrap());
+/// [`BinanceApi::Spot`] asset balance.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct BinanceAssetBalance {
+    #[serde(rename = "a")]
+    pub asset: Symbol,
+    #[serde(rename = "f")]
+    pub free: Decimal,
+    #[serde(rename = "l")]
+    pub locked: Decimal,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::model::instrument::{kind::InstrumentKind, spec::InstrumentSpec};
+    use rust_decimal_macros::dec;
+
+    const CID: &str = "b7c8ca81-08a7-4f8f-a3a2-4cf8a8c6e8f0";
+
+    fn instruments() -> HashMap<BinancePair, Instrument> {
+        let specs = InstrumentSpecs::from(vec![InstrumentSpec::new(
+            ("btc", "usdt", InstrumentKind::Perpetual),
+            dec!(0.1),
+            dec!(0.001),
+        )]);
+        instruments_by_pair(&specs)
+    }
+
+    fn exchange() -> Exchange {
+        Exchange::from(ExecutionId::BinanceFuturesUsd)
+    }
+
+    fn btc_usdt() -> Instrument {
+        Instrument::from(("btc", "usdt", InstrumentKind::Perpetual))
+    }
+
+    fn futures_order_update(execution_type: &str, status: &str) -> String {
+        format!(
+            r#"{{
+                "e": "ORDER_TRADE_UPDATE", "E": 1568879465651, "T": 1568879465650,
+                "o": {{
+                    "s": "BTCUSDT", "c": "{CID}", "S": "SELL", "o": "LIMIT", "f": "GTC",
+                    "q": "0.002", "p": "9910", "ap": "0", "sp": "0", "x": "{execution_type}",
+                    "X": "{status}", "i": 8886774, "l": "0.001", "z": "0.001", "L": "9910",
+                    "N": "USDT", "n": "0.00198200", "T": 1568879465650, "t": 12345,
+                    "b": "0", "a": "9.91", "m": false, "R": false, "wt": "CONTRACT_PRICE",
+                    "ot": "LIMIT", "ps": "BOTH", "cp": false, "rp": "0"
+                }}
+            }}"#
+        )
+    }
+
+    #[test]
+    fn test_binance_user_data_account_events() {
+        struct TestCase {
+            input: String,
+            expected: Vec<AccountEventKind>,
         }
-    })
-    .await;
-    Ok(())
+
+        let cid = ClientOrderId(Uuid::parse_str(CID).unwrap());
+
+        let tests = vec![
+            TestCase {
+                // TC0: futures ORDER_TRADE_UPDATE NEW -> OrdersNew
+                input: futures_order_update("NEW", "NEW"),
+                expected: vec![AccountEventKind::OrdersNew(vec![Order {
+                    exchange: Exchange::from(ExecutionId::BinanceFuturesUsd),
+                    instrument: btc_usdt(),
+                    cid,
+                    side: Side::Sell,
+                    state: Open {
+                        id: OrderId::from(8886774),
+                        price: dec!(9910),
+                        quantity: dec!(0.002),
+                        filled_quantity: dec!(0.001),
+                    },
+                }])],
+            },
+            TestCase {
+                // TC1: futures ORDER_TRADE_UPDATE TRADE -> Trade
+                input: futures_order_update("TRADE", "PARTIALLY_FILLED"),
+                expected: vec![AccountEventKind::Trade(Trade {
+                    id: TradeId::from("12345"),
+                    order_id: OrderId::from(8886774),
+                    instrument: btc_usdt(),
+                    side: Side::Sell,
+                    price: dec!(9910),
+                    quantity: dec!(0.001),
+                    fees: SymbolFees::new("usdt", dec!(0.001982)),
+                })],
+            },
+            TestCase {
+                // TC2: futures ORDER_TRADE_UPDATE CANCELED -> OrdersCancelled
+                input: futures_order_update("CANCELED", "CANCELED"),
+                expected: vec![AccountEventKind::OrdersCancelled(vec![Order {
+                    exchange: Exchange::from(ExecutionId::BinanceFuturesUsd),
+                    instrument: btc_usdt(),
+                    cid,
+                    side: Side::Sell,
+                    state: Cancelled::from(8886774),
+                }])],
+            },
+            TestCase {
+                // TC3: futures ACCOUNT_UPDATE -> BalanceTotals
+                input: r#"{
+                    "e": "ACCOUNT_UPDATE", "E": 1564745798939, "T": 1564745798938,
+                    "a": {
+                        "m": "ORDER",
+                        "B": [{"a": "USDT", "wb": "122624.12345678", "cw": "100.12345678", "bc": "50.12345678"}],
+                        "P": []
+                    }
+                }"#
+                .to_string(),
+                expected: vec![AccountEventKind::BalanceTotals(vec![SymbolBalanceTotal::new(
+                    "usdt",
+                    dec!(122624.12345678),
+                )])],
+            },
+            TestCase {
+                // TC4: spot outboundAccountPosition -> Balances
+                input: r#"{
+                    "e": "outboundAccountPosition", "E": 1564034571105, "u": 1564034571073,
+                    "B": [{"a": "ETH", "f": "10000.000000", "l": "5.000000"}]
+                }"#
+                .to_string(),
+                expected: vec![AccountEventKind::Balances(vec![SymbolBalance::new(
+                    "eth",
+                    Balance::new(dec!(10005), dec!(10000)),
+                )])],
+            },
+            TestCase {
+                // TC5: order update for unknown symbol is ignored
+                input: futures_order_update("NEW", "NEW").replace("BTCUSDT", "ETHUSDT"),
+                expected: vec![],
+            },
+            TestCase {
+                // TC6: untranslated message type is ignored
+                input: r#"{"e": "MARGIN_CALL", "E": 1587727187525, "cw": "3.16812045", "p": []}"#
+                    .to_string(),
+                expected: vec![],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = BinanceUserData::parse(&test.input)
+                .unwrap()
+                .account_events(&exchange(), &instruments());
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_binance_user_data_parse_listen_key_expired() {
+        let input = r#"{"e": "listenKeyExpired", "E": 1576653824250, "listenKey": "WsCMN0a4"}"#;
+        assert_eq!(
+            BinanceUserData::parse(input).unwrap(),
+            BinanceUserData::ListenKeyExpired
+        );
+    }
+
+    #[test]
+    fn test_binance_spot_execution_report_cancelled_uses_original_cid() {
+        let input = format!(
+            r#"{{
+                "e": "executionReport", "E": 1499405658658, "s": "BTCUSDT",
+                "c": "web_cancel_request", "S": "BUY", "o": "LIMIT", "f": "GTC",
+                "q": "1.00000000", "p": "0.10264410", "P": "0.00000000", "F": "0.00000000",
+                "g": -1, "C": "{CID}", "x": "CANCELED", "X": "CANCELED", "r": "NONE",
+                "i": 4293153, "l": "0.00000000", "z": "0.00000000", "L": "0.00000000",
+                "n": "0", "N": null, "T": 1499405658657, "t": -1
+            }}"#
+        );
+
+        let actual = BinanceUserData::parse(&input)
+            .unwrap()
+            .account_events(&exchange(), &instruments());
+
+        assert_eq!(
+            actual,
+            vec![AccountEventKind::OrdersCancelled(vec![Order {
+                exchange: Exchange::from(ExecutionId::BinanceFuturesUsd),
+                instrument: btc_usdt(),
+                cid: ClientOrderId(Uuid::parse_str(CID).unwrap()),
+                side: Side::Buy,
+                state: Cancelled::from(4293153),
+            }])]
+        );
+    }
 }
@@ -0,0 +1,824 @@
+use std::{fmt::Debug, marker::PhantomData, time::Duration};
+
+use barter_integration::{
+    error::SocketError,
+    metric::registry::MetricRegistry,
+    model::{instrument::kind::InstrumentKind, Side},
+    protocol::http::{
+        private::{
+            encoder::HexEncoder,
+            get_default_signer,
+            time_sync::{TimeOffset, TimeSync},
+            RequestSigner, Signer,
+        },
+        public::PublicNoHeaders,
+        rest::{
+            client::RestClient,
+            rate_limit::{RateLimit, RateLimitKind, RateLimiter},
+            ApiRequest, QueryParams, RestRequest,
+        },
+        HttpParser,
+    },
+};
+use bytes::Bytes;
+use hmac::Hmac;
+use reqwest::{RequestBuilder, StatusCode};
+use serde::Deserialize;
+
+use super::{
+    requests::{
+        bybit_error, BybitAmendOrder, BybitBatchRequest, BybitCancelAll, BybitCancelOrder,
+        BybitCategoryRequest, BybitList, BybitOrder, BybitOrderAck, BybitPlaceOrder, BybitPosition,
+        BybitResponse, BybitServerTime, BybitWallet, TIME_REQUEST,
+    },
+    BybitSymbol,
+};
+use crate::{
+    error::ExecutionError,
+    execution::binance::connection::LiveOrTest,
+    model::{
+        balance::SymbolBalance,
+        order::{Order, OrderKind, RequestAmend, RequestCancel, RequestOpen},
+    },
+    ExecutionId,
+};
+
+/// Bybit live REST base url.
+pub const BYBIT_BASE_URL: &str = "https://api.bybit.com";
+
+/// Bybit testnet REST base url.
+pub const BYBIT_TESTNET_BASE_URL: &str = "https://api-testnet.bybit.com";
+
+/// Default [`Duration`] a signed request must be received within.
+pub const DEFAULT_RECV_WINDOW: Duration = Duration::from_millis(5000);
+
+/// Bybit v5 product category traded by a [`BybitClient`].
+#[derive(Debug, Copy, Clone)]
+pub enum BybitApi {
+    Spot(LiveOrTest),
+    Perpetual(LiveOrTest),
+}
+
+impl From<BybitApi> for ExecutionId {
+    fn from(api_type: BybitApi) -> Self {
+        match api_type {
+            BybitApi::Spot(_) => ExecutionId::BybitSpot,
+            BybitApi::Perpetual(_) => ExecutionId::BybitPerpetualsUsd,
+        }
+    }
+}
+
+impl BybitApi {
+    /// Bybit v5 `category` of this [`BybitApi`].
+    pub fn category(&self) -> &'static str {
+        match self {
+            BybitApi::Spot(_) => "spot",
+            BybitApi::Perpetual(_) => "linear",
+        }
+    }
+
+    /// [`LiveOrTest`] environment of this [`BybitApi`].
+    pub fn kind(&self) -> LiveOrTest {
+        match self {
+            BybitApi::Spot(kind) | BybitApi::Perpetual(kind) => *kind,
+        }
+    }
+
+    /// Determines if the provided [`InstrumentKind`] is traded via this [`BybitApi`].
+    pub fn trades(&self, kind: &InstrumentKind) -> bool {
+        matches!(
+            (self, kind),
+            (BybitApi::Spot(_), InstrumentKind::Spot)
+                | (BybitApi::Perpetual(_), InstrumentKind::Perpetual)
+        )
+    }
+}
+
+pub type BybitInternalClient =
+    RestClient<RequestSigner<BybitSigner, Hmac<sha2::Sha256>, HexEncoder>, BybitParser>;
+
+/// [`TimeSync`] service that keeps a [`BybitClient`] [`TimeOffset`] synchronised with the Bybit
+/// server time.
+pub type BybitTimeSync =
+    TimeSync<PublicNoHeaders, BybitParser, ApiRequest<BybitResponse<BybitServerTime>, ()>>;
+
+/// Bybit API key & secret.
+#[derive(Clone)]
+pub struct BybitCredentials {
+    pub api_key: String,
+    pub secret: String,
+}
+
+impl Debug for BybitCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("BybitCredentials")
+            .field("api_key", &self.api_key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl BybitCredentials {
+    pub fn new<S>(api_key: S, secret: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            api_key: api_key.into(),
+            secret: secret.into(),
+        }
+    }
+
+    /// Load [`BybitCredentials`] from the "BYBIT_API_KEY" & "BYBIT_SECRET" environment
+    /// variables, or their "BYBIT_TEST_*" equivalents for the testnet.
+    pub fn from_env(kind: LiveOrTest) -> Self {
+        let prefix = match kind {
+            LiveOrTest::Live => "BYBIT",
+            LiveOrTest::Test => "BYBIT_TEST",
+        };
+        let var = |name: &str| {
+            let key = format!("{prefix}_{name}");
+            std::env::var(&key).unwrap_or_else(|_| panic!("{key} must be set."))
+        };
+
+        Self::new(var("API_KEY"), var("SECRET"))
+    }
+}
+
+/// Bybit v5 REST client trading a single [`BybitApi`] category. Http request duration
+/// [`Metric`](barter_integration::metric::Metric)s are aggregated by the
+/// [`MetricRegistry::global`] registry, so a [`BybitClient`] must be constructed from within a
+/// tokio runtime.
+#[derive(Debug, Clone)]
+pub struct BybitClient {
+    pub client: BybitInternalClient,
+    pub api: BybitApi,
+    /// Server time offset shared with the [`BybitSigner`], updated by a [`BybitTimeSync`].
+    pub time_offset: TimeOffset,
+}
+
+impl BybitClient {
+    pub fn new(api: BybitApi, credentials: BybitCredentials) -> Self {
+        Self::build_client(api, credentials, Self::get_url(api).to_string(), None)
+    }
+
+    pub fn new_with_recv_window(
+        api: BybitApi,
+        credentials: BybitCredentials,
+        recv_window: Duration,
+    ) -> Self {
+        Self::build_client(
+            api,
+            credentials,
+            Self::get_url(api).to_string(),
+            Some(recv_window),
+        )
+    }
+
+    pub fn new_with_url(api: BybitApi, credentials: BybitCredentials, url: String) -> Self {
+        Self::build_client(api, credentials, url, None)
+    }
+
+    fn build_client(
+        api: BybitApi,
+        credentials: BybitCredentials,
+        url: String,
+        recv_window: Option<Duration>,
+    ) -> Self {
+        // Send Http execution metrics over the Metric channel shared by every client, aggregated
+        // by the global MetricRegistry
+        let http_metric_tx = MetricRegistry::global_sender();
+
+        let time_offset = TimeOffset::new();
+        let request_signer = get_default_signer(
+            &credentials.secret,
+            BybitSigner {
+                api_key: credentials.api_key,
+                time_offset: time_offset.clone(),
+                recv_window: recv_window.unwrap_or(DEFAULT_RECV_WINDOW),
+            },
+        );
+
+        let client = RestClient::new(url, http_metric_tx, request_signer, BybitParser)
+            .with_rate_limiter(Self::rate_limiter());
+
+        Self {
+            client,
+            api,
+            time_offset,
+        }
+    }
+
+    /// Return the REST base url of the provided [`BybitApi`] environment.
+    pub fn get_url(api: BybitApi) -> &'static str {
+        match api.kind() {
+            LiveOrTest::Live => BYBIT_BASE_URL,
+            LiveOrTest::Test => BYBIT_TESTNET_BASE_URL,
+        }
+    }
+
+    /// Return the private WebSocket url of the provided environment.
+    pub fn websocket_url(kind: LiveOrTest) -> &'static str {
+        match kind {
+            LiveOrTest::Live => "wss://stream.bybit.com/v5/private",
+            LiveOrTest::Test => "wss://stream-testnet.bybit.com/v5/private",
+        }
+    }
+
+    /// Construct a [`BybitTimeSync`] service that updates the [`TimeOffset`] used to sign
+    /// requests. Server time requests share the [`RateLimiter`] of this client.
+    pub fn time_sync(&self) -> BybitTimeSync {
+        let client = RestClient::new(
+            self.client.base_url.clone(),
+            self.client.metric_tx.clone(),
+            PublicNoHeaders,
+            BybitParser,
+        )
+        .with_rate_limiter(self.client.rate_limiter.clone());
+
+        TimeSync::new(client, TIME_REQUEST, self.time_offset.clone())
+    }
+
+    /// Construct a [`RateLimiter`] approximating the Bybit limits of 600 requests every 5
+    /// seconds per IP, and 10 orders every second per account & category.
+    pub fn rate_limiter() -> RateLimiter {
+        RateLimiter::new([
+            RateLimit::new(RateLimitKind::Weight, 600, Duration::from_secs(5)),
+            RateLimit::new(RateLimitKind::Orders, 10, Duration::from_secs(1)),
+        ])
+    }
+
+    pub async fn send<Request>(&self, request: Request) -> Result<Request::Response, ExecutionError>
+    where
+        Request: RestRequest,
+        <Request as RestRequest>::Response: Debug,
+    {
+        self.client.execute(request).await
+    }
+
+    /// Place an [`Order<RequestOpen>`], returning the Bybit order id.
+    pub async fn open_order(&self, order: &Order<RequestOpen>) -> Result<String, ExecutionError> {
+        let request: ApiRequest<
+            BybitResponse<BybitOrderAck>,
+            BybitCategoryRequest<BybitPlaceOrder>,
+        > = ApiRequest {
+            path: "/v5/order/create",
+            method: reqwest::Method::POST,
+            tag_method: "open_order",
+            body: Some(BybitCategoryRequest {
+                category: self.api.category(),
+                params: Self::place_order(order)?,
+            }),
+            query_params: None,
+            weight: 1,
+            order_count: 1,
+            response: PhantomData,
+        };
+
+        Ok(self.client.execute(request).await?.into_result()?.order_id)
+    }
+
+    /// Place up to [`BATCH_ORDERS_MAX`](super::requests::BATCH_ORDERS_MAX) orders in a single
+    /// request, returning the per order results in request order.
+    pub async fn open_orders_batch(
+        &self,
+        orders: Vec<BybitPlaceOrder>,
+    ) -> Result<Vec<Result<BybitOrderAck, ExecutionError>>, ExecutionError> {
+        let order_count = orders.len() as u32;
+        let request: ApiRequest<
+            BybitResponse<BybitList<BybitOrderAck>>,
+            BybitBatchRequest<BybitPlaceOrder>,
+        > = ApiRequest {
+            path: "/v5/order/create-batch",
+            method: reqwest::Method::POST,
+            tag_method: "open_orders_batch",
+            body: Some(BybitBatchRequest {
+                category: self.api.category(),
+                request: orders,
+            }),
+            query_params: None,
+            weight: 1,
+            order_count,
+            response: PhantomData,
+        };
+
+        self.client.execute(request).await?.into_batch_results()
+    }
+
+    /// Map an [`Order<RequestOpen>`] to a [`BybitPlaceOrder`] request body.
+    ///
+    /// Stop & take profit [`OrderKind`]s are placed as conditional orders that trigger when the
+    /// price moves through the trigger price against, or in favour of, the order side
+    /// respectively. Trailing stops are set per position by Bybit, so are not supported.
+    pub fn place_order(order: &Order<RequestOpen>) -> Result<BybitPlaceOrder, ExecutionError> {
+        // Trigger direction: 1 triggers when the price rises, 2 when the price falls
+        let (order_type, time_in_force, trigger_direction) = match (order.state.kind, order.side) {
+            (OrderKind::Market, _) => ("Market", None, None),
+            (OrderKind::Limit, _) => ("Limit", Some("GTC"), None),
+            (OrderKind::PostOnly, _) => ("Limit", Some("PostOnly"), None),
+            (OrderKind::ImmediateOrCancel, _) => ("Limit", Some("IOC"), None),
+            (OrderKind::StopMarket { .. }, Side::Buy)
+            | (OrderKind::TakeProfitMarket { .. }, Side::Sell) => ("Market", None, Some(1)),
+            (OrderKind::StopMarket { .. }, Side::Sell)
+            | (OrderKind::TakeProfitMarket { .. }, Side::Buy) => ("Market", None, Some(2)),
+            (OrderKind::StopLimit { .. }, Side::Buy)
+            | (OrderKind::TakeProfitLimit { .. }, Side::Sell) => ("Limit", Some("GTC"), Some(1)),
+            (OrderKind::StopLimit { .. }, Side::Sell)
+            | (OrderKind::TakeProfitLimit { .. }, Side::Buy) => ("Limit", Some("GTC"), Some(2)),
+            (kind @ OrderKind::TrailingStop { .. }, _) => {
+                return Err(ExecutionError::UnsupportedOrderKind(kind))
+            }
+        };
+
+        let symbol = BybitSymbol::new(&order.instrument).ok_or_else(|| {
+            ExecutionError::Socket(SocketError::Unsupported {
+                entity: "Bybit",
+                item: order.instrument.kind.to_string(),
+            })
+        })?;
+        let spot = order.instrument.kind == InstrumentKind::Spot;
+        let conditional = order.state.kind.is_conditional();
+
+        Ok(BybitPlaceOrder {
+            symbol: symbol.0,
+            side: match order.side {
+                Side::Buy => "Buy",
+                Side::Sell => "Sell",
+            },
+            order_type,
+            qty: order.state.quantity,
+            price: order
+                .state
+                .kind
+                .has_limit_price()
+                .then_some(order.state.price),
+            time_in_force,
+            order_link_id: order.cid.0.to_string(),
+            reduce_only: order.state.reduce_only && !spot,
+            trigger_price: order.state.kind.trigger_price(),
+            trigger_direction: trigger_direction.filter(|_| !spot),
+            order_filter: (spot && conditional).then_some("StopOrder"),
+            market_unit: (spot && order_type == "Market").then_some("baseCoin"),
+        })
+    }
+
+    /// Cancel an [`Order<RequestCancel>`], returning the Bybit order id.
+    pub async fn cancel_order(
+        &self,
+        order: &Order<RequestCancel>,
+    ) -> Result<String, ExecutionError> {
+        let symbol = BybitSymbol::new(&order.instrument).ok_or_else(|| {
+            ExecutionError::Socket(SocketError::Unsupported {
+                entity: "Bybit",
+                item: order.instrument.kind.to_string(),
+            })
+        })?;
+
+        let request: ApiRequest<
+            BybitResponse<BybitOrderAck>,
+            BybitCategoryRequest<BybitCancelOrder>,
+        > = ApiRequest {
+            path: "/v5/order/cancel",
+            method: reqwest::Method::POST,
+            tag_method: "cancel_order",
+            body: Some(BybitCategoryRequest {
+                category: self.api.category(),
+                params: BybitCancelOrder {
+                    symbol: symbol.0,
+                    order_id: order.state.id.0.clone(),
+                },
+            }),
+            query_params: None,
+            weight: 1,
+            order_count: 0,
+            response: PhantomData,
+        };
+
+        Ok(self.client.execute(request).await?.into_result()?.order_id)
+    }
+
+    /// Amend the quantity and/or price of an open order, returning the Bybit order id.
+    pub async fn amend_order(&self, order: &Order<RequestAmend>) -> Result<String, ExecutionError> {
+        let request: ApiRequest<
+            BybitResponse<BybitOrderAck>,
+            BybitCategoryRequest<BybitAmendOrder>,
+        > = ApiRequest {
+            path: "/v5/order/amend",
+            method: reqwest::Method::POST,
+            tag_method: "amend_order",
+            body: Some(BybitCategoryRequest {
+                category: self.api.category(),
+                params: Self::amend_order_body(order)?,
+            }),
+            query_params: None,
+            weight: 1,
+            order_count: 1,
+            response: PhantomData,
+        };
+
+        Ok(self.client.execute(request).await?.into_result()?.order_id)
+    }
+
+    /// Map an [`Order<RequestAmend>`] to a [`BybitAmendOrder`] request body. The price is only
+    /// amended for [`OrderKind`]s with a limit price.
+    pub fn amend_order_body(
+        order: &Order<RequestAmend>,
+    ) -> Result<BybitAmendOrder, ExecutionError> {
+        let symbol = BybitSymbol::new(&order.instrument).ok_or_else(|| {
+            ExecutionError::Socket(SocketError::Unsupported {
+                entity: "Bybit",
+                item: order.instrument.kind.to_string(),
+            })
+        })?;
+
+        Ok(BybitAmendOrder {
+            symbol: symbol.0,
+            order_id: order.state.id.0.clone(),
+            qty: Some(order.state.quantity),
+            price: order
+                .state
+                .kind
+                .has_limit_price()
+                .then_some(order.state.price),
+        })
+    }
+
+    /// Cancel every open order of this category, optionally only those settled in the provided
+    /// coin. Linear orders can only be cancelled in bulk per settle coin.
+    pub async fn cancel_all(
+        &self,
+        settle_coin: Option<String>,
+    ) -> Result<Vec<BybitOrderAck>, ExecutionError> {
+        let request: ApiRequest<
+            BybitResponse<BybitList<BybitOrderAck>>,
+            BybitCategoryRequest<BybitCancelAll>,
+        > = ApiRequest {
+            path: "/v5/order/cancel-all",
+            method: reqwest::Method::POST,
+            tag_method: "cancel_all",
+            body: Some(BybitCategoryRequest {
+                category: self.api.category(),
+                params: BybitCancelAll { settle_coin },
+            }),
+            query_params: None,
+            weight: 1,
+            order_count: 0,
+            response: PhantomData,
+        };
+
+        Ok(self.client.execute(request).await?.into_result()?.list)
+    }
+
+    /// Fetch every open order of this category, optionally only those settled in the provided
+    /// coin, following the `nextPageCursor` until every page is fetched.
+    pub async fn fetch_open_orders(
+        &self,
+        settle_coin: Option<&str>,
+    ) -> Result<Vec<BybitOrder>, ExecutionError> {
+        let mut orders = Vec::new();
+        let mut cursor = String::new();
+
+        loop {
+            let mut query_params = self.category_query(settle_coin);
+            query_params.add_kv("limit", 50);
+            if !cursor.is_empty() {
+                query_params.add_kv("cursor", &cursor);
+            }
+
+            let request: ApiRequest<BybitResponse<BybitList<BybitOrder>>, ()> = ApiRequest {
+                query_params: Some(query_params),
+                ..ApiRequest::new(
+                    "/v5/order/realtime",
+                    reqwest::Method::GET,
+                    "fetch_open_orders",
+                )
+            };
+
+            let page = self.client.execute(request).await?.into_result()?;
+            orders.extend(page.list);
+
+            match page.next_page_cursor.is_empty() {
+                true => break Ok(orders),
+                false => cursor = page.next_page_cursor,
+            }
+        }
+    }
+
+    /// Fetch the unified trading account balance of every coin.
+    pub async fn fetch_balances(&self) -> Result<Vec<SymbolBalance>, ExecutionError> {
+        let mut query_params = QueryParams::new();
+        query_params.add_kv("accountType", "UNIFIED");
+
+        let request: ApiRequest<BybitResponse<BybitList<BybitWallet>>, ()> = ApiRequest {
+            query_params: Some(query_params),
+            ..ApiRequest::new(
+                "/v5/account/wallet-balance",
+                reqwest::Method::GET,
+                "fetch_balances",
+            )
+        };
+
+        Ok(self
+            .client
+            .execute(request)
+            .await?
+            .into_result()?
+            .list
+            .into_iter()
+            .flat_map(<Vec<SymbolBalance>>::from)
+            .collect())
+    }
+
+    /// Fetch the open [`BybitPosition`]s of this category, optionally only those settled in the
+    /// provided coin. Spot has no positions.
+    pub async fn fetch_positions(
+        &self,
+        settle_coin: Option<&str>,
+    ) -> Result<Vec<BybitPosition>, ExecutionError> {
+        let mut query_params = self.category_query(settle_coin);
+        query_params.add_kv("limit", 200);
+
+        let request: ApiRequest<BybitResponse<BybitList<BybitPosition>>, ()> = ApiRequest {
+            query_params: Some(query_params),
+            ..ApiRequest::new("/v5/position/list", reqwest::Method::GET, "fetch_positions")
+        };
+
+        Ok(self.client.execute(request).await?.into_result()?.list)
+    }
+
+    fn category_query(&self, settle_coin: Option<&str>) -> QueryParams {
+        let mut query_params = QueryParams::new();
+        query_params.add_kv("category", self.api.category());
+        if let Some(settle_coin) = settle_coin {
+            query_params.add_kv("settleCoin", settle_coin);
+        }
+        query_params
+    }
+}
+
+/// Bybit v5 [`Signer`] that signs `timestamp + api_key + recv_window + payload`, where the
+/// payload is the query string of GET requests, and the JSON body of POST requests.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/guide#authentication>
+#[derive(Debug, Clone)]
+pub struct BybitSigner {
+    pub api_key: String,
+    /// Offset between the Bybit server clock and the local clock, used to generate the request
+    /// timestamp.
+    pub time_offset: TimeOffset,
+    /// [`Duration`] the request must be received within.
+    pub recv_window: Duration,
+}
+
+#[derive(Debug)]
+pub struct BybitSignConfig<'a> {
+    api_key: &'a str,
+    timestamp: String,
+    recv_window: String,
+    payload: String,
+}
+
+impl Signer for BybitSigner {
+    type Config<'a>
+        = BybitSignConfig<'a>
+    where
+        Self: 'a;
+
+    fn config<'a, Request>(
+        &'a self,
+        _: &Request,
+        builder: RequestBuilder,
+    ) -> Result<(Self::Config<'a>, RequestBuilder), SocketError>
+    where
+        Request: RestRequest,
+    {
+        // Build the request to extract the query string or serialised body to sign
+        let (client, request) = builder.build_split();
+        let request = request?;
+
+        let payload = match request.body().and_then(reqwest::Body::as_bytes) {
+            Some(body) => String::from_utf8_lossy(body).into_owned(),
+            None => request.url().query().unwrap_or_default().to_string(),
+        };
+
+        let config = BybitSignConfig {
+            api_key: self.api_key.as_str(),
+            timestamp: self.time_offset.server_time_millis().to_string(),
+            recv_window: self.recv_window.as_millis().to_string(),
+            payload,
+        };
+
+        Ok((config, RequestBuilder::from_parts(client, request)))
+    }
+
+    fn bytes_to_sign<'a>(config: &Self::Config<'a>) -> Bytes {
+        Bytes::from(format!(
+            "{}{}{}{}",
+            config.timestamp, config.api_key, config.recv_window, config.payload
+        ))
+    }
+
+    fn build_signed_request<'a>(
+        config: Self::Config<'a>,
+        builder: RequestBuilder,
+        signature: String,
+    ) -> Result<reqwest::Request, SocketError> {
+        builder
+            .header("X-BAPI-API-KEY", config.api_key)
+            .header("X-BAPI-SIGN", signature)
+            .header("X-BAPI-TIMESTAMP", config.timestamp)
+            .header("X-BAPI-RECV-WINDOW", config.recv_window)
+            .build()
+            .map_err(SocketError::from)
+    }
+}
+
+/// Bybit error response, sent with a non-zero `retCode` and usually an empty `result`.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitApiError {
+    pub ret_code: i64,
+    pub ret_msg: String,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct BybitParser;
+
+impl HttpParser for BybitParser {
+    type ApiError = BybitApiError;
+    type OutputError = ExecutionError;
+
+    fn parse_api_error(
+        &self,
+        status: StatusCode,
+        api_error: Self::ApiError,
+        _: serde_json::Error,
+    ) -> Self::OutputError {
+        match status {
+            StatusCode::UNAUTHORIZED => ExecutionError::Unauthorised(format!(
+                "Bybit error code {}: {}",
+                api_error.ret_code, api_error.ret_msg
+            )),
+            _ => bybit_error(api_error.ret_code, &api_error.ret_msg),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::ClientOrderId;
+    use barter_integration::{
+        model::{instrument::Instrument, Exchange},
+        protocol::http::private::{encoder::Encoder, SigningKey},
+    };
+    use hmac::Mac;
+    use rust_decimal_macros::dec;
+    use uuid::Uuid;
+
+    const CID: &str = "b7c8ca81-08a7-4f8f-a3a2-4cf8a8c6e8f0";
+
+    #[test]
+    fn test_bybit_place_order() {
+        struct TestCase {
+            instrument: Instrument,
+            side: Side,
+            kind: OrderKind,
+            reduce_only: bool,
+            expected: Result<serde_json::Value, ()>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: spot market buy is sized in the base coin
+                instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                side: Side::Buy,
+                kind: OrderKind::Market,
+                reduce_only: true,
+                expected: Ok(serde_json::json!({
+                    "symbol": "BTCUSDT", "side": "Buy", "orderType": "Market", "qty": "0.5",
+                    "orderLinkId": CID, "marketUnit": "baseCoin"
+                })),
+            },
+            TestCase {
+                // TC1: perpetual reduce only limit sell
+                instrument: Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+                side: Side::Sell,
+                kind: OrderKind::Limit,
+                reduce_only: true,
+                expected: Ok(serde_json::json!({
+                    "symbol": "BTCUSDT", "side": "Sell", "orderType": "Limit", "qty": "0.5",
+                    "price": "30000", "timeInForce": "GTC", "orderLinkId": CID,
+                    "reduceOnly": true
+                })),
+            },
+            TestCase {
+                // TC2: perpetual stop market sell triggers when the price falls
+                instrument: Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+                side: Side::Sell,
+                kind: OrderKind::StopMarket {
+                    trigger_price: dec!(29000),
+                },
+                reduce_only: true,
+                expected: Ok(serde_json::json!({
+                    "symbol": "BTCUSDT", "side": "Sell", "orderType": "Market", "qty": "0.5",
+                    "orderLinkId": CID, "reduceOnly": true, "triggerPrice": "29000",
+                    "triggerDirection": 2
+                })),
+            },
+            TestCase {
+                // TC3: perpetual take profit limit sell triggers when the price rises
+                instrument: Instrument::from(("eth", "usdt", InstrumentKind::Perpetual)),
+                side: Side::Sell,
+                kind: OrderKind::TakeProfitLimit {
+                    trigger_price: dec!(31000),
+                },
+                reduce_only: false,
+                expected: Ok(serde_json::json!({
+                    "symbol": "ETHUSDT", "side": "Sell", "orderType": "Limit", "qty": "0.5",
+                    "price": "30000", "timeInForce": "GTC", "orderLinkId": CID,
+                    "triggerPrice": "31000", "triggerDirection": 1
+                })),
+            },
+            TestCase {
+                // TC4: spot stop limit buy uses the "StopOrder" filter
+                instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                side: Side::Buy,
+                kind: OrderKind::StopLimit {
+                    trigger_price: dec!(31000),
+                },
+                reduce_only: false,
+                expected: Ok(serde_json::json!({
+                    "symbol": "BTCUSDT", "side": "Buy", "orderType": "Limit", "qty": "0.5",
+                    "price": "30000", "timeInForce": "GTC", "orderLinkId": CID,
+                    "triggerPrice": "31000", "orderFilter": "StopOrder"
+                })),
+            },
+            TestCase {
+                // TC5: trailing stop is unsupported
+                instrument: Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+                side: Side::Sell,
+                kind: OrderKind::TrailingStop {
+                    trailing_delta: dec!(100),
+                    activation_price: None,
+                },
+                reduce_only: true,
+                expected: Err(()),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let order = Order {
+                exchange: Exchange::from(ExecutionId::BybitPerpetualsUsd),
+                instrument: test.instrument,
+                cid: ClientOrderId(Uuid::parse_str(CID).unwrap()),
+                side: test.side,
+                state: RequestOpen {
+                    kind: test.kind,
+                    price: dec!(30000),
+                    quantity: dec!(0.5),
+                    reduce_only: test.reduce_only,
+                },
+            };
+
+            let actual = BybitClient::place_order(&order)
+                .map(|body| serde_json::to_value(body).unwrap())
+                .map_err(|_| ());
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_bybit_signer_bytes_to_sign() {
+        struct TestCase {
+            payload: &'static str,
+            expected: &'static str,
+        }
+
+        // Expected signatures generated independently with the Python `hmac` module
+        let tests = vec![
+            TestCase {
+                // TC0: GET query string
+                payload: "category=linear&settleCoin=USDT",
+                expected: "9aeaef461a25cb7f34261fc056cbbeb1068b6af3a0487cec0f8205e45ebe73e2",
+            },
+            TestCase {
+                // TC1: POST JSON body
+                payload: r#"{"category":"linear","symbol":"BTCUSDT","orderId":"1001"}"#,
+                expected: "861a7dc1d60451377fbb89c2ab46a8b0304b7ffffdb48dee40bd3ca25d07fa0c",
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let config = BybitSignConfig {
+                api_key: "XXXXXXXXXX",
+                timestamp: "1658384314791".to_string(),
+                recv_window: "5000".to_string(),
+                payload: test.payload.to_string(),
+            };
+
+            let mac = Hmac::<sha2::Sha256>::new_from_slice(b"secret").unwrap();
+            let actual = HexEncoder.encode(mac.sign(&BybitSigner::bytes_to_sign(&config)));
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -0,0 +1,687 @@
+use async_trait::async_trait;
+use barter_data::exchange::bybit::spec::fetch_instrument_specs;
+use barter_integration::{
+    error::SocketError,
+    model::{
+        instrument::{kind::InstrumentKind, spec::InstrumentSpecs, Instrument},
+        Exchange,
+    },
+};
+use futures::future::join_all;
+use std::{
+    collections::{BTreeSet, HashMap},
+    time::Duration,
+};
+use tokio::sync::mpsc;
+use tracing::{error, warn};
+
+use crate::{
+    error::ExecutionError,
+    model::{
+        balance::SymbolBalance,
+        order::{Cancelled, Open, Order, OrderId, RequestAmend, RequestCancel, RequestOpen},
+        AccountEvent,
+    },
+    ExecutionClient, ExecutionId,
+};
+
+use self::{
+    connection::{BybitApi, BybitClient, BybitCredentials},
+    requests::{BybitOrder, BATCH_ORDERS_MAX},
+    websocket::BybitPrivateStream,
+};
+
+pub mod connection;
+pub mod requests;
+pub mod websocket;
+
+/// Bybit v5 [`ExecutionClient`] implementation trading a single [`BybitApi`] category (spot or
+/// USD perpetuals).
+#[derive(Debug)]
+pub struct BybitExecution {
+    client: BybitClient,
+    specs: InstrumentSpecs,
+    instruments: HashMap<BybitSymbol, Instrument>,
+}
+
+/// Config for initializing a [`BybitExecution`] instance.
+#[derive(Debug, Clone)]
+pub struct BybitConfig {
+    pub client_type: BybitApi,
+    pub credentials: BybitCredentials,
+    /// [`InstrumentSpecs`] used to round & validate [`Order<RequestOpen>`]s before submission.
+    /// If `None`, they are fetched from the Bybit instruments info endpoint on init.
+    pub instrument_specs: Option<InstrumentSpecs>,
+    /// [`AccountEvent`] transmitter used by the supervised private WebSocket.
+    pub event_account_tx: mpsc::UnboundedSender<AccountEvent>,
+    /// Optional `recv_window` signed requests must be received within. If `None`, the Bybit
+    /// default of 5000ms is used.
+    pub recv_window: Option<Duration>,
+}
+
+#[async_trait]
+impl ExecutionClient for BybitExecution {
+    type Config = BybitConfig;
+
+    fn exchange(&self) -> Exchange {
+        Exchange::from(ExecutionId::from(self.client.api))
+    }
+
+    async fn init(config: Self::Config) -> Self {
+        let client = match config.recv_window {
+            Some(recv_window) => BybitClient::new_with_recv_window(
+                config.client_type,
+                config.credentials.clone(),
+                recv_window,
+            ),
+            None => BybitClient::new(config.client_type, config.credentials.clone()),
+        };
+
+        // Synchronise with the Bybit server time before any signed requests are sent
+        let time_sync = client.time_sync();
+        if let Err(error) = time_sync.sync().await {
+            warn!(
+                ?error,
+                "failed to synchronise Bybit server time, assuming local time"
+            );
+        }
+        tokio::spawn(time_sync.run());
+
+        let specs = match config.instrument_specs {
+            Some(specs) => specs,
+            None => fetch_instrument_specs(config.client_type.category())
+                .await
+                .unwrap_or_else(|error| {
+                    error!(
+                        ?error,
+                        "failed to fetch Bybit InstrumentSpecs, Order<RequestOpen>s will not be validated"
+                    );
+                    InstrumentSpecs::new()
+                }),
+        };
+
+        // Translate private WebSocket messages into AccountEvents
+        BybitPrivateStream::new(
+            config.client_type,
+            config.credentials,
+            &specs,
+            config.event_account_tx,
+        )
+        .spawn();
+
+        Self::new(client, specs)
+    }
+
+    async fn fetch_orders_open(&self) -> Result<Vec<Order<Open>>, ExecutionError> {
+        let mut orders = Vec::new();
+        for settle_coin in self.settle_coins() {
+            let fetched = self
+                .client
+                .fetch_open_orders(settle_coin.as_deref())
+                .await?;
+            orders.extend(
+                fetched
+                    .into_iter()
+                    .filter_map(|order| self.order_open(order)),
+            );
+        }
+
+        Ok(orders)
+    }
+
+    async fn fetch_balances(&self) -> Result<Vec<SymbolBalance>, ExecutionError> {
+        self.client.fetch_balances().await
+    }
+
+    async fn open_orders(
+        &self,
+        open_requests: Vec<Order<RequestOpen>>,
+    ) -> Vec<Result<Order<Open>, ExecutionError>> {
+        // Round & validate Order<RequestOpen>s to the exchange trading rules, if known, and map
+        // them to Bybit request bodies
+        let requests = open_requests
+            .into_iter()
+            .map(|open_request| {
+                let open_request = match self.specs.get(&open_request.instrument) {
+                    Some(spec) => open_request.conform(spec)?,
+                    None => open_request,
+                };
+                let body = BybitClient::place_order(&open_request)?;
+                Ok((open_request, body))
+            })
+            .collect::<Vec<Result<_, ExecutionError>>>();
+
+        let mut results = Vec::with_capacity(requests.len());
+        let mut valid = Vec::new();
+        for (index, request) in requests.into_iter().enumerate() {
+            match request {
+                Ok(request) => {
+                    valid.push((index, request));
+                    results.push(None);
+                }
+                Err(error) => results.push(Some(Err(error))),
+            }
+        }
+
+        // Place valid orders in batches, preserving the index of each Order<RequestOpen>
+        let tasks = valid.chunks(BATCH_ORDERS_MAX).map(|batch| {
+            let client = self.client.clone();
+            let batch = batch.to_vec();
+            tokio::spawn(async move {
+                let bodies = batch.iter().map(|(_, (_, body))| body.clone()).collect();
+                match client.open_orders_batch(bodies).await {
+                    Ok(acks) => batch
+                        .into_iter()
+                        .zip(acks)
+                        .map(|((index, (open_request, _)), ack)| {
+                            let order = ack.map(|ack| {
+                                Order::<Open>::from((OrderId::from(ack.order_id), open_request))
+                            });
+                            (index, order)
+                        })
+                        .collect::<Vec<_>>(),
+                    Err(error) => {
+                        error!(?error, "failed to open Bybit order batch");
+                        batch
+                            .into_iter()
+                            .map(|(index, _)| {
+                                let error = SocketError::Exchange(format!(
+                                    "Bybit batch order request failed: {error}"
+                                ));
+                                (index, Err(ExecutionError::Socket(error)))
+                            })
+                            .collect()
+                    }
+                }
+            })
+        });
+
+        for batch in join_all(tasks).await {
+            for (index, result) in batch.unwrap() {
+                results[index] = Some(result);
+            }
+        }
+
+        results
+            .into_iter()
+            .map(|result| {
+                // Bybit acknowledges every order of a successful batch, so this is defensive
+                result.unwrap_or_else(|| {
+                    Err(ExecutionError::Socket(SocketError::Exchange(
+                        "Bybit batch order response missing order acknowledgement".to_string(),
+                    )))
+                })
+            })
+            .collect()
+    }
+
+    async fn cancel_orders(
+        &self,
+        cancel_requests: Vec<Order<RequestCancel>>,
+    ) -> Vec<Result<Order<Cancelled>, ExecutionError>> {
+        let tasks = cancel_requests.into_iter().map(|cancel_request| {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                match client.cancel_order(&cancel_request).await {
+                    Ok(order_id) => Ok(Order {
+                        exchange: cancel_request.exchange,
+                        instrument: cancel_request.instrument,
+                        cid: cancel_request.cid,
+                        side: cancel_request.side,
+                        state: Cancelled::from(order_id),
+                    }),
+                    Err(error) => {
+                        error!(?error, "failed to cancel Bybit order");
+                        Err(error)
+                    }
+                }
+            })
+        });
+
+        join_all(tasks)
+            .await
+            .into_iter()
+            .map(|res| res.unwrap())
+            .collect()
+    }
+
+    async fn amend_orders(
+        &self,
+        amend_requests: Vec<Order<RequestAmend>>,
+    ) -> Vec<Result<Order<Open>, ExecutionError>> {
+        let tasks = amend_requests.into_iter().map(|amend_request| {
+            // Round & validate Order<RequestAmend> to the exchange trading rules, if known
+            let amend_request = match self.specs.get(&amend_request.instrument) {
+                Some(spec) => amend_request.conform(spec),
+                None => Ok(amend_request),
+            };
+
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                let amend_request = amend_request?;
+                match client.amend_order(&amend_request).await {
+                    Ok(order_id) => Ok(Order::<Open>::from((
+                        OrderId::from(order_id),
+                        amend_request,
+                    ))),
+                    Err(error) => {
+                        error!(?error, "failed to amend Bybit order");
+                        Err(error)
+                    }
+                }
+            })
+        });
+
+        join_all(tasks)
+            .await
+            .into_iter()
+            .map(|res| res.unwrap())
+            .collect()
+    }
+
+    async fn cancel_orders_all(&self) -> Result<Vec<Order<Cancelled>>, ExecutionError> {
+        let orders = self.fetch_orders_open().await?;
+
+        let mut cancelled = Vec::with_capacity(orders.len());
+        for settle_coin in self.settle_coins() {
+            for ack in self.client.cancel_all(settle_coin).await? {
+                if let Some(order) = orders.iter().find(|order| order.state.id.0 == ack.order_id) {
+                    cancelled.push(Order {
+                        exchange: order.exchange.clone(),
+                        instrument: order.instrument.clone(),
+                        cid: order.cid,
+                        side: order.side,
+                        state: Cancelled::from(ack.order_id),
+                    });
+                }
+            }
+        }
+
+        Ok(cancelled)
+    }
+}
+
+impl BybitExecution {
+    /// Construct a [`BybitExecution`] from an existing [`BybitClient`], without synchronising the
+    /// server time or spawning a [`BybitPrivateStream`].
+    pub fn new(client: BybitClient, specs: InstrumentSpecs) -> Self {
+        Self {
+            instruments: instruments_by_symbol(&specs, client.api),
+            client,
+            specs,
+        }
+    }
+
+    /// Return the [`BybitClient`] used to send REST requests (eg/ to fetch positions).
+    pub fn client(&self) -> &BybitClient {
+        &self.client
+    }
+
+    /// Settle coins that linear open orders must be fetched & cancelled by, derived from the
+    /// quote of every known perpetual. Spot has no settle coin.
+    fn settle_coins(&self) -> Vec<Option<String>> {
+        match self.client.api {
+            BybitApi::Spot(_) => vec![None],
+            BybitApi::Perpetual(_) => {
+                let coins = self
+                    .instruments
+                    .values()
+                    .map(|instrument| instrument.quote.to_string().to_uppercase())
+                    .collect::<BTreeSet<_>>();
+
+                match coins.is_empty() {
+                    true => vec![Some("USDT".to_string())],
+                    false => coins.into_iter().map(Some).collect(),
+                }
+            }
+        }
+    }
+
+    /// Map a [`BybitOrder`] to an [`Order<Open>`]. Returns `None` for orders with an unknown
+    /// instrument, or without a Barter [`ClientOrderId`](crate::model::ClientOrderId).
+    fn order_open(&self, order: BybitOrder) -> Option<Order<Open>> {
+        let Some(instrument) = self.instruments.get(&BybitSymbol(order.symbol.clone())) else {
+            warn!(symbol = %order.symbol, "ignoring open order with unknown Bybit instrument");
+            return None;
+        };
+
+        Some(Order {
+            exchange: self.exchange(),
+            instrument: instrument.clone(),
+            cid: order.cid()?,
+            side: order.side,
+            state: Open {
+                id: OrderId::from(order.order_id),
+                price: order.price,
+                quantity: order.qty,
+                filled_quantity: order.cum_exec_qty,
+            },
+        })
+    }
+}
+
+/// Bybit symbol (eg/ "BTCUSDT"), shared by spot & linear perpetual instruments.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/market/instrument>
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct BybitSymbol(pub String);
+
+impl BybitSymbol {
+    /// Construct the [`BybitSymbol`] of an [`Instrument`]. Returns `None` for
+    /// [`InstrumentKind`]s other than spot & perpetual.
+    pub fn new(instrument: &Instrument) -> Option<Self> {
+        match instrument.kind {
+            InstrumentKind::Spot | InstrumentKind::Perpetual => Some(Self(
+                format!("{}{}", instrument.base, instrument.quote).to_uppercase(),
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// Map every [`Instrument`] traded via the provided [`BybitApi`] to the [`BybitSymbol`] used by
+/// the Bybit REST & private WebSocket APIs.
+pub(crate) fn instruments_by_symbol(
+    specs: &InstrumentSpecs,
+    api: BybitApi,
+) -> HashMap<BybitSymbol, Instrument> {
+    specs
+        .iter()
+        .filter(|spec| api.trades(&spec.instrument.kind))
+        .filter_map(|spec| {
+            let instrument = spec.instrument.clone();
+            BybitSymbol::new(&instrument).map(|symbol| (symbol, instrument))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        execution::binance::connection::LiveOrTest,
+        model::{balance::Balance, order::OrderKind, ClientOrderId},
+    };
+    use barter_integration::model::{instrument::spec::InstrumentSpec, Side};
+    use mockito::Matcher;
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    const CID: &str = "b7c8ca81-08a7-4f8f-a3a2-4cf8a8c6e8f0";
+
+    #[test]
+    fn test_bybit_symbol() {
+        struct TestCase {
+            input: Instrument,
+            expected: Option<BybitSymbol>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: spot
+                input: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                expected: Some(BybitSymbol("BTCUSDT".to_string())),
+            },
+            TestCase {
+                // TC1: perpetual
+                input: Instrument::from(("eth", "usdc", InstrumentKind::Perpetual)),
+                expected: Some(BybitSymbol("ETHUSDC".to_string())),
+            },
+            TestCase {
+                // TC2: intent order is unsupported
+                input: Instrument::from(("eth", "usdc", InstrumentKind::IntentOrder)),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = BybitSymbol::new(&test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    fn btc_usdt_perpetual() -> Instrument {
+        Instrument::from(("btc", "usdt", InstrumentKind::Perpetual))
+    }
+
+    fn order_request(kind: OrderKind, quantity: rust_decimal::Decimal) -> Order<RequestOpen> {
+        Order {
+            exchange: Exchange::from(ExecutionId::BybitPerpetualsUsd),
+            instrument: btc_usdt_perpetual(),
+            cid: ClientOrderId(Uuid::parse_str(CID).unwrap()),
+            side: Side::Buy,
+            state: RequestOpen {
+                kind,
+                price: dec!(30000.04),
+                quantity,
+                reduce_only: false,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bybit_execution_stub() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mut signed = |method: &str, path: &str| {
+            server
+                .mock(method, path)
+                .match_header("X-BAPI-API-KEY", "key")
+                .match_header("X-BAPI-RECV-WINDOW", "5000")
+                .match_header("X-BAPI-SIGN", Matcher::Regex("^[0-9a-f]{64}$".into()))
+                .match_header("X-BAPI-TIMESTAMP", Matcher::Regex(r"^\d{13}$".into()))
+        };
+
+        let _open_batch = signed("POST", "/v5/order/create-batch")
+            .match_body(Matcher::PartialJson(json!({
+                "category": "linear",
+                "request": [
+                    {
+                        "symbol": "BTCUSDT", "side": "Buy", "orderType": "Limit", "qty": "2",
+                        "price": "30000.0", "timeInForce": "GTC", "orderLinkId": CID
+                    },
+                    {"qty": "3"}
+                ]
+            })))
+            .with_body(
+                json!({
+                    "retCode": 0,
+                    "retMsg": "OK",
+                    "result": {"list": [
+                        {"category": "linear", "symbol": "BTCUSDT", "orderId": "1001", "orderLinkId": CID, "createAt": "1684738540559"},
+                        {"category": "linear", "symbol": "BTCUSDT", "orderId": "", "orderLinkId": CID, "createAt": ""}
+                    ]},
+                    "retExtInfo": {"list": [{"code": 0, "msg": "OK"}, {"code": 110007, "msg": "Insufficient balance"}]},
+                    "time": 1684738540561_u64
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let _cancel = signed("POST", "/v5/order/cancel")
+            .match_body(Matcher::Json(
+                json!({"category": "linear", "symbol": "BTCUSDT", "orderId": "1001"}),
+            ))
+            .with_body(
+                json!({
+                    "retCode": 0,
+                    "retMsg": "OK",
+                    "result": {"orderId": "1001", "orderLinkId": CID},
+                    "retExtInfo": {},
+                    "time": 1684738540561_u64
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let _amend = signed("POST", "/v5/order/amend")
+            .match_body(Matcher::Json(json!({
+                "category": "linear", "symbol": "BTCUSDT", "orderId": "1001",
+                "qty": "4", "price": "29999.0"
+            })))
+            .with_body(
+                json!({
+                    "retCode": 0,
+                    "retMsg": "OK",
+                    "result": {"orderId": "1001", "orderLinkId": CID},
+                    "retExtInfo": {},
+                    "time": 1684738540561_u64
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let _balances = signed("GET", "/v5/account/wallet-balance")
+            .match_query(Matcher::UrlEncoded("accountType".into(), "UNIFIED".into()))
+            .with_body(
+                r#"{"retCode": 0, "retMsg": "OK", "result": {"list": [{"accountType": "UNIFIED", "coin": [{"coin": "USDT", "walletBalance": "4850.43", "locked": "0", "totalOrderIM": "10.12", "totalPositionIM": "6"}]}]}, "retExtInfo": {}, "time": 1690872862481}"#,
+            )
+            .create_async()
+            .await;
+
+        let _open_orders = signed("GET", "/v5/order/realtime")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("category".into(), "linear".into()),
+                Matcher::UrlEncoded("settleCoin".into(), "USDT".into()),
+            ]))
+            .with_body(
+                json!({
+                    "retCode": 0,
+                    "retMsg": "OK",
+                    "result": {"list": [
+                        {
+                            "symbol": "BTCUSDT", "orderId": "1001", "orderLinkId": CID,
+                            "side": "Buy", "price": "30000", "qty": "2", "cumExecQty": "0.5",
+                            "orderStatus": "PartiallyFilled"
+                        },
+                        {
+                            "symbol": "BTCUSDT", "orderId": "1002", "orderLinkId": "",
+                            "side": "Sell", "price": "31000", "qty": "1", "cumExecQty": "0",
+                            "orderStatus": "New"
+                        }
+                    ], "nextPageCursor": ""},
+                    "retExtInfo": {},
+                    "time": 1684738540561_u64
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let _cancel_all = signed("POST", "/v5/order/cancel-all")
+            .match_body(Matcher::Json(
+                json!({"category": "linear", "settleCoin": "USDT"}),
+            ))
+            .with_body(
+                json!({
+                    "retCode": 0,
+                    "retMsg": "OK",
+                    "result": {"list": [
+                        {"orderId": "1001", "orderLinkId": CID},
+                        {"orderId": "1002", "orderLinkId": ""}
+                    ], "success": "1"},
+                    "retExtInfo": {},
+                    "time": 1684738540561_u64
+                })
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let client = BybitClient::new_with_url(
+            BybitApi::Perpetual(LiveOrTest::Live),
+            BybitCredentials::new("key", "secret"),
+            server.url(),
+        );
+        let specs = InstrumentSpecs::from(vec![InstrumentSpec::new(
+            btc_usdt_perpetual(),
+            dec!(0.1),
+            dec!(1),
+        )]);
+        let execution = BybitExecution::new(client, specs);
+
+        // Order<RequestOpen>s are conformed & placed in a single batch, trailing stops are
+        // rejected before submission
+        let mut opened = execution
+            .open_orders(vec![
+                order_request(OrderKind::Limit, dec!(2)),
+                order_request(
+                    OrderKind::TrailingStop {
+                        trailing_delta: dec!(100),
+                        activation_price: None,
+                    },
+                    dec!(1),
+                ),
+                order_request(OrderKind::Limit, dec!(3)),
+            ])
+            .await
+            .into_iter();
+        let open = opened.next().unwrap().unwrap();
+        assert_eq!(open.state.id, OrderId::from("1001"));
+        assert_eq!(open.state.price, dec!(30000));
+        assert!(matches!(
+            opened.next().unwrap(),
+            Err(ExecutionError::UnsupportedOrderKind(_))
+        ));
+        assert!(matches!(
+            opened.next().unwrap(),
+            Err(ExecutionError::Socket(_))
+        ));
+
+        // Order<RequestAmend>s are conformed before submission
+        let amended = execution
+            .amend_orders(vec![Order {
+                exchange: open.exchange.clone(),
+                instrument: open.instrument.clone(),
+                cid: open.cid,
+                side: open.side,
+                state: RequestAmend {
+                    id: OrderId::from("1001"),
+                    kind: OrderKind::Limit,
+                    price: dec!(29999.04),
+                    quantity: dec!(4),
+                    reduce_only: false,
+                    filled_quantity: dec!(0),
+                },
+            }])
+            .await;
+        let amended = amended[0].as_ref().unwrap();
+        assert_eq!(amended.state.id, OrderId::from("1001"));
+        assert_eq!(amended.state.price, dec!(29999));
+
+        let cancelled = execution
+            .cancel_orders(vec![Order {
+                exchange: open.exchange.clone(),
+                instrument: open.instrument.clone(),
+                cid: open.cid,
+                side: open.side,
+                state: RequestCancel::from("1001"),
+            }])
+            .await;
+        assert_eq!(
+            cancelled[0].as_ref().unwrap().state,
+            Cancelled::from("1001")
+        );
+
+        assert_eq!(
+            execution.fetch_balances().await.unwrap(),
+            vec![SymbolBalance::new(
+                "usdt",
+                Balance::new(dec!(4850.43), dec!(4834.31))
+            )]
+        );
+
+        // Orders without a Barter ClientOrderId are ignored
+        let orders_open = execution.fetch_orders_open().await.unwrap();
+        assert_eq!(orders_open.len(), 1);
+        assert_eq!(orders_open[0].state.filled_quantity, dec!(0.5));
+
+        let cancelled_all = execution.cancel_orders_all().await.unwrap();
+        assert_eq!(cancelled_all.len(), 1);
+        assert_eq!(cancelled_all[0].cid, open.cid);
+    }
+}
@@ -0,0 +1,651 @@
+use barter_integration::{
+    error::SocketError,
+    model::{instrument::symbol::Symbol, Side},
+    protocol::http::{private::time_sync::ServerTime, rest::ApiRequest},
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::ExecutionError,
+    model::balance::{Balance, SymbolBalance},
+};
+
+pub const TIME_REQUEST: ApiRequest<BybitResponse<BybitServerTime>, ()> =
+    ApiRequest::new("/v5/market/time", reqwest::Method::GET, "server_time");
+
+/// Maximum number of orders Bybit accepts in a single batch request.
+pub const BATCH_ORDERS_MAX: usize = 10;
+
+/// Envelope of every Bybit v5 REST response. A `retCode` other than 0 indicates the request
+/// failed, even though the Http status may be 200.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/market/time>
+/// ```json
+/// {
+///     "retCode": 0,
+///     "retMsg": "OK",
+///     "result": {"timeSecond": "1688639403", "timeNano": "1688639403423213947"},
+///     "retExtInfo": {},
+///     "time": 1688639403423
+/// }
+/// ```
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitResponse<T> {
+    pub ret_code: i64,
+    pub ret_msg: String,
+    pub result: T,
+    #[serde(default)]
+    pub ret_ext_info: BybitExtInfo,
+    #[serde(default)]
+    pub time: i64,
+}
+
+impl<T> BybitResponse<T> {
+    /// Return the response `result` if the request succeeded, otherwise an [`ExecutionError`].
+    pub fn into_result(self) -> Result<T, ExecutionError> {
+        match self.ret_code {
+            0 => Ok(self.result),
+            code => Err(bybit_error(code, &self.ret_msg)),
+        }
+    }
+}
+
+impl BybitResponse<BybitList<BybitOrderAck>> {
+    /// Return the per order results of a batch request, pairing each acknowledgement with it's
+    /// `retExtInfo` status code. Acknowledgements without a status code are assumed accepted.
+    pub fn into_batch_results(
+        mut self,
+    ) -> Result<Vec<Result<BybitOrderAck, ExecutionError>>, ExecutionError> {
+        let statuses = std::mem::take(&mut self.ret_ext_info.list);
+        let acks = self.into_result()?.list;
+
+        Ok(acks
+            .into_iter()
+            .zip(
+                statuses
+                    .into_iter()
+                    .map(Some)
+                    .chain(std::iter::repeat(None)),
+            )
+            .map(|(ack, status)| match status {
+                Some(BybitExtCode { code, msg }) if code != 0 => Err(bybit_error(code, &msg)),
+                _ => Ok(ack),
+            })
+            .collect())
+    }
+}
+
+/// Map a Bybit `retCode` & message to an [`ExecutionError`].
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/error>
+pub fn bybit_error(code: i64, msg: &str) -> ExecutionError {
+    let error = format!("Bybit error code {code}: {msg}");
+    match code {
+        // Invalid api key, invalid signature, permission denied, or expired api key
+        10003 | 10004 | 10005 | 10007 | 33004 => ExecutionError::Unauthorised(error),
+        _ => ExecutionError::Socket(SocketError::Exchange(error)),
+    }
+}
+
+/// Bybit `retExtInfo`, containing the per order status codes of batch requests.
+#[derive(Clone, Eq, PartialEq, Debug, Default, Deserialize)]
+pub struct BybitExtInfo {
+    #[serde(default)]
+    pub list: Vec<BybitExtCode>,
+}
+
+/// Status code of a single order in a batch request. A `code` other than 0 indicates the
+/// individual order was rejected.
+#[derive(Clone, Eq, PartialEq, Debug, Default, Deserialize)]
+pub struct BybitExtCode {
+    pub code: i64,
+    #[serde(default)]
+    pub msg: String,
+}
+
+/// Generic Bybit `result` containing a `list` of items.
+#[derive(Clone, PartialEq, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitList<T> {
+    pub list: Vec<T>,
+    #[serde(default)]
+    pub next_page_cursor: String,
+}
+
+#[derive(Copy, Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitServerTime {
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub time_second: i64,
+}
+
+impl ServerTime for BybitResponse<BybitServerTime> {
+    fn server_time_millis(&self) -> i64 {
+        // Prefer the millisecond response time, falling back to the result in seconds
+        match self.time {
+            0 => self.result.time_second * 1000,
+            time => time,
+        }
+    }
+}
+
+/// Bybit v5 request body, tagged with the product `category` (eg/ "spot", "linear").
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+pub struct BybitCategoryRequest<T> {
+    pub category: &'static str,
+    #[serde(flatten)]
+    pub params: T,
+}
+
+/// Bybit v5 batch request body. Batch requests accept up to [`BATCH_ORDERS_MAX`] orders of the
+/// same `category`.
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+pub struct BybitBatchRequest<T> {
+    pub category: &'static str,
+    pub request: Vec<T>,
+}
+
+/// Body of a Bybit place order request.
+///
+/// Conditional orders are placed with a `trigger_price`, and a `trigger_direction` of 1 if
+/// triggered when the price rises to the trigger price, or 2 if triggered when it falls.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/order/create-order>
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitPlaceOrder {
+    pub symbol: String,
+    pub side: &'static str,
+    pub order_type: &'static str,
+    pub qty: Decimal,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_in_force: Option<&'static str>,
+    pub order_link_id: String,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub reduce_only: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_price: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_direction: Option<u8>,
+    /// Spot conditional orders must be placed with the "StopOrder" filter.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_filter: Option<&'static str>,
+    /// Spot market order `qty` unit, always "baseCoin" since Bybit defaults buys to the quote coin.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub market_unit: Option<&'static str>,
+}
+
+/// Body of a Bybit cancel order request.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/order/cancel-order>
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitCancelOrder {
+    pub symbol: String,
+    pub order_id: String,
+}
+
+/// Body of a Bybit amend order request. At least one of `qty` & `price` must be provided.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/order/amend-order>
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitAmendOrder {
+    pub symbol: String,
+    pub order_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub qty: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<Decimal>,
+}
+
+/// Body of a Bybit cancel all orders request. Linear orders must be cancelled per settle coin.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/order/cancel-all>
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitCancelAll {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settle_coin: Option<String>,
+}
+
+/// Result of a Bybit place, amend or cancel order request, and of each order in a batch request.
+///
+/// ### Raw Payload Examples
+/// ```json
+/// {"orderId": "1321003749386327552", "orderLinkId": "spot-test-postonly"}
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitOrderAck {
+    #[serde(default)]
+    pub order_id: String,
+    #[serde(default)]
+    pub order_link_id: String,
+}
+
+/// Bybit order, as returned by the open orders endpoint & the private "order" topic.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/order/open-order>
+/// ```json
+/// {
+///     "symbol": "BTCUSDT",
+///     "orderId": "fd4300ae-7847-404e-b947-b46980a4d140",
+///     "orderLinkId": "b7c8ca81-08a7-4f8f-a3a2-4cf8a8c6e8f0",
+///     "side": "Buy",
+///     "orderType": "Limit",
+///     "price": "30000",
+///     "qty": "2",
+///     "cumExecQty": "1",
+///     "orderStatus": "PartiallyFilled"
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitOrder {
+    /// Only sent by the private "order" topic.
+    #[serde(default)]
+    pub category: String,
+    pub symbol: String,
+    pub order_id: String,
+    #[serde(default)]
+    pub order_link_id: String,
+    pub side: Side,
+    #[serde(deserialize_with = "barter_integration::de::de_str_or_default")]
+    pub price: Decimal,
+    #[serde(deserialize_with = "barter_integration::de::de_str_or_default")]
+    pub qty: Decimal,
+    #[serde(
+        default,
+        deserialize_with = "barter_integration::de::de_str_or_default"
+    )]
+    pub cum_exec_qty: Decimal,
+    pub order_status: BybitOrderStatus,
+}
+
+/// Bybit order status.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/enum#orderstatus>
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub enum BybitOrderStatus {
+    New,
+    PartiallyFilled,
+    Untriggered,
+    Filled,
+    Cancelled,
+    PartiallyFilledCanceled,
+    Rejected,
+    Deactivated,
+    #[serde(other)]
+    Other,
+}
+
+/// Bybit execution (ie/ fill), as sent by the private "execution" topic.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/private/execution>
+/// ```json
+/// {
+///     "category": "linear",
+///     "symbol": "BTCUSDT",
+///     "execFee": "0.015",
+///     "execId": "7e2ae69c-4edf-5800-a352-893d52b446aa",
+///     "execPrice": "30000",
+///     "execQty": "1",
+///     "execType": "Trade",
+///     "orderId": "fd4300ae-7847-404e-b947-b46980a4d140",
+///     "orderLinkId": "b7c8ca81-08a7-4f8f-a3a2-4cf8a8c6e8f0",
+///     "side": "Sell",
+///     "feeCurrency": ""
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitFill {
+    #[serde(default)]
+    pub category: String,
+    pub symbol: String,
+    /// Positive values are charged, negative values are rebates.
+    #[serde(deserialize_with = "barter_integration::de::de_str_or_default")]
+    pub exec_fee: Decimal,
+    pub exec_id: String,
+    #[serde(deserialize_with = "barter_integration::de::de_str_or_default")]
+    pub exec_price: Decimal,
+    #[serde(deserialize_with = "barter_integration::de::de_str_or_default")]
+    pub exec_qty: Decimal,
+    /// Execution type, eg/ "Trade", "Funding", "BustTrade".
+    pub exec_type: String,
+    pub order_id: String,
+    #[serde(default)]
+    pub order_link_id: String,
+    pub side: Side,
+    /// Spot fee currency, empty for derivatives which are charged in the settle coin.
+    #[serde(default)]
+    pub fee_currency: String,
+}
+
+/// Bybit wallet, as returned by the wallet balance endpoint & the private "wallet" topic.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/account/wallet-balance>
+/// ```json
+/// {
+///     "accountType": "UNIFIED",
+///     "coin": [
+///         {
+///             "coin": "USDT",
+///             "equity": "4992.89",
+///             "walletBalance": "4850.43",
+///             "locked": "0",
+///             "totalOrderIM": "10.12",
+///             "totalPositionIM": "6"
+///         }
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitWallet {
+    pub account_type: String,
+    pub coin: Vec<BybitCoinBalance>,
+}
+
+/// Bybit wallet balance of a single coin.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitCoinBalance {
+    pub coin: Symbol,
+    #[serde(deserialize_with = "barter_integration::de::de_str_or_default")]
+    pub wallet_balance: Decimal,
+    /// Balance locked by open spot orders.
+    #[serde(
+        default,
+        deserialize_with = "barter_integration::de::de_str_or_default"
+    )]
+    pub locked: Decimal,
+    /// Initial margin reserved by open derivative orders.
+    #[serde(
+        default,
+        rename = "totalOrderIM",
+        deserialize_with = "barter_integration::de::de_str_or_default"
+    )]
+    pub total_order_im: Decimal,
+    /// Initial margin reserved by open derivative positions.
+    #[serde(
+        default,
+        rename = "totalPositionIM",
+        deserialize_with = "barter_integration::de::de_str_or_default"
+    )]
+    pub total_position_im: Decimal,
+}
+
+impl From<BybitCoinBalance> for SymbolBalance {
+    fn from(balance: BybitCoinBalance) -> Self {
+        let available = (balance.wallet_balance
+            - balance.locked
+            - balance.total_order_im
+            - balance.total_position_im)
+            .max(Decimal::ZERO);
+
+        SymbolBalance::new(
+            balance.coin,
+            Balance::new(balance.wallet_balance, available),
+        )
+    }
+}
+
+impl From<BybitWallet> for Vec<SymbolBalance> {
+    fn from(wallet: BybitWallet) -> Self {
+        wallet.coin.into_iter().map(SymbolBalance::from).collect()
+    }
+}
+
+/// Bybit derivative position, as returned by the position list endpoint.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/position>
+/// ```json
+/// {
+///     "symbol": "BTCUSDT",
+///     "side": "Buy",
+///     "size": "0.5",
+///     "avgPrice": "29500",
+///     "positionValue": "14750",
+///     "markPrice": "30000",
+///     "liqPrice": "20000",
+///     "leverage": "10",
+///     "unrealisedPnl": "250"
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitPosition {
+    pub symbol: String,
+    /// "Buy" for long, "Sell" for short, or empty for flat positions.
+    pub side: String,
+    #[serde(deserialize_with = "barter_integration::de::de_str_or_default")]
+    pub size: Decimal,
+    #[serde(deserialize_with = "barter_integration::de::de_str_or_default")]
+    pub avg_price: Decimal,
+    #[serde(
+        default,
+        deserialize_with = "barter_integration::de::de_str_or_default"
+    )]
+    pub position_value: Decimal,
+    #[serde(
+        default,
+        deserialize_with = "barter_integration::de::de_str_or_default"
+    )]
+    pub mark_price: Decimal,
+    #[serde(
+        default,
+        deserialize_with = "barter_integration::de::de_str_or_default"
+    )]
+    pub liq_price: Decimal,
+    #[serde(
+        default,
+        deserialize_with = "barter_integration::de::de_str_or_default"
+    )]
+    pub leverage: Decimal,
+    #[serde(
+        default,
+        deserialize_with = "barter_integration::de::de_str_or_default"
+    )]
+    pub unrealised_pnl: Decimal,
+}
+
+impl BybitPosition {
+    /// Return the [`Side`] of this position, or `None` if the position is flat.
+    pub fn side(&self) -> Option<Side> {
+        match self.side.as_str() {
+            "Buy" => Some(Side::Buy),
+            "Sell" => Some(Side::Sell),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_de_bybit_order() {
+        struct TestCase {
+            input: &'static str,
+            expected: BybitOrder,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: partially filled linear limit order from the open orders endpoint
+                input: r#"{
+                    "orderId": "fd4300ae-7847-404e-b947-b46980a4d140",
+                    "orderLinkId": "b7c8ca81-08a7-4f8f-a3a2-4cf8a8c6e8f0",
+                    "blockTradeId": "", "symbol": "BTCUSDT", "price": "30000", "qty": "2",
+                    "side": "Buy", "isLeverage": "", "positionIdx": 0,
+                    "orderStatus": "PartiallyFilled", "cancelType": "UNKNOWN",
+                    "rejectReason": "EC_NoError", "avgPrice": "30000", "leavesQty": "1",
+                    "leavesValue": "30000", "cumExecQty": "1", "cumExecValue": "30000",
+                    "cumExecFee": "0.015", "timeInForce": "GTC", "orderType": "Limit",
+                    "triggerPrice": "", "reduceOnly": false, "createdTime": "1684738540559"
+                }"#,
+                expected: BybitOrder {
+                    category: String::new(),
+                    symbol: "BTCUSDT".to_string(),
+                    order_id: "fd4300ae-7847-404e-b947-b46980a4d140".to_string(),
+                    order_link_id: "b7c8ca81-08a7-4f8f-a3a2-4cf8a8c6e8f0".to_string(),
+                    side: Side::Buy,
+                    price: dec!(30000),
+                    qty: dec!(2),
+                    cum_exec_qty: dec!(1),
+                    order_status: BybitOrderStatus::PartiallyFilled,
+                },
+            },
+            TestCase {
+                // TC1: untriggered spot market order with an empty price & unknown status
+                input: r#"{
+                    "category": "spot", "symbol": "BTCUSDT", "orderId": "1",
+                    "orderLinkId": "", "side": "Sell", "price": "", "qty": "0.5",
+                    "cumExecQty": "0", "orderStatus": "Active"
+                }"#,
+                expected: BybitOrder {
+                    category: "spot".to_string(),
+                    symbol: "BTCUSDT".to_string(),
+                    order_id: "1".to_string(),
+                    order_link_id: String::new(),
+                    side: Side::Sell,
+                    price: Decimal::ZERO,
+                    qty: dec!(0.5),
+                    cum_exec_qty: Decimal::ZERO,
+                    order_status: BybitOrderStatus::Other,
+                },
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = serde_json::from_str::<BybitOrder>(test.input).unwrap();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_de_bybit_wallet_balance() {
+        let input = r#"{
+            "retCode": 0,
+            "retMsg": "OK",
+            "result": {
+                "list": [{
+                    "accountType": "UNIFIED", "totalEquity": "5000.12", "accountIMRate": "0",
+                    "coin": [
+                        {
+                            "coin": "USDT", "equity": "4992.89", "usdValue": "4992.89",
+                            "walletBalance": "4850.43", "locked": "0", "totalOrderIM": "10.12",
+                            "totalPositionIM": "6", "availableToWithdraw": ""
+                        },
+                        {
+                            "coin": "BTC", "equity": "0.1", "walletBalance": "0.1",
+                            "locked": "0.02", "totalOrderIM": "", "totalPositionIM": ""
+                        }
+                    ]
+                }]
+            },
+            "retExtInfo": {},
+            "time": 1690872862481
+        }"#;
+
+        let actual = serde_json::from_str::<BybitResponse<BybitList<BybitWallet>>>(input)
+            .unwrap()
+            .into_result()
+            .unwrap()
+            .list
+            .into_iter()
+            .flat_map(<Vec<SymbolBalance>>::from)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![
+                SymbolBalance::new("usdt", Balance::new(dec!(4850.43), dec!(4834.31))),
+                SymbolBalance::new("btc", Balance::new(dec!(0.1), dec!(0.08))),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bybit_response_into_batch_results() {
+        struct TestCase {
+            input: &'static str,
+            expected: Result<Vec<Result<&'static str, ()>>, ()>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: partially rejected batch contains every per order result
+                input: r#"{
+                    "retCode": 0,
+                    "retMsg": "OK",
+                    "result": {"list": [
+                        {"category": "linear", "symbol": "BTCUSDT", "orderId": "1001", "orderLinkId": "a", "createAt": "1684738540559"},
+                        {"category": "linear", "symbol": "BTCUSDT", "orderId": "", "orderLinkId": "b", "createAt": ""}
+                    ]},
+                    "retExtInfo": {"list": [{"code": 0, "msg": "OK"}, {"code": 110007, "msg": "Insufficient balance"}]},
+                    "time": 1684738540561
+                }"#,
+                expected: Ok(vec![Ok("1001"), Err(())]),
+            },
+            TestCase {
+                // TC1: failed request
+                input: r#"{
+                    "retCode": 10004,
+                    "retMsg": "error sign!",
+                    "result": {"list": []},
+                    "retExtInfo": {},
+                    "time": 1684738540561
+                }"#,
+                expected: Err(()),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual =
+                serde_json::from_str::<BybitResponse<BybitList<BybitOrderAck>>>(test.input)
+                    .unwrap()
+                    .into_batch_results()
+                    .map(|results| {
+                        results
+                            .into_iter()
+                            .map(|result| result.map(|ack| ack.order_id).map_err(|_| ()))
+                            .collect::<Vec<_>>()
+                    })
+                    .map_err(|_| ());
+
+            let expected = test.expected.map(|results| {
+                results
+                    .into_iter()
+                    .map(|result| result.map(str::to_string))
+                    .collect::<Vec<_>>()
+            });
+            assert_eq!(actual, expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_bybit_error() {
+        assert!(matches!(
+            bybit_error(10004, "error sign!"),
+            ExecutionError::Unauthorised(_)
+        ));
+        assert!(matches!(
+            bybit_error(110007, "Insufficient balance"),
+            ExecutionError::Socket(SocketError::Exchange(_))
+        ));
+    }
+}
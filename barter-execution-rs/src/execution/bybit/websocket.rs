@@ -0,0 +1,755 @@
+use super::{
+    connection::{BybitApi, BybitClient, BybitCredentials},
+    instruments_by_symbol,
+    requests::{BybitFill, BybitOrder, BybitOrderStatus, BybitWallet},
+    BybitSymbol,
+};
+use crate::{
+    error::ExecutionError,
+    model::{
+        balance::SymbolBalance,
+        order::{Cancelled, Open, Order, OrderId},
+        trade::{SymbolFees, Trade, TradeId},
+        AccountEvent, AccountEventKind, ClientOrderId,
+    },
+    ExecutionId,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{
+        instrument::{spec::InstrumentSpecs, Instrument},
+        Exchange,
+    },
+    protocol::http::private::{
+        encoder::{Encoder, HexEncoder},
+        SigningKey,
+    },
+};
+use chrono::Utc;
+use futures::{SinkExt, StreamExt};
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use serde_json::json;
+use std::{collections::HashMap, time::Duration};
+use tokio::{sync::mpsc, task::JoinHandle};
+use tokio_tungstenite::{connect_async, tungstenite::Message as WsMessage};
+use tracing::{debug, error, info, warn};
+use uuid::Uuid;
+
+/// Interval between "ping" messages, as recommended by Bybit to keep the connection alive.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect#how-to-send-the-heartbeat-packet>
+pub const PING_INTERVAL: Duration = Duration::from_secs(20);
+
+/// Maximum [`Duration`] to wait for an auth response.
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// [`Duration`] after which a signed auth request expires.
+const AUTH_EXPIRY: Duration = Duration::from_secs(10);
+
+/// Initial delay before re-connecting a failed [`BybitPrivateStream`] session. Doubles after
+/// each consecutive failure up to [`RECONNECT_BACKOFF_MAX`].
+const RECONNECT_BACKOFF_INITIAL: Duration = Duration::from_secs(1);
+
+/// Maximum delay before re-connecting a failed [`BybitPrivateStream`] session.
+const RECONNECT_BACKOFF_MAX: Duration = Duration::from_secs(60);
+
+/// Private topics subscribed to by every [`BybitPrivateStream`] session.
+const TOPICS: [&str; 3] = ["order", "execution", "wallet"];
+
+/// Supervised Bybit v5 private WebSocket that translates "order", "execution" & "wallet" topic
+/// messages of a single [`BybitApi`] category into [`AccountEvent`]s.
+///
+/// Each session connects, authenticates, and subscribes to every private topic, sending a "ping"
+/// every [`PING_INTERVAL`]. When a session ends, a new session is started with an exponential
+/// backoff. The stream stops once the [`AccountEvent`] receiver is dropped.
+#[derive(Debug, Clone)]
+pub struct BybitPrivateStream {
+    url: String,
+    credentials: BybitCredentials,
+    category: &'static str,
+    exchange: Exchange,
+    instruments: HashMap<BybitSymbol, Instrument>,
+    event_tx: mpsc::UnboundedSender<AccountEvent>,
+}
+
+/// Outcome of a single [`BybitPrivateStream`] session.
+enum Session {
+    /// Session ended and a new one should be started (eg/ disconnect).
+    Reconnect,
+    /// [`AccountEvent`] receiver dropped, so the [`BybitPrivateStream`] should stop.
+    Terminate,
+}
+
+impl BybitPrivateStream {
+    /// Construct a new [`BybitPrivateStream`]. The provided [`InstrumentSpecs`] are used to map
+    /// Bybit symbols (eg/ "BTCUSDT") of the [`BybitApi`] category back to the associated
+    /// [`Instrument`].
+    pub fn new(
+        api: BybitApi,
+        credentials: BybitCredentials,
+        specs: &InstrumentSpecs,
+        event_tx: mpsc::UnboundedSender<AccountEvent>,
+    ) -> Self {
+        Self {
+            url: BybitClient::websocket_url(api.kind()).to_string(),
+            credentials,
+            category: api.category(),
+            exchange: Exchange::from(ExecutionId::from(api)),
+            instruments: instruments_by_symbol(specs, api),
+            event_tx,
+        }
+    }
+
+    /// Connect to the provided private WebSocket url rather than the Bybit default.
+    pub fn with_url<S>(self, url: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            url: url.into(),
+            ..self
+        }
+    }
+
+    /// Spawn the supervised private WebSocket task.
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move { self.run().await })
+    }
+
+    /// Run private WebSocket sessions until the [`AccountEvent`] receiver is dropped.
+    pub async fn run(self) {
+        let mut backoff = RECONNECT_BACKOFF_INITIAL;
+        loop {
+            match self.session().await {
+                Ok(Session::Terminate) => {
+                    info!("AccountEvent receiver dropped, stopping Bybit private stream");
+                    break;
+                }
+                Ok(Session::Reconnect) => {
+                    info!("Bybit private stream session ended, reconnecting");
+                    backoff = RECONNECT_BACKOFF_INITIAL;
+                    tokio::time::sleep(backoff).await;
+                }
+                Err(error) => {
+                    error!(
+                        ?error,
+                        ?backoff,
+                        "Bybit private stream failed, reconnecting"
+                    );
+                    tokio::time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(RECONNECT_BACKOFF_MAX);
+                }
+            }
+        }
+    }
+
+    /// Run a single private WebSocket session: connect, authenticate, subscribe, and consume
+    /// messages until the connection ends.
+    async fn session(&self) -> Result<Session, ExecutionError> {
+        let (mut websocket, _) = connect_async(self.url.as_str())
+            .await
+            .map_err(SocketError::WebSocket)?;
+        info!(url = %self.url, "connected to Bybit private stream");
+
+        // Authenticate & wait for confirmation before subscribing to private topics
+        let expires = Utc::now().timestamp_millis() + AUTH_EXPIRY.as_millis() as i64;
+        websocket
+            .send(WsMessage::Text(self.auth_request(expires).to_string()))
+            .await
+            .map_err(SocketError::WebSocket)?;
+
+        let auth = tokio::time::timeout(RESPONSE_TIMEOUT, async {
+            while let Some(message) = websocket.next().await {
+                if let WsMessage::Text(payload) = message.map_err(SocketError::WebSocket)? {
+                    match BybitPrivateMessage::parse(&payload)? {
+                        BybitPrivateMessage::Auth => return Ok(()),
+                        BybitPrivateMessage::Error { op, msg } if op == "auth" => {
+                            return Err(ExecutionError::Unauthorised(format!(
+                                "Bybit auth failed: {msg}"
+                            )))
+                        }
+                        _ => continue,
+                    }
+                }
+            }
+            Err(SocketError::Terminated("closed before auth response".to_string()).into())
+        })
+        .await
+        .map_err(|_| SocketError::Exchange("Bybit auth response timed out".to_string()))?;
+        auth?;
+        debug!("authenticated with Bybit private stream");
+
+        websocket
+            .send(WsMessage::Text(Self::subscribe_request().to_string()))
+            .await
+            .map_err(SocketError::WebSocket)?;
+
+        let mut ping = tokio::time::interval(PING_INTERVAL);
+        ping.tick().await;
+
+        loop {
+            tokio::select! {
+                _ = ping.tick() => {
+                    websocket
+                        .send(WsMessage::Text(json!({"op": "ping"}).to_string()))
+                        .await
+                        .map_err(SocketError::WebSocket)?;
+                }
+                message = websocket.next() => match message {
+                    Some(Ok(WsMessage::Text(payload))) => {
+                        match BybitPrivateMessage::parse(&payload) {
+                            Ok(BybitPrivateMessage::Error { op, msg }) => {
+                                error!(%op, %msg, "Bybit private stream error");
+                            }
+                            Ok(BybitPrivateMessage::Subscribed) => {
+                                debug!("subscribed to Bybit private topics");
+                            }
+                            Ok(message) => {
+                                for kind in message.account_events(self.category, &self.exchange, &self.instruments) {
+                                    let event = AccountEvent {
+                                        received_time: Utc::now(),
+                                        exchange: self.exchange.clone(),
+                                        kind,
+                                    };
+                                    if self.event_tx.send(event).is_err() {
+                                        return Ok(Session::Terminate);
+                                    }
+                                }
+                            }
+                            Err(error) => {
+                                error!(?error, "failed to parse Bybit private stream message");
+                            }
+                        }
+                    }
+                    Some(Ok(WsMessage::Close(frame))) => {
+                        warn!(?frame, "Bybit private stream closed");
+                        return Ok(Session::Reconnect);
+                    }
+                    Some(Ok(_)) => {}
+                    Some(Err(error)) => return Err(SocketError::WebSocket(error).into()),
+                    None => return Ok(Session::Reconnect),
+                },
+            }
+        }
+    }
+
+    /// Construct the auth request, signing `"GET/realtime" + expires` where `expires` is the
+    /// time in milliseconds since the epoch after which the request is rejected.
+    ///
+    /// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect#authentication>
+    pub fn auth_request(&self, expires: i64) -> serde_json::Value {
+        let mac = Hmac::<sha2::Sha256>::new_from_slice(self.credentials.secret.as_bytes())
+            .expect("HMAC accepts keys of any length");
+        let signature = HexEncoder.encode(mac.sign(format!("GET/realtime{expires}").as_bytes()));
+
+        json!({
+            "op": "auth",
+            "args": [self.credentials.api_key, expires, signature]
+        })
+    }
+
+    /// Construct the request subscribing to every private topic.
+    pub fn subscribe_request() -> serde_json::Value {
+        json!({
+            "op": "subscribe",
+            "args": TOPICS
+        })
+    }
+}
+
+/// Bybit v5 private WebSocket messages.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://bybit-exchange.github.io/docs/v5/websocket/private/order>
+/// ```json
+/// {
+///     "id": "5923240c6880ab-c59f-420b-9adb-3639adc9dd90",
+///     "topic": "order",
+///     "creationTime": 1672364262474,
+///     "data": [{"category": "linear", "symbol": "BTCUSDT", "orderStatus": "New"}]
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub enum BybitPrivateMessage {
+    /// Authentication succeeded.
+    Auth,
+    /// Subscription succeeded.
+    Subscribed,
+    /// Authentication or subscription failed.
+    Error { op: String, msg: String },
+    /// "order" topic update.
+    Orders(Vec<BybitOrder>),
+    /// "execution" topic update.
+    Executions(Vec<BybitFill>),
+    /// "wallet" topic balance update.
+    Wallet(Vec<BybitWallet>),
+    /// Response to a "ping".
+    Pong,
+    /// Message not translated into [`AccountEvent`]s.
+    Other(String),
+}
+
+impl BybitPrivateMessage {
+    /// Parse a raw private WebSocket message using it's "op" or "topic".
+    pub fn parse(payload: &str) -> Result<Self, SocketError> {
+        #[derive(Deserialize)]
+        struct RawMessage {
+            op: Option<String>,
+            success: Option<bool>,
+            ret_msg: Option<String>,
+            topic: Option<String>,
+            data: Option<serde_json::Value>,
+        }
+
+        let deserialise = |error| SocketError::Deserialise {
+            error,
+            payload: payload.to_owned(),
+        };
+
+        let message = serde_json::from_str::<RawMessage>(payload).map_err(deserialise)?;
+
+        match (message.op, message.topic, message.data) {
+            (Some(op), _, _) => match (op.as_str(), message.success) {
+                ("pong", _) | ("ping", Some(true)) => Ok(Self::Pong),
+                ("auth", Some(true)) => Ok(Self::Auth),
+                ("subscribe", Some(true)) => Ok(Self::Subscribed),
+                (_, Some(false)) => Ok(Self::Error {
+                    op,
+                    msg: message.ret_msg.unwrap_or_default(),
+                }),
+                _ => Ok(Self::Other(op)),
+            },
+            (None, Some(topic), Some(data)) => match topic.as_str() {
+                "order" => serde_json::from_value(data).map(Self::Orders),
+                "execution" => serde_json::from_value(data).map(Self::Executions),
+                "wallet" => serde_json::from_value(data).map(Self::Wallet),
+                _ => Ok(Self::Other(topic)),
+            }
+            .map_err(deserialise),
+            (None, topic, _) => Ok(Self::Other(topic.unwrap_or_default())),
+        }
+    }
+
+    /// Translate this message into the associated [`AccountEventKind`]s, ignoring orders &
+    /// executions of other categories, and using the provided [`BybitSymbol`] to [`Instrument`]
+    /// map to identify order & trade instruments.
+    pub fn account_events(
+        self,
+        category: &str,
+        exchange: &Exchange,
+        instruments: &HashMap<BybitSymbol, Instrument>,
+    ) -> Vec<AccountEventKind> {
+        let instrument = |symbol: &str| {
+            let instrument = instruments.get(&BybitSymbol(symbol.to_owned()));
+            if instrument.is_none() {
+                warn!(%symbol, "received update for unknown Bybit instrument");
+            }
+            instrument
+        };
+
+        match self {
+            Self::Orders(orders) => orders
+                .into_iter()
+                .filter(|order| order.category == category)
+                .filter_map(|order| {
+                    let instrument = instrument(&order.symbol)?;
+                    order.account_event(exchange, instrument)
+                })
+                .collect(),
+            Self::Executions(executions) => executions
+                .into_iter()
+                .filter(|execution| {
+                    execution.category == category && execution.exec_type == "Trade"
+                })
+                .filter_map(|execution| {
+                    let instrument = instrument(&execution.symbol)?;
+                    Some(AccountEventKind::Trade(execution.trade(instrument)))
+                })
+                .collect(),
+            Self::Wallet(wallets) => {
+                let balances = wallets
+                    .into_iter()
+                    .flat_map(<Vec<SymbolBalance>>::from)
+                    .collect();
+                vec![AccountEventKind::Balances(balances)]
+            }
+            Self::Auth | Self::Subscribed | Self::Error { .. } | Self::Pong | Self::Other(_) => {
+                vec![]
+            }
+        }
+    }
+}
+
+impl BybitOrder {
+    /// Parse the Barter [`ClientOrderId`] from the Bybit `orderLinkId`, if the order was placed
+    /// by Barter.
+    pub fn cid(&self) -> Option<ClientOrderId> {
+        Uuid::parse_str(&self.order_link_id).ok().map(ClientOrderId)
+    }
+
+    /// Translate this order update into the associated [`AccountEventKind`], if any.
+    ///
+    /// Order state events are only generated for orders with a Barter [`ClientOrderId`]. Fills
+    /// are translated from the separate "execution" topic.
+    pub fn account_event(
+        self,
+        exchange: &Exchange,
+        instrument: &Instrument,
+    ) -> Option<AccountEventKind> {
+        let cid = self.cid()?;
+
+        match self.order_status {
+            BybitOrderStatus::New | BybitOrderStatus::Untriggered => {
+                Some(AccountEventKind::OrdersNew(vec![Order {
+                    exchange: exchange.clone(),
+                    instrument: instrument.clone(),
+                    cid,
+                    side: self.side,
+                    state: Open {
+                        id: OrderId::from(self.order_id),
+                        price: self.price,
+                        quantity: self.qty,
+                        filled_quantity: self.cum_exec_qty,
+                    },
+                }]))
+            }
+            BybitOrderStatus::Cancelled
+            | BybitOrderStatus::PartiallyFilledCanceled
+            | BybitOrderStatus::Deactivated => {
+                Some(AccountEventKind::OrdersCancelled(vec![Order {
+                    exchange: exchange.clone(),
+                    instrument: instrument.clone(),
+                    cid,
+                    side: self.side,
+                    state: Cancelled::from(self.order_id),
+                }]))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl BybitFill {
+    /// Translate this execution into a [`Trade`]. Derivative fees are charged in the quote
+    /// (settle) coin, whereas spot fees are charged in the provided `feeCurrency`.
+    pub fn trade(self, instrument: &Instrument) -> Trade {
+        let fees = match self.fee_currency.is_empty() {
+            true => SymbolFees::new(instrument.quote.clone(), self.exec_fee),
+            false => SymbolFees::new(self.fee_currency.as_str(), self.exec_fee),
+        };
+
+        Trade {
+            id: TradeId::from(self.exec_id.as_str()),
+            order_id: OrderId::from(self.order_id),
+            instrument: instrument.clone(),
+            side: self.side,
+            price: self.exec_price,
+            quantity: self.exec_qty,
+            fees,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{execution::binance::connection::LiveOrTest, model::balance::Balance};
+    use barter_integration::model::{
+        instrument::{kind::InstrumentKind, spec::InstrumentSpec},
+        Side,
+    };
+    use rust_decimal_macros::dec;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::accept_async;
+
+    const CID: &str = "b7c8ca81-08a7-4f8f-a3a2-4cf8a8c6e8f0";
+
+    fn specs() -> InstrumentSpecs {
+        InstrumentSpecs::from(vec![
+            InstrumentSpec::new(
+                ("btc", "usdt", InstrumentKind::Perpetual),
+                dec!(0.1),
+                dec!(0.001),
+            ),
+            InstrumentSpec::new(
+                ("btc", "usdt", InstrumentKind::Spot),
+                dec!(0.01),
+                dec!(0.000001),
+            ),
+        ])
+    }
+
+    fn btc_usdt_perpetual() -> Instrument {
+        Instrument::from(("btc", "usdt", InstrumentKind::Perpetual))
+    }
+
+    fn order_message(category: &str, status: &str) -> String {
+        format!(
+            r#"{{
+                "id": "5923240c6880ab-c59f-420b-9adb-3639adc9dd90",
+                "topic": "order",
+                "creationTime": 1672364262474,
+                "data": [{{
+                    "category": "{category}", "symbol": "BTCUSDT", "orderId": "1001",
+                    "orderLinkId": "{CID}", "side": "Sell", "orderType": "Limit",
+                    "price": "30000", "qty": "2", "cumExecQty": "0", "orderStatus": "{status}",
+                    "timeInForce": "GTC", "triggerPrice": "", "reduceOnly": false
+                }}]
+            }}"#
+        )
+    }
+
+    #[test]
+    fn test_bybit_private_message_account_events() {
+        struct TestCase {
+            input: String,
+            expected: Vec<AccountEventKind>,
+        }
+
+        let cid = ClientOrderId(Uuid::parse_str(CID).unwrap());
+        let exchange = Exchange::from(ExecutionId::BybitPerpetualsUsd);
+
+        let tests = vec![
+            TestCase {
+                // TC0: new order -> OrdersNew
+                input: order_message("linear", "New"),
+                expected: vec![AccountEventKind::OrdersNew(vec![Order {
+                    exchange: exchange.clone(),
+                    instrument: btc_usdt_perpetual(),
+                    cid,
+                    side: Side::Sell,
+                    state: Open {
+                        id: OrderId::from("1001"),
+                        price: dec!(30000),
+                        quantity: dec!(2),
+                        filled_quantity: dec!(0),
+                    },
+                }])],
+            },
+            TestCase {
+                // TC1: cancelled order -> OrdersCancelled
+                input: order_message("linear", "Cancelled"),
+                expected: vec![AccountEventKind::OrdersCancelled(vec![Order {
+                    exchange: exchange.clone(),
+                    instrument: btc_usdt_perpetual(),
+                    cid,
+                    side: Side::Sell,
+                    state: Cancelled::from("1001"),
+                }])],
+            },
+            TestCase {
+                // TC2: order of another category is ignored
+                input: order_message("spot", "New"),
+                expected: vec![],
+            },
+            TestCase {
+                // TC3: trade execution -> Trade charged in the settle coin
+                input: r#"{
+                    "id": "592324803b2785-26fa-4214-9963-bdd4727f07be",
+                    "topic": "execution",
+                    "creationTime": 1672364174455,
+                    "data": [
+                        {
+                            "category": "linear", "symbol": "BTCUSDT", "execFee": "0.015",
+                            "execId": "7e2ae69c-4edf-5800-a352-893d52b446aa",
+                            "execPrice": "30000", "execQty": "1", "execType": "Trade",
+                            "execValue": "30000", "feeRate": "0.0005", "orderId": "1001",
+                            "orderLinkId": "b7c8ca81-08a7-4f8f-a3a2-4cf8a8c6e8f0",
+                            "side": "Sell", "isMaker": false, "feeCurrency": ""
+                        },
+                        {
+                            "category": "linear", "symbol": "BTCUSDT", "execFee": "-0.1",
+                            "execId": "funding-1", "execPrice": "30000", "execQty": "0.5",
+                            "execType": "Funding", "orderId": "", "orderLinkId": "",
+                            "side": "Sell"
+                        }
+                    ]
+                }"#
+                .to_string(),
+                expected: vec![AccountEventKind::Trade(Trade {
+                    id: TradeId::from("7e2ae69c-4edf-5800-a352-893d52b446aa"),
+                    order_id: OrderId::from("1001"),
+                    instrument: btc_usdt_perpetual(),
+                    side: Side::Sell,
+                    price: dec!(30000),
+                    quantity: dec!(1),
+                    fees: SymbolFees::new("usdt", dec!(0.015)),
+                })],
+            },
+            TestCase {
+                // TC4: wallet topic -> Balances
+                input: r#"{
+                    "id": "592324d2bce751-ad38-48eb-8f42-4671d1fb4d4e",
+                    "topic": "wallet",
+                    "creationTime": 1700034722104,
+                    "data": [{
+                        "accountType": "UNIFIED", "totalEquity": "4992.89",
+                        "coin": [{
+                            "coin": "USDT", "equity": "4992.89", "walletBalance": "4850.43",
+                            "locked": "0", "totalOrderIM": "10.12", "totalPositionIM": "6"
+                        }]
+                    }]
+                }"#
+                .to_string(),
+                expected: vec![AccountEventKind::Balances(vec![SymbolBalance::new(
+                    "usdt",
+                    Balance::new(dec!(4850.43), dec!(4834.31)),
+                )])],
+            },
+            TestCase {
+                // TC5: order update for unknown instrument is ignored
+                input: order_message("linear", "New").replace("BTCUSDT", "ETHUSDT"),
+                expected: vec![],
+            },
+        ];
+
+        let instruments = instruments_by_symbol(&specs(), BybitApi::Perpetual(LiveOrTest::Live));
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = BybitPrivateMessage::parse(&test.input)
+                .unwrap()
+                .account_events("linear", &exchange, &instruments);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_bybit_private_message_parse_ops() {
+        struct TestCase {
+            input: &'static str,
+            expected: BybitPrivateMessage,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: auth success
+                input: r#"{"success": true, "ret_msg": "", "op": "auth", "conn_id": "cejreaspqfh3sjdnldmg-p"}"#,
+                expected: BybitPrivateMessage::Auth,
+            },
+            TestCase {
+                // TC1: auth failure
+                input: r#"{"success": false, "ret_msg": "Params Error", "op": "auth", "conn_id": "cejreaspqfh3sjdnldmg-p"}"#,
+                expected: BybitPrivateMessage::Error {
+                    op: "auth".to_string(),
+                    msg: "Params Error".to_string(),
+                },
+            },
+            TestCase {
+                // TC2: subscription success
+                input: r#"{"success": true, "ret_msg": "", "op": "subscribe", "conn_id": "cejreassvfrsfvb9v1a0-2m"}"#,
+                expected: BybitPrivateMessage::Subscribed,
+            },
+            TestCase {
+                // TC3: private pong
+                input: r#"{"req_id": "", "op": "pong", "args": ["1675418560633"], "conn_id": "cfcb4ocsvfriu23r3er0-1b"}"#,
+                expected: BybitPrivateMessage::Pong,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = BybitPrivateMessage::parse(test.input).unwrap();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_bybit_auth_request_signature() {
+        // Expected signature generated independently with the Python `hmac` module
+        let (event_tx, _event_rx) = mpsc::unbounded_channel();
+        let stream = BybitPrivateStream::new(
+            BybitApi::Perpetual(LiveOrTest::Live),
+            BybitCredentials::new("key", "secret"),
+            &specs(),
+            event_tx,
+        );
+
+        assert_eq!(
+            stream.auth_request(1662350400000),
+            json!({
+                "op": "auth",
+                "args": ["key", 1662350400000_i64, "d7ca36fea9ef1287007fd4b15af961e91d419a3d3f3ccbdf23585170ac116cd4"]
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_bybit_private_stream_stub() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+
+        // Stub Bybit private WebSocket: confirm auth & subscription, then push an order update
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = accept_async(stream).await.unwrap();
+
+            let auth = websocket
+                .next()
+                .await
+                .unwrap()
+                .unwrap()
+                .into_text()
+                .unwrap();
+            let auth = serde_json::from_str::<serde_json::Value>(&auth).unwrap();
+            assert_eq!(auth["op"], "auth");
+            assert_eq!(auth["args"][0], "key");
+            websocket
+                .send(WsMessage::Text(
+                    r#"{"success": true, "ret_msg": "", "op": "auth", "conn_id": "1"}"#.to_string(),
+                ))
+                .await
+                .unwrap();
+
+            let subscribe = websocket
+                .next()
+                .await
+                .unwrap()
+                .unwrap()
+                .into_text()
+                .unwrap();
+            let subscribe = serde_json::from_str::<serde_json::Value>(&subscribe).unwrap();
+            assert_eq!(subscribe, BybitPrivateStream::subscribe_request());
+            websocket
+                .send(WsMessage::Text(
+                    r#"{"success": true, "ret_msg": "", "op": "subscribe", "conn_id": "1"}"#
+                        .to_string(),
+                ))
+                .await
+                .unwrap();
+
+            websocket
+                .send(WsMessage::Text(order_message("linear", "New")))
+                .await
+                .unwrap();
+
+            // Keep the connection open until the client disconnects
+            while websocket.next().await.is_some() {}
+        });
+
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+        let stream = BybitPrivateStream::new(
+            BybitApi::Perpetual(LiveOrTest::Live),
+            BybitCredentials::new("key", "secret"),
+            &specs(),
+            event_tx,
+        )
+        .with_url(url)
+        .spawn();
+
+        let event = tokio::time::timeout(Duration::from_secs(5), event_rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(
+            event.exchange,
+            Exchange::from(ExecutionId::BybitPerpetualsUsd)
+        );
+        assert!(matches!(
+            event.kind,
+            AccountEventKind::OrdersNew(orders) if orders[0].cid == ClientOrderId(Uuid::parse_str(CID).unwrap())
+        ));
+
+        stream.abort();
+        server.abort();
+    }
+}
@@ -1,2 +1,9 @@
 /// `Binance` & `BinanceFuturesUsd` [`ExecutionClient`](crate::ExecutionClient) implementations.
 pub mod binance;
+
+/// `BybitSpot` & `BybitPerpetualsUsd` [`ExecutionClient`](crate::ExecutionClient)
+/// implementations.
+pub mod bybit;
+
+/// `Okx` [`ExecutionClient`](crate::ExecutionClient) implementation.
+pub mod okx;
@@ -0,0 +1,596 @@
+use std::{fmt::Debug, marker::PhantomData, time::Duration};
+
+use barter_integration::{
+    error::SocketError,
+    metric::registry::MetricRegistry,
+    model::{instrument::kind::InstrumentKind, Side},
+    protocol::http::{
+        private::{
+            encoder::Base64Encoder,
+            get_base64_signer, prehash,
+            time_sync::{TimeOffset, TimeSync},
+            RequestSigner, Signer,
+        },
+        public::PublicNoHeaders,
+        rest::{
+            client::RestClient,
+            rate_limit::{RateLimit, RateLimitKind, RateLimiter},
+            ApiRequest, RestRequest,
+        },
+        HttpParser,
+    },
+};
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use hmac::Hmac;
+use reqwest::{RequestBuilder, StatusCode};
+use serde::Deserialize;
+
+use super::{
+    requests::{
+        okx_error, orders_pending_query, OkxAmendOrder, OkxCancelOrder, OkxOrder, OkxOrderAck,
+        OkxPlaceOrder, OkxResponse, OkxServerTime, BALANCES_REQUEST, ORDERS_PENDING_PAGE_LIMIT,
+        ORDERS_PENDING_REQUEST, TIME_REQUEST,
+    },
+    OkxInstId,
+};
+use crate::{
+    error::ExecutionError,
+    execution::binance::connection::LiveOrTest,
+    model::{
+        balance::SymbolBalance,
+        order::{Order, OrderKind, RequestCancel, RequestOpen},
+    },
+};
+
+/// OKX REST base url, shared by live & demo trading.
+pub const OKX_BASE_URL: &str = "https://www.okx.com";
+
+pub type OkxInternalClient =
+    RestClient<RequestSigner<OkxSigner, Hmac<sha2::Sha256>, Base64Encoder>, OkxParser>;
+
+/// [`TimeSync`] service that keeps an [`OkxClient`] [`TimeOffset`] synchronised with the OKX
+/// server time.
+pub type OkxTimeSync =
+    TimeSync<PublicNoHeaders, OkxParser, ApiRequest<OkxResponse<OkxServerTime>, ()>>;
+
+/// OKX API key, secret & passphrase.
+#[derive(Clone)]
+pub struct OkxCredentials {
+    pub api_key: String,
+    pub secret: String,
+    pub passphrase: String,
+}
+
+impl Debug for OkxCredentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OkxCredentials")
+            .field("api_key", &self.api_key)
+            .finish_non_exhaustive()
+    }
+}
+
+impl OkxCredentials {
+    pub fn new<S>(api_key: S, secret: S, passphrase: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            api_key: api_key.into(),
+            secret: secret.into(),
+            passphrase: passphrase.into(),
+        }
+    }
+
+    /// Load [`OkxCredentials`] from the "OKX_API_KEY", "OKX_SECRET" & "OKX_PASSPHRASE"
+    /// environment variables, or their "OKX_TEST_*" equivalents for demo trading.
+    pub fn from_env(kind: LiveOrTest) -> Self {
+        let prefix = match kind {
+            LiveOrTest::Live => "OKX",
+            LiveOrTest::Test => "OKX_TEST",
+        };
+        let var = |name: &str| {
+            let key = format!("{prefix}_{name}");
+            std::env::var(&key).unwrap_or_else(|_| panic!("{key} must be set."))
+        };
+
+        Self::new(var("API_KEY"), var("SECRET"), var("PASSPHRASE"))
+    }
+}
+
+/// OKX REST client. Http request duration [`Metric`](barter_integration::metric::Metric)s are
+/// aggregated by the [`MetricRegistry::global`] registry, so an [`OkxClient`] must be constructed
+/// from within a tokio runtime.
+///
+/// [`LiveOrTest::Test`] clients trade on the OKX demo trading environment.
+#[derive(Debug, Clone)]
+pub struct OkxClient {
+    pub client: OkxInternalClient,
+    pub kind: LiveOrTest,
+    /// Server time offset shared with the [`OkxSigner`], updated by an [`OkxTimeSync`].
+    pub time_offset: TimeOffset,
+}
+
+impl OkxClient {
+    pub fn new(kind: LiveOrTest, credentials: OkxCredentials) -> Self {
+        Self::new_with_url(kind, credentials, OKX_BASE_URL.to_string())
+    }
+
+    pub fn new_with_url(kind: LiveOrTest, credentials: OkxCredentials, url: String) -> Self {
+        // Send Http execution metrics over the Metric channel shared by every client, aggregated
+        // by the global MetricRegistry
+        let http_metric_tx = MetricRegistry::global_sender();
+
+        let time_offset = TimeOffset::new();
+        let request_signer = get_base64_signer(
+            &credentials.secret,
+            OkxSigner {
+                api_key: credentials.api_key,
+                passphrase: credentials.passphrase,
+                time_offset: time_offset.clone(),
+                simulated: matches!(kind, LiveOrTest::Test),
+            },
+        );
+
+        let client = RestClient::new(url, http_metric_tx, request_signer, OkxParser)
+            .with_rate_limiter(Self::rate_limiter());
+
+        Self {
+            client,
+            kind,
+            time_offset,
+        }
+    }
+
+    /// Construct an [`OkxTimeSync`] service that updates the [`TimeOffset`] used to sign
+    /// requests. Server time requests share the [`RateLimiter`] of this client.
+    pub fn time_sync(&self) -> OkxTimeSync {
+        let client = RestClient::new(
+            self.client.base_url.clone(),
+            self.client.metric_tx.clone(),
+            PublicNoHeaders,
+            OkxParser,
+        )
+        .with_rate_limiter(self.client.rate_limiter.clone());
+
+        TimeSync::new(client, TIME_REQUEST, self.time_offset.clone())
+    }
+
+    /// Construct a [`RateLimiter`] approximating the OKX per endpoint limits of 60 trade
+    /// requests every 2 seconds. Balance requests are weighted to respect their lower limit.
+    pub fn rate_limiter() -> RateLimiter {
+        const TWO_SECONDS: Duration = Duration::from_secs(2);
+
+        RateLimiter::new([
+            RateLimit::new(RateLimitKind::Weight, 60, TWO_SECONDS),
+            RateLimit::new(RateLimitKind::Orders, 60, TWO_SECONDS),
+        ])
+    }
+
+    pub async fn send<Request>(&self, request: Request) -> Result<Request::Response, ExecutionError>
+    where
+        Request: RestRequest,
+        <Request as RestRequest>::Response: Debug,
+    {
+        self.client.execute(request).await
+    }
+
+    /// Place an [`Order<RequestOpen>`], returning the OKX order id.
+    pub async fn open_order(&self, order: &Order<RequestOpen>) -> Result<String, ExecutionError> {
+        let request: ApiRequest<OkxResponse<OkxOrderAck>, OkxPlaceOrder> = ApiRequest {
+            path: "/api/v5/trade/order",
+            method: reqwest::Method::POST,
+            tag_method: "open_order",
+            body: Some(Self::place_order(order)?),
+            query_params: None,
+            weight: 1,
+            order_count: 1,
+            response: PhantomData,
+        };
+
+        self.client.execute(request).await?.into_order_id()
+    }
+
+    /// Map an [`Order<RequestOpen>`] to an [`OkxPlaceOrder`] request body.
+    ///
+    /// Conditional [`OrderKind`]s are placed via the separate OKX algo order API, which is not
+    /// supported. Spot orders are placed in "cash" mode and derivatives in "cross" margin mode.
+    pub fn place_order(order: &Order<RequestOpen>) -> Result<OkxPlaceOrder, ExecutionError> {
+        let ord_type = match order.state.kind {
+            OrderKind::Market => "market",
+            OrderKind::Limit => "limit",
+            OrderKind::PostOnly => "post_only",
+            OrderKind::ImmediateOrCancel => "ioc",
+            kind => return Err(ExecutionError::UnsupportedOrderKind(kind)),
+        };
+
+        let inst_id = OkxInstId::new(&order.instrument).ok_or_else(|| {
+            ExecutionError::Socket(SocketError::Unsupported {
+                entity: "Okx",
+                item: order.instrument.kind.to_string(),
+            })
+        })?;
+        let spot = order.instrument.kind == InstrumentKind::Spot;
+
+        Ok(OkxPlaceOrder {
+            inst_id: inst_id.0,
+            td_mode: if spot { "cash" } else { "cross" },
+            cl_ord_id: order.cid.0.simple().to_string(),
+            side: match order.side {
+                Side::Buy => "buy",
+                Side::Sell => "sell",
+            },
+            ord_type,
+            sz: order.state.quantity,
+            px: order
+                .state
+                .kind
+                .has_limit_price()
+                .then_some(order.state.price),
+            tgt_ccy: (spot && order.state.kind == OrderKind::Market).then_some("base_ccy"),
+            reduce_only: order.state.reduce_only && !spot,
+        })
+    }
+
+    /// Cancel an [`Order<RequestCancel>`], returning the OKX order id.
+    pub async fn cancel_order(
+        &self,
+        order: &Order<RequestCancel>,
+    ) -> Result<String, ExecutionError> {
+        let inst_id = OkxInstId::new(&order.instrument).ok_or_else(|| {
+            ExecutionError::Socket(SocketError::Unsupported {
+                entity: "Okx",
+                item: order.instrument.kind.to_string(),
+            })
+        })?;
+
+        let request: ApiRequest<OkxResponse<OkxOrderAck>, OkxCancelOrder> = ApiRequest {
+            path: "/api/v5/trade/cancel-order",
+            method: reqwest::Method::POST,
+            tag_method: "cancel_order",
+            body: Some(OkxCancelOrder {
+                inst_id: inst_id.0,
+                ord_id: order.state.id.0.clone(),
+            }),
+            query_params: None,
+            weight: 1,
+            order_count: 0,
+            response: PhantomData,
+        };
+
+        self.client.execute(request).await?.into_order_id()
+    }
+
+    /// Cancel up to [`BATCH_ORDERS_MAX`](super::requests::BATCH_ORDERS_MAX) orders in a single
+    /// request, returning the per order results.
+    pub async fn cancel_orders_batch(
+        &self,
+        orders: Vec<OkxCancelOrder>,
+    ) -> Result<Vec<OkxOrderAck>, ExecutionError> {
+        let request: ApiRequest<OkxResponse<OkxOrderAck>, Vec<OkxCancelOrder>> = ApiRequest {
+            path: "/api/v5/trade/cancel-batch-orders",
+            method: reqwest::Method::POST,
+            tag_method: "cancel_orders_batch",
+            body: Some(orders),
+            query_params: None,
+            weight: 1,
+            order_count: 0,
+            response: PhantomData,
+        };
+
+        // Partially failed batches have a non-zero code, but still contain every per order result
+        let response = self.client.execute(request).await?;
+        match response.data.is_empty() {
+            true => response.into_data(),
+            false => Ok(response.data),
+        }
+    }
+
+    /// Amend the size and/or price of an open order, returning the OKX order id.
+    pub async fn amend_order(&self, amend: OkxAmendOrder) -> Result<String, ExecutionError> {
+        let request: ApiRequest<OkxResponse<OkxOrderAck>, OkxAmendOrder> = ApiRequest {
+            path: "/api/v5/trade/amend-order",
+            method: reqwest::Method::POST,
+            tag_method: "amend_order",
+            body: Some(amend),
+            query_params: None,
+            weight: 1,
+            order_count: 1,
+            response: PhantomData,
+        };
+
+        self.client.execute(request).await?.into_order_id()
+    }
+
+    /// Fetch every pending order of the account, across all instrument types, following the
+    /// `after` order id of the last order of each page until every page is fetched.
+    pub async fn fetch_open_orders(&self) -> Result<Vec<OkxOrder>, ExecutionError> {
+        let mut orders: Vec<OkxOrder> = Vec::new();
+
+        loop {
+            let after = orders.last().map(|order| order.ord_id.as_str());
+            let request = ApiRequest {
+                query_params: Some(orders_pending_query(after)),
+                ..ORDERS_PENDING_REQUEST
+            };
+
+            let page = self.client.execute(request).await?.into_data()?;
+            let is_last_page = page.len() < ORDERS_PENDING_PAGE_LIMIT;
+            orders.extend(page);
+
+            if is_last_page {
+                break Ok(orders);
+            }
+        }
+    }
+
+    /// Fetch the trading account balance of every currency.
+    pub async fn fetch_balances(&self) -> Result<Vec<SymbolBalance>, ExecutionError> {
+        Ok(self
+            .client
+            .execute(BALANCES_REQUEST)
+            .await?
+            .into_data()?
+            .into_iter()
+            .flat_map(<Vec<SymbolBalance>>::from)
+            .collect())
+    }
+
+    /// Return the private WebSocket url of the provided environment.
+    pub fn websocket_url(kind: LiveOrTest) -> &'static str {
+        match kind {
+            LiveOrTest::Live => "wss://ws.okx.com:8443/ws/v5/private",
+            LiveOrTest::Test => "wss://wspap.okx.com:8443/ws/v5/private?brokerId=9999",
+        }
+    }
+}
+
+/// Format milliseconds since the epoch as the ISO-8601 millisecond timestamp used by OKX
+/// (eg/ "2020-12-08T09:08:57.715Z").
+pub fn okx_timestamp(time_ms: i64) -> String {
+    DateTime::from_timestamp_millis(time_ms)
+        .unwrap_or_else(Utc::now)
+        .format("%Y-%m-%dT%H:%M:%S%.3fZ")
+        .to_string()
+}
+
+/// OKX [`Signer`] that signs `timestamp + method + request_path + body`.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#overview-rest-authentication-signature>
+#[derive(Debug, Clone)]
+pub struct OkxSigner {
+    pub api_key: String,
+    pub passphrase: String,
+    /// Offset between the OKX server clock and the local clock, used to generate the request
+    /// timestamp.
+    pub time_offset: TimeOffset,
+    /// Adds the "x-simulated-trading" header required by the OKX demo trading environment.
+    pub simulated: bool,
+}
+
+#[derive(Debug)]
+pub struct OkxSignConfig<'a> {
+    api_key: &'a str,
+    passphrase: &'a str,
+    simulated: bool,
+    timestamp: String,
+    method: reqwest::Method,
+    request_path: String,
+    body: String,
+}
+
+impl Signer for OkxSigner {
+    type Config<'a>
+        = OkxSignConfig<'a>
+    where
+        Self: 'a;
+
+    fn config<'a, Request>(
+        &'a self,
+        _: &Request,
+        builder: RequestBuilder,
+    ) -> Result<(Self::Config<'a>, RequestBuilder), SocketError>
+    where
+        Request: RestRequest,
+    {
+        // Build the request to extract the path, query string & serialised body to sign
+        let (client, request) = builder.build_split();
+        let request = request?;
+
+        let url = request.url();
+        let request_path = match url.query() {
+            Some(query) => format!("{}?{query}", url.path()),
+            None => url.path().to_string(),
+        };
+        let body = request
+            .body()
+            .and_then(reqwest::Body::as_bytes)
+            .map(|body| String::from_utf8_lossy(body).into_owned())
+            .unwrap_or_default();
+
+        let config = OkxSignConfig {
+            api_key: self.api_key.as_str(),
+            passphrase: self.passphrase.as_str(),
+            simulated: self.simulated,
+            timestamp: okx_timestamp(self.time_offset.server_time_millis()),
+            method: request.method().clone(),
+            request_path,
+            body,
+        };
+
+        Ok((config, RequestBuilder::from_parts(client, request)))
+    }
+
+    fn bytes_to_sign<'a>(config: &Self::Config<'a>) -> Bytes {
+        prehash::timestamp_method_path_body(
+            &config.timestamp,
+            &config.method,
+            &config.request_path,
+            &config.body,
+        )
+    }
+
+    fn build_signed_request<'a>(
+        config: Self::Config<'a>,
+        builder: RequestBuilder,
+        signature: String,
+    ) -> Result<reqwest::Request, SocketError> {
+        let builder = builder
+            .header("OK-ACCESS-KEY", config.api_key)
+            .header("OK-ACCESS-SIGN", signature)
+            .header("OK-ACCESS-TIMESTAMP", config.timestamp)
+            .header("OK-ACCESS-PASSPHRASE", config.passphrase);
+
+        match config.simulated {
+            true => builder.header("x-simulated-trading", "1"),
+            false => builder,
+        }
+        .build()
+        .map_err(SocketError::from)
+    }
+}
+
+/// OKX error response, sent with a non-zero `code` and an optional `data` array.
+#[derive(Debug, Deserialize)]
+pub struct OkxApiError {
+    pub code: String,
+    pub msg: String,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct OkxParser;
+
+impl HttpParser for OkxParser {
+    type ApiError = OkxApiError;
+    type OutputError = ExecutionError;
+
+    fn parse_api_error(
+        &self,
+        status: StatusCode,
+        api_error: Self::ApiError,
+        _: serde_json::Error,
+    ) -> Self::OutputError {
+        match status {
+            StatusCode::UNAUTHORIZED => ExecutionError::Unauthorised(format!(
+                "OKX error code {}: {}",
+                api_error.code, api_error.msg
+            )),
+            _ => okx_error(&api_error.code, &api_error.msg),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        model::{order::OrderKind, ClientOrderId},
+        ExecutionId,
+    };
+    use barter_integration::model::{instrument::Instrument, Exchange};
+    use rust_decimal_macros::dec;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_okx_client_new_outside_runtime() {
+        let credentials = || OkxCredentials {
+            api_key: "key".to_string(),
+            secret: "secret".to_string(),
+            passphrase: "passphrase".to_string(),
+        };
+
+        // Every client shares the global Metric channel & recorder
+        let first = OkxClient::new(LiveOrTest::Test, credentials());
+        let second = OkxClient::new(LiveOrTest::Test, credentials());
+        assert!(first
+            .client
+            .metric_tx
+            .same_channel(&second.client.metric_tx));
+    }
+
+    #[test]
+    fn test_okx_place_order() {
+        struct TestCase {
+            instrument: Instrument,
+            side: Side,
+            kind: OrderKind,
+            reduce_only: bool,
+            expected: Result<serde_json::Value, ()>,
+        }
+
+        let cid = "b7c8ca8108a74f8fa3a24cf8a8c6e8f0";
+
+        let tests = vec![
+            TestCase {
+                // TC0: spot market buy is sized in the base currency
+                instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                side: Side::Buy,
+                kind: OrderKind::Market,
+                reduce_only: true,
+                expected: Ok(serde_json::json!({
+                    "instId": "BTC-USDT", "tdMode": "cash", "clOrdId": cid, "side": "buy",
+                    "ordType": "market", "sz": "0.5", "tgtCcy": "base_ccy"
+                })),
+            },
+            TestCase {
+                // TC1: perpetual reduce only limit sell
+                instrument: Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+                side: Side::Sell,
+                kind: OrderKind::Limit,
+                reduce_only: true,
+                expected: Ok(serde_json::json!({
+                    "instId": "BTC-USDT-SWAP", "tdMode": "cross", "clOrdId": cid,
+                    "side": "sell", "ordType": "limit", "sz": "0.5", "px": "30000",
+                    "reduceOnly": true
+                })),
+            },
+            TestCase {
+                // TC2: perpetual post only
+                instrument: Instrument::from(("eth", "usdt", InstrumentKind::Perpetual)),
+                side: Side::Buy,
+                kind: OrderKind::PostOnly,
+                reduce_only: false,
+                expected: Ok(serde_json::json!({
+                    "instId": "ETH-USDT-SWAP", "tdMode": "cross", "clOrdId": cid,
+                    "side": "buy", "ordType": "post_only", "sz": "0.5", "px": "30000"
+                })),
+            },
+            TestCase {
+                // TC3: conditional OrderKind is unsupported
+                instrument: Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+                side: Side::Sell,
+                kind: OrderKind::StopMarket {
+                    trigger_price: dec!(29000),
+                },
+                reduce_only: true,
+                expected: Err(()),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let order = Order {
+                exchange: Exchange::from(ExecutionId::Okx),
+                instrument: test.instrument,
+                cid: ClientOrderId(Uuid::parse_str(cid).unwrap()),
+                side: test.side,
+                state: RequestOpen {
+                    kind: test.kind,
+                    price: dec!(30000),
+                    quantity: dec!(0.5),
+                    reduce_only: test.reduce_only,
+                },
+            };
+
+            let actual = OkxClient::place_order(&order)
+                .map(|body| serde_json::to_value(body).unwrap())
+                .map_err(|_| ());
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_okx_timestamp() {
+        assert_eq!(okx_timestamp(1607418537715), "2020-12-08T09:08:57.715Z");
+        assert_eq!(okx_timestamp(1607418537000), "2020-12-08T09:08:57.000Z");
+    }
+}
@@ -0,0 +1,654 @@
+use async_trait::async_trait;
+use barter_data::exchange::okx::spec::fetch_instrument_specs;
+use barter_integration::{
+    error::SocketError,
+    model::{
+        instrument::{
+            kind::{InstrumentKind, OptionKind},
+            spec::InstrumentSpecs,
+            Instrument,
+        },
+        Exchange,
+    },
+};
+use chrono::{DateTime, Utc};
+use futures::future::join_all;
+use std::collections::HashMap;
+use tokio::sync::mpsc;
+use tracing::{error, warn};
+
+use crate::{
+    error::ExecutionError,
+    execution::binance::connection::LiveOrTest,
+    model::{
+        balance::SymbolBalance,
+        order::{Cancelled, Open, Order, OrderId, RequestAmend, RequestCancel, RequestOpen},
+        AccountEvent,
+    },
+    ExecutionClient, ExecutionId,
+};
+
+use self::{
+    connection::{OkxClient, OkxCredentials},
+    requests::{OkxAmendOrder, OkxCancelOrder, OkxOrder, BATCH_ORDERS_MAX},
+    websocket::OkxPrivateStream,
+};
+
+pub mod connection;
+pub mod requests;
+pub mod websocket;
+
+/// OKX [`ExecutionClient`] implementation supporting spot, futures & perpetual instruments.
+#[derive(Debug)]
+pub struct OkxExecution {
+    client: OkxClient,
+    specs: InstrumentSpecs,
+    instruments: HashMap<OkxInstId, Instrument>,
+}
+
+/// Config for initializing an [`OkxExecution`] instance.
+#[derive(Debug, Clone)]
+pub struct OkxConfig {
+    /// [`LiveOrTest::Test`] trades on the OKX demo trading environment.
+    pub kind: LiveOrTest,
+    pub credentials: OkxCredentials,
+    /// [`InstrumentSpecs`] used to round & validate [`Order<RequestOpen>`]s before submission.
+    /// If `None`, the spot, futures & perpetual specs are fetched from the OKX instruments
+    /// endpoint on init.
+    pub instrument_specs: Option<InstrumentSpecs>,
+    /// [`AccountEvent`] transmitter used by the supervised private WebSocket.
+    pub event_account_tx: mpsc::UnboundedSender<AccountEvent>,
+}
+
+#[async_trait]
+impl ExecutionClient for OkxExecution {
+    type Config = OkxConfig;
+
+    fn exchange(&self) -> Exchange {
+        Exchange::from(ExecutionId::Okx)
+    }
+
+    async fn init(config: Self::Config) -> Self {
+        let client = OkxClient::new(config.kind, config.credentials.clone());
+
+        // Synchronise with the OKX server time before any signed requests are sent
+        let time_sync = client.time_sync();
+        if let Err(error) = time_sync.sync().await {
+            warn!(
+                ?error,
+                "failed to synchronise OKX server time, assuming local time"
+            );
+        }
+        tokio::spawn(time_sync.run());
+
+        let specs = match config.instrument_specs {
+            Some(specs) => specs,
+            None => {
+                let mut specs = InstrumentSpecs::new();
+                for instrument_type in ["SPOT", "FUTURES", "SWAP"] {
+                    match fetch_instrument_specs(instrument_type).await {
+                        Ok(fetched) => specs.extend(fetched.iter().cloned()),
+                        Err(error) => error!(
+                            ?error,
+                            instrument_type,
+                            "failed to fetch OKX InstrumentSpecs, Order<RequestOpen>s will not be validated"
+                        ),
+                    }
+                }
+                specs
+            }
+        };
+
+        // Translate private WebSocket messages into AccountEvents
+        OkxPrivateStream::new(
+            config.kind,
+            config.credentials,
+            &specs,
+            config.event_account_tx,
+        )
+        .spawn();
+
+        Self::new(client, specs)
+    }
+
+    async fn fetch_orders_open(&self) -> Result<Vec<Order<Open>>, ExecutionError> {
+        let orders = self.client.fetch_open_orders().await?;
+
+        Ok(orders
+            .into_iter()
+            .filter_map(|order| self.order_open(order))
+            .collect())
+    }
+
+    async fn fetch_balances(&self) -> Result<Vec<SymbolBalance>, ExecutionError> {
+        self.client.fetch_balances().await
+    }
+
+    async fn open_orders(
+        &self,
+        open_requests: Vec<Order<RequestOpen>>,
+    ) -> Vec<Result<Order<Open>, ExecutionError>> {
+        let tasks = open_requests.into_iter().map(|open_request| {
+            // Round & validate Order<RequestOpen> to the exchange trading rules, if known
+            let open_request = match self.specs.get(&open_request.instrument) {
+                Some(spec) => open_request.conform(spec),
+                None => Ok(open_request),
+            };
+
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                let open_request = open_request?;
+                match client.open_order(&open_request).await {
+                    Ok(order_id) => {
+                        Ok(Order::<Open>::from((OrderId::from(order_id), open_request)))
+                    }
+                    Err(error) => {
+                        error!(?error, "failed to open OKX order");
+                        Err(error)
+                    }
+                }
+            })
+        });
+
+        join_all(tasks)
+            .await
+            .into_iter()
+            .map(|res| res.unwrap())
+            .collect()
+    }
+
+    async fn cancel_orders(
+        &self,
+        cancel_requests: Vec<Order<RequestCancel>>,
+    ) -> Vec<Result<Order<Cancelled>, ExecutionError>> {
+        let tasks = cancel_requests.into_iter().map(|cancel_request| {
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                match client.cancel_order(&cancel_request).await {
+                    Ok(order_id) => Ok(Order {
+                        exchange: cancel_request.exchange,
+                        instrument: cancel_request.instrument,
+                        cid: cancel_request.cid,
+                        side: cancel_request.side,
+                        state: Cancelled::from(order_id),
+                    }),
+                    Err(error) => {
+                        error!(?error, "failed to cancel OKX order");
+                        Err(error)
+                    }
+                }
+            })
+        });
+
+        join_all(tasks)
+            .await
+            .into_iter()
+            .map(|res| res.unwrap())
+            .collect()
+    }
+
+    async fn amend_orders(
+        &self,
+        amend_requests: Vec<Order<RequestAmend>>,
+    ) -> Vec<Result<Order<Open>, ExecutionError>> {
+        let tasks = amend_requests.into_iter().map(|amend_request| {
+            // Round & validate Order<RequestAmend> to the exchange trading rules, if known
+            let amend_request = match self.specs.get(&amend_request.instrument) {
+                Some(spec) => amend_request.conform(spec),
+                None => Ok(amend_request),
+            };
+
+            let client = self.client.clone();
+            tokio::spawn(async move {
+                let amend_request = amend_request?;
+                let inst_id = OkxInstId::new(&amend_request.instrument).ok_or_else(|| {
+                    ExecutionError::Socket(SocketError::Unsupported {
+                        entity: "Okx",
+                        item: amend_request.instrument.kind.to_string(),
+                    })
+                })?;
+
+                let amend = OkxAmendOrder {
+                    inst_id: inst_id.0,
+                    ord_id: amend_request.state.id.0.clone(),
+                    new_sz: Some(amend_request.state.quantity),
+                    new_px: amend_request
+                        .state
+                        .kind
+                        .has_limit_price()
+                        .then_some(amend_request.state.price),
+                };
+
+                match client.amend_order(amend).await {
+                    Ok(order_id) => Ok(Order::<Open>::from((
+                        OrderId::from(order_id),
+                        amend_request,
+                    ))),
+                    Err(error) => {
+                        error!(?error, "failed to amend OKX order");
+                        Err(error)
+                    }
+                }
+            })
+        });
+
+        join_all(tasks)
+            .await
+            .into_iter()
+            .map(|res| res.unwrap())
+            .collect()
+    }
+
+    async fn cancel_orders_all(&self) -> Result<Vec<Order<Cancelled>>, ExecutionError> {
+        // OKX has no cancel all endpoint, so cancel every open order in batches
+        let orders = self
+            .client
+            .fetch_open_orders()
+            .await?
+            .into_iter()
+            .filter_map(|order| self.order_open(order))
+            .collect::<Vec<_>>();
+
+        let mut cancelled = Vec::with_capacity(orders.len());
+        for batch in orders.chunks(BATCH_ORDERS_MAX) {
+            let requests = batch
+                .iter()
+                .filter_map(|order| {
+                    Some(OkxCancelOrder {
+                        inst_id: OkxInstId::new(&order.instrument)?.0,
+                        ord_id: order.state.id.0.clone(),
+                    })
+                })
+                .collect();
+
+            let acks = self.client.cancel_orders_batch(requests).await?;
+            for ack in acks {
+                let ord_id = match ack.into_result() {
+                    Ok(ord_id) => ord_id,
+                    Err(error) => {
+                        warn!(?error, "failed to cancel OKX order");
+                        continue;
+                    }
+                };
+
+                if let Some(order) = batch.iter().find(|order| order.state.id.0 == ord_id) {
+                    cancelled.push(Order {
+                        exchange: order.exchange.clone(),
+                        instrument: order.instrument.clone(),
+                        cid: order.cid,
+                        side: order.side,
+                        state: Cancelled::from(ord_id),
+                    });
+                }
+            }
+        }
+
+        Ok(cancelled)
+    }
+}
+
+impl OkxExecution {
+    /// Construct an [`OkxExecution`] from an existing [`OkxClient`], without synchronising the
+    /// server time or spawning an [`OkxPrivateStream`].
+    pub fn new(client: OkxClient, specs: InstrumentSpecs) -> Self {
+        Self {
+            client,
+            instruments: instruments_by_inst_id(&specs),
+            specs,
+        }
+    }
+
+    /// Return the [`OkxClient`] used to send REST requests (eg/ to cancel order batches).
+    pub fn client(&self) -> &OkxClient {
+        &self.client
+    }
+
+    /// Map an [`OkxOrder`] to an [`Order<Open>`]. Returns `None` for orders with an unknown
+    /// instrument, or without a Barter [`ClientOrderId`](crate::model::ClientOrderId).
+    fn order_open(&self, order: OkxOrder) -> Option<Order<Open>> {
+        let Some(instrument) = self.instruments.get(&OkxInstId(order.inst_id.clone())) else {
+            warn!(inst_id = %order.inst_id, "ignoring open order with unknown OKX instrument");
+            return None;
+        };
+
+        Some(Order {
+            exchange: self.exchange(),
+            instrument: instrument.clone(),
+            cid: order.cid()?,
+            side: order.side,
+            state: Open {
+                id: OrderId::from(order.ord_id),
+                price: order.px,
+                quantity: order.sz,
+                filled_quantity: order.acc_fill_sz,
+            },
+        })
+    }
+}
+
+/// OKX instrument id (eg/ "BTC-USDT", "BTC-USDT-SWAP", "BTC-USD-230526").
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-rest-api-get-instruments>
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct OkxInstId(pub String);
+
+impl OkxInstId {
+    /// Construct the [`OkxInstId`] of an [`Instrument`]. Returns `None` for
+    /// [`InstrumentKind`]s that OKX does not list.
+    pub fn new(instrument: &Instrument) -> Option<Self> {
+        let Instrument { base, quote, kind } = instrument;
+
+        let inst_id = match kind {
+            InstrumentKind::Spot => format!("{base}-{quote}"),
+            InstrumentKind::Perpetual => format!("{base}-{quote}-SWAP"),
+            InstrumentKind::Future(future) => {
+                format!("{base}-{quote}-{}", format_expiry(future.expiry))
+            }
+            InstrumentKind::Option(option) => format!(
+                "{base}-{quote}-{}-{}-{}",
+                format_expiry(option.expiry),
+                option.strike,
+                match option.kind {
+                    OptionKind::Call => "C",
+                    OptionKind::Put => "P",
+                }
+            ),
+            InstrumentKind::IntentOrder => return None,
+        };
+
+        Some(Self(inst_id.to_uppercase()))
+    }
+}
+
+/// Format an expiry as used in OKX instrument ids (eg/ "230526" for the 26th of May 2023).
+fn format_expiry(expiry: DateTime<Utc>) -> String {
+    expiry.date_naive().format("%y%m%d").to_string()
+}
+
+/// Map every [`Instrument`] listed by OKX to the [`OkxInstId`] used by the OKX REST & private
+/// WebSocket APIs.
+pub(crate) fn instruments_by_inst_id(specs: &InstrumentSpecs) -> HashMap<OkxInstId, Instrument> {
+    specs
+        .iter()
+        .filter_map(|spec| {
+            let instrument = spec.instrument.clone();
+            OkxInstId::new(&instrument).map(|inst_id| (inst_id, instrument))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{balance::Balance, order::OrderKind, ClientOrderId};
+    use barter_integration::model::{
+        instrument::{
+            kind::{FutureContract, OptionContract, OptionExercise},
+            spec::InstrumentSpec,
+        },
+        Side,
+    };
+    use mockito::Matcher;
+    use rust_decimal_macros::dec;
+    use serde_json::json;
+    use uuid::Uuid;
+
+    const CID: &str = "b7c8ca8108a74f8fa3a24cf8a8c6e8f0";
+
+    #[test]
+    fn test_okx_inst_id() {
+        struct TestCase {
+            input: Instrument,
+            expected: Option<OkxInstId>,
+        }
+
+        let expiry = DateTime::from_timestamp_millis(1685088000000).unwrap();
+
+        let tests = vec![
+            TestCase {
+                // TC0: spot
+                input: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
+                expected: Some(OkxInstId("BTC-USDT".to_string())),
+            },
+            TestCase {
+                // TC1: perpetual
+                input: Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+                expected: Some(OkxInstId("BTC-USDT-SWAP".to_string())),
+            },
+            TestCase {
+                // TC2: future
+                input: Instrument::from((
+                    "btc",
+                    "usd",
+                    InstrumentKind::Future(FutureContract { expiry }),
+                )),
+                expected: Some(OkxInstId("BTC-USD-230526".to_string())),
+            },
+            TestCase {
+                // TC3: option
+                input: Instrument::from((
+                    "btc",
+                    "usd",
+                    InstrumentKind::Option(OptionContract {
+                        kind: OptionKind::Put,
+                        exercise: OptionExercise::European,
+                        expiry,
+                        strike: dec!(30000),
+                    }),
+                )),
+                expected: Some(OkxInstId("BTC-USD-230526-30000-P".to_string())),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = OkxInstId::new(&test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    fn btc_usdt_swap() -> Instrument {
+        Instrument::from(("btc", "usdt", InstrumentKind::Perpetual))
+    }
+
+    fn order_request(quantity: rust_decimal::Decimal) -> Order<RequestOpen> {
+        Order {
+            exchange: Exchange::from(ExecutionId::Okx),
+            instrument: btc_usdt_swap(),
+            cid: ClientOrderId(Uuid::parse_str(CID).unwrap()),
+            side: Side::Buy,
+            state: RequestOpen {
+                kind: OrderKind::Limit,
+                price: dec!(30000.04),
+                quantity,
+                reduce_only: false,
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_okx_execution_stub() {
+        let mut server = mockito::Server::new_async().await;
+
+        let mut signed = |method: &str, path: &str| {
+            server
+                .mock(method, path)
+                .match_header("OK-ACCESS-KEY", "key")
+                .match_header("OK-ACCESS-PASSPHRASE", "passphrase")
+                .match_header(
+                    "OK-ACCESS-SIGN",
+                    Matcher::Regex("^[A-Za-z0-9+/]{43}=$".into()),
+                )
+                .match_header(
+                    "OK-ACCESS-TIMESTAMP",
+                    Matcher::Regex(r"^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}\.\d{3}Z$".into()),
+                )
+                .match_header("x-simulated-trading", Matcher::Missing)
+        };
+
+        let _open = signed("POST", "/api/v5/trade/order")
+            .match_body(Matcher::PartialJson(json!({
+                "instId": "BTC-USDT-SWAP", "tdMode": "cross", "clOrdId": CID, "side": "buy",
+                "ordType": "limit", "sz": "2", "px": "30000.0"
+            })))
+            .with_body(
+                r#"{"code": "0", "msg": "", "data": [{"clOrdId": "b7c8ca8108a74f8fa3a24cf8a8c6e8f0", "ordId": "1001", "tag": "", "sCode": "0", "sMsg": ""}]}"#,
+            )
+            .create_async()
+            .await;
+
+        let _rejected = signed("POST", "/api/v5/trade/order")
+            .match_body(Matcher::PartialJson(json!({"sz": "3"})))
+            .with_body(
+                r#"{"code": "1", "msg": "All operations failed", "data": [{"clOrdId": "", "ordId": "", "tag": "", "sCode": "51008", "sMsg": "Insufficient balance"}]}"#,
+            )
+            .create_async()
+            .await;
+
+        let _cancel = signed("POST", "/api/v5/trade/cancel-order")
+            .match_body(Matcher::Json(
+                json!({"instId": "BTC-USDT-SWAP", "ordId": "1001"}),
+            ))
+            .with_body(
+                r#"{"code": "0", "msg": "", "data": [{"clOrdId": "", "ordId": "1001", "sCode": "0", "sMsg": ""}]}"#,
+            )
+            .create_async()
+            .await;
+
+        let _balances = signed("GET", "/api/v5/account/balance")
+            .with_body(
+                r#"{"code": "0", "msg": "", "data": [{"totalEq": "41624.32", "details": [{"ccy": "USDT", "eq": "4992.89", "cashBal": "4850.43", "availBal": "4834.31"}]}]}"#,
+            )
+            .create_async()
+            .await;
+
+        let _pending = signed("GET", "/api/v5/trade/orders-pending")
+            .match_query(Matcher::UrlEncoded("limit".into(), "100".into()))
+            .with_body(
+                json!({"code": "0", "msg": "", "data": [
+                    {
+                        "instType": "SWAP", "instId": "BTC-USDT-SWAP", "ordId": "1001",
+                        "clOrdId": CID, "px": "30000", "sz": "2", "ordType": "limit",
+                        "side": "buy", "accFillSz": "0.5", "state": "partially_filled"
+                    },
+                    {
+                        "instType": "SWAP", "instId": "BTC-USDT-SWAP", "ordId": "1002",
+                        "clOrdId": "", "px": "31000", "sz": "1", "ordType": "limit",
+                        "side": "sell", "accFillSz": "0", "state": "live"
+                    }
+                ]})
+                .to_string(),
+            )
+            .create_async()
+            .await;
+
+        let _cancel_batch = signed("POST", "/api/v5/trade/cancel-batch-orders")
+            .match_body(Matcher::Json(
+                json!([{"instId": "BTC-USDT-SWAP", "ordId": "1001"}]),
+            ))
+            .with_body(
+                r#"{"code": "0", "msg": "", "data": [{"clOrdId": "", "ordId": "1001", "sCode": "0", "sMsg": ""}]}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = OkxClient::new_with_url(
+            LiveOrTest::Live,
+            OkxCredentials::new("key", "secret", "passphrase"),
+            server.url(),
+        );
+        let specs = InstrumentSpecs::from(vec![InstrumentSpec::new(
+            btc_usdt_swap(),
+            dec!(0.1),
+            dec!(1),
+        )]);
+        let execution = OkxExecution::new(client, specs);
+
+        // Order<RequestOpen> price is conformed to the tick size before submission
+        let mut opened = execution
+            .open_orders(vec![order_request(dec!(2)), order_request(dec!(3))])
+            .await
+            .into_iter();
+        let open = opened.next().unwrap().unwrap();
+        assert_eq!(open.state.id, OrderId::from("1001"));
+        assert_eq!(open.state.price, dec!(30000));
+        assert!(matches!(
+            opened.next().unwrap(),
+            Err(ExecutionError::Socket(_))
+        ));
+
+        let cancelled = execution
+            .cancel_orders(vec![Order {
+                exchange: open.exchange.clone(),
+                instrument: open.instrument.clone(),
+                cid: open.cid,
+                side: open.side,
+                state: RequestCancel::from("1001"),
+            }])
+            .await;
+        assert_eq!(
+            cancelled[0].as_ref().unwrap().state,
+            Cancelled::from("1001")
+        );
+
+        assert_eq!(
+            execution.fetch_balances().await.unwrap(),
+            vec![SymbolBalance::new(
+                "usdt",
+                Balance::new(dec!(4992.89), dec!(4834.31))
+            )]
+        );
+
+        // Orders without a Barter ClientOrderId are ignored
+        let orders_open = execution.fetch_orders_open().await.unwrap();
+        assert_eq!(orders_open.len(), 1);
+        assert_eq!(orders_open[0].state.filled_quantity, dec!(0.5));
+
+        let cancelled_all = execution.cancel_orders_all().await.unwrap();
+        assert_eq!(cancelled_all.len(), 1);
+        assert_eq!(cancelled_all[0].cid, open.cid);
+    }
+
+    #[tokio::test]
+    async fn test_okx_fetch_open_orders_follows_every_page() {
+        let mut server = mockito::Server::new_async().await;
+
+        // Pages are sorted newest (largest ordId) first
+        let orders = |ord_ids: std::ops::Range<u64>| {
+            let data = ord_ids
+                .rev()
+                .map(|ord_id| {
+                    json!({
+                        "instType": "SWAP", "instId": "BTC-USDT-SWAP", "ordId": ord_id.to_string(),
+                        "clOrdId": "", "px": "30000", "sz": "1", "ordType": "limit",
+                        "side": "buy", "accFillSz": "0", "state": "live"
+                    })
+                })
+                .collect::<Vec<_>>();
+            json!({"code": "0", "msg": "", "data": data}).to_string()
+        };
+
+        // First page is full, so the next page is requested after it's last (oldest) order
+        let _first_page = server
+            .mock("GET", "/api/v5/trade/orders-pending")
+            .match_query(Matcher::Exact("limit=100".into()))
+            .with_body(orders(1100..1200))
+            .create_async()
+            .await;
+        let _last_page = server
+            .mock("GET", "/api/v5/trade/orders-pending")
+            .match_query(Matcher::Exact("limit=100&after=1100".into()))
+            .with_body(orders(1099..1100))
+            .create_async()
+            .await;
+
+        let client = OkxClient::new_with_url(
+            LiveOrTest::Live,
+            OkxCredentials::new("key", "secret", "passphrase"),
+            server.url(),
+        );
+
+        let actual = client.fetch_open_orders().await.unwrap();
+        assert_eq!(actual.len(), 101);
+        assert_eq!(actual.last().unwrap().ord_id, "1099");
+    }
+}
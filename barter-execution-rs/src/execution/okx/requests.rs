@@ -0,0 +1,436 @@
+use barter_integration::{
+    error::SocketError,
+    model::{instrument::symbol::Symbol, Side},
+    protocol::http::{
+        private::time_sync::ServerTime,
+        rest::{ApiRequest, QueryParams},
+    },
+};
+use chrono::Utc;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::ExecutionError,
+    model::balance::{Balance, SymbolBalance},
+};
+
+pub const TIME_REQUEST: ApiRequest<OkxResponse<OkxServerTime>, ()> =
+    ApiRequest::new("/api/v5/public/time", reqwest::Method::GET, "server_time");
+
+pub const BALANCES_REQUEST: ApiRequest<OkxResponse<OkxAccountBalance>, ()> = ApiRequest::new(
+    "/api/v5/account/balance",
+    reqwest::Method::GET,
+    "fetch_balances",
+)
+.with_weight(6);
+
+pub const ORDERS_PENDING_REQUEST: ApiRequest<OkxResponse<OkxOrder>, ()> = ApiRequest::new(
+    "/api/v5/trade/orders-pending",
+    reqwest::Method::GET,
+    "fetch_open_orders",
+);
+
+/// Maximum number of orders OKX returns in a single "/api/v5/trade/orders-pending" page.
+pub const ORDERS_PENDING_PAGE_LIMIT: usize = 100;
+
+/// Query of an "/api/v5/trade/orders-pending" page, optionally only containing the orders older
+/// than the provided OKX order id (ie/ the last order of the previous page).
+pub fn orders_pending_query(after: Option<&str>) -> QueryParams {
+    let mut query_params = QueryParams::new();
+    query_params.add_kv("limit", ORDERS_PENDING_PAGE_LIMIT);
+    if let Some(after) = after {
+        query_params.add_kv("after", after);
+    }
+    query_params
+}
+
+/// Maximum number of orders OKX accepts in a single batch request.
+pub const BATCH_ORDERS_MAX: usize = 20;
+
+/// Envelope of every OKX REST response. A `code` other than "0" indicates the request failed,
+/// even though the Http status may be 200.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#overview-rest-authentication>
+/// ```json
+/// {
+///     "code": "0",
+///     "msg": "",
+///     "data": [{"ts": "1597026383085"}]
+/// }
+/// ```
+#[derive(Debug, Deserialize)]
+pub struct OkxResponse<T> {
+    pub code: String,
+    pub msg: String,
+    #[serde(default = "Vec::new")]
+    pub data: Vec<T>,
+}
+
+impl<T> OkxResponse<T> {
+    /// Return the response `data` if the request succeeded, otherwise an [`ExecutionError`].
+    pub fn into_data(self) -> Result<Vec<T>, ExecutionError> {
+        match self.code.as_str() {
+            "0" => Ok(self.data),
+            code => Err(okx_error(code, &self.msg)),
+        }
+    }
+}
+
+impl OkxResponse<OkxOrderAck> {
+    /// Return the OKX order id of the single order acknowledged by this response. Rejected
+    /// orders are sent with a non-zero `code`, and the reason in the per order `sCode`.
+    pub fn into_order_id(self) -> Result<String, ExecutionError> {
+        let OkxResponse { code, msg, data } = self;
+        match data.into_iter().next() {
+            Some(ack) => ack.into_result(),
+            None => Err(okx_error(&code, &msg)),
+        }
+    }
+}
+
+/// Map an OKX error `code` & message to an [`ExecutionError`].
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#error-code-rest-api-authentication>
+pub fn okx_error(code: &str, msg: &str) -> ExecutionError {
+    let error = format!("OKX error code {code}: {msg}");
+    match code {
+        // Invalid OK-ACCESS-KEY, passphrase, or signature
+        "50105" | "50111" | "50113" => ExecutionError::Unauthorised(error),
+        _ => ExecutionError::Socket(SocketError::Exchange(error)),
+    }
+}
+
+#[derive(Copy, Clone, Debug, Deserialize)]
+pub struct OkxServerTime {
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub ts: i64,
+}
+
+impl ServerTime for OkxResponse<OkxServerTime> {
+    fn server_time_millis(&self) -> i64 {
+        // Fall back to the local time, leaving the offset unchanged, if no time was returned
+        self.data
+            .first()
+            .map(|time| time.ts)
+            .unwrap_or_else(|| Utc::now().timestamp_millis())
+    }
+}
+
+/// Body of an OKX place order request.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-trade-post-place-order>
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxPlaceOrder {
+    pub inst_id: String,
+    /// Trade mode: "cash" for spot, "cross" for derivatives.
+    pub td_mode: &'static str,
+    pub cl_ord_id: String,
+    pub side: &'static str,
+    pub ord_type: &'static str,
+    pub sz: Decimal,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub px: Option<Decimal>,
+    /// Spot market order `sz` unit, always "base_ccy" since OKX defaults buys to "quote_ccy".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tgt_ccy: Option<&'static str>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub reduce_only: bool,
+}
+
+/// Body of an OKX cancel order request.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-trade-post-cancel-order>
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxCancelOrder {
+    pub inst_id: String,
+    pub ord_id: String,
+}
+
+/// Body of an OKX amend order request. At least one of `new_sz` & `new_px` must be provided.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-trade-post-amend-order>
+#[derive(Clone, Eq, PartialEq, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxAmendOrder {
+    pub inst_id: String,
+    pub ord_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_sz: Option<Decimal>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_px: Option<Decimal>,
+}
+
+/// Per order result of an OKX place, cancel or amend request. An `s_code` other than "0"
+/// indicates the individual order was rejected.
+///
+/// ### Raw Payload Examples
+/// ```json
+/// {"clOrdId": "b15", "ordId": "312269865356374016", "tag": "", "sCode": "0", "sMsg": ""}
+/// ```
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxOrderAck {
+    #[serde(default)]
+    pub ord_id: String,
+    #[serde(default)]
+    pub cl_ord_id: String,
+    pub s_code: String,
+    #[serde(default)]
+    pub s_msg: String,
+}
+
+impl OkxOrderAck {
+    /// Return the OKX order id if the individual order was accepted.
+    pub fn into_result(self) -> Result<String, ExecutionError> {
+        match self.s_code.as_str() {
+            "0" => Ok(self.ord_id),
+            code => Err(okx_error(code, &self.s_msg)),
+        }
+    }
+}
+
+/// OKX order, as returned by the pending orders endpoint & the private "orders" channel.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-trade-get-order-list>
+/// ```json
+/// {
+///     "instType": "SWAP",
+///     "instId": "BTC-USDT-SWAP",
+///     "ordId": "312269865356374016",
+///     "clOrdId": "b7c8ca8108a74f8fa3a24cf8a8c6e8f0",
+///     "px": "30000",
+///     "sz": "2",
+///     "ordType": "limit",
+///     "side": "buy",
+///     "accFillSz": "1",
+///     "fillSz": "1",
+///     "fillPx": "30000",
+///     "tradeId": "242589207",
+///     "fillFee": "-0.15",
+///     "fillFeeCcy": "USDT",
+///     "state": "partially_filled"
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxOrder {
+    pub inst_id: String,
+    pub ord_id: String,
+    #[serde(default)]
+    pub cl_ord_id: String,
+    #[serde(deserialize_with = "barter_integration::de::de_str_or_default")]
+    pub px: Decimal,
+    #[serde(deserialize_with = "barter_integration::de::de_str_or_default")]
+    pub sz: Decimal,
+    pub side: Side,
+    #[serde(
+        default,
+        deserialize_with = "barter_integration::de::de_str_or_default"
+    )]
+    pub acc_fill_sz: Decimal,
+    #[serde(
+        default,
+        deserialize_with = "barter_integration::de::de_str_or_default"
+    )]
+    pub fill_sz: Decimal,
+    #[serde(
+        default,
+        deserialize_with = "barter_integration::de::de_str_or_default"
+    )]
+    pub fill_px: Decimal,
+    #[serde(default)]
+    pub trade_id: String,
+    /// Fee of the last fill. Negative values are charged, positive values are rebates.
+    #[serde(
+        default,
+        deserialize_with = "barter_integration::de::de_str_or_default"
+    )]
+    pub fill_fee: Decimal,
+    #[serde(default)]
+    pub fill_fee_ccy: String,
+    pub state: OkxOrderState,
+}
+
+/// OKX order state.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-trade-ws-order-channel>
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OkxOrderState {
+    Live,
+    PartiallyFilled,
+    Filled,
+    Canceled,
+    MmpCanceled,
+    #[serde(other)]
+    Other,
+}
+
+/// OKX trading account balance, as returned by the balance endpoint & the private "account"
+/// channel.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#trading-account-rest-api-get-balance>
+/// ```json
+/// {
+///     "totalEq": "41624.32",
+///     "details": [
+///         {"ccy": "USDT", "eq": "4992.89", "cashBal": "4850.43", "availBal": "4834.31"}
+///     ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct OkxAccountBalance {
+    pub details: Vec<OkxBalanceDetail>,
+}
+
+/// OKX balance of a single currency.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OkxBalanceDetail {
+    pub ccy: Symbol,
+    #[serde(deserialize_with = "barter_integration::de::de_str_or_default")]
+    pub eq: Decimal,
+    #[serde(
+        default,
+        deserialize_with = "barter_integration::de::de_str_or_default"
+    )]
+    pub avail_bal: Decimal,
+}
+
+impl From<OkxBalanceDetail> for SymbolBalance {
+    fn from(detail: OkxBalanceDetail) -> Self {
+        SymbolBalance::new(detail.ccy, Balance::new(detail.eq, detail.avail_bal))
+    }
+}
+
+impl From<OkxAccountBalance> for Vec<SymbolBalance> {
+    fn from(account: OkxAccountBalance) -> Self {
+        account
+            .details
+            .into_iter()
+            .map(SymbolBalance::from)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_orders_pending_query() {
+        struct TestCase {
+            after: Option<&'static str>,
+            expected: &'static str,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: first page
+                after: None,
+                expected: "limit:100",
+            },
+            TestCase {
+                // TC1: next page after the last order of the previous page
+                after: Some("312269865356374016"),
+                expected: "limit:100,after:312269865356374016",
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = orders_pending_query(test.after).to_string();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_de_okx_order() {
+        struct TestCase {
+            input: &'static str,
+            expected: OkxOrder,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: partially filled limit order
+                input: r#"{
+                    "instType": "SWAP", "instId": "BTC-USDT-SWAP", "ordId": "312269865356374016",
+                    "clOrdId": "b7c8ca8108a74f8fa3a24cf8a8c6e8f0", "px": "30000", "sz": "2",
+                    "ordType": "limit", "side": "buy", "accFillSz": "1", "fillSz": "1",
+                    "fillPx": "30000", "tradeId": "242589207", "fillFee": "-0.15",
+                    "fillFeeCcy": "USDT", "state": "partially_filled"
+                }"#,
+                expected: OkxOrder {
+                    inst_id: "BTC-USDT-SWAP".to_string(),
+                    ord_id: "312269865356374016".to_string(),
+                    cl_ord_id: "b7c8ca8108a74f8fa3a24cf8a8c6e8f0".to_string(),
+                    px: dec!(30000),
+                    sz: dec!(2),
+                    side: Side::Buy,
+                    acc_fill_sz: dec!(1),
+                    fill_sz: dec!(1),
+                    fill_px: dec!(30000),
+                    trade_id: "242589207".to_string(),
+                    fill_fee: dec!(-0.15),
+                    fill_fee_ccy: "USDT".to_string(),
+                    state: OkxOrderState::PartiallyFilled,
+                },
+            },
+            TestCase {
+                // TC1: pending market order with empty price & fill fields
+                input: r#"{
+                    "instType": "SPOT", "instId": "BTC-USDT", "ordId": "1", "clOrdId": "",
+                    "px": "", "sz": "0.5", "ordType": "market", "side": "sell",
+                    "accFillSz": "0", "fillSz": "", "fillPx": "", "tradeId": "",
+                    "fillFee": "", "fillFeeCcy": "", "state": "live"
+                }"#,
+                expected: OkxOrder {
+                    inst_id: "BTC-USDT".to_string(),
+                    ord_id: "1".to_string(),
+                    cl_ord_id: String::new(),
+                    px: Decimal::ZERO,
+                    sz: dec!(0.5),
+                    side: Side::Sell,
+                    acc_fill_sz: Decimal::ZERO,
+                    fill_sz: Decimal::ZERO,
+                    fill_px: Decimal::ZERO,
+                    trade_id: String::new(),
+                    fill_fee: Decimal::ZERO,
+                    fill_fee_ccy: String::new(),
+                    state: OkxOrderState::Live,
+                },
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = serde_json::from_str::<OkxOrder>(test.input).unwrap();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_okx_response_into_data() {
+        let ok = serde_json::from_str::<OkxResponse<OkxServerTime>>(
+            r#"{"code": "0", "msg": "", "data": [{"ts": "1597026383085"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(ok.server_time_millis(), 1597026383085);
+        assert_eq!(ok.into_data().unwrap().len(), 1);
+
+        let unauthorised = serde_json::from_str::<OkxResponse<OkxServerTime>>(
+            r#"{"code": "50113", "msg": "Invalid Sign"}"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            unauthorised.into_data(),
+            Err(ExecutionError::Unauthorised(_))
+        ));
+    }
+}
@@ -0,0 +1,693 @@
+use super::{
+    connection::{OkxClient, OkxCredentials},
+    instruments_by_inst_id,
+    requests::{OkxAccountBalance, OkxOrder, OkxOrderState},
+    OkxInstId,
+};
+use crate::{
+    error::ExecutionError,
+    execution::binance::connection::LiveOrTest,
+    model::{
+        balance::SymbolBalance,
+        order::{Cancelled, Open, Order, OrderId},
+        trade::{SymbolFees, Trade, TradeId},
+        AccountEvent, AccountEventKind, ClientOrderId,
+    },
+    ExecutionId,
+};
+use barter_integration::{
+    error::SocketError,
+    model::{
+        instrument::{spec::InstrumentSpecs, Instrument},
+        Exchange,
+    },
+    protocol::http::private::{
+        encoder::{Base64Encoder, Encoder},
+        SigningKey,
+    },
+};
+use chrono::Utc;
+use futures::{SinkExt, StreamExt};
+use hmac::{Hmac, Mac};
+use serde::Deserialize;
+use serde_json::json;
+use std::{collections::HashMap, time::Duration};
+use tokio::{sync::mpsc, task::JoinHandle};
+use tokio_tungstenite::{connect_async, tungstenite::Message as WsMessage};
+use tracing::{debug, error, info, warn};
+use uuid::Uuid;
+
+/// Interval between "ping" messages. OKX closes connections that receive no messages for 30
+/// seconds.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#overview-websocket-connect>
+pub const PING_INTERVAL: Duration = Duration::from_secs(25);
+
+/// Maximum [`Duration`] to wait for a login response.
+const RESPONSE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Initial delay before re-connecting a failed [`OkxPrivateStream`] session. Doubles after each
+/// consecutive failure up to [`RECONNECT_BACKOFF_MAX`].
+const RECONNECT_BACKOFF_INITIAL: Duration = Duration::from_secs(1);
+
+/// Maximum delay before re-connecting a failed [`OkxPrivateStream`] session.
+const RECONNECT_BACKOFF_MAX: Duration = Duration::from_secs(60);
+
+/// Supervised OKX private WebSocket that translates "orders" & "account" channel messages into
+/// [`AccountEvent`]s.
+///
+/// Each session connects, logs in, and subscribes to the "orders" channel of every instrument
+/// type & the "account" channel, sending a "ping" every [`PING_INTERVAL`]. When a session ends,
+/// a new session is started with an exponential backoff. The stream stops once the
+/// [`AccountEvent`] receiver is dropped.
+#[derive(Debug, Clone)]
+pub struct OkxPrivateStream {
+    url: String,
+    credentials: OkxCredentials,
+    exchange: Exchange,
+    instruments: HashMap<OkxInstId, Instrument>,
+    event_tx: mpsc::UnboundedSender<AccountEvent>,
+}
+
+/// Outcome of a single [`OkxPrivateStream`] session.
+enum Session {
+    /// Session ended and a new one should be started (eg/ disconnect).
+    Reconnect,
+    /// [`AccountEvent`] receiver dropped, so the [`OkxPrivateStream`] should stop.
+    Terminate,
+}
+
+impl OkxPrivateStream {
+    /// Construct a new [`OkxPrivateStream`]. The provided [`InstrumentSpecs`] are used to map OKX
+    /// instrument ids (eg/ "BTC-USDT-SWAP") back to the associated [`Instrument`].
+    pub fn new(
+        kind: LiveOrTest,
+        credentials: OkxCredentials,
+        specs: &InstrumentSpecs,
+        event_tx: mpsc::UnboundedSender<AccountEvent>,
+    ) -> Self {
+        Self {
+            url: OkxClient::websocket_url(kind).to_string(),
+            credentials,
+            exchange: Exchange::from(ExecutionId::Okx),
+            instruments: instruments_by_inst_id(specs),
+            event_tx,
+        }
+    }
+
+    /// Connect to the provided private WebSocket url rather than the OKX default.
+    pub fn with_url<S>(self, url: S) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            url: url.into(),
+            ..self
+        }
+    }
+
+    /// Spawn the supervised private WebSocket task.
+    pub fn spawn(self) -> JoinHandle<()> {
+        tokio::spawn(async move { self.run().await })
+    }
+
+    /// Run private WebSocket sessions until the [`AccountEvent`] receiver is dropped.
+    pub async fn run(self) {
+        let mut backoff = RECONNECT_BACKOFF_INITIAL;
+        loop {
+            match self.session().await {
+                Ok(Session::Terminate) => {
+                    info!("AccountEvent receiver dropped, stopping OKX private stream");
+                    break;
+                }
+                Ok(Session::Reconnect) => {
+                    info!("OKX private stream session ended, reconnecting");
+                    backoff = RECONNECT_BACKOFF_INITIAL;
+                    tokio::time::sleep(backoff).await;
+                }
+                Err(error) => {
+                    error!(?error, ?backoff, "OKX private stream failed, reconnecting");
+                    tokio::time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(RECONNECT_BACKOFF_MAX);
+                }
+            }
+        }
+    }
+
+    /// Run a single private WebSocket session: connect, login, subscribe, and consume messages
+    /// until the connection ends.
+    async fn session(&self) -> Result<Session, ExecutionError> {
+        let (mut websocket, _) = connect_async(self.url.as_str())
+            .await
+            .map_err(SocketError::WebSocket)?;
+        info!(url = %self.url, "connected to OKX private stream");
+
+        // Login & wait for confirmation before subscribing to private channels
+        let timestamp = Utc::now().timestamp().to_string();
+        websocket
+            .send(WsMessage::Text(self.login_request(&timestamp).to_string()))
+            .await
+            .map_err(SocketError::WebSocket)?;
+
+        let login = tokio::time::timeout(RESPONSE_TIMEOUT, async {
+            while let Some(message) = websocket.next().await {
+                if let WsMessage::Text(payload) = message.map_err(SocketError::WebSocket)? {
+                    match OkxPrivateMessage::parse(&payload)? {
+                        OkxPrivateMessage::Login => return Ok(()),
+                        OkxPrivateMessage::Error { code, msg } => {
+                            return Err(ExecutionError::Unauthorised(format!(
+                                "OKX login failed with code {code}: {msg}"
+                            )))
+                        }
+                        _ => continue,
+                    }
+                }
+            }
+            Err(SocketError::Terminated("closed before login response".to_string()).into())
+        })
+        .await
+        .map_err(|_| SocketError::Exchange("OKX login response timed out".to_string()))?;
+        login?;
+        debug!("logged in to OKX private stream");
+
+        websocket
+            .send(WsMessage::Text(Self::subscribe_request().to_string()))
+            .await
+            .map_err(SocketError::WebSocket)?;
+
+        let mut ping = tokio::time::interval(PING_INTERVAL);
+        ping.tick().await;
+
+        loop {
+            tokio::select! {
+                _ = ping.tick() => {
+                    websocket
+                        .send(WsMessage::Text("ping".to_string()))
+                        .await
+                        .map_err(SocketError::WebSocket)?;
+                }
+                message = websocket.next() => match message {
+                    Some(Ok(WsMessage::Text(payload))) => {
+                        match OkxPrivateMessage::parse(&payload) {
+                            Ok(OkxPrivateMessage::Error { code, msg }) => {
+                                error!(%code, %msg, "OKX private stream error");
+                            }
+                            Ok(OkxPrivateMessage::Subscribed(channel)) => {
+                                debug!(%channel, "subscribed to OKX private channel");
+                            }
+                            Ok(message) => {
+                                for kind in message.account_events(&self.exchange, &self.instruments) {
+                                    let event = AccountEvent {
+                                        received_time: Utc::now(),
+                                        exchange: self.exchange.clone(),
+                                        kind,
+                                    };
+                                    if self.event_tx.send(event).is_err() {
+                                        return Ok(Session::Terminate);
+                                    }
+                                }
+                            }
+                            Err(error) => {
+                                error!(?error, "failed to parse OKX private stream message");
+                            }
+                        }
+                    }
+                    Some(Ok(WsMessage::Close(frame))) => {
+                        warn!(?frame, "OKX private stream closed");
+                        return Ok(Session::Reconnect);
+                    }
+                    Some(Ok(_)) => {}
+                    Some(Err(error)) => return Err(SocketError::WebSocket(error).into()),
+                    None => return Ok(Session::Reconnect),
+                },
+            }
+        }
+    }
+
+    /// Construct the login request, signing `timestamp + "GET" + "/users/self/verify"` where the
+    /// timestamp is in seconds since the epoch.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#overview-websocket-login>
+    pub fn login_request(&self, timestamp: &str) -> serde_json::Value {
+        let mac = Hmac::<sha2::Sha256>::new_from_slice(self.credentials.secret.as_bytes())
+            .expect("HMAC accepts keys of any length");
+        let sign =
+            Base64Encoder.encode(mac.sign(format!("{timestamp}GET/users/self/verify").as_bytes()));
+
+        json!({
+            "op": "login",
+            "args": [{
+                "apiKey": self.credentials.api_key,
+                "passphrase": self.credentials.passphrase,
+                "timestamp": timestamp,
+                "sign": sign,
+            }]
+        })
+    }
+
+    /// Construct the request subscribing to orders of every instrument type & account balances.
+    pub fn subscribe_request() -> serde_json::Value {
+        json!({
+            "op": "subscribe",
+            "args": [
+                {"channel": "orders", "instType": "ANY"},
+                {"channel": "account"},
+            ]
+        })
+    }
+}
+
+/// OKX private WebSocket messages.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-trade-ws-order-channel>
+/// ```json
+/// {
+///     "arg": {"channel": "orders", "instType": "ANY", "uid": "77982378738415879"},
+///     "data": [{"instId": "BTC-USDT-SWAP", "ordId": "312269865356374016", "state": "live"}]
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug)]
+pub enum OkxPrivateMessage {
+    /// Login succeeded.
+    Login,
+    /// Subscription to the named channel succeeded.
+    Subscribed(String),
+    /// Login or subscription failed.
+    Error { code: String, msg: String },
+    /// "orders" channel update.
+    Orders(Vec<OkxOrder>),
+    /// "account" channel balance update.
+    Account(Vec<OkxAccountBalance>),
+    /// Response to a "ping".
+    Pong,
+    /// Message not translated into [`AccountEvent`]s (eg/ "channel-conn-count").
+    Other(String),
+}
+
+impl OkxPrivateMessage {
+    /// Parse a raw private WebSocket message using it's "event" or "arg.channel".
+    pub fn parse(payload: &str) -> Result<Self, SocketError> {
+        #[derive(Deserialize)]
+        struct Arg {
+            channel: String,
+        }
+
+        #[derive(Deserialize)]
+        struct RawMessage {
+            event: Option<String>,
+            code: Option<String>,
+            msg: Option<String>,
+            arg: Option<Arg>,
+            data: Option<serde_json::Value>,
+        }
+
+        if payload == "pong" {
+            return Ok(Self::Pong);
+        }
+
+        let deserialise = |error| SocketError::Deserialise {
+            error,
+            payload: payload.to_owned(),
+        };
+
+        let message = serde_json::from_str::<RawMessage>(payload).map_err(deserialise)?;
+        let channel = message.arg.map(|arg| arg.channel).unwrap_or_default();
+
+        match (message.event.as_deref(), message.data) {
+            (Some("login"), _) if message.code.as_deref() == Some("0") => Ok(Self::Login),
+            (Some("login" | "error"), _) => Ok(Self::Error {
+                code: message.code.unwrap_or_default(),
+                msg: message.msg.unwrap_or_default(),
+            }),
+            (Some("subscribe"), _) => Ok(Self::Subscribed(channel)),
+            (Some(event), _) => Ok(Self::Other(event.to_owned())),
+            (None, Some(data)) => match channel.as_str() {
+                "orders" => serde_json::from_value(data).map(Self::Orders),
+                "account" => serde_json::from_value(data).map(Self::Account),
+                _ => Ok(Self::Other(channel)),
+            }
+            .map_err(deserialise),
+            (None, None) => Ok(Self::Other(channel)),
+        }
+    }
+
+    /// Translate this message into the associated [`AccountEventKind`]s, using the provided
+    /// [`OkxInstId`] to [`Instrument`] map to identify order & trade instruments.
+    pub fn account_events(
+        self,
+        exchange: &Exchange,
+        instruments: &HashMap<OkxInstId, Instrument>,
+    ) -> Vec<AccountEventKind> {
+        match self {
+            Self::Orders(orders) => orders
+                .into_iter()
+                .flat_map(
+                    |order| match instruments.get(&OkxInstId(order.inst_id.clone())) {
+                        Some(instrument) => order.account_events(exchange, instrument),
+                        None => {
+                            warn!(inst_id = %order.inst_id, "received order update for unknown OKX instrument");
+                            vec![]
+                        }
+                    },
+                )
+                .collect(),
+            Self::Account(accounts) => {
+                let balances = accounts
+                    .into_iter()
+                    .flat_map(<Vec<SymbolBalance>>::from)
+                    .collect();
+                vec![AccountEventKind::Balances(balances)]
+            }
+            Self::Login
+            | Self::Subscribed(_)
+            | Self::Error { .. }
+            | Self::Pong
+            | Self::Other(_) => vec![],
+        }
+    }
+}
+
+impl OkxOrder {
+    /// Parse the Barter [`ClientOrderId`] from the OKX `clOrdId`, if the order was placed by
+    /// Barter.
+    pub fn cid(&self) -> Option<ClientOrderId> {
+        Uuid::parse_str(&self.cl_ord_id).ok().map(ClientOrderId)
+    }
+
+    /// Translate this order update into the associated [`AccountEventKind`]s.
+    ///
+    /// Order state events are only generated for orders with a Barter [`ClientOrderId`], whereas
+    /// [`Trade`]s are generated for every fill.
+    pub fn account_events(
+        self,
+        exchange: &Exchange,
+        instrument: &Instrument,
+    ) -> Vec<AccountEventKind> {
+        let mut events = Vec::new();
+
+        if !self.trade_id.is_empty() && !self.fill_sz.is_zero() {
+            let fees = match self.fill_fee_ccy.is_empty() {
+                true => SymbolFees::new(instrument.quote.clone(), -self.fill_fee),
+                false => SymbolFees::new(self.fill_fee_ccy.as_str(), -self.fill_fee),
+            };
+
+            events.push(AccountEventKind::Trade(Trade {
+                id: TradeId::from(self.trade_id.as_str()),
+                order_id: OrderId::from(&self.ord_id),
+                instrument: instrument.clone(),
+                side: self.side,
+                price: self.fill_px,
+                quantity: self.fill_sz,
+                fees,
+            }));
+        }
+
+        match (self.state, self.cid()) {
+            (OkxOrderState::Live, Some(cid)) => {
+                events.push(AccountEventKind::OrdersNew(vec![Order {
+                    exchange: exchange.clone(),
+                    instrument: instrument.clone(),
+                    cid,
+                    side: self.side,
+                    state: Open {
+                        id: OrderId::from(&self.ord_id),
+                        price: self.px,
+                        quantity: self.sz,
+                        filled_quantity: self.acc_fill_sz,
+                    },
+                }]));
+            }
+            (OkxOrderState::Canceled | OkxOrderState::MmpCanceled, Some(cid)) => {
+                events.push(AccountEventKind::OrdersCancelled(vec![Order {
+                    exchange: exchange.clone(),
+                    instrument: instrument.clone(),
+                    cid,
+                    side: self.side,
+                    state: Cancelled::from(&self.ord_id),
+                }]));
+            }
+            _ => {}
+        }
+
+        events
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::balance::Balance;
+    use barter_integration::model::{
+        instrument::{kind::InstrumentKind, spec::InstrumentSpec},
+        Side,
+    };
+    use rust_decimal_macros::dec;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::accept_async;
+
+    const CID: &str = "b7c8ca8108a74f8fa3a24cf8a8c6e8f0";
+
+    fn specs() -> InstrumentSpecs {
+        InstrumentSpecs::from(vec![InstrumentSpec::new(
+            ("btc", "usdt", InstrumentKind::Perpetual),
+            dec!(0.1),
+            dec!(1),
+        )])
+    }
+
+    fn btc_usdt_swap() -> Instrument {
+        Instrument::from(("btc", "usdt", InstrumentKind::Perpetual))
+    }
+
+    fn orders_message(state: &str, fill_sz: &str, trade_id: &str) -> String {
+        format!(
+            r#"{{
+                "arg": {{"channel": "orders", "instType": "ANY", "uid": "77982378738415879"}},
+                "data": [{{
+                    "instType": "SWAP", "instId": "BTC-USDT-SWAP", "ordId": "312269865356374016",
+                    "clOrdId": "{CID}", "px": "30000", "sz": "2", "ordType": "limit",
+                    "side": "sell", "accFillSz": "{fill_sz}", "fillSz": "{fill_sz}",
+                    "fillPx": "30000", "tradeId": "{trade_id}", "fillFee": "-0.15",
+                    "fillFeeCcy": "USDT", "state": "{state}"
+                }}]
+            }}"#
+        )
+    }
+
+    #[test]
+    fn test_okx_private_message_account_events() {
+        struct TestCase {
+            input: String,
+            expected: Vec<AccountEventKind>,
+        }
+
+        let cid = ClientOrderId(Uuid::parse_str(CID).unwrap());
+        let exchange = Exchange::from(ExecutionId::Okx);
+
+        let tests = vec![
+            TestCase {
+                // TC0: live order -> OrdersNew
+                input: orders_message("live", "0", ""),
+                expected: vec![AccountEventKind::OrdersNew(vec![Order {
+                    exchange: exchange.clone(),
+                    instrument: btc_usdt_swap(),
+                    cid,
+                    side: Side::Sell,
+                    state: Open {
+                        id: OrderId::from("312269865356374016"),
+                        price: dec!(30000),
+                        quantity: dec!(2),
+                        filled_quantity: dec!(0),
+                    },
+                }])],
+            },
+            TestCase {
+                // TC1: partial fill -> Trade with charged fee
+                input: orders_message("partially_filled", "1", "242589207"),
+                expected: vec![AccountEventKind::Trade(Trade {
+                    id: TradeId::from("242589207"),
+                    order_id: OrderId::from("312269865356374016"),
+                    instrument: btc_usdt_swap(),
+                    side: Side::Sell,
+                    price: dec!(30000),
+                    quantity: dec!(1),
+                    fees: SymbolFees::new("usdt", dec!(0.15)),
+                })],
+            },
+            TestCase {
+                // TC2: cancelled order -> OrdersCancelled
+                input: orders_message("canceled", "0", ""),
+                expected: vec![AccountEventKind::OrdersCancelled(vec![Order {
+                    exchange: exchange.clone(),
+                    instrument: btc_usdt_swap(),
+                    cid,
+                    side: Side::Sell,
+                    state: Cancelled::from("312269865356374016"),
+                }])],
+            },
+            TestCase {
+                // TC3: account channel -> Balances
+                input: r#"{
+                    "arg": {"channel": "account", "uid": "77982378738415879"},
+                    "data": [{
+                        "totalEq": "41624.32",
+                        "details": [{"ccy": "USDT", "eq": "4992.89", "cashBal": "4850.43", "availBal": "4834.31"}]
+                    }]
+                }"#
+                .to_string(),
+                expected: vec![AccountEventKind::Balances(vec![SymbolBalance::new(
+                    "usdt",
+                    Balance::new(dec!(4992.89), dec!(4834.31)),
+                )])],
+            },
+            TestCase {
+                // TC4: order update for unknown instrument is ignored
+                input: orders_message("live", "0", "").replace("BTC-USDT-SWAP", "ETH-USDT-SWAP"),
+                expected: vec![],
+            },
+            TestCase {
+                // TC5: pong is ignored
+                input: "pong".to_string(),
+                expected: vec![],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = OkxPrivateMessage::parse(&test.input)
+                .unwrap()
+                .account_events(&exchange, &instruments_by_inst_id(&specs()));
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_okx_private_message_parse_events() {
+        struct TestCase {
+            input: &'static str,
+            expected: OkxPrivateMessage,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: login success
+                input: r#"{"event": "login", "code": "0", "msg": "", "connId": "a4d3ae55"}"#,
+                expected: OkxPrivateMessage::Login,
+            },
+            TestCase {
+                // TC1: login failure
+                input: r#"{"event": "error", "code": "60009", "msg": "Login failed."}"#,
+                expected: OkxPrivateMessage::Error {
+                    code: "60009".to_string(),
+                    msg: "Login failed.".to_string(),
+                },
+            },
+            TestCase {
+                // TC2: subscription success
+                input: r#"{"event": "subscribe", "arg": {"channel": "orders", "instType": "ANY"}}"#,
+                expected: OkxPrivateMessage::Subscribed("orders".to_string()),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = OkxPrivateMessage::parse(test.input).unwrap();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_okx_login_request_sign() {
+        // Expected signature generated independently with the Python `hmac` module
+        let (event_tx, _event_rx) = mpsc::unbounded_channel();
+        let stream = OkxPrivateStream::new(
+            LiveOrTest::Live,
+            OkxCredentials::new("key", "22582BD0CFF14C41EDBF1AB98506286D", "passphrase"),
+            &specs(),
+            event_tx,
+        );
+
+        let request = stream.login_request("1538054050");
+        assert_eq!(
+            request["args"][0]["sign"],
+            "+LdIr8lkkvhr5hoA3g9TMC0+uQJ849ftAcocA/ouu4M="
+        );
+    }
+
+    #[tokio::test]
+    async fn test_okx_private_stream_stub() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+
+        // Stub OKX private WebSocket: confirm login & subscription, then push an order update
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = accept_async(stream).await.unwrap();
+
+            let login = websocket
+                .next()
+                .await
+                .unwrap()
+                .unwrap()
+                .into_text()
+                .unwrap();
+            let login = serde_json::from_str::<serde_json::Value>(&login).unwrap();
+            assert_eq!(login["op"], "login");
+            assert_eq!(login["args"][0]["apiKey"], "key");
+            websocket
+                .send(WsMessage::Text(
+                    r#"{"event": "login", "code": "0", "msg": ""}"#.to_string(),
+                ))
+                .await
+                .unwrap();
+
+            let subscribe = websocket
+                .next()
+                .await
+                .unwrap()
+                .unwrap()
+                .into_text()
+                .unwrap();
+            let subscribe = serde_json::from_str::<serde_json::Value>(&subscribe).unwrap();
+            assert_eq!(subscribe, OkxPrivateStream::subscribe_request());
+            websocket
+                .send(WsMessage::Text(
+                    r#"{"event": "subscribe", "arg": {"channel": "orders", "instType": "ANY"}}"#
+                        .to_string(),
+                ))
+                .await
+                .unwrap();
+
+            websocket
+                .send(WsMessage::Text(orders_message("live", "0", "")))
+                .await
+                .unwrap();
+
+            // Keep the connection open until the client disconnects
+            while websocket.next().await.is_some() {}
+        });
+
+        let (event_tx, mut event_rx) = mpsc::unbounded_channel();
+        let stream = OkxPrivateStream::new(
+            LiveOrTest::Live,
+            OkxCredentials::new("key", "secret", "passphrase"),
+            &specs(),
+            event_tx,
+        )
+        .with_url(url)
+        .spawn();
+
+        let event = tokio::time::timeout(Duration::from_secs(5), event_rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(event.exchange, Exchange::from(ExecutionId::Okx));
+        assert!(matches!(
+            event.kind,
+            AccountEventKind::OrdersNew(orders) if orders[0].cid == ClientOrderId(Uuid::parse_str(CID).unwrap())
+        ));
+
+        stream.abort();
+        server.abort();
+    }
+}
@@ -2,6 +2,7 @@ use crate::error::ExecutionError;
 use barter_integration::model::{instrument::Instrument, Exchange};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 /// Metadata detailing the [`Candle`](barter_data::subscription::candle::Candle) or
 /// [`Trade`](barter_data::subscription::trade::PublicTrade) close price & it's associated
@@ -67,6 +68,10 @@ pub struct FillEvent {
     pub time: DateTime<Utc>,
     pub exchange: Exchange,
     pub instrument: Instrument,
+    /// Identifier of the [`OrderEvent`](crate::model::order_event::OrderEvent) this fill executes,
+    /// if known.
+    #[serde(default)]
+    pub order_id: Option<Uuid>,
     /// Metadata propagated from source MarketEvent
     pub market_meta: MarketMeta,
     /// LONG, CloseLong, SHORT or CloseShort
@@ -77,6 +82,13 @@ pub struct FillEvent {
     pub fill_value_gross: f64,
     /// All fee types incurred when executing an [`OrderEvent`], and their associated [`FeeAmount`].
     pub fees: Fees,
+    /// Whether the fill added liquidity to the order book (maker) or removed it (taker).
+    ///
+    /// **Note:**
+    /// Defaults to [`Liquidity::Taker`], so producers must set [`Liquidity::Maker`] for fills of
+    /// resting orders (eg/ from the exchange maker flag, or a simulated passive match).
+    #[serde(default)]
+    pub liquidity: Liquidity,
 }
 
 impl FillEvent {
@@ -88,6 +100,25 @@ impl FillEvent {
     }
 }
 
+/// Liquidity role of a [`FillEvent`], as reported by the exchange or execution simulator.
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum Liquidity {
+    /// Fill of a resting order that added liquidity to the order book.
+    Maker,
+    /// Fill of an order that removed liquidity from the order book.
+    #[default]
+    Taker,
+}
+
+impl Liquidity {
+    /// Determines if a [`Liquidity`] is Maker.
+    pub fn is_maker(&self) -> bool {
+        matches!(self, Liquidity::Maker)
+    }
+}
+
 /// All potential fees incurred by a [`FillEvent`].
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
 pub struct Fees {
@@ -104,6 +135,25 @@ impl Fees {
     pub fn calculate_total_fees(&self) -> f64 {
         self.exchange + self.network + self.slippage
     }
+
+    /// Returns a new [Fees] with every [FeeAmount] multiplied by the provided factor.
+    ///
+    /// eg/ Used to attribute a proportion of a [`FillEvent`]'s fees to part of it's quantity.
+    pub fn scale(&self, factor: f64) -> Self {
+        Self {
+            exchange: self.exchange * factor,
+            slippage: self.slippage * factor,
+            network: self.network * factor,
+        }
+    }
+}
+
+impl std::ops::AddAssign for Fees {
+    fn add_assign(&mut self, rhs: Self) {
+        self.exchange += rhs.exchange;
+        self.slippage += rhs.slippage;
+        self.network += rhs.network;
+    }
 }
 
 /// Communicative type alias for Fee amount as f64.
@@ -115,11 +165,13 @@ pub struct FillEventBuilder {
     pub time: Option<DateTime<Utc>>,
     pub exchange: Option<Exchange>,
     pub instrument: Option<Instrument>,
+    pub order_id: Option<Uuid>,
     pub market_meta: Option<MarketMeta>,
     pub decision: Option<Decision>,
     pub quantity: Option<f64>,
     pub fill_value_gross: Option<f64>,
     pub fees: Option<Fees>,
+    pub liquidity: Option<Liquidity>,
 }
 
 impl FillEventBuilder {
@@ -148,6 +200,13 @@ impl FillEventBuilder {
         }
     }
 
+    pub fn order_id(self, value: Uuid) -> Self {
+        Self {
+            order_id: Some(value),
+            ..self
+        }
+    }
+
     pub fn market_meta(self, value: MarketMeta) -> Self {
         Self {
             market_meta: Some(value),
@@ -183,6 +242,15 @@ impl FillEventBuilder {
         }
     }
 
+    pub fn liquidity(self, value: Liquidity) -> Self {
+        Self {
+            liquidity: Some(value),
+            ..self
+        }
+    }
+
+    /// Builds the [`FillEvent`], defaulting to [`Liquidity::Taker`] if no liquidity is provided,
+    /// and to no order_id if the executed order is unknown.
     pub fn build(self) -> Result<FillEvent, ExecutionError> {
         Ok(FillEvent {
             time: self.time.ok_or(ExecutionError::BuilderIncomplete("time"))?,
@@ -192,6 +260,7 @@ impl FillEventBuilder {
             instrument: self
                 .instrument
                 .ok_or(ExecutionError::BuilderIncomplete("instrument"))?,
+            order_id: self.order_id,
             market_meta: self
                 .market_meta
                 .ok_or(ExecutionError::BuilderIncomplete("market_meta"))?,
@@ -205,6 +274,7 @@ impl FillEventBuilder {
                 .fill_value_gross
                 .ok_or(ExecutionError::BuilderIncomplete("fill_value_gross"))?,
             fees: self.fees.ok_or(ExecutionError::BuilderIncomplete("fees"))?,
+            liquidity: self.liquidity.unwrap_or_default(),
         })
     }
 }
@@ -25,12 +25,16 @@ use crate::{
     error::ExecutionError,
     model::{
         balance::SymbolBalance,
-        order::{Cancelled, Open, Order, OrderId, RequestCancel, RequestOpen},
+        order::{Cancelled, Open, Order, OrderId, RequestAmend, RequestCancel, RequestOpen},
     },
 };
 use async_trait::async_trait;
-use barter_integration::model::Exchange;
-use execution::binance::{BinanceConfig, BinanceExecution};
+use barter_integration::{error::SocketError, model::Exchange};
+use execution::{
+    binance::{BinanceConfig, BinanceExecution},
+    bybit::{BybitConfig, BybitExecution},
+    okx::{OkxConfig, OkxExecution},
+};
 use serde::{Deserialize, Serialize};
 use simulated::execution::{SimulatedExecution, SimulationConfig};
 use std::fmt::{Display, Formatter};
@@ -93,8 +97,65 @@ pub trait ExecutionClient {
         cancel_requests: Vec<Order<RequestCancel>>,
     ) -> Vec<Result<Order<Cancelled>, ExecutionError>>;
 
+    /// Amend the price and/or quantity of [`Order<Open>`]s, returning the amended orders.
+    ///
+    /// Venues that support modifying a resting order amend it natively, which keeps the queue
+    /// priority where the venue allows it. Venues without native support should fall back to
+    /// [`ExecutionClient::cancel_replace_orders`].
+    async fn amend_orders(
+        &self,
+        amend_requests: Vec<Order<RequestAmend>>,
+    ) -> Vec<Result<Order<Open>, ExecutionError>>;
+
     /// Cancel all account [`Order<Open>`]s.
     async fn cancel_orders_all(&self) -> Result<Vec<Order<Cancelled>>, ExecutionError>;
+
+    /// Amend [`Order<Open>`]s by cancelling them, and opening a replacement [`RequestOpen`] for
+    /// every order that was successfully cancelled.
+    ///
+    /// Replacements keep the [`ClientOrderId`](model::ClientOrderId) of the amended order, but
+    /// are assigned a new [`OrderId`] and lose their queue priority. Only the quantity remaining
+    /// after the amend is re-opened, and the returned [`Order<Open>`]s report the amended total
+    /// quantity including the quantity filled before the cancel.
+    async fn cancel_replace_orders(
+        &self,
+        amend_requests: Vec<Order<RequestAmend>>,
+    ) -> Vec<Result<Order<Open>, ExecutionError>> {
+        let cancel_requests = amend_requests.iter().map(Order::from).collect();
+        let cancelled = self.cancel_orders(cancel_requests).await;
+
+        // Only replace orders that were cancelled, so a failed cancel never duplicates an order
+        let mut results = Vec::with_capacity(amend_requests.len());
+        let mut open_requests = Vec::new();
+        for (amend_request, cancel) in amend_requests.into_iter().zip(cancelled) {
+            match cancel {
+                Ok(_) => {
+                    results.push(Ok(amend_request.state.filled_quantity));
+                    open_requests.push(Order::<RequestOpen>::from(amend_request));
+                }
+                Err(error) => results.push(Err(error)),
+            }
+        }
+
+        let mut opened = self.open_orders(open_requests).await.into_iter();
+        results
+            .into_iter()
+            .map(|result| {
+                let filled_quantity = result?;
+                let mut replacement = opened.next().unwrap_or_else(|| {
+                    Err(ExecutionError::Socket(SocketError::Exchange(
+                        "missing replacement response for cancelled Order<RequestAmend>"
+                            .to_string(),
+                    )))
+                })?;
+
+                // Replacement only holds the remaining quantity, so restore the fill progress
+                replacement.state.quantity += filled_quantity;
+                replacement.state.filled_quantity += filled_quantity;
+                Ok(replacement)
+            })
+            .collect()
+    }
 }
 
 // Todo:
@@ -104,12 +165,16 @@ pub trait ExecutionClient {
 pub enum ClientId {
     Simulated(SimulationConfig),
     Binance(BinanceConfig),
+    Bybit(BybitConfig),
+    Okx(OkxConfig),
 }
 
 #[derive(Debug)]
 pub enum ExchangeClient {
     Simulated(SimulatedExecution),
     Binance(BinanceExecution),
+    Bybit(BybitExecution),
+    Okx(OkxExecution),
 }
 
 /// Unique identifier for an [`ExecutionClient`] implementation.
@@ -117,7 +182,11 @@ pub enum ExchangeClient {
 #[serde(rename = "execution", rename_all = "snake_case")]
 pub enum ExecutionId {
     Simulated,
-    Binance,
+    BinanceSpot,
+    BinanceFuturesUsd,
+    BybitSpot,
+    BybitPerpetualsUsd,
+    Okx,
 }
 
 impl From<ExecutionId> for Exchange {
@@ -136,7 +205,11 @@ impl ExecutionId {
     pub fn as_str(&self) -> &'static str {
         match self {
             ExecutionId::Simulated => "simulated",
-            ExecutionId::Binance => "binance",
+            ExecutionId::BinanceSpot => "binance_spot",
+            ExecutionId::BinanceFuturesUsd => "binance_futures_usd",
+            ExecutionId::BybitSpot => "bybit_spot",
+            ExecutionId::BybitPerpetualsUsd => "bybit_perpetuals_usd",
+            ExecutionId::Okx => "okx",
         }
     }
 }
@@ -145,17 +218,19 @@ impl ExecutionId {
 pub mod test_util {
     use crate::{
         model::{
+            order::OrderKind,
             trade::{SymbolFees, Trade, TradeId},
             ClientOrderId,
         },
         simulated::exchange::account::order::Orders,
-        Open, Order, OrderId,
+        Open, Order, OrderId, RequestOpen,
     };
     use barter_data::subscription::trade::PublicTrade;
     use barter_integration::model::{
         instrument::{kind::InstrumentKind, Instrument},
         Exchange, Side,
     };
+    use rust_decimal::Decimal;
 
     pub fn client_orders(
         trade_number: u64,
@@ -166,15 +241,16 @@ pub mod test_util {
             trade_counter: trade_number,
             bids,
             asks,
+            pending: vec![],
         }
     }
 
     pub fn order_open(
         cid: ClientOrderId,
         side: Side,
-        price: f64,
-        quantity: f64,
-        filled: f64,
+        price: Decimal,
+        quantity: Decimal,
+        filled: Decimal,
     ) -> Order<Open> {
         Order {
             exchange: Exchange::from("exchange"),
@@ -190,7 +266,28 @@ pub mod test_util {
         }
     }
 
-    pub fn public_trade(side: Side, price: f64, amount: f64) -> PublicTrade {
+    pub fn order_request(
+        cid: ClientOrderId,
+        side: Side,
+        kind: OrderKind,
+        price: Decimal,
+        quantity: Decimal,
+    ) -> Order<RequestOpen> {
+        Order {
+            exchange: Exchange::from("exchange"),
+            instrument: Instrument::from(("base", "quote", InstrumentKind::Perpetual)),
+            cid,
+            side,
+            state: RequestOpen {
+                kind,
+                price,
+                quantity,
+                reduce_only: false,
+            },
+        }
+    }
+
+    pub fn public_trade(side: Side, price: Decimal, amount: Decimal) -> PublicTrade {
         PublicTrade {
             id: "trade_id".to_string(),
             price,
@@ -199,7 +296,13 @@ pub mod test_util {
         }
     }
 
-    pub fn trade(id: TradeId, side: Side, price: f64, quantity: f64, fees: SymbolFees) -> Trade {
+    pub fn trade(
+        id: TradeId,
+        side: Side,
+        price: Decimal,
+        quantity: Decimal,
+        fees: SymbolFees,
+    ) -> Trade {
         Trade {
             id,
             order_id: OrderId::from("order_id"),
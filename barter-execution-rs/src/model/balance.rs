@@ -1,4 +1,5 @@
 use barter_integration::model::instrument::symbol::Symbol;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// [`Balance`] associated with a [`Symbol`].
@@ -21,21 +22,42 @@ impl SymbolBalance {
     }
 }
 
+/// Total balance associated with a [`Symbol`], for exchange updates that do not report the
+/// available balance.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct SymbolBalanceTotal {
+    pub symbol: Symbol,
+    pub total: Decimal,
+}
+
+impl SymbolBalanceTotal {
+    /// Construct a new [`SymbolBalanceTotal`] from a [`Symbol`] and it's associated total balance.
+    pub fn new<S>(symbol: S, total: Decimal) -> Self
+    where
+        S: Into<Symbol>,
+    {
+        Self {
+            symbol: symbol.into(),
+            total,
+        }
+    }
+}
+
 /// Total and available balance values.
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct Balance {
-    pub total: f64,
-    pub available: f64,
+    pub total: Decimal,
+    pub available: Decimal,
 }
 
 impl Balance {
     /// Construct a new [`Balance`].
-    pub fn new(total: f64, available: f64) -> Self {
+    pub fn new(total: Decimal, available: Decimal) -> Self {
         Self { total, available }
     }
 
     /// Calculate the used (`total` - `available`) balance.
-    pub fn used(&self) -> f64 {
+    pub fn used(&self) -> Decimal {
         self.total - self.available
     }
 
@@ -49,13 +71,13 @@ impl Balance {
 /// Communicates a change to be applied to a [`Balance`];
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct BalanceDelta {
-    pub total: f64,
-    pub available: f64,
+    pub total: Decimal,
+    pub available: Decimal,
 }
 
 impl BalanceDelta {
     /// Construct a new [`BalanceDelta`].
-    pub fn new(total: f64, available: f64) -> Self {
+    pub fn new(total: Decimal, available: Decimal) -> Self {
         Self { total, available }
     }
 }
@@ -63,19 +85,20 @@ impl BalanceDelta {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rust_decimal_macros::dec;
 
     #[test]
     fn test_balance_used() {
         // No Balance is used
-        let balance = Balance::new(10.0, 10.0);
-        assert_eq!(balance.used(), 0.0);
+        let balance = Balance::new(dec!(10.0), dec!(10.0));
+        assert_eq!(balance.used(), dec!(0.0));
 
         // All Balance is used
-        let balance = Balance::new(10.0, 0.0);
+        let balance = Balance::new(dec!(10.0), dec!(0.0));
         assert_eq!(balance.used(), balance.total);
 
         // Half Balance is used
-        let balance = Balance::new(10.0, 5.0);
+        let balance = Balance::new(dec!(10.0), dec!(5.0));
         assert_eq!(balance.used(), balance.available);
     }
 
@@ -90,39 +113,39 @@ mod tests {
         let tests = vec![
             TestCase {
                 // TC0: Delta applies a negative total delta only
-                balance: Balance::new(10.0, 0.0),
-                input_delta: BalanceDelta::new(-10.0, 0.0),
-                expected: Balance::new(0.0, 0.0),
+                balance: Balance::new(dec!(10.0), dec!(0.0)),
+                input_delta: BalanceDelta::new(-dec!(10.0), dec!(0.0)),
+                expected: Balance::new(dec!(0.0), dec!(0.0)),
             },
             TestCase {
                 // TC1: Delta applies a negative available delta only
-                balance: Balance::new(10.0, 10.0),
-                input_delta: BalanceDelta::new(0.0, -10.0),
-                expected: Balance::new(10.0, 0.0),
+                balance: Balance::new(dec!(10.0), dec!(10.0)),
+                input_delta: BalanceDelta::new(dec!(0.0), -dec!(10.0)),
+                expected: Balance::new(dec!(10.0), dec!(0.0)),
             },
             TestCase {
                 // TC2: Delta applies a positive available delta only
-                balance: Balance::new(10.0, 10.0),
-                input_delta: BalanceDelta::new(0.0, 10.0),
-                expected: Balance::new(10.0, 20.0),
+                balance: Balance::new(dec!(10.0), dec!(10.0)),
+                input_delta: BalanceDelta::new(dec!(0.0), dec!(10.0)),
+                expected: Balance::new(dec!(10.0), dec!(20.0)),
             },
             TestCase {
                 // TC3: Delta applies a positive available delta only
-                balance: Balance::new(10.0, 10.0),
-                input_delta: BalanceDelta::new(0.0, 10.0),
-                expected: Balance::new(10.0, 20.0),
+                balance: Balance::new(dec!(10.0), dec!(10.0)),
+                input_delta: BalanceDelta::new(dec!(0.0), dec!(10.0)),
+                expected: Balance::new(dec!(10.0), dec!(20.0)),
             },
             TestCase {
                 // TC4: Delta applies a positive total & available delta
-                balance: Balance::new(10.0, 10.0),
-                input_delta: BalanceDelta::new(10.0, 10.0),
-                expected: Balance::new(20.0, 20.0),
+                balance: Balance::new(dec!(10.0), dec!(10.0)),
+                input_delta: BalanceDelta::new(dec!(10.0), dec!(10.0)),
+                expected: Balance::new(dec!(20.0), dec!(20.0)),
             },
             TestCase {
                 // TC5: Delta applies a negative total & available delta
-                balance: Balance::new(10.0, 10.0),
-                input_delta: BalanceDelta::new(-10.0, -10.0),
-                expected: Balance::new(0.0, 0.0),
+                balance: Balance::new(dec!(10.0), dec!(10.0)),
+                input_delta: BalanceDelta::new(-dec!(10.0), -dec!(10.0)),
+                expected: Balance::new(dec!(0.0), dec!(0.0)),
             },
         ];
 
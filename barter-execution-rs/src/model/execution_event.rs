@@ -1,6 +1,6 @@
 use barter_integration::model::Exchange;
 
-use super::order::{Order, RequestCancel, RequestOpen};
+use super::order::{Order, RequestAmend, RequestCancel, RequestOpen};
 
 // Todo: If we pass tuple (Exchange, Order<Request>), the OrderRequest should maybe be diff that doesn't include Exchange
 #[derive(Debug)]
@@ -11,6 +11,8 @@ pub enum ExecutionRequest {
 
     OpenOrders(Vec<(Exchange, Vec<Order<RequestOpen>>)>),
 
+    AmendOrders(Vec<(Exchange, Vec<Order<RequestAmend>>)>),
+
     CancelOrders(Vec<(Exchange, Vec<Order<RequestCancel>>)>),
     CancelOrdersAll(Vec<Exchange>),
 }
@@ -25,6 +27,8 @@ pub enum ExchangeRequest {
     // Open Orders
     OpenOrders(Vec<Order<RequestOpen>>),
 
+    AmendOrders(Vec<Order<RequestAmend>>),
+
     CancelOrders(Vec<Order<RequestCancel>>),
     CancelOrdersAll,
 }
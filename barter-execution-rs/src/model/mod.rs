@@ -1,5 +1,5 @@
 use self::{
-    balance::SymbolBalance,
+    balance::{SymbolBalance, SymbolBalanceTotal},
     order::{Cancelled, Open, Order},
     trade::Trade,
 };
@@ -25,15 +25,18 @@ pub struct AccountEvent {
 }
 
 /// Defines the type of Barter [`AccountEvent`].
-#[derive(Clone, Debug, Deserialize, Serialize)]
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
 pub enum AccountEventKind {
     // HTTP Only
     OrdersOpen(Vec<Order<Open>>),
     OrdersNew(Vec<Order<Open>>),
+    OrdersAmended(Vec<Order<Open>>),
     OrdersCancelled(Vec<Order<Cancelled>>),
 
     // WebSocket Only
     Balance(SymbolBalance),
+    /// Total balance updates that leave the associated available balances unchanged.
+    BalanceTotals(Vec<SymbolBalanceTotal>),
     Trade(Trade),
 
     // HTTP & WebSocket
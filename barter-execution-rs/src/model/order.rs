@@ -1,8 +1,10 @@
 use super::ClientOrderId;
+use crate::error::ExecutionError;
 use barter_integration::model::{
-    instrument::{symbol::Symbol, Instrument},
+    instrument::{spec::InstrumentSpec, symbol::Symbol, Instrument},
     Exchange, Side,
 };
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::{
     cmp::Ordering,
@@ -10,12 +12,75 @@ use std::{
 };
 
 /// Type of [`Order`].
+///
+/// Conditional kinds rest un-triggered until the trade price reaches their trigger:
+///  - Stop: [`Side::Sell`] triggers at or below the `trigger_price`, [`Side::Buy`] at or above.
+///  - Take profit: [`Side::Sell`] triggers at or above the `trigger_price`, [`Side::Buy`] at or
+///    below.
+///  - Trailing stop: triggers once the price retraces by the `trailing_delta` ratio
+///    (eg/ 0.01 for 1%) from the best price seen since the optional `activation_price` was reached.
+///
+/// Once triggered, `*Market` kinds execute as market orders, and `*Limit` kinds rest as limit
+/// orders at the [`RequestOpen`] price.
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
 pub enum OrderKind {
     Market,
     Limit,
     PostOnly,
     ImmediateOrCancel,
+    StopMarket {
+        trigger_price: Decimal,
+    },
+    StopLimit {
+        trigger_price: Decimal,
+    },
+    TakeProfitMarket {
+        trigger_price: Decimal,
+    },
+    TakeProfitLimit {
+        trigger_price: Decimal,
+    },
+    TrailingStop {
+        trailing_delta: Decimal,
+        activation_price: Option<Decimal>,
+    },
+}
+
+impl OrderKind {
+    /// Determines if this is a conditional [`OrderKind`] that only becomes active once triggered.
+    pub fn is_conditional(&self) -> bool {
+        !matches!(
+            self,
+            OrderKind::Market
+                | OrderKind::Limit
+                | OrderKind::PostOnly
+                | OrderKind::ImmediateOrCancel
+        )
+    }
+
+    /// Determines if this [`OrderKind`] executes at the [`RequestOpen`] limit price, rather than
+    /// at the market price.
+    pub fn has_limit_price(&self) -> bool {
+        matches!(
+            self,
+            OrderKind::Limit
+                | OrderKind::PostOnly
+                | OrderKind::ImmediateOrCancel
+                | OrderKind::StopLimit { .. }
+                | OrderKind::TakeProfitLimit { .. }
+        )
+    }
+
+    /// Return the fixed trigger price of a stop or take profit [`OrderKind`], if any.
+    pub fn trigger_price(&self) -> Option<Decimal> {
+        match self {
+            OrderKind::StopMarket { trigger_price }
+            | OrderKind::StopLimit { trigger_price }
+            | OrderKind::TakeProfitMarket { trigger_price }
+            | OrderKind::TakeProfitLimit { trigger_price } => Some(*trigger_price),
+            _ => None,
+        }
+    }
 }
 
 impl Display for OrderKind {
@@ -28,6 +93,11 @@ impl Display for OrderKind {
                 OrderKind::Limit => "limit",
                 OrderKind::PostOnly => "post_only",
                 OrderKind::ImmediateOrCancel => "immediate_or_cancel",
+                OrderKind::StopMarket { .. } => "stop_market",
+                OrderKind::StopLimit { .. } => "stop_limit",
+                OrderKind::TakeProfitMarket { .. } => "take_profit_market",
+                OrderKind::TakeProfitLimit { .. } => "take_profit_limit",
+                OrderKind::TrailingStop { .. } => "trailing_stop",
             }
         )
     }
@@ -45,15 +115,19 @@ pub struct Order<State> {
 
 /// The initial state of an [`Order`]. Sent to the [`ExecutionClient`](crate::ExecutionClient) for
 /// actioning.
-#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+#[derive(Copy, Clone, Eq, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct RequestOpen {
     pub kind: OrderKind,
-    pub price: f64,
-    pub quantity: f64,
+    pub price: Decimal,
+    pub quantity: Decimal,
+    /// Only reduce an existing position. Used to attach protective exits to derivatives
+    /// positions, and ignored by venues without positions (eg/ spot).
+    #[serde(default)]
+    pub reduce_only: bool,
 }
 
 impl Order<RequestOpen> {
-    pub fn required_available_balance(&self) -> (&Symbol, f64) {
+    pub fn required_available_balance(&self) -> (&Symbol, Decimal) {
         match self.side {
             Side::Buy => (
                 &self.instrument.quote,
@@ -62,6 +136,47 @@ impl Order<RequestOpen> {
             Side::Sell => (&self.instrument.base, self.state.quantity),
         }
     }
+
+    /// Round the price & quantity of this [`Order<RequestOpen>`] to the provided
+    /// [`InstrumentSpec`], and validate the result satisfies the exchange trading rules.
+    ///
+    /// Prices are rounded so they are never more aggressive than requested, and quantities are
+    /// rounded down. Prices of [`OrderKind`]s without a limit price (eg/ [`OrderKind::Market`])
+    /// are left untouched, and are only used to validate the minimum notional if non-zero.
+    /// Trigger & activation prices are rounded to the tick size.
+    pub fn conform(mut self, spec: &InstrumentSpec) -> Result<Self, ExecutionError> {
+        self.state.quantity = spec.round_quantity(self.state.quantity);
+        spec.validate_quantity(self.state.quantity)?;
+
+        match self.state.kind {
+            kind if kind.has_limit_price() => {
+                self.state.price = spec.round_price(self.state.price, self.side);
+                spec.validate_price(self.state.price)?;
+                spec.validate_notional(self.state.price, self.state.quantity)?;
+            }
+            _ if self.state.price.is_zero() => {}
+            _ => {
+                spec.validate_notional(self.state.price, self.state.quantity)?;
+            }
+        }
+
+        match &mut self.state.kind {
+            OrderKind::StopMarket { trigger_price }
+            | OrderKind::StopLimit { trigger_price }
+            | OrderKind::TakeProfitMarket { trigger_price }
+            | OrderKind::TakeProfitLimit { trigger_price }
+            | OrderKind::TrailingStop {
+                activation_price: Some(trigger_price),
+                ..
+            } => {
+                *trigger_price = spec.round_price(*trigger_price, self.side);
+                spec.validate_price(*trigger_price)?;
+            }
+            _ => {}
+        }
+
+        Ok(self)
+    }
 }
 
 /// State of an [`Order`] after a [`RequestOpen`] has been sent to the
@@ -84,21 +199,99 @@ where
     }
 }
 
+/// State of an [`Order`] after a request has been made for the price and/or quantity of an
+/// [`Open`] order to be amended.
+///
+/// The `quantity` is the new total quantity of the order, including any quantity already filled.
+/// The `kind`, `reduce_only` & `filled_quantity` of the resting order are only used by venues
+/// without native amend support, where the order is cancelled & replaced with a new
+/// [`RequestOpen`] for the remaining quantity.
+#[derive(Clone, Eq, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct RequestAmend {
+    pub id: OrderId,
+    pub kind: OrderKind,
+    pub price: Decimal,
+    pub quantity: Decimal,
+    #[serde(default)]
+    pub reduce_only: bool,
+    /// Quantity of the resting order already filled when the amend was requested.
+    #[serde(default)]
+    pub filled_quantity: Decimal,
+}
+
+impl RequestAmend {
+    /// Calculate the quantity remaining to be filled after the amend.
+    pub fn remaining_quantity(&self) -> Decimal {
+        (self.quantity - self.filled_quantity).max(Decimal::ZERO)
+    }
+}
+
+impl Order<RequestAmend> {
+    /// Round the price & quantity of this [`Order<RequestAmend>`] to the provided
+    /// [`InstrumentSpec`], and validate the result satisfies the exchange trading rules.
+    ///
+    /// Rounding follows [`Order<RequestOpen>::conform`].
+    pub fn conform(mut self, spec: &InstrumentSpec) -> Result<Self, ExecutionError> {
+        self.state.quantity = spec.round_quantity(self.state.quantity);
+        spec.validate_quantity(self.state.quantity)?;
+
+        if self.state.kind.has_limit_price() {
+            self.state.price = spec.round_price(self.state.price, self.side);
+            spec.validate_price(self.state.price)?;
+            spec.validate_notional(self.state.price, self.state.quantity)?;
+        }
+
+        Ok(self)
+    }
+}
+
 /// Todo:
 #[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
 pub struct Open {
     pub id: OrderId,
-    pub price: f64,
-    pub quantity: f64,
-    pub filled_quantity: f64,
+    pub price: Decimal,
+    pub quantity: Decimal,
+    pub filled_quantity: Decimal,
 }
 
 impl Open {
-    pub fn remaining_quantity(&self) -> f64 {
+    pub fn remaining_quantity(&self) -> Decimal {
         self.quantity - self.filled_quantity
     }
 }
 
+impl Order<Open> {
+    /// Available balance reserved by the remaining quantity of this [`Order<Open>`].
+    pub fn reserved_available_balance(&self) -> (&Symbol, Decimal) {
+        match self.side {
+            Side::Buy => (
+                &self.instrument.quote,
+                self.state.price * self.state.remaining_quantity(),
+            ),
+            Side::Sell => (&self.instrument.base, self.state.remaining_quantity()),
+        }
+    }
+
+    /// Apply the price & quantity of an amended [`Order<Open>`] returned by the venue to this
+    /// tracked [`Order<Open>`], keeping the quantity already filled.
+    ///
+    /// Amend responses do not always report fill progress, so the greater of the tracked &
+    /// amended filled quantity is kept. The [`OrderId`] only changes if the venue cancelled &
+    /// replaced the order.
+    pub fn apply_amend(&mut self, amended: &Order<Open>) {
+        self.state.price = amended.state.price;
+        self.state.quantity = amended.state.quantity;
+        self.state.filled_quantity = self
+            .state
+            .filled_quantity
+            .max(amended.state.filled_quantity);
+
+        if self.state.id != amended.state.id {
+            self.state.id = amended.state.id.clone();
+        }
+    }
+}
+
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize)]
 pub enum OrderFill {
     Full,
@@ -190,7 +383,58 @@ impl From<(OrderId, Order<RequestOpen>)> for Order<Open> {
                 id,
                 price: request.state.price,
                 quantity: request.state.quantity,
-                filled_quantity: 0.0,
+                filled_quantity: Decimal::ZERO,
+            },
+        }
+    }
+}
+
+impl From<&Order<RequestAmend>> for Order<RequestCancel> {
+    fn from(request: &Order<RequestAmend>) -> Self {
+        Self {
+            exchange: request.exchange.clone(),
+            instrument: request.instrument.clone(),
+            cid: request.cid,
+            side: request.side,
+            state: RequestCancel::from(request.state.id.clone()),
+        }
+    }
+}
+
+/// Replacement [`Order<RequestOpen>`] of a cancelled order, for only the quantity remaining after
+/// the amend so the quantity already filled is not re-opened.
+impl From<Order<RequestAmend>> for Order<RequestOpen> {
+    fn from(request: Order<RequestAmend>) -> Self {
+        Self {
+            exchange: request.exchange,
+            instrument: request.instrument,
+            cid: request.cid,
+            side: request.side,
+            state: RequestOpen {
+                kind: request.state.kind,
+                price: request.state.price,
+                quantity: request.state.remaining_quantity(),
+                reduce_only: request.state.reduce_only,
+            },
+        }
+    }
+}
+
+/// Amend responses that only acknowledge the [`OrderId`] do not report fill progress, so the
+/// `filled_quantity` is the one known when the amend was requested. Apply the result to the
+/// tracked [`Order<Open>`] with [`Order::apply_amend`] to keep any later fill progress.
+impl From<(OrderId, Order<RequestAmend>)> for Order<Open> {
+    fn from((id, request): (OrderId, Order<RequestAmend>)) -> Self {
+        Self {
+            exchange: request.exchange,
+            instrument: request.instrument,
+            cid: request.cid,
+            side: request.side,
+            state: Open {
+                id,
+                price: request.state.price,
+                quantity: request.state.quantity,
+                filled_quantity: request.state.filled_quantity,
             },
         }
     }
@@ -212,12 +456,53 @@ impl From<Order<Open>> for Order<Cancelled> {
 mod tests {
     use super::*;
     use crate::test_util::order_open;
+    use barter_integration::{error::SpecError, model::instrument::kind::InstrumentKind};
+    use rust_decimal_macros::dec;
     use uuid::Uuid;
 
     #[test]
     fn test_open_order_remaining_quantity() {
-        let order = order_open(ClientOrderId(Uuid::new_v4()), Side::Buy, 10.0, 10.0, 5.0);
-        assert_eq!(order.state.remaining_quantity(), 5.0)
+        let order = order_open(
+            ClientOrderId(Uuid::new_v4()),
+            Side::Buy,
+            dec!(10.0),
+            dec!(10.0),
+            dec!(5.0),
+        );
+        assert_eq!(order.state.remaining_quantity(), dec!(5.0))
+    }
+
+    #[test]
+    fn test_apply_amend_keeps_filled_quantity_of_partially_filled_order() {
+        let cid = ClientOrderId(Uuid::new_v4());
+        let mut tracked = order_open(cid, Side::Buy, dec!(100.0), dec!(10.0), dec!(4.0));
+
+        // Venue amend response without fill progress
+        let amended = Order::<Open>::from((
+            tracked.state.id.clone(),
+            Order {
+                exchange: tracked.exchange.clone(),
+                instrument: tracked.instrument.clone(),
+                cid,
+                side: Side::Buy,
+                state: RequestAmend {
+                    id: tracked.state.id.clone(),
+                    kind: OrderKind::Limit,
+                    price: dec!(99.0),
+                    quantity: dec!(12.0),
+                    reduce_only: false,
+                    filled_quantity: dec!(4.0),
+                },
+            },
+        ));
+
+        tracked.apply_amend(&amended);
+
+        assert_eq!(tracked.state.price, dec!(99.0));
+        assert_eq!(tracked.state.quantity, dec!(12.0));
+        assert_eq!(tracked.state.filled_quantity, dec!(4.0));
+        assert_eq!(tracked.state.remaining_quantity(), dec!(8.0));
+        assert_eq!(tracked.state.id, amended.state.id);
     }
 
     #[test]
@@ -234,118 +519,118 @@ mod tests {
             // -- Side::Buy Order<Open> --
             TestCase {
                 // TC0: Input One has higher price and higher quantity -> Greater
-                input_one: order_open(cid, Side::Buy, 1100.0, 2.0, 0.0),
-                input_two: order_open(cid, Side::Buy, 1000.0, 1.0, 0.0),
+                input_one: order_open(cid, Side::Buy, dec!(1100.0), dec!(2.0), dec!(0.0)),
+                input_two: order_open(cid, Side::Buy, dec!(1000.0), dec!(1.0), dec!(0.0)),
                 expected: Some(Ordering::Greater),
             },
             TestCase {
                 // TC1: Input One has higher price but same quantity -> Greater
-                input_one: order_open(cid, Side::Buy, 1100.0, 1.0, 0.0),
-                input_two: order_open(cid, Side::Buy, 1000.0, 1.0, 0.0),
+                input_one: order_open(cid, Side::Buy, dec!(1100.0), dec!(1.0), dec!(0.0)),
+                input_two: order_open(cid, Side::Buy, dec!(1000.0), dec!(1.0), dec!(0.0)),
                 expected: Some(Ordering::Greater),
             },
             TestCase {
                 // TC2: Input One has higher price but lower quantity -> Greater
-                input_one: order_open(cid, Side::Buy, 1100.0, 1.0, 0.0),
-                input_two: order_open(cid, Side::Buy, 1000.0, 2.0, 0.0),
+                input_one: order_open(cid, Side::Buy, dec!(1100.0), dec!(1.0), dec!(0.0)),
+                input_two: order_open(cid, Side::Buy, dec!(1000.0), dec!(2.0), dec!(0.0)),
                 expected: Some(Ordering::Greater),
             },
             TestCase {
                 // TC3: Input One has same price and higher quantity -> Greater
-                input_one: order_open(cid, Side::Buy, 1000.0, 2.0, 0.0),
-                input_two: order_open(cid, Side::Buy, 1000.0, 1.0, 0.0),
+                input_one: order_open(cid, Side::Buy, dec!(1000.0), dec!(2.0), dec!(0.0)),
+                input_two: order_open(cid, Side::Buy, dec!(1000.0), dec!(1.0), dec!(0.0)),
                 expected: Some(Ordering::Greater),
             },
             TestCase {
                 // TC4: Input One has same price and same quantity -> Equal
-                input_one: order_open(cid, Side::Buy, 1000.0, 1.0, 0.0),
-                input_two: order_open(cid, Side::Buy, 1000.0, 1.0, 0.0),
+                input_one: order_open(cid, Side::Buy, dec!(1000.0), dec!(1.0), dec!(0.0)),
+                input_two: order_open(cid, Side::Buy, dec!(1000.0), dec!(1.0), dec!(0.0)),
                 expected: Some(Ordering::Equal),
             },
             TestCase {
                 // TC5: Input One has same price but lower quantity -> Less
-                input_one: order_open(cid, Side::Buy, 1000.0, 1.0, 0.0),
-                input_two: order_open(cid, Side::Buy, 1000.0, 2.0, 0.0),
+                input_one: order_open(cid, Side::Buy, dec!(1000.0), dec!(1.0), dec!(0.0)),
+                input_two: order_open(cid, Side::Buy, dec!(1000.0), dec!(2.0), dec!(0.0)),
                 expected: Some(Ordering::Less),
             },
             TestCase {
                 // TC6: Input One has lower price but higher quantity -> Less
-                input_one: order_open(cid, Side::Buy, 1000.0, 2.0, 0.0),
-                input_two: order_open(cid, Side::Buy, 1100.0, 1.0, 0.0),
+                input_one: order_open(cid, Side::Buy, dec!(1000.0), dec!(2.0), dec!(0.0)),
+                input_two: order_open(cid, Side::Buy, dec!(1100.0), dec!(1.0), dec!(0.0)),
                 expected: Some(Ordering::Less),
             },
             TestCase {
                 // TC7: Input One has lower price and same quantity -> Less
-                input_one: order_open(cid, Side::Buy, 1000.0, 1.0, 0.0),
-                input_two: order_open(cid, Side::Buy, 1100.0, 1.0, 0.0),
+                input_one: order_open(cid, Side::Buy, dec!(1000.0), dec!(1.0), dec!(0.0)),
+                input_two: order_open(cid, Side::Buy, dec!(1100.0), dec!(1.0), dec!(0.0)),
                 expected: Some(Ordering::Less),
             },
             TestCase {
                 // TC8: Input One has lower price but lower quantity -> Less
-                input_one: order_open(cid, Side::Buy, 1000.0, 1.0, 0.0),
-                input_two: order_open(cid, Side::Buy, 1100.0, 2.0, 0.0),
+                input_one: order_open(cid, Side::Buy, dec!(1000.0), dec!(1.0), dec!(0.0)),
+                input_two: order_open(cid, Side::Buy, dec!(1100.0), dec!(2.0), dec!(0.0)),
                 expected: Some(Ordering::Less),
             },
             // -- Side::Sell Order<Open> --
             TestCase {
                 // TC9: Input One has higher price and higher quantity -> Lesser
-                input_one: order_open(cid, Side::Sell, 1100.0, 2.0, 0.0),
-                input_two: order_open(cid, Side::Sell, 1000.0, 1.0, 0.0),
+                input_one: order_open(cid, Side::Sell, dec!(1100.0), dec!(2.0), dec!(0.0)),
+                input_two: order_open(cid, Side::Sell, dec!(1000.0), dec!(1.0), dec!(0.0)),
                 expected: Some(Ordering::Less),
             },
             TestCase {
                 // TC10: Input One has higher price but same quantity -> Lesser
-                input_one: order_open(cid, Side::Sell, 1100.0, 1.0, 0.0),
-                input_two: order_open(cid, Side::Sell, 1000.0, 1.0, 0.0),
+                input_one: order_open(cid, Side::Sell, dec!(1100.0), dec!(1.0), dec!(0.0)),
+                input_two: order_open(cid, Side::Sell, dec!(1000.0), dec!(1.0), dec!(0.0)),
                 expected: Some(Ordering::Less),
             },
             TestCase {
                 // T11: Input One has higher price but lower quantity -> Lesser
-                input_one: order_open(cid, Side::Sell, 1100.0, 1.0, 0.0),
-                input_two: order_open(cid, Side::Sell, 1000.0, 2.0, 0.0),
+                input_one: order_open(cid, Side::Sell, dec!(1100.0), dec!(1.0), dec!(0.0)),
+                input_two: order_open(cid, Side::Sell, dec!(1000.0), dec!(2.0), dec!(0.0)),
                 expected: Some(Ordering::Less),
             },
             TestCase {
                 // TC12: Input One has same price and higher quantity -> Lesser
-                input_one: order_open(cid, Side::Sell, 1000.0, 2.0, 0.0),
-                input_two: order_open(cid, Side::Sell, 1000.0, 1.0, 0.0),
+                input_one: order_open(cid, Side::Sell, dec!(1000.0), dec!(2.0), dec!(0.0)),
+                input_two: order_open(cid, Side::Sell, dec!(1000.0), dec!(1.0), dec!(0.0)),
                 expected: Some(Ordering::Less),
             },
             TestCase {
                 // TC13: Input One has same price and same quantity -> Equal
-                input_one: order_open(cid, Side::Sell, 1000.0, 1.0, 0.0),
-                input_two: order_open(cid, Side::Sell, 1000.0, 1.0, 0.0),
+                input_one: order_open(cid, Side::Sell, dec!(1000.0), dec!(1.0), dec!(0.0)),
+                input_two: order_open(cid, Side::Sell, dec!(1000.0), dec!(1.0), dec!(0.0)),
                 expected: Some(Ordering::Equal),
             },
             TestCase {
                 // TC14: Input One has same price but lower quantity -> Greater
-                input_one: order_open(cid, Side::Sell, 1000.0, 1.0, 0.0),
-                input_two: order_open(cid, Side::Sell, 1000.0, 2.0, 0.0),
+                input_one: order_open(cid, Side::Sell, dec!(1000.0), dec!(1.0), dec!(0.0)),
+                input_two: order_open(cid, Side::Sell, dec!(1000.0), dec!(2.0), dec!(0.0)),
                 expected: Some(Ordering::Greater),
             },
             TestCase {
                 // TC15: Input One has lower price but higher quantity -> Greater
-                input_one: order_open(cid, Side::Sell, 1000.0, 2.0, 0.0),
-                input_two: order_open(cid, Side::Sell, 1100.0, 1.0, 0.0),
+                input_one: order_open(cid, Side::Sell, dec!(1000.0), dec!(2.0), dec!(0.0)),
+                input_two: order_open(cid, Side::Sell, dec!(1100.0), dec!(1.0), dec!(0.0)),
                 expected: Some(Ordering::Greater),
             },
             TestCase {
                 // TC16: Input One has lower price and same quantity -> Greater
-                input_one: order_open(cid, Side::Sell, 1000.0, 1.0, 0.0),
-                input_two: order_open(cid, Side::Sell, 1100.0, 1.0, 0.0),
+                input_one: order_open(cid, Side::Sell, dec!(1000.0), dec!(1.0), dec!(0.0)),
+                input_two: order_open(cid, Side::Sell, dec!(1100.0), dec!(1.0), dec!(0.0)),
                 expected: Some(Ordering::Greater),
             },
             TestCase {
                 // TC17: Input One has lower price but lower quantity -> Greater
-                input_one: order_open(cid, Side::Sell, 1000.0, 1.0, 0.0),
-                input_two: order_open(cid, Side::Sell, 1100.0, 2.0, 0.0),
+                input_one: order_open(cid, Side::Sell, dec!(1000.0), dec!(1.0), dec!(0.0)),
+                input_two: order_open(cid, Side::Sell, dec!(1100.0), dec!(2.0), dec!(0.0)),
                 expected: Some(Ordering::Greater),
             },
             // -- Inputs Are Not Comparable Due To Different Sides
             TestCase {
                 // TC18: Input One has lower price but lower quantity -> Greater
-                input_one: order_open(cid, Side::Buy, 1000.0, 1.0, 0.0),
-                input_two: order_open(cid, Side::Sell, 1100.0, 2.0, 0.0),
+                input_one: order_open(cid, Side::Buy, dec!(1000.0), dec!(1.0), dec!(0.0)),
+                input_two: order_open(cid, Side::Sell, dec!(1100.0), dec!(2.0), dec!(0.0)),
                 expected: None,
             },
         ];
@@ -386,80 +671,80 @@ mod tests {
             TestCase {
                 // TC1: Vector of Side::Buy Order<Open> already sorted
                 input: vec![
-                    order_open(cid, Side::Buy, 100.0, 1.0, 0.0),
-                    order_open(cid, Side::Buy, 200.0, 1.0, 0.0),
-                    order_open(cid, Side::Buy, 300.0, 1.0, 0.0),
+                    order_open(cid, Side::Buy, dec!(100.0), dec!(1.0), dec!(0.0)),
+                    order_open(cid, Side::Buy, dec!(200.0), dec!(1.0), dec!(0.0)),
+                    order_open(cid, Side::Buy, dec!(300.0), dec!(1.0), dec!(0.0)),
                 ],
                 expected: vec![
-                    order_open(cid, Side::Buy, 100.0, 1.0, 0.0),
-                    order_open(cid, Side::Buy, 200.0, 1.0, 0.0),
-                    order_open(cid, Side::Buy, 300.0, 1.0, 0.0),
+                    order_open(cid, Side::Buy, dec!(100.0), dec!(1.0), dec!(0.0)),
+                    order_open(cid, Side::Buy, dec!(200.0), dec!(1.0), dec!(0.0)),
+                    order_open(cid, Side::Buy, dec!(300.0), dec!(1.0), dec!(0.0)),
                 ],
             },
             TestCase {
                 // TC2: Vector of Side::Buy Order<Open> reverse sorted
                 input: vec![
-                    order_open(cid, Side::Buy, 300.0, 1.0, 0.0),
-                    order_open(cid, Side::Buy, 200.0, 1.0, 0.0),
-                    order_open(cid, Side::Buy, 100.0, 1.0, 0.0),
+                    order_open(cid, Side::Buy, dec!(300.0), dec!(1.0), dec!(0.0)),
+                    order_open(cid, Side::Buy, dec!(200.0), dec!(1.0), dec!(0.0)),
+                    order_open(cid, Side::Buy, dec!(100.0), dec!(1.0), dec!(0.0)),
                 ],
                 expected: vec![
-                    order_open(cid, Side::Buy, 100.0, 1.0, 0.0),
-                    order_open(cid, Side::Buy, 200.0, 1.0, 0.0),
-                    order_open(cid, Side::Buy, 300.0, 1.0, 0.0),
+                    order_open(cid, Side::Buy, dec!(100.0), dec!(1.0), dec!(0.0)),
+                    order_open(cid, Side::Buy, dec!(200.0), dec!(1.0), dec!(0.0)),
+                    order_open(cid, Side::Buy, dec!(300.0), dec!(1.0), dec!(0.0)),
                 ],
             },
             TestCase {
                 // TC3: Vector of Side::Buy Order<Open> unsorted sorted
                 input: vec![
-                    order_open(cid, Side::Buy, 200.0, 1.0, 0.0),
-                    order_open(cid, Side::Buy, 100.0, 1.0, 0.0),
-                    order_open(cid, Side::Buy, 300.0, 1.0, 0.0),
+                    order_open(cid, Side::Buy, dec!(200.0), dec!(1.0), dec!(0.0)),
+                    order_open(cid, Side::Buy, dec!(100.0), dec!(1.0), dec!(0.0)),
+                    order_open(cid, Side::Buy, dec!(300.0), dec!(1.0), dec!(0.0)),
                 ],
                 expected: vec![
-                    order_open(cid, Side::Buy, 100.0, 1.0, 0.0),
-                    order_open(cid, Side::Buy, 200.0, 1.0, 0.0),
-                    order_open(cid, Side::Buy, 300.0, 1.0, 0.0),
+                    order_open(cid, Side::Buy, dec!(100.0), dec!(1.0), dec!(0.0)),
+                    order_open(cid, Side::Buy, dec!(200.0), dec!(1.0), dec!(0.0)),
+                    order_open(cid, Side::Buy, dec!(300.0), dec!(1.0), dec!(0.0)),
                 ],
             },
             // -- Vector: Side::Sell Order<Open> --
             TestCase {
                 // TC1: Vector of Side::Sell Order<Open> already sorted
                 input: vec![
-                    order_open(cid, Side::Sell, 300.0, 1.0, 0.0),
-                    order_open(cid, Side::Sell, 200.0, 1.0, 0.0),
-                    order_open(cid, Side::Sell, 100.0, 1.0, 0.0),
+                    order_open(cid, Side::Sell, dec!(300.0), dec!(1.0), dec!(0.0)),
+                    order_open(cid, Side::Sell, dec!(200.0), dec!(1.0), dec!(0.0)),
+                    order_open(cid, Side::Sell, dec!(100.0), dec!(1.0), dec!(0.0)),
                 ],
                 expected: vec![
-                    order_open(cid, Side::Sell, 300.0, 1.0, 0.0),
-                    order_open(cid, Side::Sell, 200.0, 1.0, 0.0),
-                    order_open(cid, Side::Sell, 100.0, 1.0, 0.0),
+                    order_open(cid, Side::Sell, dec!(300.0), dec!(1.0), dec!(0.0)),
+                    order_open(cid, Side::Sell, dec!(200.0), dec!(1.0), dec!(0.0)),
+                    order_open(cid, Side::Sell, dec!(100.0), dec!(1.0), dec!(0.0)),
                 ],
             },
             TestCase {
                 // TC2: Vector of Side::Sell Order<Open> reverse sorted
                 input: vec![
-                    order_open(cid, Side::Sell, 100.0, 1.0, 0.0),
-                    order_open(cid, Side::Sell, 200.0, 1.0, 0.0),
-                    order_open(cid, Side::Sell, 300.0, 1.0, 0.0),
+                    order_open(cid, Side::Sell, dec!(100.0), dec!(1.0), dec!(0.0)),
+                    order_open(cid, Side::Sell, dec!(200.0), dec!(1.0), dec!(0.0)),
+                    order_open(cid, Side::Sell, dec!(300.0), dec!(1.0), dec!(0.0)),
                 ],
                 expected: vec![
-                    order_open(cid, Side::Sell, 300.0, 1.0, 0.0),
-                    order_open(cid, Side::Sell, 200.0, 1.0, 0.0),
-                    order_open(cid, Side::Sell, 100.0, 1.0, 0.0),
+                    order_open(cid, Side::Sell, dec!(300.0), dec!(1.0), dec!(0.0)),
+                    order_open(cid, Side::Sell, dec!(200.0), dec!(1.0), dec!(0.0)),
+                    order_open(cid, Side::Sell, dec!(100.0), dec!(1.0), dec!(0.0)),
                 ],
             },
             TestCase {
                 // TC3: Vector of Side::Sell Order<Open> unsorted sorted
                 input: vec![
-                    order_open(cid, Side::Sell, 200.0, 1.0, 0.0),
-                    order_open(cid, Side::Sell, 100.0, 1.0, 0.0),
-                    order_open(cid, Side::Sell, 300.0, 1.0, 0.0),
+                    order_open(cid, Side::Sell, dec!(200.0), dec!(1.0), dec!(0.0)),
+                    order_open(cid, Side::Sell, dec!(100.0), dec!(1.0), dec!(0.0)),
+                    order_open(cid, Side::Sell, dec!(300.0), dec!(1.0), dec!(0.0)),
                 ],
                 expected: vec![
-                    order_open(cid, Side::Sell, 300.0, 1.0, 0.0),
-                    order_open(cid, Side::Sell, 200.0, 1.0, 0.0),
-                    order_open(cid, Side::Sell, 100.0, 1.0, 0.0),
+                    order_open(cid, Side::Sell, dec!(300.0), dec!(1.0), dec!(0.0)),
+                    order_open(cid, Side::Sell, dec!(200.0), dec!(1.0), dec!(0.0)),
+                    order_open(cid, Side::Sell, dec!(100.0), dec!(1.0), dec!(0.0)),
                 ],
             },
         ];
@@ -469,4 +754,271 @@ mod tests {
             assert_eq!(test.input, test.expected, "TC{} failed", index);
         }
     }
+
+    #[test]
+    fn test_conform_order_request_open() {
+        struct TestCase {
+            input: Order<RequestOpen>,
+            expected: Result<Order<RequestOpen>, ExecutionError>,
+        }
+
+        let spec = InstrumentSpec {
+            instrument: Instrument::from(("base", "quote", InstrumentKind::Perpetual)),
+            tick_size: dec!(0.5),
+            lot_size: dec!(0.01),
+            min_quantity: dec!(0.01),
+            min_notional: dec!(10),
+            contract_multiplier: dec!(1),
+        };
+
+        let request = |side, kind, price, quantity| Order {
+            exchange: Exchange::from("exchange"),
+            instrument: spec.instrument.clone(),
+            cid: ClientOrderId(Uuid::nil()),
+            side,
+            state: RequestOpen {
+                kind,
+                price,
+                quantity,
+                reduce_only: false,
+            },
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: Side::Buy Limit price rounded down & quantity rounded down
+                input: request(Side::Buy, OrderKind::Limit, dec!(100.7), dec!(1.019)),
+                expected: Ok(request(
+                    Side::Buy,
+                    OrderKind::Limit,
+                    dec!(100.5),
+                    dec!(1.01),
+                )),
+            },
+            TestCase {
+                // TC1: Side::Sell PostOnly price rounded up & quantity rounded down
+                input: request(Side::Sell, OrderKind::PostOnly, dec!(100.2), dec!(1.019)),
+                expected: Ok(request(
+                    Side::Sell,
+                    OrderKind::PostOnly,
+                    dec!(100.5),
+                    dec!(1.01),
+                )),
+            },
+            TestCase {
+                // TC2: Market price is left untouched
+                input: request(Side::Buy, OrderKind::Market, dec!(100.7), dec!(1.019)),
+                expected: Ok(request(
+                    Side::Buy,
+                    OrderKind::Market,
+                    dec!(100.7),
+                    dec!(1.01),
+                )),
+            },
+            TestCase {
+                // TC3: Market with zero price skips the min notional check
+                input: request(Side::Buy, OrderKind::Market, dec!(0), dec!(0.019)),
+                expected: Ok(request(Side::Buy, OrderKind::Market, dec!(0), dec!(0.01))),
+            },
+            TestCase {
+                // TC4: quantity rounded below the min quantity is rejected
+                input: request(Side::Buy, OrderKind::Limit, dec!(100.0), dec!(0.009)),
+                expected: Err(ExecutionError::InvalidOrder(SpecError::MinQuantity {
+                    quantity: dec!(0),
+                    min_quantity: dec!(0.01),
+                })),
+            },
+            TestCase {
+                // TC5: notional below the min notional is rejected
+                input: request(Side::Buy, OrderKind::Limit, dec!(100.0), dec!(0.05)),
+                expected: Err(ExecutionError::InvalidOrder(SpecError::MinNotional {
+                    notional: dec!(5.000),
+                    min_notional: dec!(10),
+                })),
+            },
+            TestCase {
+                // TC6: Side::Sell StopMarket trigger price rounded up & zero price left untouched
+                input: request(
+                    Side::Sell,
+                    OrderKind::StopMarket {
+                        trigger_price: dec!(95.2),
+                    },
+                    dec!(0),
+                    dec!(1.019),
+                ),
+                expected: Ok(request(
+                    Side::Sell,
+                    OrderKind::StopMarket {
+                        trigger_price: dec!(95.5),
+                    },
+                    dec!(0),
+                    dec!(1.01),
+                )),
+            },
+            TestCase {
+                // TC7: Side::Buy StopLimit trigger & limit price rounded down
+                input: request(
+                    Side::Buy,
+                    OrderKind::StopLimit {
+                        trigger_price: dec!(105.2),
+                    },
+                    dec!(105.7),
+                    dec!(1),
+                ),
+                expected: Ok(request(
+                    Side::Buy,
+                    OrderKind::StopLimit {
+                        trigger_price: dec!(105.0),
+                    },
+                    dec!(105.5),
+                    dec!(1),
+                )),
+            },
+            TestCase {
+                // TC8: Side::Sell TrailingStop activation price rounded up, delta untouched
+                input: request(
+                    Side::Sell,
+                    OrderKind::TrailingStop {
+                        trailing_delta: dec!(0.013),
+                        activation_price: Some(dec!(110.1)),
+                    },
+                    dec!(0),
+                    dec!(1),
+                ),
+                expected: Ok(request(
+                    Side::Sell,
+                    OrderKind::TrailingStop {
+                        trailing_delta: dec!(0.013),
+                        activation_price: Some(dec!(110.5)),
+                    },
+                    dec!(0),
+                    dec!(1),
+                )),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.input.conform(&spec);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(actual), Err(expected)) => {
+                    assert_eq!(
+                        actual.to_string(),
+                        expected.to_string(),
+                        "TC{} failed",
+                        index
+                    )
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_conform_order_request_amend() {
+        struct TestCase {
+            input: Order<RequestAmend>,
+            expected: Result<Order<RequestAmend>, ExecutionError>,
+        }
+
+        let spec = InstrumentSpec {
+            instrument: Instrument::from(("base", "quote", InstrumentKind::Perpetual)),
+            tick_size: dec!(0.5),
+            lot_size: dec!(0.01),
+            min_quantity: dec!(0.01),
+            min_notional: dec!(10),
+            contract_multiplier: dec!(1),
+        };
+
+        let request = |side, kind, price, quantity| Order {
+            exchange: Exchange::from("exchange"),
+            instrument: spec.instrument.clone(),
+            cid: ClientOrderId(Uuid::nil()),
+            side,
+            state: RequestAmend {
+                id: OrderId::from("order_id"),
+                kind,
+                price,
+                quantity,
+                reduce_only: false,
+                filled_quantity: Decimal::ZERO,
+            },
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: Side::Buy Limit price rounded down & quantity rounded down
+                input: request(Side::Buy, OrderKind::Limit, dec!(100.7), dec!(1.019)),
+                expected: Ok(request(
+                    Side::Buy,
+                    OrderKind::Limit,
+                    dec!(100.5),
+                    dec!(1.01),
+                )),
+            },
+            TestCase {
+                // TC1: Side::Sell PostOnly price rounded up & quantity rounded down
+                input: request(Side::Sell, OrderKind::PostOnly, dec!(100.2), dec!(1.019)),
+                expected: Ok(request(
+                    Side::Sell,
+                    OrderKind::PostOnly,
+                    dec!(100.5),
+                    dec!(1.01),
+                )),
+            },
+            TestCase {
+                // TC2: StopMarket price is left untouched
+                input: request(
+                    Side::Sell,
+                    OrderKind::StopMarket {
+                        trigger_price: dec!(95),
+                    },
+                    dec!(0),
+                    dec!(1.019),
+                ),
+                expected: Ok(request(
+                    Side::Sell,
+                    OrderKind::StopMarket {
+                        trigger_price: dec!(95),
+                    },
+                    dec!(0),
+                    dec!(1.01),
+                )),
+            },
+            TestCase {
+                // TC3: notional below the min notional is rejected
+                input: request(Side::Buy, OrderKind::Limit, dec!(100.0), dec!(0.05)),
+                expected: Err(ExecutionError::InvalidOrder(SpecError::MinNotional {
+                    notional: dec!(5.000),
+                    min_notional: dec!(10),
+                })),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.input.conform(&spec);
+            match (actual, test.expected) {
+                (Ok(actual), Ok(expected)) => {
+                    assert_eq!(actual, expected, "TC{} failed", index)
+                }
+                (Err(actual), Err(expected)) => {
+                    assert_eq!(
+                        actual.to_string(),
+                        expected.to_string(),
+                        "TC{} failed",
+                        index
+                    )
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
 }
@@ -3,6 +3,7 @@ use barter_integration::model::{
     instrument::{symbol::Symbol, Instrument},
     Side,
 };
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// Normalised Barter private [`Trade`] model.
@@ -12,8 +13,8 @@ pub struct Trade {
     pub order_id: OrderId,
     pub instrument: Instrument,
     pub side: Side,
-    pub price: f64,
-    pub quantity: f64,
+    pub price: Decimal,
+    pub quantity: Decimal,
     pub fees: SymbolFees,
 }
 
@@ -37,12 +38,12 @@ where
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct SymbolFees {
     pub symbol: Symbol,
-    pub fees: f64,
+    pub fees: Decimal,
 }
 
 impl SymbolFees {
     /// Construct a new [`SymbolFees`].
-    pub fn new<S>(symbol: S, fees: f64) -> Self
+    pub fn new<S>(symbol: S, fees: Decimal) -> Self
     where
         S: Into<Symbol>,
     {
@@ -0,0 +1,375 @@
+use crate::model::{balance::BalanceDelta, trade::Trade};
+use barter_integration::model::{
+    instrument::{kind::InstrumentKind, Instrument},
+    Side,
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Leverage & maintenance margin requirements the simulated exchange enforces when trading
+/// derivative [`Instrument`]s (eg/ [`InstrumentKind::Perpetual`]).
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct MarginConfig {
+    /// Notional value of a position per unit of quote margin posted (eg/ 10x).
+    pub leverage: Decimal,
+    /// Proportion of the position notional value that must be covered by it's margin &
+    /// unrealised P&L before it is liquidated (eg/ 0.005).
+    pub maintenance_margin_rate: Decimal,
+}
+
+/// Open isolated margin position held by the client in a derivative [`Instrument`].
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct MarginPosition {
+    pub side: Side,
+    /// Absolute quantity of contracts open.
+    pub quantity: Decimal,
+    /// Volume weighted average entry price.
+    pub entry_price: Decimal,
+    /// Quote margin posted to hold the position open.
+    pub margin: Decimal,
+}
+
+impl MarginPosition {
+    /// Calculate the P&L of closing `quantity` of this [`MarginPosition`] at the input price.
+    pub fn profit_loss(&self, price: Decimal, quantity: Decimal) -> Decimal {
+        match self.side {
+            Side::Buy => (price - self.entry_price) * quantity,
+            Side::Sell => (self.entry_price - price) * quantity,
+        }
+    }
+
+    /// Calculate the price at which the posted margin plus unrealised P&L falls to the
+    /// maintenance margin requirement.
+    pub fn liquidation_price(&self, maintenance_margin_rate: Decimal) -> Decimal {
+        let entry_value = self.quantity * self.entry_price;
+
+        let liquidation_price = match self.side {
+            Side::Buy => {
+                (entry_value - self.margin)
+                    / (self.quantity * (Decimal::ONE - maintenance_margin_rate))
+            }
+            Side::Sell => {
+                (entry_value + self.margin)
+                    / (self.quantity * (Decimal::ONE + maintenance_margin_rate))
+            }
+        };
+
+        liquidation_price.max(Decimal::ZERO)
+    }
+
+    /// Determine if the [`MarginPosition`] is liquidated at the input price.
+    pub fn is_liquidated(&self, price: Decimal, maintenance_margin_rate: Decimal) -> bool {
+        let liquidation_price = self.liquidation_price(maintenance_margin_rate);
+        match self.side {
+            Side::Buy => price <= liquidation_price,
+            Side::Sell => price >= liquidation_price,
+        }
+    }
+}
+
+/// [`ClientAccount`](super::ClientAccount) [`MarginConfig`] & open [`MarginPosition`] for each
+/// derivative [`Instrument`].
+///
+/// Orders in derivative [`Instrument`]s reserve `price * quantity / leverage` of the quote
+/// [`Balance`](crate::model::balance::Balance) for either [`Side`], which becomes the margin of
+/// the [`MarginPosition`] once matched. Only isolated margin is simulated.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct ClientMargin {
+    pub config: MarginConfig,
+    pub positions: HashMap<Instrument, MarginPosition>,
+}
+
+impl ClientMargin {
+    /// Construct a new [`ClientMargin`] with no open [`MarginPosition`]s.
+    pub fn new(config: MarginConfig) -> Self {
+        Self {
+            config,
+            positions: HashMap::new(),
+        }
+    }
+
+    /// Determine if the [`Instrument`] is traded on margin rather than exchanging base & quote.
+    pub fn is_margined(instrument: &Instrument) -> bool {
+        matches!(
+            instrument.kind,
+            InstrumentKind::Perpetual | InstrumentKind::Future(_)
+        )
+    }
+
+    /// Calculate the quote margin required to trade the quantity at the input price.
+    pub fn required_margin(&self, price: Decimal, quantity: Decimal) -> Decimal {
+        price * quantity / self.config.leverage
+    }
+
+    /// Apply a client [`Trade`] to the [`MarginPosition`] of it's [`Instrument`], returning the
+    /// resulting quote [`BalanceDelta`].
+    ///
+    /// Increasing a [`MarginPosition`] converts the margin reserved by the matched order into
+    /// position margin. Reducing a [`MarginPosition`] releases the order & position margin of the
+    /// reduced quantity, and realises it's P&L. Trade fees are always charged in the quote.
+    pub fn update_from_trade(&mut self, trade: &Trade) -> BalanceDelta {
+        let fees = if trade.fees.symbol == trade.instrument.base {
+            trade.fees.fees * trade.price
+        } else {
+            trade.fees.fees
+        };
+        let mut delta = BalanceDelta {
+            total: -fees,
+            available: -fees,
+        };
+
+        let trade_margin = self.required_margin(trade.price, trade.quantity);
+
+        let position = match self.positions.get_mut(&trade.instrument) {
+            // Open a new MarginPosition
+            None => {
+                self.positions.insert(
+                    trade.instrument.clone(),
+                    MarginPosition {
+                        side: trade.side,
+                        quantity: trade.quantity,
+                        entry_price: trade.price,
+                        margin: trade_margin,
+                    },
+                );
+                return delta;
+            }
+            Some(position) => position,
+        };
+
+        // Increase the MarginPosition, re-weighting the entry price
+        if position.side == trade.side {
+            let quantity = position.quantity + trade.quantity;
+            position.entry_price = (position.entry_price * position.quantity
+                + trade.price * trade.quantity)
+                / quantity;
+            position.quantity = quantity;
+            position.margin += trade_margin;
+            return delta;
+        }
+
+        // Reduce the MarginPosition, releasing the margin of the reduced quantity
+        let reduced_quantity = trade.quantity.min(position.quantity);
+        let released_margin = position.margin * reduced_quantity / position.quantity;
+        let profit_loss = position.profit_loss(trade.price, reduced_quantity);
+        let released_trade_margin = trade_margin * reduced_quantity / trade.quantity;
+
+        delta.total += profit_loss;
+        delta.available += profit_loss + released_margin + released_trade_margin;
+
+        position.quantity -= reduced_quantity;
+        position.margin -= released_margin;
+
+        // Reverse into the opposite Side with any remaining trade quantity
+        if position.quantity.is_zero() {
+            let remaining_quantity = trade.quantity - reduced_quantity;
+
+            if remaining_quantity.is_zero() {
+                self.positions.remove(&trade.instrument);
+            } else {
+                *position = MarginPosition {
+                    side: trade.side,
+                    quantity: remaining_quantity,
+                    entry_price: trade.price,
+                    margin: trade_margin - released_trade_margin,
+                };
+            }
+        }
+
+        delta
+    }
+
+    /// Liquidate the [`MarginPosition`] of the [`Instrument`] if the input price has crossed it's
+    /// liquidation price, returning the liquidated [`MarginPosition`] & the quote
+    /// [`BalanceDelta`]. The entire position margin is forfeited.
+    pub fn liquidate(
+        &mut self,
+        instrument: &Instrument,
+        price: Decimal,
+    ) -> Option<(MarginPosition, BalanceDelta)> {
+        let maintenance_margin_rate = self.config.maintenance_margin_rate;
+
+        self.positions
+            .get(instrument)
+            .filter(|position| position.is_liquidated(price, maintenance_margin_rate))?;
+
+        self.positions.remove(instrument).map(|position| {
+            let delta = BalanceDelta {
+                total: -position.margin,
+                available: Decimal::ZERO,
+            };
+            (position, delta)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::{
+        order::OrderId,
+        trade::{SymbolFees, TradeId},
+    };
+    use rust_decimal_macros::dec;
+
+    fn perpetual() -> Instrument {
+        Instrument::from(("btc", "usdt", InstrumentKind::Perpetual))
+    }
+
+    fn trade(side: Side, price: Decimal, quantity: Decimal) -> Trade {
+        Trade {
+            id: TradeId::from("trade"),
+            order_id: OrderId::from("order"),
+            instrument: perpetual(),
+            side,
+            price,
+            quantity,
+            fees: SymbolFees::new("usdt", Decimal::ZERO),
+        }
+    }
+
+    fn client_margin() -> ClientMargin {
+        ClientMargin::new(MarginConfig {
+            leverage: dec!(10),
+            maintenance_margin_rate: dec!(0.005),
+        })
+    }
+
+    #[test]
+    fn test_margin_position_liquidation_price() {
+        struct TestCase {
+            position: MarginPosition,
+            expected: Decimal,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: 10x long liquidated ~9.5% below entry
+                position: MarginPosition {
+                    side: Side::Buy,
+                    quantity: dec!(1),
+                    entry_price: dec!(1000),
+                    margin: dec!(100),
+                },
+                expected: dec!(900) / dec!(0.995),
+            },
+            TestCase {
+                // TC1: 10x short liquidated ~9.5% above entry
+                position: MarginPosition {
+                    side: Side::Sell,
+                    quantity: dec!(1),
+                    entry_price: dec!(1000),
+                    margin: dec!(100),
+                },
+                expected: dec!(1100) / dec!(1.005),
+            },
+            TestCase {
+                // TC2: fully collateralised long is never liquidated above zero
+                position: MarginPosition {
+                    side: Side::Buy,
+                    quantity: dec!(1),
+                    entry_price: dec!(1000),
+                    margin: dec!(1000),
+                },
+                expected: Decimal::ZERO,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.position.liquidation_price(dec!(0.005));
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_client_margin_update_from_trade() {
+        struct TestCase {
+            input_trade: Trade,
+            expected_delta: BalanceDelta,
+            expected_position: Option<MarginPosition>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: open long posts the order margin as position margin
+                input_trade: trade(Side::Buy, dec!(1000), dec!(1)),
+                expected_delta: BalanceDelta::new(Decimal::ZERO, Decimal::ZERO),
+                expected_position: Some(MarginPosition {
+                    side: Side::Buy,
+                    quantity: dec!(1),
+                    entry_price: dec!(1000),
+                    margin: dec!(100),
+                }),
+            },
+            TestCase {
+                // TC1: increase long re-weights the entry price
+                input_trade: trade(Side::Buy, dec!(2000), dec!(1)),
+                expected_delta: BalanceDelta::new(Decimal::ZERO, Decimal::ZERO),
+                expected_position: Some(MarginPosition {
+                    side: Side::Buy,
+                    quantity: dec!(2),
+                    entry_price: dec!(1500),
+                    margin: dec!(300),
+                }),
+            },
+            TestCase {
+                // TC2: reduce long in profit releases margin & realises P&L
+                input_trade: trade(Side::Sell, dec!(2000), dec!(1)),
+                expected_delta: BalanceDelta::new(dec!(500), dec!(500) + dec!(150) + dec!(200)),
+                expected_position: Some(MarginPosition {
+                    side: Side::Buy,
+                    quantity: dec!(1),
+                    entry_price: dec!(1500),
+                    margin: dec!(150),
+                }),
+            },
+            TestCase {
+                // TC3: reverse long into short with the remaining quantity
+                input_trade: trade(Side::Sell, dec!(1000), dec!(3)),
+                expected_delta: BalanceDelta::new(dec!(-500), dec!(-500) + dec!(150) + dec!(100)),
+                expected_position: Some(MarginPosition {
+                    side: Side::Sell,
+                    quantity: dec!(2),
+                    entry_price: dec!(1000),
+                    margin: dec!(200),
+                }),
+            },
+            TestCase {
+                // TC4: exit short at a loss
+                input_trade: trade(Side::Buy, dec!(1100), dec!(2)),
+                expected_delta: BalanceDelta::new(dec!(-200), dec!(-200) + dec!(200) + dec!(220)),
+                expected_position: None,
+            },
+        ];
+
+        let mut margin = client_margin();
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual_delta = margin.update_from_trade(&test.input_trade);
+            assert_eq!(actual_delta, test.expected_delta, "TC{} failed", index);
+            assert_eq!(
+                margin.positions.get(&perpetual()).copied(),
+                test.expected_position,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_client_margin_liquidate() {
+        let mut margin = client_margin();
+        margin.update_from_trade(&trade(Side::Buy, dec!(1000), dec!(1)));
+
+        // Price above the liquidation price leaves the MarginPosition open
+        assert_eq!(margin.liquidate(&perpetual(), dec!(950)), None);
+        assert!(margin.positions.contains_key(&perpetual()));
+
+        // Price crossing the liquidation price forfeits the position margin
+        let (liquidated, delta) = margin.liquidate(&perpetual(), dec!(900)).unwrap();
+        assert_eq!(liquidated.quantity, dec!(1));
+        assert_eq!(delta, BalanceDelta::new(dec!(-100), Decimal::ZERO));
+        assert!(margin.positions.is_empty());
+    }
+}
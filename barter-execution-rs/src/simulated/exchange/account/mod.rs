@@ -1,15 +1,24 @@
-use self::{balance::ClientBalances, order::ClientOrders};
+use self::{
+    balance::ClientBalances,
+    margin::{ClientMargin, MarginConfig},
+    order::ClientOrders,
+};
 use crate::{
     model::{
-        balance::{Balance, SymbolBalance},
-        order::OrderKind,
+        balance::{Balance, BalanceDelta, SymbolBalance},
+        order::{OrderId, OrderKind},
+        trade::{SymbolFees, Trade},
         AccountEvent, AccountEventKind,
     },
-    Cancelled, ExecutionError, ExecutionId, Open, Order, RequestCancel, RequestOpen,
+    Cancelled, ExecutionError, ExecutionId, Open, Order, RequestAmend, RequestCancel, RequestOpen,
 };
 use barter_data::subscription::trade::PublicTrade;
-use barter_integration::model::{instrument::Instrument, Exchange, Side};
+use barter_integration::model::{
+    instrument::{symbol::Symbol, Instrument},
+    Exchange, Side,
+};
 use chrono::Utc;
+use rust_decimal::Decimal;
 use std::{fmt::Debug, time::Duration};
 use tokio::sync::{mpsc, oneshot};
 use tracing::warn;
@@ -21,15 +30,23 @@ pub mod balance;
 /// [`ClientAccount`] [`ClientOrders`] management & matching logic.
 pub mod order;
 
+/// [`ClientAccount`] leverage, margin & liquidation logic for derivative
+/// [`Instrument`]s.
+pub mod margin;
+
 /// Simulated account state containing [`ClientBalances`] and [`ClientOrders`]. Details the
 /// simulated account fees and latency.
+///
+/// If configured with a [`ClientMargin`], derivative [`Instrument`]s are traded on margin.
+/// Otherwise they are settled like spot [`Instrument`]s.
 #[derive(Clone, Debug)]
 pub struct ClientAccount {
     pub latency: Duration,
-    pub fees_percent: f64,
+    pub fees_percent: Decimal,
     pub event_account_tx: mpsc::UnboundedSender<AccountEvent>,
     pub balances: ClientBalances,
     pub orders: ClientOrders,
+    pub margin: Option<ClientMargin>,
 }
 
 impl ClientAccount {
@@ -76,8 +93,13 @@ impl ClientAccount {
     ) -> Result<Order<Open>, ExecutionError> {
         Self::check_order_kind_support(request.state.kind)?;
 
+        // Conditional orders rest un-triggered until their trigger price is reached
+        if request.state.kind.is_conditional() {
+            return self.try_open_order_pending(request);
+        }
+
         // Calculate required available balance to open order
-        let (symbol, required_balance) = request.required_available_balance();
+        let (symbol, required_balance) = required_available_balance(self.margin.as_ref(), &request);
 
         // Check available balance is sufficient
         self.balances
@@ -91,7 +113,7 @@ impl ClientAccount {
 
         // Now that fallible operations have succeeded, mutate ClientBalances & ClientOrders
         orders.add_order_open(open.clone());
-        let balance_event = self.balances.update_from_open(&open, required_balance);
+        let balance_event = self.update_balance_from_open(&open, required_balance);
 
         // Send AccountEvents to client
         self.event_account_tx
@@ -109,10 +131,40 @@ impl ClientAccount {
         Ok(open)
     }
 
+    /// Execute a conditional open order request, adding it to the un-triggered [`ClientOrders`].
+    /// [`Balance`] is only reserved once the order is triggered. Sends an [`AccountEvent`] for the
+    /// new order.
+    pub fn try_open_order_pending(
+        &mut self,
+        request: Order<RequestOpen>,
+    ) -> Result<Order<Open>, ExecutionError> {
+        // Check the Instrument is configured before generating an OrderId
+        self.orders.orders_mut(&request.instrument)?;
+
+        // Build PendingOrder & add it to the client Instrument Orders
+        let pending = self.orders.build_order_pending(request);
+        let open = pending.order_open();
+        self.orders
+            .orders_mut(&open.instrument)?
+            .add_order_pending(pending);
+
+        // Send AccountEvent to client
+        self.event_account_tx
+            .send(AccountEvent {
+                received_time: Utc::now(),
+                exchange: Exchange::from(ExecutionId::Simulated),
+                kind: AccountEventKind::OrdersNew(vec![open.clone()]),
+            })
+            .expect("Client is offline - failed to send AccountEvent::OrdersNew");
+
+        Ok(open)
+    }
+
     /// Check if the [`Order<RequestOpen>`] [`OrderKind`] is supported.
     pub fn check_order_kind_support(kind: OrderKind) -> Result<(), ExecutionError> {
         match kind {
             OrderKind::Limit | OrderKind::PostOnly => Ok(()),
+            conditional if conditional.is_conditional() => Ok(()),
             unsupported => Err(ExecutionError::UnsupportedOrderKind(unsupported)),
         }
     }
@@ -141,6 +193,25 @@ impl ClientAccount {
         // Retrieve client Instrument Orders
         let orders = self.orders.orders_mut(&request.instrument)?;
 
+        // Un-triggered conditional orders have no reserved Balance to release
+        if let Some(index) = orders
+            .pending
+            .iter()
+            .position(|pending| pending.id == request.state.id)
+        {
+            let cancelled = Order::from(orders.pending.remove(index).order_open());
+
+            self.event_account_tx
+                .send(AccountEvent {
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(ExecutionId::Simulated),
+                    kind: AccountEventKind::OrdersCancelled(vec![cancelled.clone()]),
+                })
+                .expect("Client is offline - failed to send AccountEvent::OrdersCancelled");
+
+            return Ok(cancelled);
+        }
+
         // Find & remove Order<Open> associated with the Order<RequestCancel>
         let removed = match request.side {
             Side::Buy => {
@@ -165,7 +236,7 @@ impl ClientAccount {
         };
 
         // Now that fallible operations have succeeded, mutate ClientBalances
-        let balance_event = self.balances.update_from_cancel(&removed);
+        let balance_event = self.update_balance_from_cancel(&removed);
 
         // Map Order<Open> to Order<Cancelled>
         let cancelled = Order::from(removed);
@@ -190,6 +261,103 @@ impl ClientAccount {
         Ok(cancelled)
     }
 
+    /// Execute amend order requests and send the response via the provided [`oneshot::Sender`].
+    pub fn amend_orders(
+        &mut self,
+        amend_requests: Vec<Order<RequestAmend>>,
+        response_tx: oneshot::Sender<Vec<Result<Order<Open>, ExecutionError>>>,
+    ) {
+        let amend_results = amend_requests
+            .into_iter()
+            .map(|request| self.try_amend_order_atomic(request))
+            .collect();
+
+        respond_with_latency(self.latency, response_tx, amend_results);
+    }
+
+    /// Execute an amend order request, updating the price & quantity of the associated
+    /// [`Order<Open>`] and the [`Balance`] it reserves. Sends an [`AccountEvent`] for both the
+    /// amended order and balance update.
+    ///
+    /// The amended quantity must exceed the quantity already filled. Un-triggered conditional
+    /// orders have no reserved [`Balance`], so only their [`RequestOpen`] is updated.
+    pub fn try_amend_order_atomic(
+        &mut self,
+        request: Order<RequestAmend>,
+    ) -> Result<Order<Open>, ExecutionError> {
+        // Retrieve client Instrument Orders
+        let orders = self.orders.orders_mut(&request.instrument)?;
+
+        if let Some(pending) = orders
+            .pending
+            .iter_mut()
+            .find(|pending| pending.id == request.state.id)
+        {
+            pending.request.state.price = request.state.price;
+            pending.request.state.quantity = request.state.quantity;
+            let amended = pending.order_open();
+
+            self.event_account_tx
+                .send(AccountEvent {
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(ExecutionId::Simulated),
+                    kind: AccountEventKind::OrdersAmended(vec![amended.clone()]),
+                })
+                .expect("Client is offline - failed to send AccountEvent::OrdersAmended");
+
+            return Ok(amended);
+        }
+
+        // Find Order<Open> associated with the Order<RequestAmend>
+        let book = match request.side {
+            Side::Buy => &mut orders.bids,
+            Side::Sell => &mut orders.asks,
+        };
+        let index = book
+            .iter()
+            .position(|open| open.state.id == request.state.id)
+            .ok_or(ExecutionError::OrderNotFound(request.cid))?;
+
+        let current = &book[index];
+        if request.state.quantity <= current.state.filled_quantity {
+            return Err(ExecutionError::Simulated(format!(
+                "amended quantity {} does not exceed filled quantity {}",
+                request.state.quantity, current.state.filled_quantity
+            )));
+        }
+
+        let mut amended = current.clone();
+        amended.state.price = request.state.price;
+        amended.state.quantity = request.state.quantity;
+
+        // Check available balance is sufficient for the change in reserved balance
+        let (symbol, reserved_balance) = reserved_available_balance(self.margin.as_ref(), current);
+        let (_, required_balance) = reserved_available_balance(self.margin.as_ref(), &amended);
+        let required_delta = required_balance - reserved_balance;
+        self.balances
+            .has_sufficient_available_balance(symbol, required_delta)?;
+
+        // Now that fallible operations have succeeded, mutate ClientBalances & ClientOrders
+        book.remove(index);
+        orders.add_order_open(amended.clone());
+        let balance_event = self.update_balance_from_open(&amended, required_delta);
+
+        // Send AccountEvents to client
+        self.event_account_tx
+            .send(AccountEvent {
+                received_time: Utc::now(),
+                exchange: Exchange::from(ExecutionId::Simulated),
+                kind: AccountEventKind::OrdersAmended(vec![amended.clone()]),
+            })
+            .expect("Client is offline - failed to send AccountEvent::OrdersAmended");
+
+        self.event_account_tx
+            .send(balance_event)
+            .expect("Client is offline - failed to send AccountEvent::Balance");
+
+        Ok(amended)
+    }
+
     /// Execute a cancel all orders request and send the response via the provided
     /// [`oneshot::Sender`].
     pub fn cancel_orders_all(
@@ -210,11 +378,21 @@ impl ClientAccount {
 
         let balance_updates = removed_orders
             .iter()
-            .map(|cancelled| self.balances.update_from_cancel(cancelled))
+            .map(|cancelled| self.update_balance_from_cancel(cancelled))
             .collect();
 
+        // Un-triggered conditional orders have no reserved Balance to release
+        let removed_pending = self
+            .orders
+            .all
+            .values_mut()
+            .flat_map(|orders| orders.pending.drain(..))
+            .map(|pending| pending.order_open())
+            .collect::<Vec<Order<Open>>>();
+
         let cancelled_orders = removed_orders
             .into_iter()
+            .chain(removed_pending)
             .map(Order::from)
             .collect::<Vec<Order<Cancelled>>>();
 
@@ -245,6 +423,9 @@ impl ClientAccount {
         // Client fees
         let fees_percent = self.fees_percent;
 
+        // Activate conditional orders triggered by the PublicTrade before matching
+        self.trigger_orders(&instrument, &trade);
+
         // Access the ClientOrders relating to the Instrument of the PublicTrade
         let orders = match self.orders.orders_mut(&instrument) {
             Ok(orders) => orders,
@@ -266,7 +447,7 @@ impl ClientAccount {
         // Apply Balance updates for each client Trade and send AccountEvents to client
         for trade in trades {
             // Update Balances
-            let balances_event = self.balances.update_from_trade(&trade);
+            let balances_event = self.update_balance_from_trade(&trade);
 
             self.event_account_tx
                 .send(balances_event)
@@ -280,6 +461,232 @@ impl ClientAccount {
                 })
                 .expect("Client is offline - failed to send AccountEvent::Trade");
         }
+
+        // Liquidate any MarginPosition the PublicTrade price has crossed the liquidation price of
+        self.liquidate_position(&instrument, &trade);
+    }
+
+    /// Liquidate the client [`MarginPosition`](margin::MarginPosition) of the [`Instrument`] if
+    /// the [`PublicTrade`] price has crossed it's liquidation price. The position margin is
+    /// forfeited, and the liquidation is sent to the client as an [`AccountEvent`] [`Trade`] in
+    /// the opposite [`Side`], followed by the quote [`Balance`] update.
+    pub fn liquidate_position(&mut self, instrument: &Instrument, trade: &PublicTrade) {
+        let price = trade.price;
+
+        let (position, delta) = match self
+            .margin
+            .as_mut()
+            .and_then(|margin| margin.liquidate(instrument, price))
+        {
+            Some(liquidation) => liquidation,
+            None => return,
+        };
+
+        warn!(%instrument, ?position, %price, "liquidating client MarginPosition");
+
+        let orders = self
+            .orders
+            .orders_mut(instrument)
+            .expect("MarginPosition Instrument is configured");
+        orders.trade_counter += 1;
+
+        let liquidation = Trade {
+            id: orders.trade_id(),
+            order_id: OrderId::from("liquidation"),
+            instrument: instrument.clone(),
+            side: match position.side {
+                Side::Buy => Side::Sell,
+                Side::Sell => Side::Buy,
+            },
+            price,
+            quantity: position.quantity,
+            fees: SymbolFees::new(instrument.quote.clone(), Decimal::ZERO),
+        };
+
+        let balance = self.balances.update(&instrument.quote, delta);
+
+        self.event_account_tx
+            .send(AccountEvent {
+                received_time: Utc::now(),
+                exchange: Exchange::from(ExecutionId::Simulated),
+                kind: AccountEventKind::Trade(liquidation),
+            })
+            .expect("Client is offline - failed to send AccountEvent::Trade");
+
+        self.event_account_tx
+            .send(AccountEvent {
+                received_time: Utc::now(),
+                exchange: Exchange::from(ExecutionId::Simulated),
+                kind: AccountEventKind::Balance(SymbolBalance::new(
+                    instrument.quote.clone(),
+                    balance,
+                )),
+            })
+            .expect("Client is offline - failed to send AccountEvent::Balance");
+    }
+
+    /// Updates the [`Balance`] reserved by a new [`Order<Open>`]. Orders in margined
+    /// [`Instrument`]s reserve quote margin for either [`Side`].
+    fn update_balance_from_open(
+        &mut self,
+        open: &Order<Open>,
+        required_balance: Decimal,
+    ) -> AccountEvent {
+        if margined(self.margin.as_ref(), &open.instrument).is_none() {
+            return self.balances.update_from_open(open, required_balance);
+        }
+
+        let balance = self.balances.update(
+            &open.instrument.quote,
+            BalanceDelta::new(Decimal::ZERO, -required_balance),
+        );
+
+        AccountEvent {
+            received_time: Utc::now(),
+            exchange: Exchange::from(ExecutionId::Simulated),
+            kind: AccountEventKind::Balance(SymbolBalance::new(
+                open.instrument.quote.clone(),
+                balance,
+            )),
+        }
+    }
+
+    /// Releases the [`Balance`] reserved by a cancelled [`Order<Open>`].
+    fn update_balance_from_cancel(&mut self, cancelled: &Order<Open>) -> SymbolBalance {
+        if margined(self.margin.as_ref(), &cancelled.instrument).is_none() {
+            return self.balances.update_from_cancel(cancelled);
+        }
+
+        let (symbol, reserved_balance) =
+            reserved_available_balance(self.margin.as_ref(), cancelled);
+        let balance = self
+            .balances
+            .update(symbol, BalanceDelta::new(Decimal::ZERO, reserved_balance));
+
+        SymbolBalance::new(symbol.clone(), balance)
+    }
+
+    /// Applies the [`Balance`] changes of a client [`Trade`]. Trades in margined [`Instrument`]s
+    /// update the client [`MarginPosition`](margin::MarginPosition) & settle in the quote.
+    fn update_balance_from_trade(&mut self, trade: &Trade) -> AccountEvent {
+        let delta = match self
+            .margin
+            .as_mut()
+            .filter(|_| ClientMargin::is_margined(&trade.instrument))
+        {
+            Some(margin) => margin.update_from_trade(trade),
+            None => return self.balances.update_from_trade(trade),
+        };
+
+        let quote = &trade.instrument.quote;
+        let balance = self.balances.update(quote, delta);
+
+        AccountEvent {
+            received_time: Utc::now(),
+            exchange: Exchange::from(ExecutionId::Simulated),
+            kind: AccountEventKind::Balances(vec![SymbolBalance::new(quote.clone(), balance)]),
+        }
+    }
+
+    /// Activate every conditional [`PendingOrder`](order::PendingOrder) of the [`Instrument`]
+    /// triggered by the [`PublicTrade`] price.
+    ///
+    /// Triggered orders reserve [`Balance`] and rest as limit orders, which are marketable at the
+    /// [`PublicTrade`] price for kinds without a limit price (eg/ [`OrderKind::StopMarket`]).
+    /// Triggered orders with insufficient available [`Balance`] are cancelled.
+    pub fn trigger_orders(&mut self, instrument: &Instrument, trade: &PublicTrade) {
+        let triggered = match self.orders.orders_mut(instrument) {
+            Ok(orders) => orders.trigger_pending(trade),
+            Err(_) => return,
+        };
+
+        for pending in triggered {
+            let id = pending.id.clone();
+            let request = pending.triggered(trade.price);
+
+            // Calculate & check required available balance to activate order
+            let (symbol, required_balance) =
+                required_available_balance(self.margin.as_ref(), &request);
+            if let Err(error) = self
+                .balances
+                .has_sufficient_available_balance(symbol, required_balance)
+            {
+                warn!(
+                    ?error,
+                    ?request,
+                    "cancelling triggered Order with insufficient balance"
+                );
+                self.event_account_tx
+                    .send(AccountEvent {
+                        received_time: Utc::now(),
+                        exchange: Exchange::from(ExecutionId::Simulated),
+                        kind: AccountEventKind::OrdersCancelled(vec![Order::from(
+                            Order::<Open>::from((id, request)),
+                        )]),
+                    })
+                    .expect("Client is offline - failed to send AccountEvent::OrdersCancelled");
+                continue;
+            }
+
+            // Now that fallible operations have succeeded, mutate ClientBalances & ClientOrders
+            let open = Order::<Open>::from((id, request));
+            self.orders
+                .orders_mut(instrument)
+                .expect("Instrument existence checked above")
+                .add_order_open(open.clone());
+            let balance_event = self.update_balance_from_open(&open, required_balance);
+
+            // Send AccountEvents to client
+            self.event_account_tx
+                .send(balance_event)
+                .expect("Client is offline - failed to send AccountEvent::Balance");
+
+            self.event_account_tx
+                .send(AccountEvent {
+                    received_time: Utc::now(),
+                    exchange: Exchange::from(ExecutionId::Simulated),
+                    kind: AccountEventKind::OrdersNew(vec![open]),
+                })
+                .expect("Client is offline - failed to send AccountEvent::OrdersNew");
+        }
+    }
+}
+
+/// Returns the [`ClientMargin`] if the [`Instrument`] is traded on margin.
+fn margined<'a>(
+    margin: Option<&'a ClientMargin>,
+    instrument: &Instrument,
+) -> Option<&'a ClientMargin> {
+    margin.filter(|_| ClientMargin::is_margined(instrument))
+}
+
+/// Calculate the [`Symbol`] & available [`Balance`] required to open the [`Order<RequestOpen>`].
+/// Orders in margined [`Instrument`]s require `price * quantity / leverage` of the quote.
+pub fn required_available_balance<'a>(
+    margin: Option<&ClientMargin>,
+    request: &'a Order<RequestOpen>,
+) -> (&'a Symbol, Decimal) {
+    match margined(margin, &request.instrument) {
+        Some(margin) => (
+            &request.instrument.quote,
+            margin.required_margin(request.state.price, request.state.quantity),
+        ),
+        None => request.required_available_balance(),
+    }
+}
+
+/// Calculate the [`Symbol`] & available [`Balance`] reserved by the remaining quantity of the
+/// [`Order<Open>`].
+pub fn reserved_available_balance<'a>(
+    margin: Option<&ClientMargin>,
+    open: &'a Order<Open>,
+) -> (&'a Symbol, Decimal) {
+    match margined(margin, &open.instrument) {
+        Some(margin) => (
+            &open.instrument.quote,
+            margin.required_margin(open.state.price, open.state.remaining_quantity()),
+        ),
+        None => open.reserved_available_balance(),
     }
 }
 
@@ -303,10 +710,11 @@ pub fn respond_with_latency<Response>(
 #[derive(Debug, Default)]
 pub struct ClientAccountBuilder {
     latency: Option<Duration>,
-    fees_percent: Option<f64>,
+    fees_percent: Option<Decimal>,
     event_account_tx: Option<mpsc::UnboundedSender<AccountEvent>>,
     instruments: Option<Vec<Instrument>>,
     balances: Option<ClientBalances>,
+    margin: Option<MarginConfig>,
 }
 
 impl ClientAccountBuilder {
@@ -323,7 +731,7 @@ impl ClientAccountBuilder {
         }
     }
 
-    pub fn fees_percent(self, value: f64) -> Self {
+    pub fn fees_percent(self, value: Decimal) -> Self {
         Self {
             fees_percent: Some(value),
             ..self
@@ -351,6 +759,15 @@ impl ClientAccountBuilder {
         }
     }
 
+    /// Trade derivative [`Instrument`]s on margin. If not provided, derivative [`Instrument`]s
+    /// are settled like spot.
+    pub fn margin(self, value: MarginConfig) -> Self {
+        Self {
+            margin: Some(value),
+            ..self
+        }
+    }
+
     pub fn build(self) -> Result<ClientAccount, ExecutionError> {
         // Construct ClientAccount
         let client_account = ClientAccount {
@@ -370,6 +787,7 @@ impl ClientAccountBuilder {
                 .instruments
                 .map(ClientOrders::new)
                 .ok_or_else(|| ExecutionError::BuilderIncomplete("instruments"))?,
+            margin: self.margin.map(ClientMargin::new),
         };
 
         // Validate each Instrument base & quote Symbol has an associated Balance
@@ -388,6 +806,89 @@ impl ClientAccountBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{model::ClientOrderId, test_util::order_request};
+    use barter_integration::model::instrument::kind::InstrumentKind;
+    use rust_decimal_macros::dec;
+    use uuid::Uuid;
+
+    #[test]
+    fn test_required_available_balance() {
+        struct TestCase {
+            margin: Option<ClientMargin>,
+            request: Order<RequestOpen>,
+            expected: (Symbol, Decimal),
+        }
+
+        let margin = ClientMargin::new(MarginConfig {
+            leverage: dec!(10),
+            maintenance_margin_rate: dec!(0.005),
+        });
+
+        let mut spot_request = order_request(
+            ClientOrderId(Uuid::new_v4()),
+            Side::Sell,
+            OrderKind::Limit,
+            dec!(1000),
+            dec!(2),
+        );
+        spot_request.instrument = Instrument::from(("base", "quote", InstrumentKind::Spot));
+
+        let tests = vec![
+            TestCase {
+                // TC0: Perpetual Sell without margin reserves the base
+                margin: None,
+                request: order_request(
+                    ClientOrderId(Uuid::new_v4()),
+                    Side::Sell,
+                    OrderKind::Limit,
+                    dec!(1000),
+                    dec!(2),
+                ),
+                expected: (Symbol::from("base"), dec!(2)),
+            },
+            TestCase {
+                // TC1: Perpetual Buy with margin reserves quote margin
+                margin: Some(margin.clone()),
+                request: order_request(
+                    ClientOrderId(Uuid::new_v4()),
+                    Side::Buy,
+                    OrderKind::Limit,
+                    dec!(1000),
+                    dec!(2),
+                ),
+                expected: (Symbol::from("quote"), dec!(200)),
+            },
+            TestCase {
+                // TC2: Perpetual Sell with margin reserves quote margin
+                margin: Some(margin.clone()),
+                request: order_request(
+                    ClientOrderId(Uuid::new_v4()),
+                    Side::Sell,
+                    OrderKind::Limit,
+                    dec!(1000),
+                    dec!(2),
+                ),
+                expected: (Symbol::from("quote"), dec!(200)),
+            },
+            TestCase {
+                // TC3: Spot Sell with margin reserves the base
+                margin: Some(margin),
+                request: spot_request,
+                expected: (Symbol::from("base"), dec!(2)),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (symbol, required) =
+                required_available_balance(test.margin.as_ref(), &test.request);
+            assert_eq!(
+                (symbol.clone(), required),
+                test.expected,
+                "TC{} failed",
+                index
+            );
+        }
+    }
 
     #[test]
     fn test_check_order_kind_support() {
@@ -419,6 +920,21 @@ mod tests {
                     OrderKind::ImmediateOrCancel,
                 )),
             },
+            TestCase {
+                // TC4: StopMarket
+                kind: OrderKind::StopMarket {
+                    trigger_price: Decimal::ONE,
+                },
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC5: TrailingStop
+                kind: OrderKind::TrailingStop {
+                    trailing_delta: Decimal::ONE,
+                    activation_price: None,
+                },
+                expected: Ok(()),
+            },
         ];
 
         for (index, test) in tests.into_iter().enumerate() {
@@ -1,9 +1,13 @@
 use crate::{
-    model::trade::{SymbolFees, Trade, TradeId},
+    model::{
+        order::OrderKind,
+        trade::{SymbolFees, Trade, TradeId},
+    },
     ExecutionError, Open, Order, OrderId, RequestOpen,
 };
 use barter_data::subscription::trade::PublicTrade;
 use barter_integration::model::{instrument::Instrument, Side};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use std::{cmp::Ordering, collections::HashMap};
 
@@ -35,13 +39,18 @@ impl ClientOrders {
         })
     }
 
-    /// Fetch the bid and ask [`Order<Open>`]s for every [`Instrument`].
+    /// Fetch the bid, ask and un-triggered conditional [`Order<Open>`]s for every [`Instrument`].
     pub fn fetch_all(&self) -> Vec<Order<Open>> {
         self.all
             .values()
-            .flat_map(|market| [&market.bids, &market.asks])
-            .flatten()
-            .cloned()
+            .flat_map(|market| {
+                let pending = market.pending.iter().map(PendingOrder::order_open);
+                [&market.bids, &market.asks]
+                    .into_iter()
+                    .flatten()
+                    .cloned()
+                    .chain(pending)
+            })
             .collect()
     }
 
@@ -52,6 +61,17 @@ impl ClientOrders {
         Order::from((self.order_id(), request))
     }
 
+    /// Build a [`PendingOrder`] from the provided conditional [`Order<RequestOpen>`]. The request
+    /// counter is incremented and the new total is used as a unique [`OrderId`].
+    pub fn build_order_pending(&mut self, request: Order<RequestOpen>) -> PendingOrder {
+        self.increment_request_counter();
+        PendingOrder {
+            id: self.order_id(),
+            request,
+            extreme_price: None,
+        }
+    }
+
     /// Increment the [`Order<RequestOpen>`] counter by one to ensure the next generated
     /// [`OrderId`] is unique.
     pub fn increment_request_counter(&mut self) {
@@ -71,9 +91,31 @@ pub struct Orders {
     pub trade_counter: u64,
     pub bids: Vec<Order<Open>>,
     pub asks: Vec<Order<Open>>,
+    #[serde(default)]
+    pub pending: Vec<PendingOrder>,
 }
 
 impl Orders {
+    /// Add an un-triggered conditional [`PendingOrder`].
+    pub fn add_order_pending(&mut self, pending: PendingOrder) {
+        self.pending.push(pending);
+    }
+
+    /// Remove & return every [`PendingOrder`] triggered by the [`PublicTrade`] price, updating
+    /// the trailing state of those that remain.
+    pub fn trigger_pending(&mut self, trade: &PublicTrade) -> Vec<PendingOrder> {
+        let mut triggered = vec![];
+        self.pending
+            .retain_mut(|pending| match pending.update(trade.price) {
+                true => {
+                    triggered.push(pending.clone());
+                    false
+                }
+                false => true,
+            });
+        triggered
+    }
+
     /// Add an [`Order<Open>`] to the bids or asks depending on it's [`Side`].
     pub fn add_order_open(&mut self, open: Order<Open>) {
         match open.side {
@@ -138,7 +180,7 @@ impl Orders {
 
     /// Simulates [`Side::Buy`] trades by using the [`PublicTrade`] liquidity to match on open
     /// client bid [`Order<Open>`]s.
-    pub fn match_bids(&mut self, trade: &PublicTrade, fees_percent: f64) -> Vec<Trade> {
+    pub fn match_bids(&mut self, trade: &PublicTrade, fees_percent: Decimal) -> Vec<Trade> {
         // Keep track of how much trade liquidity is remaining to match with
         let mut remaining_liquidity = trade.amount;
 
@@ -153,7 +195,7 @@ impl Orders {
             };
 
             // Break with remaining best bid if it's not a match, or trade liquidity is exhausted
-            if best_bid.state.price < trade.price || remaining_liquidity <= 0.0 {
+            if best_bid.state.price < trade.price || remaining_liquidity <= Decimal::ZERO {
                 break Some(best_bid);
             }
 
@@ -170,7 +212,7 @@ impl Orders {
                     trades.push(self.generate_trade(best_bid, trade_quantity, fees_percent));
 
                     // If exact full fill with zero remaining liquidity (highly unlikely), break
-                    if remaining_liquidity == 0.0 {
+                    if remaining_liquidity.is_zero() {
                         break None;
                     }
                 }
@@ -205,8 +247,8 @@ impl Orders {
     pub fn generate_trade(
         &self,
         order: Order<Open>,
-        trade_quantity: f64,
-        fees_percent: f64,
+        trade_quantity: Decimal,
+        fees_percent: Decimal,
     ) -> Trade {
         // Calculate the trade fees (denominated in base or quote depending on Order Side)
         let fees = calculate_fees(&order, trade_quantity, fees_percent);
@@ -231,7 +273,7 @@ impl Orders {
 
     /// Simulates [`Side::Sell`] trades by using the [`PublicTrade`] liquidity to match on open
     /// client bid [`Order<Open>`]s.
-    pub fn match_asks(&mut self, trade: &PublicTrade, fees_percent: f64) -> Vec<Trade> {
+    pub fn match_asks(&mut self, trade: &PublicTrade, fees_percent: Decimal) -> Vec<Trade> {
         // Keep track of how much trade liquidity is remaining to match with
         let mut remaining_liquidity = trade.amount;
 
@@ -246,7 +288,7 @@ impl Orders {
             };
 
             // Break with remaining best ask if it's not a match, or trade liquidity is exhausted
-            if best_ask.state.price > trade.price || remaining_liquidity <= 0.0 {
+            if best_ask.state.price > trade.price || remaining_liquidity <= Decimal::ZERO {
                 break Some(best_ask);
             }
 
@@ -263,7 +305,7 @@ impl Orders {
                     trades.push(self.generate_trade(best_ask, trade_quantity, fees_percent));
 
                     // If exact full fill with zero remaining liquidity (highly unlikely), break
-                    if remaining_liquidity == 0.0 {
+                    if remaining_liquidity.is_zero() {
                         break None;
                     }
                 }
@@ -300,6 +342,98 @@ impl Orders {
     }
 }
 
+/// Conditional client [`Order<RequestOpen>`] resting un-triggered until the [`PublicTrade`] price
+/// reaches it's trigger. See [`OrderKind`] for the trigger semantics of each conditional kind.
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct PendingOrder {
+    pub id: OrderId,
+    pub request: Order<RequestOpen>,
+    /// Best price seen since an [`OrderKind::TrailingStop`] was activated (highest for
+    /// [`Side::Sell`], lowest for [`Side::Buy`]).
+    pub extreme_price: Option<Decimal>,
+}
+
+impl PendingOrder {
+    /// Update the trailing state with the latest [`PublicTrade`] price, and determine if this
+    /// [`PendingOrder`] is triggered.
+    pub fn update(&mut self, price: Decimal) -> bool {
+        match (self.request.state.kind, self.request.side) {
+            (
+                OrderKind::StopMarket { trigger_price } | OrderKind::StopLimit { trigger_price },
+                side,
+            ) => match side {
+                Side::Buy => price >= trigger_price,
+                Side::Sell => price <= trigger_price,
+            },
+            (
+                OrderKind::TakeProfitMarket { trigger_price }
+                | OrderKind::TakeProfitLimit { trigger_price },
+                side,
+            ) => match side {
+                Side::Buy => price <= trigger_price,
+                Side::Sell => price >= trigger_price,
+            },
+            (
+                OrderKind::TrailingStop {
+                    trailing_delta,
+                    activation_price,
+                },
+                side,
+            ) => {
+                // Trailing starts once the activation price is reached, or immediately if None
+                let activated = self.extreme_price.is_some()
+                    || match (side, activation_price) {
+                        (_, None) => true,
+                        (Side::Buy, Some(activation_price)) => price <= activation_price,
+                        (Side::Sell, Some(activation_price)) => price >= activation_price,
+                    };
+                if !activated {
+                    return false;
+                }
+
+                match side {
+                    Side::Buy => {
+                        let lowest = self.extreme_price.map_or(price, |low| low.min(price));
+                        self.extreme_price = Some(lowest);
+                        price >= lowest * (Decimal::ONE + trailing_delta)
+                    }
+                    Side::Sell => {
+                        let highest = self.extreme_price.map_or(price, |high| high.max(price));
+                        self.extreme_price = Some(highest);
+                        price <= highest * (Decimal::ONE - trailing_delta)
+                    }
+                }
+            }
+            // Non-conditional OrderKinds are always active
+            _ => true,
+        }
+    }
+
+    /// Convert this triggered [`PendingOrder`] into the [`OrderKind::Limit`]
+    /// [`Order<RequestOpen>`] it activates. Kinds without a limit price execute at the triggering
+    /// [`PublicTrade`] price.
+    pub fn triggered(self, price: Decimal) -> Order<RequestOpen> {
+        let price = match self.request.state.kind.has_limit_price() {
+            true => self.request.state.price,
+            false => price,
+        };
+
+        Order {
+            state: RequestOpen {
+                kind: OrderKind::Limit,
+                price,
+                ..self.request.state
+            },
+            ..self.request
+        }
+    }
+
+    /// Represent this un-triggered [`PendingOrder`] as an [`Order<Open>`].
+    pub fn order_open(&self) -> Order<Open> {
+        Order::from((self.id.clone(), self.request.clone()))
+    }
+}
+
 /// Communicates if an [`Order<Open>`] liquidity match is a full or partial fill. Partial fills
 /// leave the order still open with some proportion of the initial quantity still active.
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug, Deserialize, Serialize)]
@@ -310,7 +444,7 @@ pub enum OrderFill {
 
 impl OrderFill {
     /// Determine the [`OrderFill`] kind given the [`Order<Open>`] and the available liquidity.
-    pub fn kind(order: &Order<Open>, liquidity: f64) -> Self {
+    pub fn kind(order: &Order<Open>, liquidity: Decimal) -> Self {
         match order.state.remaining_quantity() <= liquidity {
             true => Self::Full,
             false => Self::Partial,
@@ -319,7 +453,11 @@ impl OrderFill {
 }
 
 /// Calculate the [`SymbolFees`] of a [`Order<Open>`] match (trade).
-pub fn calculate_fees(order: &Order<Open>, trade_quantity: f64, fees_percent: f64) -> SymbolFees {
+pub fn calculate_fees(
+    order: &Order<Open>,
+    trade_quantity: Decimal,
+    fees_percent: Decimal,
+) -> SymbolFees {
     match order.side {
         Side::Buy => SymbolFees::new(order.instrument.base.clone(), fees_percent * trade_quantity),
         Side::Sell => SymbolFees::new(
@@ -335,9 +473,10 @@ mod tests {
     use crate::{
         model::ClientOrderId,
         simulated::exchange::account::order::Orders,
-        test_util::{client_orders, order_open, public_trade, trade},
+        test_util::{client_orders, order_open, order_request, public_trade, trade},
     };
     use barter_integration::model::Side;
+    use rust_decimal_macros::dec;
     use uuid::Uuid;
 
     #[test]
@@ -354,13 +493,23 @@ mod tests {
             TestCase {
                 // TC0: No matching bids or asks since no open orders
                 orders: client_orders(0, vec![], vec![]),
-                input_trade: public_trade(Side::Buy, 100.0, 1.0),
+                input_trade: public_trade(Side::Buy, dec!(100.0), dec!(1.0)),
                 expected: None,
             },
             TestCase {
                 // TC1: No matching bid for trade with no asks open
-                orders: client_orders(0, vec![order_open(cid, Side::Buy, 100.0, 1.0, 0.0)], vec![]),
-                input_trade: public_trade(Side::Buy, 150.0, 1.0),
+                orders: client_orders(
+                    0,
+                    vec![order_open(
+                        cid,
+                        Side::Buy,
+                        dec!(100.0),
+                        dec!(1.0),
+                        dec!(0.0),
+                    )],
+                    vec![],
+                ),
+                input_trade: public_trade(Side::Buy, dec!(150.0), dec!(1.0)),
                 expected: None,
             },
             TestCase {
@@ -368,15 +517,31 @@ mod tests {
                 orders: client_orders(
                     0,
                     vec![],
-                    vec![order_open(cid, Side::Sell, 100.0, 1.0, 0.0)],
+                    vec![order_open(
+                        cid,
+                        Side::Sell,
+                        dec!(100.0),
+                        dec!(1.0),
+                        dec!(0.0),
+                    )],
                 ),
-                input_trade: public_trade(Side::Sell, 50.0, 1.0),
+                input_trade: public_trade(Side::Sell, dec!(50.0), dec!(1.0)),
                 expected: None,
             },
             TestCase {
                 // TC3: Exact matching bid for trade with no asks open
-                orders: client_orders(0, vec![order_open(cid, Side::Buy, 100.0, 1.0, 0.0)], vec![]),
-                input_trade: public_trade(Side::Buy, 100.0, 1.0),
+                orders: client_orders(
+                    0,
+                    vec![order_open(
+                        cid,
+                        Side::Buy,
+                        dec!(100.0),
+                        dec!(1.0),
+                        dec!(0.0),
+                    )],
+                    vec![],
+                ),
+                input_trade: public_trade(Side::Buy, dec!(100.0), dec!(1.0)),
                 expected: Some(Side::Buy),
             },
             TestCase {
@@ -384,39 +549,75 @@ mod tests {
                 orders: client_orders(
                     0,
                     vec![],
-                    vec![order_open(cid, Side::Sell, 100.0, 1.0, 0.0)],
+                    vec![order_open(
+                        cid,
+                        Side::Sell,
+                        dec!(100.0),
+                        dec!(1.0),
+                        dec!(0.0),
+                    )],
                 ),
-                input_trade: public_trade(Side::Sell, 100.0, 1.0),
+                input_trade: public_trade(Side::Sell, dec!(100.0), dec!(1.0)),
                 expected: Some(Side::Sell),
             },
             TestCase {
                 // TC5: No matches for trade with open bids and asks
                 orders: client_orders(
                     0,
-                    vec![order_open(cid, Side::Buy, 50.0, 1.0, 0.0)],
-                    vec![order_open(cid, Side::Sell, 150.0, 1.0, 0.0)],
+                    vec![order_open(cid, Side::Buy, dec!(50.0), dec!(1.0), dec!(0.0))],
+                    vec![order_open(
+                        cid,
+                        Side::Sell,
+                        dec!(150.0),
+                        dec!(1.0),
+                        dec!(0.0),
+                    )],
                 ),
-                input_trade: public_trade(Side::Buy, 100.0, 1.0),
+                input_trade: public_trade(Side::Buy, dec!(100.0), dec!(1.0)),
                 expected: None,
             },
             TestCase {
                 // TC6: Trade matches bid & ask (same price), so take larger quantity bid
                 orders: client_orders(
                     0,
-                    vec![order_open(cid, Side::Buy, 100.0, 100.0, 0.0)],
-                    vec![order_open(cid, Side::Sell, 100.0, 1.0, 0.0)],
+                    vec![order_open(
+                        cid,
+                        Side::Buy,
+                        dec!(100.0),
+                        dec!(100.0),
+                        dec!(0.0),
+                    )],
+                    vec![order_open(
+                        cid,
+                        Side::Sell,
+                        dec!(100.0),
+                        dec!(1.0),
+                        dec!(0.0),
+                    )],
                 ),
-                input_trade: public_trade(Side::Buy, 100.0, 1.0),
+                input_trade: public_trade(Side::Buy, dec!(100.0), dec!(1.0)),
                 expected: Some(Side::Buy),
             },
             TestCase {
                 // TC6: Trade matches bid & ask (same price), so take larger quantity ask
                 orders: client_orders(
                     0,
-                    vec![order_open(cid, Side::Buy, 100.0, 1.0, 0.0)],
-                    vec![order_open(cid, Side::Sell, 100.0, 100.0, 0.0)],
+                    vec![order_open(
+                        cid,
+                        Side::Buy,
+                        dec!(100.0),
+                        dec!(1.0),
+                        dec!(0.0),
+                    )],
+                    vec![order_open(
+                        cid,
+                        Side::Sell,
+                        dec!(100.0),
+                        dec!(100.0),
+                        dec!(0.0),
+                    )],
                 ),
-                input_trade: public_trade(Side::Buy, 100.0, 1.0),
+                input_trade: public_trade(Side::Buy, dec!(100.0), dec!(1.0)),
                 expected: Some(Side::Sell),
             },
         ];
@@ -432,7 +633,7 @@ mod tests {
         struct TestCase {
             orders: Orders,
             input_trade: PublicTrade,
-            input_fees_percent: f64,
+            input_fees_percent: Decimal,
             expected_orders: Orders,
             expected_trades: Vec<Trade>,
         }
@@ -445,24 +646,30 @@ mod tests {
                 orders: client_orders(
                     0,
                     vec![
-                        order_open(cid, Side::Buy, 100.0, 1.0, 0.0),
-                        order_open(cid, Side::Buy, 200.0, 1.0, 0.0),
+                        order_open(cid, Side::Buy, dec!(100.0), dec!(1.0), dec!(0.0)),
+                        order_open(cid, Side::Buy, dec!(200.0), dec!(1.0), dec!(0.0)),
                     ],
                     vec![],
                 ),
-                input_trade: public_trade(Side::Buy, 200.0, 1.0),
-                input_fees_percent: 0.1,
+                input_trade: public_trade(Side::Buy, dec!(200.0), dec!(1.0)),
+                input_fees_percent: dec!(0.1),
                 expected_orders: client_orders(
                     1,
-                    vec![order_open(cid, Side::Buy, 100.0, 1.0, 0.0)],
+                    vec![order_open(
+                        cid,
+                        Side::Buy,
+                        dec!(100.0),
+                        dec!(1.0),
+                        dec!(0.0),
+                    )],
                     vec![],
                 ),
                 expected_trades: vec![trade(
                     TradeId(1.to_string()),
                     Side::Buy,
-                    200.0,
-                    1.0,
-                    SymbolFees::new("base", 0.1 * 1.0),
+                    dec!(200.0),
+                    dec!(1.0),
+                    SymbolFees::new("base", dec!(0.1) * dec!(1.0)),
                 )],
             },
             TestCase {
@@ -470,28 +677,28 @@ mod tests {
                 orders: client_orders(
                     0,
                     vec![
-                        order_open(cid, Side::Buy, 100.0, 1.0, 0.0),
-                        order_open(cid, Side::Buy, 200.0, 1.0, 0.0),
+                        order_open(cid, Side::Buy, dec!(100.0), dec!(1.0), dec!(0.0)),
+                        order_open(cid, Side::Buy, dec!(200.0), dec!(1.0), dec!(0.0)),
                     ],
                     vec![],
                 ),
-                input_trade: public_trade(Side::Buy, 100.0, 2.0),
-                input_fees_percent: 0.1,
+                input_trade: public_trade(Side::Buy, dec!(100.0), dec!(2.0)),
+                input_fees_percent: dec!(0.1),
                 expected_orders: client_orders(2, vec![], vec![]),
                 expected_trades: vec![
                     trade(
                         TradeId(1.to_string()),
                         Side::Buy,
-                        200.0,
-                        1.0,
-                        SymbolFees::new("base", 0.1 * 1.0),
+                        dec!(200.0),
+                        dec!(1.0),
+                        SymbolFees::new("base", dec!(0.1) * dec!(1.0)),
                     ),
                     trade(
                         TradeId(2.to_string()),
                         Side::Buy,
-                        100.0,
-                        1.0,
-                        SymbolFees::new("base", 0.1 * 1.0),
+                        dec!(100.0),
+                        dec!(1.0),
+                        SymbolFees::new("base", dec!(0.1) * dec!(1.0)),
                     ),
                 ],
             },
@@ -500,32 +707,38 @@ mod tests {
                 orders: client_orders(
                     0,
                     vec![
-                        order_open(cid, Side::Buy, 100.0, 1.0, 0.0),
-                        order_open(cid, Side::Buy, 200.0, 1.0, 0.0),
+                        order_open(cid, Side::Buy, dec!(100.0), dec!(1.0), dec!(0.0)),
+                        order_open(cid, Side::Buy, dec!(200.0), dec!(1.0), dec!(0.0)),
                     ],
                     vec![],
                 ),
-                input_trade: public_trade(Side::Sell, 100.0, 1.5),
-                input_fees_percent: 0.1,
+                input_trade: public_trade(Side::Sell, dec!(100.0), dec!(1.5)),
+                input_fees_percent: dec!(0.1),
                 expected_orders: client_orders(
                     2,
-                    vec![order_open(cid, Side::Buy, 100.0, 1.0, 0.5)],
+                    vec![order_open(
+                        cid,
+                        Side::Buy,
+                        dec!(100.0),
+                        dec!(1.0),
+                        dec!(0.5),
+                    )],
                     vec![],
                 ),
                 expected_trades: vec![
                     trade(
                         TradeId(1.to_string()),
                         Side::Buy,
-                        200.0,
-                        1.0,
-                        SymbolFees::new("base", 0.1 * 1.0),
+                        dec!(200.0),
+                        dec!(1.0),
+                        SymbolFees::new("base", dec!(0.1) * dec!(1.0)),
                     ),
                     trade(
                         TradeId(2.to_string()),
                         Side::Buy,
-                        100.0,
-                        0.5,
-                        SymbolFees::new("base", 0.1 * 0.5),
+                        dec!(100.0),
+                        dec!(0.5),
+                        SymbolFees::new("base", dec!(0.1) * dec!(0.5)),
                     ),
                 ],
             },
@@ -534,18 +747,18 @@ mod tests {
                 orders: client_orders(
                     0,
                     vec![
-                        order_open(cid, Side::Buy, 100.0, 1.0, 0.0),
-                        order_open(cid, Side::Buy, 200.0, 1.0, 0.0),
+                        order_open(cid, Side::Buy, dec!(100.0), dec!(1.0), dec!(0.0)),
+                        order_open(cid, Side::Buy, dec!(200.0), dec!(1.0), dec!(0.0)),
                     ],
                     vec![],
                 ),
-                input_trade: public_trade(Side::Sell, 1_000_000_000.0, 1.0),
-                input_fees_percent: 0.1,
+                input_trade: public_trade(Side::Sell, dec!(1_000_000_000.0), dec!(1.0)),
+                input_fees_percent: dec!(0.1),
                 expected_orders: client_orders(
                     0,
                     vec![
-                        order_open(cid, Side::Buy, 100.0, 1.0, 0.0),
-                        order_open(cid, Side::Buy, 200.0, 1.0, 0.0),
+                        order_open(cid, Side::Buy, dec!(100.0), dec!(1.0), dec!(0.0)),
+                        order_open(cid, Side::Buy, dec!(200.0), dec!(1.0), dec!(0.0)),
                     ],
                     vec![],
                 ),
@@ -569,7 +782,7 @@ mod tests {
         struct TestCase {
             orders: Orders,
             input_trade: PublicTrade,
-            input_fees_percent: f64,
+            input_fees_percent: Decimal,
             expected_orders: Orders,
             expected_trades: Vec<Trade>,
         }
@@ -583,23 +796,29 @@ mod tests {
                     0,
                     vec![],
                     vec![
-                        order_open(cid, Side::Sell, 200.0, 1.0, 0.0),
-                        order_open(cid, Side::Sell, 100.0, 1.0, 0.0),
+                        order_open(cid, Side::Sell, dec!(200.0), dec!(1.0), dec!(0.0)),
+                        order_open(cid, Side::Sell, dec!(100.0), dec!(1.0), dec!(0.0)),
                     ],
                 ),
-                input_trade: public_trade(Side::Buy, 100.0, 1.0),
-                input_fees_percent: 0.1,
+                input_trade: public_trade(Side::Buy, dec!(100.0), dec!(1.0)),
+                input_fees_percent: dec!(0.1),
                 expected_orders: client_orders(
                     1,
                     vec![],
-                    vec![order_open(cid, Side::Sell, 200.0, 1.0, 0.0)],
+                    vec![order_open(
+                        cid,
+                        Side::Sell,
+                        dec!(200.0),
+                        dec!(1.0),
+                        dec!(0.0),
+                    )],
                 ),
                 expected_trades: vec![trade(
                     TradeId(1.to_string()),
                     Side::Sell,
-                    100.0,
-                    1.0,
-                    SymbolFees::new("quote", 0.1 * 100.0 * 1.0),
+                    dec!(100.0),
+                    dec!(1.0),
+                    SymbolFees::new("quote", dec!(0.1) * dec!(100.0) * dec!(1.0)),
                 )],
             },
             TestCase {
@@ -608,27 +827,27 @@ mod tests {
                     0,
                     vec![],
                     vec![
-                        order_open(cid, Side::Sell, 200.0, 1.0, 0.0),
-                        order_open(cid, Side::Sell, 100.0, 1.0, 0.0),
+                        order_open(cid, Side::Sell, dec!(200.0), dec!(1.0), dec!(0.0)),
+                        order_open(cid, Side::Sell, dec!(100.0), dec!(1.0), dec!(0.0)),
                     ],
                 ),
-                input_trade: public_trade(Side::Buy, 200.0, 2.0),
-                input_fees_percent: 0.1,
+                input_trade: public_trade(Side::Buy, dec!(200.0), dec!(2.0)),
+                input_fees_percent: dec!(0.1),
                 expected_orders: client_orders(2, vec![], vec![]),
                 expected_trades: vec![
                     trade(
                         TradeId(1.to_string()),
                         Side::Sell,
-                        100.0,
-                        1.0,
-                        SymbolFees::new("quote", 0.1 * 100.0 * 1.0),
+                        dec!(100.0),
+                        dec!(1.0),
+                        SymbolFees::new("quote", dec!(0.1) * dec!(100.0) * dec!(1.0)),
                     ),
                     trade(
                         TradeId(2.to_string()),
                         Side::Sell,
-                        200.0,
-                        1.0,
-                        SymbolFees::new("quote", 0.1 * 200.0 * 1.0),
+                        dec!(200.0),
+                        dec!(1.0),
+                        SymbolFees::new("quote", dec!(0.1) * dec!(200.0) * dec!(1.0)),
                     ),
                 ],
             },
@@ -638,31 +857,37 @@ mod tests {
                     0,
                     vec![],
                     vec![
-                        order_open(cid, Side::Sell, 200.0, 1.0, 0.0),
-                        order_open(cid, Side::Sell, 100.0, 1.0, 0.0),
+                        order_open(cid, Side::Sell, dec!(200.0), dec!(1.0), dec!(0.0)),
+                        order_open(cid, Side::Sell, dec!(100.0), dec!(1.0), dec!(0.0)),
                     ],
                 ),
-                input_trade: public_trade(Side::Sell, 200.0, 1.5),
-                input_fees_percent: 0.1,
+                input_trade: public_trade(Side::Sell, dec!(200.0), dec!(1.5)),
+                input_fees_percent: dec!(0.1),
                 expected_orders: client_orders(
                     2,
                     vec![],
-                    vec![order_open(cid, Side::Sell, 200.0, 1.0, 0.5)],
+                    vec![order_open(
+                        cid,
+                        Side::Sell,
+                        dec!(200.0),
+                        dec!(1.0),
+                        dec!(0.5),
+                    )],
                 ),
                 expected_trades: vec![
                     trade(
                         TradeId(1.to_string()),
                         Side::Sell,
-                        100.0,
-                        1.0,
-                        SymbolFees::new("quote", 0.1 * 100.0 * 1.0),
+                        dec!(100.0),
+                        dec!(1.0),
+                        SymbolFees::new("quote", dec!(0.1) * dec!(100.0) * dec!(1.0)),
                     ),
                     trade(
                         TradeId(2.to_string()),
                         Side::Sell,
-                        200.0,
-                        0.5,
-                        SymbolFees::new("quote", 0.1 * 200.0 * 0.5),
+                        dec!(200.0),
+                        dec!(0.5),
+                        SymbolFees::new("quote", dec!(0.1) * dec!(200.0) * dec!(0.5)),
                     ),
                 ],
             },
@@ -672,18 +897,18 @@ mod tests {
                     0,
                     vec![],
                     vec![
-                        order_open(cid, Side::Sell, 200.0, 1.0, 0.0),
-                        order_open(cid, Side::Sell, 100.0, 1.0, 0.0),
+                        order_open(cid, Side::Sell, dec!(200.0), dec!(1.0), dec!(0.0)),
+                        order_open(cid, Side::Sell, dec!(100.0), dec!(1.0), dec!(0.0)),
                     ],
                 ),
-                input_trade: public_trade(Side::Sell, 1.0, 1.0),
-                input_fees_percent: 0.1,
+                input_trade: public_trade(Side::Sell, dec!(1.0), dec!(1.0)),
+                input_fees_percent: dec!(0.1),
                 expected_orders: client_orders(
                     0,
                     vec![],
                     vec![
-                        order_open(cid, Side::Sell, 200.0, 1.0, 0.0),
-                        order_open(cid, Side::Sell, 100.0, 1.0, 0.0),
+                        order_open(cid, Side::Sell, dec!(200.0), dec!(1.0), dec!(0.0)),
+                        order_open(cid, Side::Sell, dec!(100.0), dec!(1.0), dec!(0.0)),
                     ],
                 ),
                 expected_trades: vec![],
@@ -718,7 +943,17 @@ mod tests {
             },
             TestCase {
                 // TC1: one bid, empty ask
-                orders: client_orders(0, vec![order_open(cid, Side::Buy, 150.0, 1.0, 0.0)], vec![]),
+                orders: client_orders(
+                    0,
+                    vec![order_open(
+                        cid,
+                        Side::Buy,
+                        dec!(150.0),
+                        dec!(1.0),
+                        dec!(0.0),
+                    )],
+                    vec![],
+                ),
                 expected_num: 1,
             },
             TestCase {
@@ -726,7 +961,13 @@ mod tests {
                 orders: client_orders(
                     0,
                     vec![],
-                    vec![order_open(cid, Side::Sell, 150.0, 1.0, 0.0)],
+                    vec![order_open(
+                        cid,
+                        Side::Sell,
+                        dec!(150.0),
+                        dec!(1.0),
+                        dec!(0.0),
+                    )],
                 ),
                 expected_num: 1,
             },
@@ -735,12 +976,12 @@ mod tests {
                 orders: client_orders(
                     0,
                     vec![
-                        order_open(cid, Side::Sell, 150.0, 1.0, 0.0),
-                        order_open(cid, Side::Sell, 150.0, 1.0, 0.0),
+                        order_open(cid, Side::Sell, dec!(150.0), dec!(1.0), dec!(0.0)),
+                        order_open(cid, Side::Sell, dec!(150.0), dec!(1.0), dec!(0.0)),
                     ],
                     vec![
-                        order_open(cid, Side::Sell, 150.0, 1.0, 0.0),
-                        order_open(cid, Side::Sell, 150.0, 1.0, 0.0),
+                        order_open(cid, Side::Sell, dec!(150.0), dec!(1.0), dec!(0.0)),
+                        order_open(cid, Side::Sell, dec!(150.0), dec!(1.0), dec!(0.0)),
                     ],
                 ),
                 expected_num: 4,
@@ -757,7 +998,7 @@ mod tests {
     fn test_order_fill_kind() {
         struct TestCase {
             input_order: Order<Open>,
-            input_liquidity: f64,
+            input_liquidity: Decimal,
             expected: OrderFill,
         }
 
@@ -766,26 +1007,26 @@ mod tests {
         let tests = vec![
             TestCase {
                 // TC0: Zero filled bid is fully filled by remaining liquidity
-                input_order: order_open(cid, Side::Buy, 10.0, 10.0, 0.0),
-                input_liquidity: 10.0,
+                input_order: order_open(cid, Side::Buy, dec!(10.0), dec!(10.0), dec!(0.0)),
+                input_liquidity: dec!(10.0),
                 expected: OrderFill::Full,
             },
             TestCase {
                 // TC1: Partially filled bid is fully filled by remaining liquidity
-                input_order: order_open(cid, Side::Buy, 10.0, 10.0, 5.0),
-                input_liquidity: 10.0,
+                input_order: order_open(cid, Side::Buy, dec!(10.0), dec!(10.0), dec!(5.0)),
+                input_liquidity: dec!(10.0),
                 expected: OrderFill::Full,
             },
             TestCase {
                 // TC2: Zero filled bid is partially filled by remaining liquidity
-                input_order: order_open(cid, Side::Buy, 10.0, 10.0, 0.0),
-                input_liquidity: 5.0,
+                input_order: order_open(cid, Side::Buy, dec!(10.0), dec!(10.0), dec!(0.0)),
+                input_liquidity: dec!(5.0),
                 expected: OrderFill::Partial,
             },
             TestCase {
                 // TC3: Partially filled bid is partially filled by remaining liquidity
-                input_order: order_open(cid, Side::Buy, 10.0, 10.0, 1.0),
-                input_liquidity: 5.0,
+                input_order: order_open(cid, Side::Buy, dec!(10.0), dec!(10.0), dec!(1.0)),
+                input_liquidity: dec!(5.0),
                 expected: OrderFill::Partial,
             },
         ];
@@ -800,8 +1041,8 @@ mod tests {
     fn test_calculate_fees() {
         struct TestCase {
             order: Order<Open>,
-            trade_quantity: f64,
-            fees_percent: f64,
+            trade_quantity: Decimal,
+            fees_percent: Decimal,
             expected: SymbolFees,
         }
 
@@ -810,17 +1051,17 @@ mod tests {
         let tests = vec![
             TestCase {
                 // TC0: 10% trade fees from matched Side::Buy order
-                order: order_open(cid, Side::Buy, 100.0, 10.0, 0.0),
-                trade_quantity: 10.0,
-                fees_percent: 0.1,
-                expected: SymbolFees::new("base", 0.1 * 10.0),
+                order: order_open(cid, Side::Buy, dec!(100.0), dec!(10.0), dec!(0.0)),
+                trade_quantity: dec!(10.0),
+                fees_percent: dec!(0.1),
+                expected: SymbolFees::new("base", dec!(0.1) * dec!(10.0)),
             },
             TestCase {
                 // TC1: 50% trade fees from matched Side::Sell order
-                order: order_open(cid, Side::Sell, 100.0, 10.0, 0.0),
-                trade_quantity: 10.0,
-                fees_percent: 0.5,
-                expected: SymbolFees::new("quote", 0.5 * 100.0 * 10.0),
+                order: order_open(cid, Side::Sell, dec!(100.0), dec!(10.0), dec!(0.0)),
+                trade_quantity: dec!(10.0),
+                fees_percent: dec!(0.5),
+                expected: SymbolFees::new("quote", dec!(0.5) * dec!(100.0) * dec!(10.0)),
             },
         ];
 
@@ -829,4 +1070,119 @@ mod tests {
             assert_eq!(actual, test.expected, "TC{} failed", index);
         }
     }
+
+    #[test]
+    fn test_pending_order_update() {
+        struct TestCase {
+            side: Side,
+            kind: OrderKind,
+            prices: Vec<Decimal>,
+            expected: Vec<bool>,
+        }
+
+        let cid = ClientOrderId(Uuid::new_v4());
+
+        let tests = vec![
+            TestCase {
+                // TC0: Side::Sell StopMarket triggers at or below the trigger price
+                side: Side::Sell,
+                kind: OrderKind::StopMarket {
+                    trigger_price: dec!(90),
+                },
+                prices: vec![dec!(100), dec!(91), dec!(90)],
+                expected: vec![false, false, true],
+            },
+            TestCase {
+                // TC1: Side::Buy StopLimit triggers at or above the trigger price
+                side: Side::Buy,
+                kind: OrderKind::StopLimit {
+                    trigger_price: dec!(110),
+                },
+                prices: vec![dec!(100), dec!(109), dec!(111)],
+                expected: vec![false, false, true],
+            },
+            TestCase {
+                // TC2: Side::Sell TakeProfitMarket triggers at or above the trigger price
+                side: Side::Sell,
+                kind: OrderKind::TakeProfitMarket {
+                    trigger_price: dec!(110),
+                },
+                prices: vec![dec!(90), dec!(110)],
+                expected: vec![false, true],
+            },
+            TestCase {
+                // TC3: Side::Buy TakeProfitLimit triggers at or below the trigger price
+                side: Side::Buy,
+                kind: OrderKind::TakeProfitLimit {
+                    trigger_price: dec!(90),
+                },
+                prices: vec![dec!(100), dec!(89)],
+                expected: vec![false, true],
+            },
+            TestCase {
+                // TC4: Side::Sell TrailingStop trails the highest price since activation
+                side: Side::Sell,
+                kind: OrderKind::TrailingStop {
+                    trailing_delta: dec!(0.1),
+                    activation_price: None,
+                },
+                prices: vec![dec!(100), dec!(120), dec!(109), dec!(108)],
+                expected: vec![false, false, false, true],
+            },
+            TestCase {
+                // TC5: Side::Buy TrailingStop only trails once the activation price is reached
+                side: Side::Buy,
+                kind: OrderKind::TrailingStop {
+                    trailing_delta: dec!(0.1),
+                    activation_price: Some(dec!(90)),
+                },
+                prices: vec![dec!(100), dec!(120), dec!(80), dec!(87), dec!(88)],
+                expected: vec![false, false, false, false, true],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut pending = PendingOrder {
+                id: OrderId::from("order_id"),
+                request: order_request(cid, test.side, test.kind, dec!(0), dec!(1)),
+                extreme_price: None,
+            };
+            let actual = test
+                .prices
+                .into_iter()
+                .map(|price| pending.update(price))
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_orders_trigger_pending() {
+        let cid = ClientOrderId(Uuid::new_v4());
+        let stop = |id: &str, trigger_price| PendingOrder {
+            id: OrderId::from(id),
+            request: order_request(
+                cid,
+                Side::Sell,
+                OrderKind::StopLimit { trigger_price },
+                dec!(85),
+                dec!(1),
+            ),
+            extreme_price: None,
+        };
+
+        let mut orders = client_orders(0, vec![], vec![]);
+        orders.add_order_pending(stop("1", dec!(90)));
+        orders.add_order_pending(stop("2", dec!(80)));
+
+        let triggered = orders.trigger_pending(&public_trade(Side::Sell, dec!(88), dec!(1)));
+
+        assert_eq!(triggered, vec![stop("1", dec!(90))]);
+        assert_eq!(orders.pending, vec![stop("2", dec!(80))]);
+
+        // StopLimit activates as a Limit at it's limit price, not the trade price
+        let request = triggered[0].clone().triggered(dec!(88));
+        assert_eq!(request.state.kind, OrderKind::Limit);
+        assert_eq!(request.state.price, dec!(85));
+    }
 }
@@ -31,6 +31,9 @@ impl SimulatedExchange {
                 SimulatedEvent::OpenOrders((open_requests, response_tx)) => {
                     self.account.open_orders(open_requests, response_tx)
                 }
+                SimulatedEvent::AmendOrders((amend_requests, response_tx)) => {
+                    self.account.amend_orders(amend_requests, response_tx)
+                }
                 SimulatedEvent::CancelOrders((cancel_requests, response_tx)) => {
                     self.account.cancel_orders(cancel_requests, response_tx)
                 }
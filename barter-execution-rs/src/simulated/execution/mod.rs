@@ -5,7 +5,8 @@ use crate::{
         order_event::OrderEvent,
     },
     simulated::SimulatedEvent,
-    ExecutionClient, ExecutionError, ExecutionId, RequestCancel, RequestOpen, SymbolBalance,
+    ExecutionClient, ExecutionError, ExecutionId, RequestAmend, RequestCancel, RequestOpen,
+    SymbolBalance,
 };
 use async_trait::async_trait;
 use barter_integration::model::Exchange;
@@ -125,6 +126,24 @@ impl ExecutionClient for SimulatedExecution {
             .expect("SimulatedExchange is offline - failed to receive CancelOrders response")
     }
 
+    async fn amend_orders(
+        &self,
+        amend_requests: Vec<Order<RequestAmend>>,
+    ) -> Vec<Result<Order<Open>, ExecutionError>> {
+        // Oneshot channel to communicate with the SimulatedExchange
+        let (response_tx, response_rx) = oneshot::channel();
+
+        // Send AmendOrders request to the SimulatedExchange
+        self.request_tx
+            .send(SimulatedEvent::AmendOrders((amend_requests, response_tx)))
+            .expect("SimulatedExchange is offline - failed to send AmendOrders request");
+
+        // Receive AmendOrders response from the SimulatedExchange
+        response_rx
+            .await
+            .expect("SimulatedExchange is offline - failed to receive AmendOrders response")
+    }
+
     async fn cancel_orders_all(&self) -> Result<Vec<Order<Cancelled>>, ExecutionError> {
         // Oneshot channel to communicate with the SimulatedExchange
         let (response_tx, response_rx) = oneshot::channel();
@@ -1,4 +1,6 @@
-use crate::{Cancelled, ExecutionError, Open, Order, RequestCancel, RequestOpen, SymbolBalance};
+use crate::{
+    Cancelled, ExecutionError, Open, Order, RequestAmend, RequestCancel, RequestOpen, SymbolBalance,
+};
 use barter_data::subscription::trade::PublicTrade;
 use barter_integration::model::instrument::Instrument;
 use tokio::sync::oneshot;
@@ -30,6 +32,12 @@ pub enum SimulatedEvent {
             oneshot::Sender<Vec<Result<Order<Open>, ExecutionError>>>,
         ),
     ),
+    AmendOrders(
+        (
+            Vec<Order<RequestAmend>>,
+            oneshot::Sender<Vec<Result<Order<Open>, ExecutionError>>>,
+        ),
+    ),
     CancelOrders(
         (
             Vec<Order<RequestCancel>>,
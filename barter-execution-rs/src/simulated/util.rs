@@ -1,6 +1,7 @@
 use std::{collections::HashMap, time::Duration};
 
 use barter_integration::model::instrument::{kind::InstrumentKind, symbol::Symbol, Instrument};
+use rust_decimal::Decimal;
 use tokio::sync::mpsc;
 
 use crate::model::{balance::Balance, AccountEvent};
@@ -45,8 +46,14 @@ pub async fn run_default_exchange(
 // Initial SimulatedExchange ClientAccount balances for each Symbol
 pub fn initial_balances() -> ClientBalances {
     ClientBalances(HashMap::from([
-        (Symbol::from("btc"), Balance::new(10.0, 10.0)),
-        (Symbol::from("usdt"), Balance::new(10_000.0, 10_000.0)),
+        (
+            Symbol::from("btc"),
+            Balance::new(Decimal::TEN, Decimal::TEN),
+        ),
+        (
+            Symbol::from("usdt"),
+            Balance::new(Decimal::from(10_000), Decimal::from(10_000)),
+        ),
     ]))
 }
 
@@ -59,6 +66,6 @@ pub fn latency_50ms() -> Duration {
     Duration::from_millis(50)
 }
 
-pub fn fees_50_percent() -> f64 {
-    0.5
+pub fn fees_50_percent() -> Decimal {
+    Decimal::new(5, 1)
 }
@@ -1,4 +1,7 @@
-use crate::util::{open_order, order_cancel_request, order_cancelled, order_request_limit};
+use crate::util::{
+    open_order, order_amend_request, order_cancel_request, order_cancelled,
+    order_request_conditional, order_request_limit,
+};
 use barter_data::subscription::trade::PublicTrade;
 use barter_execution::simulated::util::{
     fees_50_percent, initial_balances, latency_50ms, run_default_exchange,
@@ -8,7 +11,7 @@ use barter_execution::{
     fill::Fees,
     model::{
         balance::{Balance, SymbolBalance},
-        order::OrderId,
+        order::{OrderId, OrderKind},
         trade::{SymbolFees, Trade, TradeId},
         AccountEvent, AccountEventKind, ClientOrderId,
     },
@@ -19,6 +22,7 @@ use barter_integration::model::{
     instrument::{kind::InstrumentKind, symbol::Symbol, Instrument},
     Side,
 };
+use rust_decimal_macros::dec;
 use tokio::sync::mpsc;
 use uuid::Uuid;
 
@@ -142,6 +146,50 @@ async fn main() {
 
     // 14. Fail to cancel limit order with OrderNotFound using incorrect OrderId
     test_14_fail_to_cancel_limit_with_order_not_found(&client).await;
+
+    // 15. Open STOP_MARKET Sell Order, send MarketEvents that do not & do trigger it, and check
+    //     the triggered order reserves balance and is matched at the trigger trade price
+    let test_15_ids = Ids::new(Uuid::new_v4(), 7);
+    test_15_open_stop_market_sell_and_trigger(
+        &client,
+        test_15_ids,
+        &mut event_simulated_tx,
+        &mut event_account_rx,
+    )
+    .await;
+
+    // 16. Open TAKE_PROFIT_MARKET Sell Order and cancel it before it triggers. Check no balance
+    //     AccountEvent is sent since no balance was reserved.
+    let test_16_ids = Ids::new(Uuid::new_v4(), 8);
+    test_16_cancel_untriggered_take_profit(&client, test_16_ids, &mut event_account_rx).await;
+
+    // 17. Open LIMIT Buy Order and amend it's price & quantity. Check AccountEvents for the
+    //     amended order and the change in reserved balance are sent, and the OrderId is kept.
+    let test_17_ids = Ids::new(Uuid::new_v4(), 9);
+    test_17_amend_limit_buy_order(&client, test_17_ids.clone(), &mut event_account_rx).await;
+
+    // 18. Cancel-replace the amended LIMIT Buy Order. Check the replacement keeps the
+    //     ClientOrderId, but is assigned a new OrderId.
+    let test_18_ids = Ids::new(Uuid::nil(), 10);
+    test_18_cancel_replace_limit_buy_order(
+        &client,
+        test_17_ids.clone(),
+        test_18_ids.clone(),
+        &mut event_account_rx,
+    )
+    .await;
+
+    // 19. Send MarketEvent that partially matches the replaced LIMIT Buy Order, then
+    //     cancel-replace it. Check only the remaining quantity is re-opened, and the returned
+    //     Order<Open> reports the total quantity including the earlier fill.
+    test_19_cancel_replace_partially_filled_limit_buy_order(
+        &client,
+        Ids::new(test_17_ids.cid.0, test_18_ids.id),
+        Ids::new(test_17_ids.cid.0, 11),
+        &mut event_simulated_tx,
+        &mut event_account_rx,
+    )
+    .await;
 }
 
 // 1. Fetch initial OpenOrders when we have no open Orders.
@@ -174,8 +222,8 @@ async fn test_3_open_limit_buy_order(
             Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
             test_3_ids.cid,
             Side::Buy,
-            100.0,
-            1.0,
+            dec!(100.0),
+            dec!(1.0),
         )])
         .await;
 
@@ -184,9 +232,9 @@ async fn test_3_open_limit_buy_order(
         test_3_ids.cid,
         test_3_ids.id,
         Side::Buy,
-        100.0,
-        1.0,
-        0.0,
+        dec!(100.0),
+        dec!(1.0),
+        dec!(0.0),
     );
 
     assert_eq!(new_orders.len(), 1);
@@ -199,7 +247,7 @@ async fn test_3_open_limit_buy_order(
             ..
         }) => {
             // Expected usdt Balance.available = 10_000 - (100.0 * 1.0)
-            let expected = SymbolBalance::new("usdt", Balance::new(10_000.0, 9_900.0));
+            let expected = SymbolBalance::new("usdt", Balance::new(dec!(10_000.0), dec!(9_900.0)));
             assert_eq!(usdt_balance, expected);
         }
         other => {
@@ -241,8 +289,8 @@ fn test_4_send_market_event_that_does_not_match_any_open_order(
             PublicTrade {
                 id: "test_4".to_string(),
                 side: Side::Sell,
-                price: 1000.0,
-                amount: 1.0,
+                price: dec!(1000.0),
+                amount: dec!(1.0),
             },
         )))
         .unwrap();
@@ -302,7 +350,7 @@ async fn test_5_cancel_buy_order(
             ..
         }) => {
             // Expected usdt Balance.available = 9_900 + (100.0 * 1.0)
-            let expected = SymbolBalance::new("usdt", Balance::new(10_000.0, 10_000.0));
+            let expected = SymbolBalance::new("usdt", Balance::new(dec!(10_000.0), dec!(10_000.0)));
             assert_eq!(usdt_balance, expected);
         }
         other => {
@@ -332,15 +380,15 @@ async fn test_6_open_2x_limit_buy_orders(
                 Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
                 test_6_ids_1.cid,
                 Side::Buy,
-                100.0,
-                1.0,
+                dec!(100.0),
+                dec!(1.0),
             ),
             order_request_limit(
                 Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
                 test_6_ids_2.cid,
                 Side::Buy,
-                200.0,
-                1.0,
+                dec!(200.0),
+                dec!(1.0),
             ),
         ])
         .await;
@@ -350,9 +398,9 @@ async fn test_6_open_2x_limit_buy_orders(
         test_6_ids_1.cid,
         test_6_ids_1.id.clone(),
         Side::Buy,
-        100.0,
-        1.0,
-        0.0,
+        dec!(100.0),
+        dec!(1.0),
+        dec!(0.0),
     );
 
     let expected_order_new_2 = open_order(
@@ -360,9 +408,9 @@ async fn test_6_open_2x_limit_buy_orders(
         test_6_ids_2.cid,
         test_6_ids_2.id,
         Side::Buy,
-        200.0,
-        1.0,
-        0.0,
+        dec!(200.0),
+        dec!(1.0),
+        dec!(0.0),
     );
 
     assert_eq!(opened_orders.len(), 2);
@@ -382,7 +430,7 @@ async fn test_6_open_2x_limit_buy_orders(
             ..
         }) => {
             // Expected usdt Balance.available = 10_000 - (100.0 * 1.0)
-            let expected = SymbolBalance::new("usdt", Balance::new(10_000.0, 9_900.0));
+            let expected = SymbolBalance::new("usdt", Balance::new(dec!(10_000.0), dec!(9_900.0)));
             assert_eq!(usdt_balance, expected);
         }
         other => {
@@ -411,7 +459,7 @@ async fn test_6_open_2x_limit_buy_orders(
             ..
         }) => {
             // Expected usdt Balance.available = 9_900 - (200.0 * 1.0)
-            let expected = SymbolBalance::new("usdt", Balance::new(10_000.0, 9_700.0));
+            let expected = SymbolBalance::new("usdt", Balance::new(dec!(10_000.0), dec!(9_700.0)));
             assert_eq!(usdt_balance, expected);
         }
         other => {
@@ -455,8 +503,8 @@ async fn test_7_send_market_event_that_exact_full_matches_order(
             PublicTrade {
                 id: "test_7".to_string(),
                 side: Side::Sell,
-                price: 200.0,
-                amount: 1.0,
+                price: dec!(200.0),
+                amount: dec!(1.0),
             },
         )))
         .unwrap();
@@ -473,15 +521,19 @@ async fn test_7_send_market_event_that_exact_full_matches_order(
             assert_eq!(balances.len(), 2);
 
             // Base Balance first: expected btc { total: 10.0 + 1.0 - fees, available: 10.0 + 1.0 - fees }
-            let btc_fees = 1.0 * fees_50_percent();
+            let btc_fees = dec!(1.0) * fees_50_percent();
             let expected_btc = SymbolBalance::new(
                 "btc",
-                Balance::new(10.0 + 1.0 - btc_fees, 10.0 + 1.0 - btc_fees),
+                Balance::new(
+                    dec!(10.0) + dec!(1.0) - btc_fees,
+                    dec!(10.0) + dec!(1.0) - btc_fees,
+                ),
             );
             assert_eq!(balances[0], expected_btc);
 
             // Quote Balance second: expected usdt Balance { total: 10_000 - 200, available: 9_700 }
-            let expected_usdt = SymbolBalance::new("usdt", Balance::new(9_800.0, 9_700.0));
+            let expected_usdt =
+                SymbolBalance::new("usdt", Balance::new(dec!(9_800.0), dec!(9_700.0)));
             assert_eq!(balances[1], expected_usdt);
         }
         other => {
@@ -500,9 +552,9 @@ async fn test_7_send_market_event_that_exact_full_matches_order(
                 order_id: OrderId(3.to_string()),
                 instrument: Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
                 side: Side::Buy,
-                price: 200.0,
-                quantity: 1.0,
-                fees: SymbolFees::new("btc", 1.0 * fees_50_percent()),
+                price: dec!(200.0),
+                quantity: dec!(1.0),
+                fees: SymbolFees::new("btc", dec!(1.0) * fees_50_percent()),
             };
             assert_eq!(trade, expected);
         }
@@ -534,9 +586,9 @@ async fn test_8_fetch_open_orders_and_check_test_6_order_cid_1_only(
             test_6_ids_1.cid,
             test_6_ids_1.id,
             Side::Buy,
-            100.0,
-            1.0,
-            0.0
+            dec!(100.0),
+            dec!(1.0),
+            dec!(0.0)
         )
     );
 }
@@ -554,15 +606,15 @@ async fn test_9_open_2x_limit_sell_orders(
                 Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
                 test_9_ids_1.cid,
                 Side::Sell,
-                500.0,
-                1.0,
+                dec!(500.0),
+                dec!(1.0),
             ),
             order_request_limit(
                 Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
                 test_9_ids_2.cid,
                 Side::Sell,
-                1000.0,
-                1.0,
+                dec!(1000.0),
+                dec!(1.0),
             ),
         ])
         .await;
@@ -572,9 +624,9 @@ async fn test_9_open_2x_limit_sell_orders(
         test_9_ids_1.cid,
         test_9_ids_1.id,
         Side::Sell,
-        500.0,
-        1.0,
-        0.0,
+        dec!(500.0),
+        dec!(1.0),
+        dec!(0.0),
     );
 
     let expected_order_new_2 = open_order(
@@ -582,9 +634,9 @@ async fn test_9_open_2x_limit_sell_orders(
         test_9_ids_2.cid,
         test_9_ids_2.id,
         Side::Sell,
-        1000.0,
-        1.0,
-        0.0,
+        dec!(1000.0),
+        dec!(1.0),
+        dec!(0.0),
     );
 
     assert_eq!(opened_orders.len(), 2);
@@ -604,7 +656,8 @@ async fn test_9_open_2x_limit_sell_orders(
             ..
         }) => {
             // Expected btc Balance.available = 10.5 - 1.0
-            let expected = SymbolBalance::new("btc", Balance::new(10.5, 10.5 - 1.0));
+            let expected =
+                SymbolBalance::new("btc", Balance::new(dec!(10.5), dec!(10.5) - dec!(1.0)));
             assert_eq!(btc_balance, expected);
         }
         other => {
@@ -639,7 +692,8 @@ async fn test_9_open_2x_limit_sell_orders(
             ..
         }) => {
             // Expected btc Balance.available = 9.5 - 1.0
-            let expected = SymbolBalance::new("btc", Balance::new(10.5, 9.5 - 1.0));
+            let expected =
+                SymbolBalance::new("btc", Balance::new(dec!(10.5), dec!(9.5) - dec!(1.0)));
             assert_eq!(btc_balance, expected);
         }
         other => {
@@ -692,8 +746,8 @@ async fn test_10_send_market_event_that_full_and_partial_matches_orders(
             PublicTrade {
                 id: "test_10".to_string(),
                 side: Side::Buy,
-                price: 1000.0,
-                amount: 1.5,
+                price: dec!(1000.0),
+                amount: dec!(1.5),
             },
         )))
         .unwrap();
@@ -701,7 +755,7 @@ async fn test_10_send_market_event_that_full_and_partial_matches_orders(
     tokio::time::sleep(latency_50ms()).await;
 
     // a) First full match fill:
-    let first_full_fill_fees = (500.0 * 1.0) * fees_50_percent();
+    let first_full_fill_fees = (dec!(500.0) * dec!(1.0)) * fees_50_percent();
     // Check AccountEvent Balances for base & quote currencies related to the trade
     match event_account_rx.try_recv() {
         Ok(AccountEvent {
@@ -712,13 +766,15 @@ async fn test_10_send_market_event_that_full_and_partial_matches_orders(
             assert_eq!(balances.len(), 2);
 
             // Base Balance first: expected btc Balance { total: 10.5 - 1.0, available: 8.5 }
-            let expected_btc = SymbolBalance::new("btc", Balance::new(10.5 - 1.0, 8.5));
+            let expected_btc =
+                SymbolBalance::new("btc", Balance::new(dec!(10.5) - dec!(1.0), dec!(8.5)));
             assert_eq!(balances[0], expected_btc);
 
             // Quote Balance second:
             // Expected usdt increase = (500 * 1.0) - (500 * 1.0 * 0.5) = 500 - 250 = 250
             // expected usdt Balance { total: 9_800 + 250, available: 9_700 + 250 }
-            let expected_usdt = SymbolBalance::new("usdt", Balance::new(10_050.0, 9_950.0));
+            let expected_usdt =
+                SymbolBalance::new("usdt", Balance::new(dec!(10_050.0), dec!(9_950.0)));
             assert_eq!(balances[1], expected_usdt);
         }
         other => {
@@ -739,8 +795,8 @@ async fn test_10_send_market_event_that_full_and_partial_matches_orders(
                 order_id: OrderId(4.to_string()),
                 instrument: Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
                 side: Side::Sell,
-                price: 500.0,
-                quantity: 1.0,
+                price: dec!(500.0),
+                quantity: dec!(1.0),
                 fees: SymbolFees::new("usdt", first_full_fill_fees),
             };
             assert_eq!(trade, expected);
@@ -754,7 +810,7 @@ async fn test_10_send_market_event_that_full_and_partial_matches_orders(
     }
 
     // b) Second partial match fill
-    let second_partial_fill_fees = (1000.0 * 0.5) * fees_50_percent();
+    let second_partial_fill_fees = (dec!(1000.0) * dec!(0.5)) * fees_50_percent();
 
     // Check AccountEvent Balances for base & quote currencies related to the trade
     match event_account_rx.try_recv() {
@@ -768,14 +824,17 @@ async fn test_10_send_market_event_that_full_and_partial_matches_orders(
             // btc { total: 9.0, available: 8.5 } 0.5 left in partially filled trade
 
             // Base Balance first: expected btc Balance { total: 9.5 - 0.5, available: 8.5 }
-            let expected_btc = SymbolBalance::new("btc", Balance::new(9.5 - 0.5, 8.5));
+            let expected_btc =
+                SymbolBalance::new("btc", Balance::new(dec!(9.5) - dec!(0.5), dec!(8.5)));
             assert_eq!(balances[0], expected_btc);
 
             // Quote Balance second:
             // Expected usdt increase = (1000 * 0.5) - (1000 * 0.5 * 0.5) = 500 - 250 = 250
             // expected usdt Balance { total: 10_050 + 250, available: 9_950 + 250 }
-            let expected_usdt =
-                SymbolBalance::new("usdt", Balance::new(10_050.0 + 250.0, 9_950.0 + 250.0));
+            let expected_usdt = SymbolBalance::new(
+                "usdt",
+                Balance::new(dec!(10_050.0) + dec!(250.0), dec!(9_950.0) + dec!(250.0)),
+            );
             assert_eq!(balances[1], expected_usdt);
         }
         other => {
@@ -796,8 +855,8 @@ async fn test_10_send_market_event_that_full_and_partial_matches_orders(
                 order_id: OrderId(5.to_string()),
                 instrument: Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
                 side: Side::Sell,
-                price: 1000.0,
-                quantity: 0.5,
+                price: dec!(1000.0),
+                quantity: dec!(0.5),
                 fees: SymbolFees::new("usdt", second_partial_fill_fees),
             };
             assert_eq!(trade, expected);
@@ -883,14 +942,17 @@ async fn test_11_cancel_all_orders(
             // Bids are cancelled first, so balance is updated first
             // test_6_order_cid_1, Side::Buy, price=100.0, quantity=1.0
             // Therefore, usdt Balance { total: 10_300, available: 10_200 + (100 * 1)
-            let expected_usdt =
-                SymbolBalance::new("usdt", Balance::new(10_300.0, 10_200.0 + 100.0));
+            let expected_usdt = SymbolBalance::new(
+                "usdt",
+                Balance::new(dec!(10_300.0), dec!(10_200.0) + dec!(100.0)),
+            );
             assert_eq!(balances[0], expected_usdt);
 
             // Asks are cancelled second, so balance is updated first
             // test_9_order_cid_2, Side::Sell, price=1000.0, quantity=1.0, filled=0.5
             // Therefore, btc Balance { total: 9.0, available: 8.5 + 0.5 }
-            let expected_btc = SymbolBalance::new("btc", Balance::new(9.0, 8.5 + 0.5));
+            let expected_btc =
+                SymbolBalance::new("btc", Balance::new(dec!(9.0), dec!(8.5) + dec!(0.5)));
             assert_eq!(balances[1], expected_btc);
         }
         other => {
@@ -932,15 +994,15 @@ async fn test_13_fail_to_open_one_of_two_limits_with_insufficient_funds(
                 Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
                 test_13_ids_1.cid,
                 Side::Buy,
-                1_000_000_000.0,
-                1.0,
+                dec!(1_000_000_000.0),
+                dec!(1.0),
             ),
             order_request_limit(
                 Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
                 test_13_ids_2.cid,
                 Side::Sell,
-                1000.0,
-                1.0,
+                dec!(1000.0),
+                dec!(1.0),
             ),
         ])
         .await;
@@ -951,9 +1013,9 @@ async fn test_13_fail_to_open_one_of_two_limits_with_insufficient_funds(
         test_13_ids_2.cid,
         test_13_ids_2.id,
         Side::Sell,
-        1000.0,
-        1.0,
-        0.0,
+        dec!(1000.0),
+        dec!(1.0),
+        dec!(0.0),
     );
 
     assert_eq!(opened_orders.len(), 2);
@@ -975,7 +1037,8 @@ async fn test_13_fail_to_open_one_of_two_limits_with_insufficient_funds(
             ..
         }) => {
             // Expected btc Balance.available = 9.0 - 1.0
-            let expected = SymbolBalance::new("btc", Balance::new(9.0, 9.0 - 1.0));
+            let expected =
+                SymbolBalance::new("btc", Balance::new(dec!(9.0), dec!(9.0) - dec!(1.0)));
             assert_eq!(btc_balance, expected);
         }
         other => {
@@ -1036,3 +1099,554 @@ async fn test_14_fail_to_cancel_limit_with_order_not_found(client: &SimulatedExe
         _ => panic!("Expected an IO error"),
     }
 }
+
+// 15. Open STOP_MARKET Sell Order, send MarketEvents that do not & do trigger it, and check the
+// triggered order reserves balance and is matched at the trigger trade price.
+async fn test_15_open_stop_market_sell_and_trigger(
+    client: &SimulatedExecution,
+    test_15_ids: Ids,
+    event_simulated_tx: &mut mpsc::UnboundedSender<SimulatedEvent>,
+    event_account_rx: &mut mpsc::UnboundedReceiver<AccountEvent>,
+) {
+    let kind = OrderKind::StopMarket {
+        trigger_price: dec!(100.0),
+    };
+    let opened_orders = client
+        .open_orders(vec![order_request_conditional(
+            Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+            test_15_ids.cid,
+            Side::Sell,
+            kind,
+            dec!(1.0),
+        )])
+        .await;
+
+    let expected_pending = open_order(
+        Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+        test_15_ids.cid,
+        test_15_ids.id.clone(),
+        Side::Sell,
+        dec!(0),
+        dec!(1.0),
+        dec!(0),
+    );
+    assert_eq!(opened_orders.len(), 1);
+    assert_eq!(opened_orders[0].as_ref().unwrap().clone(), expected_pending);
+
+    // Check AccountEvent OrdersNew only, since no balance is reserved until triggered
+    match event_account_rx.try_recv() {
+        Ok(AccountEvent {
+            kind: AccountEventKind::OrdersNew(new_orders),
+            ..
+        }) => {
+            assert_eq!(new_orders, vec![expected_pending]);
+        }
+        other => {
+            panic!("try_recv() consumed unexpected: {:?}", other);
+        }
+    }
+
+    // Send MarketEvent above the trigger price & check no AccountEvents are sent
+    let send_trade = |id: &str, price| {
+        event_simulated_tx
+            .send(SimulatedEvent::MarketTrade((
+                Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+                PublicTrade {
+                    id: id.to_string(),
+                    side: Side::Sell,
+                    price,
+                    amount: dec!(1.0),
+                },
+            )))
+            .unwrap()
+    };
+    send_trade("test_15_1", dec!(150.0));
+    tokio::time::sleep(latency_50ms()).await;
+    match event_account_rx.try_recv() {
+        Err(mpsc::error::TryRecvError::Empty) => {}
+        other => {
+            panic!("try_recv() consumed unexpected: {:?}", other);
+        }
+    }
+
+    // Send MarketEvent at the trigger price
+    send_trade("test_15_2", dec!(100.0));
+    tokio::time::sleep(latency_50ms()).await;
+
+    // Check AccountEvent Balance for the triggered order - btc available decreases by 1.0
+    match event_account_rx.try_recv() {
+        Ok(AccountEvent {
+            kind: AccountEventKind::Balance(btc_balance),
+            ..
+        }) => {
+            let expected = SymbolBalance::new("btc", Balance::new(dec!(9.0), dec!(7.0)));
+            assert_eq!(btc_balance, expected);
+        }
+        other => {
+            panic!("try_recv() consumed unexpected: {:?}", other);
+        }
+    }
+
+    // Check AccountEvent OrdersNew for the triggered order, now priced at the trade price
+    let expected_triggered = open_order(
+        Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+        test_15_ids.cid,
+        test_15_ids.id.clone(),
+        Side::Sell,
+        dec!(100.0),
+        dec!(1.0),
+        dec!(0),
+    );
+    match event_account_rx.try_recv() {
+        Ok(AccountEvent {
+            kind: AccountEventKind::OrdersNew(new_orders),
+            ..
+        }) => {
+            assert_eq!(new_orders, vec![expected_triggered]);
+        }
+        other => {
+            panic!("try_recv() consumed unexpected: {:?}", other);
+        }
+    }
+
+    // Check AccountEvent Balances for base & quote currencies related to the trade
+    match event_account_rx.try_recv() {
+        Ok(AccountEvent {
+            kind: AccountEventKind::Balances(balances),
+            ..
+        }) => {
+            assert_eq!(balances.len(), 2);
+            assert_eq!(
+                balances[0],
+                SymbolBalance::new("btc", Balance::new(dec!(8.0), dec!(7.0)))
+            );
+        }
+        other => {
+            panic!("try_recv() consumed unexpected: {:?}", other);
+        }
+    }
+
+    // Check AccountEvent Trade at the trigger trade price
+    match event_account_rx.try_recv() {
+        Ok(AccountEvent {
+            kind: AccountEventKind::Trade(trade),
+            ..
+        }) => {
+            assert_eq!(trade.order_id, test_15_ids.id);
+            assert_eq!(trade.side, Side::Sell);
+            assert_eq!(trade.price, dec!(100.0));
+            assert_eq!(trade.quantity, dec!(1.0));
+        }
+        other => {
+            panic!("try_recv() consumed unexpected: {:?}", other);
+        }
+    }
+
+    // Check no more AccountEvents generated
+    match event_account_rx.try_recv() {
+        Err(mpsc::error::TryRecvError::Empty) => {}
+        other => {
+            panic!("try_recv() consumed unexpected: {:?}", other);
+        }
+    }
+}
+
+// 16. Open TAKE_PROFIT_MARKET Sell Order and cancel it before it triggers. Check no balance
+// AccountEvent is sent since no balance was reserved.
+async fn test_16_cancel_untriggered_take_profit(
+    client: &SimulatedExecution,
+    test_16_ids: Ids,
+    event_account_rx: &mut mpsc::UnboundedReceiver<AccountEvent>,
+) {
+    let opened_orders = client
+        .open_orders(vec![order_request_conditional(
+            Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+            test_16_ids.cid,
+            Side::Sell,
+            OrderKind::TakeProfitMarket {
+                trigger_price: dec!(2000.0),
+            },
+            dec!(1.0),
+        )])
+        .await;
+    assert!(opened_orders[0].is_ok());
+
+    // Consume AccountEvent OrdersNew
+    match event_account_rx.try_recv() {
+        Ok(AccountEvent {
+            kind: AccountEventKind::OrdersNew(_),
+            ..
+        }) => {}
+        other => {
+            panic!("try_recv() consumed unexpected: {:?}", other);
+        }
+    }
+
+    let cancelled = client
+        .cancel_orders(vec![order_cancel_request(
+            Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+            test_16_ids.cid,
+            Side::Sell,
+            test_16_ids.id.clone(),
+        )])
+        .await;
+
+    let expected = order_cancelled(
+        Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+        test_16_ids.cid,
+        Side::Sell,
+        test_16_ids.id,
+    );
+    assert_eq!(cancelled.len(), 1);
+    assert_eq!(cancelled[0].as_ref().unwrap().clone(), expected);
+
+    // Check AccountEvent OrdersCancelled
+    match event_account_rx.try_recv() {
+        Ok(AccountEvent {
+            kind: AccountEventKind::OrdersCancelled(cancelled),
+            ..
+        }) => {
+            assert_eq!(cancelled, vec![expected]);
+        }
+        other => {
+            panic!("try_recv() consumed unexpected: {:?}", other);
+        }
+    }
+
+    // Check no more AccountEvents generated
+    match event_account_rx.try_recv() {
+        Err(mpsc::error::TryRecvError::Empty) => {}
+        other => {
+            panic!("try_recv() consumed unexpected: {:?}", other);
+        }
+    }
+}
+
+// 17. Open LIMIT Buy Order and amend it's price & quantity. Check AccountEvents for the amended
+// order and the change in reserved balance are sent, and the OrderId is kept.
+async fn test_17_amend_limit_buy_order(
+    client: &SimulatedExecution,
+    test_17_ids: Ids,
+    event_account_rx: &mut mpsc::UnboundedReceiver<AccountEvent>,
+) {
+    let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Perpetual));
+    let usdt = client
+        .fetch_balances()
+        .await
+        .unwrap()
+        .into_iter()
+        .find(|balance| balance.symbol == Symbol::from("usdt"))
+        .unwrap()
+        .balance;
+
+    let opened = client
+        .open_orders(vec![order_request_limit(
+            instrument.clone(),
+            test_17_ids.cid,
+            Side::Buy,
+            dec!(100.0),
+            dec!(1.0),
+        )])
+        .await;
+    assert!(opened[0].is_ok());
+
+    // Consume AccountEvents Balance & OrdersNew
+    for _ in 0..2 {
+        event_account_rx.try_recv().unwrap();
+    }
+
+    // Fail to amend quantity beyond the available balance, leaving the order untouched
+    let amended = client
+        .amend_orders(vec![order_amend_request(
+            instrument.clone(),
+            test_17_ids.cid,
+            Side::Buy,
+            test_17_ids.id.clone(),
+            dec!(100.0),
+            dec!(1000.0),
+        )])
+        .await;
+    assert!(matches!(
+        amended[0],
+        Err(ExecutionError::InsufficientBalance(_))
+    ));
+
+    // Amend price & quantity, reserving 2 * 200 usdt instead of 1 * 100 usdt
+    let amended = client
+        .amend_orders(vec![order_amend_request(
+            instrument.clone(),
+            test_17_ids.cid,
+            Side::Buy,
+            test_17_ids.id.clone(),
+            dec!(200.0),
+            dec!(2.0),
+        )])
+        .await;
+
+    let expected = open_order(
+        instrument,
+        test_17_ids.cid,
+        test_17_ids.id,
+        Side::Buy,
+        dec!(200.0),
+        dec!(2.0),
+        dec!(0.0),
+    );
+    assert_eq!(amended.len(), 1);
+    assert_eq!(amended[0].as_ref().unwrap().clone(), expected);
+
+    // Check AccountEvent OrdersAmended
+    match event_account_rx.try_recv() {
+        Ok(AccountEvent {
+            kind: AccountEventKind::OrdersAmended(amended),
+            ..
+        }) => {
+            assert_eq!(amended, vec![expected]);
+        }
+        other => {
+            panic!("try_recv() consumed unexpected: {:?}", other);
+        }
+    }
+
+    // Check AccountEvent Balance for the quote currency (usdt)
+    match event_account_rx.try_recv() {
+        Ok(AccountEvent {
+            kind: AccountEventKind::Balance(usdt_balance),
+            ..
+        }) => {
+            let expected = SymbolBalance::new(
+                "usdt",
+                Balance::new(usdt.total, usdt.available - dec!(400.0)),
+            );
+            assert_eq!(usdt_balance, expected);
+        }
+        other => {
+            panic!("try_recv() consumed unexpected: {:?}", other);
+        }
+    }
+
+    // Check no more AccountEvents generated
+    match event_account_rx.try_recv() {
+        Err(mpsc::error::TryRecvError::Empty) => {}
+        other => {
+            panic!("try_recv() consumed unexpected: {:?}", other);
+        }
+    }
+}
+
+// 18. Cancel-replace the amended LIMIT Buy Order. Check the replacement keeps the ClientOrderId,
+// but is assigned a new OrderId.
+async fn test_18_cancel_replace_limit_buy_order(
+    client: &SimulatedExecution,
+    test_17_ids: Ids,
+    test_18_ids: Ids,
+    event_account_rx: &mut mpsc::UnboundedReceiver<AccountEvent>,
+) {
+    let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Perpetual));
+
+    let replaced = client
+        .cancel_replace_orders(vec![
+            order_amend_request(
+                instrument.clone(),
+                test_17_ids.cid,
+                Side::Buy,
+                test_17_ids.id.clone(),
+                dec!(150.0),
+                dec!(1.0),
+            ),
+            order_amend_request(
+                instrument.clone(),
+                test_18_ids.cid,
+                Side::Buy,
+                OrderId::from("order will not be found"),
+                dec!(150.0),
+                dec!(1.0),
+            ),
+        ])
+        .await;
+
+    let expected = open_order(
+        instrument,
+        test_17_ids.cid,
+        test_18_ids.id,
+        Side::Buy,
+        dec!(150.0),
+        dec!(1.0),
+        dec!(0.0),
+    );
+    assert_eq!(replaced.len(), 2);
+    assert_eq!(replaced[0].as_ref().unwrap().clone(), expected);
+    assert!(matches!(
+        replaced[1],
+        Err(ExecutionError::OrderNotFound(cid)) if cid == test_18_ids.cid
+    ));
+
+    // Check AccountEvents OrdersCancelled & Balance, then Balance & OrdersNew
+    match event_account_rx.try_recv() {
+        Ok(AccountEvent {
+            kind: AccountEventKind::OrdersCancelled(cancelled),
+            ..
+        }) => {
+            assert_eq!(cancelled[0].state.id, test_17_ids.id);
+        }
+        other => {
+            panic!("try_recv() consumed unexpected: {:?}", other);
+        }
+    }
+    for _ in 0..2 {
+        match event_account_rx.try_recv() {
+            Ok(AccountEvent {
+                kind: AccountEventKind::Balance(_),
+                ..
+            }) => {}
+            other => {
+                panic!("try_recv() consumed unexpected: {:?}", other);
+            }
+        }
+    }
+    match event_account_rx.try_recv() {
+        Ok(AccountEvent {
+            kind: AccountEventKind::OrdersNew(new),
+            ..
+        }) => {
+            assert_eq!(new, vec![expected]);
+        }
+        other => {
+            panic!("try_recv() consumed unexpected: {:?}", other);
+        }
+    }
+
+    // Check no more AccountEvents generated
+    match event_account_rx.try_recv() {
+        Err(mpsc::error::TryRecvError::Empty) => {}
+        other => {
+            panic!("try_recv() consumed unexpected: {:?}", other);
+        }
+    }
+}
+
+// 19. Send MarketEvent that partially matches the replaced LIMIT Buy Order, then cancel-replace it.
+// Check only the remaining quantity is re-opened, and the returned Order<Open> reports the total
+// quantity including the earlier fill.
+async fn test_19_cancel_replace_partially_filled_limit_buy_order(
+    client: &SimulatedExecution,
+    test_18_ids: Ids,
+    test_19_ids: Ids,
+    event_simulated_tx: &mut mpsc::UnboundedSender<SimulatedEvent>,
+    event_account_rx: &mut mpsc::UnboundedReceiver<AccountEvent>,
+) {
+    let instrument = Instrument::from(("btc", "usdt", InstrumentKind::Perpetual));
+
+    // Send MarketEvent that partially matches the replaced order
+    event_simulated_tx
+        .send(SimulatedEvent::MarketTrade((
+            instrument.clone(),
+            PublicTrade {
+                id: "test_19".to_string(),
+                side: Side::Sell,
+                price: dec!(150.0),
+                amount: dec!(0.4),
+            },
+        )))
+        .unwrap();
+
+    tokio::time::sleep(latency_50ms()).await;
+
+    // Check AccountEvents Balances & Trade for the partial fill
+    match event_account_rx.try_recv() {
+        Ok(AccountEvent {
+            kind: AccountEventKind::Balances(_),
+            ..
+        }) => {}
+        other => {
+            panic!("try_recv() consumed unexpected: {:?}", other);
+        }
+    }
+    match event_account_rx.try_recv() {
+        Ok(AccountEvent {
+            kind: AccountEventKind::Trade(trade),
+            ..
+        }) => {
+            assert_eq!(trade.order_id, test_18_ids.id);
+            assert_eq!(trade.quantity, dec!(0.4));
+        }
+        other => {
+            panic!("try_recv() consumed unexpected: {:?}", other);
+        }
+    }
+
+    let mut amend_request = order_amend_request(
+        instrument.clone(),
+        test_18_ids.cid,
+        Side::Buy,
+        test_18_ids.id,
+        dec!(150.0),
+        dec!(1.0),
+    );
+    amend_request.state.filled_quantity = dec!(0.4);
+
+    let replaced = client.cancel_replace_orders(vec![amend_request]).await;
+
+    let expected = open_order(
+        instrument.clone(),
+        test_19_ids.cid,
+        test_19_ids.id.clone(),
+        Side::Buy,
+        dec!(150.0),
+        dec!(1.0),
+        dec!(0.4),
+    );
+    assert_eq!(replaced.len(), 1);
+    assert_eq!(replaced[0].as_ref().unwrap().clone(), expected);
+
+    // Check AccountEvents OrdersCancelled & Balance, then Balance & OrdersNew with only the
+    // remaining quantity re-opened
+    match event_account_rx.try_recv() {
+        Ok(AccountEvent {
+            kind: AccountEventKind::OrdersCancelled(_),
+            ..
+        }) => {}
+        other => {
+            panic!("try_recv() consumed unexpected: {:?}", other);
+        }
+    }
+    for _ in 0..2 {
+        match event_account_rx.try_recv() {
+            Ok(AccountEvent {
+                kind: AccountEventKind::Balance(_),
+                ..
+            }) => {}
+            other => {
+                panic!("try_recv() consumed unexpected: {:?}", other);
+            }
+        }
+    }
+    match event_account_rx.try_recv() {
+        Ok(AccountEvent {
+            kind: AccountEventKind::OrdersNew(new),
+            ..
+        }) => {
+            let remaining = open_order(
+                instrument,
+                test_19_ids.cid,
+                test_19_ids.id,
+                Side::Buy,
+                dec!(150.0),
+                dec!(0.6),
+                dec!(0.0),
+            );
+            assert_eq!(new, vec![remaining]);
+        }
+        other => {
+            panic!("try_recv() consumed unexpected: {:?}", other);
+        }
+    }
+
+    // Check no more AccountEvents generated
+    match event_account_rx.try_recv() {
+        Err(mpsc::error::TryRecvError::Empty) => {}
+        other => {
+            panic!("try_recv() consumed unexpected: {:?}", other);
+        }
+    }
+}
@@ -1,19 +1,22 @@
 use barter_execution::{
     model::{
-        order::{Cancelled, Open, Order, OrderId, OrderKind, RequestCancel, RequestOpen},
+        order::{
+            Cancelled, Open, Order, OrderId, OrderKind, RequestAmend, RequestCancel, RequestOpen,
+        },
         ClientOrderId,
     },
     ExecutionId,
 };
 use barter_integration::model::{instrument::Instrument, Exchange, Side};
+use rust_decimal::Decimal;
 
 // Utility for creating an Open Order request
 pub(super) fn order_request_limit<I>(
     instrument: I,
     cid: ClientOrderId,
     side: Side,
-    price: f64,
-    quantity: f64,
+    price: Decimal,
+    quantity: Decimal,
 ) -> Order<RequestOpen>
 where
     I: Into<Instrument>,
@@ -27,6 +30,32 @@ where
             kind: OrderKind::Limit,
             price,
             quantity,
+            reduce_only: false,
+        },
+    }
+}
+
+// Utility for creating a conditional Open Order request
+pub(super) fn order_request_conditional<I>(
+    instrument: I,
+    cid: ClientOrderId,
+    side: Side,
+    kind: OrderKind,
+    quantity: Decimal,
+) -> Order<RequestOpen>
+where
+    I: Into<Instrument>,
+{
+    Order {
+        exchange: Exchange::from(ExecutionId::Simulated),
+        instrument: instrument.into(),
+        cid,
+        side,
+        state: RequestOpen {
+            kind,
+            price: Decimal::ZERO,
+            quantity,
+            reduce_only: true,
         },
     }
 }
@@ -37,9 +66,9 @@ pub(super) fn open_order<I>(
     cid: ClientOrderId,
     id: OrderId,
     side: Side,
-    price: f64,
-    quantity: f64,
-    filled: f64,
+    price: Decimal,
+    quantity: Decimal,
+    filled: Decimal,
 ) -> Order<Open>
 where
     I: Into<Instrument>,
@@ -78,6 +107,35 @@ where
     }
 }
 
+// Utility for creating a LIMIT Order RequestAmend
+pub(super) fn order_amend_request<I, Id>(
+    instrument: I,
+    cid: ClientOrderId,
+    side: Side,
+    id: Id,
+    price: Decimal,
+    quantity: Decimal,
+) -> Order<RequestAmend>
+where
+    I: Into<Instrument>,
+    Id: Into<OrderId>,
+{
+    Order {
+        exchange: Exchange::from(ExecutionId::Simulated),
+        instrument: instrument.into(),
+        cid,
+        side,
+        state: RequestAmend {
+            id: id.into(),
+            kind: OrderKind::Limit,
+            price,
+            quantity,
+            reduce_only: false,
+            filled_quantity: Decimal::ZERO,
+        },
+    }
+}
+
 // Utility for creating an Order<Cancelled>
 pub(super) fn order_cancelled<I, Id>(
     instrument: I,
@@ -26,6 +26,21 @@ where
     }
 }
 
+/// Deserialize a `String` as the desired type, treating the empty `String` some exchanges send
+/// for non-applicable fields (eg/ the price of a market order) as `T::default()`.
+pub fn de_str_or_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    T: std::str::FromStr + Default,
+    T::Err: std::fmt::Display,
+{
+    let data = <String as serde::de::Deserialize>::deserialize(deserializer)?;
+    match data.as_str() {
+        "" => Ok(T::default()),
+        data => data.parse::<T>().map_err(serde::de::Error::custom),
+    }
+}
+
 /// Deserialize a `u64` milliseconds value as `DateTime<Utc>`.
 pub fn de_u64_epoch_ms_as_datetime_utc<'de, D>(
     deserializer: D,
@@ -1,5 +1,6 @@
 use crate::model::SubscriptionId;
 use reqwest::Error;
+use rust_decimal::Decimal;
 use thiserror::Error;
 
 /// All socket IO related errors generated in `barter-integration`.
@@ -56,6 +57,9 @@ pub enum SocketError {
 
     #[error("consumed error message from exchange: {0}")]
     Exchange(String),
+
+    #[error("invalid request signing key: {0}")]
+    SigningKey(String),
 }
 
 impl From<reqwest::Error> for SocketError {
@@ -66,3 +70,35 @@ impl From<reqwest::Error> for SocketError {
         }
     }
 }
+
+/// All [`InstrumentSpec`](crate::model::instrument::spec::InstrumentSpec) related errors
+/// generated in `barter-integration`.
+#[derive(Debug, Error)]
+pub enum SpecError {
+    #[error("price {price} is not a multiple of tick size {tick_size}")]
+    TickSize { price: Decimal, tick_size: Decimal },
+
+    #[error("quantity {quantity} is not a multiple of lot size {lot_size}")]
+    LotSize {
+        quantity: Decimal,
+        lot_size: Decimal,
+    },
+
+    #[error("quantity {quantity} is below minimum quantity {min_quantity}")]
+    MinQuantity {
+        quantity: Decimal,
+        min_quantity: Decimal,
+    },
+
+    #[error("notional {notional} is below minimum notional {min_notional}")]
+    MinNotional {
+        notional: Decimal,
+        min_notional: Decimal,
+    },
+
+    #[error("IO error reading instrument specs: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Deserialising instrument specs JSON error: {0}")]
+    Deserialise(#[from] serde_json::Error),
+}
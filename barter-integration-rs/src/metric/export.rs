@@ -0,0 +1,315 @@
+use super::{
+    registry::{MetricRegistry, MetricSnapshot},
+    Metric, Tag, Value,
+};
+use chrono::Utc;
+use std::{fmt::Write, net::SocketAddr, time::Duration};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+};
+use tracing::{debug, warn};
+
+/// Render a [`MetricSnapshot`] in the Prometheus text exposition format.
+pub fn prometheus(snapshot: &MetricSnapshot) -> String {
+    let mut output = String::new();
+    let mut previous_name = None;
+
+    for (key, histogram) in &snapshot.histograms {
+        let name = sanitise_name(&key.name);
+        if previous_name.as_ref() != Some(&name) {
+            let _ = writeln!(output, "# TYPE {name} histogram");
+            previous_name = Some(name.clone());
+        }
+
+        for (bound, count) in histogram.buckets() {
+            let le = match bound.is_infinite() {
+                true => "+Inf".to_string(),
+                false => bound.to_string(),
+            };
+            let labels = prometheus_labels(&key.tags, Some(&le));
+            let _ = writeln!(output, "{name}_bucket{labels} {count}");
+        }
+
+        let labels = prometheus_labels(&key.tags, None);
+        let _ = writeln!(output, "{name}_sum{labels} {}", histogram.sum());
+        let _ = writeln!(output, "{name}_count{labels} {}", histogram.count());
+    }
+
+    previous_name = None;
+    for (key, count) in &snapshot.counters {
+        let name = format!("{}_total", sanitise_name(&key.name));
+        if previous_name.as_ref() != Some(&name) {
+            let _ = writeln!(output, "# TYPE {name} counter");
+            previous_name = Some(name.clone());
+        }
+
+        let labels = prometheus_labels(&key.tags, None);
+        let _ = writeln!(output, "{name}{labels} {count}");
+    }
+
+    output
+}
+
+/// Render a [`MetricSnapshot`] in the InfluxDB line protocol, timestamped with the provided
+/// milliseconds since the epoch.
+///
+/// Each [`Histogram`](super::registry::Histogram) is written as a `count`, `sum` & `mean` line,
+/// and each counter as a `value` line.
+pub fn influx(snapshot: &MetricSnapshot, time_ms: u64) -> String {
+    let mut output = String::new();
+    let time_ns = u128::from(time_ms) * 1_000_000;
+
+    for (key, histogram) in &snapshot.histograms {
+        let _ = write!(
+            output,
+            "{}{} count={}u,sum={}",
+            escape_influx(&key.name),
+            influx_tags(&key.tags),
+            histogram.count(),
+            histogram.sum()
+        );
+        if let Some(mean) = histogram.mean() {
+            let _ = write!(output, ",mean={mean}");
+        }
+        let _ = writeln!(output, " {time_ns}");
+    }
+
+    for (key, count) in &snapshot.counters {
+        let _ = writeln!(
+            output,
+            "{}{} value={count}u {time_ns}",
+            escape_influx(&key.name),
+            influx_tags(&key.tags),
+        );
+    }
+
+    output
+}
+
+impl Metric {
+    /// Render this raw [`Metric`] as a single InfluxDB line protocol line.
+    pub fn to_line_protocol(&self) -> String {
+        let fields = self
+            .fields
+            .iter()
+            .map(|field| {
+                let value = match &field.value {
+                    Value::Float(value) => value.to_string(),
+                    Value::Int(value) => format!("{value}i"),
+                    Value::UInt(value) => format!("{value}u"),
+                    Value::Bool(value) => value.to_string(),
+                    Value::String(value) => {
+                        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+                    }
+                };
+                format!("{}={value}", escape_influx(field.key))
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let mut tags = self.tags.clone();
+        tags.sort();
+
+        format!(
+            "{}{} {fields} {}",
+            escape_influx(self.name),
+            influx_tags(&tags),
+            u128::from(self.time) * 1_000_000
+        )
+    }
+}
+
+/// Serve the [`MetricRegistry`] in the Prometheus text exposition format to every Http request
+/// received on the provided [`SocketAddr`], for a Prometheus server to scrape.
+pub async fn serve_prometheus(
+    registry: MetricRegistry,
+    addr: SocketAddr,
+) -> Result<(), std::io::Error> {
+    let listener = TcpListener::bind(addr).await?;
+    debug!(%addr, "serving Prometheus metrics");
+
+    loop {
+        let (mut stream, peer) = listener.accept().await?;
+        let registry = registry.clone();
+
+        tokio::spawn(async move {
+            // Request path & headers are irrelevant, every request is served the metrics
+            let mut buffer = [0_u8; 1024];
+            if let Err(error) = stream.read(&mut buffer).await {
+                warn!(%peer, ?error, "failed to read Prometheus scrape request");
+                return;
+            }
+
+            let body = prometheus(&registry.snapshot());
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                body.len()
+            );
+
+            if let Err(error) = stream.write_all(response.as_bytes()).await {
+                warn!(%peer, ?error, "failed to write Prometheus scrape response");
+            }
+        });
+    }
+}
+
+/// Periodically pushes a [`MetricRegistry`] to an InfluxDB write endpoint using the line
+/// protocol (eg/ "http://localhost:8086/api/v2/write?org=barter&bucket=metrics&precision=ns").
+#[derive(Debug, Clone)]
+pub struct InfluxExporter {
+    pub http_client: reqwest::Client,
+    pub write_url: String,
+    pub token: Option<String>,
+    pub interval: Duration,
+}
+
+impl InfluxExporter {
+    /// Construct a new [`InfluxExporter`] that pushes to the provided write Url every `interval`.
+    pub fn new<S>(write_url: S, token: Option<String>, interval: Duration) -> Self
+    where
+        S: Into<String>,
+    {
+        Self {
+            http_client: reqwest::Client::new(),
+            write_url: write_url.into(),
+            token,
+            interval,
+        }
+    }
+
+    /// Push the current [`MetricSnapshot`] of the [`MetricRegistry`].
+    pub async fn push(&self, registry: &MetricRegistry) -> Result<(), reqwest::Error> {
+        let body = influx(&registry.snapshot(), Utc::now().timestamp_millis() as u64);
+
+        let mut request = self.http_client.post(&self.write_url).body(body);
+        if let Some(token) = &self.token {
+            request = request.header("Authorization", format!("Token {token}"));
+        }
+
+        request.send().await?.error_for_status().map(|_| ())
+    }
+
+    /// Push the [`MetricRegistry`] every interval. Failed pushes are logged and retried on the
+    /// next interval.
+    pub async fn run(self, registry: MetricRegistry) {
+        let mut interval = tokio::time::interval(self.interval);
+        loop {
+            interval.tick().await;
+            if let Err(error) = self.push(&registry).await {
+                warn!(?error, "failed to push metrics to InfluxDB");
+            }
+        }
+    }
+}
+
+/// Replace every character that is invalid in a Prometheus metric name with an underscore.
+fn sanitise_name(name: &str) -> String {
+    name.chars()
+        .map(
+            |char| match char.is_ascii_alphanumeric() || char == '_' || char == ':' {
+                true => char,
+                false => '_',
+            },
+        )
+        .collect()
+}
+
+fn prometheus_labels(tags: &[Tag], le: Option<&str>) -> String {
+    let labels = tags
+        .iter()
+        .map(|tag| (tag.key, tag.value.as_str()))
+        .chain(le.map(|le| ("le", le)))
+        .map(|(key, value)| {
+            let value = value
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\n', "\\n");
+            format!("{}=\"{value}\"", sanitise_name(key))
+        })
+        .collect::<Vec<_>>();
+
+    match labels.is_empty() {
+        true => String::new(),
+        false => format!("{{{}}}", labels.join(",")),
+    }
+}
+
+fn influx_tags(tags: &[Tag]) -> String {
+    tags.iter()
+        .map(|tag| format!(",{}={}", escape_influx(tag.key), escape_influx(&tag.value)))
+        .collect()
+}
+
+fn escape_influx(value: &str) -> String {
+    value
+        .replace(',', "\\,")
+        .replace('=', "\\=")
+        .replace(' ', "\\ ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::Field;
+
+    fn registry() -> MetricRegistry {
+        let registry = MetricRegistry::with_buckets(&[10.0, 100.0]);
+        registry.observe(
+            "http_request_duration",
+            vec![Tag::new("status_code", "200")],
+            5.0,
+        );
+        registry.observe(
+            "http_request_duration",
+            vec![Tag::new("status_code", "200")],
+            50.0,
+        );
+        registry.increment("ws_messages", vec![Tag::new("exchange", "binance spot")], 7);
+        registry
+    }
+
+    #[test]
+    fn test_prometheus() {
+        let expected = "\
+# TYPE http_request_duration histogram
+http_request_duration_bucket{status_code=\"200\",le=\"10\"} 1
+http_request_duration_bucket{status_code=\"200\",le=\"100\"} 2
+http_request_duration_bucket{status_code=\"200\",le=\"+Inf\"} 2
+http_request_duration_sum{status_code=\"200\"} 55
+http_request_duration_count{status_code=\"200\"} 2
+# TYPE ws_messages_total counter
+ws_messages_total{exchange=\"binance spot\"} 7
+";
+
+        assert_eq!(prometheus(&registry().snapshot()), expected);
+    }
+
+    #[test]
+    fn test_influx() {
+        let expected = "\
+http_request_duration,status_code=200 count=2u,sum=55,mean=27.5 1000000000
+ws_messages,exchange=binance\\ spot value=7u 1000000000
+";
+
+        assert_eq!(influx(&registry().snapshot(), 1_000), expected);
+    }
+
+    #[test]
+    fn test_metric_to_line_protocol() {
+        let metric = Metric {
+            name: "http_request_duration",
+            time: 1_000,
+            tags: vec![
+                Tag::new("path", "/api/v3/order"),
+                Tag::new("http_method", "GET"),
+            ],
+            fields: vec![Field::new("duration", 20_u64), Field::new("retried", false)],
+        };
+
+        assert_eq!(
+            metric.to_line_protocol(),
+            "http_request_duration,http_method=GET,path=/api/v3/order duration=20u,retried=false 1000000000"
+        );
+    }
+}
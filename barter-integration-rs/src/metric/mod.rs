@@ -1,5 +1,13 @@
 use serde::{Deserialize, Serialize};
 
+/// Shared [`MetricRegistry`](registry::MetricRegistry) that aggregates [`Metric`]s into
+/// histograms & counters.
+pub mod registry;
+
+/// Prometheus text exposition & InfluxDB line protocol exporters of a
+/// [`MetricRegistry`](registry::MetricRegistry).
+pub mod export;
+
 #[derive(Debug, Clone, PartialOrd, PartialEq, Serialize)]
 pub struct Metric {
     /// Metric name.
@@ -78,6 +86,18 @@ impl Field {
     }
 }
 
+impl Value {
+    /// Numeric representation of this [`Value`], if any.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            Value::Float(value) => Some(*value),
+            Value::Int(value) => Some(*value as f64),
+            Value::UInt(value) => Some(*value as f64),
+            Value::Bool(_) | Value::String(_) => None,
+        }
+    }
+}
+
 impl From<f64> for Value {
     fn from(value: f64) -> Self {
         Self::Float(value)
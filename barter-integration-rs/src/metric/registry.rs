@@ -0,0 +1,523 @@
+use super::{Metric, Tag};
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, OnceLock,
+    },
+};
+use tokio::{sync::mpsc, task::JoinHandle};
+use tracing::debug;
+
+/// Default [`Histogram`] bucket upper bounds, suitable for latencies measured in milliseconds.
+pub const DEFAULT_BUCKETS: [f64; 14] = [
+    1.0, 2.0, 5.0, 10.0, 25.0, 50.0, 100.0, 250.0, 500.0, 1_000.0, 2_500.0, 5_000.0, 10_000.0,
+    30_000.0,
+];
+
+/// Identifies a single time series: a metric name & its sorted [`Tag`]s.
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq)]
+pub struct SeriesKey {
+    pub name: String,
+    pub tags: Vec<Tag>,
+}
+
+impl SeriesKey {
+    /// Construct a new [`SeriesKey`], sorting the [`Tag`]s so that their order is irrelevant.
+    pub fn new<S>(name: S, mut tags: Vec<Tag>) -> Self
+    where
+        S: Into<String>,
+    {
+        tags.sort();
+        Self {
+            name: name.into(),
+            tags,
+        }
+    }
+}
+
+/// Cumulative histogram of observed values, with fixed bucket upper bounds.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Histogram {
+    bounds: Vec<f64>,
+    /// Observations per bucket, with a final overflow bucket for values above every bound.
+    counts: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    /// Construct a new empty [`Histogram`] with the provided bucket upper bounds.
+    pub fn new(bounds: &[f64]) -> Self {
+        Self {
+            bounds: bounds.to_vec(),
+            counts: vec![0; bounds.len() + 1],
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    /// Record an observed value.
+    pub fn observe(&mut self, value: f64) {
+        let bucket = self
+            .bounds
+            .iter()
+            .position(|bound| value <= *bound)
+            .unwrap_or(self.bounds.len());
+
+        self.counts[bucket] += 1;
+        self.sum += value;
+        self.count += 1;
+    }
+
+    /// Sum of every observed value.
+    pub fn sum(&self) -> f64 {
+        self.sum
+    }
+
+    /// Number of observed values.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Mean observed value, if any values have been observed.
+    pub fn mean(&self) -> Option<f64> {
+        (self.count > 0).then(|| self.sum / self.count as f64)
+    }
+
+    /// Cumulative number of observations less than or equal to each bucket upper bound. The
+    /// final `(f64::INFINITY, count)` bucket contains every observation.
+    pub fn buckets(&self) -> Vec<(f64, u64)> {
+        self.bounds
+            .iter()
+            .copied()
+            .chain(std::iter::once(f64::INFINITY))
+            .zip(self.counts.iter().scan(0, |cumulative, count| {
+                *cumulative += count;
+                Some(*cumulative)
+            }))
+            .collect()
+    }
+
+    /// Add the observations of another [`Histogram`] with the same bucket upper bounds.
+    fn merge(&mut self, other: &Histogram) {
+        self.counts
+            .iter_mut()
+            .zip(&other.counts)
+            .for_each(|(count, other)| *count += other);
+        self.sum += other.sum;
+        self.count += other.count;
+    }
+}
+
+/// Lock-free handle to a [`Histogram`] series of a [`MetricRegistry`], used to record values on
+/// hot paths without allocating [`Tag`]s or locking the registry. Cheap to clone.
+#[derive(Debug, Clone)]
+pub struct HistogramHandle(Arc<AtomicHistogram>);
+
+#[derive(Debug)]
+struct AtomicHistogram {
+    bounds: Arc<[f64]>,
+    counts: Box<[AtomicU64]>,
+    /// Bits of the f64 sum of every observed value.
+    sum: AtomicU64,
+    count: AtomicU64,
+}
+
+impl HistogramHandle {
+    fn new(bounds: Arc<[f64]>) -> Self {
+        Self(Arc::new(AtomicHistogram {
+            counts: (0..=bounds.len()).map(|_| AtomicU64::new(0)).collect(),
+            bounds,
+            sum: AtomicU64::new(0.0_f64.to_bits()),
+            count: AtomicU64::new(0),
+        }))
+    }
+
+    /// Record an observed value.
+    pub fn observe(&self, value: f64) {
+        let histogram = &self.0;
+        let bucket = histogram
+            .bounds
+            .iter()
+            .position(|bound| value <= *bound)
+            .unwrap_or(histogram.bounds.len());
+
+        histogram.counts[bucket].fetch_add(1, Ordering::Relaxed);
+        let _ = histogram
+            .sum
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |sum| {
+                Some((f64::from_bits(sum) + value).to_bits())
+            });
+        histogram.count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Copy the observations recorded so far into a [`Histogram`].
+    fn load(&self) -> Histogram {
+        let histogram = &self.0;
+        Histogram {
+            bounds: histogram.bounds.to_vec(),
+            counts: histogram
+                .counts
+                .iter()
+                .map(|count| count.load(Ordering::Relaxed))
+                .collect(),
+            sum: f64::from_bits(histogram.sum.load(Ordering::Relaxed)),
+            count: histogram.count.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Lock-free handle to a counter series of a [`MetricRegistry`], used to count on hot paths
+/// without allocating [`Tag`]s or locking the registry. Cheap to clone.
+#[derive(Debug, Clone, Default)]
+pub struct CounterHandle(Arc<AtomicU64>);
+
+impl CounterHandle {
+    /// Increment the counter.
+    pub fn increment(&self, by: u64) {
+        self.0.fetch_add(by, Ordering::Relaxed);
+    }
+}
+
+/// Point in time copy of every series aggregated by a [`MetricRegistry`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct MetricSnapshot {
+    pub histograms: BTreeMap<SeriesKey, Histogram>,
+    pub counters: BTreeMap<SeriesKey, u64>,
+}
+
+#[derive(Debug, Default)]
+struct RegistryState {
+    histograms: BTreeMap<SeriesKey, Histogram>,
+    counters: BTreeMap<SeriesKey, u64>,
+    histogram_handles: BTreeMap<SeriesKey, HistogramHandle>,
+    counter_handles: BTreeMap<SeriesKey, CounterHandle>,
+}
+
+/// Aggregates [`Metric`]s into [`Histogram`]s & counters, ready to be exported.
+///
+/// State is shared by every clone of a [`MetricRegistry`], so a single registry can be fed by
+/// many components (eg/ every `RestClient` & `MarketStream`) and exported in one place.
+#[derive(Debug, Clone)]
+pub struct MetricRegistry {
+    state: Arc<Mutex<RegistryState>>,
+    buckets: Arc<[f64]>,
+}
+
+impl Default for MetricRegistry {
+    fn default() -> Self {
+        Self::with_buckets(&DEFAULT_BUCKETS)
+    }
+}
+
+impl MetricRegistry {
+    /// Construct a new empty [`MetricRegistry`] using the [`DEFAULT_BUCKETS`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Construct a new empty [`MetricRegistry`] whose [`Histogram`]s use the provided bucket
+    /// upper bounds.
+    pub fn with_buckets(buckets: &[f64]) -> Self {
+        Self {
+            state: Arc::default(),
+            buckets: Arc::from(buckets),
+        }
+    }
+
+    /// Process wide [`MetricRegistry`] used by default when no registry is provided.
+    pub fn global() -> Self {
+        static GLOBAL: OnceLock<MetricRegistry> = OnceLock::new();
+        GLOBAL.get_or_init(MetricRegistry::new).clone()
+    }
+
+    /// Sender of the process wide [`Metric`] channel recorded by the [`MetricRegistry::global`]
+    /// registry.
+    ///
+    /// Every caller shares a single recorder, which runs on a dedicated thread spawned on first
+    /// use, so this may be called outside of a tokio runtime.
+    pub fn global_sender() -> mpsc::UnboundedSender<Metric> {
+        static GLOBAL_TX: OnceLock<mpsc::UnboundedSender<Metric>> = OnceLock::new();
+        GLOBAL_TX
+            .get_or_init(|| {
+                let (metric_tx, metric_rx) = mpsc::unbounded_channel();
+                let registry = Self::global();
+                std::thread::Builder::new()
+                    .name("metric-recorder".to_string())
+                    .spawn(move || registry.record_blocking(metric_rx))
+                    .expect("failed to spawn MetricRegistry recorder thread");
+                metric_tx
+            })
+            .clone()
+    }
+
+    /// Lock-free [`HistogramHandle`] to the provided series. Handles to the same series share
+    /// their observations, which are included in every [`MetricSnapshot`].
+    pub fn histogram<S>(&self, name: S, tags: Vec<Tag>) -> HistogramHandle
+    where
+        S: Into<String>,
+    {
+        let mut state = self.state.lock().expect("MetricRegistry Mutex poisoned");
+        state
+            .histogram_handles
+            .entry(SeriesKey::new(name, tags))
+            .or_insert_with(|| HistogramHandle::new(self.buckets.clone()))
+            .clone()
+    }
+
+    /// Lock-free [`CounterHandle`] to the provided series. Handles to the same series share
+    /// their count, which is included in every [`MetricSnapshot`].
+    pub fn counter<S>(&self, name: S, tags: Vec<Tag>) -> CounterHandle
+    where
+        S: Into<String>,
+    {
+        let mut state = self.state.lock().expect("MetricRegistry Mutex poisoned");
+        state
+            .counter_handles
+            .entry(SeriesKey::new(name, tags))
+            .or_default()
+            .clone()
+    }
+
+    /// Record an observed value in the [`Histogram`] of the provided series.
+    pub fn observe<S>(&self, name: S, tags: Vec<Tag>, value: f64)
+    where
+        S: Into<String>,
+    {
+        let mut state = self.state.lock().expect("MetricRegistry Mutex poisoned");
+        state
+            .histograms
+            .entry(SeriesKey::new(name, tags))
+            .or_insert_with(|| Histogram::new(&self.buckets))
+            .observe(value);
+    }
+
+    /// Increment the counter of the provided series.
+    pub fn increment<S>(&self, name: S, tags: Vec<Tag>, by: u64)
+    where
+        S: Into<String>,
+    {
+        let mut state = self.state.lock().expect("MetricRegistry Mutex poisoned");
+        *state
+            .counters
+            .entry(SeriesKey::new(name, tags))
+            .or_default() += by;
+    }
+
+    /// Record every numeric [`Field`](super::Field) of the provided [`Metric`] in a
+    /// [`Histogram`].
+    ///
+    /// The series name is `{metric}_{field}`, unless the metric name already ends with the field
+    /// key (eg/ "http_request_duration" with field "duration").
+    pub fn record(&self, metric: &Metric) {
+        for field in &metric.fields {
+            let Some(value) = field.value.as_f64() else {
+                continue;
+            };
+
+            let name = match metric.name.ends_with(field.key) {
+                true => metric.name.to_string(),
+                false => format!("{}_{}", metric.name, field.key),
+            };
+
+            self.observe(name, metric.tags.clone(), value);
+        }
+    }
+
+    /// Record every [`Metric`] received until the channel is closed.
+    pub async fn record_from(self, mut metric_rx: mpsc::UnboundedReceiver<Metric>) {
+        while let Some(metric) = metric_rx.recv().await {
+            self.record(&metric);
+        }
+        debug!("Metric channel closed, MetricRegistry recorder stopping");
+    }
+
+    /// Record every [`Metric`] received until the channel is closed, blocking the current
+    /// thread. Must not be called from within an async context.
+    pub fn record_blocking(self, mut metric_rx: mpsc::UnboundedReceiver<Metric>) {
+        while let Some(metric) = metric_rx.blocking_recv() {
+            self.record(&metric);
+        }
+        debug!("Metric channel closed, MetricRegistry recorder stopping");
+    }
+
+    /// Spawn a task that records every [`Metric`] received until the channel is closed.
+    ///
+    /// Must be called from within a tokio runtime.
+    pub fn spawn_recorder(&self, metric_rx: mpsc::UnboundedReceiver<Metric>) -> JoinHandle<()> {
+        tokio::spawn(self.clone().record_from(metric_rx))
+    }
+
+    /// Copy every series aggregated so far.
+    pub fn snapshot(&self) -> MetricSnapshot {
+        let state = self.state.lock().expect("MetricRegistry Mutex poisoned");
+        let mut snapshot = MetricSnapshot {
+            histograms: state.histograms.clone(),
+            counters: state.counters.clone(),
+        };
+
+        for (key, handle) in &state.histogram_handles {
+            snapshot
+                .histograms
+                .entry(key.clone())
+                .or_insert_with(|| Histogram::new(&self.buckets))
+                .merge(&handle.load());
+        }
+
+        for (key, handle) in &state.counter_handles {
+            *snapshot.counters.entry(key.clone()).or_default() += handle.0.load(Ordering::Relaxed);
+        }
+
+        snapshot
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metric::Field;
+
+    #[test]
+    fn test_histogram_observe() {
+        let mut histogram = Histogram::new(&[10.0, 100.0]);
+
+        for value in [5.0, 10.0, 50.0, 500.0] {
+            histogram.observe(value);
+        }
+
+        assert_eq!(histogram.count(), 4);
+        assert_eq!(histogram.sum(), 565.0);
+        assert_eq!(histogram.mean(), Some(141.25));
+        assert_eq!(
+            histogram.buckets(),
+            vec![(10.0, 2), (100.0, 3), (f64::INFINITY, 4)]
+        );
+    }
+
+    #[test]
+    fn test_metric_registry_record() {
+        struct TestCase {
+            input: Metric,
+            expected_name: &'static str,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: metric name already ends with field key
+                input: Metric {
+                    name: "http_request_duration",
+                    time: 0,
+                    tags: vec![
+                        Tag::new("status_code", "200"),
+                        Tag::new("http_method", "GET"),
+                    ],
+                    fields: vec![Field::new("duration", 20_u64)],
+                },
+                expected_name: "http_request_duration",
+            },
+            TestCase {
+                // TC1: field key appended to metric name
+                input: Metric {
+                    name: "ws_message",
+                    time: 0,
+                    tags: vec![Tag::new("exchange", "binance_spot")],
+                    fields: vec![
+                        Field::new("latency", 5.5),
+                        Field::new("ignored", "non numeric".to_string()),
+                    ],
+                },
+                expected_name: "ws_message_latency",
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let registry = MetricRegistry::new();
+            registry.record(&test.input);
+            registry.record(&test.input);
+
+            let snapshot = registry.snapshot();
+            assert_eq!(snapshot.histograms.len(), 1, "TC{} failed", index);
+
+            let key = SeriesKey::new(test.expected_name, test.input.tags.clone());
+            assert_eq!(
+                snapshot.histograms.get(&key).map(Histogram::count),
+                Some(2),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_metric_registry_shared_between_clones() {
+        let registry = MetricRegistry::new();
+        let (metric_tx, metric_rx) = mpsc::unbounded_channel();
+        let recorder = registry.spawn_recorder(metric_rx);
+
+        metric_tx
+            .send(Metric {
+                name: "http_request_duration",
+                time: 0,
+                tags: vec![],
+                fields: vec![Field::new("duration", 15_u64)],
+            })
+            .unwrap();
+        drop(metric_tx);
+        recorder.await.unwrap();
+
+        registry.clone().increment("ws_messages", vec![], 3);
+
+        let snapshot = registry.snapshot();
+        assert_eq!(
+            snapshot.histograms[&SeriesKey::new("http_request_duration", vec![])].sum(),
+            15.0
+        );
+        assert_eq!(snapshot.counters[&SeriesKey::new("ws_messages", vec![])], 3);
+    }
+
+    #[test]
+    fn test_metric_registry_handles_included_in_snapshot() {
+        let registry = MetricRegistry::with_buckets(&[10.0, 100.0]);
+        let tags = vec![Tag::new("exchange", "binance_spot")];
+
+        let histogram = registry.histogram("ws_message_latency", tags.clone());
+        let counter = registry.counter("ws_messages", tags.clone());
+        for value in [5.0, 50.0] {
+            histogram.observe(value);
+            counter.increment(1);
+        }
+
+        // Handles to the same series share their observations with the Mutex recorded series
+        registry
+            .histogram("ws_message_latency", tags.clone())
+            .observe(500.0);
+        registry.observe("ws_message_latency", tags.clone(), 5.0);
+        registry.increment("ws_messages", tags.clone(), 1);
+
+        let snapshot = registry.snapshot();
+        let latency = &snapshot.histograms[&SeriesKey::new("ws_message_latency", tags.clone())];
+        assert_eq!(latency.count(), 4);
+        assert_eq!(latency.sum(), 560.0);
+        assert_eq!(
+            latency.buckets(),
+            vec![(10.0, 2), (100.0, 3), (f64::INFINITY, 4)]
+        );
+        assert_eq!(snapshot.counters[&SeriesKey::new("ws_messages", tags)], 3);
+    }
+
+    #[test]
+    fn test_metric_registry_global_sender_outside_runtime() {
+        let metric_tx = MetricRegistry::global_sender();
+        assert!(metric_tx.same_channel(&MetricRegistry::global_sender()));
+
+        metric_tx
+            .send(Metric {
+                name: "global_sender_test_duration",
+                time: 0,
+                tags: vec![],
+                fields: vec![Field::new("duration", 1_u64)],
+            })
+            .unwrap();
+    }
+}
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter};
 
 pub mod kind;
+pub mod spec;
 pub mod symbol;
 
 /// Barter representation of an `Instrument`. Used to uniquely identify a `base_quote` pair, and it's
@@ -0,0 +1,383 @@
+use crate::{
+    error::SpecError,
+    model::{instrument::Instrument, Side},
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fs::File, io::BufReader, path::Path};
+
+/// Exchange trading rules for an [`Instrument`], used to round and validate order prices and
+/// quantities before they are submitted.
+///
+/// eg/ InstrumentSpec { instrument: btc_usdt_spot, tick_size: 0.01, lot_size: 0.00001, .. }
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct InstrumentSpec {
+    pub instrument: Instrument,
+    /// Minimum price increment.
+    pub tick_size: Decimal,
+    /// Minimum quantity increment.
+    pub lot_size: Decimal,
+    /// Minimum order quantity.
+    #[serde(default)]
+    pub min_quantity: Decimal,
+    /// Minimum order value (`price * quantity * contract_multiplier`).
+    #[serde(default)]
+    pub min_notional: Decimal,
+    /// Quantity of the underlying represented by one contract. One for spot instruments.
+    #[serde(default = "default_contract_multiplier")]
+    pub contract_multiplier: Decimal,
+}
+
+fn default_contract_multiplier() -> Decimal {
+    Decimal::ONE
+}
+
+impl InstrumentSpec {
+    /// Constructs a new [`InstrumentSpec`] with no minimum quantity or notional, and a contract
+    /// multiplier of one.
+    pub fn new<I>(instrument: I, tick_size: Decimal, lot_size: Decimal) -> Self
+    where
+        I: Into<Instrument>,
+    {
+        Self {
+            instrument: instrument.into(),
+            tick_size,
+            lot_size,
+            min_quantity: Decimal::ZERO,
+            min_notional: Decimal::ZERO,
+            contract_multiplier: Decimal::ONE,
+        }
+    }
+
+    /// Round a price to a multiple of the `tick_size`, never making it more aggressive.
+    ///
+    /// [`Side::Buy`] prices are rounded down and [`Side::Sell`] prices are rounded up.
+    pub fn round_price(&self, price: Decimal, side: Side) -> Decimal {
+        if self.tick_size.is_zero() {
+            return price;
+        }
+
+        let ticks = price / self.tick_size;
+        let ticks = match side {
+            Side::Buy => ticks.floor(),
+            Side::Sell => ticks.ceil(),
+        };
+
+        ticks * self.tick_size
+    }
+
+    /// Round a quantity down to a multiple of the `lot_size`.
+    pub fn round_quantity(&self, quantity: Decimal) -> Decimal {
+        if self.lot_size.is_zero() {
+            return quantity;
+        }
+
+        (quantity / self.lot_size).floor() * self.lot_size
+    }
+
+    /// Calculate the notional value of an order with the provided price and quantity.
+    pub fn notional(&self, price: Decimal, quantity: Decimal) -> Decimal {
+        price * quantity * self.contract_multiplier
+    }
+
+    /// Validate the price is a multiple of the `tick_size`.
+    pub fn validate_price(&self, price: Decimal) -> Result<(), SpecError> {
+        match self.tick_size.is_zero() || (price % self.tick_size).is_zero() {
+            true => Ok(()),
+            false => Err(SpecError::TickSize {
+                price,
+                tick_size: self.tick_size,
+            }),
+        }
+    }
+
+    /// Validate the quantity is a multiple of the `lot_size`, and at least the `min_quantity`.
+    pub fn validate_quantity(&self, quantity: Decimal) -> Result<(), SpecError> {
+        if !self.lot_size.is_zero() && !(quantity % self.lot_size).is_zero() {
+            return Err(SpecError::LotSize {
+                quantity,
+                lot_size: self.lot_size,
+            });
+        }
+
+        match quantity >= self.min_quantity {
+            true => Ok(()),
+            false => Err(SpecError::MinQuantity {
+                quantity,
+                min_quantity: self.min_quantity,
+            }),
+        }
+    }
+
+    /// Validate the notional value of an order is at least the `min_notional`.
+    pub fn validate_notional(&self, price: Decimal, quantity: Decimal) -> Result<(), SpecError> {
+        let notional = self.notional(price, quantity);
+        match notional >= self.min_notional {
+            true => Ok(()),
+            false => Err(SpecError::MinNotional {
+                notional,
+                min_notional: self.min_notional,
+            }),
+        }
+    }
+
+    /// Validate an order price and quantity satisfy every rule of this [`InstrumentSpec`].
+    pub fn validate(&self, price: Decimal, quantity: Decimal) -> Result<(), SpecError> {
+        self.validate_price(price)?;
+        self.validate_quantity(quantity)?;
+        self.validate_notional(price, quantity)
+    }
+}
+
+/// Registry of [`InstrumentSpec`]s for the [`Instrument`]s of a single exchange.
+///
+/// Populated from exchange REST endpoints, or a local JSON file containing an array of
+/// [`InstrumentSpec`]s.
+#[derive(Clone, Eq, PartialEq, Debug, Default, Deserialize, Serialize)]
+#[serde(from = "Vec<InstrumentSpec>", into = "Vec<InstrumentSpec>")]
+pub struct InstrumentSpecs(HashMap<Instrument, InstrumentSpec>);
+
+impl InstrumentSpecs {
+    /// Construct a new empty [`InstrumentSpecs`] registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Load an [`InstrumentSpecs`] registry from a JSON file containing an array of
+    /// [`InstrumentSpec`]s.
+    pub fn from_json_file<P>(path: P) -> Result<Self, SpecError>
+    where
+        P: AsRef<Path>,
+    {
+        let reader = BufReader::new(File::open(path)?);
+        serde_json::from_reader(reader).map_err(SpecError::from)
+    }
+
+    /// Insert an [`InstrumentSpec`], returning the previous [`InstrumentSpec`] for the same
+    /// [`Instrument`], if any.
+    pub fn insert(&mut self, spec: InstrumentSpec) -> Option<InstrumentSpec> {
+        self.0.insert(spec.instrument.clone(), spec)
+    }
+
+    /// Return the [`InstrumentSpec`] associated with the provided [`Instrument`], if any.
+    pub fn get(&self, instrument: &Instrument) -> Option<&InstrumentSpec> {
+        self.0.get(instrument)
+    }
+
+    /// Return an iterator over every [`InstrumentSpec`] in the registry.
+    pub fn iter(&self) -> impl Iterator<Item = &InstrumentSpec> {
+        self.0.values()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl FromIterator<InstrumentSpec> for InstrumentSpecs {
+    fn from_iter<Iter>(iter: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = InstrumentSpec>,
+    {
+        Self(
+            iter.into_iter()
+                .map(|spec| (spec.instrument.clone(), spec))
+                .collect(),
+        )
+    }
+}
+
+impl Extend<InstrumentSpec> for InstrumentSpecs {
+    fn extend<Iter>(&mut self, iter: Iter)
+    where
+        Iter: IntoIterator<Item = InstrumentSpec>,
+    {
+        for spec in iter {
+            self.insert(spec);
+        }
+    }
+}
+
+impl From<Vec<InstrumentSpec>> for InstrumentSpecs {
+    fn from(specs: Vec<InstrumentSpec>) -> Self {
+        specs.into_iter().collect()
+    }
+}
+
+impl From<InstrumentSpecs> for Vec<InstrumentSpec> {
+    fn from(specs: InstrumentSpecs) -> Self {
+        specs.0.into_values().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::model::instrument::kind::InstrumentKind;
+    use rust_decimal_macros::dec;
+
+    fn spec() -> InstrumentSpec {
+        InstrumentSpec {
+            instrument: Instrument::from(("btc", "usdt", InstrumentKind::Perpetual)),
+            tick_size: dec!(0.10),
+            lot_size: dec!(0.001),
+            min_quantity: dec!(0.001),
+            min_notional: dec!(5),
+            contract_multiplier: dec!(1),
+        }
+    }
+
+    #[test]
+    fn test_round_price() {
+        struct TestCase {
+            price: Decimal,
+            side: Side,
+            expected: Decimal,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Buy price rounded down to the tick size
+                price: dec!(30000.17),
+                side: Side::Buy,
+                expected: dec!(30000.1),
+            },
+            TestCase {
+                // TC1: Sell price rounded up to the tick size
+                price: dec!(30000.11),
+                side: Side::Sell,
+                expected: dec!(30000.2),
+            },
+            TestCase {
+                // TC2: price already a multiple of the tick size is unchanged
+                price: dec!(30000.3),
+                side: Side::Sell,
+                expected: dec!(30000.3),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = spec().round_price(test.price, test.side);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_round_quantity() {
+        assert_eq!(spec().round_quantity(dec!(0.0129)), dec!(0.012));
+        assert_eq!(spec().round_quantity(dec!(0.0009)), dec!(0));
+
+        let no_lot_size = InstrumentSpec::new(spec().instrument, dec!(0.1), dec!(0));
+        assert_eq!(no_lot_size.round_quantity(dec!(0.0129)), dec!(0.0129));
+    }
+
+    #[test]
+    fn test_validate() {
+        struct TestCase {
+            price: Decimal,
+            quantity: Decimal,
+            expected: Result<(), SpecError>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: valid price & quantity
+                price: dec!(30000.1),
+                quantity: dec!(0.002),
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC1: price is not a multiple of the tick size
+                price: dec!(30000.15),
+                quantity: dec!(0.002),
+                expected: Err(SpecError::TickSize {
+                    price: dec!(30000.15),
+                    tick_size: dec!(0.10),
+                }),
+            },
+            TestCase {
+                // TC2: quantity is not a multiple of the lot size
+                price: dec!(30000.1),
+                quantity: dec!(0.0025),
+                expected: Err(SpecError::LotSize {
+                    quantity: dec!(0.0025),
+                    lot_size: dec!(0.001),
+                }),
+            },
+            TestCase {
+                // TC3: quantity below the minimum quantity
+                price: dec!(30000.1),
+                quantity: dec!(0),
+                expected: Err(SpecError::MinQuantity {
+                    quantity: dec!(0),
+                    min_quantity: dec!(0.001),
+                }),
+            },
+            TestCase {
+                // TC4: notional below the minimum notional
+                price: dec!(1000),
+                quantity: dec!(0.001),
+                expected: Err(SpecError::MinNotional {
+                    notional: dec!(1.000),
+                    min_notional: dec!(5),
+                }),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = spec().validate(test.price, test.quantity);
+            match (actual, test.expected) {
+                (Ok(()), Ok(())) => {
+                    // Test passed
+                }
+                (Err(actual), Err(expected)) => {
+                    assert_eq!(
+                        actual.to_string(),
+                        expected.to_string(),
+                        "TC{} failed",
+                        index
+                    )
+                }
+                (actual, expected) => {
+                    // Test failed
+                    panic!("TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_de_instrument_specs() {
+        let input = r#"[
+            {
+                "instrument": {"base": "btc", "quote": "usdt", "instrument_kind": "perpetual"},
+                "tick_size": "0.10",
+                "lot_size": "0.001",
+                "min_quantity": "0.001",
+                "min_notional": "5"
+            },
+            {
+                "instrument": {"base": "eth", "quote": "usdt", "instrument_kind": "spot"},
+                "tick_size": "0.01",
+                "lot_size": "0.0001"
+            }
+        ]"#;
+
+        let actual = serde_json::from_str::<InstrumentSpecs>(input).unwrap();
+
+        assert_eq!(actual.len(), 2);
+        assert_eq!(actual.get(&spec().instrument), Some(&spec()));
+        assert_eq!(
+            actual.get(&Instrument::from(("eth", "usdt", InstrumentKind::Spot))),
+            Some(&InstrumentSpec::new(
+                ("eth", "usdt", InstrumentKind::Spot),
+                dec!(0.01),
+                dec!(0.0001)
+            ))
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+
 /// Encodes bytes data.
 pub trait Encoder {
     /// Encodes the bytes data into some `String` format.
@@ -18,3 +20,66 @@ impl Encoder for HexEncoder {
         hex::encode(data)
     }
 }
+
+/// Encodes bytes data as a standard (padded) Base64 `String`.
+#[derive(Debug, Copy, Clone)]
+pub struct Base64Encoder;
+
+impl Encoder for Base64Encoder {
+    fn encode<Bytes>(&self, data: Bytes) -> String
+    where
+        Bytes: AsRef<[u8]>,
+    {
+        STANDARD.encode(data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encoders() {
+        struct TestCase {
+            input: &'static [u8],
+            expected_hex: &'static str,
+            expected_base64: &'static str,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: empty input
+                input: b"",
+                expected_hex: "",
+                expected_base64: "",
+            },
+            TestCase {
+                // TC1: input requiring Base64 padding
+                input: b"bart",
+                expected_hex: "62617274",
+                expected_base64: "YmFydA==",
+            },
+            TestCase {
+                // TC2: non utf8 input
+                input: &[0xfb, 0xff, 0x00],
+                expected_hex: "fbff00",
+                expected_base64: "+/8A",
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(
+                HexEncoder.encode(test.input),
+                test.expected_hex,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                Base64Encoder.encode(test.input),
+                test.expected_base64,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
@@ -1,12 +1,23 @@
-use self::encoder::{Encoder, HexEncoder};
+use self::encoder::{Base64Encoder, Encoder, HexEncoder};
 use super::{rest::RestRequest, BuildStrategy};
 use crate::error::SocketError;
+use base64::{engine::general_purpose::STANDARD, Engine};
 use bytes::Bytes;
 use hmac::{Hmac, Mac};
 
 /// Implementations for encoding signatures generated by a [`RequestSigner`].
 pub mod encoder;
 
+/// Exchange specific pre-hashing of the bytes to sign (eg/ Kraken nonce + SHA256), and a
+/// monotonic [`Nonce`](prehash::Nonce) generator.
+pub mod prehash;
+
+/// Asymmetric [`SigningKey`]s (eg/ Ed25519 & RSA) used by APIs that do not sign with a HMAC.
+pub mod key;
+
+/// Server time synchronisation used to generate [`Signer`] timestamps in exchange server time.
+pub mod time_sync;
+
 /// API specific signing logic used by a [`RequestSigner`].
 #[allow(clippy::needless_lifetimes)]
 pub trait Signer {
@@ -78,19 +89,40 @@ pub trait Signer {
     ) -> Result<reqwest::Request, SocketError>;
 }
 
-/// Generically signs Http [`RestRequest`]s utilising API specific [`Signer`] logic, a hashable
-/// [`Mac`], and a signature [`Encoder`].
+/// Cryptographic key that generates a signature from the bytes to sign.
+///
+/// Implemented for every hashable [`Mac`] (eg/ `Hmac<Sha256>`), as well as the asymmetric
+/// [`key::Ed25519Key`] & [`key::RsaKey`].
+pub trait SigningKey {
+    /// Generate the signature of the provided bytes.
+    fn sign(&self, bytes_to_sign: &[u8]) -> Vec<u8>;
+}
+
+impl<M> SigningKey for M
+where
+    M: Mac + Clone,
+{
+    fn sign(&self, bytes_to_sign: &[u8]) -> Vec<u8> {
+        // Update Mac state & finalise bytes
+        let mut mac = self.clone();
+        mac.update(bytes_to_sign);
+        mac.finalize().into_bytes().to_vec()
+    }
+}
+
+/// Generically signs Http [`RestRequest`]s utilising API specific [`Signer`] logic, a
+/// [`SigningKey`] (eg/ a hashable [`Mac`]), and a signature [`Encoder`].
 #[derive(Debug, Copy, Clone)]
-pub struct RequestSigner<Sig, Hmac, SigEncoder> {
+pub struct RequestSigner<Sig, Key, SigEncoder> {
     signer: Sig,
-    mac: Hmac,
+    key: Key,
     encoder: SigEncoder,
 }
 
-impl<Sig, Hmac, SigEncoder> BuildStrategy for RequestSigner<Sig, Hmac, SigEncoder>
+impl<Sig, Key, SigEncoder> BuildStrategy for RequestSigner<Sig, Key, SigEncoder>
 where
     Sig: Signer,
-    Hmac: Mac + Clone,
+    Key: SigningKey,
     SigEncoder: Encoder,
 {
     fn build<Request>(
@@ -107,24 +139,22 @@ where
         // Generate bytes data used to update Mac state
         let bytes_to_sign = Sig::bytes_to_sign(&config);
 
-        // Update Mac state & finalise bytes
-        let mut mac = self.mac.clone();
-        mac.update(&bytes_to_sign);
-        let bytes_to_encode = mac.finalize().into_bytes();
+        // Generate signature bytes using the SigningKey
+        let bytes_to_encode = self.key.sign(&bytes_to_sign);
 
-        // Encode signature from Mac bytes
+        // Encode signature bytes
         let signature = self.encoder.encode(bytes_to_encode);
 
         Sig::build_signed_request(config, builder, signature)
     }
 }
 
-impl<Sig, Hmac, SigEncoder> RequestSigner<Sig, Hmac, SigEncoder> {
+impl<Sig, Key, SigEncoder> RequestSigner<Sig, Key, SigEncoder> {
     /// Construct a new [`Self`] using the provided API specific configuration.
-    pub fn new(signer: Sig, mac: Hmac, encoder: SigEncoder) -> Self {
+    pub fn new(signer: Sig, key: Key, encoder: SigEncoder) -> Self {
         Self {
             signer,
-            mac,
+            key,
             encoder,
         }
     }
@@ -138,3 +168,30 @@ pub fn get_default_signer<SignerImpl>(
     let encoder = HexEncoder;
     RequestSigner::new(signer, mac, encoder)
 }
+
+/// Construct a [`RequestSigner`] that signs with a HMAC-SHA256 keyed by the raw `api_secret`, and
+/// encodes signatures as Base64 (eg/ OKX).
+pub fn get_base64_signer<SignerImpl>(
+    api_secret: &str,
+    signer: SignerImpl,
+) -> RequestSigner<SignerImpl, Hmac<sha2::Sha256>, Base64Encoder> {
+    let mac: Hmac<sha2::Sha256> = Hmac::new_from_slice(api_secret.as_bytes()).unwrap();
+    RequestSigner::new(signer, mac, Base64Encoder)
+}
+
+/// Construct a [`RequestSigner`] that signs with a `Mac` keyed by the Base64 decoded
+/// `api_secret`, and encodes signatures as Base64 (eg/ Kraken HMAC-SHA512, Coinbase HMAC-SHA256).
+pub fn get_base64_secret_signer<SignerImpl, M>(
+    api_secret: &str,
+    signer: SignerImpl,
+) -> Result<RequestSigner<SignerImpl, M, Base64Encoder>, SocketError>
+where
+    M: Mac + hmac::digest::KeyInit,
+{
+    let secret = STANDARD
+        .decode(api_secret)
+        .map_err(|error| SocketError::SigningKey(format!("secret is not Base64: {error}")))?;
+    let mac = <M as Mac>::new_from_slice(&secret)
+        .map_err(|error| SocketError::SigningKey(error.to_string()))?;
+    Ok(RequestSigner::new(signer, mac, Base64Encoder))
+}
@@ -0,0 +1,249 @@
+use crate::protocol::http::{
+    rest::{client::RestClient, RestRequest},
+    BuildStrategy, HttpParser,
+};
+use chrono::Utc;
+use std::{
+    fmt::Debug,
+    sync::{
+        atomic::{AtomicI64, AtomicU64, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+use tracing::{debug, warn};
+
+/// Default interval between [`TimeSync`] synchronisations.
+pub const DEFAULT_TIME_SYNC_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Default number of server time samples taken per [`TimeSync`] synchronisation.
+pub const DEFAULT_TIME_SYNC_SAMPLES: usize = 3;
+
+/// [`RestRequest::Response`] of an exchange server time endpoint.
+pub trait ServerTime {
+    /// Server time in milliseconds since the epoch.
+    fn server_time_millis(&self) -> i64;
+}
+
+/// Estimated offset between the server clock and the local clock, shared by every clone.
+///
+/// Used by a [`Signer`](super::Signer) to generate request timestamps in server time.
+#[derive(Debug, Clone, Default)]
+pub struct TimeOffset {
+    offset_ms: Arc<AtomicI64>,
+    round_trip_ms: Arc<AtomicU64>,
+}
+
+impl TimeOffset {
+    /// Construct a [`TimeOffset`] that assumes the server & local clocks are synchronised.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Estimated server time minus local time, in milliseconds.
+    pub fn offset_millis(&self) -> i64 {
+        self.offset_ms.load(Ordering::Relaxed)
+    }
+
+    /// Round trip [`Duration`] of the [`TimeSample`] the current offset was estimated from.
+    pub fn round_trip(&self) -> Duration {
+        Duration::from_millis(self.round_trip_ms.load(Ordering::Relaxed))
+    }
+
+    /// Estimated server time now, in milliseconds since the epoch.
+    pub fn server_time_millis(&self) -> i64 {
+        Utc::now().timestamp_millis() + self.offset_millis()
+    }
+
+    /// Update the offset from the provided [`TimeSample`].
+    pub fn update(&self, sample: TimeSample) {
+        self.offset_ms.store(sample.offset_ms, Ordering::Relaxed);
+        self.round_trip_ms
+            .store(sample.round_trip_ms, Ordering::Relaxed);
+    }
+}
+
+/// Single server time measurement.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct TimeSample {
+    /// Estimated server time minus local time, in milliseconds.
+    pub offset_ms: i64,
+    /// Request round trip, in milliseconds.
+    pub round_trip_ms: u64,
+}
+
+impl TimeSample {
+    /// Estimate the clock offset assuming the server time was generated half way through the
+    /// request round trip.
+    pub fn estimate(sent_ms: i64, received_ms: i64, server_ms: i64) -> Self {
+        let round_trip_ms = received_ms.saturating_sub(sent_ms).max(0);
+        Self {
+            offset_ms: server_ms - (sent_ms + round_trip_ms / 2),
+            round_trip_ms: round_trip_ms as u64,
+        }
+    }
+}
+
+/// Service that periodically queries an exchange server time endpoint and updates a shared
+/// [`TimeOffset`].
+///
+/// Each synchronisation takes several samples and keeps the one with the shortest round trip,
+/// since it has the smallest estimation error.
+#[derive(Debug)]
+pub struct TimeSync<Strategy, Parser, Request> {
+    pub client: RestClient<Strategy, Parser>,
+    pub request: Request,
+    pub offset: TimeOffset,
+    pub interval: Duration,
+    pub samples: usize,
+}
+
+impl<Strategy, Parser, Request> TimeSync<Strategy, Parser, Request>
+where
+    Strategy: BuildStrategy,
+    Parser: HttpParser,
+    Parser::OutputError: Debug,
+    Request: RestRequest + Clone,
+    Request::Response: ServerTime + Debug,
+{
+    /// Construct a new [`TimeSync`] using the default interval & number of samples.
+    pub fn new(client: RestClient<Strategy, Parser>, request: Request, offset: TimeOffset) -> Self {
+        Self {
+            client,
+            request,
+            offset,
+            interval: DEFAULT_TIME_SYNC_INTERVAL,
+            samples: DEFAULT_TIME_SYNC_SAMPLES,
+        }
+    }
+
+    /// Set the interval between synchronisations.
+    pub fn with_interval(self, interval: Duration) -> Self {
+        Self { interval, ..self }
+    }
+
+    /// Query the server time endpoint, and update the [`TimeOffset`] from the sample with the
+    /// shortest round trip.
+    pub async fn sync(&self) -> Result<TimeSample, Parser::OutputError> {
+        let mut best: Option<TimeSample> = None;
+
+        for _ in 0..self.samples.max(1) {
+            let sent_ms = Utc::now().timestamp_millis();
+            let response = self.client.execute(self.request.clone()).await?;
+            let received_ms = Utc::now().timestamp_millis();
+
+            let sample = TimeSample::estimate(sent_ms, received_ms, response.server_time_millis());
+
+            if best.is_none_or(|best| sample.round_trip_ms < best.round_trip_ms) {
+                best = Some(sample);
+            }
+        }
+
+        let best = best.expect("at least one TimeSample is taken");
+        debug!(
+            offset_ms = best.offset_ms,
+            round_trip_ms = best.round_trip_ms,
+            "synchronised server time"
+        );
+        self.offset.update(best);
+        Ok(best)
+    }
+
+    /// Synchronise once every interval, starting one interval from now (use [`Self::sync`] to
+    /// initialise the [`TimeOffset`] beforehand). Failed synchronisations are logged and the
+    /// previous [`TimeOffset`] is kept.
+    pub async fn run(self) {
+        let start = tokio::time::Instant::now() + self.interval;
+        let mut interval = tokio::time::interval_at(start, self.interval);
+        loop {
+            interval.tick().await;
+            if let Err(error) = self.sync().await {
+                warn!(
+                    ?error,
+                    "failed to synchronise server time, keeping previous offset"
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_sample_estimate() {
+        struct TestCase {
+            sent_ms: i64,
+            received_ms: i64,
+            server_ms: i64,
+            expected: TimeSample,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: synchronised clocks
+                sent_ms: 1_000,
+                received_ms: 1_100,
+                server_ms: 1_050,
+                expected: TimeSample {
+                    offset_ms: 0,
+                    round_trip_ms: 100,
+                },
+            },
+            TestCase {
+                // TC1: local clock ahead of server
+                sent_ms: 2_000,
+                received_ms: 2_040,
+                server_ms: 1_520,
+                expected: TimeSample {
+                    offset_ms: -500,
+                    round_trip_ms: 40,
+                },
+            },
+            TestCase {
+                // TC2: local clock behind server
+                sent_ms: 2_000,
+                received_ms: 2_040,
+                server_ms: 3_020,
+                expected: TimeSample {
+                    offset_ms: 1_000,
+                    round_trip_ms: 40,
+                },
+            },
+            TestCase {
+                // TC3: local clock stepped backwards mid request
+                sent_ms: 2_000,
+                received_ms: 1_990,
+                server_ms: 2_000,
+                expected: TimeSample {
+                    offset_ms: 0,
+                    round_trip_ms: 0,
+                },
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = TimeSample::estimate(test.sent_ms, test.received_ms, test.server_ms);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_time_offset_shared_between_clones() {
+        let offset = TimeOffset::new();
+        let clone = offset.clone();
+
+        clone.update(TimeSample {
+            offset_ms: -1_500,
+            round_trip_ms: 20,
+        });
+
+        assert_eq!(offset.offset_millis(), -1_500);
+        assert_eq!(offset.round_trip(), Duration::from_millis(20));
+
+        let local_ms = Utc::now().timestamp_millis();
+        let server_ms = offset.server_time_millis();
+        assert!((local_ms - 1_500 - server_ms).abs() < 1_000);
+    }
+}
@@ -1,11 +1,15 @@
 use crate::{
     error::SocketError,
     metric::{Field, Metric, Tag},
-    protocol::http::{rest::RestRequest, BuildStrategy, HttpParser},
+    protocol::http::{
+        rest::{rate_limit::RateLimiter, retry::RetryPolicy, RestRequest},
+        BuildStrategy, HttpParser,
+    },
 };
 use bytes::Bytes;
 use chrono::Utc;
-use std::fmt::Debug;
+use reqwest::{header::RETRY_AFTER, StatusCode};
+use std::{fmt::Debug, time::Duration};
 use tokio::sync::mpsc;
 use tracing::warn;
 
@@ -37,6 +41,13 @@ pub struct RestClient<Strategy, Parser> {
     /// [`HttpParser`] that deserialises [`RestRequest::Response`]s, and upon failure parses
     /// API errors returned from the server.
     pub parser: Parser,
+
+    /// [`RateLimiter`] that every [`RestRequest`] must acquire capacity from before executing.
+    /// Shared by all clones of this [`RestClient`].
+    pub rate_limiter: RateLimiter,
+
+    /// [`RetryPolicy`] for idempotent [`RestRequest`]s that fail with a transient error.
+    pub retry_policy: RetryPolicy,
 }
 
 impl<'a, Strategy, Parser> RestClient<Strategy, Parser>
@@ -45,6 +56,9 @@ where
     Parser: HttpParser,
 {
     /// Execute the provided [`RestRequest`].
+    ///
+    /// Waits for [`RateLimiter`] capacity before each attempt, and retries idempotent requests
+    /// that fail with a transient error according to the [`RetryPolicy`].
     pub async fn execute<Request>(
         &self,
         request_input: Request,
@@ -53,16 +67,49 @@ where
         Request: RestRequest,
         <Request as RestRequest>::Response: Debug,
     {
-        // Use provided Request to construct a signed reqwest::Request
-        let request = self.build(&request_input)?;
-
-        // Measure request execution
-        let (status, payload) = self
-            .measured_execution::<Request>(request, request_input)
-            .await?;
+        let max_retries = match request_input.idempotent() {
+            true => self.retry_policy.max_retries,
+            false => 0,
+        };
 
-        // Attempt to parse API Success or Error response
-        self.parser.parse::<Request::Response>(status, &payload)
+        let mut attempt = 0;
+        loop {
+            // Wait until the RateLimiter has capacity for this request
+            self.rate_limiter
+                .acquire(request_input.weight(), request_input.order_count())
+                .await;
+
+            // Use provided Request to construct a signed reqwest::Request (re-signed each attempt)
+            let request = self.build(&request_input)?;
+
+            // Measure request execution
+            let result = self
+                .measured_execution::<Request>(request, &request_input)
+                .await;
+
+            // Retry transient failures with backoff
+            let retryable = match &result {
+                Ok((status, _)) => RetryPolicy::is_retryable_status(*status),
+                Err(error) => RetryPolicy::is_retryable_error(error),
+            };
+            if retryable && attempt < max_retries {
+                let backoff = self.retry_policy.backoff(attempt);
+                warn!(
+                    attempt,
+                    ?backoff,
+                    metric_tag = ?request_input.metric_tag(),
+                    "retrying HTTP request after transient failure"
+                );
+                tokio::time::sleep(backoff).await;
+                attempt += 1;
+                continue;
+            }
+
+            let (status, payload) = result?;
+
+            // Attempt to parse API Success or Error response
+            return self.parser.parse::<Request::Response>(status, &payload);
+        }
     }
 
     /// Use the provided [`RestRequest`] to construct a signed Http [`reqwest::Request`].
@@ -96,11 +143,11 @@ where
     /// Execute the built [`reqwest::Request`] using the [`reqwest::Client`].
     ///
     /// Measures the Http request round trip duration and sends the associated [`Metric`]
-    /// via the [`Metric`] transmitter.
+    /// via the [`Metric`] transmitter. Response headers are used to update the [`RateLimiter`].
     pub async fn measured_execution<Request>(
         &self,
         request: reqwest::Request,
-        request_input: Request, //  this is the original request constructed by client
+        request_input: &Request, //  this is the original request constructed by client
     ) -> Result<(reqwest::StatusCode, Bytes), SocketError>
     where
         Request: RestRequest,
@@ -127,6 +174,25 @@ where
             warn!("failed to send Metric due to dropped channel receiver");
         }
 
+        // Synchronise RateLimiter with server-side usage, and pause if rate limited
+        self.rate_limiter.update(response.headers());
+        if matches!(
+            response.status(),
+            StatusCode::TOO_MANY_REQUESTS | StatusCode::IM_A_TEAPOT
+        ) {
+            let retry_after = response
+                .headers()
+                .get(RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse::<u64>().ok())
+                .map(Duration::from_secs);
+
+            if let Some(retry_after) = retry_after {
+                warn!(status = %response.status(), ?retry_after, "rate limited by server, pausing requests");
+                self.rate_limiter.pause(retry_after);
+            }
+        }
+
         // Extract Status Code & reqwest::Response Bytes
         let status_code = response.status();
         let payload = response.bytes().await?;
@@ -149,6 +215,24 @@ impl<Strategy, Parser> RestClient<Strategy, Parser> {
             metric_tx,
             strategy,
             parser,
+            rate_limiter: RateLimiter::unlimited(),
+            retry_policy: RetryPolicy::default(),
+        }
+    }
+
+    /// Use the provided [`RateLimiter`] for every [`RestRequest`].
+    pub fn with_rate_limiter(self, rate_limiter: RateLimiter) -> Self {
+        Self {
+            rate_limiter,
+            ..self
+        }
+    }
+
+    /// Use the provided [`RetryPolicy`] for idempotent [`RestRequest`]s.
+    pub fn with_retry_policy(self, retry_policy: RetryPolicy) -> Self {
+        Self {
+            retry_policy,
+            ..self
         }
     }
 }
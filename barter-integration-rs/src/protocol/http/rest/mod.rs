@@ -6,6 +6,14 @@ use std::{fmt::Display, marker::PhantomData, time::Duration};
 /// responses.
 pub mod client;
 
+/// Client side [`RateLimiter`](rate_limit::RateLimiter) composed of token buckets for each
+/// exchange [`RateLimit`](rate_limit::RateLimit).
+pub mod rate_limit;
+
+/// [`RetryPolicy`](retry::RetryPolicy) for idempotent [`RestRequest`]s that fail with a transient
+/// error.
+pub mod retry;
+
 /// Default Http [`reqwest::Request`] timeout Duration.
 const DEFAULT_HTTP_REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
 
@@ -45,6 +53,22 @@ pub trait RestRequest {
     fn timeout(&self) -> Duration {
         DEFAULT_HTTP_REQUEST_TIMEOUT
     }
+
+    /// [`RateLimitKind::Weight`](rate_limit::RateLimitKind::Weight) consumed by this request.
+    fn weight(&self) -> u32 {
+        1
+    }
+
+    /// Number of orders placed by this request, consumed from any
+    /// [`RateLimitKind::Orders`](rate_limit::RateLimitKind::Orders) limits.
+    fn order_count(&self) -> u32 {
+        0
+    }
+
+    /// Determines if this request can be safely retried after a transient failure.
+    fn idempotent(&self) -> bool {
+        self.method().is_idempotent()
+    }
 }
 
 #[derive(Debug)]
@@ -54,9 +78,29 @@ pub struct ApiRequest<Response, Body> {
     pub tag_method: &'static str,
     pub body: Option<Body>,
     pub query_params: Option<QueryParams>,
+    pub weight: u32,
+    pub order_count: u32,
     pub response: PhantomData<Response>,
 }
 
+impl<Response, Body> Clone for ApiRequest<Response, Body>
+where
+    Body: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            path: self.path,
+            method: self.method.clone(),
+            tag_method: self.tag_method,
+            body: self.body.clone(),
+            query_params: self.query_params.clone(),
+            weight: self.weight,
+            order_count: self.order_count,
+            response: PhantomData,
+        }
+    }
+}
+
 impl<Response, Body> ApiRequest<Response, Body> {
     pub const fn new(
         path: &'static str,
@@ -69,9 +113,17 @@ impl<Response, Body> ApiRequest<Response, Body> {
             tag_method,
             body: None,
             query_params: None,
+            weight: 1,
+            order_count: 0,
             response: PhantomData,
         }
     }
+
+    /// Set the rate limit weight consumed by this request.
+    pub const fn with_weight(mut self, weight: u32) -> Self {
+        self.weight = weight;
+        self
+    }
 }
 
 impl<Response, Body> RestRequest for ApiRequest<Response, Body>
@@ -107,11 +159,19 @@ where
             None => None,
         }
     }
+
+    fn weight(&self) -> u32 {
+        self.weight
+    }
+
+    fn order_count(&self) -> u32 {
+        self.order_count
+    }
 }
 
 type QueryKey = &'static str;
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Clone, Serialize)]
 pub struct QueryParams(Vec<(String, String)>);
 
 impl QueryParams {
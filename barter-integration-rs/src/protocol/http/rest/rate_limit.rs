@@ -0,0 +1,298 @@
+use reqwest::header::HeaderMap;
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+use tracing::debug;
+
+/// Unit a [`RateLimit`] is measured in.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum RateLimitKind {
+    /// Request weight consumed by every [`RestRequest`](super::RestRequest)
+    /// (eg/ Binance "REQUEST_WEIGHT").
+    Weight,
+    /// Number of orders placed (eg/ Binance "ORDERS").
+    Orders,
+}
+
+/// Fixed window rate limit (eg/ 6000 weight every minute).
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct RateLimit {
+    pub kind: RateLimitKind,
+    pub capacity: u32,
+    pub interval: Duration,
+    /// Optional response header that reports the server-side usage of this limit in the current
+    /// window (eg/ "X-MBX-USED-WEIGHT-1M").
+    pub header: Option<&'static str>,
+}
+
+impl RateLimit {
+    /// Construct a new [`RateLimit`] that is not synchronised with any response header.
+    pub const fn new(kind: RateLimitKind, capacity: u32, interval: Duration) -> Self {
+        Self {
+            kind,
+            capacity,
+            interval,
+            header: None,
+        }
+    }
+
+    /// Synchronise this [`RateLimit`] usage with the provided response header.
+    pub const fn with_header(self, header: &'static str) -> Self {
+        Self {
+            header: Some(header),
+            ..self
+        }
+    }
+}
+
+/// Token bucket tracking the usage of a [`RateLimit`] in the current window.
+#[derive(Debug)]
+struct TokenBucket {
+    limit: RateLimit,
+    used: u32,
+    window_start: Instant,
+}
+
+impl TokenBucket {
+    fn new(limit: RateLimit, now: Instant) -> Self {
+        Self {
+            limit,
+            used: 0,
+            window_start: now,
+        }
+    }
+
+    /// Refill the bucket if the current window has elapsed.
+    fn refresh(&mut self, now: Instant) {
+        if now.duration_since(self.window_start) >= self.limit.interval {
+            self.used = 0;
+            self.window_start = now;
+        }
+    }
+
+    /// Request cost measured in the [`RateLimitKind`] of this bucket.
+    fn cost(&self, weight: u32, order_count: u32) -> u32 {
+        match self.limit.kind {
+            RateLimitKind::Weight => weight,
+            RateLimitKind::Orders => order_count,
+        }
+    }
+
+    /// [`Duration`] to wait until the bucket has capacity for the provided cost. A cost larger
+    /// than the capacity is allowed once the bucket is empty, otherwise it would never proceed.
+    fn wait(&self, cost: u32, now: Instant) -> Duration {
+        if cost == 0 || self.used == 0 || self.used.saturating_add(cost) <= self.limit.capacity {
+            Duration::ZERO
+        } else {
+            self.limit
+                .interval
+                .saturating_sub(now.duration_since(self.window_start))
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct RateLimiterState {
+    buckets: Vec<TokenBucket>,
+    paused_until: Option<Instant>,
+}
+
+/// Client side rate limiter composed of a token bucket for each configured [`RateLimit`].
+///
+/// Bucket usage is synchronised with server-side usage reported in response headers, and all
+/// requests can be paused (eg/ after a 429 with a `Retry-After` header). State is shared by every
+/// clone of a [`RateLimiter`], and so by every clone of the associated
+/// [`RestClient`](super::client::RestClient).
+#[derive(Debug, Clone, Default)]
+pub struct RateLimiter {
+    state: Arc<Mutex<RateLimiterState>>,
+}
+
+impl RateLimiter {
+    /// Construct a new [`RateLimiter`] enforcing the provided [`RateLimit`]s.
+    pub fn new<Limits>(limits: Limits) -> Self
+    where
+        Limits: IntoIterator<Item = RateLimit>,
+    {
+        let now = Instant::now();
+        Self {
+            state: Arc::new(Mutex::new(RateLimiterState {
+                buckets: limits
+                    .into_iter()
+                    .map(|limit| TokenBucket::new(limit, now))
+                    .collect(),
+                paused_until: None,
+            })),
+        }
+    }
+
+    /// Construct a [`RateLimiter`] that never waits, unless paused.
+    pub fn unlimited() -> Self {
+        Self::default()
+    }
+
+    /// Consume the request cost from every token bucket if they all have capacity, otherwise
+    /// return the [`Duration`] to wait before trying again.
+    pub fn try_acquire(&self, weight: u32, order_count: u32) -> Result<(), Duration> {
+        let mut state = self.state.lock().expect("RateLimiter Mutex poisoned");
+        let now = Instant::now();
+
+        if let Some(paused_until) = state.paused_until {
+            match paused_until > now {
+                true => return Err(paused_until - now),
+                false => state.paused_until = None,
+            }
+        }
+
+        let wait = state
+            .buckets
+            .iter_mut()
+            .map(|bucket| {
+                bucket.refresh(now);
+                bucket.wait(bucket.cost(weight, order_count), now)
+            })
+            .max()
+            .unwrap_or(Duration::ZERO);
+
+        if !wait.is_zero() {
+            return Err(wait);
+        }
+
+        for bucket in state.buckets.iter_mut() {
+            bucket.used = bucket.used.saturating_add(bucket.cost(weight, order_count));
+        }
+
+        Ok(())
+    }
+
+    /// Wait until every token bucket has capacity for the request cost, and then consume it.
+    pub async fn acquire(&self, weight: u32, order_count: u32) {
+        while let Err(wait) = self.try_acquire(weight, order_count) {
+            debug!(
+                ?wait,
+                weight, order_count, "rate limited, waiting for capacity"
+            );
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Synchronise token bucket usage with the server-side usage reported in response headers.
+    pub fn update(&self, headers: &HeaderMap) {
+        let mut state = self.state.lock().expect("RateLimiter Mutex poisoned");
+        for bucket in state.buckets.iter_mut() {
+            let used = bucket
+                .limit
+                .header
+                .and_then(|header| headers.get(header))
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse::<u32>().ok());
+
+            if let Some(used) = used {
+                bucket.used = used;
+            }
+        }
+    }
+
+    /// Pause all requests for the provided [`Duration`].
+    pub fn pause(&self, duration: Duration) {
+        let mut state = self.state.lock().expect("RateLimiter Mutex poisoned");
+        let until = Instant::now() + duration;
+        state.paused_until = Some(state.paused_until.map_or(until, |paused| paused.max(until)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reqwest::header::HeaderValue;
+
+    #[test]
+    fn test_rate_limiter_try_acquire() {
+        struct TestCase {
+            weight: u32,
+            order_count: u32,
+            expected_ok: bool,
+        }
+
+        let limiter = RateLimiter::new([
+            RateLimit::new(RateLimitKind::Weight, 10, Duration::from_secs(60)),
+            RateLimit::new(RateLimitKind::Orders, 2, Duration::from_secs(60)),
+        ]);
+
+        let tests = vec![
+            TestCase {
+                // TC0: weight within capacity
+                weight: 5,
+                order_count: 0,
+                expected_ok: true,
+            },
+            TestCase {
+                // TC1: order within both capacities
+                weight: 1,
+                order_count: 1,
+                expected_ok: true,
+            },
+            TestCase {
+                // TC2: weight exceeds remaining capacity
+                weight: 5,
+                order_count: 0,
+                expected_ok: false,
+            },
+            TestCase {
+                // TC3: order within both remaining capacities
+                weight: 1,
+                order_count: 1,
+                expected_ok: true,
+            },
+            TestCase {
+                // TC4: order count exceeds remaining capacity
+                weight: 1,
+                order_count: 1,
+                expected_ok: false,
+            },
+            TestCase {
+                // TC5: weight exactly fills remaining capacity
+                weight: 3,
+                order_count: 0,
+                expected_ok: true,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = limiter.try_acquire(test.weight, test.order_count);
+            assert_eq!(actual.is_ok(), test.expected_ok, "TC{} failed", index);
+            if let Err(wait) = actual {
+                assert!(wait <= Duration::from_secs(60), "TC{} failed", index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rate_limiter_shared_between_clones_and_updated_from_headers() {
+        let limiter =
+            RateLimiter::new([
+                RateLimit::new(RateLimitKind::Weight, 100, Duration::from_secs(60))
+                    .with_header("X-MBX-USED-WEIGHT-1M"),
+            ]);
+        let clone = limiter.clone();
+
+        let mut headers = HeaderMap::new();
+        headers.insert("x-mbx-used-weight-1m", HeaderValue::from_static("99"));
+        clone.update(&headers);
+
+        assert!(limiter.try_acquire(1, 0).is_ok());
+        assert!(limiter.try_acquire(1, 0).is_err());
+        assert!(clone.try_acquire(1, 0).is_err());
+    }
+
+    #[test]
+    fn test_rate_limiter_pause() {
+        let limiter = RateLimiter::unlimited();
+        assert!(limiter.try_acquire(1, 1).is_ok());
+
+        limiter.pause(Duration::from_secs(30));
+        let wait = limiter.try_acquire(1, 1).unwrap_err();
+        assert!(wait > Duration::from_secs(29) && wait <= Duration::from_secs(30));
+    }
+}
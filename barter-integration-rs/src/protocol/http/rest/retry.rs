@@ -0,0 +1,151 @@
+use crate::error::SocketError;
+use reqwest::StatusCode;
+use std::time::Duration;
+
+/// Retry & exponential backoff policy used by a [`RestClient`](super::client::RestClient) for
+/// idempotent [`RestRequest`](super::RestRequest)s that fail with a transient error.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct RetryPolicy {
+    /// Maximum number of retries after the initial attempt.
+    pub max_retries: u32,
+    /// Backoff before the first retry, doubled after each subsequent retry.
+    pub initial_backoff: Duration,
+    /// Maximum backoff between retries.
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(250),
+            max_backoff: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// [`RetryPolicy`] that never retries.
+    pub const fn none() -> Self {
+        Self {
+            max_retries: 0,
+            initial_backoff: Duration::ZERO,
+            max_backoff: Duration::ZERO,
+        }
+    }
+
+    /// Backoff [`Duration`] before the provided retry attempt (zero indexed).
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2_u32.saturating_pow(attempt))
+            .min(self.max_backoff)
+    }
+
+    /// Determines if a response [`StatusCode`] is transient, and so may succeed if retried.
+    ///
+    /// Note that a 418 (IP banned) is not retried since the ban may last far longer than any
+    /// sensible backoff.
+    pub fn is_retryable_status(status: StatusCode) -> bool {
+        status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+    }
+
+    /// Determines if a [`SocketError`] is transient (eg/ timeout), and so may succeed if retried.
+    pub fn is_retryable_error(error: &SocketError) -> bool {
+        match error {
+            SocketError::HttpTimeout(_) => true,
+            SocketError::Http(error) => error.is_connect() || error.is_timeout(),
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_retry_policy_backoff() {
+        struct TestCase {
+            attempt: u32,
+            expected: Duration,
+        }
+
+        let policy = RetryPolicy {
+            max_retries: 10,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(1),
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: first retry uses initial backoff
+                attempt: 0,
+                expected: Duration::from_millis(100),
+            },
+            TestCase {
+                // TC1: backoff doubles
+                attempt: 2,
+                expected: Duration::from_millis(400),
+            },
+            TestCase {
+                // TC2: backoff capped at max
+                attempt: 4,
+                expected: Duration::from_secs(1),
+            },
+            TestCase {
+                // TC3: large attempt does not overflow
+                attempt: 64,
+                expected: Duration::from_secs(1),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(
+                policy.backoff(test.attempt),
+                test.expected,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_retry_policy_is_retryable_status() {
+        struct TestCase {
+            input: StatusCode,
+            expected: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: 429 rate limited
+                input: StatusCode::TOO_MANY_REQUESTS,
+                expected: true,
+            },
+            TestCase {
+                // TC1: 418 IP banned
+                input: StatusCode::IM_A_TEAPOT,
+                expected: false,
+            },
+            TestCase {
+                // TC2: 503 unavailable
+                input: StatusCode::SERVICE_UNAVAILABLE,
+                expected: true,
+            },
+            TestCase {
+                // TC3: 400 bad request
+                input: StatusCode::BAD_REQUEST,
+                expected: false,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(
+                RetryPolicy::is_retryable_status(test.input),
+                test.expected,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+}
@@ -21,6 +21,7 @@ use barter_execution::{
     ExecutionId,
 };
 use dotenv::dotenv;
+use rust_decimal::Decimal;
 
 use barter_data::{
     event::{DataKind, MarketEvent},
@@ -48,7 +49,7 @@ use tracing::info;
 
 struct StrategyExample {
     counter: usize,
-    price: Option<f64>,
+    price: Option<Decimal>,
     // rsi: ta::indicators::RelativeStrengthIndex,
 }
 
@@ -91,11 +92,14 @@ impl strategy::OrderGenerator for StrategyExample {
             ClientOrderId(uuid::Uuid::new_v4()),
             Side::Buy,
             self.price.unwrap(),
-            0.01,
+            Decimal::new(1, 2),
         );
 
         self.counter += 1;
-        Some(vec![(Exchange::from(ExecutionId::Binance), vec![order])])
+        Some(vec![(
+            Exchange::from(ExecutionId::BinanceFuturesUsd),
+            vec![order],
+        )])
     }
 }
 
@@ -104,8 +108,8 @@ fn order_request_limit<I>(
     instrument: I,
     cid: ClientOrderId,
     side: Side,
-    price: f64,
-    quantity: f64,
+    price: Decimal,
+    quantity: Decimal,
 ) -> Order<RequestOpen>
 where
     I: Into<Instrument>,
@@ -119,6 +123,7 @@ where
             kind: OrderKind::Limit,
             price,
             quantity,
+            reduce_only: false,
         },
     }
 }
@@ -242,11 +247,28 @@ async fn init_account_feed(
     event_tx: mpsc::UnboundedSender<Event>,
     exchange_rx: mpsc::UnboundedReceiver<ExecutionRequest>,
 ) {
+    // Forward BinanceExecution user data stream AccountEvents to the Cerebrum EventFeed
+    let (event_account_tx, mut event_account_rx) = mpsc::unbounded_channel();
+    let account_event_tx = event_tx.clone();
+    tokio::spawn(async move {
+        while let Some(account) = event_account_rx.recv().await {
+            if account_event_tx.send(Event::from(account)).is_err() {
+                break;
+            }
+        }
+    });
+
     let mut exchanges = HashMap::new();
     let execution_config = BinanceConfig {
         client_type: BinanceApi::Futures(LiveOrTest::Test),
+        instrument_specs: None,
+        event_account_tx,
+        recv_window: None,
     };
-    exchanges.insert(ExecutionId::Binance, ClientId::Binance(execution_config));
+    exchanges.insert(
+        ExecutionId::BinanceFuturesUsd,
+        ClientId::Binance(execution_config),
+    );
     let ex_portal = ExchangePortal::init(exchanges, exchange_rx, event_tx)
         .await
         .expect("failed to init ExchangePortal");
@@ -306,8 +328,8 @@ fn init_account(instruments: Vec<Instrument>) -> Account {
             (
                 symbol,
                 Balance {
-                    total: 1000.0,
-                    available: 1000.0,
+                    total: Decimal::from(1000),
+                    available: Decimal::from(1000),
                 },
             )
         })
@@ -19,6 +19,7 @@ use barter_execution::{
     ExecutionId,
 };
 use dotenv::dotenv;
+use rust_decimal::Decimal;
 
 use barter_data::{
     event::{DataKind, MarketEvent},
@@ -85,8 +86,8 @@ impl strategy::OrderGenerator for StrategyExample {
             Instrument::new("btc", "usdt", InstrumentKind::Perpetual),
             ClientOrderId(uuid::Uuid::new_v4()),
             Side::Buy,
-            10000.0,
-            0.001,
+            Decimal::from(10000),
+            Decimal::new(1, 3),
         );
 
         self.counter += 1;
@@ -99,8 +100,8 @@ fn order_request_limit<I>(
     instrument: I,
     cid: ClientOrderId,
     side: Side,
-    price: f64,
-    quantity: f64,
+    price: Decimal,
+    quantity: Decimal,
 ) -> Order<RequestOpen>
 where
     I: Into<Instrument>,
@@ -114,6 +115,7 @@ where
             kind: OrderKind::Limit,
             price,
             quantity,
+            reduce_only: false,
         },
     }
 }
@@ -328,8 +330,8 @@ fn init_account(instruments: Vec<Instrument>) -> Account {
             (
                 symbol,
                 Balance {
-                    total: 1000.0,
-                    available: 1000.0,
+                    total: Decimal::from(1000),
+                    available: Decimal::from(1000),
                 },
             )
         })
@@ -300,6 +300,10 @@ async fn listen_to_engine_events(mut event_rx: mpsc::UnboundedReceiver<Event>) {
                 // OrderNew Event occurred in Engine
                 println!("{new_order:?}");
             }
+            Event::OrderRejected(rejection) => {
+                // OrderRejected Event occurred in Engine
+                println!("{rejection:?}");
+            }
             Event::OrderUpdate => {
                 // OrderUpdate Event occurred in Engine
             }
@@ -116,6 +116,7 @@ async fn main() {
             trading_days_per_year: 365,
             risk_free_return: 0.0,
         }))
+        .report_directory("target/reports/engine_with_historic_candles")
         .build()
         .expect("failed to build engine");
 
@@ -161,6 +162,10 @@ async fn listen_to_engine_events(mut event_rx: mpsc::UnboundedReceiver<Event>) {
                 // OrderNew Event occurred in Engine
                 println!("{new_order:?}");
             }
+            Event::OrderRejected(rejection) => {
+                // OrderRejected Event occurred in Engine
+                println!("{rejection:?}");
+            }
             Event::OrderUpdate => {
                 // OrderUpdate Event occurred in Engine
             }
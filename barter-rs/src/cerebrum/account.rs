@@ -1,7 +1,7 @@
 use super::{consume::Consumer, Cerebrum, Engine};
 use barter_data::event::{DataKind, MarketEvent};
 use barter_execution::model::{
-    balance::{Balance, SymbolBalance},
+    balance::{Balance, SymbolBalance, SymbolBalanceTotal},
     order::{Cancelled, InFlight, Open, Order},
     AccountEvent, AccountEventKind, ClientOrderId,
 };
@@ -9,6 +9,7 @@ use barter_integration::model::{
     instrument::{symbol::Symbol, Instrument},
     Exchange,
 };
+use rust_decimal::Decimal;
 use std::collections::HashMap;
 use tracing::{debug, error, info, warn};
 
@@ -33,6 +34,11 @@ impl<Strategy> Cerebrum<AccountUpdater, Strategy> {
                 info!(kind = "Account", exchange = ?account.exchange, payload = ?balances, "received Event");
                 self.accounts.update_balances(&account.exchange, &balances);
             }
+            AccountEventKind::BalanceTotals(totals) => {
+                info!(kind = "Account", exchange = ?account.exchange, payload = ?totals, "received Event");
+                self.accounts
+                    .update_balance_totals(&account.exchange, &totals);
+            }
 
             AccountEventKind::OrdersOpen(orders) => {
                 info!(kind = "Account", exchange = ?account.exchange, payload = "OrdersOpen", "received Event");
@@ -51,6 +57,13 @@ impl<Strategy> Cerebrum<AccountUpdater, Strategy> {
                 // .for_each(|order| self.accounts.update_order_from_new(&order));
             }
 
+            AccountEventKind::OrdersAmended(orders) => {
+                info!(kind = "Account", exchange = ?account.exchange, payload = "OrdersAmended", "received Event");
+                orders
+                    .iter()
+                    .for_each(|order| self.accounts.update_orders_from_amend(order));
+            }
+
             AccountEventKind::OrdersCancelled(cancelled) => {
                 info!(kind = "Account", exchange = ?account.exchange, payload = "OrderCancelled", "received Event");
                 cancelled
@@ -79,6 +92,7 @@ impl<Strategy> From<Cerebrum<AccountUpdater, Strategy>> for Cerebrum<Consumer, S
             accounts: cerebrum.accounts,
             request_tx: cerebrum.request_tx,
             strategy: cerebrum.strategy,
+            risk: cerebrum.risk,
             audit_tx: cerebrum.audit_tx,
         }
     }
@@ -126,6 +140,32 @@ impl Accounts {
             .for_each(|balance| self.update_balance(exchange, balance))
     }
 
+    /// Update the total [`Balance`] of each [`Symbol`], keeping the available balance unchanged.
+    ///
+    /// **Notes:**
+    ///  - Untracked [`Symbol`]s are added with zero available balance, since the venue did not
+    ///    report it. The next full [`SymbolBalance`] update sets the available balance.
+    pub fn update_balance_totals(&mut self, exchange: &Exchange, totals: &[SymbolBalanceTotal]) {
+        let account = self.account(exchange);
+        for total in totals {
+            match account.balances.get_mut(&total.symbol) {
+                Some(balance) => balance.total = total.total,
+                None => {
+                    warn!(
+                        exchange = ?exchange,
+                        symbol = %total.symbol,
+                        action = "adding Balance with zero available balance",
+                        "received SymbolBalanceTotal for untracked Symbol"
+                    );
+                    account.balances.insert(
+                        total.symbol.clone(),
+                        Balance::new(total.total, Decimal::ZERO),
+                    );
+                }
+            }
+        }
+    }
+
     pub fn update_positions(&mut self, _market: &MarketEvent<DataKind>) {
         // Todo: Update relevant Positions
     }
@@ -215,6 +255,40 @@ impl Accounts {
         };
     }
 
+    /// Update relevant [`Exchange`] [`Account`] after receiving an amended [`Order<Open>`].
+    ///
+    /// **Notes:**
+    ///  - Amended orders keep their [`ClientOrderId`], but may have a new
+    ///    [`OrderId`](barter_execution::model::order::OrderId) if the venue cancelled & replaced
+    ///    them.
+    ///  - Only the price & quantity of the previous [`Order<Open>`] are updated, so it keeps the
+    ///    quantity already filled.
+    pub fn update_orders_from_amend(&mut self, order: &Order<Open>) {
+        // Exchange Account associated with the Order
+        let account = self.account(&order.exchange);
+
+        match account.orders_open.get_mut(&order.cid) {
+            Some(open) => {
+                open.apply_amend(order);
+                debug!(
+                    exchange = ?order.exchange,
+                    cid = ?order.cid,
+                    action = "updated previous Order<Open> with amended price & quantity",
+                    "received amended Order<Open>"
+                );
+            }
+            None => {
+                account.orders_open.insert(order.cid, order.clone());
+                warn!(
+                    exchange = ?order.exchange,
+                    cid = ?order.cid,
+                    action = "added to orders_open HashMap",
+                    "received amended Order<Open> for Order not Open"
+                );
+            }
+        };
+    }
+
     /// Update relevant [`Exchange`] [`Account`] after receiving an [`Order<Cancelled>`].
     ///
     /// **Process:**
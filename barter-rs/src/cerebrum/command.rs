@@ -1,19 +1,21 @@
+use crate::cerebrum::consume::Consumer;
 use crate::cerebrum::event::Command;
 use crate::cerebrum::order::{Manual, OrderGenerator};
 use crate::cerebrum::terminate::Terminated;
 use crate::cerebrum::{Cerebrum, Engine};
-use tracing::info;
+use tracing::{info, warn};
 
 /// Commander can transition to:
 ///  a) End
 ///  b) OrderGenerator<Manual>
+///  c) Consumer
 #[derive(Debug, Clone, Copy)]
 pub struct Commander {
     pub command: Command,
 }
 
 impl<Strategy> Cerebrum<Commander, Strategy> {
-    pub fn execute_manual_command(self) -> Engine<Strategy> {
+    pub fn execute_manual_command(mut self) -> Engine<Strategy> {
         // Action Command
         match self.state.command {
             Command::Terminate => {
@@ -44,6 +46,24 @@ impl<Strategy> Cerebrum<Commander, Strategy> {
                 // Todo: Add relevant metadata for the Position to exit
                 Engine::OrderGeneratorManual((Cerebrum::from(self), ()))
             }
+            Command::EngageKillSwitch => {
+                warn!(
+                    kind = "Command",
+                    payload = "EngageKillSwitch",
+                    "received Event"
+                );
+                self.risk.engage_kill_switch();
+                Engine::Consumer(Cerebrum::from(self))
+            }
+            Command::DisengageKillSwitch => {
+                info!(
+                    kind = "Command",
+                    payload = "DisengageKillSwitch",
+                    "received Event"
+                );
+                self.risk.disengage_kill_switch();
+                Engine::Consumer(Cerebrum::from(self))
+            }
         }
     }
 }
@@ -57,6 +77,7 @@ impl<Strategy> From<Cerebrum<Commander, Strategy>> for Cerebrum<Terminated, Stra
             accounts: cerebrum.accounts,
             request_tx: cerebrum.request_tx,
             strategy: cerebrum.strategy,
+            risk: cerebrum.risk,
             audit_tx: cerebrum.audit_tx,
         }
     }
@@ -71,6 +92,22 @@ impl<Strategy> From<Cerebrum<Commander, Strategy>> for Cerebrum<OrderGenerator<M
             accounts: cerebrum.accounts,
             request_tx: cerebrum.request_tx,
             strategy: cerebrum.strategy,
+            risk: cerebrum.risk,
+            audit_tx: cerebrum.audit_tx,
+        }
+    }
+}
+
+/// c) Commander -> Consumer
+impl<Strategy> From<Cerebrum<Commander, Strategy>> for Cerebrum<Consumer, Strategy> {
+    fn from(cerebrum: Cerebrum<Commander, Strategy>) -> Self {
+        Self {
+            state: Consumer,
+            feed: cerebrum.feed,
+            accounts: cerebrum.accounts,
+            request_tx: cerebrum.request_tx,
+            strategy: cerebrum.strategy,
+            risk: cerebrum.risk,
             audit_tx: cerebrum.audit_tx,
         }
     }
@@ -34,6 +34,7 @@ impl<Strategy> From<Cerebrum<Consumer, Strategy>> for Cerebrum<MarketUpdater, St
             accounts: cerebrum.accounts,
             request_tx: cerebrum.request_tx,
             strategy: cerebrum.strategy,
+            risk: cerebrum.risk,
             audit_tx: cerebrum.audit_tx,
         }
     }
@@ -48,6 +49,7 @@ impl<Strategy> From<Cerebrum<Consumer, Strategy>> for Cerebrum<AccountUpdater, S
             accounts: cerebrum.accounts,
             request_tx: cerebrum.request_tx,
             strategy: cerebrum.strategy,
+            risk: cerebrum.risk,
             audit_tx: cerebrum.audit_tx,
         }
     }
@@ -62,6 +64,7 @@ impl<Strategy> From<(Cerebrum<Consumer, Strategy>, Command)> for Cerebrum<Comman
             accounts: cerebrum.accounts,
             request_tx: cerebrum.request_tx,
             strategy: cerebrum.strategy,
+            risk: cerebrum.risk,
             audit_tx: cerebrum.audit_tx,
         }
     }
@@ -21,6 +21,8 @@ pub enum Command {
     FetchOpenPositions,
     ExitPosition,
     ExitAllPositions,
+    EngageKillSwitch,
+    DisengageKillSwitch,
 }
 
 #[derive(Debug)]
@@ -132,6 +132,20 @@ impl ExchangePortal {
                         });
                     });
                 }
+                ExecutionRequest::AmendOrders(amend_requests) => {
+                    amend_requests.into_iter().for_each(|amend_request| {
+                        let exchange = amend_request.0;
+                        let orders = amend_request.1;
+                        let client = self.client(&exchange);
+                        let tx = self.event_tx.clone();
+                        tokio::spawn(async move {
+                            let amended_orders = client.amend_orders(orders).await;
+                            let amended_orders = remove_error_responses(amended_orders);
+                            let account_event = AccountEventKind::OrdersAmended(amended_orders);
+                            Self::send_account_tx(tx, exchange, account_event);
+                        });
+                    });
+                }
                 ExecutionRequest::CancelOrders(cancel_requests) => {
                     cancel_requests.into_iter().for_each(|cancel_request| {
                         let exchange = cancel_request.0;
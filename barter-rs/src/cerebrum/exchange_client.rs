@@ -1,10 +1,14 @@
 use async_trait::async_trait;
 use barter_execution::{
     error::ExecutionError,
-    execution::binance::{BinanceConfig, BinanceExecution},
+    execution::{
+        binance::{BinanceConfig, BinanceExecution},
+        bybit::{BybitConfig, BybitExecution},
+        okx::{OkxConfig, OkxExecution},
+    },
     model::{
         balance::SymbolBalance,
-        order::{Cancelled, Open, Order, RequestCancel, RequestOpen},
+        order::{Cancelled, Open, Order, RequestAmend, RequestCancel, RequestOpen},
     },
     simulated::execution::{SimulatedExecution, SimulationConfig},
     ExecutionClient,
@@ -18,12 +22,16 @@ use barter_integration::model::Exchange;
 pub enum ClientId {
     Simulated(SimulationConfig),
     Binance(BinanceConfig),
+    Bybit(BybitConfig),
+    Okx(OkxConfig),
 }
 
 #[derive(Debug)]
 pub enum ExchangeClient {
     Simulated(SimulatedExecution),
     Binance(BinanceExecution),
+    Bybit(BybitExecution),
+    Okx(OkxExecution),
 }
 
 #[async_trait]
@@ -34,6 +42,8 @@ impl ExecutionClient for ExchangeClient {
         match self {
             ExchangeClient::Simulated(client) => client.exchange(),
             ExchangeClient::Binance(client) => client.exchange(),
+            ExchangeClient::Bybit(client) => client.exchange(),
+            ExchangeClient::Okx(client) => client.exchange(),
         }
     }
 
@@ -47,6 +57,14 @@ impl ExecutionClient for ExchangeClient {
                 let client = BinanceExecution::init(config).await;
                 ExchangeClient::Binance(client)
             }
+            ClientId::Bybit(config) => {
+                let client = BybitExecution::init(config).await;
+                ExchangeClient::Bybit(client)
+            }
+            ClientId::Okx(config) => {
+                let client = OkxExecution::init(config).await;
+                ExchangeClient::Okx(client)
+            }
         }
     }
 
@@ -54,6 +72,8 @@ impl ExecutionClient for ExchangeClient {
         match self {
             ExchangeClient::Simulated(client) => client.fetch_orders_open().await,
             ExchangeClient::Binance(client) => client.fetch_orders_open().await,
+            ExchangeClient::Bybit(client) => client.fetch_orders_open().await,
+            ExchangeClient::Okx(client) => client.fetch_orders_open().await,
         }
     }
 
@@ -61,6 +81,8 @@ impl ExecutionClient for ExchangeClient {
         match self {
             ExchangeClient::Simulated(client) => client.fetch_balances().await,
             ExchangeClient::Binance(client) => client.fetch_balances().await,
+            ExchangeClient::Bybit(client) => client.fetch_balances().await,
+            ExchangeClient::Okx(client) => client.fetch_balances().await,
         }
     }
 
@@ -71,6 +93,8 @@ impl ExecutionClient for ExchangeClient {
         match self {
             ExchangeClient::Simulated(client) => client.open_orders(open_requests).await,
             ExchangeClient::Binance(client) => client.open_orders(open_requests).await,
+            ExchangeClient::Bybit(client) => client.open_orders(open_requests).await,
+            ExchangeClient::Okx(client) => client.open_orders(open_requests).await,
         }
     }
 
@@ -81,6 +105,20 @@ impl ExecutionClient for ExchangeClient {
         match self {
             ExchangeClient::Simulated(client) => client.cancel_orders(cancel_requests).await,
             ExchangeClient::Binance(client) => client.cancel_orders(cancel_requests).await,
+            ExchangeClient::Bybit(client) => client.cancel_orders(cancel_requests).await,
+            ExchangeClient::Okx(client) => client.cancel_orders(cancel_requests).await,
+        }
+    }
+
+    async fn amend_orders(
+        &self,
+        amend_requests: Vec<Order<RequestAmend>>,
+    ) -> Vec<Result<Order<Open>, ExecutionError>> {
+        match self {
+            ExchangeClient::Simulated(client) => client.amend_orders(amend_requests).await,
+            ExchangeClient::Binance(client) => client.amend_orders(amend_requests).await,
+            ExchangeClient::Bybit(client) => client.amend_orders(amend_requests).await,
+            ExchangeClient::Okx(client) => client.amend_orders(amend_requests).await,
         }
     }
 
@@ -88,6 +126,8 @@ impl ExecutionClient for ExchangeClient {
         match self {
             ExchangeClient::Simulated(client) => client.cancel_orders_all().await,
             ExchangeClient::Binance(client) => client.cancel_orders_all().await,
+            ExchangeClient::Bybit(client) => client.cancel_orders_all().await,
+            ExchangeClient::Okx(client) => client.cancel_orders_all().await,
         }
     }
 }
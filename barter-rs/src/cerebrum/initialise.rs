@@ -47,6 +47,7 @@ impl<Strategy> From<Cerebrum<Initialiser, Strategy>> for Cerebrum<Consumer, Stra
             accounts: cerebrum.accounts,
             request_tx: cerebrum.request_tx,
             strategy: cerebrum.strategy,
+            risk: cerebrum.risk,
             audit_tx: cerebrum.audit_tx,
         }
     }
@@ -61,6 +62,7 @@ impl<Strategy> From<Cerebrum<Initialiser, Strategy>> for Cerebrum<Terminated, St
             accounts: cerebrum.accounts,
             request_tx: cerebrum.request_tx,
             strategy: cerebrum.strategy,
+            risk: cerebrum.risk,
             audit_tx: cerebrum.audit_tx,
         }
     }
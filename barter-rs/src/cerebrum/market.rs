@@ -17,6 +17,9 @@ where
         // Update Positions
         self.accounts.update_positions(&market);
 
+        // Update last prices used by pre-trade risk checks
+        self.risk.update_from_market(&market);
+
         // Update Indicators
         self.strategy.update_indicators(&market);
 
@@ -35,6 +38,7 @@ impl<Strategy> From<Cerebrum<MarketUpdater, Strategy>>
             accounts: cerebrum.accounts,
             request_tx: cerebrum.request_tx,
             strategy: cerebrum.strategy,
+            risk: cerebrum.risk,
             audit_tx: cerebrum.audit_tx,
         }
     }
@@ -6,6 +6,7 @@ use self::{
     initialise::Initialiser,
     market::MarketUpdater,
     order::{Algorithmic, Manual, OrderGenerator},
+    risk::PreTradeRisk,
     strategy::IndicatorUpdater,
     terminate::Terminated,
 };
@@ -23,6 +24,7 @@ pub mod exchange_client;
 pub mod initialise;
 pub mod market;
 pub mod order;
+pub mod risk;
 pub mod strategy;
 pub mod terminate;
 
@@ -74,6 +76,7 @@ pub struct Components<Strategy> {
     accounts: Accounts,
     exchange_tx: mpsc::UnboundedSender<ExecutionRequest>,
     strategy: Strategy,
+    risk: PreTradeRisk,
     audit_tx: (),
 }
 
@@ -96,6 +99,7 @@ pub struct Cerebrum<State, Strategy> {
     pub accounts: Accounts,
     pub request_tx: mpsc::UnboundedSender<ExecutionRequest>,
     pub strategy: Strategy,
+    pub risk: PreTradeRisk,
     pub audit_tx: (),
 }
 
@@ -110,6 +114,7 @@ where
             accounts: components.accounts,
             request_tx: components.exchange_tx,
             strategy: components.strategy,
+            risk: components.risk,
             audit_tx: components.audit_tx,
         })
     }
@@ -156,6 +161,7 @@ pub struct EngineBuilder<Strategy> {
     pub accounts: Option<Accounts>,
     pub exchange_tx: Option<mpsc::UnboundedSender<ExecutionRequest>>,
     pub strategy: Option<Strategy>,
+    pub risk: Option<PreTradeRisk>,
     pub audit_tx: Option<()>,
 }
 
@@ -166,6 +172,7 @@ impl<Strategy> EngineBuilder<Strategy> {
             accounts: None,
             exchange_tx: None,
             strategy: None,
+            risk: None,
             audit_tx: None,
         }
     }
@@ -198,6 +205,17 @@ impl<Strategy> EngineBuilder<Strategy> {
         }
     }
 
+    /// Pre-trade risk stage applied to every [`Order<RequestOpen>`] generated by the Strategy.
+    /// Defaults to [`PreTradeRisk::default`], which only enforces available balances.
+    ///
+    /// [`Order<RequestOpen>`]: barter_execution::model::order::Order
+    pub fn risk(self, value: PreTradeRisk) -> Self {
+        Self {
+            risk: Some(value),
+            ..self
+        }
+    }
+
     pub fn audit_tx(self, value: ()) -> Self {
         Self {
             audit_tx: Some(value),
@@ -220,6 +238,7 @@ impl<Strategy> EngineBuilder<Strategy> {
             strategy: self
                 .strategy
                 .ok_or(EngineError::BuilderIncomplete("strategy"))?,
+            risk: self.risk.unwrap_or_default(),
             audit_tx: self
                 .audit_tx
                 .ok_or(EngineError::BuilderIncomplete("audit_tx"))?,
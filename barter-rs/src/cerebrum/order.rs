@@ -1,6 +1,6 @@
 use barter_execution::model::execution_event::ExecutionRequest;
 
-use super::{consume::Consumer, Cerebrum, Engine};
+use super::{consume::Consumer, risk::RiskAudit, Cerebrum, Engine};
 
 /// OrderGenerator can transition to:
 ///  a) Consumer
@@ -27,11 +27,30 @@ where
                 .unwrap()
         }
 
-        // Send OpenOrders Command to ExchangeClient
+        // Send AmendOrders Command to ExchangeClient, after dropping any requests that fail
+        // pre-trade risk checks
+        if let Some(amend_requests) = self.strategy.generate_amends(&self.accounts) {
+            let (amend_requests, audits) = self.risk.check_amends(amend_requests);
+            audits.iter().for_each(RiskAudit::log);
+
+            if !amend_requests.is_empty() {
+                self.request_tx
+                    .send(ExecutionRequest::AmendOrders(amend_requests))
+                    .unwrap();
+            }
+        }
+
+        // Send OpenOrders Command to ExchangeClient, after dropping or trimming any requests that
+        // fail pre-trade risk checks
         if let Some(open_requests) = self.strategy.generate_orders(&self.accounts) {
-            self.request_tx
-                .send(ExecutionRequest::OpenOrders(open_requests))
-                .unwrap();
+            let (open_requests, audits) = self.risk.check_orders(&self.accounts, open_requests);
+            audits.iter().for_each(RiskAudit::log);
+
+            if !open_requests.is_empty() {
+                self.request_tx
+                    .send(ExecutionRequest::OpenOrders(open_requests))
+                    .unwrap();
+            }
         }
 
         Engine::Consumer(Cerebrum::from(self))
@@ -57,6 +76,7 @@ impl<State, Strategy> From<Cerebrum<OrderGenerator<State>, Strategy>>
             accounts: cerebrum.accounts,
             request_tx: cerebrum.request_tx,
             strategy: cerebrum.strategy,
+            risk: cerebrum.risk,
             audit_tx: cerebrum.audit_tx,
         }
     }
@@ -0,0 +1,1090 @@
+use super::account::{Account, Accounts};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_execution::model::{
+    order::{Order, OrderKind, RequestAmend, RequestOpen},
+    ClientOrderId,
+};
+use barter_integration::model::{
+    instrument::{kind::InstrumentKind, symbol::Symbol, Instrument},
+    Exchange, Side,
+};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use thiserror::Error;
+use tracing::warn;
+
+/// Limits enforced by [`PreTradeRisk`] on every [`Order<RequestOpen>`] & [`Order<RequestAmend>`].
+/// Limits that are `None` (or absent for an [`Instrument`]) are not enforced.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct PreTradeRiskConfig {
+    /// Maximum number of open & in flight orders per [`Exchange`] [`Account`].
+    pub max_open_orders: Option<usize>,
+    /// Maximum absolute inventory of an [`Instrument`] base [`Symbol`], including the remaining
+    /// quantity of open orders.
+    pub max_inventory: HashMap<Instrument, Decimal>,
+    /// Maximum quantity of a single order for an [`Instrument`].
+    pub max_order_quantity: HashMap<Instrument, Decimal>,
+    /// Maximum notional value of a single order, denominated in the [`Instrument`] quote
+    /// [`Symbol`].
+    pub max_order_notional: Option<Decimal>,
+    /// Maximum deviation of a request price from the last market price, as a proportion of the
+    /// last market price (eg/ 0.05 for 5%).
+    pub price_band: Option<Decimal>,
+    /// Leverage of a derivative [`Instrument`], used to calculate the quote [`Symbol`] margin
+    /// required by it's orders. Derivative [`Instrument`]s without a leverage use 1x.
+    pub leverage: HashMap<Instrument, Decimal>,
+}
+
+/// Pre-trade risk stage that sits between the Strategy and the
+/// [`ExchangePortal`](super::exchange::ExchangePortal). Validates every [`Order<RequestOpen>`]
+/// against the [`Accounts`] balances & open orders, per [`Instrument`] inventory & order size
+/// limits, fat-finger price bands and a global kill switch. Every [`Order<RequestAmend>`] is
+/// validated against the kill switch, price band & order size limits using it's new price &
+/// quantity.
+///
+/// Derivative (eg/ [`InstrumentKind::Perpetual`]) requests of either [`Side`] require margin in
+/// the quote [`Symbol`], net of the configured leverage, and `reduce_only` requests require no
+/// available balance.
+///
+/// Violating open requests are either dropped or have their quantity trimmed, violating amend
+/// requests are dropped, and a [`RiskAudit`] is produced for each.
+///
+/// Prices are checked according to the [`OrderKind`]:
+///  - [`OrderKind::Market`] requests have no meaningful price, so are valued at the last market
+///    price and are not subject to the price band.
+///  - Stop & take profit requests are checked on their trigger price, and the limit price of
+///    `*Limit` kinds.
+///  - [`OrderKind::TrailingStop`] requests are checked on their activation price, if any.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct PreTradeRisk {
+    pub config: PreTradeRiskConfig,
+    kill_switch: bool,
+    last_prices: HashMap<(Exchange, Instrument), Decimal>,
+}
+
+/// Action taken by [`PreTradeRisk`] on an [`Order<RequestOpen>`] or [`Order<RequestAmend>`] that
+/// violated a limit.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub enum RiskAction {
+    Dropped,
+    Trimmed { from: Decimal, to: Decimal },
+}
+
+/// Reason an [`Order<RequestOpen>`] or [`Order<RequestAmend>`] was dropped or trimmed by
+/// [`PreTradeRisk`].
+#[derive(Error, Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub enum PreTradeViolation {
+    #[error("kill switch engaged")]
+    KillSwitch,
+
+    #[error("{open} open orders already at limit {limit}")]
+    OpenOrders { open: usize, limit: usize },
+
+    #[error("price {price} deviates from last price {last_price} by more than {limit}")]
+    PriceBand {
+        price: Decimal,
+        last_price: Decimal,
+        limit: Decimal,
+    },
+
+    #[error("order quantity {quantity} exceeds limit {limit}")]
+    Quantity { quantity: Decimal, limit: Decimal },
+
+    #[error("order notional {notional} exceeds limit {limit}")]
+    Notional { notional: Decimal, limit: Decimal },
+
+    #[error("order notional cannot be valued without a last market price")]
+    Unpriced,
+
+    #[error("inventory {inventory} would exceed limit {limit}")]
+    Inventory { inventory: Decimal, limit: Decimal },
+
+    #[error("required {required} {symbol} exceeds available {available}")]
+    Balance {
+        symbol: Symbol,
+        required: Decimal,
+        available: Decimal,
+    },
+}
+
+/// Audit record of an [`Order<RequestOpen>`] or [`Order<RequestAmend>`] dropped or trimmed by
+/// [`PreTradeRisk`].
+#[derive(Clone, Eq, PartialEq, Debug, Deserialize, Serialize)]
+pub struct RiskAudit {
+    pub exchange: Exchange,
+    pub instrument: Instrument,
+    pub cid: ClientOrderId,
+    pub action: RiskAction,
+    pub violation: PreTradeViolation,
+}
+
+impl RiskAudit {
+    fn new<State>(
+        request: &Order<State>,
+        action: RiskAction,
+        violation: PreTradeViolation,
+    ) -> Self {
+        Self {
+            exchange: request.exchange.clone(),
+            instrument: request.instrument.clone(),
+            cid: request.cid,
+            action,
+            violation,
+        }
+    }
+
+    /// Log the [`RiskAudit`].
+    pub fn log(&self) {
+        warn!(
+            kind = "PreTradeRisk",
+            exchange = ?self.exchange,
+            instrument = %self.instrument,
+            cid = ?self.cid,
+            action = ?self.action,
+            violation = %self.violation,
+            "Order request failed pre-trade risk check"
+        );
+    }
+}
+
+/// Exposure approved earlier in the same batch of [`Order<RequestOpen>`]s for an [`Exchange`],
+/// which is not yet reflected in the [`Account`].
+#[derive(Debug, Default)]
+struct BatchExposure {
+    orders: usize,
+    reserved: HashMap<Symbol, Decimal>,
+    inventory: HashMap<Instrument, Decimal>,
+}
+
+impl PreTradeRisk {
+    /// Construct a new [`PreTradeRisk`] using the provided [`PreTradeRiskConfig`].
+    pub fn new(config: PreTradeRiskConfig) -> Self {
+        Self {
+            config,
+            kill_switch: false,
+            last_prices: HashMap::new(),
+        }
+    }
+
+    /// Engage the global kill switch, dropping every subsequent [`Order<RequestOpen>`] &
+    /// [`Order<RequestAmend>`].
+    pub fn engage_kill_switch(&mut self) {
+        self.kill_switch = true;
+    }
+
+    /// Disengage the global kill switch.
+    pub fn disengage_kill_switch(&mut self) {
+        self.kill_switch = false;
+    }
+
+    /// Determines if the global kill switch is engaged.
+    pub fn is_kill_switch_engaged(&self) -> bool {
+        self.kill_switch
+    }
+
+    /// Update the last market price used for fat-finger price band & notional checks.
+    pub fn update_from_market(&mut self, market: &MarketEvent<DataKind>) {
+        let price = match &market.kind {
+            DataKind::Trade(trade) => trade.price,
+            DataKind::Candle(candle) => candle.close,
+            DataKind::OrderBookL1(book_l1) => book_l1.volume_weighed_mid_price(),
+            DataKind::OrderBook(book) => match book.volume_weighed_mid_price() {
+                Some(price) => price,
+                None => return,
+            },
+            DataKind::Liquidation(_) | DataKind::IntentOrder(_) => return,
+        };
+
+        self.last_prices
+            .insert((market.exchange.clone(), market.instrument.clone()), price);
+    }
+
+    /// Validate every [`Order<RequestOpen>`] against the [`Accounts`], returning the requests
+    /// that passed (possibly trimmed), and a [`RiskAudit`] for every request dropped or trimmed.
+    pub fn check_orders(
+        &self,
+        accounts: &Accounts,
+        requests: Vec<(Exchange, Vec<Order<RequestOpen>>)>,
+    ) -> (Vec<(Exchange, Vec<Order<RequestOpen>>)>, Vec<RiskAudit>) {
+        let mut audits = Vec::new();
+
+        let approved = requests
+            .into_iter()
+            .filter_map(|(exchange, requests)| {
+                let account = accounts.get(&exchange);
+                let mut batch = BatchExposure::default();
+
+                let approved = requests
+                    .into_iter()
+                    .filter_map(|mut request| {
+                        match self.check_order(account, &mut batch, &mut request, &mut audits) {
+                            Ok(()) => Some(request),
+                            Err(violation) => {
+                                audits.push(RiskAudit::new(
+                                    &request,
+                                    RiskAction::Dropped,
+                                    violation,
+                                ));
+                                None
+                            }
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                (!approved.is_empty()).then_some((exchange, approved))
+            })
+            .collect();
+
+        (approved, audits)
+    }
+
+    /// Validate every [`Order<RequestAmend>`] using it's new price & quantity, returning the
+    /// requests that passed, and a [`RiskAudit`] for every request dropped.
+    pub fn check_amends(
+        &self,
+        requests: Vec<(Exchange, Vec<Order<RequestAmend>>)>,
+    ) -> (Vec<(Exchange, Vec<Order<RequestAmend>>)>, Vec<RiskAudit>) {
+        let mut audits = Vec::new();
+
+        let approved = requests
+            .into_iter()
+            .filter_map(|(exchange, requests)| {
+                let approved = requests
+                    .into_iter()
+                    .filter(|request| match self.check_amend(request) {
+                        Ok(()) => true,
+                        Err(violation) => {
+                            audits.push(RiskAudit::new(request, RiskAction::Dropped, violation));
+                            false
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                (!approved.is_empty()).then_some((exchange, approved))
+            })
+            .collect();
+
+        (approved, audits)
+    }
+
+    /// Validate an [`Order<RequestOpen>`], trimming it's quantity where possible. Returns the
+    /// [`PreTradeViolation`] if the request must be dropped.
+    fn check_order(
+        &self,
+        account: &Account,
+        batch: &mut BatchExposure,
+        request: &mut Order<RequestOpen>,
+        audits: &mut Vec<RiskAudit>,
+    ) -> Result<(), PreTradeViolation> {
+        // Global kill switch
+        if self.kill_switch {
+            return Err(PreTradeViolation::KillSwitch);
+        }
+
+        // Fat-finger price band
+        if let Some(violation) = self.check_price_band(
+            &request.exchange,
+            &request.instrument,
+            &request.state.kind,
+            request.state.price,
+        ) {
+            return Err(violation);
+        }
+
+        // Order quantity & notional limits
+        let price = self.execution_price(
+            &request.exchange,
+            &request.instrument,
+            &request.state.kind,
+            request.state.price,
+        );
+        if let Err((max_quantity, violation)) =
+            self.check_order_size(&request.instrument, request.state.quantity, price)
+        {
+            trim_quantity(request, max_quantity, violation, audits)?;
+        }
+
+        // Open order count
+        if let Some(limit) = self.config.max_open_orders {
+            let open = account.orders_open.len() + account.orders_in_flight.len() + batch.orders;
+            if open >= limit {
+                return Err(PreTradeViolation::OpenOrders { open, limit });
+            }
+        }
+
+        // Inventory limit
+        if let Some(limit) = self.config.max_inventory.get(&request.instrument) {
+            let inventory = calculate_inventory(account, batch, &request.instrument);
+            let headroom = match request.side {
+                Side::Buy => *limit - inventory,
+                Side::Sell => *limit + inventory,
+            };
+
+            if request.state.quantity > headroom {
+                let violation = PreTradeViolation::Inventory {
+                    inventory,
+                    limit: *limit,
+                };
+                trim_quantity(request, headroom, violation, audits)?;
+            }
+        }
+
+        // Available balance, net of requests already approved in this batch
+        let price = price.unwrap_or(request.state.price);
+        let requirement = self.balance_per_quantity(request, price);
+        if let Some((symbol, per_quantity)) = &requirement {
+            let per_quantity = *per_quantity;
+            let required = per_quantity * request.state.quantity;
+            let available = account
+                .balances
+                .get(symbol)
+                .map(|balance| balance.available)
+                .unwrap_or_default()
+                - batch.reserved.get(symbol).copied().unwrap_or_default();
+
+            if required > available {
+                let violation = PreTradeViolation::Balance {
+                    symbol: symbol.clone(),
+                    required,
+                    available,
+                };
+                let max_quantity = match per_quantity > Decimal::ZERO {
+                    true => available / per_quantity,
+                    false => Decimal::ZERO,
+                };
+                trim_quantity(request, max_quantity, violation, audits)?;
+            }
+        }
+
+        // Record the approved request exposure
+        if let Some((symbol, per_quantity)) = requirement {
+            *batch.reserved.entry(symbol).or_default() += per_quantity * request.state.quantity;
+        }
+        *batch
+            .inventory
+            .entry(request.instrument.clone())
+            .or_default() += signed_quantity(request.side, request.state.quantity);
+        batch.orders += 1;
+
+        Ok(())
+    }
+
+    /// Validate an [`Order<RequestAmend>`] using it's new price & quantity. Returns the
+    /// [`PreTradeViolation`] if the request must be dropped.
+    fn check_amend(&self, request: &Order<RequestAmend>) -> Result<(), PreTradeViolation> {
+        // Global kill switch
+        if self.kill_switch {
+            return Err(PreTradeViolation::KillSwitch);
+        }
+
+        // Fat-finger price band
+        if let Some(violation) = self.check_price_band(
+            &request.exchange,
+            &request.instrument,
+            &request.state.kind,
+            request.state.price,
+        ) {
+            return Err(violation);
+        }
+
+        // Order quantity & notional limits
+        let price = self.execution_price(
+            &request.exchange,
+            &request.instrument,
+            &request.state.kind,
+            request.state.price,
+        );
+        self.check_order_size(&request.instrument, request.state.quantity, price)
+            .map_err(|(_, violation)| violation)
+    }
+
+    /// Check every price of a request that is determined by the [`OrderKind`] is within the band
+    /// around the last market price.
+    fn check_price_band(
+        &self,
+        exchange: &Exchange,
+        instrument: &Instrument,
+        kind: &OrderKind,
+        price: Decimal,
+    ) -> Option<PreTradeViolation> {
+        let limit = self.config.price_band?;
+        let last_price = self.last_price(exchange, instrument)?;
+
+        if last_price <= Decimal::ZERO {
+            return None;
+        }
+
+        let limit_price = kind.has_limit_price().then_some(price);
+        let activation_price = match kind {
+            OrderKind::TrailingStop {
+                activation_price, ..
+            } => *activation_price,
+            _ => None,
+        };
+
+        [kind.trigger_price(), activation_price, limit_price]
+            .into_iter()
+            .flatten()
+            .find(|price| (*price - last_price).abs() / last_price > limit)
+            .map(|price| PreTradeViolation::PriceBand {
+                price,
+                last_price,
+                limit,
+            })
+    }
+
+    /// Check the request quantity & notional are within the configured order size limits.
+    /// Returns the maximum allowed quantity & the [`PreTradeViolation`] if a limit is breached.
+    fn check_order_size(
+        &self,
+        instrument: &Instrument,
+        quantity: Decimal,
+        price: Option<Decimal>,
+    ) -> Result<(), (Decimal, PreTradeViolation)> {
+        if let Some(limit) = self.config.max_order_quantity.get(instrument) {
+            if quantity > *limit {
+                return Err((
+                    *limit,
+                    PreTradeViolation::Quantity {
+                        quantity,
+                        limit: *limit,
+                    },
+                ));
+            }
+        }
+
+        if let Some(limit) = self.config.max_order_notional {
+            let price = match price {
+                Some(price) if price > Decimal::ZERO => price,
+                _ => return Err((Decimal::ZERO, PreTradeViolation::Unpriced)),
+            };
+
+            let notional = price * quantity;
+            if notional > limit {
+                return Err((
+                    limit / price,
+                    PreTradeViolation::Notional { notional, limit },
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Determine the price a request is expected to execute at, according to it's [`OrderKind`].
+    /// Kinds without a limit price are expected to execute at their trigger price, or the last
+    /// market price if they have no fixed trigger price.
+    fn execution_price(
+        &self,
+        exchange: &Exchange,
+        instrument: &Instrument,
+        kind: &OrderKind,
+        price: Decimal,
+    ) -> Option<Decimal> {
+        if kind.has_limit_price() {
+            return Some(price);
+        }
+
+        match kind {
+            OrderKind::TrailingStop {
+                activation_price: Some(activation_price),
+                ..
+            } => Some(*activation_price),
+            _ => kind
+                .trigger_price()
+                .or_else(|| self.last_price(exchange, instrument)),
+        }
+    }
+
+    /// Calculate the available balance required per unit quantity of an [`Order<RequestOpen>`]
+    /// expected to execute at the provided price, and the [`Symbol`] it is required in.
+    ///
+    /// Returns `None` for `reduce_only` requests, since they only release exposure. Derivative
+    /// requests of either [`Side`] require margin in the quote [`Symbol`], net of leverage,
+    /// whereas spot sells require the base [`Symbol`] and spot buys the full quote value.
+    fn balance_per_quantity(
+        &self,
+        request: &Order<RequestOpen>,
+        price: Decimal,
+    ) -> Option<(Symbol, Decimal)> {
+        if request.state.reduce_only {
+            return None;
+        }
+
+        match (&request.instrument.kind, request.side) {
+            (InstrumentKind::Perpetual | InstrumentKind::Future(_), _) => {
+                let leverage = self
+                    .config
+                    .leverage
+                    .get(&request.instrument)
+                    .copied()
+                    .filter(|leverage| *leverage > Decimal::ZERO)
+                    .unwrap_or(Decimal::ONE);
+                Some((request.instrument.quote.clone(), price / leverage))
+            }
+            (_, Side::Buy) => Some((request.instrument.quote.clone(), price)),
+            (_, Side::Sell) => Some((request.instrument.base.clone(), Decimal::ONE)),
+        }
+    }
+
+    fn last_price(&self, exchange: &Exchange, instrument: &Instrument) -> Option<Decimal> {
+        self.last_prices
+            .get(&(exchange.clone(), instrument.clone()))
+            .copied()
+    }
+}
+
+/// Trim the [`Order<RequestOpen>`] quantity to the maximum allowed, recording a [`RiskAudit`].
+/// Returns the [`PreTradeViolation`] if nothing remains and the request must be dropped.
+fn trim_quantity(
+    request: &mut Order<RequestOpen>,
+    max_quantity: Decimal,
+    violation: PreTradeViolation,
+    audits: &mut Vec<RiskAudit>,
+) -> Result<(), PreTradeViolation> {
+    if max_quantity <= Decimal::ZERO {
+        return Err(violation);
+    }
+
+    let action = RiskAction::Trimmed {
+        from: request.state.quantity,
+        to: max_quantity,
+    };
+    request.state.quantity = max_quantity;
+    audits.push(RiskAudit::new(request, action, violation));
+    Ok(())
+}
+
+/// Calculate the signed inventory of an [`Instrument`] base [`Symbol`] held in the [`Account`],
+/// including the remaining quantity of open orders & requests approved earlier in the batch.
+fn calculate_inventory(
+    account: &Account,
+    batch: &BatchExposure,
+    instrument: &Instrument,
+) -> Decimal {
+    let held = account
+        .balances
+        .get(&instrument.base)
+        .map(|balance| balance.total)
+        .unwrap_or_default();
+
+    let open = account
+        .orders_open
+        .values()
+        .filter(|open| open.instrument == *instrument)
+        .map(|open| signed_quantity(open.side, open.state.remaining_quantity()))
+        .sum::<Decimal>();
+
+    held + open + batch.inventory.get(instrument).copied().unwrap_or_default()
+}
+
+fn signed_quantity(side: Side, quantity: Decimal) -> Decimal {
+    match side {
+        Side::Buy => quantity,
+        Side::Sell => -quantity,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_execution::model::{
+        balance::Balance,
+        order::{Open, OrderKind},
+    };
+    use uuid::Uuid;
+
+    fn instrument() -> Instrument {
+        Instrument::from(("btc", "usdt", InstrumentKind::Spot))
+    }
+
+    fn perpetual_instrument() -> Instrument {
+        Instrument::from(("btc", "usdt", InstrumentKind::Perpetual))
+    }
+
+    fn perpetual(request: Order<RequestOpen>) -> Order<RequestOpen> {
+        Order {
+            instrument: perpetual_instrument(),
+            ..request
+        }
+    }
+
+    fn accounts(usdt: Decimal, btc: Decimal) -> Accounts {
+        Accounts(HashMap::from([(
+            Exchange::from("simulated"),
+            Account {
+                balances: HashMap::from([
+                    (Symbol::from("usdt"), Balance::new(usdt, usdt)),
+                    (Symbol::from("btc"), Balance::new(btc, btc)),
+                ]),
+                positions: HashMap::new(),
+                orders_in_flight: HashMap::new(),
+                orders_open: HashMap::new(),
+            },
+        )]))
+    }
+
+    fn request(side: Side, price: Decimal, quantity: Decimal) -> Order<RequestOpen> {
+        request_kind(OrderKind::Limit, side, price, quantity)
+    }
+
+    fn request_kind(
+        kind: OrderKind,
+        side: Side,
+        price: Decimal,
+        quantity: Decimal,
+    ) -> Order<RequestOpen> {
+        Order {
+            exchange: Exchange::from("simulated"),
+            instrument: instrument(),
+            cid: ClientOrderId(Uuid::new_v4()),
+            side,
+            state: RequestOpen {
+                kind,
+                price,
+                quantity,
+                reduce_only: false,
+            },
+        }
+    }
+
+    fn amend(price: Decimal, quantity: Decimal) -> Order<RequestAmend> {
+        Order {
+            exchange: Exchange::from("simulated"),
+            instrument: instrument(),
+            cid: ClientOrderId(Uuid::new_v4()),
+            side: Side::Buy,
+            state: RequestAmend {
+                id: "open".into(),
+                kind: OrderKind::Limit,
+                price,
+                quantity,
+                reduce_only: false,
+                filled_quantity: Decimal::ZERO,
+            },
+        }
+    }
+
+    fn risk(config: PreTradeRiskConfig) -> PreTradeRisk {
+        let mut risk = PreTradeRisk::new(config);
+        risk.last_prices.insert(
+            (Exchange::from("simulated"), instrument()),
+            Decimal::from(100),
+        );
+        risk
+    }
+
+    #[test]
+    fn test_check_order() {
+        struct TestCase {
+            risk: PreTradeRisk,
+            accounts: Accounts,
+            request: Order<RequestOpen>,
+            expected_quantity: Option<Decimal>,
+            expected_audit: Option<(RiskAction, PreTradeViolation)>,
+        }
+
+        let kill_switched = {
+            let mut risk = risk(PreTradeRiskConfig::default());
+            risk.engage_kill_switch();
+            risk
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: request within every limit is approved unchanged
+                risk: risk(PreTradeRiskConfig::default()),
+                accounts: accounts(Decimal::from(1000), Decimal::from(0)),
+                request: request(Side::Buy, Decimal::from(100), Decimal::from(5)),
+                expected_quantity: Some(Decimal::from(5)),
+                expected_audit: None,
+            },
+            TestCase {
+                // TC1: kill switch drops every request
+                risk: kill_switched,
+                accounts: accounts(Decimal::from(1000), Decimal::from(0)),
+                request: request(Side::Buy, Decimal::from(100), Decimal::from(1)),
+                expected_quantity: None,
+                expected_audit: Some((RiskAction::Dropped, PreTradeViolation::KillSwitch)),
+            },
+            TestCase {
+                // TC2: fat-finger price outside the band is dropped
+                risk: risk(PreTradeRiskConfig {
+                    price_band: Some(Decimal::new(5, 2)),
+                    ..PreTradeRiskConfig::default()
+                }),
+                accounts: accounts(Decimal::from(1000), Decimal::from(0)),
+                request: request(Side::Buy, Decimal::from(110), Decimal::from(1)),
+                expected_quantity: None,
+                expected_audit: Some((
+                    RiskAction::Dropped,
+                    PreTradeViolation::PriceBand {
+                        price: Decimal::from(110),
+                        last_price: Decimal::from(100),
+                        limit: Decimal::new(5, 2),
+                    },
+                )),
+            },
+            TestCase {
+                // TC3: buy exceeding the available quote balance is trimmed
+                risk: risk(PreTradeRiskConfig::default()),
+                accounts: accounts(Decimal::from(250), Decimal::from(0)),
+                request: request(Side::Buy, Decimal::from(100), Decimal::from(5)),
+                expected_quantity: Some(Decimal::new(25, 1)),
+                expected_audit: Some((
+                    RiskAction::Trimmed {
+                        from: Decimal::from(5),
+                        to: Decimal::new(25, 1),
+                    },
+                    PreTradeViolation::Balance {
+                        symbol: Symbol::from("usdt"),
+                        required: Decimal::from(500),
+                        available: Decimal::from(250),
+                    },
+                )),
+            },
+            TestCase {
+                // TC4: sell without any base balance is dropped
+                risk: risk(PreTradeRiskConfig::default()),
+                accounts: accounts(Decimal::from(1000), Decimal::from(0)),
+                request: request(Side::Sell, Decimal::from(100), Decimal::from(1)),
+                expected_quantity: None,
+                expected_audit: Some((
+                    RiskAction::Dropped,
+                    PreTradeViolation::Balance {
+                        symbol: Symbol::from("btc"),
+                        required: Decimal::from(1),
+                        available: Decimal::from(0),
+                    },
+                )),
+            },
+            TestCase {
+                // TC5: buy exceeding the inventory limit is trimmed to the headroom
+                risk: risk(PreTradeRiskConfig {
+                    max_inventory: HashMap::from([(instrument(), Decimal::from(3))]),
+                    ..PreTradeRiskConfig::default()
+                }),
+                accounts: accounts(Decimal::from(1000), Decimal::from(2)),
+                request: request(Side::Buy, Decimal::from(100), Decimal::from(5)),
+                expected_quantity: Some(Decimal::from(1)),
+                expected_audit: Some((
+                    RiskAction::Trimmed {
+                        from: Decimal::from(5),
+                        to: Decimal::from(1),
+                    },
+                    PreTradeViolation::Inventory {
+                        inventory: Decimal::from(2),
+                        limit: Decimal::from(3),
+                    },
+                )),
+            },
+            TestCase {
+                // TC6: market request with no price is valued at the last price, not price banded
+                risk: risk(PreTradeRiskConfig {
+                    price_band: Some(Decimal::new(5, 2)),
+                    ..PreTradeRiskConfig::default()
+                }),
+                accounts: accounts(Decimal::from(1000), Decimal::from(0)),
+                request: request_kind(
+                    OrderKind::Market,
+                    Side::Buy,
+                    Decimal::ZERO,
+                    Decimal::from(5),
+                ),
+                expected_quantity: Some(Decimal::from(5)),
+                expected_audit: None,
+            },
+            TestCase {
+                // TC7: market request exceeding the notional limit at the last price is trimmed
+                risk: risk(PreTradeRiskConfig {
+                    max_order_notional: Some(Decimal::from(200)),
+                    ..PreTradeRiskConfig::default()
+                }),
+                accounts: accounts(Decimal::from(1000), Decimal::from(0)),
+                request: request_kind(
+                    OrderKind::Market,
+                    Side::Buy,
+                    Decimal::ZERO,
+                    Decimal::from(5),
+                ),
+                expected_quantity: Some(Decimal::from(2)),
+                expected_audit: Some((
+                    RiskAction::Trimmed {
+                        from: Decimal::from(5),
+                        to: Decimal::from(2),
+                    },
+                    PreTradeViolation::Notional {
+                        notional: Decimal::from(500),
+                        limit: Decimal::from(200),
+                    },
+                )),
+            },
+            TestCase {
+                // TC8: market buy exceeding the available balance at the last price is trimmed
+                risk: risk(PreTradeRiskConfig::default()),
+                accounts: accounts(Decimal::from(250), Decimal::from(0)),
+                request: request_kind(
+                    OrderKind::Market,
+                    Side::Buy,
+                    Decimal::ZERO,
+                    Decimal::from(5),
+                ),
+                expected_quantity: Some(Decimal::new(25, 1)),
+                expected_audit: Some((
+                    RiskAction::Trimmed {
+                        from: Decimal::from(5),
+                        to: Decimal::new(25, 1),
+                    },
+                    PreTradeViolation::Balance {
+                        symbol: Symbol::from("usdt"),
+                        required: Decimal::from(500),
+                        available: Decimal::from(250),
+                    },
+                )),
+            },
+            TestCase {
+                // TC9: stop market request with a trigger price inside the band is approved
+                risk: risk(PreTradeRiskConfig {
+                    price_band: Some(Decimal::new(5, 2)),
+                    ..PreTradeRiskConfig::default()
+                }),
+                accounts: accounts(Decimal::from(1000), Decimal::from(1)),
+                request: request_kind(
+                    OrderKind::StopMarket {
+                        trigger_price: Decimal::from(97),
+                    },
+                    Side::Sell,
+                    Decimal::ZERO,
+                    Decimal::from(1),
+                ),
+                expected_quantity: Some(Decimal::from(1)),
+                expected_audit: None,
+            },
+            TestCase {
+                // TC10: stop market request with a trigger price outside the band is dropped
+                risk: risk(PreTradeRiskConfig {
+                    price_band: Some(Decimal::new(5, 2)),
+                    ..PreTradeRiskConfig::default()
+                }),
+                accounts: accounts(Decimal::from(1000), Decimal::from(1)),
+                request: request_kind(
+                    OrderKind::StopMarket {
+                        trigger_price: Decimal::from(80),
+                    },
+                    Side::Sell,
+                    Decimal::from(100),
+                    Decimal::from(1),
+                ),
+                expected_quantity: None,
+                expected_audit: Some((
+                    RiskAction::Dropped,
+                    PreTradeViolation::PriceBand {
+                        price: Decimal::from(80),
+                        last_price: Decimal::from(100),
+                        limit: Decimal::new(5, 2),
+                    },
+                )),
+            },
+            TestCase {
+                // TC11: request exceeding the max order quantity is trimmed to the limit
+                risk: risk(PreTradeRiskConfig {
+                    max_order_quantity: HashMap::from([(instrument(), Decimal::from(2))]),
+                    ..PreTradeRiskConfig::default()
+                }),
+                accounts: accounts(Decimal::from(1000), Decimal::from(0)),
+                request: request(Side::Buy, Decimal::from(100), Decimal::from(5)),
+                expected_quantity: Some(Decimal::from(2)),
+                expected_audit: Some((
+                    RiskAction::Trimmed {
+                        from: Decimal::from(5),
+                        to: Decimal::from(2),
+                    },
+                    PreTradeViolation::Quantity {
+                        quantity: Decimal::from(5),
+                        limit: Decimal::from(2),
+                    },
+                )),
+            },
+            TestCase {
+                // TC12: perpetual short without any base balance is approved
+                risk: risk(PreTradeRiskConfig::default()),
+                accounts: accounts(Decimal::from(1000), Decimal::from(0)),
+                request: perpetual(request(Side::Sell, Decimal::from(100), Decimal::from(1))),
+                expected_quantity: Some(Decimal::from(1)),
+                expected_audit: None,
+            },
+            TestCase {
+                // TC13: leveraged perpetual buy only requires the margin in the quote balance
+                risk: risk(PreTradeRiskConfig {
+                    leverage: HashMap::from([(perpetual_instrument(), Decimal::from(10))]),
+                    ..PreTradeRiskConfig::default()
+                }),
+                accounts: accounts(Decimal::from(100), Decimal::from(0)),
+                request: perpetual(request(Side::Buy, Decimal::from(100), Decimal::from(5))),
+                expected_quantity: Some(Decimal::from(5)),
+                expected_audit: None,
+            },
+            TestCase {
+                // TC14: perpetual short exceeding the leveraged quote margin is trimmed
+                risk: risk(PreTradeRiskConfig {
+                    leverage: HashMap::from([(perpetual_instrument(), Decimal::from(2))]),
+                    ..PreTradeRiskConfig::default()
+                }),
+                accounts: accounts(Decimal::from(100), Decimal::from(0)),
+                request: perpetual(request(Side::Sell, Decimal::from(100), Decimal::from(5))),
+                expected_quantity: Some(Decimal::from(2)),
+                expected_audit: Some((
+                    RiskAction::Trimmed {
+                        from: Decimal::from(5),
+                        to: Decimal::from(2),
+                    },
+                    PreTradeViolation::Balance {
+                        symbol: Symbol::from("usdt"),
+                        required: Decimal::from(250),
+                        available: Decimal::from(100),
+                    },
+                )),
+            },
+            TestCase {
+                // TC15: reduce only sell without any base balance is approved
+                risk: risk(PreTradeRiskConfig::default()),
+                accounts: accounts(Decimal::from(0), Decimal::from(0)),
+                request: {
+                    let mut request = request(Side::Sell, Decimal::from(100), Decimal::from(1));
+                    request.state.reduce_only = true;
+                    request
+                },
+                expected_quantity: Some(Decimal::from(1)),
+                expected_audit: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (approved, audits) = test.risk.check_orders(
+                &test.accounts,
+                vec![(Exchange::from("simulated"), vec![test.request])],
+            );
+
+            let actual_quantity = approved
+                .first()
+                .and_then(|(_, requests)| requests.first())
+                .map(|request| request.state.quantity);
+            assert_eq!(
+                actual_quantity, test.expected_quantity,
+                "TC{} failed",
+                index
+            );
+
+            let actual_audit = audits
+                .into_iter()
+                .next()
+                .map(|audit| (audit.action, audit.violation));
+            assert_eq!(actual_audit, test.expected_audit, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_check_orders_accounts_for_batch_and_open_orders() {
+        let risk = risk(PreTradeRiskConfig {
+            max_open_orders: Some(3),
+            ..PreTradeRiskConfig::default()
+        });
+
+        let mut accounts = accounts(Decimal::from(250), Decimal::from(0));
+        let open = request(Side::Buy, Decimal::from(100), Decimal::from(1));
+        accounts
+            .account(&Exchange::from("simulated"))
+            .orders_open
+            .insert(
+                open.cid,
+                Order {
+                    exchange: open.exchange,
+                    instrument: open.instrument,
+                    cid: open.cid,
+                    side: open.side,
+                    state: Open {
+                        id: "open".into(),
+                        price: Decimal::from(100),
+                        quantity: Decimal::from(1),
+                        filled_quantity: Decimal::from(0),
+                    },
+                },
+            );
+
+        let (approved, audits) = risk.check_orders(
+            &accounts,
+            vec![(
+                Exchange::from("simulated"),
+                vec![
+                    request(Side::Buy, Decimal::from(100), Decimal::from(2)),
+                    request(Side::Buy, Decimal::from(100), Decimal::from(1)),
+                    request(Side::Buy, Decimal::from(100), Decimal::from(1)),
+                ],
+            )],
+        );
+
+        // First request reserves 200 usdt, second is trimmed to the remaining 50 usdt
+        let quantities = approved[0]
+            .1
+            .iter()
+            .map(|request| request.state.quantity)
+            .collect::<Vec<_>>();
+        assert_eq!(quantities, vec![Decimal::from(2), Decimal::new(5, 1)]);
+
+        // Third request is dropped since 1 open & 2 approved orders are at the limit
+        assert_eq!(audits.len(), 2);
+        assert_eq!(
+            audits[1].violation,
+            PreTradeViolation::OpenOrders { open: 3, limit: 3 }
+        );
+    }
+
+    #[test]
+    fn test_check_amends() {
+        let risk = risk(PreTradeRiskConfig {
+            price_band: Some(Decimal::new(5, 2)),
+            max_order_notional: Some(Decimal::from(1000)),
+            ..PreTradeRiskConfig::default()
+        });
+
+        let in_band = amend(Decimal::from(101), Decimal::from(5));
+        let out_of_band = amend(Decimal::from(150), Decimal::from(5));
+        let over_notional = amend(Decimal::from(100), Decimal::from(20));
+
+        let (approved, audits) = risk.check_amends(vec![(
+            Exchange::from("simulated"),
+            vec![in_band.clone(), out_of_band.clone(), over_notional.clone()],
+        )]);
+
+        // Only the amend within every limit is sent to the ExchangeClient
+        assert_eq!(approved, vec![(Exchange::from("simulated"), vec![in_band])]);
+
+        let actual = audits
+            .into_iter()
+            .map(|audit| (audit.cid, audit.action, audit.violation))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            actual,
+            vec![
+                (
+                    out_of_band.cid,
+                    RiskAction::Dropped,
+                    PreTradeViolation::PriceBand {
+                        price: Decimal::from(150),
+                        last_price: Decimal::from(100),
+                        limit: Decimal::new(5, 2),
+                    }
+                ),
+                (
+                    over_notional.cid,
+                    RiskAction::Dropped,
+                    PreTradeViolation::Notional {
+                        notional: Decimal::from(2000),
+                        limit: Decimal::from(1000),
+                    }
+                ),
+            ]
+        );
+    }
+}
@@ -1,5 +1,5 @@
 use barter_data::event::{DataKind, MarketEvent};
-use barter_execution::model::order::{Order, RequestCancel, RequestOpen};
+use barter_execution::model::order::{Order, RequestAmend, RequestCancel, RequestOpen};
 use barter_integration::model::Exchange;
 
 use super::account::Accounts;
@@ -21,6 +21,15 @@ pub trait OrderGenerator {
         &mut self,
         accounts: &Accounts,
     ) -> Option<Vec<(Exchange, Vec<Order<RequestOpen>>)>>;
+
+    /// Amend the price and/or quantity of open orders rather than cancelling & re-opening them,
+    /// which keeps their queue priority on venues that support amends.
+    fn generate_amends(
+        &mut self,
+        _accounts: &Accounts,
+    ) -> Option<Vec<(Exchange, Vec<Order<RequestAmend>>)>> {
+        None
+    }
 }
 
 // Todo: What does the Strategy do?
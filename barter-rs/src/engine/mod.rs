@@ -7,16 +7,20 @@ use crate::{
         repository::{PositionHandler, StatisticHandler},
         FillUpdater, MarketUpdater, OrderGenerator,
     },
-    statistic::summary::{PositionSummariser, TableBuilder},
+    statistic::{
+        report::BacktestReport,
+        summary::{equity::EquityCurve, PositionSummariser, TableBuilder},
+    },
     strategy::SignalGenerator,
 };
 use barter_data::event::{DataKind, MarketEvent};
 use barter_execution::ExecutionClient;
 use barter_integration::model::{Market, MarketId};
+use chrono::Duration;
 use parking_lot::Mutex;
 use prettytable::Table;
 use serde::Serialize;
-use std::{collections::HashMap, fmt::Debug, sync::Arc, thread};
+use std::{collections::HashMap, fmt::Debug, path::PathBuf, sync::Arc, thread};
 use tokio::sync::{mpsc, oneshot};
 use tracing::{error, info, warn};
 use uuid::Uuid;
@@ -73,6 +77,9 @@ where
     /// Uses trading session's exited [`Position`]s to calculate an average statistical summary
     /// across all [`Market`]s traded.
     pub statistics_summary: Statistic,
+    /// Optional directory to export a [`BacktestReport`] of the trading session into when the
+    /// [`Engine`] terminates.
+    pub report_directory: Option<PathBuf>,
 }
 
 /// Multi-threaded Trading Engine capable of trading with an arbitrary number of [`Trader`]s, one
@@ -114,6 +121,9 @@ where
     /// Uses trading session's exited [`Position`]s to calculate an average statistical summary
     /// across all [`Market`]s traded.
     statistics_summary: Statistic,
+    /// Optional directory to export a [`BacktestReport`] of the trading session into when the
+    /// [`Engine`] terminates.
+    report_directory: Option<PathBuf>,
 }
 
 impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
@@ -145,6 +155,7 @@ where
             traders: lego.traders,
             trader_command_txs: lego.trader_command_txs,
             statistics_summary: lego.statistics_summary,
+            report_directory: lego.report_directory,
         }
     }
 
@@ -157,7 +168,8 @@ where
     /// receives [`Command`]s via the `command_rx` and actions them
     /// (eg/ terminate_traders, fetch_open_positions). If all of the [`Trader`]s stop organically
     /// (eg/ due to a finished [`MarketGenerator`]), the [`Engine`] terminates & prints a summary
-    /// for the trading session.
+    /// for the trading session, exporting a [`BacktestReport`] if a report directory is
+    /// configured.
     pub async fn run(mut self) {
         // Run Traders on threads & send notification when they have stopped organically
         let mut notify_traders_stopped = self.run_traders().await;
@@ -194,8 +206,38 @@ where
             }
         }
 
-        // Print Trading Session Summary
-        self.generate_session_summary().printstd();
+        // Generate Trading Session statistics using the session's exited Positions
+        let exited_positions = self.fetch_exited_positions();
+        let statistics = self.generate_session_statistics(&exited_positions);
+        let equity_curve = self.portfolio.lock().equity_curve().cloned();
+
+        // Print Trading Session Summary, followed by any time-based equity curve & execution
+        // quality metrics
+        crate::statistic::summary::combine(statistics.clone()).printstd();
+        if let Some(equity_curve) = &equity_curve {
+            Self::generate_equity_curve_summary(equity_curve).printstd();
+        }
+        if let Some(execution_summary) = self.portfolio.lock().execution_summary() {
+            execution_summary.table("Total").printstd();
+        }
+
+        // Export a report of the trading session if a report directory is configured
+        if let Some(directory) = &self.report_directory {
+            let report =
+                BacktestReport::new(self.engine_id, statistics, exited_positions, equity_curve);
+
+            match report.write(directory) {
+                Ok(()) => info!(
+                    directory = %directory.display(),
+                    "exported trading session report"
+                ),
+                Err(error) => error!(
+                    ?error,
+                    directory = %directory.display(),
+                    "failed to export trading session report"
+                ),
+            }
+        }
     }
 
     /// Runs each [`Trader`] it's own thread. Sends a message on the returned `mpsc::Receiver<bool>`
@@ -314,45 +356,65 @@ where
         }
     }
 
-    /// Generate a trading session summary. Uses the Portfolio's statistics per [`Market`] in
-    /// combination with the average statistics across all [`Market`]s traded.
-    fn generate_session_summary(mut self) -> Table {
-        // Fetch statistics for each Market
-        let stats_per_market = self.trader_command_txs.into_keys().filter_map(|market| {
-            let market_id = MarketId::from(&market);
-
-            match self.portfolio.lock().get_statistics(&market_id) {
-                Ok(statistics) => Some((market_id.0, statistics)),
-                Err(error) => {
-                    error!(
-                        ?error,
-                        ?market,
-                        "failed to get Market statistics when generating trading session summary"
-                    );
-                    None
-                }
-            }
-        });
+    /// Generate a summary of the annualised daily & hourly return metrics of the Portfolio's
+    /// mark-to-market [`EquityCurve`].
+    fn generate_equity_curve_summary(equity_curve: &EquityCurve) -> Table {
+        crate::statistic::summary::combine([
+            ("Daily".to_owned(), equity_curve.metrics(Duration::days(1))),
+            (
+                "Hourly".to_owned(),
+                equity_curve.metrics(Duration::hours(1)),
+            ),
+        ])
+    }
 
-        // Generate average statistics across all markets using session's exited Positions
+    /// Fetch the trading session's exited [`Position`]s from the Portfolio.
+    fn fetch_exited_positions(&self) -> Vec<Position> {
         self.portfolio
             .lock()
             .get_exited_positions(self.engine_id)
-            .map(|exited_positions| {
-                self.statistics_summary.generate_summary(&exited_positions);
-            })
             .unwrap_or_else(|error| {
                 warn!(
                     ?error,
                     why = "failed to get exited Positions from Portfolio's repository",
                     "failed to generate Statistics summary for trading session"
                 );
-            });
+                Vec::new()
+            })
+    }
+
+    /// Generate the trading session statistics. Uses the Portfolio's statistics per [`Market`] in
+    /// combination with the average statistics across all [`Market`]s traded.
+    fn generate_session_statistics(
+        &mut self,
+        exited_positions: &[Position],
+    ) -> Vec<(String, Statistic)> {
+        // Fetch statistics for each Market
+        let mut statistics = self
+            .trader_command_txs
+            .keys()
+            .filter_map(|market| {
+                let market_id = MarketId::from(market);
+
+                match self.portfolio.lock().get_statistics(&market_id) {
+                    Ok(statistics) => Some((market_id.0, statistics)),
+                    Err(error) => {
+                        error!(
+                        ?error,
+                        ?market,
+                        "failed to get Market statistics when generating trading session summary"
+                    );
+                        None
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+
+        // Generate average statistics across all markets using session's exited Positions
+        self.statistics_summary.generate_summary(exited_positions);
+        statistics.push(("Total".to_owned(), self.statistics_summary));
 
-        // Combine Total & Per-Market Statistics Into Table
-        crate::statistic::summary::combine(
-            stats_per_market.chain([("Total".to_owned(), self.statistics_summary)]),
-        )
+        statistics
     }
 }
 
@@ -373,6 +435,7 @@ where
     traders: Option<Vec<Trader<EventTx, Statistic, Portfolio, Data, Strategy, Execution>>>,
     trader_command_txs: Option<HashMap<Market, mpsc::Sender<Command>>>,
     statistics_summary: Option<Statistic>,
+    report_directory: Option<PathBuf>,
 }
 
 impl<EventTx, Statistic, Portfolio, Data, Strategy, Execution>
@@ -398,6 +461,7 @@ where
             traders: None,
             trader_command_txs: None,
             statistics_summary: None,
+            report_directory: None,
         }
     }
 
@@ -446,6 +510,18 @@ where
         }
     }
 
+    /// Export a [`BacktestReport`] of the trading session into the input directory when the
+    /// [`Engine`] terminates.
+    pub fn report_directory<P>(self, value: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self {
+            report_directory: Some(value.into()),
+            ..self
+        }
+    }
+
     pub fn build(
         self,
     ) -> Result<Engine<EventTx, Statistic, Portfolio, Data, Strategy, Execution>, EngineError> {
@@ -468,6 +544,7 @@ where
             statistics_summary: self
                 .statistics_summary
                 .ok_or(EngineError::BuilderIncomplete("statistics_summary"))?,
+            report_directory: self.report_directory,
         })
     }
 }
@@ -183,20 +183,41 @@ where
                             .update_from_market(&market)
                             .expect("failed to update Portfolio from market")
                         {
+                            // Force exit a margined Position that has crossed it's liquidation price
+                            if position_update.liquidated {
+                                warn!(
+                                    engine_id = %self.engine_id,
+                                    market = ?self.market,
+                                    ?position_update,
+                                    "Position liquidated - generating SignalForceExit"
+                                );
+                                self.event_q.push_back(Event::SignalForceExit(
+                                    SignalForceExit::from(self.market.clone()),
+                                ));
+                            }
+
                             self.event_tx.send(Event::PositionUpdate(position_update));
                         }
                     }
 
                     Event::Signal(signal) => {
-                        if let Some(order) = self
-                            .portfolio
-                            .lock()
+                        let mut portfolio = self.portfolio.lock();
+                        if let Some(order) = portfolio
                             .generate_order(&signal)
                             .expect("failed to generate order")
                         {
                             self.event_tx.send(Event::OrderNew(order.clone()));
                             self.event_q.push_back(Event::OrderNew(order));
                         }
+
+                        // Communicate any OrderEvents rejected by the Portfolio risk manager
+                        self.event_tx.send_many(
+                            portfolio
+                                .drain_risk_rejections()
+                                .into_iter()
+                                .map(Event::OrderRejected)
+                                .collect(),
+                        );
                     }
 
                     Event::SignalForceExit(signal_force_exit) => {
@@ -1,6 +1,7 @@
 use crate::{
     portfolio::{
         position::{Position, PositionExit, PositionUpdate},
+        risk::RiskRejection,
         Balance,
     },
     strategy::{Signal, SignalForceExit},
@@ -22,6 +23,7 @@ pub enum Event {
     Signal(Signal),
     SignalForceExit(SignalForceExit),
     OrderNew(OrderEvent),
+    OrderRejected(RiskRejection),
     OrderUpdate,
     Fill(FillEvent),
     PositionNew(Position),
@@ -94,7 +94,7 @@
 //!     event::Event,
 //!     test_util,
 //! };
-//! use barter_integration::model::{Market, instrument::kind::InstrumentKind};
+//! use barter_integration::model::{Market, instrument::{kind::InstrumentKind, symbol::Symbol}};
 //! use std::marker::PhantomData;
 //! use uuid::Uuid;
 //!
@@ -105,6 +105,10 @@
 //!     allocator: DefaultAllocator{ default_order_value: 100.0 },
 //!     risk: DefaultRisk{},
 //!     starting_cash: 10000.0,
+//!     reporting_currency: Symbol::from("usdt"),
+//!     margin: None,
+//!     equity_curve: None,
+//!     execution_summary: None,
 //!     statistic_config: StatisticConfig {
 //!         starting_equity: 10000.0 ,
 //!         trading_days_per_year: 365,
@@ -263,7 +267,7 @@ pub mod test_util {
         subscription::{candle::Candle, trade::PublicTrade},
     };
     use barter_execution::{
-        fill::{Decision, Fees, FillEvent, MarketMeta},
+        fill::{Decision, Fees, FillEvent, Liquidity, MarketMeta},
         model::order_event::{OrderEvent, OrderType},
     };
     use barter_integration::model::{
@@ -271,6 +275,7 @@ pub mod test_util {
         Exchange, Side,
     };
     use chrono::Utc;
+    use rust_decimal::Decimal;
     use std::ops::Add;
 
     /// Build a [`MarketEvent`] of [`DataKind::PublicTrade`](DataKind) with the provided [`Side`].
@@ -282,8 +287,8 @@ pub mod test_util {
             instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
             kind: DataKind::Trade(PublicTrade {
                 id: "trade_id".to_string(),
-                price: 1000.0,
-                amount: 1.0,
+                price: Decimal::from(1000),
+                amount: Decimal::ONE,
                 side,
             }),
         }
@@ -299,11 +304,11 @@ pub mod test_util {
             instrument: Instrument::from(("btc", "usdt", InstrumentKind::Spot)),
             kind: DataKind::Candle(Candle {
                 close_time: now,
-                open: 960.0,
-                high: 1100.0,
-                low: 950.0,
-                close: 1000.0,
-                volume: 100000.0,
+                open: Decimal::from(960),
+                high: Decimal::from(1100),
+                low: Decimal::from(950),
+                close: Decimal::from(1000),
+                volume: Decimal::from(100000),
                 trade_count: 1000,
             }),
         }
@@ -340,11 +345,13 @@ pub mod test_util {
             time: Utc::now(),
             exchange: Exchange::from("binance"),
             instrument: Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
+            order_id: None,
             market_meta: Default::default(),
             decision: Decision::default(),
             quantity: 1.0,
             fill_value_gross: 100.0,
             fees: Fees::default(),
+            liquidity: Liquidity::default(),
         }
     }
 
@@ -369,6 +376,7 @@ pub mod test_util {
             current_value_gross: 100.0,
             unrealised_profit_loss: 0.0,
             realised_profit_loss: 0.0,
+            margin: None,
         }
     }
 }
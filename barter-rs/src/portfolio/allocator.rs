@@ -1,21 +1,48 @@
-use crate::{portfolio::position::Position, strategy::SignalStrength};
+use crate::{
+    portfolio::{position::Position, valuation::market_price, Balance},
+    statistic::summary::TradeOutcomes,
+    strategy::SignalStrength,
+};
+use barter_data::event::{DataKind, MarketEvent};
 use barter_execution::{fill::Decision, model::order_event::OrderEvent};
+use barter_integration::model::MarketId;
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal, RoundingStrategy,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
 
 /// Allocates an appropriate [`OrderEvent`] quantity.
 pub trait OrderAllocator {
     /// Returns an [`OrderEvent`] with a calculated order quantity based on the input order,
-    /// [`SignalStrength`] and potential existing [`Position`].
+    /// [`SignalStrength`], potential existing [`Position`] and Portfolio [`AllocationContext`].
     fn allocate_order(
         &self,
         order: &mut OrderEvent,
         position: Option<&Position>,
         signal_strength: SignalStrength,
+        context: &AllocationContext,
     );
+
+    /// Updates any market state the allocator sizes orders from (eg/ realised volatility).
+    fn update_from_market(&mut self, _: &MarketEvent<DataKind>) {}
+}
+
+/// Portfolio state an [`OrderAllocator`] sizes an [`OrderEvent`] from.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct AllocationContext {
+    /// Portfolio [`Balance`] valued in the reporting currency.
+    pub balance: Balance,
+    /// [`TradeOutcomes`] of the Positions previously exited in the [`OrderEvent`] market.
+    pub trade_outcomes: Option<TradeOutcomes>,
 }
 
 /// Default allocation manager that implements [`OrderAllocator`]. Order size is calculated by
 /// using the default_order_value, symbol close value, and [`SignalStrength`].
+///
+/// Exit orders close the whole [`Position`], unless the [`SignalStrength`] is between 0 and 1,
+/// in which case only that proportion of the [`Position`] is closed (eg/ taking partial profit).
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
 pub struct DefaultAllocator {
     pub default_order_value: f64,
@@ -27,20 +54,249 @@ impl OrderAllocator for DefaultAllocator {
         order: &mut OrderEvent,
         position: Option<&Position>,
         signal_strength: SignalStrength,
+        _: &AllocationContext,
+    ) {
+        allocate(order, position, signal_strength, self.default_order_value)
+    }
+}
+
+/// Fixed-fractional allocation manager that implements [`OrderAllocator`]. Entry order value is
+/// a fixed fraction of the current Portfolio equity (total [`Balance`]).
+///
+/// Exit orders are allocated in the same way as the [`DefaultAllocator`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct FixedFractionalAllocator {
+    /// Proportion of equity allocated to each entry order (eg/ 0.02 for 2%).
+    pub fraction: f64,
+}
+
+impl OrderAllocator for FixedFractionalAllocator {
+    fn allocate_order(
+        &self,
+        order: &mut OrderEvent,
+        position: Option<&Position>,
+        signal_strength: SignalStrength,
+        context: &AllocationContext,
+    ) {
+        let order_value = context.balance.total * self.fraction;
+        allocate(order, position, signal_strength, order_value)
+    }
+}
+
+/// Fractional Kelly allocation manager that implements [`OrderAllocator`]. Entry order value is
+/// the Kelly fraction of equity, calculated from the win rate & payoff ratio of the Positions
+/// previously exited in the market, and scaled by the `kelly_multiplier`.
+///
+/// Until `min_trades` Positions have been exited, the `default_fraction` of equity is used.
+/// Exit orders are allocated in the same way as the [`DefaultAllocator`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KellyAllocator {
+    /// Proportion of the full Kelly fraction to allocate (eg/ 0.5 for half Kelly).
+    pub kelly_multiplier: f64,
+    /// Maximum proportion of equity allocated to each entry order.
+    pub max_fraction: f64,
+    /// Minimum number of exited Positions before the Kelly fraction is used.
+    pub min_trades: u64,
+    /// Proportion of equity allocated to each entry order before `min_trades` is reached.
+    pub default_fraction: f64,
+}
+
+impl Default for KellyAllocator {
+    fn default() -> Self {
+        Self {
+            kelly_multiplier: 0.5,
+            max_fraction: 0.25,
+            min_trades: 30,
+            default_fraction: 0.01,
+        }
+    }
+}
+
+impl OrderAllocator for KellyAllocator {
+    fn allocate_order(
+        &self,
+        order: &mut OrderEvent,
+        position: Option<&Position>,
+        signal_strength: SignalStrength,
+        context: &AllocationContext,
+    ) {
+        let fraction = match context.trade_outcomes {
+            Some(outcomes) if outcomes.trades >= self.min_trades => (self.kelly_multiplier
+                * Self::calculate_kelly_fraction(outcomes))
+            .clamp(0.0, self.max_fraction),
+            _ => self.default_fraction,
+        };
+
+        allocate(
+            order,
+            position,
+            signal_strength,
+            context.balance.total * fraction,
+        )
+    }
+}
+
+impl KellyAllocator {
+    /// Calculate the full Kelly fraction: win_rate - (1 - win_rate) / payoff_ratio.
+    pub fn calculate_kelly_fraction(outcomes: TradeOutcomes) -> f64 {
+        outcomes.win_rate - (1.0 - outcomes.win_rate) / outcomes.payoff_ratio
+    }
+}
+
+/// Volatility targeting allocation manager that implements [`OrderAllocator`]. Entry order value
+/// is sized so the expected volatility of the Position matches the target, using the realised
+/// volatility of the market's [`MarketEvent`] price returns:
+/// equity * target_volatility / realised_volatility.
+///
+/// Order value is capped at `max_leverage` * equity, and no entry order is allocated until
+/// `lookback` returns have been observed, or while the realised volatility is zero. Exit orders
+/// are allocated in the same way as the [`DefaultAllocator`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct VolatilityTargetAllocator {
+    /// Target volatility per [`MarketEvent`] return (eg/ 0.01 for 1%).
+    pub target_volatility: f64,
+    /// Number of [`MarketEvent`] returns used to calculate the realised volatility.
+    pub lookback: usize,
+    /// Maximum order value as a multiple of equity.
+    pub max_leverage: f64,
+    volatility: HashMap<MarketId, RealisedVolatility>,
+}
+
+impl OrderAllocator for VolatilityTargetAllocator {
+    fn allocate_order(
+        &self,
+        order: &mut OrderEvent,
+        position: Option<&Position>,
+        signal_strength: SignalStrength,
+        context: &AllocationContext,
     ) {
-        // Calculate exact order_size, then round it to a more appropriate decimal place
-        let default_order_size = self.default_order_value / order.market_meta.close;
-        let default_order_size = (default_order_size * 10000.0).floor() / 10000.0;
+        let equity = context.balance.total;
+        let order_value = match self
+            .volatility
+            .get(&MarketId::new(&order.exchange, &order.instrument))
+            .and_then(|volatility| volatility.calculate(self.lookback))
+        {
+            Some(volatility) if volatility > 0.0 => {
+                (equity * self.target_volatility / volatility).min(equity * self.max_leverage)
+            }
+            // Zero realised volatility (eg/ a stale price) is no evidence of low risk
+            Some(_) | None => 0.0,
+        };
 
-        match order.decision {
-            // Entry
-            Decision::Long => order.quantity = default_order_size * signal_strength.0,
+        allocate(order, position, signal_strength, order_value)
+    }
 
-            // Entry
-            Decision::Short => order.quantity = -default_order_size * signal_strength.0,
+    fn update_from_market(&mut self, market: &MarketEvent<DataKind>) {
+        if let Some(price) = market_price(market) {
+            self.volatility
+                .entry(MarketId::new(&market.exchange, &market.instrument))
+                .or_default()
+                .update(price, self.lookback);
+        }
+    }
+}
 
-            // Exit
-            _ => order.quantity = 0.0 - position.as_ref().unwrap().quantity,
+impl VolatilityTargetAllocator {
+    /// Construct a new [`VolatilityTargetAllocator`].
+    pub fn new(target_volatility: f64, lookback: usize, max_leverage: f64) -> Self {
+        Self {
+            target_volatility,
+            lookback,
+            max_leverage,
+            volatility: HashMap::new(),
+        }
+    }
+}
+
+/// Rolling window of price returns used to calculate realised volatility.
+#[derive(Clone, PartialEq, Debug, Default)]
+struct RealisedVolatility {
+    last_price: Option<f64>,
+    returns: VecDeque<f64>,
+}
+
+impl RealisedVolatility {
+    fn update(&mut self, price: f64, lookback: usize) {
+        if let Some(last_price) = self.last_price.filter(|last_price| *last_price > 0.0) {
+            self.returns.push_back(price / last_price - 1.0);
+            while self.returns.len() > lookback {
+                self.returns.pop_front();
+            }
+        }
+        self.last_price = Some(price);
+    }
+
+    /// Calculate the population standard deviation of the returns, if the lookback window is
+    /// full.
+    fn calculate(&self, lookback: usize) -> Option<f64> {
+        if lookback < 2 || self.returns.len() < lookback {
+            return None;
+        }
+
+        let count = self.returns.len() as f64;
+        let mean = self.returns.iter().sum::<f64>() / count;
+        let variance = self
+            .returns
+            .iter()
+            .map(|value| (value - mean).powi(2))
+            .sum::<f64>()
+            / count;
+
+        Some(variance.sqrt())
+    }
+}
+
+/// Round an order size down to 4 decimal places, without the binary rounding error of scaling
+/// an `f64` (eg/ `0.29 * 10000.0` is `2899.9999999999995`). Non-finite sizes are zero.
+fn round_order_size(order_size: f64) -> f64 {
+    Decimal::from_f64(order_size)
+        .map(|size| size.round_dp_with_strategy(4, RoundingStrategy::ToNegativeInfinity))
+        .and_then(|size| size.to_f64())
+        .unwrap_or(0.0)
+}
+
+/// Allocates an entry order of the input value, or an exit order for the open [`Position`].
+///
+/// Entry orders on the same side as an open [`Position`] scale into it, and entry orders on the
+/// opposite side also exit the full open [`Position`] quantity, reversing it.
+fn allocate(
+    order: &mut OrderEvent,
+    position: Option<&Position>,
+    signal_strength: SignalStrength,
+    order_value: f64,
+) {
+    // Calculate exact order_size, then round it to a more appropriate decimal place
+    let order_size = round_order_size(order_value / order.market_meta.close);
+
+    match order.decision {
+        // Entry, scale in, or reversal
+        Decision::Long | Decision::Short => {
+            let enter_quantity = match order.decision {
+                Decision::Long => order_size * signal_strength.0,
+                _ => -order_size * signal_strength.0,
+            };
+
+            // Reversal exits the opposite side Position before entering
+            let exit_quantity = match position {
+                Some(position)
+                    if enter_quantity != 0.0
+                        && position.quantity.signum() != enter_quantity.signum() =>
+                {
+                    -position.quantity
+                }
+                _ => 0.0,
+            };
+
+            order.quantity = enter_quantity + exit_quantity
+        }
+
+        // Exit (partial if 0 < signal_strength < 1)
+        _ => {
+            let exit_proportion = match signal_strength.0 {
+                strength if strength > 0.0 && strength < 1.0 => strength,
+                _ => 1.0,
+            };
+            order.quantity = 0.0 - position.as_ref().unwrap().quantity * exit_proportion
         }
     }
 }
@@ -48,7 +304,17 @@ impl OrderAllocator for DefaultAllocator {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_util::{order_event, position};
+    use crate::test_util::{market_event_trade, order_event, position};
+    use barter_integration::model::Side;
+    use chrono::Utc;
+    use rust_decimal::Decimal;
+
+    fn context(total: f64, trade_outcomes: Option<TradeOutcomes>) -> AllocationContext {
+        AllocationContext {
+            balance: Balance::new(Utc::now(), total, total),
+            trade_outcomes,
+        }
+    }
 
     #[test]
     fn should_allocate_order_to_exit_open_long_position() {
@@ -68,6 +334,7 @@ mod tests {
             &mut input_order,
             Some(&input_position),
             input_signal_strength,
+            &context(0.0, None),
         );
 
         let actual_result = input_order.quantity;
@@ -94,6 +361,7 @@ mod tests {
             &mut input_order,
             Some(&input_position),
             input_signal_strength,
+            &context(0.0, None),
         );
 
         let actual_result = input_order.quantity;
@@ -102,6 +370,30 @@ mod tests {
         assert_eq!(actual_result, expected_result)
     }
 
+    #[test]
+    fn should_allocate_order_to_partially_exit_open_long_position() {
+        let allocator = DefaultAllocator {
+            default_order_value: 1000.0,
+        };
+
+        let mut input_order = order_event();
+        input_order.decision = Decision::CloseLong;
+
+        let mut input_position = position();
+        input_position.quantity = 100.0;
+
+        let input_signal_strength = SignalStrength(0.25);
+
+        allocator.allocate_order(
+            &mut input_order,
+            Some(&input_position),
+            input_signal_strength,
+            &context(0.0, None),
+        );
+
+        assert_eq!(input_order.quantity, -25.0)
+    }
+
     #[test]
     fn should_allocate_order_to_enter_long_position_with_correct_quantity() {
         let default_order_value = 1000.0;
@@ -116,7 +408,12 @@ mod tests {
 
         let input_signal_strength = SignalStrength(1.0);
 
-        allocator.allocate_order(&mut input_order, None, input_signal_strength);
+        allocator.allocate_order(
+            &mut input_order,
+            None,
+            input_signal_strength,
+            &context(0.0, None),
+        );
 
         let actual_result = input_order.quantity;
         let expected_result = (default_order_value / order_close) * input_signal_strength.0 as f64;
@@ -124,6 +421,42 @@ mod tests {
         assert_eq!(actual_result, expected_result)
     }
 
+    #[test]
+    fn should_round_order_size_down_to_4_decimal_places() {
+        struct TestCase {
+            input: f64,
+            expected: f64,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Size with more than 4 decimal places is rounded down
+                input: 0.882019,
+                expected: 0.882,
+            },
+            TestCase {
+                // TC1: Size with 4 decimal places is unchanged, despite f64 scaling error
+                input: 0.29,
+                expected: 0.29,
+            },
+            TestCase {
+                // TC2: Negative size is rounded down
+                input: -1.23456,
+                expected: -1.2346,
+            },
+            TestCase {
+                // TC3: Non-finite size is zero
+                input: f64::INFINITY,
+                expected: 0.0,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = round_order_size(test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
     #[test]
     fn should_allocate_order_to_enter_long_position_with_non_zero_quantity() {
         let default_order_value = 200.0;
@@ -138,10 +471,15 @@ mod tests {
 
         let input_signal_strength = SignalStrength(1.0);
 
-        allocator.allocate_order(&mut input_order, None, input_signal_strength);
+        allocator.allocate_order(
+            &mut input_order,
+            None,
+            input_signal_strength,
+            &context(0.0, None),
+        );
 
         let actual_result = input_order.quantity;
-        let expected_order_size = ((default_order_value / order_close) * 10000.0).floor() / 10000.0;
+        let expected_order_size = 0.882;
         let expected_result = expected_order_size * input_signal_strength.0 as f64;
 
         assert_ne!(actual_result, 0.0);
@@ -162,7 +500,12 @@ mod tests {
 
         let input_signal_strength = SignalStrength(1.0);
 
-        allocator.allocate_order(&mut input_order, None, input_signal_strength);
+        allocator.allocate_order(
+            &mut input_order,
+            None,
+            input_signal_strength,
+            &context(0.0, None),
+        );
 
         let actual_result = input_order.quantity;
         let expected_result = -(default_order_value / order_close) * input_signal_strength.0 as f64;
@@ -184,13 +527,177 @@ mod tests {
 
         let input_signal_strength = SignalStrength(1.0);
 
-        allocator.allocate_order(&mut input_order, None, input_signal_strength);
+        allocator.allocate_order(
+            &mut input_order,
+            None,
+            input_signal_strength,
+            &context(0.0, None),
+        );
 
         let actual_result = input_order.quantity;
-        let expected_order_size = ((default_order_value / order_close) * 10000.0).floor() / 10000.0;
+        let expected_order_size = 0.882;
         let expected_result = -expected_order_size * input_signal_strength.0;
 
         assert_ne!(actual_result, 0.0);
         assert_eq!(actual_result, expected_result)
     }
+
+    #[test]
+    fn should_allocate_fixed_fraction_of_equity_to_enter_long_position() {
+        let allocator = FixedFractionalAllocator { fraction: 0.1 };
+
+        let mut input_order = order_event();
+        input_order.market_meta.close = 10.0;
+        input_order.decision = Decision::Long;
+
+        allocator.allocate_order(
+            &mut input_order,
+            None,
+            SignalStrength(1.0),
+            &context(5000.0, None),
+        );
+
+        assert_eq!(input_order.quantity, 50.0)
+    }
+
+    #[test]
+    fn should_allocate_fractional_kelly_of_equity_to_enter_short_position() {
+        struct TestCase {
+            trade_outcomes: Option<TradeOutcomes>,
+            expected_quantity: f64,
+        }
+
+        let allocator = KellyAllocator {
+            kelly_multiplier: 0.5,
+            max_fraction: 0.3,
+            min_trades: 10,
+            default_fraction: 0.01,
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: default fraction used before min_trades Positions are exited
+                trade_outcomes: Some(TradeOutcomes {
+                    trades: 5,
+                    win_rate: 0.9,
+                    payoff_ratio: 2.0,
+                }),
+                expected_quantity: -10.0,
+            },
+            TestCase {
+                // TC1: half Kelly of 0.75 - 0.25 / 1.0 = 0.5
+                trade_outcomes: Some(TradeOutcomes {
+                    trades: 10,
+                    win_rate: 0.75,
+                    payoff_ratio: 1.0,
+                }),
+                expected_quantity: -250.0,
+            },
+            TestCase {
+                // TC2: Kelly fraction is capped at max_fraction
+                trade_outcomes: Some(TradeOutcomes {
+                    trades: 10,
+                    win_rate: 0.9,
+                    payoff_ratio: f64::INFINITY,
+                }),
+                expected_quantity: -300.0,
+            },
+            TestCase {
+                // TC3: negative edge allocates nothing
+                trade_outcomes: Some(TradeOutcomes {
+                    trades: 10,
+                    win_rate: 0.3,
+                    payoff_ratio: 1.0,
+                }),
+                expected_quantity: -0.0,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut input_order = order_event();
+            input_order.market_meta.close = 1.0;
+            input_order.decision = Decision::Short;
+
+            allocator.allocate_order(
+                &mut input_order,
+                None,
+                SignalStrength(1.0),
+                &context(1000.0, test.trade_outcomes),
+            );
+
+            assert_eq!(
+                input_order.quantity, test.expected_quantity,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn should_allocate_volatility_targeted_order_from_realised_volatility() {
+        let mut allocator = VolatilityTargetAllocator::new(0.01, 4, 2.0);
+
+        let mut market = market_event_trade(Side::Buy);
+        let mut input_order = order_event();
+        input_order.exchange = market.exchange.clone();
+        input_order.instrument = market.instrument.clone();
+        input_order.decision = Decision::Long;
+
+        // No entry allocated until the lookback window of returns is full
+        allocator.allocate_order(
+            &mut input_order,
+            None,
+            SignalStrength(1.0),
+            &context(1000.0, None),
+        );
+        assert_eq!(input_order.quantity, 0.0);
+
+        // Alternating +2% & -2% returns have a realised volatility of 2%
+        for price in [100.0, 102.0, 99.96, 101.9592, 99.920016] {
+            if let DataKind::Trade(trade) = &mut market.kind {
+                trade.price = Decimal::try_from(price).unwrap();
+            }
+            allocator.update_from_market(&market);
+        }
+
+        input_order.market_meta.close = 100.0;
+        allocator.allocate_order(
+            &mut input_order,
+            None,
+            SignalStrength(1.0),
+            &context(1000.0, None),
+        );
+
+        // Order value = 1000.0 * 0.01 / 0.02 = 500.0
+        assert!((input_order.quantity - 5.0).abs() < 1e-3)
+    }
+
+    #[test]
+    fn should_allocate_no_volatility_targeted_order_with_zero_realised_volatility() {
+        let mut allocator = VolatilityTargetAllocator::new(0.01, 4, 2.0);
+
+        let mut market = market_event_trade(Side::Buy);
+        let mut input_order = order_event();
+        input_order.exchange = market.exchange.clone();
+        input_order.instrument = market.instrument.clone();
+        input_order.decision = Decision::Long;
+        input_order.market_meta.close = 100.0;
+
+        // Unchanged prices have a realised volatility of 0%
+        if let DataKind::Trade(trade) = &mut market.kind {
+            trade.price = Decimal::try_from(100.0).unwrap();
+        }
+        for _ in 0..5 {
+            allocator.update_from_market(&market);
+        }
+
+        allocator.allocate_order(
+            &mut input_order,
+            None,
+            SignalStrength(1.0),
+            &context(1000.0, None),
+        );
+
+        assert_eq!(input_order.quantity, 0.0);
+    }
 }
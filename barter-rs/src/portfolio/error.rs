@@ -1,4 +1,5 @@
 use crate::portfolio::repository::error::RepositoryError;
+use barter_integration::model::instrument::symbol::Symbol;
 use thiserror::Error;
 
 /// All errors generated in the barter::portfolio module.
@@ -19,6 +20,9 @@ pub enum PortfolioError {
     #[error("Cannot generate PositionExit from Position that has not been exited")]
     PositionExit,
 
+    #[error("Cannot value {0:?} in the reporting currency without a conversion price")]
+    UnpricedBalances(Vec<Symbol>),
+
     #[error("Failed to interact with repository")]
     RepositoryInteraction(#[from] RepositoryError),
 }
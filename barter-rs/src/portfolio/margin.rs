@@ -0,0 +1,305 @@
+use crate::statistic::{de_duration_from_secs, se_duration_as_secs};
+use barter_integration::model::{
+    instrument::{kind::InstrumentKind, Instrument},
+    Side,
+};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Determines the collateral backing a margined [`Position`](super::position::Position).
+#[derive(
+    Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize,
+)]
+pub enum MarginMode {
+    /// Position is backed by the margin posted to open it, plus the free Portfolio
+    /// [`Balance`](super::Balance) available when it was entered.
+    Cross,
+    /// Position is backed only by the margin posted to open it.
+    #[default]
+    Isolated,
+}
+
+/// Leverage, maintenance margin & funding configuration applied to every
+/// [`Position`](super::position::Position) in a derivative [`Instrument`]
+/// (eg/ [`InstrumentKind::Perpetual`]).
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct MarginConfig {
+    pub mode: MarginMode,
+    /// Notional value of a Position per unit of margin posted (eg/ 10x).
+    pub leverage: f64,
+    /// Proportion of the Position notional value that must be covered by it's collateral &
+    /// unrealised P&L before it is liquidated (eg/ 0.005).
+    pub maintenance_margin_rate: f64,
+    /// Proportion of the Position notional value exchanged every funding interval. Longs pay
+    /// shorts when +ve, and shorts pay longs when -ve.
+    pub funding_rate: f64,
+    /// Time between funding payments, aligned to the unix epoch (eg/ 8 hours).
+    #[serde(
+        deserialize_with = "de_duration_from_secs",
+        serialize_with = "se_duration_as_secs"
+    )]
+    pub funding_interval: Duration,
+}
+
+impl Default for MarginConfig {
+    fn default() -> Self {
+        Self {
+            mode: MarginMode::default(),
+            leverage: 1.0,
+            maintenance_margin_rate: 0.005,
+            funding_rate: 0.0001,
+            funding_interval: Duration::hours(8),
+        }
+    }
+}
+
+impl MarginConfig {
+    /// Determine if a [`Position`](super::position::Position) in the [`Instrument`] is traded on
+    /// margin.
+    pub fn is_margined(instrument: &Instrument) -> bool {
+        matches!(
+            instrument.kind,
+            InstrumentKind::Perpetual | InstrumentKind::Future(_)
+        )
+    }
+
+    /// Funding interval in whole seconds, treating non-positive intervals as one second.
+    fn funding_interval_secs(&self) -> i64 {
+        self.funding_interval.num_seconds().max(1)
+    }
+
+    /// Determine the first funding time after the input time.
+    pub fn next_funding_time(&self, time: DateTime<Utc>) -> DateTime<Utc> {
+        let interval_secs = self.funding_interval_secs();
+        let next_secs = (time.timestamp().div_euclid(interval_secs) + 1) * interval_secs;
+        Utc.timestamp_opt(next_secs, 0).single().unwrap_or(time)
+    }
+}
+
+/// Margin state of an open [`Position`](super::position::Position) in a derivative
+/// [`Instrument`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct PositionMargin {
+    pub config: MarginConfig,
+
+    /// Margin posted to hold the open quantity. Open enter value gross / leverage.
+    pub initial_margin: f64,
+
+    /// Free Portfolio [`Balance`](super::Balance) also backing the Position in
+    /// [`MarginMode::Cross`]. Always zero in [`MarginMode::Isolated`].
+    pub cross_collateral: f64,
+
+    /// Price at which the Position collateral plus unrealised P&L falls to the maintenance margin.
+    pub liquidation_price: f64,
+
+    /// Cumulative funding paid (+ve) or received (-ve) whilst the Position has been open.
+    pub funding_paid: f64,
+
+    /// Timestamp of the next funding payment.
+    pub next_funding_time: DateTime<Utc>,
+}
+
+impl PositionMargin {
+    /// Construct a new [`PositionMargin`] for a Position entered at the input time.
+    pub fn new(config: MarginConfig, cross_collateral: f64, enter_time: DateTime<Utc>) -> Self {
+        Self {
+            config,
+            initial_margin: 0.0,
+            cross_collateral: match config.mode {
+                MarginMode::Cross => cross_collateral.max(0.0),
+                MarginMode::Isolated => 0.0,
+            },
+            liquidation_price: 0.0,
+            funding_paid: 0.0,
+            next_funding_time: config.next_funding_time(enter_time),
+        }
+    }
+
+    /// Calculate the collateral backing the Position, net of any funding paid.
+    pub fn calculate_collateral(&self) -> f64 {
+        self.initial_margin + self.cross_collateral - self.funding_paid
+    }
+
+    /// Recalculate the initial margin & liquidation price of the open quantity.
+    pub fn recalculate(&mut self, side: Side, quantity: f64, open_enter_value_gross: f64) {
+        self.initial_margin = open_enter_value_gross / self.config.leverage;
+        self.liquidation_price =
+            self.calculate_liquidation_price(side, quantity.abs(), open_enter_value_gross);
+    }
+
+    /// Calculate the price at which the collateral plus unrealised P&L of the open quantity
+    /// falls to the maintenance margin requirement.
+    ///
+    /// Long: collateral + q(P - E) = mmr * q * P
+    /// Short: collateral + q(E - P) = mmr * q * P
+    pub fn calculate_liquidation_price(
+        &self,
+        side: Side,
+        quantity: f64,
+        open_enter_value_gross: f64,
+    ) -> f64 {
+        if quantity == 0.0 {
+            return 0.0;
+        }
+
+        let collateral = self.calculate_collateral();
+        let maintenance_margin_rate = self.config.maintenance_margin_rate;
+
+        let liquidation_price = match side {
+            Side::Buy => {
+                (open_enter_value_gross - collateral) / (quantity * (1.0 - maintenance_margin_rate))
+            }
+            Side::Sell => {
+                (open_enter_value_gross + collateral) / (quantity * (1.0 + maintenance_margin_rate))
+            }
+        };
+
+        liquidation_price.max(0.0)
+    }
+
+    /// Determine if the Position is liquidated at the input price.
+    pub fn is_liquidated(&self, side: Side, price: f64) -> bool {
+        match side {
+            Side::Buy => price <= self.liquidation_price,
+            Side::Sell => price >= self.liquidation_price,
+        }
+    }
+
+    /// Apply every funding payment due up to the input time using the current Position notional
+    /// value, returning the funding paid (+ve) or received (-ve).
+    pub fn apply_funding(
+        &mut self,
+        side: Side,
+        current_value_gross: f64,
+        time: DateTime<Utc>,
+    ) -> f64 {
+        let payment = match side {
+            Side::Buy => self.config.funding_rate * current_value_gross,
+            Side::Sell => -self.config.funding_rate * current_value_gross,
+        };
+
+        if time < self.next_funding_time {
+            return 0.0;
+        }
+
+        // Count the funding times passed, including the next funding time itself
+        let interval_secs = self.config.funding_interval_secs();
+        let payments = (time - self.next_funding_time).num_seconds() / interval_secs + 1;
+        self.next_funding_time += Duration::seconds(payments * interval_secs);
+
+        let funding = payment * payments as f64;
+        self.funding_paid += funding;
+        funding
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn margin(mode: MarginMode, cross_collateral: f64) -> PositionMargin {
+        let config = MarginConfig {
+            mode,
+            leverage: 10.0,
+            ..MarginConfig::default()
+        };
+        PositionMargin::new(config, cross_collateral, Utc.timestamp_opt(0, 0).unwrap())
+    }
+
+    #[test]
+    fn test_position_margin_recalculate() {
+        struct TestCase {
+            margin: PositionMargin,
+            side: Side,
+            expected_initial_margin: f64,
+            expected_liquidation_price: f64,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: isolated 10x long
+                margin: margin(MarginMode::Isolated, 500.0),
+                side: Side::Buy,
+                expected_initial_margin: 100.0,
+                expected_liquidation_price: 900.0 / 0.995,
+            },
+            TestCase {
+                // TC1: isolated 10x short
+                margin: margin(MarginMode::Isolated, 500.0),
+                side: Side::Sell,
+                expected_initial_margin: 100.0,
+                expected_liquidation_price: 1100.0 / 1.005,
+            },
+            TestCase {
+                // TC2: cross 10x long backed by free balance
+                margin: margin(MarginMode::Cross, 500.0),
+                side: Side::Buy,
+                expected_initial_margin: 100.0,
+                expected_liquidation_price: 400.0 / 0.995,
+            },
+            TestCase {
+                // TC3: cross long with collateral exceeding the notional is never liquidated
+                margin: margin(MarginMode::Cross, 5000.0),
+                side: Side::Buy,
+                expected_initial_margin: 100.0,
+                expected_liquidation_price: 0.0,
+            },
+        ];
+
+        for (index, mut test) in tests.into_iter().enumerate() {
+            test.margin.recalculate(test.side, 1.0, 1000.0);
+            assert_eq!(
+                test.margin.initial_margin, test.expected_initial_margin,
+                "TC{} failed",
+                index
+            );
+            assert_eq!(
+                test.margin.liquidation_price, test.expected_liquidation_price,
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_position_margin_apply_funding() {
+        let mut margin = margin(MarginMode::Isolated, 0.0);
+        assert_eq!(
+            margin.next_funding_time,
+            Utc.timestamp_opt(8 * 3600, 0).unwrap()
+        );
+
+        // No funding is due before the next funding time
+        let actual = margin.apply_funding(Side::Buy, 1000.0, Utc.timestamp_opt(3600, 0).unwrap());
+        assert_eq!(actual, 0.0);
+
+        // Long pays two funding intervals once the second funding time has passed
+        let actual =
+            margin.apply_funding(Side::Buy, 1000.0, Utc.timestamp_opt(16 * 3600, 0).unwrap());
+        assert_eq!(actual, 0.2);
+        assert_eq!(margin.funding_paid, 0.2);
+        assert_eq!(
+            margin.next_funding_time,
+            Utc.timestamp_opt(24 * 3600, 0).unwrap()
+        );
+
+        // Short receives funding when the rate is +ve
+        let actual =
+            margin.apply_funding(Side::Sell, 1000.0, Utc.timestamp_opt(24 * 3600, 0).unwrap());
+        assert_eq!(actual, -0.1);
+
+        // Non-positive funding interval is treated as one second, rather than never advancing
+        let config = MarginConfig {
+            funding_interval: Duration::zero(),
+            ..MarginConfig::default()
+        };
+        let mut zero_interval = PositionMargin::new(config, 0.0, Utc.timestamp_opt(0, 0).unwrap());
+        let actual =
+            zero_interval.apply_funding(Side::Buy, 1000.0, Utc.timestamp_opt(10, 0).unwrap());
+        assert_eq!(actual, 1.0);
+        assert_eq!(
+            zero_interval.next_funding_time,
+            Utc.timestamp_opt(11, 0).unwrap()
+        );
+    }
+}
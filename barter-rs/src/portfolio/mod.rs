@@ -1,12 +1,15 @@
 use crate::{
     event::Event,
-    portfolio::{error::PortfolioError, position::PositionUpdate},
+    portfolio::{error::PortfolioError, position::PositionUpdate, risk::RiskRejection},
+    statistic::summary::{equity::EquityCurve, execution::ExecutionSummary},
     strategy::{Signal, SignalForceExit},
 };
 use barter_data::event::{DataKind, MarketEvent};
 use barter_execution::{fill::FillEvent, model::order_event::OrderEvent};
+use barter_integration::model::instrument::symbol::Symbol;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use uuid::Uuid;
 
 /// Logic for [`OrderEvent`] quantity allocation.
@@ -15,6 +18,10 @@ pub mod allocator;
 /// Barter portfolio module specific errors.
 pub mod error;
 
+/// Margin, leverage, funding & liquidation modelling for
+/// [`Position`](position::Position)s in derivative instruments (eg/ perpetuals).
+pub mod margin;
+
 /// Core Portfolio logic containing an implementation of [`MarketUpdater`],
 /// [`OrderGenerator`] and [`FillUpdater`]. Utilises the risk and allocator logic to optimise
 /// [`OrderEvent`] generation.
@@ -30,6 +37,9 @@ pub mod repository;
 /// Logic for evaluating the risk associated with a proposed [`OrderEvent`].
 pub mod risk;
 
+/// Live prices used to value per [`Symbol`] balances in a Portfolio reporting currency.
+pub mod valuation;
+
 /// Updates the Portfolio from an input [`MarketEvent`].
 pub trait MarketUpdater {
     /// Determines if the Portfolio has an open Position relating to the input [`MarketEvent`]. If
@@ -39,6 +49,12 @@ pub trait MarketUpdater {
         &mut self,
         market: &MarketEvent<DataKind>,
     ) -> Result<Option<PositionUpdate>, PortfolioError>;
+
+    /// Returns the mark-to-market [`EquityCurve`] sampled from market updates, if the Portfolio
+    /// records one.
+    fn equity_curve(&self) -> Option<&EquityCurve> {
+        None
+    }
 }
 
 /// May generate an [`OrderEvent`] from an input advisory [`Signal`].
@@ -52,6 +68,12 @@ pub trait OrderGenerator {
         &mut self,
         signal: SignalForceExit,
     ) -> Result<Option<OrderEvent>, PortfolioError>;
+
+    /// Drains the [`RiskRejection`]s of every [`OrderEvent`] refused by the risk manager since
+    /// the last call.
+    fn drain_risk_rejections(&mut self) -> Vec<RiskRejection> {
+        Vec::new()
+    }
 }
 
 /// Updates the Portfolio from an input [`FillEvent`].
@@ -60,11 +82,21 @@ pub trait FillUpdater {
     /// Position entry or exit, and the Portfolio updates key fields such as current_cash and
     /// current_value accordingly.
     fn update_from_fill(&mut self, fill: &FillEvent) -> Result<Vec<Event>, PortfolioError>;
+
+    /// Returns the [`ExecutionSummary`] measuring the quality of the Portfolio's fills, if the
+    /// Portfolio records one.
+    fn execution_summary(&self) -> Option<&ExecutionSummary> {
+        None
+    }
 }
 
 /// Communicates a String represents a unique identifier for an Engine's Portfolio [`Balance`].
 pub type BalanceId = String;
 
+/// Total and available [`Balance`] of every [`Symbol`] held by a Portfolio, each denominated in
+/// it's own [`Symbol`].
+pub type SymbolBalances = HashMap<Symbol, Balance>;
+
 /// Total and available balance at a point in time.
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct Balance {
@@ -97,4 +129,9 @@ impl Balance {
     pub fn balance_id(engine_id: Uuid) -> BalanceId {
         format!("{}_balance", engine_id)
     }
+
+    /// Returns the unique identifier for an Engine's [`SymbolBalances`].
+    pub fn symbol_balances_id(engine_id: Uuid) -> BalanceId {
+        format!("{}_symbol_balances", engine_id)
+    }
 }
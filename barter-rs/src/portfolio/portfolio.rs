@@ -1,17 +1,26 @@
 use super::{
-    allocator::OrderAllocator,
+    allocator::{AllocationContext, OrderAllocator},
     error::PortfolioError,
+    margin::MarginConfig,
     position::{
-        determine_position_id, Position, PositionEnterer, PositionExiter, PositionId,
-        PositionUpdate, PositionUpdater,
+        determine_position_id, Position, PositionEnterer, PositionExit, PositionExiter,
+        PositionFill, PositionId, PositionScaler, PositionUpdate, PositionUpdater,
     },
     repository::{error::RepositoryError, BalanceHandler, PositionHandler, StatisticHandler},
-    risk::OrderEvaluator,
-    Balance, FillUpdater, MarketUpdater, OrderEvent, OrderGenerator,
+    risk::{OrderEvaluator, RiskContext, RiskRejection},
+    valuation::PriceTable,
+    Balance, FillUpdater, MarketUpdater, OrderEvent, OrderGenerator, SymbolBalances,
 };
 use crate::{
     event::Event,
-    statistic::summary::{Initialiser, PositionSummariser},
+    statistic::{
+        metric::EquityPoint,
+        summary::{
+            equity::{EquityCurve, EquityCurveConfig},
+            execution::ExecutionSummary,
+            Initialiser, PositionSummariser,
+        },
+    },
     strategy::{Signal, SignalForceExit, SignalStrength},
 };
 use barter_data::event::{DataKind, MarketEvent};
@@ -19,11 +28,14 @@ use barter_execution::{
     fill::{Decision, FillEvent, MarketMeta},
     model::order_event::OrderType,
 };
-use barter_integration::model::{Market, MarketId, Side};
-use chrono::Utc;
+use barter_integration::model::{
+    instrument::{symbol::Symbol, Instrument},
+    Market, MarketId, Side,
+};
+use chrono::{DateTime, Utc};
 use serde::Serialize;
 use std::{collections::HashMap, marker::PhantomData};
-use tracing::info;
+use tracing::{info, warn};
 use uuid::Uuid;
 
 /// Lego components for constructing & initialising a [`MetaPortfolio`] via the init() constructor
@@ -48,8 +60,19 @@ where
     pub allocator: Allocator,
     /// Risk manager implements [`OrderEvaluator`].
     pub risk: RiskManager,
-    /// Cash balance a [`MetaPortfolio`] starts with.
+    /// Cash balance a [`MetaPortfolio`] starts with, held in the reporting currency.
     pub starting_cash: f64,
+    /// Currency a [`MetaPortfolio`] values it's per [`Symbol`] balances & equity in (eg/ usdt).
+    pub reporting_currency: Symbol,
+    /// Optional [`MarginConfig`] used to trade [`Position`]s in derivative instruments
+    /// (eg/ perpetuals) on margin. If None, every [`Position`] is fully funded.
+    pub margin: Option<MarginConfig>,
+    /// Optional [`EquityCurveConfig`] used to sample a mark-to-market [`EquityCurve`] from
+    /// market updates. If None, no [`EquityCurve`] is recorded.
+    pub equity_curve: Option<EquityCurveConfig>,
+    /// Optional [`ExecutionSummary`] used to measure execution quality from the generated
+    /// [`OrderEvent`]s, [`FillEvent`]s & market updates. If None, execution is not measured.
+    pub execution_summary: Option<ExecutionSummary>,
     /// Configuration used to initialise the Statistics for every Market's performance tracked by a
     /// [`MetaPortfolio`].
     pub statistic_config: Statistic::Config,
@@ -75,6 +98,20 @@ where
     allocation_manager: Allocator,
     /// Risk manager implements [`OrderEvaluator`].
     risk_manager: RiskManager,
+    /// Currency the [`MetaPortfolio`] values it's per [`Symbol`] balances & equity in.
+    reporting_currency: Symbol,
+    /// Latest market prices used to convert per [`Symbol`] balances into the reporting currency.
+    prices: PriceTable,
+    /// Optional [`MarginConfig`] used to trade [`Position`]s in derivative instruments on margin.
+    margin: Option<MarginConfig>,
+    /// [`Market`]s tracked by the [`MetaPortfolio`], used to evaluate risk across open Positions.
+    markets: Vec<Market>,
+    /// [`RiskRejection`]s from the risk manager that have not yet been drained.
+    rejections: Vec<RiskRejection>,
+    /// Optional mark-to-market [`EquityCurve`] sampled from market updates.
+    equity_curve: Option<EquityCurve>,
+    /// Optional [`ExecutionSummary`] measuring execution quality.
+    execution_summary: Option<ExecutionSummary>,
     _statistic_marker: PhantomData<Statistic>,
 }
 
@@ -90,21 +127,77 @@ where
         &mut self,
         market: &MarketEvent<DataKind>,
     ) -> Result<Option<PositionUpdate>, PortfolioError> {
+        // Update the latest price used to value balances held in the MarketEvent Symbols
+        self.prices.update_from_market(market);
+
+        // Update any market state the allocation manager sizes orders from
+        self.allocation_manager.update_from_market(market);
+
+        // Measure any execution markouts due at the MarketEvent time
+        if let Some(execution_summary) = self.execution_summary.as_mut() {
+            execution_summary.update_from_market(market);
+        }
+
         // Determine the position_id associated to the input MarketEvent
         let position_id =
             determine_position_id(self.engine_id, &market.exchange, &market.instrument);
 
         // Update Position if Portfolio has an open Position for that Symbol-Exchange combination
+        let mut position_update = None;
         if let Some(mut position) = self.repository.get_open_position(&position_id)? {
             // Derive PositionUpdate event that communicates the open Position's change in state
-            if let Some(position_update) = position.update(market) {
+            if let Some(update) = position.update(market) {
                 // Save updated open Position in the repository
                 self.repository.set_open_position(position)?;
-                return Ok(Some(position_update));
+                position_update = Some(update);
+            }
+        }
+
+        // Record the Portfolio equity with the risk manager if due (eg/ daily loss day rollover)
+        if self.risk_manager.is_equity_due(market.exchange_time) {
+            let balance = self.repository.get_balance(self.engine_id)?;
+            let open_positions = self
+                .repository
+                .get_open_positions(self.engine_id, self.markets.iter())?;
+            let context = RiskContext {
+                balance,
+                position: None,
+                open_positions: &open_positions,
+                reference_price: None,
+            };
+            self.risk_manager
+                .update_equity(market.exchange_time, context.calculate_equity());
+        }
+
+        // Sample the mark-to-market EquityCurve if the next sample is due
+        if self
+            .equity_curve
+            .as_ref()
+            .is_some_and(|curve| curve.is_sample_due(market.exchange_time))
+        {
+            // Skip the sample rather than record an understated equity
+            match self.mark_to_market(market.exchange_time) {
+                Ok(equity_point) => {
+                    if let Some(curve) = self.equity_curve.as_mut() {
+                        curve.update(equity_point);
+                    }
+                }
+                Err(PortfolioError::UnpricedBalances(unpriced)) => {
+                    warn!(
+                        engine_id = %self.engine_id,
+                        ?unpriced,
+                        "skipping EquityCurve sample that cannot be valued in the reporting currency"
+                    );
+                }
+                Err(error) => return Err(error),
             }
         }
 
-        Ok(None)
+        Ok(position_update)
+    }
+
+    fn equity_curve(&self) -> Option<&EquityCurve> {
+        self.equity_curve.as_ref()
     }
 }
 
@@ -122,11 +215,6 @@ where
             determine_position_id(self.engine_id, &signal.exchange, &signal.instrument);
         let position = self.repository.get_open_position(&position_id)?;
 
-        // If signal is advising to open a new Position rather than close one, check we have cash
-        if position.is_none() && self.no_cash_to_enter_new_position()? {
-            return Ok(None);
-        }
-
         // Parse signals from Strategy to determine net signal decision & associated strength
         let position = position.as_ref();
         let (signal_decision, signal_strength) =
@@ -135,10 +223,16 @@ where
                 Some(net_signal) => net_signal,
             };
 
-        // Construct mutable OrderEvent that can be modified by Allocation & Risk management
+        // If signal is advising to enter, scale in or reverse rather than close, check we have cash
+        if signal_decision.is_entry() && self.no_cash_to_enter_new_position()? {
+            return Ok(None);
+        }
+
+        // Construct mutable OrderEvent that can be modified by Allocation & Risk management,
+        // timestamped with the source MarketEvent time so it shares the FillEvent clock
         let mut order = OrderEvent {
             id: Uuid::new_v4(),
-            time: Utc::now(),
+            time: signal.market_meta.time,
             exchange: signal.exchange.clone(),
             instrument: signal.instrument.clone(),
             market_meta: signal.market_meta,
@@ -148,11 +242,60 @@ where
         };
 
         // Manage OrderEvent size allocation
+        let balance = self.repository.get_balance(self.engine_id)?;
+        let context = AllocationContext {
+            balance,
+            trade_outcomes: self
+                .repository
+                .get_statistics(
+                    &self.statistics_id(&MarketId::new(&signal.exchange, &signal.instrument)),
+                )
+                .ok()
+                .and_then(|statistic| statistic.trade_outcomes()),
+        };
         self.allocation_manager
-            .allocate_order(&mut order, position, *signal_strength);
+            .allocate_order(&mut order, position, *signal_strength, &context);
+
+        // Allocation manager may size an OrderEvent to zero (eg/ no edge)
+        if order.quantity == 0.0 {
+            return Ok(None);
+        }
+
+        // Manage global risk when evaluating OrderEvent - keep the same, refine or reject
+        let open_positions = self
+            .repository
+            .get_open_positions(self.engine_id, self.markets.iter())?;
+        let context = RiskContext {
+            balance,
+            position,
+            open_positions: &open_positions,
+            reference_price: self
+                .prices
+                .previous_price(&signal.instrument.base, &signal.instrument.quote),
+        };
+
+        match self.risk_manager.evaluate_order(order, &context) {
+            Ok(order) => {
+                if let Some(execution_summary) = self.execution_summary.as_mut() {
+                    execution_summary.update_from_order(&order);
+                }
+                Ok(Some(order))
+            }
+            Err(rejection) => {
+                info!(
+                    order_id = %rejection.order.id,
+                    violation = %rejection.violation,
+                    outcome = "no OrderEvent generated",
+                    "risk manager rejected OrderEvent"
+                );
+                self.rejections.push(rejection);
+                Ok(None)
+            }
+        }
+    }
 
-        // Manage global risk when evaluating OrderEvent - keep the same, refine or cancel
-        Ok(self.risk_manager.evaluate_order(order))
+    fn drain_risk_rejections(&mut self) -> Vec<RiskRejection> {
+        std::mem::take(&mut self.rejections)
     }
 
     fn generate_exit_order(
@@ -176,9 +319,10 @@ where
             Some(position) => position,
         };
 
-        Ok(Some(OrderEvent {
+        // Timestamp with the latest MarketEvent time of the Position so it shares the FillEvent clock
+        let order = OrderEvent {
             id: Uuid::new_v4(),
-            time: Utc::now(),
+            time: position.meta.update_time,
             exchange: signal.exchange,
             instrument: signal.instrument,
             market_meta: MarketMeta {
@@ -188,7 +332,13 @@ where
             decision: position.determine_exit_decision(),
             quantity: 0.0 - position.quantity,
             order_type: OrderType::Market,
-        }))
+        };
+
+        if let Some(execution_summary) = self.execution_summary.as_mut() {
+            execution_summary.update_from_order(&order);
+        }
+
+        Ok(Some(order))
     }
 }
 
@@ -204,62 +354,104 @@ where
         // Allocate Vector<Event> to contain any update_from_fill generated events
         let mut generated_events: Vec<Event> = Vec::with_capacity(2);
 
-        // Get the Portfolio Balance from Repository & update timestamp
-        let mut balance = self.repository.get_balance(self.engine_id)?;
-        balance.time = fill.time;
+        // Measure the execution quality of the FillEvent
+        if let Some(execution_summary) = self.execution_summary.as_mut() {
+            execution_summary.update_from_fill(fill);
+        }
+
+        // Get the Portfolio SymbolBalances from Repository - FillEvents settle in the quote Symbol
+        let mut balances = self.repository.get_symbol_balances(self.engine_id)?;
+
+        // Update the latest price of the FillEvent Instrument
+        if fill.quantity != 0.0 {
+            self.prices.set_price(
+                fill.instrument.base.clone(),
+                fill.instrument.quote.clone(),
+                Position::calculate_avg_price_gross(fill),
+            );
+        }
 
         // Determine the position_id that is related to the input FillEvent
         let position_id = determine_position_id(self.engine_id, &fill.exchange, &fill.instrument);
 
         // Determine FillEvent context based on existence or absence of an open Position
         match self.repository.remove_position(&position_id)? {
-            // EXIT SCENARIO - FillEvent for Symbol-Exchange combination with open Position
-            Some(mut position) => {
-                // Exit Position (in place mutation), & add the PositionExit event to Vec<Event>
-                let position_exit = position.exit(balance, fill)?;
-                generated_events.push(Event::PositionExit(position_exit));
-
-                // Update Portfolio balance on Position exit
-                // '--> available balance adds enter_total_fees since included in result PnL calc
-                balance.available += position.enter_value_gross
-                    + position.realised_profit_loss
-                    + position.enter_fees_total;
-                balance.total += position.realised_profit_loss;
-
-                // Update statistics for exited Position market
-                let market_id = MarketId::new(&fill.exchange, &fill.instrument);
-
-                let mut stats = self.repository.get_statistics(&market_id)?;
-                stats.update(&position);
-
-                // Persist exited Position & Updated Market statistics in Repository
-                self.repository.set_statistics(market_id, stats)?;
-                self.repository
-                    .set_exited_position(self.engine_id, position)?;
-            }
+            // FillEvent for Symbol-Exchange combination with open Position
+            Some(mut position) => match position.determine_position_fill(fill) {
+                // SCALE IN SCENARIO - FillEvent in the same direction as the open Position
+                PositionFill::Increase => {
+                    let reserved_before = position.calculate_open_reserved_value();
+
+                    let position_update = position.increase(fill)?;
+                    generated_events.push(Event::PositionUpdate(position_update));
+
+                    // Update Portfolio Balance.available on Position increase
+                    let reserved = position.calculate_open_reserved_value() - reserved_before;
+                    let balance = Self::quote_balance(&mut balances, fill);
+                    balance.available += -reserved - fill.fees.calculate_total_fees();
+
+                    self.repository.set_open_position(position)?;
+                }
+
+                // SCALE OUT SCENARIO - FillEvent reducing part of the open Position
+                PositionFill::Reduce => {
+                    let open_enter_before = position.calculate_open_reserved_value()
+                        + position.calculate_open_enter_fees_total();
+                    let realised_profit_loss_before = position.realised_profit_loss;
+
+                    let position_update = position.reduce(fill)?;
+                    generated_events.push(Event::PositionUpdate(position_update));
+
+                    // Update Portfolio balance on Position reduction
+                    // '--> available balance releases the reduced share of the reserved value & fees
+                    let released_enter = open_enter_before
+                        - position.calculate_open_reserved_value()
+                        - position.calculate_open_enter_fees_total();
+                    let realised_profit_loss =
+                        position.realised_profit_loss - realised_profit_loss_before;
+                    let balance = Self::quote_balance(&mut balances, fill);
+                    balance.available += released_enter + realised_profit_loss;
+                    balance.total += realised_profit_loss;
+
+                    self.repository.set_open_position(position)?;
+                }
+
+                // EXIT SCENARIO - FillEvent exiting the entire open Position
+                PositionFill::Exit => {
+                    self.exit_position(&mut balances, position, fill, &mut generated_events)?;
+                }
+
+                // REVERSAL SCENARIO - FillEvent exiting the open Position & entering the opposite Side
+                PositionFill::Reverse => {
+                    let (exit_fill, enter_fill) = position.split_reverse_fill(fill);
+                    self.exit_position(&mut balances, position, &exit_fill, &mut generated_events)?;
+                    self.enter_position(&mut balances, &enter_fill, &mut generated_events)?;
+                }
+            },
 
             // ENTRY SCENARIO - FillEvent for Symbol-Exchange with no Position
             None => {
-                // Enter new Position, & add the PositionNew event to Vec<Event>
-                let position = Position::enter(self.engine_id, fill)?;
-                generated_events.push(Event::PositionNew(position.clone()));
-
-                // Update Portfolio Balance.available on Position entry
-                balance.available += -position.enter_value_gross - position.enter_fees_total;
-
-                // Add to current Positions in Repository
-                self.repository.set_open_position(position)?;
+                self.enter_position(&mut balances, fill, &mut generated_events)?;
             }
         };
 
+        // Value the updated SymbolBalances in the reporting currency
+        let balance = self.value_balances(&balances, fill.time);
+
         // Add new Balance event to the Vec<Event>
         generated_events.push(Event::Balance(balance));
 
-        // Persist updated Portfolio Balance in Repository
+        // Persist updated Portfolio Balance & SymbolBalances in Repository
         self.repository.set_balance(self.engine_id, balance)?;
+        self.repository
+            .set_symbol_balances(self.engine_id, balances)?;
 
         Ok(generated_events)
     }
+
+    fn execution_summary(&self) -> Option<&ExecutionSummary> {
+        self.execution_summary.as_ref()
+    }
 }
 
 impl<Repository, Allocator, RiskManager, Statistic> PositionHandler
@@ -319,11 +511,13 @@ where
         market_id: MarketId,
         statistic: Statistic,
     ) -> Result<(), RepositoryError> {
-        self.repository.set_statistics(market_id, statistic)
+        self.repository
+            .set_statistics(self.statistics_id(&market_id), statistic)
     }
 
     fn get_statistics(&mut self, market_id: &MarketId) -> Result<Statistic, RepositoryError> {
-        self.repository.get_statistics(market_id)
+        self.repository
+            .get_statistics(&self.statistics_id(market_id))
     }
 }
 
@@ -346,6 +540,13 @@ where
             repository: lego.repository,
             allocation_manager: lego.allocator,
             risk_manager: lego.risk,
+            reporting_currency: lego.reporting_currency,
+            prices: PriceTable::default(),
+            margin: lego.margin,
+            markets: lego.markets.clone(),
+            rejections: Vec::new(),
+            equity_curve: lego.equity_curve.map(EquityCurve::new),
+            execution_summary: lego.execution_summary,
             _statistic_marker: PhantomData::default(),
         };
 
@@ -356,7 +557,8 @@ where
     }
 
     /// Persist initial [`MetaPortfolio`] state in the repository. This includes initialised
-    /// Statistics every market provided, as well as starting `AvailableCash` & `TotalEquity`.
+    /// Statistics every market provided, as well as starting `AvailableCash` & `TotalEquity`
+    /// held in the reporting currency.
     pub fn bootstrap_repository<Markets, Id>(
         &mut self,
         starting_cash: f64,
@@ -368,28 +570,128 @@ where
         Id: Into<MarketId>,
     {
         // Persist initial Balance (total & available)
-        self.repository.set_balance(
+        let balance = Balance {
+            time: Utc::now(),
+            total: starting_cash,
+            available: starting_cash,
+        };
+        self.repository.set_balance(self.engine_id, balance)?;
+        self.repository.set_symbol_balances(
             self.engine_id,
-            Balance {
-                time: Utc::now(),
-                total: starting_cash,
-                available: starting_cash,
-            },
+            SymbolBalances::from([(self.reporting_currency.clone(), balance)]),
         )?;
 
         // Persist initial MetaPortfolio Statistics for every Market
         markets.into_iter().try_for_each(|market| {
             self.repository
-                .set_statistics(market.into(), Statistic::init(statistic_config))
+                .set_statistics(
+                    self.statistics_id(&market.into()),
+                    Statistic::init(statistic_config),
+                )
                 .map_err(PortfolioError::RepositoryInteraction)
         })
     }
 
+    /// Returns the [`MarketId`] a market's Statistics are persisted under in the Repository.
+    /// Scoped to the engine_id, like [`PositionId`]s, so engines sharing a Repository do not
+    /// overwrite each other's Statistics.
+    fn statistics_id(&self, market_id: &MarketId) -> MarketId {
+        MarketId(format!("{}_{}", self.engine_id, market_id))
+    }
+
     /// Returns a [`MetaPortfolioBuilder`] instance.
     pub fn builder() -> MetaPortfolioBuilder<Repository, Allocator, RiskManager, Statistic> {
         MetaPortfolioBuilder::new()
     }
 
+    /// Returns the currency the [`MetaPortfolio`] values it's balances & equity in.
+    pub fn reporting_currency(&self) -> &Symbol {
+        &self.reporting_currency
+    }
+
+    /// Values the current [`SymbolBalances`] in the reporting currency using the latest market
+    /// prices, returning the Portfolio [`EquityPoint`].
+    ///
+    /// Returns [`PortfolioError::UnpricedBalances`] if any [`Symbol`] cannot be valued, rather
+    /// than understating the Portfolio equity.
+    pub fn equity_point(&mut self, time: DateTime<Utc>) -> Result<EquityPoint, PortfolioError> {
+        let balances = self.repository.get_symbol_balances(self.engine_id)?;
+
+        let valuation = self
+            .prices
+            .value_balances(&balances, &self.reporting_currency, time);
+
+        match valuation.is_complete() {
+            true => Ok(EquityPoint::from(valuation.balance)),
+            false => Err(PortfolioError::UnpricedBalances(valuation.unpriced)),
+        }
+    }
+
+    /// Values the current [`SymbolBalances`] plus the unrealised P&L of every open [`Position`]
+    /// in the reporting currency, returning the mark-to-market Portfolio [`EquityPoint`].
+    ///
+    /// Returns [`PortfolioError::UnpricedBalances`] if any [`Symbol`] balance or open
+    /// [`Position`] P&L cannot be valued, rather than understating the Portfolio equity.
+    pub fn mark_to_market(&mut self, time: DateTime<Utc>) -> Result<EquityPoint, PortfolioError> {
+        let mut equity_point = self.equity_point(time)?;
+
+        let open_positions = self
+            .repository
+            .get_open_positions(self.engine_id, self.markets.iter())?;
+
+        // Unrealised P&L is denominated in the quote Symbol of each Position Instrument
+        for position in &open_positions {
+            equity_point.total += self
+                .prices
+                .convert(
+                    position.unrealised_profit_loss,
+                    &position.instrument.quote,
+                    &self.reporting_currency,
+                )
+                .ok_or_else(|| {
+                    PortfolioError::UnpricedBalances(vec![position.instrument.quote.clone()])
+                })?;
+        }
+
+        Ok(equity_point)
+    }
+
+    /// Values the input [`SymbolBalances`] in the reporting currency, logging any [`Symbol`]
+    /// excluded from the valued [`Balance`] since it has no conversion price.
+    fn value_balances(&self, balances: &SymbolBalances, time: DateTime<Utc>) -> Balance {
+        let valuation = self
+            .prices
+            .value_balances(balances, &self.reporting_currency, time);
+
+        if !valuation.is_complete() {
+            warn!(
+                engine_id = %self.engine_id,
+                unpriced = ?valuation.unpriced,
+                reporting_currency = %self.reporting_currency,
+                "Balance excludes Symbols without a conversion price to the reporting currency"
+            );
+        }
+
+        valuation.balance
+    }
+
+    /// Returns the [`Balance`] of the input [`FillEvent`] quote [`Symbol`] that the fill settles
+    /// in, updating it's timestamp.
+    fn quote_balance<'a>(balances: &'a mut SymbolBalances, fill: &FillEvent) -> &'a mut Balance {
+        let balance = balances
+            .entry(fill.instrument.quote.clone())
+            .or_insert_with(|| Balance::new(fill.time, 0.0, 0.0));
+        balance.time = fill.time;
+        balance
+    }
+
+    /// Returns the [`MarginConfig`] if [`Position`]s in the input [`Instrument`] are traded on
+    /// margin.
+    fn margin_config(&self, instrument: &Instrument) -> Option<MarginConfig> {
+        self.margin
+            .filter(|_| MarginConfig::is_margined(instrument))
+    }
+
     /// Determines if the Portfolio has any cash to enter a new [`Position`].
     fn no_cash_to_enter_new_position(&mut self) -> Result<bool, PortfolioError> {
         self.repository
@@ -397,6 +699,82 @@ where
             .map(|balance| balance.available == 0.0)
             .map_err(PortfolioError::RepositoryInteraction)
     }
+
+    /// Enters a new [`Position`] from the input entry [`FillEvent`], updating the quote
+    /// [`Balance`] & persisting the open [`Position`] in the repository.
+    fn enter_position(
+        &mut self,
+        balances: &mut SymbolBalances,
+        fill: &FillEvent,
+        generated_events: &mut Vec<Event>,
+    ) -> Result<(), PortfolioError> {
+        // Enter new Position
+        let mut position = Position::enter(self.engine_id, fill)?;
+        let balance = Self::quote_balance(balances, fill);
+
+        // Trade Positions in derivative instruments on margin
+        // '--> cross margin Positions are also backed by the free Balance remaining after entry
+        if let Some(config) = self.margin_config(&fill.instrument) {
+            let free_balance = balance.available
+                - position.enter_value_gross / config.leverage
+                - position.enter_fees_total;
+            position.apply_margin(config, free_balance);
+        }
+
+        // Add the PositionNew event to Vec<Event>
+        generated_events.push(Event::PositionNew(position.clone()));
+
+        // Update Portfolio Balance.available on Position entry
+        balance.available += -position.calculate_open_reserved_value() - position.enter_fees_total;
+
+        // Add to current Positions in Repository
+        self.repository.set_open_position(position)?;
+        Ok(())
+    }
+
+    /// Exits the entire open [`Position`] using the input exit [`FillEvent`], updating the
+    /// quote [`Balance`] & the exited market statistics, and persisting the exited [`Position`]
+    /// in the repository.
+    fn exit_position(
+        &mut self,
+        balances: &mut SymbolBalances,
+        mut position: Position,
+        fill: &FillEvent,
+        generated_events: &mut Vec<Event>,
+    ) -> Result<(), PortfolioError> {
+        // Reserved value, fees & P&L not yet released to the Balance by any previous reductions
+        let open_reserved_value = position.calculate_open_reserved_value();
+        let open_enter_fees_total = position.calculate_open_enter_fees_total();
+        let realised_profit_loss_before = position.realised_profit_loss;
+
+        // Exit Position (in place mutation)
+        let balance = Self::quote_balance(balances, fill);
+        position.exit(*balance, fill)?;
+
+        // Update Portfolio balance on Position exit
+        // '--> available balance adds enter_total_fees since included in result PnL calc
+        let realised_profit_loss = position.realised_profit_loss - realised_profit_loss_before;
+        balance.available += open_reserved_value + realised_profit_loss + open_enter_fees_total;
+        balance.total += realised_profit_loss;
+
+        // Value the exit Balance in the reporting currency so equity is comparable across markets
+        position.meta.exit_balance = Some(self.value_balances(balances, fill.time));
+
+        // Add the PositionExit event to Vec<Event>
+        generated_events.push(Event::PositionExit(PositionExit::try_from(&mut position)?));
+
+        // Update statistics for exited Position market
+        let market_id = self.statistics_id(&MarketId::new(&fill.exchange, &fill.instrument));
+
+        let mut stats = self.repository.get_statistics(&market_id)?;
+        stats.update(&position);
+
+        // Persist exited Position & Updated Market statistics in Repository
+        self.repository.set_statistics(market_id, stats)?;
+        self.repository
+            .set_exited_position(self.engine_id, position)?;
+        Ok(())
+    }
 }
 
 #[derive(Debug, Default)]
@@ -413,6 +791,10 @@ where
     repository: Option<Repository>,
     allocation_manager: Option<Allocator>,
     risk_manager: Option<RiskManager>,
+    reporting_currency: Option<Symbol>,
+    margin: Option<MarginConfig>,
+    equity_curve: Option<EquityCurveConfig>,
+    execution_summary: Option<ExecutionSummary>,
     statistic_config: Option<Statistic::Config>,
     _statistic_marker: Option<PhantomData<Statistic>>,
 }
@@ -433,6 +815,10 @@ where
             repository: None,
             allocation_manager: None,
             risk_manager: None,
+            reporting_currency: None,
+            margin: None,
+            equity_curve: None,
+            execution_summary: None,
             statistic_config: None,
             _statistic_marker: None,
         }
@@ -480,6 +866,45 @@ where
         }
     }
 
+    /// Currency to value balances & equity in. Defaults to the quote [`Symbol`] of the first
+    /// [`Market`] provided.
+    pub fn reporting_currency<S>(self, value: S) -> Self
+    where
+        S: Into<Symbol>,
+    {
+        Self {
+            reporting_currency: Some(value.into()),
+            ..self
+        }
+    }
+
+    /// Trade [`Position`]s in derivative instruments (eg/ perpetuals) on margin. If not provided,
+    /// every [`Position`] is fully funded.
+    pub fn margin(self, value: MarginConfig) -> Self {
+        Self {
+            margin: Some(value),
+            ..self
+        }
+    }
+
+    /// Sample a mark-to-market [`EquityCurve`] from market updates. If not provided, no
+    /// [`EquityCurve`] is recorded.
+    pub fn equity_curve(self, value: EquityCurveConfig) -> Self {
+        Self {
+            equity_curve: Some(value),
+            ..self
+        }
+    }
+
+    /// Measure execution quality using the provided [`ExecutionSummary`]. If not provided,
+    /// execution is not measured.
+    pub fn execution_summary(self, value: ExecutionSummary) -> Self {
+        Self {
+            execution_summary: Some(value),
+            ..self
+        }
+    }
+
     pub fn statistic_config(self, value: Statistic::Config) -> Self {
         Self {
             statistic_config: Some(value),
@@ -490,6 +915,20 @@ where
     pub fn build_and_init(
         self,
     ) -> Result<MetaPortfolio<Repository, Allocator, RiskManager, Statistic>, PortfolioError> {
+        let markets = self
+            .markets
+            .ok_or(PortfolioError::BuilderIncomplete("markets"))?;
+
+        // Default reporting currency to the quote Symbol of the first Market
+        let reporting_currency = self
+            .reporting_currency
+            .or_else(|| {
+                markets
+                    .first()
+                    .map(|market| market.instrument.quote.clone())
+            })
+            .ok_or(PortfolioError::BuilderIncomplete("reporting_currency"))?;
+
         // Construct Portfolio
         let mut portfolio = MetaPortfolio {
             engine_id: self
@@ -504,6 +943,13 @@ where
             risk_manager: self
                 .risk_manager
                 .ok_or(PortfolioError::BuilderIncomplete("risk_manager"))?,
+            reporting_currency,
+            prices: PriceTable::default(),
+            margin: self.margin,
+            markets: markets.clone(),
+            rejections: Vec::new(),
+            equity_curve: self.equity_curve.map(EquityCurve::new),
+            execution_summary: self.execution_summary,
             _statistic_marker: PhantomData::default(),
         };
 
@@ -511,9 +957,7 @@ where
         portfolio.bootstrap_repository(
             self.starting_cash
                 .ok_or(PortfolioError::BuilderIncomplete("starting_cash"))?,
-            &self
-                .markets
-                .ok_or(PortfolioError::BuilderIncomplete("markets"))?,
+            &markets,
             self.statistic_config
                 .ok_or(PortfolioError::BuilderIncomplete("statistic_config"))?,
         )?;
@@ -524,6 +968,10 @@ where
 
 /// Parses an incoming [`Signal`]'s signals map. Determines what the net signal [`Decision`]
 /// will be, and it's associated [`SignalStrength`].
+///
+/// With an open [`Position`], a close signal for the [`Position`] [`Side`] takes priority.
+/// Otherwise a net open signal on the same [`Side`] scales into the [`Position`], and a net open
+/// signal on the opposite [`Side`] reverses it.
 pub fn parse_signal_decisions<'a>(
     position: &'a Option<&Position>,
     signals: &'a HashMap<Decision, SignalStrength>,
@@ -536,14 +984,14 @@ pub fn parse_signal_decisions<'a>(
 
     // If an existing Position exists, check for net close signals
     if let Some(position) = position {
-        return match position.side {
-            Side::Buy if signal_close_long.is_some() => signal_close_long,
-            Side::Sell if signal_close_short.is_some() => signal_close_short,
-            _ => None,
-        };
+        match position.side {
+            Side::Buy if signal_close_long.is_some() => return signal_close_long,
+            Side::Sell if signal_close_short.is_some() => return signal_close_short,
+            _ => {}
+        }
     }
 
-    // Else check for net open signals
+    // Else check for net open signals to enter, scale in, or reverse
     match (signal_long, signal_short) {
         (Some(signal_long), None) => Some(signal_long),
         (None, Some(signal_short)) => Some(signal_short),
@@ -554,21 +1002,26 @@ pub fn parse_signal_decisions<'a>(
 #[cfg(test)]
 pub mod tests {
     use super::*;
+    use rust_decimal::Decimal;
 
     use crate::{
         portfolio::{
-            allocator::DefaultAllocator, position::PositionBuilder,
-            repository::error::RepositoryError, risk::DefaultRisk,
+            allocator::DefaultAllocator,
+            margin::MarginMode,
+            position::PositionBuilder,
+            repository::{error::RepositoryError, in_memory::InMemoryRepository},
+            risk::{DefaultRisk, MaxMarketNotional, PriceBand, RiskChain, RiskViolation},
         },
         statistic::summary::pnl::PnLReturnSummary,
         strategy::SignalForceExit,
         test_util::{fill_event, market_event_trade, position, signal},
     };
-    use barter_execution::fill::Fees;
+    use barter_execution::fill::{Fees, Liquidity};
     use barter_integration::model::{
         instrument::{kind::InstrumentKind, Instrument},
         Exchange, Side,
     };
+    use chrono::{Duration, TimeZone};
 
     #[derive(Default)]
     struct MockRepository<Statistic> {
@@ -590,6 +1043,7 @@ pub mod tests {
         get_statistics: Option<fn(market_id: &MarketId) -> Result<Statistic, RepositoryError>>,
         position: Option<PositionBuilder>,
         balance: Option<Balance>,
+        symbol_balances: Option<SymbolBalances>,
     }
 
     impl<Statistic> PositionHandler for MockRepository<Statistic> {
@@ -597,6 +1051,7 @@ pub mod tests {
             self.position = Some(
                 Position::builder()
                     .side(position.side.clone())
+                    .quantity(position.quantity)
                     .current_symbol_price(position.current_symbol_price)
                     .current_value_gross(position.current_value_gross)
                     .enter_fees_total(position.enter_fees_total)
@@ -608,6 +1063,9 @@ pub mod tests {
                     .unrealised_profit_loss(position.unrealised_profit_loss)
                     .realised_profit_loss(position.realised_profit_loss),
             );
+            if let Some(builder) = &mut self.position {
+                builder.margin = position.margin;
+            }
             self.set_open_position.unwrap()(position)
         }
 
@@ -623,7 +1081,11 @@ pub mod tests {
             engine_id: Uuid,
             markets: Markets,
         ) -> Result<Vec<Position>, RepositoryError> {
-            self.get_open_positions.unwrap()(engine_id, markets.into_iter().collect())
+            // Default to holding no open Positions
+            match self.get_open_positions {
+                Some(get_open_positions) => get_open_positions(engine_id, markets.collect()),
+                None => Ok(Vec::new()),
+            }
         }
 
         fn remove_position(
@@ -662,6 +1124,29 @@ pub mod tests {
         fn get_balance(&mut self, engine_id: Uuid) -> Result<Balance, RepositoryError> {
             self.get_balance.unwrap()(engine_id)
         }
+
+        fn set_symbol_balances(
+            &mut self,
+            _: Uuid,
+            balances: SymbolBalances,
+        ) -> Result<(), RepositoryError> {
+            self.symbol_balances = Some(balances);
+            Ok(())
+        }
+
+        fn get_symbol_balances(
+            &mut self,
+            engine_id: Uuid,
+        ) -> Result<SymbolBalances, RepositoryError> {
+            // Default to holding the mocked Balance in the reporting currency
+            match self.symbol_balances.clone() {
+                Some(balances) => Ok(balances),
+                None => Ok(SymbolBalances::from([(
+                    Symbol::from("usdt"),
+                    self.get_balance(engine_id)?,
+                )])),
+            }
+        }
     }
 
     impl<Statistic> StatisticHandler<Statistic> for MockRepository<Statistic> {
@@ -674,7 +1159,11 @@ pub mod tests {
         }
 
         fn get_statistics(&mut self, market_id: &MarketId) -> Result<Statistic, RepositoryError> {
-            self.get_statistics.unwrap()(market_id)
+            // Default to holding no Statistics
+            match self.get_statistics {
+                Some(get_statistics) => get_statistics(market_id),
+                None => Err(RepositoryError::ExpectedDataNotPresentError),
+            }
         }
     }
 
@@ -697,11 +1186,12 @@ pub mod tests {
         build_uninitialised_portfolio(builder)
     }
 
-    fn build_uninitialised_portfolio<Repository, Statistic>(
-        builder: MetaPortfolioBuilder<Repository, DefaultAllocator, DefaultRisk, Statistic>,
-    ) -> Result<MetaPortfolio<Repository, DefaultAllocator, DefaultRisk, Statistic>, PortfolioError>
+    fn build_uninitialised_portfolio<Repository, RiskManager, Statistic>(
+        builder: MetaPortfolioBuilder<Repository, DefaultAllocator, RiskManager, Statistic>,
+    ) -> Result<MetaPortfolio<Repository, DefaultAllocator, RiskManager, Statistic>, PortfolioError>
     where
         Repository: PositionHandler + BalanceHandler + StatisticHandler<Statistic>,
+        RiskManager: OrderEvaluator,
         Statistic: PositionSummariser + Initialiser,
     {
         Ok(MetaPortfolio {
@@ -717,6 +1207,15 @@ pub mod tests {
             risk_manager: builder
                 .risk_manager
                 .ok_or(PortfolioError::BuilderIncomplete("risk_manager"))?,
+            reporting_currency: builder
+                .reporting_currency
+                .unwrap_or_else(|| Symbol::from("usdt")),
+            prices: PriceTable::default(),
+            margin: builder.margin,
+            markets: builder.markets.unwrap_or_default(),
+            rejections: Vec::new(),
+            equity_curve: builder.equity_curve.map(EquityCurve::new),
+            execution_summary: builder.execution_summary,
             _statistic_marker: Default::default(),
         })
     }
@@ -753,8 +1252,8 @@ pub mod tests {
 
         match input_market.kind {
             // candle.close +100.0 on input_position.current_symbol_price
-            DataKind::Candle(ref mut candle) => candle.close = 200.0,
-            DataKind::Trade(ref mut trade) => trade.price = 200.0,
+            DataKind::Candle(ref mut candle) => candle.close = Decimal::from(200),
+            DataKind::Trade(ref mut trade) => trade.price = Decimal::from(200),
             _ => todo!(),
         };
 
@@ -801,8 +1300,8 @@ pub mod tests {
         let mut input_market = market_event_trade(Side::Buy);
         match input_market.kind {
             // -50.0 on input_position.current_symbol_price
-            DataKind::Candle(ref mut candle) => candle.close = 50.0,
-            DataKind::Trade(ref mut trade) => trade.price = 50.0,
+            DataKind::Candle(ref mut candle) => candle.close = Decimal::from(50),
+            DataKind::Trade(ref mut trade) => trade.price = Decimal::from(50),
             _ => todo!(),
         };
 
@@ -846,8 +1345,8 @@ pub mod tests {
 
         match input_market.kind {
             // -50.0 on input_position.current_symbol_price
-            DataKind::Candle(ref mut candle) => candle.close = 50.0,
-            DataKind::Trade(ref mut trade) => trade.price = 50.0,
+            DataKind::Candle(ref mut candle) => candle.close = Decimal::from(50),
+            DataKind::Trade(ref mut trade) => trade.price = Decimal::from(50),
             _ => todo!(),
         };
 
@@ -891,8 +1390,8 @@ pub mod tests {
 
         match input_market.kind {
             // +100.0 on input_position.current_symbol_price
-            DataKind::Candle(ref mut candle) => candle.close = 200.0,
-            DataKind::Trade(ref mut trade) => trade.price = 200.0,
+            DataKind::Candle(ref mut candle) => candle.close = Decimal::from(200),
+            DataKind::Trade(ref mut trade) => trade.price = Decimal::from(200),
             _ => todo!(),
         };
 
@@ -984,6 +1483,102 @@ pub mod tests {
         assert_eq!(actual.decision, Decision::Long)
     }
 
+    #[test]
+    fn generate_order_rejected_by_risk_manager_records_risk_rejection() {
+        // Build Portfolio with a RiskChain that only allows 50.0 notional per market
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_open_position = Some(|_| Ok(None));
+        mock_repository.get_balance = Some(|_| {
+            Ok(Balance {
+                time: Utc::now(),
+                total: 1000.0,
+                available: 1000.0,
+            })
+        });
+        let builder = MetaPortfolio::builder()
+            .engine_id(Uuid::new_v4())
+            .repository(mock_repository)
+            .allocation_manager(DefaultAllocator {
+                default_order_value: 100.0,
+            })
+            .risk_manager(RiskChain::new().rule(MaxMarketNotional { limit: 50.0 }));
+        let mut portfolio = build_uninitialised_portfolio(builder).unwrap();
+
+        // Input SignalEvent
+        let mut input_signal = signal();
+        input_signal
+            .signals
+            .insert(Decision::Long, SignalStrength(1.0));
+
+        let actual = portfolio.generate_order(&input_signal).unwrap();
+        assert!(actual.is_none());
+
+        let rejections = portfolio.drain_risk_rejections();
+        assert_eq!(rejections.len(), 1);
+        assert_eq!(rejections[0].order.decision, Decision::Long);
+        assert_eq!(
+            rejections[0].violation,
+            RiskViolation::MarketNotional {
+                notional: 100.0,
+                limit: 50.0
+            }
+        );
+        assert!(portfolio.drain_risk_rejections().is_empty());
+    }
+
+    #[test]
+    fn generate_order_rejected_by_price_band_versus_price_before_latest_market_event() {
+        // Build Portfolio with a RiskChain that only allows a 5% price deviation
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_open_position = Some(|_| Ok(None));
+        mock_repository.get_balance = Some(|_| {
+            Ok(Balance {
+                time: Utc::now(),
+                total: 1000.0,
+                available: 1000.0,
+            })
+        });
+        let builder = MetaPortfolio::builder()
+            .engine_id(Uuid::new_v4())
+            .repository(mock_repository)
+            .allocation_manager(DefaultAllocator {
+                default_order_value: 100.0,
+            })
+            .risk_manager(RiskChain::new().rule(PriceBand { limit: 0.05 }));
+        let mut portfolio = build_uninitialised_portfolio(builder).unwrap();
+
+        // Input SignalEvent generated from the latest MarketEvent close
+        let mut input_signal = signal();
+        input_signal.market_meta.close = 1200.0;
+        input_signal
+            .signals
+            .insert(Decision::Long, SignalStrength(1.0));
+
+        // First MarketEvent has no previous price to reference
+        let mut input_market = market_event_trade(Side::Buy);
+        portfolio.update_from_market(&input_market).unwrap();
+
+        // Latest MarketEvent jumps 20% from the previous price of 1000.0
+        if let DataKind::Trade(trade) = &mut input_market.kind {
+            trade.price = Decimal::from(1200);
+        }
+        portfolio.update_from_market(&input_market).unwrap();
+
+        let actual = portfolio.generate_order(&input_signal).unwrap();
+        assert!(actual.is_none());
+
+        let rejections = portfolio.drain_risk_rejections();
+        assert_eq!(
+            rejections[0].violation,
+            RiskViolation::PriceBand {
+                price: 1200.0,
+                reference_price: 1000.0,
+                deviation: 0.2,
+                limit: 0.05,
+            }
+        );
+    }
+
     #[test]
     fn generate_order_short_with_no_position_and_input_net_short_signal() {
         // Build Portfolio
@@ -1435,6 +2030,460 @@ pub mod tests {
         assert_eq!(updated_value, 200.0 + (100.0 - 150.0 - 6.0));
     }
 
+    #[test]
+    fn update_from_fill_increasing_long_position() {
+        // Build Portfolio
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_balance = Some(|_| {
+            Ok(Balance {
+                time: Utc::now(),
+                total: 600.0,
+                available: 500.0,
+            })
+        });
+        mock_repository.remove_position = Some(|_| {
+            Ok({
+                Some({
+                    let mut input_position = position();
+                    input_position.side = Side::Buy;
+                    input_position.quantity = 1.0;
+                    input_position.enter_avg_price_gross = 100.0;
+                    input_position.enter_value_gross = 100.0;
+                    input_position
+                })
+            })
+        });
+        mock_repository.set_open_position = Some(|_| Ok(()));
+        mock_repository.set_balance = Some(|_, _| Ok(()));
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Input FillEvent
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Long;
+        input_fill.quantity = 1.0;
+        input_fill.fill_value_gross = 200.0;
+        input_fill.fees = Fees {
+            exchange: 1.0,
+            slippage: 1.0,
+            network: 1.0,
+        };
+
+        let result = portfolio.update_from_fill(&input_fill).unwrap();
+        let updated_repository = portfolio.repository;
+        let increased_position = updated_repository.position.unwrap();
+        let updated_balance = updated_repository.balance.unwrap();
+
+        assert!(matches!(
+            result.as_slice(),
+            [Event::PositionUpdate(_), Event::Balance(_)]
+        ));
+        assert_eq!(increased_position.quantity.unwrap(), 2.0);
+        assert_eq!(increased_position.enter_value_gross.unwrap(), 300.0);
+        assert_eq!(increased_position.enter_avg_price_gross.unwrap(), 150.0);
+        assert_eq!(increased_position.enter_fees_total.unwrap(), 3.0);
+        // cash += -fill_value_gross - fill_fees
+        assert_eq!(updated_balance.available, 500.0 - 200.0 - 3.0);
+        assert_eq!(updated_balance.total, 600.0);
+    }
+
+    #[test]
+    fn update_from_fill_reducing_long_position_in_profit() {
+        // Build Portfolio
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_balance = Some(|_| {
+            Ok(Balance {
+                time: Utc::now(),
+                total: 200.0,
+                available: 0.0,
+            })
+        });
+        mock_repository.remove_position = Some(|_| {
+            Ok({
+                Some({
+                    let mut input_position = position();
+                    input_position.side = Side::Buy;
+                    input_position.quantity = 2.0;
+                    input_position.enter_fees_total = 4.0;
+                    input_position.enter_avg_price_gross = 100.0;
+                    input_position.enter_value_gross = 200.0;
+                    input_position
+                })
+            })
+        });
+        mock_repository.set_open_position = Some(|_| Ok(()));
+        mock_repository.set_balance = Some(|_, _| Ok(()));
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Input FillEvent
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::CloseLong;
+        input_fill.quantity = -1.0;
+        input_fill.fill_value_gross = 150.0;
+        input_fill.fees = Fees {
+            exchange: 2.0,
+            slippage: 0.0,
+            network: 0.0,
+        };
+
+        let result = portfolio.update_from_fill(&input_fill).unwrap();
+        let updated_repository = portfolio.repository;
+        let reduced_position = updated_repository.position.unwrap();
+        let updated_balance = updated_repository.balance.unwrap();
+
+        assert!(matches!(
+            result.as_slice(),
+            [Event::PositionUpdate(_), Event::Balance(_)]
+        ));
+        assert_eq!(reduced_position.quantity.unwrap(), 1.0);
+        // LONG reduction pnl = exit_value_gross - reduced enter_value_gross - reduced fees - exit fees
+        assert_eq!(
+            reduced_position.realised_profit_loss.unwrap(),
+            150.0 - 100.0 - 2.0 - 2.0
+        );
+        // cash += reduced enter_value_gross + reduced enter_fees + reduction pnl
+        assert_eq!(updated_balance.available, 0.0 + 100.0 + 2.0 + 46.0);
+        // value += reduction pnl
+        assert_eq!(updated_balance.total, 200.0 + 46.0);
+    }
+
+    #[test]
+    fn update_from_fill_reversing_long_position_to_short() {
+        // Build Portfolio
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_balance = Some(|_| {
+            Ok(Balance {
+                time: Utc::now(),
+                total: 200.0,
+                available: 97.0,
+            })
+        });
+        mock_repository.remove_position = Some(|_| {
+            Ok({
+                Some({
+                    let mut input_position = position();
+                    input_position.side = Side::Buy;
+                    input_position.quantity = 1.0;
+                    input_position.enter_fees_total = 3.0;
+                    input_position.enter_value_gross = 100.0;
+                    input_position
+                })
+            })
+        });
+        mock_repository.get_statistics = Some(|_| Ok(PnLReturnSummary::default()));
+        mock_repository.set_statistics = Some(|_, _| Ok(()));
+        mock_repository.set_exited_position = Some(|_, _| Ok(()));
+        mock_repository.set_open_position = Some(|_| Ok(()));
+        mock_repository.set_balance = Some(|_, _| Ok(()));
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Input FillEvent
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Short;
+        input_fill.quantity = -3.0;
+        input_fill.fill_value_gross = 600.0;
+        input_fill.fees = Fees {
+            exchange: 3.0,
+            slippage: 0.0,
+            network: 0.0,
+        };
+
+        let result = portfolio.update_from_fill(&input_fill).unwrap();
+        let updated_repository = portfolio.repository;
+        let entered_position = updated_repository.position.unwrap();
+        let updated_balance = updated_repository.balance.unwrap();
+
+        assert!(matches!(
+            result.as_slice(),
+            [
+                Event::PositionExit(_),
+                Event::PositionNew(_),
+                Event::Balance(_)
+            ]
+        ));
+
+        // Remainder of the fill enters a new Short Position
+        assert_eq!(entered_position.side.unwrap(), Side::Sell);
+        assert_eq!(entered_position.quantity.unwrap(), -2.0);
+        assert_eq!(entered_position.enter_value_gross.unwrap(), 400.0);
+        assert_eq!(entered_position.enter_fees_total.unwrap(), 2.0);
+
+        // LONG exit pnl = 200.0 - 100.0 - (3.0 + 1.0)
+        // cash += enter_value_gross + exit pnl + enter_fees_total - short enter value & fees
+        assert_eq!(
+            updated_balance.available,
+            97.0 + 100.0 + 96.0 + 3.0 - 400.0 - 2.0
+        );
+        assert_eq!(updated_balance.total, 200.0 + 96.0);
+    }
+
+    /// Build the [`FillEvent`] of an [`OrderEvent`] fully filled at it's decision price.
+    fn fill_order(order: &OrderEvent) -> FillEvent {
+        FillEvent {
+            time: Utc::now(),
+            exchange: order.exchange.clone(),
+            instrument: order.instrument.clone(),
+            order_id: Some(order.id),
+            market_meta: order.market_meta,
+            decision: order.decision,
+            quantity: order.quantity,
+            fill_value_gross: order.quantity.abs() * order.market_meta.close,
+            fees: Fees::default(),
+            liquidity: Liquidity::default(),
+        }
+    }
+
+    #[test]
+    fn generate_order_and_update_from_fill_increasing_long_position_with_long_signal() {
+        // Build Portfolio
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_open_position = Some(|_| Ok(Some(position())));
+        mock_repository.remove_position = Some(|_| Ok(Some(position())));
+        mock_repository.get_balance = Some(|_| {
+            Ok(Balance {
+                time: Utc::now(),
+                total: 600.0,
+                available: 500.0,
+            })
+        });
+        mock_repository.set_open_position = Some(|_| Ok(()));
+        mock_repository.set_balance = Some(|_, _| Ok(()));
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Input SignalEvent to scale into the open 1.0 quantity Long Position, from a historic
+        // MarketEvent
+        let mut input_signal = signal();
+        input_signal
+            .signals
+            .insert(Decision::Long, SignalStrength(1.0));
+        input_signal.market_meta.time = Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+
+        let order = portfolio.generate_order(&input_signal).unwrap().unwrap();
+        assert_eq!(order.decision, Decision::Long);
+        assert_eq!(order.quantity, 1.0);
+        assert_eq!(order.time, input_signal.market_meta.time);
+
+        let result = portfolio.update_from_fill(&fill_order(&order)).unwrap();
+        let updated_repository = portfolio.repository;
+        let increased_position = updated_repository.position.unwrap();
+        let updated_balance = updated_repository.balance.unwrap();
+
+        assert!(matches!(
+            result.as_slice(),
+            [Event::PositionUpdate(_), Event::Balance(_)]
+        ));
+        assert_eq!(increased_position.side.unwrap(), Side::Buy);
+        assert_eq!(increased_position.quantity.unwrap(), 2.0);
+        assert_eq!(increased_position.enter_value_gross.unwrap(), 200.0);
+        assert_eq!(updated_balance.available, 500.0 - 100.0);
+        assert_eq!(updated_balance.total, 600.0);
+    }
+
+    #[test]
+    fn generate_order_and_update_from_fill_reversing_long_position_with_short_signal() {
+        // Build Portfolio
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_open_position = Some(|_| Ok(Some(position())));
+        mock_repository.remove_position = Some(|_| Ok(Some(position())));
+        mock_repository.get_balance = Some(|_| {
+            Ok(Balance {
+                time: Utc::now(),
+                total: 200.0,
+                available: 100.0,
+            })
+        });
+        mock_repository.get_statistics = Some(|_| Ok(PnLReturnSummary::default()));
+        mock_repository.set_statistics = Some(|_, _| Ok(()));
+        mock_repository.set_exited_position = Some(|_, _| Ok(()));
+        mock_repository.set_open_position = Some(|_| Ok(()));
+        mock_repository.set_balance = Some(|_, _| Ok(()));
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+
+        // Input SignalEvent to reverse the open 1.0 quantity Long Position
+        let mut input_signal = signal();
+        input_signal
+            .signals
+            .insert(Decision::Short, SignalStrength(1.0));
+
+        let order = portfolio.generate_order(&input_signal).unwrap().unwrap();
+        assert_eq!(order.decision, Decision::Short);
+        assert_eq!(order.quantity, -2.0);
+
+        let result = portfolio.update_from_fill(&fill_order(&order)).unwrap();
+        let updated_repository = portfolio.repository;
+        let entered_position = updated_repository.position.unwrap();
+        let updated_balance = updated_repository.balance.unwrap();
+
+        assert!(matches!(
+            result.as_slice(),
+            [
+                Event::PositionExit(_),
+                Event::PositionNew(_),
+                Event::Balance(_)
+            ]
+        ));
+        assert_eq!(entered_position.side.unwrap(), Side::Sell);
+        assert_eq!(entered_position.quantity.unwrap(), -1.0);
+        assert_eq!(entered_position.enter_value_gross.unwrap(), 100.0);
+        // cash += Long enter_value_gross - Short enter_value_gross
+        assert_eq!(updated_balance.available, 100.0 + 100.0 - 100.0);
+        assert_eq!(updated_balance.total, 200.0);
+    }
+
+    #[test]
+    fn update_from_fill_entering_margined_perpetual_position_reserves_initial_margin() {
+        // Build Portfolio
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.get_balance = Some(|_| {
+            Ok(Balance {
+                time: Utc::now(),
+                total: 200.0,
+                available: 200.0,
+            })
+        });
+        mock_repository.remove_position = Some(|_| Ok(None));
+        mock_repository.set_open_position = Some(|_| Ok(()));
+        mock_repository.set_balance = Some(|_, _| Ok(()));
+        let builder = MetaPortfolio::builder()
+            .engine_id(Uuid::new_v4())
+            .repository(mock_repository)
+            .allocation_manager(DefaultAllocator {
+                default_order_value: 100.0,
+            })
+            .risk_manager(DefaultRisk {})
+            .margin(MarginConfig {
+                mode: MarginMode::Cross,
+                leverage: 10.0,
+                ..MarginConfig::default()
+            });
+        let mut portfolio = build_uninitialised_portfolio(builder).unwrap();
+
+        // Input FillEvent
+        let mut input_fill = fill_event();
+        input_fill.instrument = Instrument::from(("eth", "usdt", InstrumentKind::Perpetual));
+        input_fill.decision = Decision::Long;
+        input_fill.quantity = 1.0;
+        input_fill.fill_value_gross = 100.0;
+        input_fill.fees = Fees {
+            exchange: 1.0,
+            slippage: 0.0,
+            network: 0.0,
+        };
+
+        let result = portfolio.update_from_fill(&input_fill);
+        let updated_repository = portfolio.repository;
+        let entered_position = updated_repository.position.unwrap();
+        let margin = entered_position.margin.unwrap();
+        let updated_balance = updated_repository.balance.unwrap();
+
+        assert!(result.is_ok());
+        assert_eq!(margin.initial_margin, 10.0);
+        // Cross margin is also backed by the free balance remaining after entry
+        assert_eq!(margin.cross_collateral, 200.0 - 10.0 - 1.0);
+        assert_eq!(updated_balance.available, 200.0 - 10.0 - 1.0); // reserves initial margin only
+        assert_eq!(updated_balance.total, 200.0);
+    }
+
+    #[test]
+    fn update_from_fill_values_cross_currency_balances_in_reporting_currency() {
+        // Build Portfolio holding usdt & btc
+        let time = Utc::now();
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.symbol_balances = Some(SymbolBalances::from([
+            (Symbol::from("usdt"), Balance::new(time, 1000.0, 1000.0)),
+            (Symbol::from("btc"), Balance::new(time, 1.0, 1.0)),
+        ]));
+        mock_repository.remove_position = Some(|_| Ok(None));
+        mock_repository.set_open_position = Some(|_| Ok(()));
+        mock_repository.set_balance = Some(|_, _| Ok(()));
+        let mut portfolio = new_mocked_portfolio(mock_repository).unwrap();
+        portfolio
+            .prices
+            .set_price(Symbol::from("btc"), Symbol::from("usdt"), 20000.0);
+
+        // Input FillEvent entering an eth-btc Position, settled in btc
+        let mut input_fill = fill_event();
+        input_fill.instrument = Instrument::from(("eth", "btc", InstrumentKind::Spot));
+        input_fill.decision = Decision::Long;
+        input_fill.quantity = 1.0;
+        input_fill.fill_value_gross = 0.05;
+
+        let result = portfolio.update_from_fill(&input_fill).unwrap();
+        let equity_point = portfolio.equity_point(time).unwrap();
+        let updated_repository = portfolio.repository;
+        let updated_balances = updated_repository.symbol_balances.unwrap();
+        let updated_balance = updated_repository.balance.unwrap();
+
+        // Entry is booked against the btc Balance only
+        assert_eq!(
+            updated_balances[&Symbol::from("usdt")],
+            Balance::new(time, 1000.0, 1000.0)
+        );
+        assert_eq!(updated_balances[&Symbol::from("btc")].total, 1.0);
+        assert_eq!(updated_balances[&Symbol::from("btc")].available, 0.95);
+
+        // Portfolio Balance is valued in the usdt reporting currency
+        assert!(
+            matches!(result.last(), Some(Event::Balance(balance)) if *balance == updated_balance)
+        );
+        assert_eq!(updated_balance.total, 1000.0 + 20000.0);
+        assert_eq!(updated_balance.available, 1000.0 + 0.95 * 20000.0);
+        assert_eq!(equity_point.total, updated_balance.total);
+    }
+
+    #[test]
+    fn update_from_market_samples_mark_to_market_equity_curve() {
+        // Build Portfolio holding usdt with an open Position in unrealised profit
+        let time = Utc.timestamp_opt(0, 0).unwrap();
+        let mut mock_repository = MockRepository::<PnLReturnSummary>::default();
+        mock_repository.symbol_balances = Some(SymbolBalances::from([(
+            Symbol::from("usdt"),
+            Balance::new(time, 1000.0, 900.0),
+        )]));
+        mock_repository.get_open_position = Some(|_| Ok(None));
+        mock_repository.get_open_positions = Some(|_, _| {
+            Ok(vec![{
+                let mut position = position();
+                position.unrealised_profit_loss = 50.0;
+                position
+            }])
+        });
+        let builder = MetaPortfolio::builder()
+            .engine_id(Uuid::new_v4())
+            .repository(mock_repository)
+            .allocation_manager(DefaultAllocator {
+                default_order_value: 100.0,
+            })
+            .risk_manager(DefaultRisk {})
+            .equity_curve(EquityCurveConfig {
+                interval: Duration::minutes(1),
+                ..EquityCurveConfig::default()
+            });
+        let mut portfolio = build_uninitialised_portfolio(builder).unwrap();
+
+        // Input MarketEvents, the second within the same sampling interval as the first
+        for secs in [0, 30, 60] {
+            let mut input_market = market_event_trade(Side::Buy);
+            input_market.exchange_time = time + Duration::seconds(secs);
+            portfolio.update_from_market(&input_market).unwrap();
+        }
+
+        // Equity includes the open Position unrealised profit, sampled once per interval
+        let equity_curve = portfolio.equity_curve().unwrap();
+        assert_eq!(
+            equity_curve.points,
+            vec![
+                EquityPoint {
+                    time,
+                    total: 1050.0
+                },
+                EquityPoint {
+                    time: time + Duration::seconds(60),
+                    total: 1050.0
+                },
+            ]
+        );
+    }
+
     #[test]
     fn parse_signal_decisions_to_net_close_long() {
         // Some(Position)
@@ -1454,7 +2503,7 @@ pub mod tests {
     }
 
     #[test]
-    fn parse_signal_decisions_to_none_with_some_long_position_and_long_signal() {
+    fn parse_signal_decisions_to_net_long_increase_with_some_long_position_and_long_signal() {
         // Some(Position)
         let mut position = position();
         position.side = Side::Buy;
@@ -1468,7 +2517,25 @@ pub mod tests {
 
         let actual = parse_signal_decisions(&position, &signals);
 
-        assert!(actual.is_none())
+        assert_eq!(actual.unwrap().0, &Decision::Long);
+    }
+
+    #[test]
+    fn parse_signal_decisions_to_net_short_reversal_with_some_long_position_and_short_signal() {
+        // Some(Position)
+        let mut position = position();
+        position.side = Side::Buy;
+        let position = Some(position);
+        let position = position.as_ref();
+
+        // Signals HashMap
+        let mut signals = HashMap::with_capacity(4);
+        signals.insert(Decision::Short, SignalStrength(1.0));
+        signals.insert(Decision::CloseShort, SignalStrength(1.0));
+
+        let actual = parse_signal_decisions(&position, &signals);
+
+        assert_eq!(actual.unwrap().0, &Decision::Short);
     }
 
     #[test]
@@ -1510,7 +2577,7 @@ pub mod tests {
     }
 
     #[test]
-    fn parse_signal_decisions_to_none_with_some_short_position_and_short_signal() {
+    fn parse_signal_decisions_to_net_short_increase_with_some_short_position_and_short_signal() {
         // Some(Position)
         let mut position = position();
         position.side = Side::Sell;
@@ -1524,6 +2591,25 @@ pub mod tests {
 
         let actual = parse_signal_decisions(&position, &signals);
 
+        assert_eq!(actual.unwrap().0, &Decision::Short);
+    }
+
+    #[test]
+    fn parse_signal_decisions_to_none_with_some_short_position_and_conflicting_open_signals() {
+        // Some(Position)
+        let mut position = position();
+        position.side = Side::Sell;
+        let position = Some(position);
+        let position = position.as_ref();
+
+        // Signals HashMap
+        let mut signals = HashMap::with_capacity(4);
+        signals.insert(Decision::CloseLong, SignalStrength(1.0));
+        signals.insert(Decision::Short, SignalStrength(1.0));
+        signals.insert(Decision::Long, SignalStrength(1.0));
+
+        let actual = parse_signal_decisions(&position, &signals);
+
         assert!(actual.is_none())
     }
 
@@ -1593,4 +2679,38 @@ pub mod tests {
 
         assert_eq!(actual, None);
     }
+
+    #[test]
+    fn statistics_are_scoped_to_the_engine_id() {
+        let builder = MetaPortfolio::builder()
+            .engine_id(Uuid::new_v4())
+            .starting_cash(1000.0)
+            .repository(InMemoryRepository::<PnLReturnSummary>::new())
+            .allocation_manager(DefaultAllocator {
+                default_order_value: 100.0,
+            })
+            .risk_manager(DefaultRisk {});
+        let mut portfolio = build_uninitialised_portfolio(builder).unwrap();
+
+        let market_id = MarketId::new(
+            &Exchange::from("binance"),
+            &Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
+        );
+        let statistic = PnLReturnSummary::default();
+        portfolio
+            .set_statistics(market_id.clone(), statistic)
+            .unwrap();
+
+        // Repository holds the Statistics under the engine scoped MarketId
+        let engine_market_id = MarketId(format!("{}_{}", portfolio.engine_id, market_id));
+        assert!(portfolio.repository.get_statistics(&market_id).is_err());
+        assert_eq!(
+            portfolio
+                .repository
+                .get_statistics(&engine_market_id)
+                .unwrap(),
+            statistic
+        );
+        assert_eq!(portfolio.get_statistics(&market_id).unwrap(), statistic);
+    }
 }
@@ -1,8 +1,13 @@
-use crate::portfolio::{error::PortfolioError, Balance};
+use crate::portfolio::{
+    error::PortfolioError,
+    margin::{MarginConfig, PositionMargin},
+    Balance,
+};
 use barter_data::event::{DataKind, MarketEvent};
 use barter_execution::fill::{Decision, FeeAmount, Fees, FillEvent};
 use barter_integration::model::{instrument::Instrument, Exchange, Side};
 use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
 use serde::{Deserialize, Serialize};
 use std::convert::TryFrom;
 use uuid::Uuid;
@@ -27,6 +32,31 @@ pub trait PositionExiter {
     fn exit(&mut self, balance: Balance, fill: &FillEvent) -> Result<PositionExit, PortfolioError>;
 }
 
+/// Scales an open [`Position`] in or out without fully exiting it.
+pub trait PositionScaler {
+    /// Adds to an open [`Position`] with an entry [`FillEvent`] in the same direction,
+    /// re-weighting the average entry price & accumulating the entry fees.
+    fn increase(&mut self, fill: &FillEvent) -> Result<PositionUpdate, PortfolioError>;
+
+    /// Partially reduces an open [`Position`] with an exit [`FillEvent`], realising the P&L of
+    /// the reduced quantity against the average entry price & it's share of the entry fees.
+    fn reduce(&mut self, fill: &FillEvent) -> Result<PositionUpdate, PortfolioError>;
+}
+
+/// Effect an input [`FillEvent`] has on an open [`Position`].
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum PositionFill {
+    /// Fill in the same direction as the [`Position`] - scale in.
+    Increase,
+    /// Fill in the opposite direction for less than the open quantity - scale out.
+    Reduce,
+    /// Fill in the opposite direction for exactly the open quantity - full exit.
+    Exit,
+    /// Fill in the opposite direction for more than the open quantity - exit, then enter a
+    /// new [`Position`] on the opposite [`Side`] with the remainder.
+    Reverse,
+}
+
 /// Communicates a String represents a unique [`Position`] identifier.
 pub type PositionId = String;
 
@@ -61,31 +91,35 @@ pub struct Position {
     /// - Side::Sell considered synonymous with Short.
     pub side: Side,
 
-    /// +ve or -ve quantity of symbol contracts opened.
+    /// +ve or -ve quantity of symbol contracts currently open.
+    ///
+    /// Once exited, this is the total quantity entered over the life of the [`Position`].
     pub quantity: f64,
 
-    /// All fees types incurred from entering a [`Position`], and their associated [`FeeAmount`].
+    /// All fees types incurred from entering (and adding to) a [`Position`], and their
+    /// associated [`FeeAmount`].
     pub enter_fees: Fees,
 
     /// Total of enter_fees incurred. Sum of every [`FeeAmount`] in [`Fees`] when entering a [`Position`].
     pub enter_fees_total: FeeAmount,
 
-    /// Enter average price excluding the entry_fees_total.
+    /// Volume weighted enter average price of every entry fill, excluding the entry_fees_total.
     pub enter_avg_price_gross: f64,
 
-    /// abs(Quantity) * enter_avg_price_gross.
+    /// abs(Quantity entered) * enter_avg_price_gross.
     pub enter_value_gross: f64,
 
-    /// All fees types incurred from exiting a [`Position`], and their associated [`FeeAmount`].
+    /// All fees types incurred from exiting (and reducing) a [`Position`], and their associated
+    /// [`FeeAmount`].
     pub exit_fees: Fees,
 
     /// Total of exit_fees incurred. Sum of every [`FeeAmount`] in [`Fees`] when entering a [`Position`].
     pub exit_fees_total: FeeAmount,
 
-    /// Exit average price excluding the exit_fees_total.
+    /// Volume weighted exit average price of every exit fill, excluding the exit_fees_total.
     pub exit_avg_price_gross: f64,
 
-    /// abs(Quantity) * exit_avg_price_gross.
+    /// abs(Quantity exited) * exit_avg_price_gross.
     pub exit_value_gross: f64,
 
     /// Symbol current close price.
@@ -97,8 +131,14 @@ pub struct Position {
     /// Unrealised P&L whilst the [`Position`] is open.
     pub unrealised_profit_loss: f64,
 
-    /// Realised P&L after the [`Position`] has closed.
+    /// Realised P&L of the quantity exited so far. Once the [`Position`] has closed, this is
+    /// the exact P&L over it's lifetime, including any funding paid or received.
     pub realised_profit_loss: f64,
+
+    /// Margin state of a [`Position`] in a derivative [`Instrument`] traded on margin. None if
+    /// the [`Position`] is fully funded (eg/ spot).
+    #[serde(default)]
+    pub margin: Option<PositionMargin>,
 }
 
 impl PositionEnterer for Position {
@@ -138,6 +178,7 @@ impl PositionEnterer for Position {
             current_value_gross: fill.fill_value_gross,
             unrealised_profit_loss,
             realised_profit_loss: 0.0,
+            margin: None,
         })
     }
 }
@@ -152,7 +193,8 @@ impl PositionUpdater for Position {
             DataKind::OrderBook(book) => book.volume_weighed_mid_price()?,
             DataKind::Liquidation(_) => return None,
             DataKind::IntentOrder(_) => return None,
-        };
+        }
+        .to_f64()?;
 
         self.meta.update_time = market.exchange_time;
 
@@ -161,6 +203,15 @@ impl PositionUpdater for Position {
         // Market value gross
         self.current_value_gross = close * self.quantity.abs();
 
+        // Apply any funding payments due & the resulting change in liquidation price
+        if let Some(margin) = &mut self.margin {
+            if margin.apply_funding(self.side, self.current_value_gross, market.exchange_time)
+                != 0.0
+            {
+                self.recalculate_margin();
+            }
+        }
+
         // Unreal profit & loss
         self.unrealised_profit_loss = self.calculate_unrealised_profit_loss();
 
@@ -179,20 +230,28 @@ impl PositionExiter for Position {
             return Err(PortfolioError::CannotExitPositionWithEntryFill);
         }
 
+        // Quantity & profit & loss already realised by any previous reductions
+        let exit_quantity_before = self.calculate_exit_quantity();
+        let realised_profit_loss_before = self.realised_profit_loss;
+
         // Exit fees
-        self.exit_fees = fill.fees;
-        self.exit_fees_total = fill.fees.calculate_total_fees();
+        self.exit_fees += fill.fees;
+        self.exit_fees_total += fill.fees.calculate_total_fees();
 
         // Exit value & price
-        self.exit_value_gross = fill.fill_value_gross;
-        self.exit_avg_price_gross = Position::calculate_avg_price_gross(fill);
+        self.exit_value_gross += fill.fill_value_gross;
+        self.exit_avg_price_gross =
+            self.exit_value_gross / (exit_quantity_before + fill.quantity.abs());
+
+        // Closed Position quantity is the total quantity entered over it's lifetime
+        self.quantity = self.quantity.signum() * (self.quantity.abs() + exit_quantity_before);
 
-        // Result profit & loss
+        // Result profit & loss - exact since the entered quantity has now been fully exited
         self.realised_profit_loss = self.calculate_realised_profit_loss();
         self.unrealised_profit_loss = self.realised_profit_loss;
 
         // Metadata
-        balance.total += self.realised_profit_loss;
+        balance.total += self.realised_profit_loss - realised_profit_loss_before;
         self.meta.update_time = fill.time;
         self.meta.exit_balance = Some(balance);
 
@@ -200,6 +259,65 @@ impl PositionExiter for Position {
     }
 }
 
+impl PositionScaler for Position {
+    fn increase(&mut self, fill: &FillEvent) -> Result<PositionUpdate, PortfolioError> {
+        if Position::parse_entry_side(fill)? != self.side {
+            return Err(PortfolioError::ParseEntrySide);
+        }
+
+        // Enter fees
+        self.enter_fees += fill.fees;
+        self.enter_fees_total += fill.fees.calculate_total_fees();
+
+        // Enter value & volume weighted average price
+        let enter_quantity = self.calculate_enter_quantity() + fill.quantity.abs();
+        self.enter_value_gross += fill.fill_value_gross;
+        self.enter_avg_price_gross = self.enter_value_gross / enter_quantity;
+
+        self.quantity += fill.quantity;
+        self.recalculate_margin();
+        self.update_from_fill_price(fill);
+
+        Ok(PositionUpdate::from(self))
+    }
+
+    fn reduce(&mut self, fill: &FillEvent) -> Result<PositionUpdate, PortfolioError> {
+        if fill.decision.is_entry() {
+            return Err(PortfolioError::CannotExitPositionWithEntryFill);
+        }
+
+        // Share of the open entry value & entry fees attributable to the reduced quantity
+        let reduced_proportion = fill.quantity.abs() / self.quantity.abs();
+        let reduced_enter_value_gross =
+            self.calculate_open_enter_value_gross() * reduced_proportion;
+        let reduced_enter_fees_total = self.calculate_open_enter_fees_total() * reduced_proportion;
+
+        // Exit fees
+        let exit_quantity = self.calculate_exit_quantity() + fill.quantity.abs();
+        let fill_fees_total = fill.fees.calculate_total_fees();
+        self.exit_fees += fill.fees;
+        self.exit_fees_total += fill_fees_total;
+
+        // Exit value & volume weighted average price
+        self.exit_value_gross += fill.fill_value_gross;
+        self.exit_avg_price_gross = self.exit_value_gross / exit_quantity;
+
+        // Realised profit & loss of the reduced quantity
+        let reduced_profit_loss = match self.side {
+            Side::Buy => fill.fill_value_gross - reduced_enter_value_gross,
+            Side::Sell => reduced_enter_value_gross - fill.fill_value_gross,
+        };
+        self.realised_profit_loss +=
+            reduced_profit_loss - reduced_enter_fees_total - fill_fees_total;
+
+        self.quantity += fill.quantity;
+        self.recalculate_margin();
+        self.update_from_fill_price(fill);
+
+        Ok(PositionUpdate::from(self))
+    }
+}
+
 impl Position {
     /// Returns a [`PositionBuilder`] instance.
     pub fn builder() -> PositionBuilder {
@@ -232,24 +350,159 @@ impl Position {
         }
     }
 
-    /// Calculate the approximate [`Position::unrealised_profit_loss`] of a [`Position`].
-    pub fn calculate_unrealised_profit_loss(&self) -> f64 {
-        let approx_total_fees = self.enter_fees_total * 2.0;
+    /// Determines the [`PositionFill`] effect the input [`FillEvent`] has on this open
+    /// [`Position`], by comparing the fill quantity to the open quantity.
+    pub fn determine_position_fill(&self, fill: &FillEvent) -> PositionFill {
+        if fill.quantity.is_sign_positive() == self.quantity.is_sign_positive() {
+            return PositionFill::Increase;
+        }
 
-        match self.side {
-            Side::Buy => self.current_value_gross - self.enter_value_gross - approx_total_fees,
-            Side::Sell => self.enter_value_gross - self.current_value_gross - approx_total_fees,
+        match fill.quantity.abs().partial_cmp(&self.quantity.abs()) {
+            Some(std::cmp::Ordering::Less) => PositionFill::Reduce,
+            Some(std::cmp::Ordering::Greater) => PositionFill::Reverse,
+            _ => PositionFill::Exit,
+        }
+    }
+
+    /// Splits a [`PositionFill::Reverse`] [`FillEvent`] into the fill that exits this open
+    /// [`Position`], and the remainder fill that enters a new [`Position`] on the opposite
+    /// [`Side`]. Fill value & fees are attributed pro rata by quantity.
+    pub fn split_reverse_fill(&self, fill: &FillEvent) -> (FillEvent, FillEvent) {
+        let exit_proportion = self.quantity.abs() / fill.quantity.abs();
+        let enter_proportion = (fill.quantity.abs() - self.quantity.abs()) / fill.quantity.abs();
+        let exit_value_gross = fill.fill_value_gross * exit_proportion;
+
+        let exit_fill = FillEvent {
+            decision: self.determine_exit_decision(),
+            quantity: -self.quantity,
+            fill_value_gross: exit_value_gross,
+            fees: fill.fees.scale(exit_proportion),
+            ..fill.clone()
+        };
+
+        let enter_fill = FillEvent {
+            decision: match self.side {
+                Side::Buy => Decision::Short,
+                Side::Sell => Decision::Long,
+            },
+            quantity: fill.quantity + self.quantity,
+            fill_value_gross: fill.fill_value_gross - exit_value_gross,
+            fees: fill.fees.scale(enter_proportion),
+            ..fill.clone()
+        };
+
+        (exit_fill, enter_fill)
+    }
+
+    /// Trades the open [`Position`] on margin using the [`MarginConfig`]. In
+    /// [`MarginMode::Cross`](super::margin::MarginMode::Cross) the `cross_collateral` free
+    /// [`Balance`] also backs the [`Position`] against liquidation.
+    pub fn apply_margin(&mut self, config: MarginConfig, cross_collateral: f64) {
+        self.margin = Some(PositionMargin::new(
+            config,
+            cross_collateral,
+            self.meta.enter_time,
+        ));
+        self.recalculate_margin();
+        self.unrealised_profit_loss = self.calculate_unrealised_profit_loss();
+    }
+
+    /// Recalculate the initial margin & liquidation price of the open quantity.
+    fn recalculate_margin(&mut self) {
+        let open_enter_value_gross = self.calculate_open_enter_value_gross();
+        if let Some(margin) = &mut self.margin {
+            margin.recalculate(self.side, self.quantity, open_enter_value_gross);
+        }
+    }
+
+    /// Determines if the current symbol price has crossed the liquidation price of a [`Position`]
+    /// traded on margin.
+    pub fn is_liquidated(&self) -> bool {
+        self.margin
+            .map(|margin| margin.is_liquidated(self.side, self.current_symbol_price))
+            .unwrap_or(false)
+    }
+
+    /// Calculate the Portfolio [`Balance`] reserved to hold the open quantity. This is the
+    /// initial margin if the [`Position`] is traded on margin, else the open enter value gross.
+    pub fn calculate_open_reserved_value(&self) -> f64 {
+        match &self.margin {
+            Some(margin) => margin.initial_margin,
+            None => self.calculate_open_enter_value_gross(),
+        }
+    }
+
+    /// Calculate the cumulative funding paid (+ve) or received (-ve) by a [`Position`] traded on
+    /// margin.
+    pub fn calculate_funding_paid(&self) -> f64 {
+        self.margin.map(|margin| margin.funding_paid).unwrap_or(0.0)
+    }
+
+    /// Calculate the absolute quantity entered over the life of an open [`Position`].
+    pub fn calculate_enter_quantity(&self) -> f64 {
+        self.quantity.abs() + self.calculate_exit_quantity()
+    }
+
+    /// Calculate the absolute quantity exited by any previous reductions of a [`Position`].
+    pub fn calculate_exit_quantity(&self) -> f64 {
+        if self.exit_avg_price_gross == 0.0 {
+            0.0
+        } else {
+            self.exit_value_gross / self.exit_avg_price_gross
         }
     }
 
-    /// Calculate the exact [`Position::realised_profit_loss`] of a [`Position`].
+    /// Calculate the share of the [`Position::enter_value_gross`] attributable to the open
+    /// quantity.
+    pub fn calculate_open_enter_value_gross(&self) -> f64 {
+        self.enter_value_gross * self.calculate_open_proportion()
+    }
+
+    /// Calculate the share of the [`Position::enter_fees_total`] attributable to the open
+    /// quantity.
+    pub fn calculate_open_enter_fees_total(&self) -> f64 {
+        self.enter_fees_total * self.calculate_open_proportion()
+    }
+
+    /// Calculate the proportion of the quantity entered that is still open.
+    fn calculate_open_proportion(&self) -> f64 {
+        self.quantity.abs() / self.calculate_enter_quantity()
+    }
+
+    /// Updates the current price, value & unrealised P&L of a [`Position`] that has just been
+    /// scaled in or out by the input [`FillEvent`].
+    fn update_from_fill_price(&mut self, fill: &FillEvent) {
+        self.meta.update_time = fill.time;
+        self.current_symbol_price = Position::calculate_avg_price_gross(fill);
+        self.current_value_gross = self.current_symbol_price * self.quantity.abs();
+        self.unrealised_profit_loss = self.calculate_unrealised_profit_loss();
+    }
+
+    /// Calculate the approximate [`Position::unrealised_profit_loss`] of the open quantity of a
+    /// [`Position`], net of any funding paid.
+    pub fn calculate_unrealised_profit_loss(&self) -> f64 {
+        let open_enter_value_gross = self.calculate_open_enter_value_gross();
+        let approx_total_fees = self.calculate_open_enter_fees_total() * 2.0;
+
+        let unrealised_profit_loss = match self.side {
+            Side::Buy => self.current_value_gross - open_enter_value_gross - approx_total_fees,
+            Side::Sell => open_enter_value_gross - self.current_value_gross - approx_total_fees,
+        };
+
+        unrealised_profit_loss - self.calculate_funding_paid()
+    }
+
+    /// Calculate the exact [`Position::realised_profit_loss`] of a [`Position`], net of any
+    /// funding paid.
     pub fn calculate_realised_profit_loss(&self) -> f64 {
         let total_fees = self.enter_fees_total + self.exit_fees_total;
 
-        match self.side {
+        let realised_profit_loss = match self.side {
             Side::Buy => self.exit_value_gross - self.enter_value_gross - total_fees,
             Side::Sell => self.enter_value_gross - self.exit_value_gross - total_fees,
-        }
+        };
+
+        realised_profit_loss - self.calculate_funding_paid()
     }
 
     /// Calculate the PnL return of a closed [`Position`] - assumed [`Position::realised_profit_loss`] is
@@ -280,6 +533,7 @@ pub struct PositionBuilder {
     pub current_value_gross: Option<f64>,
     pub unrealised_profit_loss: Option<f64>,
     pub realised_profit_loss: Option<f64>,
+    pub margin: Option<PositionMargin>,
 }
 
 impl PositionBuilder {
@@ -413,6 +667,14 @@ impl PositionBuilder {
         }
     }
 
+    /// Optional margin state of a [`Position`] traded on margin.
+    pub fn margin(self, value: PositionMargin) -> Self {
+        Self {
+            margin: Some(value),
+            ..self
+        }
+    }
+
     pub fn build(self) -> Result<Position, PortfolioError> {
         Ok(Position {
             position_id: self
@@ -465,6 +727,7 @@ impl PositionBuilder {
             realised_profit_loss: self
                 .realised_profit_loss
                 .ok_or(PortfolioError::BuilderIncomplete("realised_profit_loss"))?,
+            margin: self.margin,
         })
     }
 }
@@ -493,19 +756,29 @@ impl Default for PositionMeta {
     }
 }
 
-/// [`Position`] update event. Occurs as a result of receiving new [`MarketEvent`] data.
+/// [`Position`] update event. Occurs as a result of receiving new [`MarketEvent`] data, or a
+/// [`FillEvent`] that scales an open [`Position`] in or out.
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct PositionUpdate {
     /// Unique identifier for a [`Position`], generated from an exchange, symbol, and enter_time.
     pub position_id: String,
     /// Event timestamp of the last event to trigger a [`Position`] update.
     pub update_time: DateTime<Utc>,
+    /// +ve or -ve quantity of symbol contracts currently open.
+    pub quantity: f64,
     /// Symbol current close price.
     pub current_symbol_price: f64,
     /// abs(Quantity) * current_symbol_price.
     pub current_value_gross: f64,
     /// Unrealised P&L whilst the [`Position`] is open.
     pub unrealised_profit_loss: f64,
+    /// Realised P&L of any quantity the [`Position`] has been reduced by.
+    pub realised_profit_loss: f64,
+    /// Liquidation price of a [`Position`] traded on margin.
+    pub liquidation_price: Option<f64>,
+    /// Whether the current symbol price has crossed the liquidation price of a [`Position`]
+    /// traded on margin, requiring it to be exited.
+    pub liquidated: bool,
 }
 
 impl From<&mut Position> for PositionUpdate {
@@ -513,9 +786,15 @@ impl From<&mut Position> for PositionUpdate {
         Self {
             position_id: updated_position.position_id.clone(),
             update_time: updated_position.meta.update_time,
+            quantity: updated_position.quantity,
             current_symbol_price: updated_position.current_symbol_price,
             current_value_gross: updated_position.current_value_gross,
             unrealised_profit_loss: updated_position.unrealised_profit_loss,
+            realised_profit_loss: updated_position.realised_profit_loss,
+            liquidation_price: updated_position
+                .margin
+                .map(|margin| margin.liquidation_price),
+            liquidated: updated_position.is_liquidated(),
         }
     }
 }
@@ -573,6 +852,8 @@ mod tests {
     use super::*;
     use crate::test_util::{fill_event, market_event_trade, position};
     use barter_integration::model::Side;
+    use chrono::TimeZone;
+    use rust_decimal::Decimal;
 
     #[test]
     fn enter_new_position_with_long_decision_provided() {
@@ -757,8 +1038,8 @@ mod tests {
         let mut input_market = market_event_trade(Side::Buy);
         match input_market.kind {
             // +100.0 higher than current_symbol_price
-            DataKind::Candle(ref mut candle) => candle.close = 200.0,
-            DataKind::Trade(ref mut trade) => trade.price = 200.0,
+            DataKind::Candle(ref mut candle) => candle.close = Decimal::from(200),
+            DataKind::Trade(ref mut trade) => trade.price = Decimal::from(200),
             _ => todo!(),
         };
 
@@ -777,8 +1058,8 @@ mod tests {
 
         // Assert updated fields are correct
         let close = match &input_market.kind {
-            DataKind::Trade(trade) => trade.price,
-            DataKind::Candle(candle) => candle.close,
+            DataKind::Trade(trade) => trade.price.to_f64().unwrap(),
+            DataKind::Candle(candle) => candle.close.to_f64().unwrap(),
             _ => todo!(),
         };
         assert_eq!(position.current_symbol_price, close);
@@ -814,8 +1095,8 @@ mod tests {
 
         match input_market.kind {
             // -50.0 lower than current_symbol_price
-            DataKind::Candle(ref mut candle) => candle.close = 50.0,
-            DataKind::Trade(ref mut trade) => trade.price = 50.0,
+            DataKind::Candle(ref mut candle) => candle.close = Decimal::from(50),
+            DataKind::Trade(ref mut trade) => trade.price = Decimal::from(50),
             _ => todo!(),
         };
 
@@ -834,8 +1115,8 @@ mod tests {
 
         // Assert updated fields are correct
         let close = match &input_market.kind {
-            DataKind::Trade(trade) => trade.price,
-            DataKind::Candle(candle) => candle.close,
+            DataKind::Trade(trade) => trade.price.to_f64().unwrap(),
+            DataKind::Candle(candle) => candle.close.to_f64().unwrap(),
             _ => todo!(),
         };
         assert_eq!(position.current_symbol_price, close);
@@ -871,8 +1152,8 @@ mod tests {
 
         match input_market.kind {
             // -50.0 lower than current_symbol_price
-            DataKind::Candle(ref mut candle) => candle.close = 50.0,
-            DataKind::Trade(ref mut trade) => trade.price = 50.0,
+            DataKind::Candle(ref mut candle) => candle.close = Decimal::from(50),
+            DataKind::Trade(ref mut trade) => trade.price = Decimal::from(50),
             _ => todo!(),
         };
 
@@ -891,8 +1172,8 @@ mod tests {
 
         // Assert updated fields are correct
         let close = match &input_market.kind {
-            DataKind::Trade(trade) => trade.price,
-            DataKind::Candle(candle) => candle.close,
+            DataKind::Trade(trade) => trade.price.to_f64().unwrap(),
+            DataKind::Candle(candle) => candle.close.to_f64().unwrap(),
             _ => todo!(),
         };
         assert_eq!(position.current_symbol_price, close);
@@ -928,8 +1209,8 @@ mod tests {
 
         match input_market.kind {
             // +100.0 higher than current_symbol_price
-            DataKind::Candle(ref mut candle) => candle.close = 200.0,
-            DataKind::Trade(ref mut trade) => trade.price = 200.0,
+            DataKind::Candle(ref mut candle) => candle.close = Decimal::from(200),
+            DataKind::Trade(ref mut trade) => trade.price = Decimal::from(200),
             _ => todo!(),
         };
 
@@ -948,8 +1229,8 @@ mod tests {
 
         // Assert updated fields are correct
         let close = match &input_market.kind {
-            DataKind::Trade(trade) => trade.price,
-            DataKind::Candle(candle) => candle.close,
+            DataKind::Trade(trade) => trade.price.to_f64().unwrap(),
+            DataKind::Candle(candle) => candle.close.to_f64().unwrap(),
             _ => todo!(),
         };
         assert_eq!(position.current_symbol_price, close);
@@ -1638,4 +1919,284 @@ mod tests {
 
         assert!(PositionExit::try_from(&mut exited_position).is_err());
     }
+
+    #[test]
+    fn increase_long_position_with_long_fill_reweights_enter_avg_price() {
+        let mut position = position();
+        position.side = Side::Buy;
+        position.quantity = 1.0;
+        position.enter_fees_total = 3.0;
+        position.enter_fees = Fees {
+            exchange: 1.0,
+            slippage: 1.0,
+            network: 1.0,
+        };
+        position.enter_avg_price_gross = 100.0;
+        position.enter_value_gross = 100.0;
+
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Long;
+        input_fill.quantity = 1.0;
+        input_fill.fill_value_gross = 200.0;
+        input_fill.fees = Fees {
+            exchange: 1.0,
+            slippage: 1.0,
+            network: 1.0,
+        };
+
+        let position_update = position.increase(&input_fill).unwrap();
+
+        assert_eq!(position.quantity, 2.0);
+        assert_eq!(position.enter_value_gross, 300.0);
+        assert_eq!(position.enter_avg_price_gross, 150.0);
+        assert_eq!(position.enter_fees_total, 6.0);
+        assert_eq!(position.enter_fees.exchange, 2.0);
+        assert_eq!(position.current_symbol_price, 200.0);
+        assert_eq!(position.current_value_gross, 400.0);
+        // current_value_gross - enter_value_gross - 2 * enter_fees_total
+        assert_eq!(position.unrealised_profit_loss, 400.0 - 300.0 - 12.0);
+        assert_eq!(position.realised_profit_loss, 0.0);
+        assert_eq!(position_update.quantity, 2.0);
+        assert_eq!(position_update.unrealised_profit_loss, 88.0);
+    }
+
+    #[test]
+    fn increase_long_position_with_short_fill_and_return_err() {
+        let mut position = position();
+        position.side = Side::Buy;
+
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Short;
+        input_fill.quantity = -1.0;
+
+        assert!(position.increase(&input_fill).is_err());
+    }
+
+    #[test]
+    fn reduce_then_exit_long_position_realises_pnl_per_reduction() {
+        let mut position = position();
+        position.side = Side::Buy;
+        position.quantity = 2.0;
+        position.enter_fees_total = 4.0;
+        position.enter_avg_price_gross = 100.0;
+        position.enter_value_gross = 200.0;
+
+        // Reduce half the Position
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::CloseLong;
+        input_fill.quantity = -1.0;
+        input_fill.fill_value_gross = 150.0;
+        input_fill.fees = Fees {
+            exchange: 2.0,
+            slippage: 0.0,
+            network: 0.0,
+        };
+
+        let position_update = position.reduce(&input_fill).unwrap();
+
+        // Entry fields are untouched by a reduction
+        assert_eq!(position.enter_value_gross, 200.0);
+        assert_eq!(position.enter_avg_price_gross, 100.0);
+        assert_eq!(position.enter_fees_total, 4.0);
+
+        assert_eq!(position.quantity, 1.0);
+        assert_eq!(position.exit_value_gross, 150.0);
+        assert_eq!(position.exit_avg_price_gross, 150.0);
+        assert_eq!(position.exit_fees_total, 2.0);
+        assert_eq!(position.calculate_exit_quantity(), 1.0);
+        assert_eq!(position.calculate_open_enter_value_gross(), 100.0);
+        assert_eq!(position.calculate_open_enter_fees_total(), 2.0);
+        // exit_value_gross - reduced enter_value_gross - reduced enter_fees - exit_fees
+        assert_eq!(position.realised_profit_loss, 150.0 - 100.0 - 2.0 - 2.0);
+        // current_value_gross - open enter_value_gross - 2 * open enter_fees
+        assert_eq!(position.unrealised_profit_loss, 150.0 - 100.0 - 4.0);
+        assert_eq!(position_update.quantity, 1.0);
+        assert_eq!(position_update.realised_profit_loss, 46.0);
+
+        // Exit the remainder of the Position
+        let current_balance = Balance {
+            time: Utc::now(),
+            total: 10000.0,
+            available: 10000.0,
+        };
+        input_fill.fill_value_gross = 200.0;
+
+        let position_exit = position.exit(current_balance, &input_fill).unwrap();
+
+        // Closed Position quantity is the total quantity entered
+        assert_eq!(position.quantity, 2.0);
+        assert_eq!(position.exit_value_gross, 350.0);
+        assert_eq!(position.exit_avg_price_gross, 175.0);
+        assert_eq!(position.exit_fees_total, 4.0);
+        // Total realised = 46.0 from the reduction + (200.0 - 100.0 - 2.0 - 2.0) from the exit
+        assert_eq!(position.realised_profit_loss, 350.0 - 200.0 - 8.0);
+        assert_eq!(position_exit.realised_profit_loss, 142.0);
+        // Only P&L not realised by the previous reduction is added to the exit balance
+        assert_eq!(position_exit.exit_balance.total, 10000.0 + 96.0);
+    }
+
+    #[test]
+    fn reduce_short_position_with_entry_fill_and_return_err() {
+        let mut position = position();
+        position.side = Side::Sell;
+        position.quantity = -2.0;
+
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Long;
+        input_fill.quantity = 1.0;
+
+        assert!(position.reduce(&input_fill).is_err());
+    }
+
+    #[test]
+    fn reduce_short_position_with_positive_real_pnl() {
+        let mut position = position();
+        position.side = Side::Sell;
+        position.quantity = -4.0;
+        position.enter_fees_total = 4.0;
+        position.enter_avg_price_gross = 100.0;
+        position.enter_value_gross = 400.0;
+
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::CloseShort;
+        input_fill.quantity = 1.0;
+        input_fill.fill_value_gross = 50.0;
+        input_fill.fees = Fees::default();
+
+        position.reduce(&input_fill).unwrap();
+
+        assert_eq!(position.quantity, -3.0);
+        // reduced enter_value_gross - exit_value_gross - reduced enter_fees
+        assert_eq!(position.realised_profit_loss, 100.0 - 50.0 - 1.0);
+        // open enter_value_gross - current_value_gross - 2 * open enter_fees
+        assert_eq!(position.unrealised_profit_loss, 300.0 - 150.0 - 6.0);
+    }
+
+    #[test]
+    fn determine_position_fill_from_fill_quantity() {
+        let mut position = position();
+        position.side = Side::Buy;
+        position.quantity = 2.0;
+
+        let mut input_fill = fill_event();
+
+        input_fill.quantity = 1.0;
+        assert_eq!(
+            position.determine_position_fill(&input_fill),
+            PositionFill::Increase
+        );
+
+        input_fill.quantity = -1.0;
+        assert_eq!(
+            position.determine_position_fill(&input_fill),
+            PositionFill::Reduce
+        );
+
+        input_fill.quantity = -2.0;
+        assert_eq!(
+            position.determine_position_fill(&input_fill),
+            PositionFill::Exit
+        );
+
+        input_fill.quantity = -3.0;
+        assert_eq!(
+            position.determine_position_fill(&input_fill),
+            PositionFill::Reverse
+        );
+    }
+
+    #[test]
+    fn split_reverse_fill_pro_rata_by_quantity() {
+        let mut position = position();
+        position.side = Side::Buy;
+        position.quantity = 1.0;
+
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::Short;
+        input_fill.quantity = -4.0;
+        input_fill.fill_value_gross = 400.0;
+        input_fill.fees = Fees {
+            exchange: 4.0,
+            slippage: 0.0,
+            network: 0.0,
+        };
+
+        let (exit_fill, enter_fill) = position.split_reverse_fill(&input_fill);
+
+        assert_eq!(exit_fill.decision, Decision::CloseLong);
+        assert_eq!(exit_fill.quantity, -1.0);
+        assert_eq!(exit_fill.fill_value_gross, 100.0);
+        assert_eq!(exit_fill.fees.exchange, 1.0);
+
+        assert_eq!(enter_fill.decision, Decision::Short);
+        assert_eq!(enter_fill.quantity, -3.0);
+        assert_eq!(enter_fill.fill_value_gross, 300.0);
+        assert_eq!(enter_fill.fees.exchange, 3.0);
+    }
+
+    fn margined_long_position() -> Position {
+        let mut position = position();
+        position.side = Side::Buy;
+        position.quantity = 1.0;
+        position.meta.enter_time = Utc.timestamp_opt(0, 0).unwrap();
+        position.apply_margin(
+            MarginConfig {
+                leverage: 10.0,
+                funding_rate: 0.01,
+                ..MarginConfig::default()
+            },
+            0.0,
+        );
+        position
+    }
+
+    fn market_event_trade_at(price: i64, hours: i64) -> MarketEvent<DataKind> {
+        let mut input_market = market_event_trade(Side::Buy);
+        input_market.exchange_time = Utc.timestamp_opt(hours * 3600, 0).unwrap();
+        if let DataKind::Trade(ref mut trade) = input_market.kind {
+            trade.price = Decimal::from(price);
+        }
+        input_market
+    }
+
+    #[test]
+    fn update_margined_long_position_applies_funding_and_detects_liquidation() {
+        let mut position = margined_long_position();
+        let margin = position.margin.unwrap();
+        assert_eq!(margin.initial_margin, 10.0);
+        assert_eq!(margin.liquidation_price, 90.0 / 0.995);
+        assert_eq!(position.calculate_open_reserved_value(), 10.0);
+
+        // Funding is paid on the current notional value once the funding time has passed
+        let update = position.update(&market_event_trade_at(100, 8)).unwrap();
+        assert_eq!(position.calculate_funding_paid(), 1.0);
+        assert_eq!(update.unrealised_profit_loss, -1.0);
+        assert_eq!(update.liquidation_price, Some((100.0 - 9.0) / 0.995));
+        assert!(!update.liquidated);
+
+        // Price crossing the liquidation price flags the Position as liquidated
+        let update = position.update(&market_event_trade_at(91, 9)).unwrap();
+        assert_eq!(position.calculate_funding_paid(), 1.0);
+        assert!(update.liquidated);
+    }
+
+    #[test]
+    fn exit_margined_position_realises_funding_paid() {
+        let mut position = margined_long_position();
+        position.update(&market_event_trade_at(100, 8)).unwrap();
+
+        let mut input_fill = fill_event();
+        input_fill.decision = Decision::CloseLong;
+        input_fill.quantity = -1.0;
+        input_fill.fill_value_gross = 100.0;
+        input_fill.fees = Fees::default();
+
+        let exit = position
+            .exit(Balance::new(Utc::now(), 1000.0, 1000.0), &input_fill)
+            .unwrap();
+
+        // Flat price so the realised P&L is only the funding paid
+        assert_eq!(exit.realised_profit_loss, -1.0);
+        assert_eq!(exit.exit_balance.total, 999.0);
+    }
 }
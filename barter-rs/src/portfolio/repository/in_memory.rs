@@ -5,7 +5,7 @@ use crate::{
             determine_exited_positions_id, error::RepositoryError, BalanceHandler, PositionHandler,
             StatisticHandler,
         },
-        Balance, BalanceId,
+        Balance, BalanceId, SymbolBalances,
     },
     statistic::summary::PositionSummariser,
 };
@@ -22,6 +22,7 @@ pub struct InMemoryRepository<Statistic: PositionSummariser> {
     open_positions: HashMap<PositionId, Position>,
     closed_positions: HashMap<String, Vec<Position>>,
     current_balances: HashMap<BalanceId, Balance>,
+    symbol_balances: HashMap<BalanceId, SymbolBalances>,
     statistics: HashMap<MarketId, Statistic>,
 }
 
@@ -103,6 +104,23 @@ impl<Statistic: PositionSummariser> BalanceHandler for InMemoryRepository<Statis
             .copied()
             .ok_or(RepositoryError::ExpectedDataNotPresentError)
     }
+
+    fn set_symbol_balances(
+        &mut self,
+        engine_id: Uuid,
+        balances: SymbolBalances,
+    ) -> Result<(), RepositoryError> {
+        self.symbol_balances
+            .insert(Balance::symbol_balances_id(engine_id), balances);
+        Ok(())
+    }
+
+    fn get_symbol_balances(&mut self, engine_id: Uuid) -> Result<SymbolBalances, RepositoryError> {
+        self.symbol_balances
+            .get(&Balance::symbol_balances_id(engine_id))
+            .cloned()
+            .ok_or(RepositoryError::ExpectedDataNotPresentError)
+    }
 }
 
 impl<Statistic: PositionSummariser> StatisticHandler<Statistic> for InMemoryRepository<Statistic> {
@@ -130,6 +148,7 @@ impl<Statistic: PositionSummariser> InMemoryRepository<Statistic> {
             open_positions: HashMap::new(),
             closed_positions: HashMap::new(),
             current_balances: HashMap::new(),
+            symbol_balances: HashMap::new(),
             statistics: HashMap::new(),
         }
     }
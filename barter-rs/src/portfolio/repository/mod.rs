@@ -1,7 +1,7 @@
 use crate::portfolio::{
     position::{Position, PositionId},
     repository::error::RepositoryError,
-    Balance,
+    Balance, SymbolBalances,
 };
 use barter_integration::model::{Market, MarketId};
 use uuid::Uuid;
@@ -15,6 +15,9 @@ pub mod in_memory;
 /// Redis repository for state keeping.
 pub mod redis;
 
+/// Embedded SQLite repository for state keeping with a versioned schema & queryable history.
+pub mod sqlite;
+
 /// Handles the reading & writing of a [`Position`] to/from the persistence layer.
 pub trait PositionHandler {
     /// Upsert the open [`Position`] using it's [`PositionId`].
@@ -56,6 +59,14 @@ pub trait BalanceHandler {
     fn set_balance(&mut self, engine_id: Uuid, balance: Balance) -> Result<(), RepositoryError>;
     /// Get the Portfolio [`Balance`] using the engine_id provided.
     fn get_balance(&mut self, engine_id: Uuid) -> Result<Balance, RepositoryError>;
+    /// Upsert the Portfolio [`SymbolBalances`] at the engine_id.
+    fn set_symbol_balances(
+        &mut self,
+        engine_id: Uuid,
+        balances: SymbolBalances,
+    ) -> Result<(), RepositoryError>;
+    /// Get the Portfolio [`SymbolBalances`] using the engine_id provided.
+    fn get_symbol_balances(&mut self, engine_id: Uuid) -> Result<SymbolBalances, RepositoryError>;
 }
 
 /// Handles the reading & writing of a Portfolio's statistics for each of it's
@@ -6,7 +6,7 @@ use crate::{
             determine_exited_positions_id, error::RepositoryError, BalanceHandler, PositionHandler,
             StatisticHandler,
         },
-        Balance,
+        Balance, SymbolBalances,
     },
     statistic::summary::PositionSummariser,
 };
@@ -136,6 +136,27 @@ where
 
         Ok(serde_json::from_str::<Balance>(&balance_value)?)
     }
+
+    fn set_symbol_balances(
+        &mut self,
+        engine_id: Uuid,
+        balances: SymbolBalances,
+    ) -> Result<(), RepositoryError> {
+        let balances_string = serde_json::to_string(&balances)?;
+
+        self.conn
+            .set(Balance::symbol_balances_id(engine_id), balances_string)
+            .map_err(|_| RepositoryError::WriteError)
+    }
+
+    fn get_symbol_balances(&mut self, engine_id: Uuid) -> Result<SymbolBalances, RepositoryError> {
+        let balances_value: String = self
+            .conn
+            .get(Balance::symbol_balances_id(engine_id))
+            .map_err(|_| RepositoryError::ReadError)?;
+
+        Ok(serde_json::from_str::<SymbolBalances>(&balances_value)?)
+    }
 }
 
 impl<Statistic> StatisticHandler<Statistic> for RedisRepository<Statistic>
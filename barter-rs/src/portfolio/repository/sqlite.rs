@@ -0,0 +1,749 @@
+use crate::{
+    portfolio::{
+        error::PortfolioError,
+        position::{determine_position_id, Position, PositionId},
+        repository::{error::RepositoryError, BalanceHandler, PositionHandler, StatisticHandler},
+        Balance, SymbolBalances,
+    },
+    statistic::summary::PositionSummariser,
+};
+use barter_integration::model::{instrument::symbol::Symbol, Market, MarketId};
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fmt::{Debug, Formatter},
+    marker::PhantomData,
+};
+use uuid::Uuid;
+
+/// Configuration for constructing a [`SqliteRepository`] via the new() constructor method.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default, Deserialize, Serialize)]
+pub struct Config {
+    /// Path of the SQLite database file, created if it does not exist. Use ":memory:" for a
+    /// transient in-memory database.
+    pub path: String,
+}
+
+/// Ordered schema migrations. The schema version of a database is the number of migrations
+/// applied, tracked via `PRAGMA user_version`.
+const MIGRATIONS: &[&str] = &[
+    // Version 1: Positions, Balances & Statistics
+    "
+    CREATE TABLE open_positions (
+        position_id TEXT PRIMARY KEY NOT NULL,
+        engine_id TEXT NOT NULL,
+        exchange TEXT NOT NULL,
+        instrument TEXT NOT NULL,
+        side TEXT NOT NULL,
+        quantity REAL NOT NULL,
+        enter_time TEXT NOT NULL,
+        update_time TEXT NOT NULL,
+        position TEXT NOT NULL
+    );
+    CREATE INDEX open_positions_engine_id ON open_positions (engine_id);
+
+    CREATE TABLE exited_positions (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        engine_id TEXT NOT NULL,
+        position_id TEXT NOT NULL,
+        exchange TEXT NOT NULL,
+        instrument TEXT NOT NULL,
+        side TEXT NOT NULL,
+        quantity REAL NOT NULL,
+        enter_time TEXT NOT NULL,
+        exit_time TEXT,
+        realised_profit_loss REAL NOT NULL,
+        position TEXT NOT NULL
+    );
+    CREATE INDEX exited_positions_engine_id ON exited_positions (engine_id, id);
+
+    CREATE TABLE balances (
+        engine_id TEXT PRIMARY KEY NOT NULL,
+        time TEXT NOT NULL,
+        total REAL NOT NULL,
+        available REAL NOT NULL
+    );
+
+    CREATE TABLE balance_history (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        engine_id TEXT NOT NULL,
+        time TEXT NOT NULL,
+        total REAL NOT NULL,
+        available REAL NOT NULL
+    );
+    CREATE INDEX balance_history_engine_id ON balance_history (engine_id, id);
+
+    CREATE TABLE symbol_balances (
+        engine_id TEXT NOT NULL,
+        symbol TEXT NOT NULL,
+        time TEXT NOT NULL,
+        total REAL NOT NULL,
+        available REAL NOT NULL,
+        PRIMARY KEY (engine_id, symbol)
+    );
+
+    CREATE TABLE symbol_balance_engines (
+        engine_id TEXT PRIMARY KEY NOT NULL
+    );
+
+    CREATE TABLE statistics (
+        market_id TEXT PRIMARY KEY NOT NULL,
+        statistic TEXT NOT NULL
+    );
+    ",
+];
+
+/// SQLite persisted repository that implements [`PositionHandler`], [`BalanceHandler`],
+/// & [`StatisticHandler`]. Used by a Portfolio implementation to persist the Portfolio state in
+/// an embedded database, so trading sessions can be resumed & audited without external services.
+///
+/// Exited [`Position`]s & every [`Balance`] update are appended to per engine_id history tables,
+/// preserving the order they occurred in. Statistics are keyed by the [`MarketId`] provided,
+/// which the [`MetaPortfolio`](crate::portfolio::portfolio::MetaPortfolio) scopes to it's
+/// engine_id so engines can share a database.
+pub struct SqliteRepository<Statistic>
+where
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+{
+    conn: Connection,
+    _statistic_marker: PhantomData<Statistic>,
+}
+
+impl<Statistic> PositionHandler for SqliteRepository<Statistic>
+where
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+{
+    fn set_open_position(&mut self, position: Position) -> Result<(), RepositoryError> {
+        self.conn
+            .execute(
+                "INSERT INTO open_positions
+                    (position_id, engine_id, exchange, instrument, side, quantity, enter_time,
+                     update_time, position)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)
+                 ON CONFLICT (position_id) DO UPDATE SET
+                    side = excluded.side,
+                    quantity = excluded.quantity,
+                    update_time = excluded.update_time,
+                    position = excluded.position",
+                params![
+                    position.position_id,
+                    position_engine_id(&position.position_id),
+                    position.exchange.to_string(),
+                    position.instrument.to_string(),
+                    position.side.to_string(),
+                    position.quantity,
+                    position.meta.enter_time,
+                    position.meta.update_time,
+                    serde_json::to_string(&position)?,
+                ],
+            )
+            .map(|_| ())
+            .map_err(|_| RepositoryError::WriteError)
+    }
+
+    fn get_open_position(
+        &mut self,
+        position_id: &PositionId,
+    ) -> Result<Option<Position>, RepositoryError> {
+        self.conn
+            .query_row(
+                "SELECT position FROM open_positions WHERE position_id = ?1",
+                [position_id],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()
+            .map_err(|_| RepositoryError::ReadError)?
+            .map(|position| serde_json::from_str::<Position>(&position))
+            .transpose()
+            .map_err(RepositoryError::JsonSerDeError)
+    }
+
+    fn get_open_positions<'a, Markets: Iterator<Item = &'a Market>>(
+        &mut self,
+        engine_id: Uuid,
+        markets: Markets,
+    ) -> Result<Vec<Position>, RepositoryError> {
+        let mut statement = self
+            .conn
+            .prepare("SELECT position_id, position FROM open_positions WHERE engine_id = ?1")
+            .map_err(|_| RepositoryError::ReadError)?;
+
+        let mut positions = statement
+            .query_map([engine_id.to_string()], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+            })
+            .and_then(Iterator::collect::<Result<HashMap<PositionId, String>, _>>)
+            .map_err(|_| RepositoryError::ReadError)?;
+
+        // Return the open Positions in the order of the input Markets
+        markets
+            .filter_map(|market| {
+                positions.remove(&determine_position_id(
+                    engine_id,
+                    &market.exchange,
+                    &market.instrument,
+                ))
+            })
+            .map(|position| serde_json::from_str::<Position>(&position))
+            .collect::<Result<Vec<Position>, serde_json::Error>>()
+            .map_err(RepositoryError::JsonSerDeError)
+    }
+
+    fn remove_position(
+        &mut self,
+        position_id: &String,
+    ) -> Result<Option<Position>, RepositoryError> {
+        let position = self.get_open_position(position_id)?;
+
+        self.conn
+            .execute(
+                "DELETE FROM open_positions WHERE position_id = ?1",
+                [position_id],
+            )
+            .map_err(|_| RepositoryError::DeleteError)?;
+
+        Ok(position)
+    }
+
+    fn set_exited_position(
+        &mut self,
+        engine_id: Uuid,
+        position: Position,
+    ) -> Result<(), RepositoryError> {
+        self.conn
+            .execute(
+                "INSERT INTO exited_positions
+                    (engine_id, position_id, exchange, instrument, side, quantity, enter_time,
+                     exit_time, realised_profit_loss, position)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+                params![
+                    engine_id.to_string(),
+                    position.position_id,
+                    position.exchange.to_string(),
+                    position.instrument.to_string(),
+                    position.side.to_string(),
+                    position.quantity,
+                    position.meta.enter_time,
+                    position.meta.exit_balance.map(|balance| balance.time),
+                    position.realised_profit_loss,
+                    serde_json::to_string(&position)?,
+                ],
+            )
+            .map(|_| ())
+            .map_err(|_| RepositoryError::WriteError)
+    }
+
+    fn get_exited_positions(&mut self, engine_id: Uuid) -> Result<Vec<Position>, RepositoryError> {
+        let mut statement = self
+            .conn
+            .prepare("SELECT position FROM exited_positions WHERE engine_id = ?1 ORDER BY id")
+            .map_err(|_| RepositoryError::ReadError)?;
+
+        let positions = statement
+            .query_map([engine_id.to_string()], |row| row.get::<_, String>(0))
+            .and_then(Iterator::collect::<Result<Vec<String>, _>>)
+            .map_err(|_| RepositoryError::ReadError)?;
+
+        positions
+            .iter()
+            .map(|position| serde_json::from_str::<Position>(position))
+            .collect::<Result<Vec<Position>, serde_json::Error>>()
+            .map_err(RepositoryError::JsonSerDeError)
+    }
+}
+
+impl<Statistic> BalanceHandler for SqliteRepository<Statistic>
+where
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+{
+    fn set_balance(&mut self, engine_id: Uuid, balance: Balance) -> Result<(), RepositoryError> {
+        let engine_id = engine_id.to_string();
+        let transaction = self
+            .conn
+            .transaction()
+            .map_err(|_| RepositoryError::WriteError)?;
+
+        transaction
+            .execute(
+                "INSERT INTO balances (engine_id, time, total, available)
+                 VALUES (?1, ?2, ?3, ?4)
+                 ON CONFLICT (engine_id) DO UPDATE SET
+                    time = excluded.time,
+                    total = excluded.total,
+                    available = excluded.available",
+                params![engine_id, balance.time, balance.total, balance.available],
+            )
+            .and_then(|_| {
+                transaction.execute(
+                    "INSERT INTO balance_history (engine_id, time, total, available)
+                     VALUES (?1, ?2, ?3, ?4)",
+                    params![engine_id, balance.time, balance.total, balance.available],
+                )
+            })
+            .and_then(|_| transaction.commit())
+            .map_err(|_| RepositoryError::WriteError)
+    }
+
+    fn get_balance(&mut self, engine_id: Uuid) -> Result<Balance, RepositoryError> {
+        self.conn
+            .query_row(
+                "SELECT time, total, available FROM balances WHERE engine_id = ?1",
+                [engine_id.to_string()],
+                |row| Ok(Balance::new(row.get(0)?, row.get(1)?, row.get(2)?)),
+            )
+            .optional()
+            .map_err(|_| RepositoryError::ReadError)?
+            .ok_or(RepositoryError::ExpectedDataNotPresentError)
+    }
+
+    fn set_symbol_balances(
+        &mut self,
+        engine_id: Uuid,
+        balances: SymbolBalances,
+    ) -> Result<(), RepositoryError> {
+        let engine_id = engine_id.to_string();
+        let transaction = self
+            .conn
+            .transaction()
+            .map_err(|_| RepositoryError::WriteError)?;
+
+        // Replace every SymbolBalance of the engine_id, recording that it has been set so an
+        // empty SymbolBalances is distinguishable from one never set
+        transaction
+            .execute(
+                "DELETE FROM symbol_balances WHERE engine_id = ?1",
+                [&engine_id],
+            )
+            .and_then(|_| {
+                transaction.execute(
+                    "INSERT OR IGNORE INTO symbol_balance_engines (engine_id) VALUES (?1)",
+                    [&engine_id],
+                )
+            })
+            .map_err(|_| RepositoryError::WriteError)?;
+
+        for (symbol, balance) in balances {
+            transaction
+                .execute(
+                    "INSERT INTO symbol_balances (engine_id, symbol, time, total, available)
+                     VALUES (?1, ?2, ?3, ?4, ?5)",
+                    params![
+                        engine_id,
+                        symbol.as_ref(),
+                        balance.time,
+                        balance.total,
+                        balance.available
+                    ],
+                )
+                .map_err(|_| RepositoryError::WriteError)?;
+        }
+
+        transaction
+            .commit()
+            .map_err(|_| RepositoryError::WriteError)
+    }
+
+    fn get_symbol_balances(&mut self, engine_id: Uuid) -> Result<SymbolBalances, RepositoryError> {
+        let engine_id = engine_id.to_string();
+
+        let is_set = self
+            .conn
+            .query_row(
+                "SELECT 1 FROM symbol_balance_engines WHERE engine_id = ?1",
+                [&engine_id],
+                |_| Ok(()),
+            )
+            .optional()
+            .map_err(|_| RepositoryError::ReadError)?
+            .is_some();
+
+        if !is_set {
+            return Err(RepositoryError::ExpectedDataNotPresentError);
+        }
+
+        let mut statement = self
+            .conn
+            .prepare(
+                "SELECT symbol, time, total, available FROM symbol_balances WHERE engine_id = ?1",
+            )
+            .map_err(|_| RepositoryError::ReadError)?;
+
+        statement
+            .query_map([&engine_id], |row| {
+                Ok((
+                    Symbol::from(row.get::<_, String>(0)?),
+                    Balance::new(row.get(1)?, row.get(2)?, row.get(3)?),
+                ))
+            })
+            .and_then(Iterator::collect::<Result<SymbolBalances, _>>)
+            .map_err(|_| RepositoryError::ReadError)
+    }
+}
+
+impl<Statistic> StatisticHandler<Statistic> for SqliteRepository<Statistic>
+where
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+{
+    fn set_statistics(
+        &mut self,
+        market_id: MarketId,
+        statistic: Statistic,
+    ) -> Result<(), RepositoryError> {
+        self.conn
+            .execute(
+                "INSERT INTO statistics (market_id, statistic) VALUES (?1, ?2)
+                 ON CONFLICT (market_id) DO UPDATE SET statistic = excluded.statistic",
+                params![market_id.0, serde_json::to_string(&statistic)?],
+            )
+            .map(|_| ())
+            .map_err(|_| RepositoryError::WriteError)
+    }
+
+    fn get_statistics(&mut self, market_id: &MarketId) -> Result<Statistic, RepositoryError> {
+        let statistic = self
+            .conn
+            .query_row(
+                "SELECT statistic FROM statistics WHERE market_id = ?1",
+                [&market_id.0],
+                |row| row.get::<_, String>(0),
+            )
+            .optional()
+            .map_err(|_| RepositoryError::ReadError)?
+            .ok_or(RepositoryError::ExpectedDataNotPresentError)?;
+
+        serde_json::from_str(&statistic).map_err(RepositoryError::JsonSerDeError)
+    }
+}
+
+/// Returns the engine_id prefix of a [`PositionId`] generated by [`determine_position_id`].
+fn position_engine_id(position_id: &str) -> &str {
+    position_id.split('_').next().unwrap_or_default()
+}
+
+impl<Statistic> Debug for SqliteRepository<Statistic>
+where
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SqliteRepository")
+            .field("path", &self.conn.path())
+            .finish()
+    }
+}
+
+impl<Statistic> SqliteRepository<Statistic>
+where
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+{
+    /// Constructs a new [`SqliteRepository`] component using the provided SQLite connection,
+    /// applying any pending schema migrations.
+    pub fn new(mut connection: Connection) -> Result<Self, RepositoryError> {
+        Self::migrate(&mut connection)?;
+
+        Ok(Self {
+            conn: connection,
+            _statistic_marker: PhantomData,
+        })
+    }
+
+    /// Returns a [`SqliteRepositoryBuilder`] instance.
+    pub fn builder() -> SqliteRepositoryBuilder<Statistic> {
+        SqliteRepositoryBuilder::new()
+    }
+
+    /// Open & return a SQLite connection.
+    pub fn setup_sqlite_connection(cfg: Config) -> Connection {
+        Connection::open(cfg.path).expect("Failed to open SQLite database")
+    }
+
+    /// Apply every schema migration that has not yet been applied to the database, each in it's
+    /// own transaction.
+    pub fn migrate(connection: &mut Connection) -> Result<(), RepositoryError> {
+        let version = Self::schema_version(connection)?;
+
+        for (index, migration) in MIGRATIONS.iter().enumerate().skip(version) {
+            let transaction = connection
+                .transaction()
+                .map_err(|_| RepositoryError::WriteError)?;
+
+            transaction
+                .execute_batch(migration)
+                .and_then(|_| transaction.pragma_update(None, "user_version", index + 1))
+                .and_then(|_| transaction.commit())
+                .map_err(|_| RepositoryError::WriteError)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of schema migrations applied to the database.
+    pub fn schema_version(connection: &Connection) -> Result<usize, RepositoryError> {
+        connection
+            .query_row("PRAGMA user_version", [], |row| row.get(0))
+            .map_err(|_| RepositoryError::ReadError)
+    }
+
+    /// Get every [`Balance`] persisted for the engine_id, in the order they were set.
+    pub fn get_balance_history(
+        &mut self,
+        engine_id: Uuid,
+    ) -> Result<Vec<Balance>, RepositoryError> {
+        let mut statement = self
+            .conn
+            .prepare(
+                "SELECT time, total, available FROM balance_history
+                 WHERE engine_id = ?1 ORDER BY id",
+            )
+            .map_err(|_| RepositoryError::ReadError)?;
+
+        let balances = statement
+            .query_map([engine_id.to_string()], |row| {
+                Ok(Balance::new(
+                    row.get::<_, DateTime<Utc>>(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                ))
+            })
+            .and_then(Iterator::collect::<Result<Vec<Balance>, _>>)
+            .map_err(|_| RepositoryError::ReadError)?;
+
+        Ok(balances)
+    }
+}
+
+/// Builder to construct [`SqliteRepository`] instances.
+pub struct SqliteRepositoryBuilder<Statistic>
+where
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+{
+    conn: Option<Connection>,
+    _statistic_marker: PhantomData<Statistic>,
+}
+
+impl<Statistic> Default for SqliteRepositoryBuilder<Statistic>
+where
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Statistic> SqliteRepositoryBuilder<Statistic>
+where
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+{
+    pub fn new() -> Self {
+        Self {
+            conn: None,
+            _statistic_marker: PhantomData,
+        }
+    }
+
+    pub fn conn(self, value: Connection) -> Self {
+        Self {
+            conn: Some(value),
+            ..self
+        }
+    }
+
+    pub fn build(self) -> Result<SqliteRepository<Statistic>, PortfolioError> {
+        SqliteRepository::new(self.conn.ok_or(PortfolioError::BuilderIncomplete("conn"))?)
+            .map_err(PortfolioError::RepositoryInteraction)
+    }
+}
+
+impl<Statistic> Debug for SqliteRepositoryBuilder<Statistic>
+where
+    Statistic: PositionSummariser + Serialize + DeserializeOwned,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SqliteRepositoryBuilder")
+            .field("conn", &"Option<rusqlite::Connection>")
+            .field("_statistic_marker", &self._statistic_marker)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        statistic::summary::{
+            trading::{Config as StatisticConfig, TradingSummary},
+            Initialiser,
+        },
+        test_util::position,
+    };
+    use barter_integration::model::{
+        instrument::{kind::InstrumentKind, Instrument},
+        Exchange,
+    };
+    use chrono::TimeZone;
+
+    fn repository() -> SqliteRepository<TradingSummary> {
+        SqliteRepository::new(Connection::open_in_memory().unwrap()).unwrap()
+    }
+
+    fn time(secs: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(secs, 0).unwrap()
+    }
+
+    #[test]
+    fn migrate_applies_every_migration_once() {
+        let mut connection = Connection::open_in_memory().unwrap();
+        assert_eq!(
+            SqliteRepository::<TradingSummary>::schema_version(&connection).unwrap(),
+            0
+        );
+
+        SqliteRepository::<TradingSummary>::migrate(&mut connection).unwrap();
+        SqliteRepository::<TradingSummary>::migrate(&mut connection).unwrap();
+
+        assert_eq!(
+            SqliteRepository::<TradingSummary>::schema_version(&connection).unwrap(),
+            MIGRATIONS.len()
+        );
+    }
+
+    #[test]
+    fn sqlite_repository_persists_positions_in_order() {
+        let mut repository = repository();
+        let engine_id = Uuid::new_v4();
+        let market = Market::new("binance", ("eth", "usdt", InstrumentKind::Spot));
+
+        // Open Position is upserted & retrievable via it's Market
+        let mut open_position = position();
+        open_position.position_id = determine_position_id(
+            engine_id,
+            &Exchange::from("binance"),
+            &Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
+        );
+        repository.set_open_position(open_position.clone()).unwrap();
+        open_position.quantity = 2.0;
+        repository.set_open_position(open_position.clone()).unwrap();
+
+        // Open Position of the same Market for another engine_id is not returned
+        let mut other_engine_position = position();
+        other_engine_position.position_id = determine_position_id(
+            Uuid::new_v4(),
+            &Exchange::from("binance"),
+            &Instrument::from(("eth", "usdt", InstrumentKind::Spot)),
+        );
+        repository.set_open_position(other_engine_position).unwrap();
+
+        assert_eq!(
+            repository
+                .get_open_positions(engine_id, [market].iter())
+                .unwrap(),
+            vec![open_position.clone()]
+        );
+
+        // Removed Position is returned & no longer open
+        assert_eq!(
+            repository
+                .remove_position(&open_position.position_id)
+                .unwrap(),
+            Some(open_position.clone())
+        );
+        assert_eq!(
+            repository
+                .get_open_position(&open_position.position_id)
+                .unwrap(),
+            None
+        );
+
+        // Exited Positions are returned in the order they were exited, per engine_id
+        let exited_positions = (0..3)
+            .map(|index| {
+                let mut position = position();
+                position.realised_profit_loss = index as f64;
+                position
+            })
+            .collect::<Vec<_>>();
+        for exited in exited_positions.iter().cloned() {
+            repository.set_exited_position(engine_id, exited).unwrap();
+        }
+        repository
+            .set_exited_position(Uuid::new_v4(), position())
+            .unwrap();
+
+        assert_eq!(
+            repository.get_exited_positions(engine_id).unwrap(),
+            exited_positions
+        );
+    }
+
+    #[test]
+    fn sqlite_repository_persists_balances_and_statistics() {
+        let mut repository = repository();
+        let engine_id = Uuid::new_v4();
+
+        // No Balance set yet
+        assert!(matches!(
+            repository.get_balance(engine_id),
+            Err(RepositoryError::ExpectedDataNotPresentError)
+        ));
+
+        // Latest Balance is returned, with every update kept in the history
+        let balances = vec![
+            Balance::new(time(0), 1000.0, 1000.0),
+            Balance::new(time(1), 1100.0, 900.0),
+        ];
+        for balance in balances.iter() {
+            repository.set_balance(engine_id, *balance).unwrap();
+        }
+        assert_eq!(repository.get_balance(engine_id).unwrap(), balances[1]);
+        assert_eq!(repository.get_balance_history(engine_id).unwrap(), balances);
+
+        // No SymbolBalances set yet, while an empty SymbolBalances is returned once set
+        assert!(matches!(
+            repository.get_symbol_balances(engine_id),
+            Err(RepositoryError::ExpectedDataNotPresentError)
+        ));
+        repository
+            .set_symbol_balances(engine_id, SymbolBalances::new())
+            .unwrap();
+        assert_eq!(
+            repository.get_symbol_balances(engine_id).unwrap(),
+            SymbolBalances::new()
+        );
+
+        // SymbolBalances are replaced as a whole
+        repository
+            .set_symbol_balances(
+                engine_id,
+                SymbolBalances::from([
+                    (Symbol::from("usdt"), Balance::new(time(0), 1000.0, 1000.0)),
+                    (Symbol::from("btc"), Balance::new(time(0), 1.0, 1.0)),
+                ]),
+            )
+            .unwrap();
+        let symbol_balances =
+            SymbolBalances::from([(Symbol::from("usdt"), Balance::new(time(1), 900.0, 800.0))]);
+        repository
+            .set_symbol_balances(engine_id, symbol_balances.clone())
+            .unwrap();
+        assert_eq!(
+            repository.get_symbol_balances(engine_id).unwrap(),
+            symbol_balances
+        );
+
+        // Statistics are upserted per MarketId
+        let market_id = MarketId::from(&Market::new(
+            "binance",
+            ("eth", "usdt", InstrumentKind::Spot),
+        ));
+        let statistic = TradingSummary::init(StatisticConfig {
+            starting_equity: 1000.0,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        });
+        repository
+            .set_statistics(market_id.clone(), statistic)
+            .unwrap();
+        assert_eq!(repository.get_statistics(&market_id).unwrap(), statistic);
+    }
+}
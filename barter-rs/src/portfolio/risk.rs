@@ -1,5 +1,9 @@
+use crate::portfolio::{position::Position, Balance};
 use barter_execution::model::order_event::{OrderEvent, OrderType};
+use chrono::{DateTime, Duration, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use std::{collections::VecDeque, fmt::Debug};
+use thiserror::Error;
 
 /// Evaluates the risk associated with an [`OrderEvent`] to determine if it should be actioned. It
 /// can also amend the order (eg/ [`OrderType`]) to better fit the risk strategy required for
@@ -7,9 +11,112 @@ use serde::{Deserialize, Serialize};
 pub trait OrderEvaluator {
     const DEFAULT_ORDER_TYPE: OrderType;
 
-    /// May return an amended [`OrderEvent`] if the associated risk is appropriate. Returns `None`
-    /// if the risk is too high.
-    fn evaluate_order(&self, order: OrderEvent) -> Option<OrderEvent>;
+    /// May return an amended [`OrderEvent`] if the associated risk is appropriate given the
+    /// Portfolio [`RiskContext`]. Returns a [`RiskRejection`] detailing why if the risk is too
+    /// high.
+    #[allow(clippy::result_large_err)]
+    fn evaluate_order(
+        &mut self,
+        order: OrderEvent,
+        context: &RiskContext<'_>,
+    ) -> Result<OrderEvent, RiskRejection>;
+
+    /// Determines if the Portfolio equity should be recorded with [`Self::update_equity`] after a
+    /// market update at the input time.
+    fn is_equity_due(&self, _time: DateTime<Utc>) -> bool {
+        false
+    }
+
+    /// Records the Portfolio equity (see [`RiskContext::calculate_equity`]) after a market update,
+    /// timestamped with the market time.
+    fn update_equity(&mut self, _time: DateTime<Utc>, _equity: f64) {}
+}
+
+/// Portfolio state an [`OrderEvaluator`] evaluates an [`OrderEvent`] against.
+#[derive(Clone, PartialEq, Debug)]
+pub struct RiskContext<'a> {
+    /// Portfolio [`Balance`] valued in the reporting currency.
+    pub balance: Balance,
+    /// Open [`Position`] in the [`OrderEvent`] market, if any.
+    pub position: Option<&'a Position>,
+    /// Every open [`Position`] across the Portfolio markets.
+    pub open_positions: &'a [Position],
+    /// Price the Portfolio observed for the [`OrderEvent`] market before the latest
+    /// [`MarketEvent`](barter_data::event::MarketEvent), if any. Used as an independent reference
+    /// for the price the [`OrderEvent`] was generated from.
+    pub reference_price: Option<f64>,
+}
+
+impl<'a> RiskContext<'a> {
+    /// Calculate the Portfolio equity: total [`Balance`] plus the unrealised P&L of every open
+    /// [`Position`].
+    pub fn calculate_equity(&self) -> f64 {
+        self.balance.total
+            + self
+                .open_positions
+                .iter()
+                .map(|position| position.unrealised_profit_loss)
+                .sum::<f64>()
+    }
+}
+
+/// [`OrderEvent`] refused by an [`OrderEvaluator`], and the [`RiskViolation`] that caused it.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct RiskRejection {
+    pub order: OrderEvent,
+    pub violation: RiskViolation,
+}
+
+/// Reason an [`OrderEvent`] was refused by a [`RiskRule`].
+#[derive(Error, Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub enum RiskViolation {
+    #[error("market notional {notional} would exceed limit {limit}")]
+    MarketNotional { notional: f64, limit: f64 },
+
+    #[error("total notional {notional} would exceed limit {limit}")]
+    TotalNotional { notional: f64, limit: f64 },
+
+    #[error("{open} open Positions already at limit {limit}")]
+    OpenPositions { open: usize, limit: usize },
+
+    #[error("daily loss {loss} exceeds limit {limit}")]
+    DailyLoss { loss: f64, limit: f64 },
+
+    #[error("drawdown {drawdown} exceeded limit {limit} - kill switch engaged")]
+    Drawdown { drawdown: f64, limit: f64 },
+
+    #[error("{orders} orders in the last interval already at limit {limit}")]
+    OrderRate { orders: usize, limit: usize },
+
+    #[error(
+        "price {price} deviates {deviation} from reference price {reference_price}, exceeding {limit}"
+    )]
+    PriceBand {
+        price: f64,
+        reference_price: f64,
+        deviation: f64,
+        limit: f64,
+    },
+}
+
+/// Composable risk check that is run against every [`OrderEvent`] evaluated by a [`RiskChain`].
+pub trait RiskRule: Debug + Send {
+    /// Checks the [`OrderEvent`] against the Portfolio [`RiskContext`], returning the
+    /// [`RiskViolation`] if it breaches this rule.
+    fn check(&mut self, order: &OrderEvent, context: &RiskContext<'_>)
+        -> Result<(), RiskViolation>;
+
+    /// Records an [`OrderEvent`] that was approved by every [`RiskRule`] in the [`RiskChain`].
+    fn on_approved(&mut self, _: &OrderEvent) {}
+
+    /// Determines if the Portfolio equity should be recorded with [`Self::update_equity`] after a
+    /// market update at the input time.
+    fn is_equity_due(&self, _time: DateTime<Utc>) -> bool {
+        false
+    }
+
+    /// Records the Portfolio equity after a market update, timestamped with the market time.
+    fn update_equity(&mut self, _time: DateTime<Utc>, _equity: f64) {}
 }
 
 /// Default risk manager that implements [`OrderEvaluator`].
@@ -19,17 +126,628 @@ pub struct DefaultRisk {}
 impl OrderEvaluator for DefaultRisk {
     const DEFAULT_ORDER_TYPE: OrderType = OrderType::Market;
 
-    fn evaluate_order(&self, mut order: OrderEvent) -> Option<OrderEvent> {
-        if self.risk_too_high(&order) {
-            return None;
-        }
+    fn evaluate_order(
+        &mut self,
+        mut order: OrderEvent,
+        _: &RiskContext<'_>,
+    ) -> Result<OrderEvent, RiskRejection> {
         order.order_type = DefaultRisk::DEFAULT_ORDER_TYPE;
-        Some(order)
+        Ok(order)
     }
 }
 
-impl DefaultRisk {
-    fn risk_too_high(&self, _: &OrderEvent) -> bool {
-        false
+/// Risk manager that implements [`OrderEvaluator`] by running every [`OrderEvent`] through a
+/// chain of [`RiskRule`]s. The first [`RiskViolation`] rejects the [`OrderEvent`].
+///
+/// eg/ `RiskChain::new().rule(MaxOpenPositions::new(5)).rule(MaxDrawdown::new(0.2))`
+#[derive(Debug, Default)]
+pub struct RiskChain {
+    rules: Vec<Box<dyn RiskRule>>,
+}
+
+impl OrderEvaluator for RiskChain {
+    const DEFAULT_ORDER_TYPE: OrderType = OrderType::Market;
+
+    fn evaluate_order(
+        &mut self,
+        mut order: OrderEvent,
+        context: &RiskContext<'_>,
+    ) -> Result<OrderEvent, RiskRejection> {
+        if let Some(violation) = self
+            .rules
+            .iter_mut()
+            .find_map(|rule| rule.check(&order, context).err())
+        {
+            return Err(RiskRejection { order, violation });
+        }
+
+        self.rules
+            .iter_mut()
+            .for_each(|rule| rule.on_approved(&order));
+
+        order.order_type = RiskChain::DEFAULT_ORDER_TYPE;
+        Ok(order)
+    }
+
+    fn is_equity_due(&self, time: DateTime<Utc>) -> bool {
+        self.rules.iter().any(|rule| rule.is_equity_due(time))
+    }
+
+    fn update_equity(&mut self, time: DateTime<Utc>, equity: f64) {
+        self.rules
+            .iter_mut()
+            .for_each(|rule| rule.update_equity(time, equity));
+    }
+}
+
+impl RiskChain {
+    /// Construct a new [`RiskChain`] with no [`RiskRule`]s.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a [`RiskRule`] to the end of the chain.
+    pub fn rule<Rule>(mut self, rule: Rule) -> Self
+    where
+        Rule: RiskRule + 'static,
+    {
+        self.rules.push(Box::new(rule));
+        self
+    }
+}
+
+/// Calculate the notional value of an [`OrderEvent`] using it's [`MarketMeta`] close.
+///
+/// [`MarketMeta`]: barter_execution::fill::MarketMeta
+fn calculate_order_notional(order: &OrderEvent) -> f64 {
+    order.quantity.abs() * order.market_meta.close
+}
+
+/// Limits the notional value held in a single market. Only entry [`OrderEvent`]s are checked.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct MaxMarketNotional {
+    pub limit: f64,
+}
+
+impl RiskRule for MaxMarketNotional {
+    fn check(
+        &mut self,
+        order: &OrderEvent,
+        context: &RiskContext<'_>,
+    ) -> Result<(), RiskViolation> {
+        if !order.decision.is_entry() {
+            return Ok(());
+        }
+
+        let notional = context
+            .position
+            .map(|position| position.current_value_gross)
+            .unwrap_or(0.0)
+            + calculate_order_notional(order);
+
+        match notional > self.limit {
+            true => Err(RiskViolation::MarketNotional {
+                notional,
+                limit: self.limit,
+            }),
+            false => Ok(()),
+        }
+    }
+}
+
+/// Limits the notional value held across every market. Only entry [`OrderEvent`]s are checked.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct MaxTotalNotional {
+    pub limit: f64,
+}
+
+impl RiskRule for MaxTotalNotional {
+    fn check(
+        &mut self,
+        order: &OrderEvent,
+        context: &RiskContext<'_>,
+    ) -> Result<(), RiskViolation> {
+        if !order.decision.is_entry() {
+            return Ok(());
+        }
+
+        let notional = context
+            .open_positions
+            .iter()
+            .map(|position| position.current_value_gross)
+            .sum::<f64>()
+            + calculate_order_notional(order);
+
+        match notional > self.limit {
+            true => Err(RiskViolation::TotalNotional {
+                notional,
+                limit: self.limit,
+            }),
+            false => Ok(()),
+        }
+    }
+}
+
+/// Limits the number of open [`Position`]s. Only entry [`OrderEvent`]s for a market without an
+/// open [`Position`] are checked.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct MaxOpenPositions {
+    pub limit: usize,
+}
+
+impl RiskRule for MaxOpenPositions {
+    fn check(
+        &mut self,
+        order: &OrderEvent,
+        context: &RiskContext<'_>,
+    ) -> Result<(), RiskViolation> {
+        if !order.decision.is_entry() || context.position.is_some() {
+            return Ok(());
+        }
+
+        let open = context.open_positions.len();
+        match open >= self.limit {
+            true => Err(RiskViolation::OpenPositions {
+                open,
+                limit: self.limit,
+            }),
+            false => Ok(()),
+        }
+    }
+}
+
+/// Refuses entry [`OrderEvent`]s once the Portfolio equity has fallen by more than the limit
+/// since the start of the current UTC day. Resets every day.
+///
+/// The day start equity is the first equity recorded in the UTC day, from either a market update
+/// ([`RiskRule::update_equity`]) or an [`OrderEvent`] check, so losses made before the first
+/// order of the day are included.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct MaxDailyLoss {
+    /// Maximum loss in the reporting currency.
+    pub limit: f64,
+    day: Option<NaiveDate>,
+    day_start_equity: f64,
+}
+
+impl MaxDailyLoss {
+    /// Construct a new [`MaxDailyLoss`] with a limit denominated in the reporting currency.
+    pub fn new(limit: f64) -> Self {
+        Self {
+            limit,
+            day: None,
+            day_start_equity: 0.0,
+        }
+    }
+
+    /// Record the equity as the day start equity if the input time is in a new UTC day.
+    fn update_day(&mut self, time: DateTime<Utc>, equity: f64) {
+        let day = time.date_naive();
+        if self.day != Some(day) {
+            self.day = Some(day);
+            self.day_start_equity = equity;
+        }
+    }
+}
+
+impl RiskRule for MaxDailyLoss {
+    fn check(
+        &mut self,
+        order: &OrderEvent,
+        context: &RiskContext<'_>,
+    ) -> Result<(), RiskViolation> {
+        // Track the equity at the start of each day using the market time
+        let equity = context.calculate_equity();
+        self.update_day(order.market_meta.time, equity);
+
+        let loss = self.day_start_equity - equity;
+        match order.decision.is_entry() && loss > self.limit {
+            true => Err(RiskViolation::DailyLoss {
+                loss,
+                limit: self.limit,
+            }),
+            false => Ok(()),
+        }
+    }
+
+    fn is_equity_due(&self, time: DateTime<Utc>) -> bool {
+        self.day != Some(time.date_naive())
+    }
+
+    fn update_equity(&mut self, time: DateTime<Utc>, equity: f64) {
+        self.update_day(time, equity);
+    }
+}
+
+/// Kill switch that permanently refuses entry [`OrderEvent`]s once the Portfolio equity has
+/// drawn down from it's peak by more than the limit.
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct MaxDrawdown {
+    /// Maximum drawdown as a proportion of the peak equity (eg/ 0.2 for 20%).
+    pub limit: f64,
+    peak_equity: f64,
+    max_drawdown: f64,
+}
+
+impl MaxDrawdown {
+    /// Construct a new [`MaxDrawdown`] with a limit as a proportion of the peak equity.
+    pub fn new(limit: f64) -> Self {
+        Self {
+            limit,
+            peak_equity: 0.0,
+            max_drawdown: 0.0,
+        }
+    }
+
+    /// Determines if the drawdown limit has been breached, engaging the kill switch.
+    pub fn is_engaged(&self) -> bool {
+        self.max_drawdown > self.limit
+    }
+}
+
+impl RiskRule for MaxDrawdown {
+    fn check(
+        &mut self,
+        order: &OrderEvent,
+        context: &RiskContext<'_>,
+    ) -> Result<(), RiskViolation> {
+        let equity = context.calculate_equity();
+        self.peak_equity = self.peak_equity.max(equity);
+
+        if self.peak_equity > 0.0 {
+            let drawdown = (self.peak_equity - equity) / self.peak_equity;
+            self.max_drawdown = self.max_drawdown.max(drawdown);
+        }
+
+        match order.decision.is_entry() && self.is_engaged() {
+            true => Err(RiskViolation::Drawdown {
+                drawdown: self.max_drawdown,
+                limit: self.limit,
+            }),
+            false => Ok(()),
+        }
+    }
+}
+
+/// Limits the number of approved [`OrderEvent`]s within a rolling interval of market time.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct MaxOrderRate {
+    pub limit: usize,
+    #[serde(
+        deserialize_with = "crate::statistic::de_duration_from_secs",
+        serialize_with = "crate::statistic::se_duration_as_secs"
+    )]
+    pub interval: Duration,
+    approved: VecDeque<DateTime<Utc>>,
+}
+
+impl MaxOrderRate {
+    /// Construct a new [`MaxOrderRate`] allowing `limit` orders per rolling `interval`.
+    pub fn new(limit: usize, interval: Duration) -> Self {
+        Self {
+            limit,
+            interval,
+            approved: VecDeque::with_capacity(limit),
+        }
+    }
+}
+
+impl RiskRule for MaxOrderRate {
+    fn check(&mut self, order: &OrderEvent, _: &RiskContext<'_>) -> Result<(), RiskViolation> {
+        // Discard approved order times that have left the rolling interval
+        let interval_start = order.market_meta.time - self.interval;
+        while matches!(self.approved.front(), Some(time) if *time <= interval_start) {
+            self.approved.pop_front();
+        }
+
+        let orders = self.approved.len();
+        match orders >= self.limit {
+            true => Err(RiskViolation::OrderRate {
+                orders,
+                limit: self.limit,
+            }),
+            false => Ok(()),
+        }
+    }
+
+    fn on_approved(&mut self, order: &OrderEvent) {
+        self.approved.push_back(order.market_meta.time);
+    }
+}
+
+/// Refuses entry [`OrderEvent`]s with a [`MarketMeta`] close that deviates from the
+/// [`RiskContext`] reference price by more than the limit (eg/ stale or erroneous data). The
+/// reference price is observed before the market update the [`OrderEvent`] was generated from,
+/// so a single erroneous price cannot validate itself. Exits are never refused, so an open
+/// Position can always be closed during a fast market.
+///
+/// [`MarketMeta`]: barter_execution::fill::MarketMeta
+#[derive(Copy, Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct PriceBand {
+    /// Maximum deviation as a proportion of the last price (eg/ 0.05 for 5%).
+    pub limit: f64,
+}
+
+impl RiskRule for PriceBand {
+    fn check(
+        &mut self,
+        order: &OrderEvent,
+        context: &RiskContext<'_>,
+    ) -> Result<(), RiskViolation> {
+        if order.decision.is_exit() {
+            return Ok(());
+        }
+
+        let reference_price = match context.reference_price {
+            Some(reference_price) if reference_price > 0.0 => reference_price,
+            _ => return Ok(()),
+        };
+
+        let price = order.market_meta.close;
+        let deviation = (price - reference_price).abs() / reference_price;
+        match deviation > self.limit {
+            true => Err(RiskViolation::PriceBand {
+                price,
+                reference_price,
+                deviation,
+                limit: self.limit,
+            }),
+            false => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{order_event, position};
+    use barter_execution::fill::{Decision, MarketMeta};
+
+    fn context<'a>(
+        position: Option<&'a Position>,
+        open_positions: &'a [Position],
+        total: f64,
+    ) -> RiskContext<'a> {
+        RiskContext {
+            balance: Balance::new(Utc::now(), total, total),
+            position,
+            open_positions,
+            reference_price: Some(100.0),
+        }
+    }
+
+    fn order(decision: Decision, quantity: f64, close: f64, minutes: i64) -> OrderEvent {
+        OrderEvent {
+            decision,
+            quantity,
+            market_meta: MarketMeta {
+                close,
+                time: DateTime::<Utc>::MIN_UTC + Duration::days(1) + Duration::minutes(minutes),
+            },
+            ..order_event()
+        }
+    }
+
+    #[test]
+    fn test_risk_rules_check() {
+        struct TestCase {
+            rule: Box<dyn RiskRule>,
+            order: OrderEvent,
+            expected: Result<(), RiskViolation>,
+        }
+
+        // position() is a 1.0 quantity long with a current value of 100.0
+        let open = vec![position(), position()];
+
+        let tests = vec![
+            TestCase {
+                // TC0: market notional within limit
+                rule: Box::new(MaxMarketNotional { limit: 300.0 }),
+                order: order(Decision::Long, 2.0, 100.0, 0),
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC1: market notional including open Position exceeds limit
+                rule: Box::new(MaxMarketNotional { limit: 250.0 }),
+                order: order(Decision::Long, 2.0, 100.0, 0),
+                expected: Err(RiskViolation::MarketNotional {
+                    notional: 300.0,
+                    limit: 250.0,
+                }),
+            },
+            TestCase {
+                // TC2: exits are not limited by notional
+                rule: Box::new(MaxMarketNotional { limit: 0.0 }),
+                order: order(Decision::CloseLong, -1.0, 100.0, 0),
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC3: total notional across open Positions exceeds limit
+                rule: Box::new(MaxTotalNotional { limit: 250.0 }),
+                order: order(Decision::Short, -1.0, 100.0, 0),
+                expected: Err(RiskViolation::TotalNotional {
+                    notional: 300.0,
+                    limit: 250.0,
+                }),
+            },
+            TestCase {
+                // TC4: price within band
+                rule: Box::new(PriceBand { limit: 0.05 }),
+                order: order(Decision::Long, 1.0, 104.0, 0),
+                expected: Ok(()),
+            },
+            TestCase {
+                // TC5: price outside band
+                rule: Box::new(PriceBand { limit: 0.05 }),
+                order: order(Decision::Short, -1.0, 90.0, 0),
+                expected: Err(RiskViolation::PriceBand {
+                    price: 90.0,
+                    reference_price: 100.0,
+                    deviation: 0.1,
+                    limit: 0.05,
+                }),
+            },
+            TestCase {
+                // TC6: exits are not limited by price band
+                rule: Box::new(PriceBand { limit: 0.05 }),
+                order: order(Decision::CloseLong, -1.0, 90.0, 0),
+                expected: Ok(()),
+            },
+        ];
+
+        for (index, mut test) in tests.into_iter().enumerate() {
+            let context = context(open.first(), &open, 1000.0);
+            let actual = test.rule.check(&test.order, &context);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_max_open_positions_only_checks_new_markets() {
+        let open = vec![position(), position()];
+        let mut rule = MaxOpenPositions { limit: 2 };
+
+        // Entry in a market with an open Position
+        let actual = rule.check(
+            &order(Decision::Long, 1.0, 100.0, 0),
+            &context(open.first(), &open, 1000.0),
+        );
+        assert_eq!(actual, Ok(()));
+
+        // Entry in a new market
+        let actual = rule.check(
+            &order(Decision::Long, 1.0, 100.0, 0),
+            &context(None, &open, 1000.0),
+        );
+        assert_eq!(
+            actual,
+            Err(RiskViolation::OpenPositions { open: 2, limit: 2 })
+        );
+    }
+
+    #[test]
+    fn test_max_drawdown_kill_switch_stays_engaged() {
+        let mut rule = MaxDrawdown::new(0.2);
+        let entry = order(Decision::Long, 1.0, 100.0, 0);
+
+        assert_eq!(rule.check(&entry, &context(None, &[], 1000.0)), Ok(()));
+        assert_eq!(
+            rule.check(&entry, &context(None, &[], 750.0)),
+            Err(RiskViolation::Drawdown {
+                drawdown: 0.25,
+                limit: 0.2
+            })
+        );
+
+        // Kill switch remains engaged after equity recovers, but exits are allowed
+        assert!(rule.check(&entry, &context(None, &[], 1000.0)).is_err());
+        assert_eq!(
+            rule.check(
+                &order(Decision::CloseLong, -1.0, 100.0, 0),
+                &context(None, &[], 1000.0)
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_max_daily_loss_resets_each_day() {
+        let mut rule = MaxDailyLoss::new(100.0);
+
+        assert_eq!(
+            rule.check(
+                &order(Decision::Long, 1.0, 100.0, 0),
+                &context(None, &[], 1000.0)
+            ),
+            Ok(())
+        );
+        assert_eq!(
+            rule.check(
+                &order(Decision::Long, 1.0, 100.0, 60),
+                &context(None, &[], 850.0)
+            ),
+            Err(RiskViolation::DailyLoss {
+                loss: 150.0,
+                limit: 100.0
+            })
+        );
+
+        // Next day measures the loss from the new day start equity
+        assert_eq!(
+            rule.check(
+                &order(Decision::Long, 1.0, 100.0, 24 * 60),
+                &context(None, &[], 850.0)
+            ),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_max_daily_loss_anchors_day_start_equity_at_day_rollover() {
+        let mut chain = RiskChain::new().rule(MaxDailyLoss::new(100.0));
+        let day_start = DateTime::<Utc>::MIN_UTC + Duration::days(1);
+
+        // Equity recorded at the day rollover, before any OrderEvent is checked
+        assert!(chain.is_equity_due(day_start));
+        chain.update_equity(day_start, 1000.0);
+        assert!(!chain.is_equity_due(day_start + Duration::minutes(30)));
+        chain.update_equity(day_start + Duration::minutes(30), 900.0);
+
+        // First OrderEvent of the day measures the loss from the day rollover equity
+        let rejection = chain
+            .evaluate_order(
+                order(Decision::Long, 1.0, 100.0, 60),
+                &context(None, &[], 850.0),
+            )
+            .unwrap_err();
+        assert_eq!(
+            rejection.violation,
+            RiskViolation::DailyLoss {
+                loss: 150.0,
+                limit: 100.0
+            }
+        );
+    }
+
+    #[test]
+    fn test_risk_chain_rejects_with_first_violation_and_limits_order_rate() {
+        let mut chain = RiskChain::new()
+            .rule(MaxOrderRate::new(2, Duration::minutes(10)))
+            .rule(MaxMarketNotional { limit: 150.0 });
+        let context = context(None, &[], 1000.0);
+
+        // Rejected orders do not count towards the order rate
+        let rejection = chain
+            .evaluate_order(order(Decision::Long, 2.0, 100.0, 0), &context)
+            .unwrap_err();
+        assert_eq!(
+            rejection.violation,
+            RiskViolation::MarketNotional {
+                notional: 200.0,
+                limit: 150.0
+            }
+        );
+
+        assert!(chain
+            .evaluate_order(order(Decision::Long, 1.0, 100.0, 1), &context)
+            .is_ok());
+        assert!(chain
+            .evaluate_order(order(Decision::Long, 1.0, 100.0, 2), &context)
+            .is_ok());
+        assert_eq!(
+            chain
+                .evaluate_order(order(Decision::Long, 1.0, 100.0, 3), &context)
+                .unwrap_err()
+                .violation,
+            RiskViolation::OrderRate {
+                orders: 2,
+                limit: 2
+            }
+        );
+
+        // Orders leave the rolling interval
+        assert!(chain
+            .evaluate_order(order(Decision::Long, 1.0, 100.0, 11), &context)
+            .is_ok());
     }
 }
@@ -0,0 +1,257 @@
+use crate::portfolio::{Balance, SymbolBalances};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_integration::model::instrument::symbol::Symbol;
+use chrono::{DateTime, Utc};
+use rust_decimal::prelude::ToPrimitive;
+use std::collections::{BTreeSet, HashMap};
+
+/// Latest price of every base/quote [`Symbol`] pair a Portfolio has observed. Used to convert an
+/// amount of one [`Symbol`] into another (eg/ valuing an ETH balance in USDT).
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct PriceTable {
+    prices: HashMap<(Symbol, Symbol), f64>,
+    previous_prices: HashMap<(Symbol, Symbol), f64>,
+}
+
+impl PriceTable {
+    /// Updates the price of the input [`MarketEvent`] base/quote pair, returning the new price
+    /// if the [`DataKind`] contained one. The price it replaces is kept as the previous price.
+    pub fn update_from_market(&mut self, market: &MarketEvent<DataKind>) -> Option<f64> {
+        let price = market_price(market)?;
+
+        let pair = (
+            market.instrument.base.clone(),
+            market.instrument.quote.clone(),
+        );
+        if let Some(previous) = self.prices.insert(pair.clone(), price) {
+            self.previous_prices.insert(pair, previous);
+        }
+
+        Some(price)
+    }
+
+    /// Returns the price of one unit of the base [`Symbol`] denominated in the quote [`Symbol`]
+    /// observed before the latest [`MarketEvent`] of the pair, if any.
+    pub fn previous_price(&self, base: &Symbol, quote: &Symbol) -> Option<f64> {
+        self.previous_prices
+            .get(&(base.clone(), quote.clone()))
+            .copied()
+    }
+
+    /// Sets the latest price of one unit of the base [`Symbol`], denominated in the quote
+    /// [`Symbol`].
+    pub fn set_price(&mut self, base: Symbol, quote: Symbol, price: f64) {
+        self.prices.insert((base, quote), price);
+    }
+
+    /// Returns the price of one unit of the base [`Symbol`] denominated in the quote [`Symbol`].
+    /// Uses the inverse pair if only that has been observed.
+    pub fn price(&self, base: &Symbol, quote: &Symbol) -> Option<f64> {
+        if base == quote {
+            return Some(1.0);
+        }
+
+        if let Some(price) = self.prices.get(&(base.clone(), quote.clone())) {
+            return Some(*price);
+        }
+
+        self.prices
+            .get(&(quote.clone(), base.clone()))
+            .filter(|inverse_price| **inverse_price != 0.0)
+            .map(|inverse_price| 1.0 / inverse_price)
+    }
+
+    /// Converts an amount of the `from` [`Symbol`] into the `to` [`Symbol`]. If no pair between
+    /// them has been observed, converts via a single intermediate [`Symbol`]
+    /// (eg/ ETH -> BTC -> USDT).
+    ///
+    /// Intermediate [`Symbol`]s are tried in sorted order, so the same prices always convert via
+    /// the same path.
+    pub fn convert(&self, amount: f64, from: &Symbol, to: &Symbol) -> Option<f64> {
+        if let Some(price) = self.price(from, to) {
+            return Some(amount * price);
+        }
+
+        self.prices
+            .keys()
+            .flat_map(|(base, quote)| [base, quote])
+            .filter(|via| *via != from && *via != to)
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .find_map(|via| Some(amount * self.price(from, via)? * self.price(via, to)?))
+    }
+
+    /// Values every [`SymbolBalances`] entry in the reporting currency [`Symbol`], returning the
+    /// aggregate [`Balance`] in a [`Valuation`]. Symbols without a conversion path to the
+    /// reporting currency are excluded from the aggregate [`Balance`], and returned as unpriced.
+    pub fn value_balances(
+        &self,
+        balances: &SymbolBalances,
+        reporting_currency: &Symbol,
+        time: DateTime<Utc>,
+    ) -> Valuation {
+        let mut valuation = Valuation {
+            balance: Balance::new(time, 0.0, 0.0),
+            unpriced: Vec::new(),
+        };
+
+        for (symbol, balance) in balances {
+            match (
+                self.convert(balance.total, symbol, reporting_currency),
+                self.convert(balance.available, symbol, reporting_currency),
+            ) {
+                (Some(total), Some(available)) => {
+                    valuation.balance.total += total;
+                    valuation.balance.available += available;
+                }
+                _ => valuation.unpriced.push(symbol.clone()),
+            }
+        }
+
+        valuation.unpriced.sort();
+        valuation
+    }
+}
+
+/// [`SymbolBalances`] valued in a reporting currency [`Symbol`] by a [`PriceTable`].
+#[derive(Clone, PartialEq, Debug)]
+pub struct Valuation {
+    /// Aggregate [`Balance`] of every priced [`Symbol`].
+    pub balance: Balance,
+    /// Sorted [`Symbol`]s without a conversion path to the reporting currency, which are
+    /// excluded from the aggregate [`Balance`].
+    pub unpriced: Vec<Symbol>,
+}
+
+impl Valuation {
+    /// Determines if every [`Symbol`] [`Balance`] was included in the aggregate [`Balance`].
+    pub fn is_complete(&self) -> bool {
+        self.unpriced.is_empty()
+    }
+}
+
+/// Returns the price contained in the [`MarketEvent`] [`DataKind`], if any.
+pub fn market_price(market: &MarketEvent<DataKind>) -> Option<f64> {
+    match &market.kind {
+        DataKind::Trade(trade) => trade.price,
+        DataKind::Candle(candle) => candle.close,
+        DataKind::OrderBookL1(book_l1) => book_l1.volume_weighed_mid_price(),
+        DataKind::OrderBook(book) => book.volume_weighed_mid_price()?,
+        DataKind::Liquidation(_) => return None,
+        DataKind::IntentOrder(_) => return None,
+    }
+    .to_f64()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn price_table() -> PriceTable {
+        let mut prices = PriceTable::default();
+        prices.set_price(Symbol::from("btc"), Symbol::from("usdt"), 20000.0);
+        prices.set_price(Symbol::from("eth"), Symbol::from("btc"), 0.05);
+        prices
+    }
+
+    #[test]
+    fn test_price_table_convert() {
+        struct TestCase {
+            amount: f64,
+            from: Symbol,
+            to: Symbol,
+            expected: Option<f64>,
+        }
+
+        let prices = price_table();
+
+        let tests = vec![
+            TestCase {
+                // TC0: identical Symbols convert 1:1
+                amount: 10.0,
+                from: Symbol::from("usdt"),
+                to: Symbol::from("usdt"),
+                expected: Some(10.0),
+            },
+            TestCase {
+                // TC1: direct base/quote pair
+                amount: 2.0,
+                from: Symbol::from("btc"),
+                to: Symbol::from("usdt"),
+                expected: Some(40000.0),
+            },
+            TestCase {
+                // TC2: inverse of an observed base/quote pair
+                amount: 40000.0,
+                from: Symbol::from("usdt"),
+                to: Symbol::from("btc"),
+                expected: Some(2.0),
+            },
+            TestCase {
+                // TC3: via an intermediate Symbol
+                amount: 10.0,
+                from: Symbol::from("eth"),
+                to: Symbol::from("usdt"),
+                expected: Some(10000.0),
+            },
+            TestCase {
+                // TC4: no conversion path
+                amount: 10.0,
+                from: Symbol::from("sol"),
+                to: Symbol::from("usdt"),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = prices.convert(test.amount, &test.from, &test.to);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_price_table_value_balances() {
+        let prices = price_table();
+        let time = Utc::now();
+
+        let balances = SymbolBalances::from([
+            (Symbol::from("usdt"), Balance::new(time, 1000.0, 500.0)),
+            (Symbol::from("btc"), Balance::new(time, 0.5, 0.25)),
+            (Symbol::from("eth"), Balance::new(time, 10.0, 10.0)),
+            (Symbol::from("sol"), Balance::new(time, 99.0, 99.0)),
+        ]);
+
+        let actual = prices.value_balances(&balances, &Symbol::from("usdt"), time);
+
+        // usdt + btc * 20000 + eth * 0.05 * 20000, excluding sol that cannot be valued
+        assert_eq!(
+            actual,
+            Valuation {
+                balance: Balance::new(time, 21000.0, 15500.0),
+                unpriced: vec![Symbol::from("sol")],
+            }
+        );
+        assert!(!actual.is_complete());
+    }
+
+    #[test]
+    fn test_price_table_convert_via_intermediate_is_deterministic() {
+        // eth converts to usdt via both btc & usdc, which imply different prices
+        let price_table = || {
+            let mut prices = PriceTable::default();
+            prices.set_price(Symbol::from("eth"), Symbol::from("usdc"), 1000.0);
+            prices.set_price(Symbol::from("usdc"), Symbol::from("usdt"), 1.0);
+            prices.set_price(Symbol::from("eth"), Symbol::from("btc"), 0.05);
+            prices.set_price(Symbol::from("btc"), Symbol::from("usdt"), 21000.0);
+            prices
+        };
+
+        // Intermediate Symbols are tried in sorted order, so btc is always used before usdc,
+        // regardless of the HashMap iteration order of each PriceTable
+        for _ in 0..10 {
+            let prices = price_table();
+            let actual = prices.convert(1.0, &Symbol::from("eth"), &Symbol::from("usdt"));
+            assert_eq!(actual, Some(1050.0));
+        }
+    }
+}
@@ -9,3 +9,13 @@ pub enum StatisticError {
     #[error("Failed to build struct due to insufficient metrics provided")]
     BuilderNoMetricsProvided,
 }
+
+/// All errors generated when exporting a [`BacktestReport`](super::report::BacktestReport).
+#[derive(Error, Debug)]
+pub enum ReportError {
+    #[error("Failed to serialise report to JSON: {0}")]
+    JsonSerialise(#[from] serde_json::Error),
+
+    #[error("Failed to write report file: {0}")]
+    Io(#[from] std::io::Error),
+}
@@ -8,7 +8,8 @@ use serde::{Deserialize, Serialize};
 pub mod drawdown;
 pub mod ratio;
 
-/// Total equity at a point in time - equates to [`Balance.total`](Balance).
+/// Total equity at a point in time, valued in the Portfolio reporting currency - equates to
+/// [`Balance.total`](Balance).
 #[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct EquityPoint {
     pub time: DateTime<Utc>,
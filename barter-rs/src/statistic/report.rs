@@ -0,0 +1,625 @@
+use crate::{
+    portfolio::position::Position,
+    statistic::{
+        error::ReportError,
+        metric::{drawdown::Drawdown, EquityPoint},
+        summary::{
+            equity::{EquityCurve, TimeSeriesMetrics},
+            TableBuilder,
+        },
+    },
+};
+use chrono::{DateTime, Duration, Utc};
+use prettytable::Row;
+use serde::Serialize;
+use std::{fmt::Write as _, fs, path::Path};
+use uuid::Uuid;
+
+/// Statistics summarising the performance of one [`Market`](barter_integration::model::Market),
+/// or of every [`Market`](barter_integration::model::Market) traded (eg/ "Total").
+#[derive(Clone, PartialEq, Debug, Serialize)]
+pub struct StatisticReport<Statistic> {
+    pub id: String,
+    pub statistic: Statistic,
+}
+
+/// Exportable report of a trading session (eg/ a backtest). Serialises to JSON & CSV, and renders
+/// a self-contained HTML tearsheet with equity & drawdown charts.
+#[derive(Clone, PartialEq, Debug, Serialize)]
+pub struct BacktestReport<Statistic> {
+    pub engine_id: Uuid,
+    /// Statistics per [`Market`](barter_integration::model::Market) traded, followed by the
+    /// statistics across every [`Market`](barter_integration::model::Market).
+    pub statistics: Vec<StatisticReport<Statistic>>,
+    /// Annualised daily & hourly return metrics, if a mark-to-market [`EquityCurve`] was recorded.
+    pub time_series: Vec<TimeSeriesMetrics>,
+    /// Every exited [`Position`] of the trading session.
+    pub positions: Vec<Position>,
+    /// Portfolio equity over the trading session.
+    pub equity_curve: Vec<EquityPoint>,
+    /// Every drawdown period of the equity curve, including any drawdown still in progress.
+    pub drawdowns: Vec<Drawdown>,
+}
+
+impl<Statistic> BacktestReport<Statistic>
+where
+    Statistic: TableBuilder + Serialize,
+{
+    /// Construct a new [`BacktestReport`]. Uses the mark-to-market [`EquityCurve`] if one was
+    /// recorded, otherwise the equity curve is derived from the exit [`Balance`](crate::portfolio::Balance)
+    /// of every exited [`Position`].
+    pub fn new<Statistics>(
+        engine_id: Uuid,
+        statistics: Statistics,
+        mut positions: Vec<Position>,
+        equity_curve: Option<EquityCurve>,
+    ) -> Self
+    where
+        Statistics: IntoIterator<Item = (String, Statistic)>,
+    {
+        positions.sort_by_key(|position| position.meta.update_time);
+
+        let (equity_curve, time_series) = match equity_curve {
+            Some(curve) => {
+                let time_series = vec![
+                    curve.metrics(Duration::days(1)),
+                    curve.metrics(Duration::hours(1)),
+                ];
+                (curve.points, time_series)
+            }
+            None => (equity_curve_from_positions(&positions), Vec::new()),
+        };
+
+        Self {
+            engine_id,
+            statistics: statistics
+                .into_iter()
+                .map(|(id, statistic)| StatisticReport { id, statistic })
+                .collect(),
+            time_series,
+            positions,
+            drawdowns: calculate_drawdowns(&equity_curve),
+            equity_curve,
+        }
+    }
+
+    /// Serialise the [`BacktestReport`] into pretty printed JSON.
+    pub fn to_json(&self) -> Result<String, ReportError> {
+        serde_json::to_string_pretty(self).map_err(ReportError::from)
+    }
+
+    /// Render the statistics of every [`StatisticReport`] as CSV, using the [`TableBuilder`]
+    /// titles as the header.
+    pub fn statistics_csv(&self) -> String {
+        let mut csv = String::new();
+
+        if let Some(first) = self.statistics.first() {
+            write_csv_record(
+                &mut csv,
+                std::iter::once("id".to_owned()).chain(cells(&first.statistic.titles())),
+            );
+        }
+
+        for report in &self.statistics {
+            write_csv_record(
+                &mut csv,
+                std::iter::once(report.id.clone()).chain(cells(&report.statistic.row())),
+            );
+        }
+
+        csv
+    }
+
+    /// Render every exited [`Position`] as CSV.
+    pub fn positions_csv(&self) -> String {
+        let mut csv = String::new();
+        write_csv_record(
+            &mut csv,
+            [
+                "position_id",
+                "exchange",
+                "instrument",
+                "side",
+                "quantity",
+                "enter_time",
+                "exit_time",
+                "enter_avg_price_gross",
+                "exit_avg_price_gross",
+                "enter_fees_total",
+                "exit_fees_total",
+                "realised_profit_loss",
+            ]
+            .map(String::from),
+        );
+
+        for position in &self.positions {
+            write_csv_record(
+                &mut csv,
+                [
+                    position.position_id.clone(),
+                    position.exchange.to_string(),
+                    position.instrument.to_string(),
+                    position.side.to_string(),
+                    position.quantity.to_string(),
+                    position.meta.enter_time.to_rfc3339(),
+                    position
+                        .meta
+                        .exit_balance
+                        .map(|balance| balance.time.to_rfc3339())
+                        .unwrap_or_default(),
+                    position.enter_avg_price_gross.to_string(),
+                    position.exit_avg_price_gross.to_string(),
+                    position.enter_fees_total.to_string(),
+                    position.exit_fees_total.to_string(),
+                    position.realised_profit_loss.to_string(),
+                ],
+            );
+        }
+
+        csv
+    }
+
+    /// Render the equity curve as CSV, including the drawdown from the running equity peak.
+    pub fn equity_curve_csv(&self) -> String {
+        let mut csv = String::new();
+        write_csv_record(&mut csv, ["time", "equity", "drawdown"].map(String::from));
+
+        for (point, drawdown) in self.equity_curve.iter().zip(underwater(&self.equity_curve)) {
+            write_csv_record(
+                &mut csv,
+                [
+                    point.time.to_rfc3339(),
+                    point.total.to_string(),
+                    drawdown.to_string(),
+                ],
+            );
+        }
+
+        csv
+    }
+
+    /// Render every drawdown period as CSV.
+    pub fn drawdowns_csv(&self) -> String {
+        let mut csv = String::new();
+        write_csv_record(
+            &mut csv,
+            ["start_time", "duration_secs", "peak", "trough", "drawdown"].map(String::from),
+        );
+
+        for drawdown in &self.drawdowns {
+            write_csv_record(
+                &mut csv,
+                [
+                    drawdown.start_time.to_rfc3339(),
+                    drawdown.duration.num_seconds().to_string(),
+                    drawdown.equity_range.high.to_string(),
+                    drawdown.equity_range.low.to_string(),
+                    drawdown.drawdown.to_string(),
+                ],
+            );
+        }
+
+        csv
+    }
+
+    /// Render a self-contained HTML tearsheet with statistics tables, equity & drawdown charts,
+    /// and the exited [`Position`]s. Charts are inline SVG, so no external assets are required.
+    pub fn to_html(&self) -> String {
+        let mut html = String::new();
+        let _ = write!(
+            html,
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n\
+             <title>Backtest Report {engine_id}</title>\n<style>{STYLE}</style>\n</head>\n<body>\n\
+             <h1>Backtest Report</h1>\n<p>Engine: {engine_id}</p>\n",
+            engine_id = self.engine_id,
+        );
+
+        // Statistics per Market & across every Market
+        if let Some(first) = self.statistics.first() {
+            html.push_str("<h2>Statistics</h2>\n");
+            write_html_table(
+                &mut html,
+                std::iter::once(String::new()).chain(cells(&first.statistic.titles())),
+                self.statistics.iter().map(|report| {
+                    std::iter::once(report.id.clone())
+                        .chain(cells(&report.statistic.row()))
+                        .collect()
+                }),
+            );
+        }
+
+        // Time-based return metrics of the mark-to-market equity curve
+        if let Some(first) = self.time_series.first() {
+            html.push_str("<h2>Time Series Metrics</h2>\n");
+            write_html_table(
+                &mut html,
+                std::iter::once("Period".to_owned()).chain(cells(&first.titles())),
+                self.time_series.iter().map(|metrics| {
+                    std::iter::once(format_period(metrics.period))
+                        .chain(cells(&metrics.row()))
+                        .collect()
+                }),
+            );
+        }
+
+        // Equity & drawdown charts
+        html.push_str("<h2>Equity</h2>\n");
+        html.push_str(&svg_line_chart(
+            self.equity_curve
+                .iter()
+                .map(|point| (point.time, point.total)),
+            "#2b6cb0",
+        ));
+        html.push_str("<h2>Drawdown</h2>\n");
+        html.push_str(&svg_line_chart(
+            self.equity_curve
+                .iter()
+                .zip(underwater(&self.equity_curve))
+                .map(|(point, drawdown)| (point.time, drawdown)),
+            "#c53030",
+        ));
+
+        // Exited Positions
+        html.push_str("<h2>Positions</h2>\n");
+        write_html_table(
+            &mut html,
+            [
+                "Exchange",
+                "Instrument",
+                "Side",
+                "Quantity",
+                "Enter Time",
+                "Exit Time",
+                "Realised PnL",
+            ]
+            .map(String::from),
+            self.positions.iter().map(|position| {
+                vec![
+                    position.exchange.to_string(),
+                    position.instrument.to_string(),
+                    position.side.to_string(),
+                    format!("{:.6}", position.quantity),
+                    position.meta.enter_time.to_rfc3339(),
+                    position
+                        .meta
+                        .exit_balance
+                        .map(|balance| balance.time.to_rfc3339())
+                        .unwrap_or_default(),
+                    format!("{:.3}", position.realised_profit_loss),
+                ]
+            }),
+        );
+
+        html.push_str("</body>\n</html>\n");
+        html
+    }
+
+    /// Write the [`BacktestReport`] into the input directory as `report.json`, `statistics.csv`,
+    /// `positions.csv`, `equity_curve.csv`, `drawdowns.csv` & `tearsheet.html`. Creates the
+    /// directory if it does not exist.
+    pub fn write<P>(&self, directory: P) -> Result<(), ReportError>
+    where
+        P: AsRef<Path>,
+    {
+        let directory = directory.as_ref();
+        fs::create_dir_all(directory)?;
+
+        fs::write(directory.join("report.json"), self.to_json()?)?;
+        fs::write(directory.join("statistics.csv"), self.statistics_csv())?;
+        fs::write(directory.join("positions.csv"), self.positions_csv())?;
+        fs::write(directory.join("equity_curve.csv"), self.equity_curve_csv())?;
+        fs::write(directory.join("drawdowns.csv"), self.drawdowns_csv())?;
+        fs::write(directory.join("tearsheet.html"), self.to_html())?;
+
+        Ok(())
+    }
+}
+
+/// Derive an equity curve from the exit [`Balance`](crate::portfolio::Balance) of every exited
+/// [`Position`], ordered by exit time.
+pub fn equity_curve_from_positions(positions: &[Position]) -> Vec<EquityPoint> {
+    let mut equity_curve = positions
+        .iter()
+        .filter_map(|position| position.meta.exit_balance.map(EquityPoint::from))
+        .collect::<Vec<_>>();
+
+    equity_curve.sort_by_key(|point| point.time);
+    equity_curve
+}
+
+/// Calculate every [`Drawdown`] period of the equity curve, including any drawdown still in
+/// progress at the final [`EquityPoint`].
+pub fn calculate_drawdowns(equity_curve: &[EquityPoint]) -> Vec<Drawdown> {
+    let Some(first) = equity_curve.first() else {
+        return Vec::new();
+    };
+
+    let mut current = Drawdown::init(first.total);
+    let mut drawdowns = equity_curve
+        .iter()
+        .skip(1)
+        .filter_map(|point| current.update(*point))
+        .collect::<Vec<_>>();
+
+    if current.drawdown != 0.0 {
+        drawdowns.push(current);
+    }
+
+    drawdowns
+}
+
+/// Calculate the drawdown of every [`EquityPoint`] from the running equity peak (eg/ -0.1).
+fn underwater(equity_curve: &[EquityPoint]) -> Vec<f64> {
+    equity_curve
+        .iter()
+        .scan(f64::MIN, |peak, point| {
+            *peak = peak.max(point.total);
+            Some(match *peak == 0.0 {
+                true => 0.0,
+                false => (point.total - *peak) / *peak,
+            })
+        })
+        .collect()
+}
+
+fn cells(row: &Row) -> impl Iterator<Item = String> + '_ {
+    row.iter().map(|cell| cell.get_content())
+}
+
+fn format_period(period: Duration) -> String {
+    match period.num_days() {
+        0 => format!("{}h", period.num_hours()),
+        days => format!("{days}d"),
+    }
+}
+
+/// Append a CSV record, quoting any field containing a delimiter, quote or newline.
+fn write_csv_record<Fields>(csv: &mut String, fields: Fields)
+where
+    Fields: IntoIterator<Item = String>,
+{
+    let record = fields
+        .into_iter()
+        .map(|field| match field.contains([',', '"', '\n', '\r']) {
+            true => format!("\"{}\"", field.replace('"', "\"\"")),
+            false => field,
+        })
+        .collect::<Vec<_>>()
+        .join(",");
+
+    csv.push_str(&record);
+    csv.push('\n');
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn write_html_table<Titles, Rows>(html: &mut String, titles: Titles, rows: Rows)
+where
+    Titles: IntoIterator<Item = String>,
+    Rows: IntoIterator<Item = Vec<String>>,
+{
+    html.push_str("<table>\n<tr>");
+    for title in titles {
+        let _ = write!(html, "<th>{}</th>", escape_html(&title));
+    }
+    html.push_str("</tr>\n");
+
+    for row in rows {
+        html.push_str("<tr>");
+        for cell in row {
+            let _ = write!(html, "<td>{}</td>", escape_html(&cell));
+        }
+        html.push_str("</tr>\n");
+    }
+    html.push_str("</table>\n");
+}
+
+const STYLE: &str = "body{font-family:sans-serif;margin:2em;color:#1a202c}\
+    table{border-collapse:collapse;margin-bottom:1em;font-size:0.85em}\
+    th,td{border:1px solid #cbd5e0;padding:4px 8px;text-align:right}\
+    th{background:#edf2f7}svg{background:#f7fafc;border:1px solid #cbd5e0}";
+
+const CHART_WIDTH: f64 = 900.0;
+const CHART_HEIGHT: f64 = 250.0;
+const CHART_PADDING: f64 = 40.0;
+
+/// Render the input time series as an inline SVG line chart, labelled with the value range &
+/// time range.
+fn svg_line_chart<Series>(series: Series, colour: &str) -> String
+where
+    Series: IntoIterator<Item = (DateTime<Utc>, f64)>,
+{
+    let series = series.into_iter().collect::<Vec<_>>();
+    let (Some(first), Some(last)) = (series.first(), series.last()) else {
+        return "<p>No data</p>\n".to_owned();
+    };
+
+    let (min, max) = series
+        .iter()
+        .fold((f64::MAX, f64::MIN), |(min, max), (_, value)| {
+            (min.min(*value), max.max(*value))
+        });
+    let start = first.0.timestamp_millis() as f64;
+    let time_range = (last.0.timestamp_millis() as f64 - start).max(1.0);
+    let value_range = match max - min == 0.0 {
+        true => 1.0,
+        false => max - min,
+    };
+
+    let plot_width = CHART_WIDTH - 2.0 * CHART_PADDING;
+    let plot_height = CHART_HEIGHT - 2.0 * CHART_PADDING;
+    let points = series
+        .iter()
+        .map(|(time, value)| {
+            let x =
+                CHART_PADDING + (time.timestamp_millis() as f64 - start) / time_range * plot_width;
+            let y = CHART_PADDING + (max - value) / value_range * plot_height;
+            format!("{x:.1},{y:.1}")
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+
+    format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{CHART_WIDTH}\" height=\"{CHART_HEIGHT}\">\n\
+         <polyline fill=\"none\" stroke=\"{colour}\" stroke-width=\"1.5\" points=\"{points}\"/>\n\
+         <text x=\"4\" y=\"{top:.1}\" font-size=\"11\">{max:.3}</text>\n\
+         <text x=\"4\" y=\"{bottom:.1}\" font-size=\"11\">{min:.3}</text>\n\
+         <text x=\"{CHART_PADDING}\" y=\"{label:.1}\" font-size=\"11\">{start_time}</text>\n\
+         <text x=\"{end:.1}\" y=\"{label:.1}\" font-size=\"11\" text-anchor=\"end\">{end_time}</text>\n\
+         </svg>\n",
+        top = CHART_PADDING - 4.0,
+        bottom = CHART_HEIGHT - CHART_PADDING + 12.0,
+        label = CHART_HEIGHT - 8.0,
+        end = CHART_WIDTH - CHART_PADDING,
+        start_time = first.0.format("%Y-%m-%d %H:%M"),
+        end_time = last.0.format("%Y-%m-%d %H:%M"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        portfolio::Balance,
+        statistic::summary::trading::{Config, TradingSummary},
+        statistic::summary::{Initialiser, PositionSummariser},
+        test_util::position,
+    };
+    use chrono::TimeZone;
+
+    fn point(secs: i64, total: f64) -> EquityPoint {
+        EquityPoint {
+            time: Utc.timestamp_opt(secs, 0).unwrap(),
+            total,
+        }
+    }
+
+    fn exited_position(exit_secs: i64, total: f64, realised_profit_loss: f64) -> Position {
+        let mut position = position();
+        position.meta.exit_balance = Some(Balance::new(
+            Utc.timestamp_opt(exit_secs, 0).unwrap(),
+            total,
+            total,
+        ));
+        position.realised_profit_loss = realised_profit_loss;
+        position
+    }
+
+    fn report() -> BacktestReport<TradingSummary> {
+        let positions = vec![
+            exited_position(200, 1050.0, 50.0),
+            exited_position(100, 900.0, -100.0),
+            exited_position(300, 1100.0, 50.0),
+        ];
+
+        let mut summary = TradingSummary::init(Config {
+            starting_equity: 1000.0,
+            trading_days_per_year: 365,
+            risk_free_return: 0.0,
+        });
+        summary.generate_summary(&positions);
+
+        BacktestReport::new(
+            Uuid::new_v4(),
+            [("Total".to_owned(), summary)],
+            positions,
+            None,
+        )
+    }
+
+    #[test]
+    fn calculate_drawdowns_includes_drawdown_in_progress() {
+        struct TestCase {
+            input: Vec<EquityPoint>,
+            expected: Vec<(f64, f64)>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: empty equity curve
+                input: vec![],
+                expected: vec![],
+            },
+            TestCase {
+                // TC1: equity only increasing
+                input: vec![point(0, 100.0), point(1, 110.0)],
+                expected: vec![],
+            },
+            TestCase {
+                // TC2: one recovered drawdown
+                input: vec![point(0, 100.0), point(1, 80.0), point(2, 120.0)],
+                expected: vec![(100.0, 80.0)],
+            },
+            TestCase {
+                // TC3: recovered drawdown followed by a drawdown in progress
+                input: vec![
+                    point(0, 100.0),
+                    point(1, 90.0),
+                    point(2, 120.0),
+                    point(3, 60.0),
+                ],
+                expected: vec![(100.0, 90.0), (120.0, 60.0)],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = calculate_drawdowns(&test.input)
+                .into_iter()
+                .map(|drawdown| (drawdown.equity_range.high, drawdown.equity_range.low))
+                .collect::<Vec<_>>();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn backtest_report_derives_equity_curve_from_exited_positions() {
+        let report = report();
+
+        assert_eq!(
+            report.equity_curve,
+            vec![point(100, 900.0), point(200, 1050.0), point(300, 1100.0)]
+        );
+        assert!(report.time_series.is_empty());
+        assert_eq!(report.drawdowns.len(), 0);
+    }
+
+    #[test]
+    fn backtest_report_exports_json_csv_and_html() {
+        let report = report();
+
+        let json = serde_json::from_str::<serde_json::Value>(&report.to_json().unwrap()).unwrap();
+        assert_eq!(json["statistics"][0]["id"], "Total");
+        assert_eq!(json["positions"].as_array().unwrap().len(), 3);
+
+        let statistics_csv = report.statistics_csv();
+        let mut lines = statistics_csv.lines();
+        assert!(lines.next().unwrap().starts_with("id,"));
+        assert!(lines.next().unwrap().starts_with("Total,"));
+
+        assert_eq!(report.positions_csv().lines().count(), 4);
+        assert_eq!(
+            report.equity_curve_csv().lines().nth(1).unwrap(),
+            "1970-01-01T00:01:40+00:00,900,0"
+        );
+
+        let html = report.to_html();
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert_eq!(html.matches("<svg").count(), 2);
+        assert!(html.contains("<td>Total</td>"));
+    }
+
+    #[test]
+    fn write_csv_record_quotes_special_characters() {
+        let mut csv = String::new();
+        write_csv_record(
+            &mut csv,
+            ["plain", "with,comma", "with\"quote"].map(String::from),
+        );
+        assert_eq!(csv, "plain,\"with,comma\",\"with\"\"quote\"\n");
+    }
+}
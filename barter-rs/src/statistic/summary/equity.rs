@@ -0,0 +1,379 @@
+use crate::statistic::{
+    algorithm::welford_online, de_duration_from_secs, metric::EquityPoint, se_duration_as_secs,
+    summary::TableBuilder,
+};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use prettytable::Row;
+use serde::{Deserialize, Serialize};
+
+/// Configuration for sampling a mark-to-market [`EquityCurve`] & annualising the
+/// [`TimeSeriesMetrics`] computed from it's returns.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct EquityCurveConfig {
+    /// Time between [`EquityPoint`] samples, aligned to the unix epoch (eg/ 1 minute).
+    #[serde(
+        deserialize_with = "de_duration_from_secs",
+        serialize_with = "se_duration_as_secs"
+    )]
+    pub interval: Duration,
+    /// Annual risk free return used to calculate excess returns (eg/ 0.02).
+    pub risk_free_return: f64,
+    pub trading_days_per_year: u32,
+}
+
+impl Default for EquityCurveConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::minutes(1),
+            risk_free_return: 0.0,
+            trading_days_per_year: 365,
+        }
+    }
+}
+
+/// Mark-to-market Portfolio equity sampled on a fixed interval. Unlike the per-trade statistics
+/// that only update when a [`Position`](crate::portfolio::position::Position) is exited, it
+/// includes the unrealised P&L of open Positions, so returns can be measured per unit of time.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct EquityCurve {
+    pub config: EquityCurveConfig,
+    pub points: Vec<EquityPoint>,
+    next_sample_time: Option<DateTime<Utc>>,
+}
+
+impl EquityCurve {
+    /// Construct a new empty [`EquityCurve`] using the provided [`EquityCurveConfig`].
+    pub fn new(config: EquityCurveConfig) -> Self {
+        Self {
+            config,
+            points: Vec::new(),
+            next_sample_time: None,
+        }
+    }
+
+    /// Determine if an [`EquityPoint`] should be sampled at the input time.
+    pub fn is_sample_due(&self, time: DateTime<Utc>) -> bool {
+        match self.next_sample_time {
+            None => true,
+            Some(next_sample_time) => time >= next_sample_time,
+        }
+    }
+
+    /// Append the input [`EquityPoint`] to the curve if a sample is due at it's timestamp,
+    /// returning true if it was sampled.
+    pub fn update(&mut self, point: EquityPoint) -> bool {
+        if !self.is_sample_due(point.time) {
+            return false;
+        }
+
+        self.next_sample_time = Some(next_boundary(point.time, self.config.interval));
+        self.points.push(point);
+        true
+    }
+
+    /// Calculate the simple returns of the curve resampled to the input period. Each period is
+    /// closed by the last [`EquityPoint`] sampled within it, and periods without a sample carry
+    /// the previous close forward.
+    pub fn returns(&self, period: Duration) -> Vec<f64> {
+        let period_secs = period.num_seconds().max(1);
+
+        // Determine the closing equity of every period containing a sample
+        let closes = self
+            .points
+            .iter()
+            .fold(Vec::<(i64, f64)>::new(), |mut closes, point| {
+                let bucket = point.time.timestamp().div_euclid(period_secs);
+                match closes.last_mut() {
+                    Some((last_bucket, close)) if *last_bucket == bucket => *close = point.total,
+                    _ => closes.push((bucket, point.total)),
+                }
+                closes
+            });
+
+        closes
+            .windows(2)
+            .flat_map(|window| {
+                let ((prev_bucket, prev_close), (bucket, close)) = (window[0], window[1]);
+                let skipped_periods = (bucket - prev_bucket - 1).max(0) as usize;
+                let period_return = match prev_close == 0.0 {
+                    true => 0.0,
+                    false => (close - prev_close) / prev_close,
+                };
+
+                std::iter::repeat_n(0.0, skipped_periods).chain(std::iter::once(period_return))
+            })
+            .collect()
+    }
+
+    /// Calculate the simple returns of the curve resampled to hourly periods.
+    pub fn hourly_returns(&self) -> Vec<f64> {
+        self.returns(Duration::hours(1))
+    }
+
+    /// Calculate the simple returns of the curve resampled to daily periods.
+    pub fn daily_returns(&self) -> Vec<f64> {
+        self.returns(Duration::days(1))
+    }
+
+    /// Calculate the largest peak-to-trough decline of the sampled equity as a proportion of the
+    /// peak (eg/ -0.2).
+    pub fn calculate_max_drawdown(&self) -> f64 {
+        self.points
+            .iter()
+            .fold((f64::MIN, 0.0_f64), |(peak, max_drawdown), point| {
+                let peak = peak.max(point.total);
+                let drawdown = match peak == 0.0 {
+                    true => 0.0,
+                    false => (point.total - peak) / peak,
+                };
+                (peak, max_drawdown.min(drawdown))
+            })
+            .1
+    }
+
+    /// Calculate annualised [`TimeSeriesMetrics`] from the returns of the curve resampled to the
+    /// input period.
+    pub fn metrics(&self, period: Duration) -> TimeSeriesMetrics {
+        TimeSeriesMetrics::calculate(
+            period,
+            &self.returns(period),
+            self.calculate_max_drawdown(),
+            self.config,
+        )
+    }
+}
+
+/// Determine the first multiple of the interval (aligned to the unix epoch) after the input time.
+fn next_boundary(time: DateTime<Utc>, interval: Duration) -> DateTime<Utc> {
+    let interval_secs = interval.num_seconds().max(1);
+    let next_secs = (time.timestamp().div_euclid(interval_secs) + 1) * interval_secs;
+    Utc.timestamp_opt(next_secs, 0).single().unwrap_or(time)
+}
+
+/// Annualised risk & return metrics calculated from time-based returns of an [`EquityCurve`].
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct TimeSeriesMetrics {
+    /// Length of each return period (eg/ 1 day).
+    #[serde(
+        deserialize_with = "de_duration_from_secs",
+        serialize_with = "se_duration_as_secs"
+    )]
+    pub period: Duration,
+    /// Number of return periods.
+    pub periods: u64,
+    pub annual_return: f64,
+    pub annual_volatility: f64,
+    pub sharpe_ratio: f64,
+    pub sortino_ratio: f64,
+    pub calmar_ratio: f64,
+    pub max_drawdown: f64,
+}
+
+impl TimeSeriesMetrics {
+    /// Calculate the annualised [`TimeSeriesMetrics`] of the input per period returns.
+    pub fn calculate(
+        period: Duration,
+        returns: &[f64],
+        max_drawdown: f64,
+        config: EquityCurveConfig,
+    ) -> Self {
+        let periods_per_year = config.trading_days_per_year as f64
+            * Duration::days(1).num_seconds() as f64
+            / period.num_seconds().max(1) as f64;
+        let risk_free_return = config.risk_free_return / periods_per_year;
+
+        // Calculate the mean & sample standard deviation of returns in one pass
+        let (count, mean, recurrence_relation_m) = returns.iter().fold(
+            (0_u64, 0.0, 0.0),
+            |(count, prev_mean, prev_m), next_return| {
+                let count = count + 1;
+                let mean = welford_online::calculate_mean(prev_mean, *next_return, count as f64);
+                let m = welford_online::calculate_recurrence_relation_m(
+                    prev_m,
+                    prev_mean,
+                    *next_return,
+                    mean,
+                );
+                (count, mean, m)
+            },
+        );
+        let std_dev =
+            welford_online::calculate_sample_variance(recurrence_relation_m, count).sqrt();
+
+        // Downside deviation only penalises returns below the risk free return
+        let downside_dev = match count {
+            0 => 0.0,
+            _ => {
+                let downside_sum_squares = returns
+                    .iter()
+                    .map(|period_return| (period_return - risk_free_return).min(0.0).powi(2))
+                    .sum::<f64>();
+                (downside_sum_squares / count as f64).sqrt()
+            }
+        };
+
+        let excess_return = mean - risk_free_return;
+        let annual_return = mean * periods_per_year;
+        let annualise = periods_per_year.sqrt();
+
+        Self {
+            period,
+            periods: count,
+            annual_return,
+            annual_volatility: std_dev * annualise,
+            sharpe_ratio: match std_dev == 0.0 {
+                true => 0.0,
+                false => excess_return / std_dev * annualise,
+            },
+            sortino_ratio: match downside_dev == 0.0 {
+                true => 0.0,
+                false => excess_return / downside_dev * annualise,
+            },
+            calmar_ratio: match max_drawdown == 0.0 {
+                true => 0.0,
+                false => (annual_return - config.risk_free_return) / max_drawdown.abs(),
+            },
+            max_drawdown,
+        }
+    }
+}
+
+impl TableBuilder for TimeSeriesMetrics {
+    fn titles(&self) -> Row {
+        row![
+            "Periods",
+            "Annual Return",
+            "Annual Volatility",
+            "Sharpe Ratio",
+            "Sortino Ratio",
+            "Calmar Ratio",
+            "Max Drawdown",
+        ]
+    }
+
+    fn row(&self) -> Row {
+        row![
+            self.periods.to_string(),
+            format!("{:.3}", self.annual_return),
+            format!("{:.3}", self.annual_volatility),
+            format!("{:.3}", self.sharpe_ratio),
+            format!("{:.3}", self.sortino_ratio),
+            format!("{:.3}", self.calmar_ratio),
+            format!("{:.3}", self.max_drawdown),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn point(secs: i64, total: f64) -> EquityPoint {
+        EquityPoint {
+            time: Utc.timestamp_opt(secs, 0).unwrap(),
+            total,
+        }
+    }
+
+    #[test]
+    fn equity_curve_update_samples_once_per_interval() {
+        let mut curve = EquityCurve::new(EquityCurveConfig {
+            interval: Duration::minutes(1),
+            ..EquityCurveConfig::default()
+        });
+
+        struct TestCase {
+            input: EquityPoint,
+            expected_sampled: bool,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: first point is always sampled
+                input: point(30, 100.0),
+                expected_sampled: true,
+            },
+            TestCase {
+                // TC1: point within the same interval is not sampled
+                input: point(59, 101.0),
+                expected_sampled: false,
+            },
+            TestCase {
+                // TC2: point on the next interval boundary is sampled
+                input: point(60, 102.0),
+                expected_sampled: true,
+            },
+            TestCase {
+                // TC3: point several intervals later is sampled
+                input: point(300, 103.0),
+                expected_sampled: true,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            assert_eq!(
+                curve.update(test.input),
+                test.expected_sampled,
+                "TC{} failed",
+                index
+            );
+        }
+
+        assert_eq!(
+            curve.points,
+            vec![point(30, 100.0), point(60, 102.0), point(300, 103.0)]
+        );
+    }
+
+    #[test]
+    fn equity_curve_returns_resampled_to_period() {
+        let hour = 3600;
+        let mut curve = EquityCurve::new(EquityCurveConfig {
+            interval: Duration::minutes(30),
+            ..EquityCurveConfig::default()
+        });
+
+        for point in [
+            point(0, 100.0),
+            point(hour / 2, 105.0),
+            point(hour, 110.0),
+            point(3 * hour, 99.0),
+            point(4 * hour, 99.0),
+        ] {
+            curve.update(point);
+        }
+
+        // Hour 0 closes at 105, hour 1 at 110, hour 2 carries 110 forward, hour 3 at 99...
+        assert_eq!(curve.hourly_returns(), vec![5.0 / 105.0, 0.0, -0.1, 0.0]);
+        assert!(curve.daily_returns().is_empty());
+        assert_eq!(curve.calculate_max_drawdown(), -0.1);
+    }
+
+    #[test]
+    fn time_series_metrics_calculate() {
+        let config = EquityCurveConfig {
+            interval: Duration::hours(1),
+            risk_free_return: 0.0,
+            trading_days_per_year: 365,
+        };
+
+        let actual =
+            TimeSeriesMetrics::calculate(Duration::days(1), &[0.02, -0.01, 0.02], -0.01, config);
+
+        // mean = 0.01, sample std_dev = sqrt(0.0003), downside_dev = sqrt(0.0001 / 3)
+        let annualise = 365_f64.sqrt();
+        assert_eq!(actual.periods, 3);
+        assert!((actual.annual_return - 3.65).abs() < 1e-9);
+        assert!((actual.annual_volatility - 0.0003_f64.sqrt() * annualise).abs() < 1e-9);
+        assert!((actual.sharpe_ratio - 0.01 / 0.0003_f64.sqrt() * annualise).abs() < 1e-9);
+        assert!((actual.sortino_ratio - 0.01 / (0.0001_f64 / 3.0).sqrt() * annualise).abs() < 1e-9);
+        assert!((actual.calmar_ratio - 365.0).abs() < 1e-9);
+
+        // No returns produces zeroed metrics rather than NaNs
+        let actual = TimeSeriesMetrics::calculate(Duration::days(1), &[], 0.0, config);
+        assert_eq!(actual.periods, 0);
+        assert_eq!(actual.sharpe_ratio, 0.0);
+        assert_eq!(actual.sortino_ratio, 0.0);
+        assert_eq!(actual.calmar_ratio, 0.0);
+    }
+}
@@ -0,0 +1,512 @@
+use crate::{
+    portfolio::valuation::market_price,
+    statistic::{
+        algorithm::welford_online, de_duration_from_secs, se_duration_as_secs,
+        summary::TableBuilder,
+    },
+};
+use barter_data::event::{DataKind, MarketEvent};
+use barter_execution::{
+    fill::FillEvent,
+    model::order_event::{OrderEvent, OrderType},
+};
+use barter_integration::model::MarketId;
+use chrono::{DateTime, Duration, Utc};
+use prettytable::{Cell, Row};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use uuid::Uuid;
+
+/// Remaining [`OpenOrder`] quantity below which it is considered fully filled, absorbing the
+/// rounding error of summing `f64` fill quantities.
+const FILLED_QUANTITY_TOLERANCE: f64 = 1e-9;
+
+/// Running mean of a series of values, calculated in one pass.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Default, Deserialize, Serialize)]
+pub struct RunningMean {
+    pub count: u64,
+    pub mean: f64,
+}
+
+impl RunningMean {
+    /// Updates the [`RunningMean`] with the next value in the series.
+    pub fn update(&mut self, value: f64) {
+        self.count += 1;
+        self.mean = welford_online::calculate_mean(self.mean, value, self.count as f64);
+    }
+}
+
+/// Mean signed price move after a fill, measured at a fixed horizon. Positive values indicate the
+/// market moved in favour of the fill.
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct Markout {
+    #[serde(
+        deserialize_with = "de_duration_from_secs",
+        serialize_with = "se_duration_as_secs"
+    )]
+    pub horizon: Duration,
+    /// Markout in basis points of the fill price.
+    pub bps: RunningMean,
+}
+
+/// [`OrderEvent`] awaiting it's [`FillEvent`]s, used to measure time-to-fill & the limit fill
+/// rate.
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct OpenOrder {
+    id: Uuid,
+    time: DateTime<Utc>,
+    is_limit: bool,
+    /// Absolute quantity not yet filled.
+    remaining: f64,
+}
+
+/// Fill awaiting a [`MarketEvent`] at, or after, a [`Markout`] horizon.
+#[derive(Clone, PartialEq, Debug)]
+struct PendingMarkout {
+    market_id: MarketId,
+    horizon_index: usize,
+    due_time: DateTime<Utc>,
+    fill_price: f64,
+    side_sign: f64,
+}
+
+/// Trade-level execution quality statistics. Fed by the [`OrderEvent`]s sent for execution, the
+/// [`FillEvent`]s they generate, and the [`MarketEvent`]s that follow the fills.
+///
+/// [`FillEvent`]s are matched to the open [`OrderEvent`] with the same order_id, or to the oldest
+/// open [`OrderEvent`] of the same market if the order_id is unknown. An [`OrderEvent`] stays open
+/// until fills of it's full quantity are received, at which point it counts towards the limit fill
+/// rate & time-to-fill. Fills are classified as maker or taker by their
+/// [`Liquidity`](barter_execution::fill::Liquidity), which the fill producer must set.
+#[derive(Clone, PartialEq, Debug, Deserialize, Serialize)]
+pub struct ExecutionSummary {
+    pub orders: u64,
+    pub limit_orders: u64,
+    pub fills: u64,
+    pub limit_fills: u64,
+    pub maker_fills: u64,
+    /// Fill price versus decision price ([`MarketMeta.close`](barter_execution::fill::MarketMeta))
+    /// in basis points. Positive values indicate the fill was worse than the decision price.
+    pub slippage_bps: RunningMean,
+    /// Seconds between an [`OrderEvent`] & the [`FillEvent`] that completes it's quantity.
+    pub time_to_fill_secs: RunningMean,
+    pub markouts: Vec<Markout>,
+    #[serde(skip)]
+    open_orders: HashMap<MarketId, VecDeque<OpenOrder>>,
+    #[serde(skip)]
+    pending_markouts: Vec<PendingMarkout>,
+}
+
+impl Default for ExecutionSummary {
+    fn default() -> Self {
+        Self::new([
+            Duration::seconds(1),
+            Duration::seconds(10),
+            Duration::seconds(60),
+        ])
+    }
+}
+
+impl ExecutionSummary {
+    /// Construct a new [`ExecutionSummary`] measuring [`Markout`]s at the input horizons.
+    pub fn new<Horizons>(markout_horizons: Horizons) -> Self
+    where
+        Horizons: IntoIterator<Item = Duration>,
+    {
+        Self {
+            orders: 0,
+            limit_orders: 0,
+            fills: 0,
+            limit_fills: 0,
+            maker_fills: 0,
+            slippage_bps: RunningMean::default(),
+            time_to_fill_secs: RunningMean::default(),
+            markouts: markout_horizons
+                .into_iter()
+                .map(|horizon| Markout {
+                    horizon,
+                    bps: RunningMean::default(),
+                })
+                .collect(),
+            open_orders: HashMap::new(),
+            pending_markouts: Vec::new(),
+        }
+    }
+
+    /// Updates the [`ExecutionSummary`] with an [`OrderEvent`] sent for execution.
+    pub fn update_from_order(&mut self, order: &OrderEvent) {
+        let is_limit = matches!(order.order_type, OrderType::Limit { .. });
+
+        self.orders += 1;
+        if is_limit {
+            self.limit_orders += 1;
+        }
+
+        self.open_orders
+            .entry(MarketId::new(&order.exchange, &order.instrument))
+            .or_default()
+            .push_back(OpenOrder {
+                id: order.id,
+                time: order.time,
+                is_limit,
+                remaining: order.quantity.abs(),
+            });
+    }
+
+    /// Updates the [`ExecutionSummary`] with a [`FillEvent`], and schedules it's [`Markout`]s.
+    pub fn update_from_fill(&mut self, fill: &FillEvent) {
+        let market_id = MarketId::new(&fill.exchange, &fill.instrument);
+        self.fills += 1;
+
+        // Match the FillEvent to it's OrderEvent, or the oldest open OrderEvent of the same market
+        if let Some(orders) = self.open_orders.get_mut(&market_id) {
+            let index = match fill.order_id {
+                Some(order_id) => orders.iter().position(|order| order.id == order_id),
+                None => (!orders.is_empty()).then_some(0),
+            };
+
+            if let Some(index) = index {
+                let order = &mut orders[index];
+                order.remaining -= fill.quantity.abs();
+
+                // OrderEvent is filled once fills of it's full quantity have been received
+                if order.remaining <= FILLED_QUANTITY_TOLERANCE {
+                    let order = orders.remove(index).expect("index is within open orders");
+                    let time_to_fill = fill.time.signed_duration_since(order.time);
+                    self.time_to_fill_secs
+                        .update(time_to_fill.num_milliseconds() as f64 / 1000.0);
+
+                    if order.is_limit {
+                        self.limit_fills += 1;
+                    }
+                }
+            }
+        }
+
+        if fill.liquidity.is_maker() {
+            self.maker_fills += 1;
+        }
+
+        // Price measurements require a non-zero quantity
+        if fill.quantity == 0.0 {
+            return;
+        }
+        let fill_price = fill.fill_value_gross / fill.quantity.abs();
+        let side_sign = fill.quantity.signum();
+
+        // Slippage versus the decision price, +ve when paying more to buy or receiving less to sell
+        if fill.market_meta.close != 0.0 {
+            self.slippage_bps.update(
+                side_sign * (fill_price - fill.market_meta.close) / fill.market_meta.close
+                    * 10_000.0,
+            );
+        }
+
+        // Schedule a Markout at every horizon
+        if fill_price != 0.0 {
+            self.pending_markouts
+                .extend(
+                    self.markouts
+                        .iter()
+                        .enumerate()
+                        .map(|(horizon_index, markout)| PendingMarkout {
+                            market_id: market_id.clone(),
+                            horizon_index,
+                            due_time: fill.time + markout.horizon,
+                            fill_price,
+                            side_sign,
+                        }),
+                );
+        }
+    }
+
+    /// Updates every [`Markout`] of the [`MarketEvent`] market that is due at it's exchange time.
+    pub fn update_from_market(&mut self, market: &MarketEvent<DataKind>) {
+        if self.pending_markouts.is_empty() {
+            return;
+        }
+
+        let Some(price) = market_price(market) else {
+            return;
+        };
+
+        let market_id = MarketId::new(&market.exchange, &market.instrument);
+        let markouts = &mut self.markouts;
+
+        self.pending_markouts.retain(|pending| {
+            if pending.market_id != market_id || market.exchange_time < pending.due_time {
+                return true;
+            }
+
+            markouts[pending.horizon_index].bps.update(
+                pending.side_sign * (price - pending.fill_price) / pending.fill_price * 10_000.0,
+            );
+            false
+        });
+    }
+
+    /// Calculate the proportion of limit [`OrderEvent`]s that have been fully filled.
+    pub fn calculate_limit_fill_rate(&self) -> f64 {
+        match self.limit_orders {
+            0 => 0.0,
+            limit_orders => self.limit_fills as f64 / limit_orders as f64,
+        }
+    }
+
+    /// Calculate the proportion of [`FillEvent`]s that were maker fills.
+    pub fn calculate_maker_ratio(&self) -> f64 {
+        match self.fills {
+            0 => 0.0,
+            fills => self.maker_fills as f64 / fills as f64,
+        }
+    }
+}
+
+impl TableBuilder for ExecutionSummary {
+    fn titles(&self) -> Row {
+        let mut titles = row![
+            "Orders",
+            "Fills",
+            "Limit Fill Rate",
+            "Maker Ratio",
+            "Avg. Slippage (bps)",
+            "Avg. Time To Fill (s)",
+        ];
+
+        for markout in &self.markouts {
+            titles.add_cell(Cell::new(&format!(
+                "Markout {}s (bps)",
+                markout.horizon.num_seconds()
+            )));
+        }
+
+        titles
+    }
+
+    fn row(&self) -> Row {
+        let mut row = row![
+            self.orders.to_string(),
+            self.fills.to_string(),
+            format!("{:.3}", self.calculate_limit_fill_rate()),
+            format!("{:.3}", self.calculate_maker_ratio()),
+            format!("{:.3}", self.slippage_bps.mean),
+            format!("{:.3}", self.time_to_fill_secs.mean),
+        ];
+
+        for markout in &self.markouts {
+            row.add_cell(Cell::new(&format!("{:.3}", markout.bps.mean)));
+        }
+
+        row
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::{fill_event, market_event_trade, order_event};
+    use barter_execution::{fill::Liquidity, model::order_event::OrderExecutionType};
+    use barter_integration::model::Side;
+    use rust_decimal::Decimal;
+
+    #[test]
+    fn execution_summary_matches_fills_to_orders() {
+        let mut summary = ExecutionSummary::default();
+        let time = Utc::now();
+
+        let mut limit_order = order_event();
+        limit_order.time = time;
+        limit_order.order_type = OrderType::Limit {
+            price: 100.0,
+            execution_type: OrderExecutionType::MakerOnly,
+        };
+        let mut market_order = order_event();
+        market_order.time = time;
+        market_order.order_type = OrderType::Market;
+
+        summary.update_from_order(&limit_order);
+        summary.update_from_order(&market_order);
+
+        // Limit order filled 2s after it was sent, buying 1 unit at 101 versus a decision price of 100
+        let mut fill = fill_event();
+        fill.exchange = limit_order.exchange.clone();
+        fill.instrument = limit_order.instrument.clone();
+        fill.time = time + Duration::seconds(2);
+        fill.quantity = 1.0;
+        fill.fill_value_gross = 101.0;
+        fill.market_meta.close = 100.0;
+        fill.liquidity = Liquidity::Maker;
+        summary.update_from_fill(&fill);
+
+        assert_eq!(summary.orders, 2);
+        assert_eq!(summary.limit_orders, 1);
+        assert_eq!(summary.fills, 1);
+        assert_eq!(summary.calculate_limit_fill_rate(), 1.0);
+        assert_eq!(summary.calculate_maker_ratio(), 1.0);
+        assert_eq!(summary.time_to_fill_secs.mean, 2.0);
+        assert!((summary.slippage_bps.mean - 100.0).abs() < 1e-9);
+
+        // Second fill matches the market order, selling 1 unit at 99 versus 100
+        fill.quantity = -1.0;
+        fill.fill_value_gross = 99.0;
+        fill.liquidity = Liquidity::Taker;
+        summary.update_from_fill(&fill);
+
+        assert_eq!(summary.fills, 2);
+        assert_eq!(summary.calculate_maker_ratio(), 0.5);
+        assert!((summary.slippage_bps.mean - 100.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn execution_summary_matches_partial_fills_by_order_id() {
+        let mut summary = ExecutionSummary::default();
+        let time = Utc::now();
+
+        let mut limit_order = order_event();
+        limit_order.id = Uuid::new_v4();
+        limit_order.time = time;
+        limit_order.quantity = 2.0;
+        limit_order.order_type = OrderType::Limit {
+            price: 100.0,
+            execution_type: OrderExecutionType::MakerOnly,
+        };
+        let mut market_order = order_event();
+        market_order.id = Uuid::new_v4();
+        market_order.time = time;
+        market_order.order_type = OrderType::Market;
+
+        summary.update_from_order(&limit_order);
+        summary.update_from_order(&market_order);
+
+        // Fill of the market order is matched by order_id, leaving the older limit order open
+        let mut fill = fill_event();
+        fill.exchange = limit_order.exchange.clone();
+        fill.instrument = limit_order.instrument.clone();
+        fill.order_id = Some(market_order.id);
+        fill.time = time + Duration::seconds(1);
+        summary.update_from_fill(&fill);
+
+        assert_eq!(summary.time_to_fill_secs.count, 1);
+        assert_eq!(summary.calculate_limit_fill_rate(), 0.0);
+
+        // Partial fill with an unknown order_id matches the oldest open order, keeping it open
+        fill.order_id = None;
+        fill.time = time + Duration::seconds(2);
+        summary.update_from_fill(&fill);
+
+        assert_eq!(summary.time_to_fill_secs.count, 1);
+        assert_eq!(summary.calculate_limit_fill_rate(), 0.0);
+
+        // Fill of the remaining quantity completes the limit order
+        fill.order_id = Some(limit_order.id);
+        fill.time = time + Duration::seconds(5);
+        summary.update_from_fill(&fill);
+
+        assert_eq!(summary.fills, 3);
+        assert_eq!(summary.time_to_fill_secs.count, 2);
+        assert_eq!(summary.time_to_fill_secs.mean, 3.0);
+        assert_eq!(summary.calculate_limit_fill_rate(), 1.0);
+        assert!(summary.open_orders[&MarketId::new(&fill.exchange, &fill.instrument)].is_empty());
+    }
+
+    #[test]
+    fn execution_summary_classifies_maker_fills_by_fill_liquidity() {
+        let mut summary = ExecutionSummary::default();
+
+        // Limit order that crossed the spread is a taker fill
+        let mut limit_order = order_event();
+        limit_order.order_type = OrderType::Limit {
+            price: 100.0,
+            execution_type: OrderExecutionType::None,
+        };
+        summary.update_from_order(&limit_order);
+
+        let mut fill = fill_event();
+        fill.exchange = limit_order.exchange.clone();
+        fill.instrument = limit_order.instrument.clone();
+        fill.liquidity = Liquidity::Taker;
+        summary.update_from_fill(&fill);
+
+        assert_eq!(summary.calculate_limit_fill_rate(), 1.0);
+        assert_eq!(summary.calculate_maker_ratio(), 0.0);
+
+        // Fill reported as maker is a maker fill, regardless of the matched OrderEvent
+        let mut market_order = order_event();
+        market_order.order_type = OrderType::Market;
+        summary.update_from_order(&market_order);
+
+        fill.liquidity = Liquidity::Maker;
+        summary.update_from_fill(&fill);
+
+        assert_eq!(summary.calculate_limit_fill_rate(), 1.0);
+        assert_eq!(summary.calculate_maker_ratio(), 0.5);
+    }
+
+    #[test]
+    fn execution_summary_markouts_at_horizons() {
+        let mut summary = ExecutionSummary::new([Duration::seconds(1), Duration::seconds(10)]);
+        let time = Utc::now();
+
+        // Buy 1 unit at 100
+        let mut fill = fill_event();
+        fill.time = time;
+        fill.quantity = 1.0;
+        fill.fill_value_gross = 100.0;
+        summary.update_from_fill(&fill);
+
+        struct TestCase {
+            offset_secs: i64,
+            price: f64,
+            expected_markouts: [(u64, f64); 2],
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: MarketEvent before the first horizon records no markouts
+                offset_secs: 0,
+                price: 200.0,
+                expected_markouts: [(0, 0.0), (0, 0.0)],
+            },
+            TestCase {
+                // TC1: MarketEvent at the 1s horizon records the 1s markout
+                offset_secs: 1,
+                price: 101.0,
+                expected_markouts: [(1, 100.0), (0, 0.0)],
+            },
+            TestCase {
+                // TC2: MarketEvent after the 10s horizon records the 10s markout only once
+                offset_secs: 15,
+                price: 99.0,
+                expected_markouts: [(1, 100.0), (1, -100.0)],
+            },
+            TestCase {
+                // TC3: no markouts left pending
+                offset_secs: 60,
+                price: 150.0,
+                expected_markouts: [(1, 100.0), (1, -100.0)],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let mut market = market_event_trade(Side::Buy);
+            market.exchange = fill.exchange.clone();
+            market.instrument = fill.instrument.clone();
+            market.exchange_time = time + Duration::seconds(test.offset_secs);
+            if let DataKind::Trade(trade) = &mut market.kind {
+                trade.price = Decimal::try_from(test.price).unwrap();
+            }
+            summary.update_from_market(&market);
+
+            for (markout, (expected_count, expected_bps)) in
+                summary.markouts.iter().zip(test.expected_markouts)
+            {
+                assert_eq!(markout.bps.count, expected_count, "TC{} failed", index);
+                assert!(
+                    (markout.bps.mean - expected_bps).abs() < 1e-9,
+                    "TC{} failed",
+                    index
+                );
+            }
+        }
+    }
+}
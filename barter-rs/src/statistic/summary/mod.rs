@@ -1,5 +1,7 @@
 pub mod data;
 pub mod drawdown;
+pub mod equity;
+pub mod execution;
 pub mod pnl;
 pub mod trading;
 
@@ -18,6 +20,24 @@ pub trait PositionSummariser: Copy {
             self.update(position)
         }
     }
+
+    /// Returns the [`TradeOutcomes`] of the exited [`Position`]s summarised, if the summary
+    /// tracks them and at least one [`Position`] has been summarised.
+    fn trade_outcomes(&self) -> Option<TradeOutcomes> {
+        None
+    }
+}
+
+/// Win rate & payoff of exited [`Position`]s. Used to size orders (eg/ Kelly criterion).
+#[derive(Copy, Clone, PartialEq, PartialOrd, Debug)]
+pub struct TradeOutcomes {
+    /// Number of exited [`Position`]s.
+    pub trades: u64,
+    /// Proportion of exited [`Position`]s with a non-negative PnL return.
+    pub win_rate: f64,
+    /// Mean winning PnL return divided by the absolute mean losing PnL return. Infinite if there
+    /// have been no losses.
+    pub payoff_ratio: f64,
 }
 
 pub trait TableBuilder {
@@ -2,7 +2,9 @@ use crate::{
     portfolio::position::Position,
     statistic::{
         de_duration_from_secs, se_duration_as_secs,
-        summary::{data::DataSummary, Initialiser, PositionSummariser, TableBuilder},
+        summary::{
+            data::DataSummary, Initialiser, PositionSummariser, TableBuilder, TradeOutcomes,
+        },
     },
 };
 use barter_integration::model::Side;
@@ -65,6 +67,29 @@ impl PositionSummariser for PnLReturnSummary {
             self.losses.update(pnl_return);
         }
     }
+
+    fn trade_outcomes(&self) -> Option<TradeOutcomes> {
+        if self.total.count == 0 {
+            return None;
+        }
+
+        let wins = self.total.count - self.losses.count;
+        let win_mean = match wins {
+            0 => 0.0,
+            wins => (self.total.sum - self.losses.sum) / wins as f64,
+        };
+
+        let payoff_ratio = match self.losses.count {
+            0 => f64::INFINITY,
+            _ => win_mean / self.losses.mean.abs(),
+        };
+
+        Some(TradeOutcomes {
+            trades: self.total.count,
+            win_rate: wins as f64 / self.total.count as f64,
+            payoff_ratio,
+        })
+    }
 }
 
 impl TableBuilder for PnLReturnSummary {
@@ -210,6 +235,26 @@ mod tests {
         // Todo:
     }
 
+    #[test]
+    fn trade_outcomes_from_win_and_loss_pnl_returns() {
+        let mut pnl_return_view = PnLReturnSummary::new();
+        assert_eq!(pnl_return_view.trade_outcomes(), None);
+
+        // Wins of 0.2 & 0.4, losses of -0.1 & -0.2
+        pnl_return_view.total.update(0.2);
+        pnl_return_view.total.update(0.4);
+        pnl_return_view.total.update(-0.1);
+        pnl_return_view.total.update(-0.2);
+        pnl_return_view.losses.update(-0.1);
+        pnl_return_view.losses.update(-0.2);
+
+        let actual = pnl_return_view.trade_outcomes().unwrap();
+
+        assert_eq!(actual.trades, 4);
+        assert_eq!(actual.win_rate, 0.5);
+        assert!((actual.payoff_ratio - 2.0).abs() < 1e-9);
+    }
+
     #[test]
     fn update_trading_session_duration_with_non_exited_position() {
         let base_time = Utc::now();
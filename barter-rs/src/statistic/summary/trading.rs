@@ -4,7 +4,7 @@ use crate::{
         metric::ratio::{CalmarRatio, Ratio, SharpeRatio, SortinoRatio},
         summary::{
             drawdown::DrawdownSummary, pnl::PnLReturnSummary, Initialiser, PositionSummariser,
-            TableBuilder,
+            TableBuilder, TradeOutcomes,
         },
     },
 };
@@ -45,6 +45,10 @@ impl PositionSummariser for TradingSummary {
         self.drawdown.update(position);
         self.tear_sheet.update(&self.pnl_returns, &self.drawdown);
     }
+
+    fn trade_outcomes(&self) -> Option<TradeOutcomes> {
+        self.pnl_returns.trade_outcomes()
+    }
 }
 
 impl TableBuilder for TradingSummary {
@@ -2,6 +2,7 @@ use super::{Decision, Signal, SignalGenerator, SignalStrength};
 use barter_data::event::{DataKind, MarketEvent};
 use barter_execution::fill::MarketMeta;
 use chrono::Utc;
+use rust_decimal::prelude::ToPrimitive;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use ta::{indicators::RelativeStrengthIndex, Next};
@@ -22,7 +23,7 @@ impl SignalGenerator for RSIStrategy {
     fn generate_signal(&mut self, market: &MarketEvent<DataKind>) -> Option<Signal> {
         // Check if it's a MarketEvent with a candle
         let candle_close = match &market.kind {
-            DataKind::Candle(candle) => candle.close,
+            DataKind::Candle(candle) => candle.close.to_f64()?,
             DataKind::IntentOrder(_order) => return None,
             _ => return None,
         };
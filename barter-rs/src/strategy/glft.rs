@@ -5,6 +5,7 @@ use barter_integration::model::Side;
 use core::fmt::Error;
 use ndarray::prelude::*;
 use ndarray_stats::{QuantileExt, SummaryStatisticsExt};
+use rust_decimal::prelude::ToPrimitive;
 // use plotpy::{Curve, Plot, StrError};
 
 #[derive(Clone, Debug, Copy)]
@@ -38,7 +39,7 @@ pub fn measure_trading_intensity_and_volatility<'a>(
         let mut depth = -(f64::INFINITY);
         for trade in last_trades {
             let side = trade.side;
-            let trade_price_tick = trade.price / m.tick_size;
+            let trade_price_tick = trade.price.to_f64().unwrap_or(f64::NAN) / m.tick_size;
             if side == Side::Buy {
                 depth = array![trade_price_tick - m.mid_price_tick, depth]
                     .max_skipnan()
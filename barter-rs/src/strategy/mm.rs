@@ -6,6 +6,7 @@ use barter_data::{
 };
 use chrono::{DateTime, Timelike, Utc};
 use ndarray::prelude::*;
+use rust_decimal::prelude::ToPrimitive;
 use serde::{Deserialize, Serialize};
 
 use tracing::{error, warn};
@@ -48,7 +49,10 @@ impl SignalGenerator for GLFTStrategy {
         let _book = match &market.kind {
             DataKind::OrderBook(book) => {
                 let m = &mut self.measurement_params;
-                match (book.best_bid(), book.best_ask()) {
+                match (
+                    book.best_bid().and_then(|price| price.to_f64()),
+                    book.best_ask().and_then(|price| price.to_f64()),
+                ) {
                     (Some(best_bid), Some(best_ask)) => {
                         m.best_bid_tick = best_bid / m.tick_size;
                         m.best_ask_tick = best_ask / m.tick_size;